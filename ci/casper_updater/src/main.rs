@@ -176,6 +176,9 @@ fn get_args() -> Args {
 }
 
 fn main() {
+    let types_macros = Package::cargo("types_macros", &*regex_data::types_macros::DEPENDENT_FILES);
+    types_macros.update();
+
     let types = Package::cargo("types", &*regex_data::types::DEPENDENT_FILES);
     types.update();
 
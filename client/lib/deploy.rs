@@ -5,13 +5,15 @@ use std::{
 
 use semver::Version;
 use serde::{Deserialize, Serialize};
+use thiserror::Error as ThisError;
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::{
+    crypto::{self, hash::Digest},
     rpcs::{account::PutDeploy, chain::GetBlockResult, info::GetDeploy, RpcWithParams},
-    types::{Deploy, DeployHash, TimeDiff, Timestamp},
+    types::{Deploy, DeployHash, DeployLimits, TimeDiff, Timestamp},
 };
-use casper_types::SecretKey;
+use casper_types::{bytesrepr::ToBytes, PublicKey, SecretKey};
 
 use crate::{
     error::{Error, Result},
@@ -112,6 +114,122 @@ pub struct DeployParams {
     pub chain_name: String,
 }
 
+/// `DeployRefreshParams` are used with `DeployExt::refresh` to renew a previously-built `Deploy`,
+/// e.g. once it has expired.
+pub struct DeployRefreshParams {
+    /// The secret keys with which to re-sign the `Deploy`.
+    pub secret_keys: Vec<SecretKey>,
+
+    /// The new creation timestamp of the `Deploy`.
+    pub timestamp: Timestamp,
+
+    /// The new time to live of the `Deploy`.  If `None`, the existing time to live is kept.
+    pub maybe_ttl: Option<TimeDiff>,
+}
+
+/// A way in which a `Deploy` fails to comply with the limits of the network it is intended for.
+#[derive(ThisError, Serialize, Clone, PartialEq, Eq, Debug)]
+pub enum DeployDiagnostic {
+    /// The chain name differs from that of the network.
+    #[error("chain name '{got}' differs from the network's chain name '{expected}'")]
+    InvalidChainName {
+        /// The chain name of the network.
+        expected: String,
+        /// The chain name of the deploy.
+        got: String,
+    },
+    /// The serialized deploy is too large.
+    #[error("serialized deploy size of {got} bytes exceeds limit of {max_deploy_size}")]
+    ExcessiveSize {
+        /// The maximum serialized size of a deploy in bytes.
+        max_deploy_size: u32,
+        /// The serialized size of the deploy in bytes.
+        got: usize,
+    },
+    /// The session Wasm module alone exceeds the maximum deploy size.
+    #[error("session module size of {got} bytes exceeds deploy size limit of {max_deploy_size}")]
+    ExcessiveSessionModuleSize {
+        /// The maximum serialized size of a deploy in bytes.
+        max_deploy_size: u32,
+        /// The size of the session module in bytes.
+        got: usize,
+    },
+    /// The time-to-live is too long.
+    #[error("time-to-live of {got} exceeds limit of {max_ttl}")]
+    ExcessiveTimeToLive {
+        /// The maximum time-to-live.
+        max_ttl: TimeDiff,
+        /// The time-to-live of the deploy.
+        got: TimeDiff,
+    },
+    /// There are too many dependencies.
+    #[error("{got} dependencies exceeds limit of {max_dependencies}")]
+    ExcessiveDependencies {
+        /// The maximum number of dependencies.
+        max_dependencies: u8,
+        /// The number of dependencies of the deploy.
+        got: usize,
+    },
+    /// The serialized payment args are too long.
+    #[error("serialized payment args of {got} bytes exceeds limit of {max_length}")]
+    ExcessivePaymentArgsLength {
+        /// The maximum serialized length of the payment args.
+        max_length: u32,
+        /// The serialized length of the deploy's payment args.
+        got: usize,
+    },
+    /// The serialized session args are too long.
+    #[error("serialized session args of {got} bytes exceeds limit of {max_length}")]
+    ExcessiveSessionArgsLength {
+        /// The maximum serialized length of the session args.
+        max_length: u32,
+        /// The serialized length of the deploy's session args.
+        got: usize,
+    },
+    /// The deploy has not been signed.
+    #[error("deploy has no approvals")]
+    MissingApprovals,
+}
+
+/// The outcome of verifying a single approval of a `Deploy`.
+#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct ApprovalVerification {
+    /// The public key of the signer.
+    pub signer: PublicKey,
+    /// Why the signature fails to verify against the deploy hash, or `None` if it is valid.
+    pub error: Option<String>,
+}
+
+/// The outcome of every check performed when verifying a `Deploy` offline.
+#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct DeployVerificationReport {
+    /// The deploy hash as given in the deploy.
+    pub deploy_hash: DeployHash,
+    /// The hash of the deploy's header, which the deploy hash should match.
+    pub computed_deploy_hash: DeployHash,
+    /// The body hash as given in the deploy's header.
+    pub body_hash: Digest,
+    /// The hash of the deploy's payment and session code, which the body hash should match.
+    pub computed_body_hash: Digest,
+    /// The outcome of verifying each approval, in order.
+    pub approvals: Vec<ApprovalVerification>,
+    /// Every way in which the deploy fails to comply with the network's limits.
+    pub diagnostics: Vec<DeployDiagnostic>,
+}
+
+impl DeployVerificationReport {
+    /// Returns true if the deploy passed all checks.
+    pub fn is_valid(&self) -> bool {
+        self.deploy_hash == self.computed_deploy_hash
+            && self.body_hash == self.computed_body_hash
+            && self
+                .approvals
+                .iter()
+                .all(|approval| approval.error.is_none())
+            && self.diagnostics.is_empty()
+    }
+}
+
 /// An extension trait that adds some client-specific functionality to `Deploy`.
 pub(super) trait DeployExt {
     /// Constructs a `Deploy`.
@@ -136,6 +254,22 @@ pub(super) trait DeployExt {
     where
         R: Read,
         W: Write;
+
+    /// Returns a copy of the `Deploy` with an updated timestamp and time to live, signed by each of
+    /// the given secret keys.
+    ///
+    /// The payment and session are preserved, while the existing approvals are dropped since they
+    /// are no longer valid for the new deploy hash.
+    fn refresh(&self, params: DeployRefreshParams) -> Result<Deploy>;
+
+    /// Checks the `Deploy` against the given chainspec limits, returning every way in which it
+    /// fails to comply.  An empty result means the deploy would not be rejected for exceeding any
+    /// of the limits.
+    fn validate_against_chainspec(&self, limits: &DeployLimits) -> Vec<DeployDiagnostic>;
+
+    /// Checks the `Deploy`'s hash, body hash and every approval signature, as well as its
+    /// compliance with the given chainspec limits, reporting the outcome of each check.
+    fn verify(&self, limits: &DeployLimits) -> DeployVerificationReport;
 }
 
 impl DeployExt for Deploy {
@@ -157,6 +291,7 @@ impl DeployExt for Deploy {
             timestamp,
             ttl,
             gas_price,
+            None,
             dependencies,
             chain_name,
             payment,
@@ -201,6 +336,116 @@ impl DeployExt for Deploy {
         deploy.write_deploy(output)?;
         Ok(())
     }
+
+    fn refresh(&self, params: DeployRefreshParams) -> Result<Deploy> {
+        let DeployRefreshParams {
+            secret_keys,
+            timestamp,
+            maybe_ttl,
+        } = params;
+
+        if secret_keys.is_empty() {
+            return Err(Error::InvalidArgument(
+                "secret_keys",
+                "at least one secret key is required to re-sign the deploy".to_string(),
+            ));
+        }
+
+        let ttl = maybe_ttl.unwrap_or_else(|| self.header().ttl());
+        let mut deploy = self.with_timestamp_and_ttl(timestamp, ttl);
+        for secret_key in &secret_keys {
+            deploy.sign(secret_key);
+        }
+        deploy.is_valid_size(MAX_SERIALIZED_SIZE)?;
+        Ok(deploy)
+    }
+
+    fn validate_against_chainspec(&self, limits: &DeployLimits) -> Vec<DeployDiagnostic> {
+        let mut diagnostics = vec![];
+        let header = self.header();
+
+        if header.chain_name() != limits.chain_name {
+            diagnostics.push(DeployDiagnostic::InvalidChainName {
+                expected: limits.chain_name.clone(),
+                got: header.chain_name().to_string(),
+            });
+        }
+
+        let deploy_size = self.serialized_length();
+        if deploy_size > limits.max_deploy_size as usize {
+            diagnostics.push(DeployDiagnostic::ExcessiveSize {
+                max_deploy_size: limits.max_deploy_size,
+                got: deploy_size,
+            });
+        }
+
+        if let ExecutableDeployItem::ModuleBytes { module_bytes, .. } = self.session() {
+            if module_bytes.len() > limits.max_deploy_size as usize {
+                diagnostics.push(DeployDiagnostic::ExcessiveSessionModuleSize {
+                    max_deploy_size: limits.max_deploy_size,
+                    got: module_bytes.len(),
+                });
+            }
+        }
+
+        if header.ttl() > limits.max_ttl {
+            diagnostics.push(DeployDiagnostic::ExcessiveTimeToLive {
+                max_ttl: limits.max_ttl,
+                got: header.ttl(),
+            });
+        }
+
+        if header.dependencies().len() > limits.max_dependencies as usize {
+            diagnostics.push(DeployDiagnostic::ExcessiveDependencies {
+                max_dependencies: limits.max_dependencies,
+                got: header.dependencies().len(),
+            });
+        }
+
+        let payment_args_length = self.payment().args().serialized_length();
+        if payment_args_length > limits.payment_args_max_length as usize {
+            diagnostics.push(DeployDiagnostic::ExcessivePaymentArgsLength {
+                max_length: limits.payment_args_max_length,
+                got: payment_args_length,
+            });
+        }
+
+        let session_args_length = self.session().args().serialized_length();
+        if session_args_length > limits.session_args_max_length as usize {
+            diagnostics.push(DeployDiagnostic::ExcessiveSessionArgsLength {
+                max_length: limits.session_args_max_length,
+                got: session_args_length,
+            });
+        }
+
+        if self.approvals().is_empty() {
+            diagnostics.push(DeployDiagnostic::MissingApprovals);
+        }
+
+        diagnostics
+    }
+
+    fn verify(&self, limits: &DeployLimits) -> DeployVerificationReport {
+        let approvals = self
+            .approvals()
+            .iter()
+            .map(|approval| ApprovalVerification {
+                signer: *approval.signer(),
+                error: crypto::verify(self.id(), approval.signature(), approval.signer())
+                    .err()
+                    .map(|error| error.to_string()),
+            })
+            .collect();
+
+        DeployVerificationReport {
+            deploy_hash: *self.id(),
+            computed_deploy_hash: self.compute_hash(),
+            body_hash: *self.header().body_hash(),
+            computed_body_hash: self.compute_body_hash(),
+            approvals,
+            diagnostics: self.validate_against_chainspec(limits),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -208,6 +453,7 @@ mod tests {
     use std::convert::TryInto;
 
     use casper_node::{crypto::AsymmetricKeyExt, types::ExcessiveSizeDeployError};
+    use casper_types::PublicKey;
 
     use super::*;
     use crate::{DeployStrParams, PaymentStrParams, SessionStrParams};
@@ -422,4 +668,153 @@ mod tests {
             signed_deploy
         );
     }
+
+    #[test]
+    fn should_refresh_expired_deploy() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        let now = Timestamp::now();
+        assert!(deploy.header().expired(now));
+
+        let secret_key = SecretKey::generate_ed25519().unwrap();
+        let signer = PublicKey::from(&secret_key);
+        let params = DeployRefreshParams {
+            secret_keys: vec![secret_key],
+            timestamp: now,
+            maybe_ttl: None,
+        };
+        let mut refreshed_deploy = deploy.refresh(params).unwrap();
+
+        assert!(!refreshed_deploy.header().expired(now));
+        assert_eq!(refreshed_deploy.header().timestamp(), now);
+        assert_eq!(refreshed_deploy.header().ttl(), deploy.header().ttl());
+        assert_eq!(
+            refreshed_deploy.header().account(),
+            deploy.header().account()
+        );
+        assert_eq!(refreshed_deploy.payment(), deploy.payment());
+        assert_eq!(refreshed_deploy.session(), deploy.session());
+        assert_ne!(refreshed_deploy.id(), deploy.id());
+
+        assert_eq!(refreshed_deploy.approvals().len(), 1);
+        assert_eq!(refreshed_deploy.approvals()[0].signer(), &signer);
+        refreshed_deploy
+            .is_valid()
+            .unwrap_or_else(|error| panic!("{} - {:#?}", error, refreshed_deploy));
+    }
+
+    #[test]
+    fn should_refresh_deploy_with_new_ttl() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        let ttl = TimeDiff::from_seconds(60);
+        let params = DeployRefreshParams {
+            secret_keys: vec![SecretKey::generate_ed25519().unwrap()],
+            timestamp: Timestamp::now(),
+            maybe_ttl: Some(ttl),
+        };
+        let refreshed_deploy = deploy.refresh(params).unwrap();
+        assert_eq!(refreshed_deploy.header().ttl(), ttl);
+    }
+
+    #[test]
+    fn should_fail_to_refresh_deploy_without_secret_keys() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        let params = DeployRefreshParams {
+            secret_keys: vec![],
+            timestamp: Timestamp::now(),
+            maybe_ttl: None,
+        };
+        assert!(matches!(
+            deploy.refresh(params),
+            Err(Error::InvalidArgument("secret_keys", _))
+        ));
+    }
+
+    fn deploy_limits() -> DeployLimits {
+        DeployLimits {
+            chain_name: "casper-test-chain-name-1".to_string(),
+            max_deploy_size: MAX_SERIALIZED_SIZE,
+            max_ttl: TimeDiff::from(86_400_000),
+            max_dependencies: 10,
+            payment_args_max_length: 1024,
+            session_args_max_length: 1024,
+        }
+    }
+
+    #[test]
+    fn should_validate_deploy_against_chainspec() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        assert!(deploy
+            .validate_against_chainspec(&deploy_limits())
+            .is_empty());
+    }
+
+    #[test]
+    fn should_verify_deploy() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        let report = deploy.verify(&deploy_limits());
+        assert!(report.is_valid(), "{:#?}", report);
+        assert_eq!(report.approvals.len(), 2);
+    }
+
+    #[test]
+    fn should_report_invalid_deploy_hash_and_approvals() {
+        let tampered_deploy = SAMPLE_DEPLOY.replace(
+            "4858bbd79ab7b825244c4e6959cbcd588a05608168ef36518bc6590937191d55",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        );
+        let deploy = Deploy::read_deploy(tampered_deploy.as_bytes()).unwrap();
+        let report = deploy.verify(&deploy_limits());
+
+        assert!(!report.is_valid());
+        assert_ne!(report.deploy_hash, report.computed_deploy_hash);
+        assert_eq!(report.body_hash, report.computed_body_hash);
+        assert!(report
+            .approvals
+            .iter()
+            .all(|approval| approval.error.is_some()));
+        assert!(report.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn should_report_all_exceeded_chainspec_limits() {
+        let deploy = Deploy::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+        let limits = DeployLimits {
+            chain_name: "other-chain".to_string(),
+            max_deploy_size: 100,
+            max_ttl: TimeDiff::from(1_000),
+            max_dependencies: 1,
+            ..deploy_limits()
+        };
+
+        let diagnostics = deploy.validate_against_chainspec(&limits);
+        assert_eq!(diagnostics.len(), 4, "{:?}", diagnostics);
+        assert_eq!(
+            diagnostics[0],
+            DeployDiagnostic::InvalidChainName {
+                expected: "other-chain".to_string(),
+                got: "casper-test-chain-name-1".to_string(),
+            }
+        );
+        assert!(matches!(
+            diagnostics[1],
+            DeployDiagnostic::ExcessiveSize {
+                max_deploy_size: 100,
+                ..
+            }
+        ));
+        assert_eq!(
+            diagnostics[2],
+            DeployDiagnostic::ExcessiveTimeToLive {
+                max_ttl: TimeDiff::from(1_000),
+                got: TimeDiff::from(10_000),
+            }
+        );
+        assert_eq!(
+            diagnostics[3],
+            DeployDiagnostic::ExcessiveDependencies {
+                max_dependencies: 1,
+                got: 2,
+            }
+        );
+    }
 }
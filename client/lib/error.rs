@@ -2,11 +2,13 @@ use std::{num::ParseIntError, path::PathBuf};
 
 use humantime::{DurationError, TimestampError};
 use jsonrpc_lite::JsonRpc;
+use serde::Serialize;
 use thiserror::Error;
 
 use casper_node::{crypto::Error as CryptoError, types::ExcessiveSizeDeployError};
 use casper_types::{
-    bytesrepr::Error as ToBytesError, CLValueError, UIntParseError, URefFromStrError,
+    bytesrepr::Error as ToBytesError, CLValueError, TransferFromStrError, UIntParseError,
+    URefFromStrError,
 };
 
 use crate::validation::ValidateResponseError;
@@ -27,6 +29,10 @@ pub enum Error {
     #[error("Failed to parse '{0}' as a uref: {1:?}")]
     FailedToParseURef(&'static str, URefFromStrError),
 
+    /// Failed to parse a `DeployHash` from a string.
+    #[error("Failed to parse '{0}' as a deploy hash: {1}")]
+    FailedToParseDeployHash(&'static str, TransferFromStrError),
+
     /// Failed to parse an integer from a string.
     #[error("Failed to parse '{0}' as an integer: {1:?}")]
     FailedToParseInt(&'static str, ParseIntError),
@@ -123,6 +129,17 @@ pub enum Error {
     #[error("Invalid response: {0}")]
     InvalidResponse(#[from] ValidateResponseError),
 
+    /// The deploy's chain name differs from that of the node it is being sent to.
+    #[error(
+        "Deploy is for chain '{deploy_chain_name}' but the node is on chain '{node_chain_name}'"
+    )]
+    ChainNameMismatch {
+        /// The chain name in the deploy.
+        deploy_chain_name: String,
+        /// The chain name reported by the node.
+        node_chain_name: String,
+    },
+
     /// Must call FFI's setup function prior to making ffi calls.
     #[cfg(feature = "ffi")]
     #[error("Failed to call casper_setup_client()")]
@@ -148,3 +165,166 @@ impl From<CLValueError> for Error {
         }
     }
 }
+
+/// The category of an [`Error`], for callers which need to branch on the kind of failure rather
+/// than on its message.
+///
+/// Each code maps to a distinct process exit code.  The exit codes start at 10 so as not to clash
+/// with the generic failure code `1` or the outcome codes used by the `watch-*` subcommands.
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// An argument or input file passed to the client is invalid.
+    InvalidInput,
+    /// The request could not be sent to the node, or its response could not be read.
+    RpcFailure,
+    /// The node responded with a JSON-RPC error.
+    RpcError,
+    /// The node's response failed validation.
+    ValidationFailure,
+    /// A cryptographic operation such as signing or key parsing failed.
+    SigningFailure,
+    /// Reading or writing a file failed.
+    Io,
+    /// Serializing or deserializing a value failed.
+    Serialization,
+}
+
+impl ErrorCode {
+    /// Returns the process exit code corresponding to this error code.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorCode::InvalidInput => 10,
+            ErrorCode::RpcFailure => 11,
+            ErrorCode::RpcError => 12,
+            ErrorCode::ValidationFailure => 13,
+            ErrorCode::SigningFailure => 14,
+            ErrorCode::Io => 15,
+            ErrorCode::Serialization => 16,
+        }
+    }
+}
+
+impl Error {
+    /// Returns the category of this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::FailedToParseKey
+            | Error::FailedToParseURef(..)
+            | Error::FailedToParseDeployHash(..)
+            | Error::FailedToParseInt(..)
+            | Error::FailedToParseTimeDiff(..)
+            | Error::FailedToParseTimestamp(..)
+            | Error::FailedToParseUint(..)
+            | Error::DeploySizeTooLarge(_)
+            | Error::UnsupportedAlgorithm(_)
+            | Error::InvalidCLValue(_)
+            | Error::InvalidArgument(..)
+            | Error::ConflictingArguments { .. }
+            | Error::ChainNameMismatch { .. } => ErrorCode::InvalidInput,
+            #[cfg(feature = "ffi")]
+            Error::FFISetupNotCalled | Error::FFIPtrNullButRequired(_) => ErrorCode::InvalidInput,
+            Error::FailedToGetResponse(_)
+            | Error::FailedToParseResponse(_)
+            | Error::InvalidRpcResponse(_)
+            | Error::FailedSending(_) => ErrorCode::RpcFailure,
+            Error::ResponseIsError(_) => ErrorCode::RpcError,
+            Error::InvalidResponse(_) => ErrorCode::ValidationFailure,
+            Error::CryptoError { .. } => ErrorCode::SigningFailure,
+            Error::FileAlreadyExists(_) | Error::IoError { .. } => ErrorCode::Io,
+            Error::InvalidJson(_) | Error::ToBytesError(_) => ErrorCode::Serialization,
+        }
+    }
+
+    /// Returns additional context for this error, such as the name of the offending argument or
+    /// the operation which failed, if any.
+    pub fn context(&self) -> Option<String> {
+        match self {
+            Error::FailedToParseURef(context, _)
+            | Error::FailedToParseDeployHash(context, _)
+            | Error::FailedToParseInt(context, _)
+            | Error::FailedToParseTimeDiff(context, _)
+            | Error::FailedToParseTimestamp(context, _)
+            | Error::FailedToParseUint(context, _)
+            | Error::InvalidArgument(context, _)
+            | Error::CryptoError { context, .. }
+            | Error::ConflictingArguments { context, .. } => Some(context.to_string()),
+            #[cfg(feature = "ffi")]
+            Error::FFIPtrNullButRequired(context) => Some(context.to_string()),
+            Error::IoError { context, .. } => Some(context.clone()),
+            Error::FileAlreadyExists(path) => Some(path.display().to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// A machine-readable description of an [`Error`].
+#[derive(Serialize, Debug)]
+pub struct ErrorReport {
+    /// The category of the error.
+    pub code: ErrorCode,
+    /// The human-readable error message.
+    pub message: String,
+    /// Additional context, such as the name of the offending argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+impl From<&Error> for ErrorReport {
+    fn from(error: &Error) -> Self {
+        ErrorReport {
+            code: error.code(),
+            message: error.to_string(),
+            context: error.context(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_error_report() {
+        let error = Error::InvalidArgument("payment_amount", "must be positive".to_string());
+        let report = ErrorReport::from(&error);
+        assert_eq!(report.code, ErrorCode::InvalidInput);
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "code": "invalid_input",
+                "message": "Invalid argument 'payment_amount': must be positive",
+                "context": "payment_amount",
+            })
+        );
+    }
+
+    #[test]
+    fn should_omit_missing_context() {
+        let error = Error::FailedToParseKey;
+        let json = serde_json::to_value(&ErrorReport::from(&error)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "invalid_input",
+                "message": "Failed to parse as a key",
+            })
+        );
+    }
+
+    #[test]
+    fn exit_codes_should_be_distinct() {
+        let codes = [
+            ErrorCode::InvalidInput,
+            ErrorCode::RpcFailure,
+            ErrorCode::RpcError,
+            ErrorCode::ValidationFailure,
+            ErrorCode::SigningFailure,
+            ErrorCode::Io,
+            ErrorCode::Serialization,
+        ];
+        let exit_codes: std::collections::BTreeSet<i32> =
+            codes.iter().map(|code| code.exit_code()).collect();
+        assert_eq!(exit_codes.len(), codes.len());
+    }
+}
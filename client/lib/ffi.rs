@@ -6,6 +6,7 @@ use std::{
     os::raw::{c_char, c_uchar},
     slice,
     sync::Mutex,
+    time::Duration,
 };
 
 use once_cell::sync::Lazy;
@@ -53,6 +54,8 @@ pub enum casper_error_t {
     CASPER_FFI_PTR_NULL_BUT_REQUIRED = -22,
     CASPER_CONFLICTING_ARGUMENTS = -23,
     CASPER_DEPLOY_SIZE_TOO_LARGE = -24,
+    CASPER_CHAIN_NAME_MISMATCH = -25,
+    CASPER_FAILED_TO_PARSE_DEPLOY_HASH = -26,
 }
 
 trait AsFFIError {
@@ -64,6 +67,9 @@ impl AsFFIError for Error {
         match self {
             Error::FailedToParseKey => casper_error_t::CASPER_FAILED_TO_PARSE_KEY,
             Error::FailedToParseURef(_, _) => casper_error_t::CASPER_FAILED_TO_PARSE_UREF,
+            Error::FailedToParseDeployHash(_, _) => {
+                casper_error_t::CASPER_FAILED_TO_PARSE_DEPLOY_HASH
+            }
             Error::FailedToParseInt(_, _) => casper_error_t::CASPER_FAILED_TO_PARSE_INT,
             Error::FailedToParseTimeDiff(_, _) => casper_error_t::CASPER_FAILED_TO_PARSE_TIME_DIFF,
             Error::FailedToParseTimestamp(_, _) => casper_error_t::CASPER_FAILED_TO_PARSE_TIMESTAMP,
@@ -86,6 +92,7 @@ impl AsFFIError for Error {
             Error::FFIPtrNullButRequired(_) => casper_error_t::CASPER_FFI_PTR_NULL_BUT_REQUIRED,
             Error::ConflictingArguments { .. } => casper_error_t::CASPER_CONFLICTING_ARGUMENTS,
             Error::DeploySizeTooLarge(_) => casper_error_t::CASPER_DEPLOY_SIZE_TOO_LARGE,
+            Error::ChainNameMismatch { .. } => casper_error_t::CASPER_CHAIN_NAME_MISMATCH,
         }
     }
 }
@@ -236,6 +243,22 @@ pub extern "C" fn casper_shutdown_client() {
     *runtime = None; // triggers drop on our runtime
 }
 
+/// Sets the timeout, retry and backoff settings used by all subsequent requests.
+///
+/// See [super::TransportConfig](super::TransportConfig) for more details.
+#[no_mangle]
+pub extern "C" fn casper_set_transport_config(
+    timeout_millis: u64,
+    max_retries: u32,
+    retry_backoff_millis: u64,
+) {
+    super::set_transport_config(super::TransportConfig::new(
+        Duration::from_millis(timeout_millis),
+        max_retries,
+        Duration::from_millis(retry_backoff_millis),
+    ));
+}
+
 /// Gets the last error copied to the provided buffer.
 ///
 /// * `buf` is the buffer where the result will be stored.
@@ -20,24 +20,32 @@ pub mod ffi;
 pub mod keygen;
 mod parsing;
 mod rpc;
+mod staking;
+mod transport;
 mod validation;
 
-use std::{convert::TryInto, fs::File};
+use std::{convert::TryInto, fs::File, io::BufReader};
 
 use jsonrpc_lite::JsonRpc;
 use serde::Serialize;
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_node::types::Deploy;
+use casper_node::types::{Deploy, DeployLimits};
 use casper_types::{UIntParseError, U512};
 
 pub use cl_type::help;
-pub use deploy::ListDeploysResult;
+pub use deploy::{
+    ApprovalVerification, DeployDiagnostic, DeployVerificationReport, ListDeploysResult,
+};
 use deploy::{DeployExt, DeployParams};
-pub use error::Error;
 use error::Result;
+pub use error::{Error, ErrorCode, ErrorReport};
 use parsing::none_if_empty;
 use rpc::{RpcCall, TransferTarget};
+pub use staking::{
+    AuctionInfoSummary, EraInfoSummary, Pagination, Stake, StakingFilter, ValidatorWeight,
+};
+pub use transport::{set_transport_config, transport_config, TransportConfig};
 pub use validation::ValidateResponseError;
 
 /// Creates a `Deploy` and sends it to the network for execution.
@@ -74,6 +82,81 @@ pub fn put_deploy(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).put_deploy(deploy)
 }
 
+/// Creates a `Deploy` and sends it to the network for execution, after checking its chain name
+/// against that of the node.
+///
+/// The node's chain name is fetched via its status.  The parameters are as for
+/// [`put_deploy`](fn.put_deploy.html), with the addition of:
+///
+/// * `chain_name_check` determines how a mismatch between the `Deploy`'s chain name and that of the
+///   node is handled. See [`ChainNameCheck`](enum.ChainNameCheck.html) for more details.
+pub fn put_deploy_with_chain_name_check(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy: DeployStrParams<'_>,
+    session: SessionStrParams<'_>,
+    payment: PaymentStrParams<'_>,
+    chain_name_check: ChainNameCheck,
+) -> Result<JsonRpc> {
+    let mut deploy_params: DeployParams = deploy.try_into()?;
+    let payment: ExecutableDeployItem = payment.try_into()?;
+    let session: ExecutableDeployItem = session.try_into()?;
+    let get_node_chain_name =
+        || RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_chain_name();
+    match chain_name_check {
+        ChainNameCheck::Skip => {}
+        ChainNameCheck::Warn => match get_node_chain_name() {
+            Ok(node_chain_name) if node_chain_name != deploy_params.chain_name => eprintln!(
+                "warning: deploy is for chain '{}' but the node is on chain '{}'",
+                deploy_params.chain_name, node_chain_name
+            ),
+            Ok(_) => {}
+            Err(error) => eprintln!(
+                "warning: failed to get the node's chain name, so the deploy's chain name is \
+                unchecked: {}",
+                error
+            ),
+        },
+        ChainNameCheck::Fail => {
+            let node_chain_name = get_node_chain_name()?;
+            if node_chain_name != deploy_params.chain_name {
+                return Err(Error::ChainNameMismatch {
+                    deploy_chain_name: deploy_params.chain_name,
+                    node_chain_name,
+                });
+            }
+        }
+        ChainNameCheck::Fix => deploy_params.chain_name = get_node_chain_name()?,
+    }
+    let deploy = Deploy::with_payment_and_session(deploy_params, payment, session)?;
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).put_deploy(deploy)
+}
+
+/// How [`put_deploy_with_chain_name_check`](fn.put_deploy_with_chain_name_check.html) handles a
+/// `Deploy` whose chain name differs from that of the node it is sent to.
+///
+/// Such a `Deploy` would otherwise be accepted by the node but never executed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChainNameCheck {
+    /// Don't query the node for its chain name; send the `Deploy` as is.
+    Skip,
+    /// Print a warning to `stderr`, then send the `Deploy` as is.  If the node's chain name can't
+    /// be fetched, a warning is printed and the `Deploy` is still sent.
+    Warn,
+    /// Return [`Error::ChainNameMismatch`](enum.Error.html#variant.ChainNameMismatch) without
+    /// sending the `Deploy`.
+    Fail,
+    /// Build and sign the `Deploy` using the node's chain name instead, then send it.
+    Fix,
+}
+
+impl Default for ChainNameCheck {
+    fn default() -> Self {
+        ChainNameCheck::Skip
+    }
+}
+
 /// Creates a `Deploy` and outputs it to a file or stdout.
 ///
 /// As a file, the `Deploy` can subsequently be signed by other parties using
@@ -140,6 +223,46 @@ pub fn sign_deploy_file(input_path: &str, secret_key: &str, maybe_output_path: &
     Deploy::sign_and_write_deploy(input, secret_key, output)
 }
 
+/// Reads a previously-saved `Deploy` from a file, renews its timestamp and time to live, re-signs
+/// it, and outputs it to a file or stdout.
+///
+/// This allows a `Deploy` which has expired to be re-submitted without rebuilding it.  Its payment
+/// and session are preserved, while its previous approvals are dropped.
+///
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+/// * `secret_keys` specifies the paths to the secret keys with which to sign the refreshed
+///   `Deploy`.  At least one must be provided.
+/// * `timestamp` is the new RFC3339-like formatted timestamp.  If empty, the current time will be
+///   used.
+/// * `ttl` is the new time to live.  If empty, the existing time to live is kept.
+/// * `maybe_output_path` specifies the output file, or if empty, will print it to `stdout`. If the
+///   file already exists, it will be overwritten.
+pub fn refresh_deploy_file(
+    input_path: &str,
+    secret_keys: &[&str],
+    timestamp: &str,
+    ttl: &str,
+    maybe_output_path: &str,
+) -> Result<()> {
+    let params = parsing::parse_deploy_refresh_params(secret_keys, timestamp, ttl)?;
+    let maybe_output_path = parsing::output(maybe_output_path);
+
+    let input = File::open(&input_path).map_err(|error| Error::IoError {
+        context: format!("unable to read deploy file at '{}'", input_path),
+        error,
+    })?;
+    let deploy = Deploy::read_deploy(input)?.refresh(params)?;
+
+    let output = deploy::output_or_stdout(maybe_output_path).map_err(|error| Error::IoError {
+        context: format!(
+            "unable to get file or stdout, provided '{:?}'",
+            maybe_output_path
+        ),
+        error,
+    })?;
+    deploy.write_deploy(output)
+}
+
 /// Reads a previously-saved `Deploy` from a file and sends it to the network for execution.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -232,7 +355,7 @@ pub fn transfer(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-/// * `deploy_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `deploy_hash` must be a hex-encoded, 32-byte hash digest, optionally prefixed with `deploy-`.
 pub fn get_deploy(
     maybe_rpc_id: &str,
     node_address: &str,
@@ -359,7 +482,8 @@ pub fn get_item(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest or empty. If empty, the balance
+///   at the state root of the latest block will be returned.
 /// * `purse` is a URef, formatted as e.g.
 /// ```text
 /// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
@@ -419,6 +543,91 @@ pub fn get_auction_info(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_auction_info()
 }
 
+/// Retrieves the validators joining or leaving the validator set, and changes to validators'
+/// weights, over the eras for which the auction holds validator snapshots as of the most recently
+/// added `Block`.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+pub fn get_validator_changes(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_validator_changes()
+}
+
+/// Retrieves the chainspec limits a `Deploy` must comply with in order to be accepted by the
+/// network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+pub fn get_deploy_limits(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy_limits()
+}
+
+/// Checks a previously-saved `Deploy` file against the given chainspec limits before submitting it
+/// to the network.
+///
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+/// * `deploy_limits` are the limits to check against, usually as retrieved via
+///   [`get_deploy_limits`](fn.get_deploy_limits.html).
+///
+/// Returns every way in which the `Deploy` fails to comply with the limits; an empty result means
+/// the `Deploy` complies with all of them.
+pub fn validate_deploy_file(
+    input_path: &str,
+    deploy_limits: &DeployLimits,
+) -> Result<Vec<DeployDiagnostic>> {
+    let input = File::open(input_path).map_err(|error| Error::IoError {
+        context: format!("unable to read input file '{}'", input_path),
+        error,
+    })?;
+    let deploy = Deploy::read_deploy(input)?;
+    Ok(deploy.validate_against_chainspec(deploy_limits))
+}
+
+/// Verifies a previously-saved `Deploy` file offline, without submitting it to the network.
+///
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+/// * `deploy_limits` are the limits to check against, including the expected chain name.
+///
+/// Checks the deploy hash, the body hash and every approval signature, and the `Deploy`'s
+/// compliance with the limits, returning the outcome of each check.
+pub fn verify_deploy_file(
+    input_path: &str,
+    deploy_limits: &DeployLimits,
+) -> Result<DeployVerificationReport> {
+    let input = File::open(input_path).map_err(|error| Error::IoError {
+        context: format!("unable to read input file '{}'", input_path),
+        error,
+    })?;
+    // The serialized size is reported as one of the limit checks, so the `Deploy` isn't rejected
+    // for its size while being read.
+    let deploy: Deploy = serde_json::from_reader(BufReader::new(input))?;
+    Ok(deploy.verify(deploy_limits))
+}
+
 /// Retrieves information and examples for all currently supported RPCs.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -1147,6 +1356,8 @@ mod param_tests {
     mod deploy_str_params {
         use humantime::{DurationError, TimestampError};
 
+        use casper_types::TransferFromStrError;
+
         use super::*;
 
         use std::{convert::TryInto, result::Result as StdResult};
@@ -1234,19 +1445,16 @@ mod param_tests {
 
         #[test]
         fn should_fail_to_convert_with_bad_dependencies() {
-            use casper_node::crypto::Error as CryptoError;
             let mut params = test_value();
             params.dependencies = vec!["invalid dep"];
             let result: StdResult<DeployParams, Error> = params.try_into();
-            let result = result.map(|_| ()).map_err(ErrWrapper);
-            assert_eq!(
+            assert!(matches!(
                 result,
-                Err(Error::CryptoError {
-                    context: "dependencies",
-                    error: CryptoError::FromHex(hex::FromHexError::OddLength)
-                }
-                .into())
-            );
+                Err(Error::FailedToParseDeployHash(
+                    "dependencies",
+                    TransferFromStrError::Hex(_)
+                ))
+            ));
         }
     }
 }
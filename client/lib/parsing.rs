@@ -17,7 +17,7 @@ use casper_types::{
 
 use crate::{
     cl_type,
-    deploy::DeployParams,
+    deploy::{DeployParams, DeployRefreshParams},
     error::{Error, Result},
     help, TransferTarget,
 };
@@ -49,11 +49,9 @@ fn gas_price(value: &str) -> Result<u64> {
 fn dependencies(values: &[&str]) -> Result<Vec<DeployHash>> {
     let mut hashes = Vec::with_capacity(values.len());
     for value in values {
-        let digest = Digest::from_hex(value).map_err(|error| Error::CryptoError {
-            context: "dependencies",
-            error,
-        })?;
-        hashes.push(DeployHash::new(digest))
+        let deploy_hash = casper_types::DeployHash::from_str(value)
+            .map_err(|error| Error::FailedToParseDeployHash("dependencies", error))?;
+        hashes.push(deploy_hash.into())
     }
     Ok(hashes)
 }
@@ -357,6 +355,25 @@ pub(super) fn parse_deploy_params(
     })
 }
 
+pub(super) fn parse_deploy_refresh_params(
+    secret_keys: &[&str],
+    timestamp: &str,
+    ttl: &str,
+) -> Result<DeployRefreshParams> {
+    let secret_keys = secret_keys
+        .iter()
+        .map(|secret_key| self::secret_key(secret_key))
+        .collect::<Result<Vec<_>>>()?;
+    let timestamp = self::timestamp(timestamp)?;
+    let maybe_ttl = none_if_empty(ttl).map(self::ttl).transpose()?;
+
+    Ok(DeployRefreshParams {
+        secret_keys,
+        timestamp,
+        maybe_ttl,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn parse_session_info(
     session_hash: &str,
@@ -1,11 +1,12 @@
-use std::fs::File;
+use std::{fs::File, str::FromStr};
 
 use futures::executor;
 use jsonrpc_lite::{Id, JsonRpc, Params};
 use rand::Rng;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::Serialize;
 use serde_json::{json, Map, Value};
+use tokio::time;
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::{
@@ -17,18 +18,18 @@ use casper_node::{
             GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
         },
         docs::ListRpcs,
-        info::{GetDeploy, GetDeployParams},
+        info::{GetDeploy, GetDeployLimits, GetDeployParams, GetStatus, GetValidatorChanges},
         state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
     },
-    types::{BlockHash, Deploy, DeployHash},
+    types::{BlockHash, Deploy},
 };
 use casper_types::{AsymmetricType, Key, PublicKey, RuntimeArgs, URef, U512};
 
 use crate::{
     deploy::{DeployExt, DeployParams, SendDeploy, Transfer},
     error::{Error, Result},
-    validation,
+    transport, validation,
 };
 
 /// Target for a given transfer.
@@ -41,10 +42,18 @@ pub(crate) enum TransferTarget {
 #[derive(Debug)]
 pub(crate) struct RpcCall {
     rpc_id: Id,
-    node_address: String,
+    node_addresses: Vec<String>,
     verbosity_level: u64,
 }
 
+/// The outcome of a failed attempt to send a request to a single node.
+enum FailedAttempt {
+    /// The failure may not recur, so the request can be retried.
+    Transient(Error),
+    /// The failure would recur, so the request should not be retried.
+    Permanent(Error),
+}
+
 /// `RpcCall` encapsulates calls made to the casper node service via JSON-RPC.
 impl RpcCall {
     /// Creates a new RPC instance.
@@ -53,7 +62,8 @@ impl RpcCall {
     /// the node in the corresponding response.
     ///
     /// `node_address` identifies the network address of the target node's HTTP server, e.g.
-    /// `"http://127.0.0.1:7777"`.
+    /// `"http://127.0.0.1:7777"`.  Several comma-separated addresses may be given, in which case
+    /// each is tried in order until one of them responds.
     ///
     /// When `verbosity_level` is `1`, the request will be printed to `stdout` with long string
     /// fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char count
@@ -71,18 +81,21 @@ impl RpcCall {
 
         Self {
             rpc_id,
-            node_address: node_address.trim_end_matches('/').to_string(),
+            node_addresses: node_address
+                .split(',')
+                .map(str::trim)
+                .filter(|address| !address.is_empty())
+                .map(|address| address.trim_end_matches('/').to_string())
+                .collect(),
             verbosity_level,
         }
     }
 
     pub(crate) fn get_deploy(self, deploy_hash: &str) -> Result<JsonRpc> {
-        let hash = Digest::from_hex(deploy_hash).map_err(|error| Error::CryptoError {
-            context: "deploy_hash",
-            error,
-        })?;
+        let deploy_hash = casper_types::DeployHash::from_str(deploy_hash)
+            .map_err(|error| Error::FailedToParseDeployHash("deploy_hash", error))?;
         let params = GetDeployParams {
-            deploy_hash: DeployHash::new(hash),
+            deploy_hash: deploy_hash.into(),
         };
         GetDeploy::request_with_map_params(self, params)
     }
@@ -131,11 +144,16 @@ impl RpcCall {
     }
 
     pub(crate) fn get_balance(self, state_root_hash: &str, purse_uref: &str) -> Result<JsonRpc> {
-        let state_root_hash =
-            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
-                context: "state_root_hash",
-                error,
-            })?;
+        let state_root_hash = if state_root_hash.is_empty() {
+            None
+        } else {
+            Some(
+                Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                    context: "state_root_hash",
+                    error,
+                })?,
+            )
+        };
         let uref = URef::from_formatted_str(purse_uref)
             .map_err(|error| Error::FailedToParseURef("purse_uref", error))?;
         let key = Key::from(uref);
@@ -145,7 +163,7 @@ impl RpcCall {
             purse_uref: purse_uref.to_string(),
         };
         let response = GetBalance::request_with_map_params(self, params)?;
-        validation::validate_get_balance_response(&response, &state_root_hash, &key)?;
+        validation::validate_get_balance_response(&response, state_root_hash.as_ref(), &key)?;
         Ok(response)
     }
 
@@ -168,6 +186,25 @@ impl RpcCall {
         GetAuctionInfo::request(self)
     }
 
+    pub(crate) fn get_validator_changes(self) -> Result<JsonRpc> {
+        GetValidatorChanges::request(self)
+    }
+
+    pub(crate) fn get_deploy_limits(self) -> Result<JsonRpc> {
+        GetDeployLimits::request(self)
+    }
+
+    /// Returns the name of the chain the node is running, as reported by its status.
+    pub(crate) fn get_chain_name(self) -> Result<String> {
+        let response = GetStatus::request(self)?;
+        let maybe_chain_name = response
+            .get_result()
+            .and_then(|result| result.get("chainspec_name"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        maybe_chain_name.ok_or(Error::InvalidRpcResponse(response))
+    }
+
     pub(crate) fn list_rpcs(self) -> Result<JsonRpc> {
         ListRpcs::request(self)
     }
@@ -267,55 +304,103 @@ impl RpcCall {
     }
 
     async fn request(self, method: &str, params: Params) -> Result<JsonRpc> {
-        let url = format!("{}/{}", self.node_address, RPC_API_PATH);
         let rpc_req = JsonRpc::request_with_params(self.rpc_id, method, params);
 
         crate::pretty_print_at_level(&rpc_req, self.verbosity_level);
 
-        let client = Client::new();
-        let response = client
-            .post(&url)
-            .json(&rpc_req)
-            .send()
-            .await
+        let transport_config = transport::transport_config();
+        let client = Client::builder()
+            .timeout(transport_config.timeout())
+            .build()
             .map_err(Error::FailedToGetResponse)?;
 
+        let mut last_error = Error::InvalidArgument(
+            "node_address",
+            "at least one node address must be provided".to_string(),
+        );
+        for node_address in &self.node_addresses {
+            let url = format!("{}/{}", node_address, RPC_API_PATH);
+            for retry in 0..=transport_config.max_retries() {
+                if retry > 0 {
+                    time::delay_for(transport_config.backoff(retry)).await;
+                }
+                match Self::send(&client, &url, &rpc_req, self.verbosity_level).await {
+                    Ok(rpc_response) => return Ok(rpc_response),
+                    Err(FailedAttempt::Transient(error)) => {
+                        if self.verbosity_level > 0 {
+                            println!("Request to {} failed: {}", node_address, error);
+                        }
+                        last_error = error;
+                    }
+                    Err(FailedAttempt::Permanent(error)) => return Err(error),
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    async fn send(
+        client: &Client,
+        url: &str,
+        rpc_req: &JsonRpc,
+        verbosity_level: u64,
+    ) -> std::result::Result<JsonRpc, FailedAttempt> {
+        let response = match client.post(url).json(rpc_req).send().await {
+            Ok(response) => response,
+            Err(error) if error.is_timeout() || error.is_connect() => {
+                return Err(FailedAttempt::Transient(Error::FailedToGetResponse(error)))
+            }
+            Err(error) => return Err(FailedAttempt::Permanent(Error::FailedToGetResponse(error))),
+        };
+
         if let Err(error) = response.error_for_status_ref() {
-            if self.verbosity_level > 0 {
+            if verbosity_level > 0 {
                 println!("Failed Sending {}", error);
             }
-            return Err(Error::FailedSending(rpc_req));
+            let error = Error::FailedSending(rpc_req.clone());
+            let status = response.status();
+            if status.is_server_error()
+                || status == StatusCode::TOO_MANY_REQUESTS
+                || status == StatusCode::REQUEST_TIMEOUT
+            {
+                return Err(FailedAttempt::Transient(error));
+            }
+            return Err(FailedAttempt::Permanent(error));
         }
 
         let rpc_response = response.json().await.map_err(Error::FailedToParseResponse);
 
         if let Err(error) = rpc_response {
-            if self.verbosity_level > 0 {
+            if verbosity_level > 0 {
                 println!("Failed parsing as a JSON-RPC response: {}", error);
             }
-            return Err(error);
+            return Err(FailedAttempt::Permanent(error));
         }
 
-        let rpc_response: JsonRpc = rpc_response?;
+        let rpc_response: JsonRpc = rpc_response.map_err(FailedAttempt::Permanent)?;
 
         if rpc_response.get_result().is_some() {
-            if self.verbosity_level > 0 {
+            if verbosity_level > 0 {
                 println!("Received successful response:");
             }
             return Ok(rpc_response);
         }
 
         if let Some(error) = rpc_response.get_error() {
-            if self.verbosity_level > 0 {
+            if verbosity_level > 0 {
                 println!("Response returned an error");
             }
-            return Err(Error::ResponseIsError(error.clone()));
+            return Err(FailedAttempt::Permanent(Error::ResponseIsError(
+                error.clone(),
+            )));
         }
 
-        if self.verbosity_level > 0 {
+        if verbosity_level > 0 {
             println!("Invalid response returned");
         }
-        Err(Error::InvalidRpcResponse(rpc_response))
+        Err(FailedAttempt::Permanent(Error::InvalidRpcResponse(
+            rpc_response,
+        )))
     }
 }
 
@@ -366,6 +451,18 @@ impl RpcClient for GetAuctionInfo {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetValidatorChanges {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
+impl RpcClient for GetDeployLimits {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
+impl RpcClient for GetStatus {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for ListRpcs {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
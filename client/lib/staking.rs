@@ -0,0 +1,604 @@
+//! Filtering, pagination and rendering of the staking data returned by the
+//! "state_get_auction_info" and "chain_get_era_info_by_switch_block" RPCs.
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use casper_node::{
+    crypto::hash::Digest,
+    rpcs::{chain::GetEraInfoResult, state::GetAuctionInfoResult},
+    types::{json_compatibility::StoredValue, BlockHash},
+};
+use casper_types::{
+    system::auction::{DelegationRate, EraId, SeigniorageAllocation},
+    AsymmetricType, PublicKey, U512,
+};
+
+use crate::error::{Error, Result};
+
+/// Placeholder shown in table cells which have no value.
+const EMPTY_CELL: &str = "-";
+
+/// Restricts staking data to that of a given validator and/or delegator.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct StakingFilter {
+    validator: Option<PublicKey>,
+    delegator: Option<PublicKey>,
+}
+
+impl StakingFilter {
+    /// Constructs a new `StakingFilter`.
+    ///
+    /// `maybe_validator` and `maybe_delegator` must each be a hex-encoded public key or empty.  An
+    /// empty value doesn't restrict the data.
+    pub fn new(maybe_validator: &str, maybe_delegator: &str) -> Result<Self> {
+        Ok(StakingFilter {
+            validator: parse_public_key("validator", maybe_validator)?,
+            delegator: parse_public_key("delegator", maybe_delegator)?,
+        })
+    }
+
+    /// Returns `true` if the filter doesn't restrict the data.
+    pub fn is_empty(&self) -> bool {
+        self.validator.is_none() && self.delegator.is_none()
+    }
+
+    fn matches_validator(&self, validator: &PublicKey) -> bool {
+        self.validator
+            .as_ref()
+            .map_or(true, |expected| expected == validator)
+    }
+
+    fn matches_delegator(&self, delegator: Option<&PublicKey>) -> bool {
+        match &self.delegator {
+            Some(expected) => delegator == Some(expected),
+            None => true,
+        }
+    }
+}
+
+fn parse_public_key(arg_name: &'static str, value: &str) -> Result<Option<PublicKey>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    PublicKey::from_hex(value)
+        .map(Some)
+        .map_err(|error| Error::InvalidArgument(arg_name, format!("{}: {:?}", value, error)))
+}
+
+/// Selects a single page of the entries of a list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    page: usize,
+    page_size: usize,
+}
+
+impl Pagination {
+    /// Constructs a new `Pagination`, or `None` if `page_size` is empty.
+    ///
+    /// `page` is the 1-based number of the page to select, and defaults to `1` if empty.
+    pub fn new(page: &str, page_size: &str) -> Result<Option<Self>> {
+        if page_size.is_empty() {
+            return Ok(None);
+        }
+        let page = if page.is_empty() {
+            1
+        } else {
+            page.parse::<usize>()
+                .map_err(|error| Error::FailedToParseInt("page", error))?
+        };
+        let page_size = page_size
+            .parse::<usize>()
+            .map_err(|error| Error::FailedToParseInt("page_size", error))?;
+        if page == 0 {
+            return Err(Error::InvalidArgument(
+                "page",
+                "pages are numbered from 1".to_string(),
+            ));
+        }
+        if page_size == 0 {
+            return Err(Error::InvalidArgument(
+                "page_size",
+                "must be greater than 0".to_string(),
+            ));
+        }
+        Ok(Some(Pagination { page, page_size }))
+    }
+
+    fn apply<T>(pagination: Option<Self>, entries: Vec<T>) -> Vec<T> {
+        match pagination {
+            Some(Pagination { page, page_size }) => entries
+                .into_iter()
+                .skip((page - 1).saturating_mul(page_size))
+                .take(page_size)
+                .collect(),
+            None => entries,
+        }
+    }
+}
+
+/// The weight of a validator in a given era.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorWeight {
+    /// The era ID.
+    pub era_id: EraId,
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The validator's weight.
+    pub weight: U512,
+}
+
+/// An amount staked with a validator, either by the validator itself or by one of its delegators.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Stake {
+    /// The validator's public key.
+    pub validator: PublicKey,
+    /// The hex-encoded public key of the delegator, or the formatted contract package hash of the
+    /// delegating contract, if the amount is not staked by the validator itself.
+    pub delegator: Option<String>,
+    /// The staked amount.
+    pub staked_amount: U512,
+    /// The validator's delegation rate.
+    pub delegation_rate: DelegationRate,
+    /// Whether the validator is inactive.
+    pub inactive: bool,
+}
+
+/// The auction state, with the bids flattened into one entry per stake and optionally filtered
+/// and paginated.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuctionInfoSummary {
+    /// The RPC API version.
+    pub api_version: Version,
+    /// Global state hash.
+    pub state_root_hash: Digest,
+    /// Block height.
+    pub block_height: u64,
+    /// The validator weights of all eras.
+    pub era_validators: Vec<ValidatorWeight>,
+    /// The stakes on the selected page.
+    pub stakes: Vec<Stake>,
+    /// The number of stakes matching the filter, across all pages.
+    pub total_stakes: usize,
+}
+
+impl AuctionInfoSummary {
+    /// Constructs a new `AuctionInfoSummary` from the response to a "state_get_auction_info" RPC.
+    ///
+    /// If the filter restricts the data to a delegator, only the weights of validators that
+    /// delegator delegates to are retained.  Pagination only applies to the stakes.
+    pub fn new(
+        result: GetAuctionInfoResult,
+        filter: &StakingFilter,
+        pagination: Option<Pagination>,
+    ) -> Self {
+        let auction_state = result.auction_state;
+
+        let mut stakes = Vec::new();
+        for json_bids in auction_state.bids() {
+            let validator = *json_bids.public_key();
+            if !filter.matches_validator(&validator) {
+                continue;
+            }
+            let bid = json_bids.bid();
+            let stake = |delegator, staked_amount| Stake {
+                validator,
+                delegator,
+                staked_amount,
+                delegation_rate: bid.delegation_rate(),
+                inactive: bid.inactive(),
+            };
+            if filter.matches_delegator(None) {
+                stakes.push(stake(None, *bid.staked_amount()));
+            }
+            for delegator in bid.delegators() {
+                if filter.matches_delegator(Some(delegator.public_key())) {
+                    stakes.push(stake(
+                        Some(delegator.public_key().to_hex()),
+                        *delegator.staked_amount(),
+                    ));
+                }
+            }
+            for contract_delegator in bid.contract_delegators() {
+                if filter.matches_delegator(None) {
+                    stakes.push(stake(
+                        Some(
+                            contract_delegator
+                                .contract_package_hash()
+                                .to_formatted_string(),
+                        ),
+                        *contract_delegator.staked_amount(),
+                    ));
+                }
+            }
+        }
+
+        let era_validators = auction_state
+            .era_validators
+            .iter()
+            .flat_map(|json_era_validators| {
+                let era_id = json_era_validators.era_id();
+                json_era_validators
+                    .validator_weights()
+                    .iter()
+                    .map(move |weights| ValidatorWeight {
+                        era_id,
+                        public_key: *weights.public_key(),
+                        weight: *weights.weight(),
+                    })
+            })
+            .filter(|weight| {
+                filter.matches_validator(&weight.public_key)
+                    && (filter.delegator.is_none()
+                        || stakes
+                            .iter()
+                            .any(|stake| stake.validator == weight.public_key))
+            })
+            .collect();
+
+        let total_stakes = stakes.len();
+        AuctionInfoSummary {
+            api_version: result.api_version,
+            state_root_hash: auction_state.state_root_hash,
+            block_height: auction_state.block_height,
+            era_validators,
+            stakes: Pagination::apply(pagination, stakes),
+            total_stakes,
+        }
+    }
+
+    /// Renders the summary as human-readable tables.
+    pub fn to_table(&self) -> String {
+        let era_validators = self
+            .era_validators
+            .iter()
+            .map(|weight| {
+                vec![
+                    weight.era_id.to_string(),
+                    weight.public_key.to_hex(),
+                    weight.weight.to_string(),
+                ]
+            })
+            .collect();
+        let stakes = self
+            .stakes
+            .iter()
+            .map(|stake| {
+                vec![
+                    stake.validator.to_hex(),
+                    stake
+                        .delegator
+                        .clone()
+                        .unwrap_or_else(|| EMPTY_CELL.to_string()),
+                    stake.staked_amount.to_string(),
+                    stake.delegation_rate.to_string(),
+                    stake.inactive.to_string(),
+                ]
+            })
+            .collect();
+        format!(
+            "State root hash: {}\nBlock height: {}\n\nEra validators:\n{}\nStakes ({} of {}):\n{}",
+            self.state_root_hash,
+            self.block_height,
+            render_table(&["ERA", "VALIDATOR", "WEIGHT"], era_validators),
+            self.stakes.len(),
+            self.total_stakes,
+            render_table(
+                &[
+                    "VALIDATOR",
+                    "DELEGATOR",
+                    "STAKED AMOUNT",
+                    "DELEGATION RATE",
+                    "INACTIVE"
+                ],
+                stakes
+            ),
+        )
+    }
+}
+
+/// The seigniorage allocations of an era, optionally filtered and paginated.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EraInfoSummary {
+    /// The RPC API version.
+    pub api_version: Version,
+    /// The hash of the switch block, or `None` if the requested block is not a switch block.
+    pub block_hash: Option<BlockHash>,
+    /// The era ID, or `None` if the requested block is not a switch block.
+    pub era_id: Option<u64>,
+    /// The seigniorage allocations on the selected page.
+    pub seigniorage_allocations: Vec<SeigniorageAllocation>,
+    /// The number of seigniorage allocations matching the filter, across all pages.
+    pub total_seigniorage_allocations: usize,
+}
+
+impl EraInfoSummary {
+    /// Constructs a new `EraInfoSummary` from the response to a
+    /// "chain_get_era_info_by_switch_block" RPC.
+    pub fn new(
+        result: GetEraInfoResult,
+        filter: &StakingFilter,
+        pagination: Option<Pagination>,
+    ) -> Self {
+        let (block_hash, era_id, allocations) = match result.era_summary {
+            Some(era_summary) => {
+                let allocations = match era_summary.stored_value {
+                    StoredValue::EraInfo(era_info) => era_info
+                        .seigniorage_allocations()
+                        .iter()
+                        .filter(|allocation| allocation_matches(filter, allocation))
+                        .cloned()
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                (
+                    Some(era_summary.block_hash),
+                    Some(era_summary.era_id),
+                    allocations,
+                )
+            }
+            None => (None, None, Vec::new()),
+        };
+
+        let total_seigniorage_allocations = allocations.len();
+        EraInfoSummary {
+            api_version: result.api_version,
+            block_hash,
+            era_id,
+            seigniorage_allocations: Pagination::apply(pagination, allocations),
+            total_seigniorage_allocations,
+        }
+    }
+
+    /// Renders the summary as a human-readable table.
+    pub fn to_table(&self) -> String {
+        let (block_hash, era_id) = match (&self.block_hash, self.era_id) {
+            (Some(block_hash), Some(era_id)) => (block_hash, era_id),
+            _ => return "The given block is not a switch block".to_string(),
+        };
+        let allocations = self
+            .seigniorage_allocations
+            .iter()
+            .map(|allocation| match allocation {
+                SeigniorageAllocation::Validator {
+                    validator_public_key,
+                    amount,
+                } => vec![
+                    validator_public_key.to_hex(),
+                    EMPTY_CELL.to_string(),
+                    amount.to_string(),
+                ],
+                SeigniorageAllocation::Delegator {
+                    delegator_public_key,
+                    validator_public_key,
+                    amount,
+                } => vec![
+                    validator_public_key.to_hex(),
+                    delegator_public_key.to_hex(),
+                    amount.to_string(),
+                ],
+                SeigniorageAllocation::ContractDelegator {
+                    contract_package_hash,
+                    validator_public_key,
+                    amount,
+                } => vec![
+                    validator_public_key.to_hex(),
+                    contract_package_hash.to_formatted_string(),
+                    amount.to_string(),
+                ],
+            })
+            .collect();
+        format!(
+            "Era: {}\nSwitch block hash: {}\n\nSeigniorage allocations ({} of {}):\n{}",
+            era_id,
+            block_hash,
+            self.seigniorage_allocations.len(),
+            self.total_seigniorage_allocations,
+            render_table(&["VALIDATOR", "DELEGATOR", "AMOUNT"], allocations),
+        )
+    }
+}
+
+fn allocation_matches(filter: &StakingFilter, allocation: &SeigniorageAllocation) -> bool {
+    match allocation {
+        SeigniorageAllocation::Validator {
+            validator_public_key,
+            ..
+        }
+        | SeigniorageAllocation::ContractDelegator {
+            validator_public_key,
+            ..
+        } => filter.matches_validator(validator_public_key) && filter.matches_delegator(None),
+        SeigniorageAllocation::Delegator {
+            delegator_public_key,
+            validator_public_key,
+            ..
+        } => {
+            filter.matches_validator(validator_public_key)
+                && filter.matches_delegator(Some(delegator_public_key))
+        }
+    }
+}
+
+/// Renders the rows as left-aligned columns under the given headers, one row per line.
+fn render_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let headers = headers.iter().map(|header| header.to_string()).collect();
+    let mut table = String::new();
+    for row in std::iter::once(headers).chain(rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use casper_node::types::json_compatibility::AuctionState;
+    use casper_types::{
+        system::auction::{Bid, Delegator, EraInfo},
+        AccessRights, SecretKey, URef,
+    };
+
+    use super::*;
+
+    fn public_key(seed: u8) -> PublicKey {
+        SecretKey::ed25519([seed; SecretKey::ED25519_LENGTH]).into()
+    }
+
+    fn auction_info() -> GetAuctionInfoResult {
+        let bonding_purse = URef::new([250; 32], AccessRights::READ_ADD_WRITE);
+        let mut bids = BTreeMap::new();
+        let mut validator_weights = BTreeMap::new();
+        for validator_seed in 1..=3 {
+            let validator = public_key(validator_seed);
+            let mut bid = Bid::unlocked(validator, bonding_purse, U512::from(100), 10);
+            for delegator_seed in 11..=12 {
+                let delegator = public_key(delegator_seed);
+                let _ = bid.delegators_mut().insert(
+                    delegator,
+                    Delegator::unlocked(delegator, U512::from(10), bonding_purse, validator),
+                );
+            }
+            bids.insert(validator, bid);
+            validator_weights.insert(validator, U512::from(120));
+        }
+        let mut era_validators = BTreeMap::new();
+        era_validators.insert(1, validator_weights);
+
+        GetAuctionInfoResult {
+            api_version: Version::new(1, 0, 0),
+            auction_state: AuctionState::new(
+                Digest::from([1; Digest::LENGTH]),
+                10,
+                Some(era_validators),
+                Some(bids),
+            ),
+        }
+    }
+
+    #[test]
+    fn should_filter_auction_info() {
+        let unfiltered = AuctionInfoSummary::new(auction_info(), &StakingFilter::default(), None);
+        assert_eq!(unfiltered.total_stakes, 9);
+        assert_eq!(unfiltered.era_validators.len(), 3);
+
+        let validator = public_key(2);
+        let filter = StakingFilter::new(&validator.to_hex(), "").unwrap();
+        let summary = AuctionInfoSummary::new(auction_info(), &filter, None);
+        assert_eq!(summary.total_stakes, 3);
+        assert!(summary
+            .stakes
+            .iter()
+            .all(|stake| stake.validator == validator));
+        assert_eq!(summary.era_validators.len(), 1);
+
+        let delegator = public_key(11);
+        let filter = StakingFilter::new(&validator.to_hex(), &delegator.to_hex()).unwrap();
+        let summary = AuctionInfoSummary::new(auction_info(), &filter, None);
+        assert_eq!(summary.stakes.len(), 1);
+        assert_eq!(summary.stakes[0].delegator, Some(delegator.to_hex()));
+        assert_eq!(summary.stakes[0].staked_amount, U512::from(10));
+    }
+
+    #[test]
+    fn should_paginate_auction_info() {
+        let pagination = Pagination::new("3", "4").unwrap();
+        let summary =
+            AuctionInfoSummary::new(auction_info(), &StakingFilter::default(), pagination);
+        assert_eq!(summary.stakes.len(), 1);
+        assert_eq!(summary.total_stakes, 9);
+
+        let pagination = Pagination::new("4", "4").unwrap();
+        let summary =
+            AuctionInfoSummary::new(auction_info(), &StakingFilter::default(), pagination);
+        assert!(summary.stakes.is_empty());
+    }
+
+    #[test]
+    fn should_reject_invalid_pagination() {
+        assert_eq!(Pagination::new("2", "").unwrap(), None);
+        assert!(matches!(
+            Pagination::new("0", "10"),
+            Err(Error::InvalidArgument("page", _))
+        ));
+        assert!(matches!(
+            Pagination::new("", "0"),
+            Err(Error::InvalidArgument("page_size", _))
+        ));
+        assert!(matches!(
+            Pagination::new("x", "10"),
+            Err(Error::FailedToParseInt("page", _))
+        ));
+    }
+
+    #[test]
+    fn should_reject_invalid_public_key() {
+        assert!(matches!(
+            StakingFilter::new("", "not a key"),
+            Err(Error::InvalidArgument("delegator", _))
+        ));
+    }
+
+    #[test]
+    fn should_filter_era_info_by_delegator() {
+        let validator = public_key(1);
+        let delegator = public_key(11);
+        let mut era_info = EraInfo::new();
+        era_info.seigniorage_allocations_mut().extend(vec![
+            SeigniorageAllocation::validator(validator, U512::from(5)),
+            SeigniorageAllocation::delegator(delegator, validator, U512::from(3)),
+            SeigniorageAllocation::delegator(public_key(12), validator, U512::from(2)),
+        ]);
+        let era_summary = casper_node::rpcs::chain::EraSummary {
+            block_hash: BlockHash::new(Digest::from([2; Digest::LENGTH])),
+            era_id: 4,
+            stored_value: StoredValue::EraInfo(era_info),
+            state_root_hash: Digest::from([3; Digest::LENGTH]),
+            merkle_proof: String::new(),
+        };
+        let result = GetEraInfoResult {
+            api_version: Version::new(1, 0, 0),
+            era_summary: Some(era_summary),
+        };
+
+        let filter = StakingFilter::new("", &delegator.to_hex()).unwrap();
+        let summary = EraInfoSummary::new(result, &filter, None);
+        assert_eq!(summary.era_id, Some(4));
+        assert_eq!(
+            summary.seigniorage_allocations,
+            vec![SeigniorageAllocation::delegator(
+                delegator,
+                validator,
+                U512::from(3)
+            )]
+        );
+        assert!(summary.to_table().contains(&delegator.to_hex()));
+    }
+
+    #[test]
+    fn should_render_aligned_table() {
+        let rows = vec![
+            vec!["1".to_string(), "long value".to_string()],
+            vec!["22".to_string(), "-".to_string()],
+        ];
+        assert_eq!(
+            render_table(&["A", "B"], rows),
+            "A   B\n1   long value\n22  -\n"
+        );
+    }
+}
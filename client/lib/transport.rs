@@ -0,0 +1,109 @@
+//! Settings controlling how requests are sent to the node.
+
+use std::{sync::RwLock, time::Duration};
+
+use once_cell::sync::Lazy;
+
+/// The limit on the factor by which the retry backoff grows.
+const MAX_BACKOFF_FACTOR: u32 = 1 << 6;
+
+static TRANSPORT_CONFIG: Lazy<RwLock<TransportConfig>> =
+    Lazy::new(|| RwLock::new(TransportConfig::default()));
+
+/// Settings controlling how JSON-RPC requests are sent to the node, shared by all requests made by
+/// the client library.
+///
+/// A request which fails due to a transient error (e.g. the node being unreachable, timing out or
+/// responding with an HTTP status such as `503 Service Unavailable`) is retried up to
+/// `max_retries` times, waiting `retry_backoff` before the first retry and doubling the wait
+/// before each subsequent one.  If all attempts fail and more than one node address was provided,
+/// the request is then tried against the next address in the same way.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TransportConfig {
+    timeout: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+}
+
+impl TransportConfig {
+    /// The default timeout of a single request.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+    /// The default number of times a request is retried against a single node.
+    pub const DEFAULT_MAX_RETRIES: u32 = 2;
+    /// The default wait before the first retry of a request.
+    pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(250);
+
+    /// Creates a new `TransportConfig`.
+    pub fn new(timeout: Duration, max_retries: u32, retry_backoff: Duration) -> Self {
+        TransportConfig {
+            timeout,
+            max_retries,
+            retry_backoff,
+        }
+    }
+
+    /// Returns the timeout of a single request.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the number of times a request is retried against a single node.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the wait before the first retry of a request.
+    pub fn retry_backoff(&self) -> Duration {
+        self.retry_backoff
+    }
+
+    /// Returns the wait before the given retry, where the first retry is `1`.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1_u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(MAX_BACKOFF_FACTOR)
+            .min(MAX_BACKOFF_FACTOR);
+        self.retry_backoff * factor
+    }
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        TransportConfig::new(
+            Self::DEFAULT_TIMEOUT,
+            Self::DEFAULT_MAX_RETRIES,
+            Self::DEFAULT_RETRY_BACKOFF,
+        )
+    }
+}
+
+/// Sets the transport settings used by all subsequent requests.
+pub fn set_transport_config(transport_config: TransportConfig) {
+    *TRANSPORT_CONFIG.write().expect("should lock") = transport_config;
+}
+
+/// Returns the transport settings currently in use.
+pub fn transport_config() -> TransportConfig {
+    *TRANSPORT_CONFIG.read().expect("should lock")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_should_double_up_to_limit() {
+        let config = TransportConfig::new(
+            TransportConfig::DEFAULT_TIMEOUT,
+            10,
+            Duration::from_millis(100),
+        );
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(3), Duration::from_millis(400));
+        assert_eq!(
+            config.backoff(u32::max_value()),
+            Duration::from_millis(100) * MAX_BACKOFF_FACTOR
+        );
+    }
+}
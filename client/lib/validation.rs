@@ -17,6 +17,7 @@ use casper_types::{bytesrepr, Key, U512};
 const GET_ITEM_RESULT_BALANCE_VALUE: &str = "balance_value";
 const GET_ITEM_RESULT_STORED_VALUE: &str = "stored_value";
 const GET_ITEM_RESULT_MERKLE_PROOF: &str = "merkle_proof";
+const GET_BALANCE_RESULT_STATE_ROOT_HASH: &str = "state_root_hash";
 
 /// Error that can be returned when validating a block returned from a JSON-RPC method.
 #[derive(Error, Debug)]
@@ -56,6 +57,10 @@ pub enum ValidateResponseError {
     /// Block height was not as requested.
     #[error("block height was not as requested")]
     UnexpectedBlockHeight,
+
+    /// State root hash requested does not correspond to response.
+    #[error("state root hash requested does not correspond to response")]
+    UnexpectedStateRootHash,
 }
 
 impl From<bytesrepr::Error> for ValidateResponseError {
@@ -176,7 +181,7 @@ pub(crate) fn validate_query_response(
 
 pub(crate) fn validate_get_balance_response(
     response: &JsonRpc,
-    state_root_hash: &Digest,
+    maybe_state_root_hash: Option<&Digest>,
     key: &Key,
 ) -> Result<(), ValidateResponseError> {
     let value = response
@@ -187,6 +192,19 @@ pub(crate) fn validate_get_balance_response(
         .as_object()
         .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
 
+    let state_root_hash: Digest = {
+        let value = object
+            .get(GET_BALANCE_RESULT_STATE_ROOT_HASH)
+            .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
+        serde_json::from_value(value.clone())?
+    };
+
+    if let Some(requested_state_root_hash) = maybe_state_root_hash {
+        if *requested_state_root_hash != state_root_hash {
+            return Err(ValidateResponseError::UnexpectedStateRootHash);
+        }
+    }
+
     let balance_proof: TrieMerkleProof<Key, StoredValue> = {
         let proof = object
             .get(GET_ITEM_RESULT_MERKLE_PROOF)
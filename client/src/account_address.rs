@@ -3,7 +3,7 @@ use std::{fs, str};
 use clap::{App, Arg, ArgMatches, SubCommand};
 
 use casper_client::Error;
-use casper_types::{AsymmetricType, PublicKey};
+use casper_types::{checksummed_hex, AsymmetricType, PublicKey};
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -11,6 +11,7 @@ use crate::{command::ClientCommand, common, Success};
 enum DisplayOrder {
     Verbose,
     Key,
+    Checksummed,
 }
 
 /// Handles providing the arg for and retrieval of the public key.
@@ -65,6 +66,29 @@ mod public_key {
     }
 }
 
+/// Handles providing the arg for and retrieval of the flag to checksum the account hash.
+mod checksummed {
+    use super::*;
+
+    const ARG_NAME: &str = "checksummed";
+    const ARG_HELP: &str =
+        "Prints the account hash as checksummed hex, i.e. with the case of each letter encoding a \
+        checksum which guards against typos.  Checksummed hex is accepted wherever an account \
+        hash is expected";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Checksummed as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
 pub struct GenerateAccountHash {}
 
 impl<'a, 'b> ClientCommand<'a, 'b> for GenerateAccountHash {
@@ -77,6 +101,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GenerateAccountHash {
             .display_order(display_order)
             .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
             .arg(public_key::arg())
+            .arg(checksummed::arg())
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -86,6 +111,11 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GenerateAccountHash {
             Error::FailedToParseKey
         })?;
         let account_hash = public_key.to_account_hash();
-        Ok(Success::Output(account_hash.to_string()))
+        let output = if checksummed::get(matches) {
+            checksummed_hex::encode(account_hash.as_bytes())
+        } else {
+            account_hash.to_string()
+        };
+        Ok(Success::Output(output))
     }
 }
@@ -9,6 +9,9 @@ pub enum Success {
     Response(JsonRpc),
     /// The output which should be presented to the user for non-RPC client commands.
     Output(String),
+    /// The command has already written its output to `stdout`; the process should exit with the
+    /// given code.
+    Exit(i32),
 }
 
 impl From<JsonRpc> for Success {
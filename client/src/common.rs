@@ -29,6 +29,28 @@ pub mod verbose {
     }
 }
 
+/// Handles the global arg for whether errors should be printed in a machine-readable format.
+pub mod json_errors {
+    use super::*;
+
+    const ARG_NAME: &str = "json";
+    const ARG_HELP: &str =
+        "If an error occurs, prints it as a JSON object with 'code', 'message' and 'context' \
+        fields rather than as plain text";
+
+    pub fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .global(true)
+            .help(ARG_HELP)
+    }
+
+    pub fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
 /// Handles providing the arg for and retrieval of the node hostname/IP and port.
 pub mod node_address {
     use super::*;
@@ -37,7 +59,10 @@ pub mod node_address {
     const ARG_SHORT: &str = "n";
     const ARG_VALUE_NAME: &str = "HOST:PORT";
     const ARG_DEFAULT: &str = "http://localhost:7777";
-    const ARG_HELP: &str = "Hostname or IP and port of node on which HTTP service is running";
+    const ARG_HELP: &str =
+        "Hostname or IP and port of node on which HTTP service is running.  Several \
+        comma-separated addresses may be given, in which case each is tried in order until one of \
+        them responds";
 
     pub fn arg(order: usize) -> Arg<'static, 'static> {
         Arg::with_name(ARG_NAME)
@@ -57,6 +82,82 @@ pub mod node_address {
     }
 }
 
+/// Handles the global args controlling timeouts and retries of requests sent to the node.
+pub mod transport {
+    use std::time::Duration;
+
+    use casper_client::{Error, TransportConfig};
+
+    use super::*;
+
+    const TIMEOUT_ARG_NAME: &str = "request-timeout";
+    const TIMEOUT_ARG_VALUE_NAME: &str = "DURATION";
+    const TIMEOUT_ARG_HELP: &str =
+        "Timeout of each request sent to the node, e.g. '10s' or '1min'.  If not provided, \
+        defaults to 30s";
+
+    const MAX_RETRIES_ARG_NAME: &str = "max-retries";
+    const MAX_RETRIES_ARG_VALUE_NAME: &str = super::ARG_INTEGER;
+    const MAX_RETRIES_ARG_HELP: &str =
+        "Number of times a request is retried against a node after failing due to a transient \
+        error such as the node being unreachable.  If not provided, defaults to 2";
+
+    const RETRY_BACKOFF_ARG_NAME: &str = "retry-backoff";
+    const RETRY_BACKOFF_ARG_VALUE_NAME: &str = "DURATION";
+    const RETRY_BACKOFF_ARG_HELP: &str =
+        "Wait before the first retry of a failed request, doubled before each subsequent retry, \
+        e.g. '500ms'.  If not provided, defaults to 250ms";
+
+    pub fn args() -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name(TIMEOUT_ARG_NAME)
+                .long(TIMEOUT_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(TIMEOUT_ARG_VALUE_NAME)
+                .help(TIMEOUT_ARG_HELP),
+            Arg::with_name(MAX_RETRIES_ARG_NAME)
+                .long(MAX_RETRIES_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(MAX_RETRIES_ARG_VALUE_NAME)
+                .help(MAX_RETRIES_ARG_HELP),
+            Arg::with_name(RETRY_BACKOFF_ARG_NAME)
+                .long(RETRY_BACKOFF_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(RETRY_BACKOFF_ARG_VALUE_NAME)
+                .help(RETRY_BACKOFF_ARG_HELP),
+        ]
+    }
+
+    pub fn get(matches: &ArgMatches) -> Result<TransportConfig, Error> {
+        let parse_duration = |arg_name: &'static str, default: Duration| {
+            matches
+                .value_of(arg_name)
+                .map(|value| {
+                    humantime::parse_duration(value)
+                        .map_err(|error| Error::FailedToParseTimeDiff(arg_name, error))
+                })
+                .unwrap_or(Ok(default))
+        };
+        let timeout = parse_duration(TIMEOUT_ARG_NAME, TransportConfig::DEFAULT_TIMEOUT)?;
+        let retry_backoff = parse_duration(
+            RETRY_BACKOFF_ARG_NAME,
+            TransportConfig::DEFAULT_RETRY_BACKOFF,
+        )?;
+        let max_retries = matches
+            .value_of(MAX_RETRIES_ARG_NAME)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|error| Error::FailedToParseInt(MAX_RETRIES_ARG_NAME, error))
+            })
+            .unwrap_or(Ok(TransportConfig::DEFAULT_MAX_RETRIES))?;
+        Ok(TransportConfig::new(timeout, max_retries, retry_backoff))
+    }
+}
+
 /// Handles providing the arg for the RPC ID.
 pub mod rpc_id {
     use super::*;
@@ -189,3 +290,119 @@ pub mod block_identifier {
         matches.value_of(ARG_NAME).unwrap_or_default()
     }
 }
+
+/// Handles providing the args for and retrieval of the validator and delegator by which staking
+/// data is filtered.
+pub mod staking_filter {
+    use casper_client::{Error, StakingFilter};
+
+    use super::*;
+
+    const VALIDATOR_ARG_NAME: &str = "validator";
+    const VALIDATOR_ARG_HELP: &str =
+        "Hex-encoded public key of a validator. If given, only data relating to this validator \
+        will be shown";
+    const DELEGATOR_ARG_NAME: &str = "delegator";
+    const DELEGATOR_ARG_HELP: &str =
+        "Hex-encoded public key of a delegator. If given, only data relating to this delegator's \
+        delegations will be shown";
+
+    pub(crate) fn args(order: usize) -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name(VALIDATOR_ARG_NAME)
+                .long(VALIDATOR_ARG_NAME)
+                .required(false)
+                .value_name(super::ARG_HEX_STRING)
+                .help(VALIDATOR_ARG_HELP)
+                .display_order(order),
+            Arg::with_name(DELEGATOR_ARG_NAME)
+                .long(DELEGATOR_ARG_NAME)
+                .required(false)
+                .value_name(super::ARG_HEX_STRING)
+                .help(DELEGATOR_ARG_HELP)
+                .display_order(order),
+        ]
+    }
+
+    pub(crate) fn get(matches: &ArgMatches) -> Result<StakingFilter, Error> {
+        StakingFilter::new(
+            matches.value_of(VALIDATOR_ARG_NAME).unwrap_or_default(),
+            matches.value_of(DELEGATOR_ARG_NAME).unwrap_or_default(),
+        )
+    }
+}
+
+/// Handles providing the args for and retrieval of the page of entries to show.
+pub mod pagination {
+    use casper_client::{Error, Pagination};
+
+    use super::*;
+
+    const PAGE_ARG_NAME: &str = "page";
+    const PAGE_ARG_HELP: &str = "Number of the page of entries to show, starting from 1";
+    const PAGE_SIZE_ARG_NAME: &str = "page-size";
+    const PAGE_SIZE_ARG_HELP: &str =
+        "Maximum number of entries per page. If not given, all entries will be shown";
+
+    pub(crate) fn args(order: usize) -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name(PAGE_ARG_NAME)
+                .long(PAGE_ARG_NAME)
+                .required(false)
+                .requires(PAGE_SIZE_ARG_NAME)
+                .value_name(super::ARG_INTEGER)
+                .help(PAGE_ARG_HELP)
+                .display_order(order),
+            Arg::with_name(PAGE_SIZE_ARG_NAME)
+                .long(PAGE_SIZE_ARG_NAME)
+                .required(false)
+                .value_name(super::ARG_INTEGER)
+                .help(PAGE_SIZE_ARG_HELP)
+                .display_order(order),
+        ]
+    }
+
+    pub(crate) fn get(matches: &ArgMatches) -> Result<Option<Pagination>, Error> {
+        Pagination::new(
+            matches.value_of(PAGE_ARG_NAME).unwrap_or_default(),
+            matches.value_of(PAGE_SIZE_ARG_NAME).unwrap_or_default(),
+        )
+    }
+}
+
+/// Handles providing the arg for and retrieval of the format in which to show the output.
+pub mod output_format {
+    use super::*;
+
+    const ARG_NAME: &str = "format";
+    const ARG_VALUE_NAME: &str = "FORMAT";
+    const JSON: &str = "json";
+    const TABLE: &str = "table";
+    const ARG_HELP: &str =
+        "Format of the output: either the JSON response or a human-readable table";
+
+    /// The format in which to show the output.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub(crate) enum OutputFormat {
+        Json,
+        Table,
+    }
+
+    pub(crate) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .default_value(JSON)
+            .possible_values(&[JSON, TABLE])
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(crate) fn get(matches: &ArgMatches) -> OutputFormat {
+        match matches.value_of(ARG_NAME) {
+            Some(TABLE) => OutputFormat::Table,
+            _ => OutputFormat::Json,
+        }
+    }
+}
@@ -6,6 +6,7 @@ mod put;
 mod send;
 mod sign;
 mod transfer;
+mod verify;
 
 pub use transfer::Transfer;
 
@@ -13,3 +14,4 @@ pub use list::ListDeploys;
 pub use make::MakeDeploy;
 pub use send::SendDeploy;
 pub use sign::SignDeploy;
+pub use verify::VerifyDeploy;
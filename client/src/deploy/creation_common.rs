@@ -26,6 +26,9 @@ pub(super) enum DisplayOrder {
     GasPrice,
     Dependencies,
     ChainName,
+    CheckChainName,
+    StrictChainName,
+    FixChainName,
     SessionCode,
     SessionArgSimple,
     SessionArgsComplex,
@@ -1,11 +1,70 @@
-use clap::{App, ArgMatches, SubCommand};
+use clap::{App, Arg, ArgMatches, SubCommand};
 
-use casper_client::{DeployStrParams, Error};
+use casper_client::{ChainNameCheck, DeployStrParams, Error};
 use casper_node::rpcs::account::PutDeploy;
 
 use super::creation_common::{self, DisplayOrder};
 use crate::{command::ClientCommand, common, Success};
 
+/// Handles providing the args for and retrieval of how the deploy's chain name is checked against
+/// the node's.
+mod chain_name_check {
+    use super::*;
+
+    const CHECK_ARG_NAME: &str = "check-chain-name";
+    const CHECK_ARG_HELP: &str =
+        "If this flag is passed, the node's chain name is fetched and a warning is printed if the \
+        deploy's chain name differs from it.  The deploy is sent regardless";
+
+    const STRICT_ARG_NAME: &str = "strict-chain-name";
+    const STRICT_ARG_HELP: &str =
+        "If this flag is passed, the deploy is not sent if its chain name differs from that of the \
+        node, or if the node's chain name can't be fetched";
+
+    const FIX_ARG_NAME: &str = "fix-chain-name";
+    const FIX_ARG_HELP: &str =
+        "If this flag is passed and the deploy's chain name differs from that of the node, the \
+        deploy is rebuilt and re-signed using the node's chain name before being sent";
+
+    pub(super) fn check_arg() -> Arg<'static, 'static> {
+        Arg::with_name(CHECK_ARG_NAME)
+            .long(CHECK_ARG_NAME)
+            .required(false)
+            .conflicts_with_all(&[STRICT_ARG_NAME, FIX_ARG_NAME])
+            .help(CHECK_ARG_HELP)
+            .display_order(DisplayOrder::CheckChainName as usize)
+    }
+
+    pub(super) fn strict_arg() -> Arg<'static, 'static> {
+        Arg::with_name(STRICT_ARG_NAME)
+            .long(STRICT_ARG_NAME)
+            .required(false)
+            .conflicts_with(FIX_ARG_NAME)
+            .help(STRICT_ARG_HELP)
+            .display_order(DisplayOrder::StrictChainName as usize)
+    }
+
+    pub(super) fn fix_arg() -> Arg<'static, 'static> {
+        Arg::with_name(FIX_ARG_NAME)
+            .long(FIX_ARG_NAME)
+            .required(false)
+            .help(FIX_ARG_HELP)
+            .display_order(DisplayOrder::FixChainName as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> ChainNameCheck {
+        if matches.is_present(FIX_ARG_NAME) {
+            ChainNameCheck::Fix
+        } else if matches.is_present(STRICT_ARG_NAME) {
+            ChainNameCheck::Fail
+        } else if matches.is_present(CHECK_ARG_NAME) {
+            ChainNameCheck::Warn
+        } else {
+            ChainNameCheck::Skip
+        }
+    }
+}
+
 impl<'a, 'b> ClientCommand<'a, 'b> for PutDeploy {
     const NAME: &'static str = "put-deploy";
     const ABOUT: &'static str = "Creates a deploy and sends it to the network for execution";
@@ -19,6 +78,9 @@ impl<'a, 'b> ClientCommand<'a, 'b> for PutDeploy {
         let subcommand = creation_common::apply_common_session_options(subcommand);
         let subcommand = creation_common::apply_common_payment_options(subcommand);
         creation_common::apply_common_creation_options(subcommand, true)
+            .arg(chain_name_check::check_arg())
+            .arg(chain_name_check::strict_arg())
+            .arg(chain_name_check::fix_arg())
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -34,11 +96,12 @@ impl<'a, 'b> ClientCommand<'a, 'b> for PutDeploy {
         let gas_price = creation_common::gas_price::get(matches);
         let dependencies = creation_common::dependencies::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
+        let chain_name_check = chain_name_check::get(matches);
 
         let session_str_params = creation_common::session_str_params(matches);
         let payment_str_params = creation_common::payment_str_params(matches);
 
-        casper_client::put_deploy(
+        casper_client::put_deploy_with_chain_name_check(
             maybe_rpc_id,
             node_address,
             verbosity_level,
@@ -52,6 +115,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for PutDeploy {
             },
             session_str_params,
             payment_str_params,
+            chain_name_check,
         )
         .map(Success::from)
     }
@@ -0,0 +1,203 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::{Error, ErrorCode};
+use casper_node::types::{DeployLimits, TimeDiff};
+
+use super::creation_common;
+use crate::{command::ClientCommand, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Input,
+    ChainName,
+    MaxDeploySize,
+    MaxTtl,
+    MaxDependencies,
+    PaymentArgsMaxLength,
+    SessionArgsMaxLength,
+}
+
+/// Returns an optional arg for an integer chainspec limit, defaulting to its value in the
+/// production chainspec.
+fn limit_arg(
+    arg_name: &'static str,
+    default: &'static str,
+    help: &'static str,
+    display_order: DisplayOrder,
+) -> Arg<'static, 'static> {
+    Arg::with_name(arg_name)
+        .long(arg_name)
+        .required(false)
+        .value_name("INTEGER")
+        .default_value(default)
+        .help(help)
+        .display_order(display_order as usize)
+}
+
+fn get_limit<T: FromStr<Err = ParseIntError>>(
+    matches: &ArgMatches,
+    arg_name: &'static str,
+) -> Result<T, Error> {
+    matches
+        .value_of(arg_name)
+        .unwrap_or_else(|| panic!("should have {} arg", arg_name))
+        .parse()
+        .map_err(|error| Error::FailedToParseInt(arg_name, error))
+}
+
+/// Handles providing the arg for and retrieval of the maximum deploy size.
+mod max_deploy_size {
+    use super::*;
+
+    const ARG_NAME: &str = "max-deploy-size";
+    const ARG_DEFAULT: &str = "1048576";
+    const ARG_HELP: &str = "Maximum serialized size of a deploy in bytes";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        limit_arg(ARG_NAME, ARG_DEFAULT, ARG_HELP, DisplayOrder::MaxDeploySize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<u32, Error> {
+        get_limit(matches, ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum number of dependencies.
+mod max_dependencies {
+    use super::*;
+
+    const ARG_NAME: &str = "max-dependencies";
+    const ARG_DEFAULT: &str = "10";
+    const ARG_HELP: &str = "Maximum number of dependencies of a deploy";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        limit_arg(
+            ARG_NAME,
+            ARG_DEFAULT,
+            ARG_HELP,
+            DisplayOrder::MaxDependencies,
+        )
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<u8, Error> {
+        get_limit(matches, ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum length of the payment args.
+mod payment_args_max_length {
+    use super::*;
+
+    const ARG_NAME: &str = "payment-args-max-length";
+    const ARG_DEFAULT: &str = "1024";
+    const ARG_HELP: &str = "Maximum serialized length of the payment code's runtime args in bytes";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        limit_arg(
+            ARG_NAME,
+            ARG_DEFAULT,
+            ARG_HELP,
+            DisplayOrder::PaymentArgsMaxLength,
+        )
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<u32, Error> {
+        get_limit(matches, ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum length of the session args.
+mod session_args_max_length {
+    use super::*;
+
+    const ARG_NAME: &str = "session-args-max-length";
+    const ARG_DEFAULT: &str = "1024";
+    const ARG_HELP: &str = "Maximum serialized length of the session code's runtime args in bytes";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        limit_arg(
+            ARG_NAME,
+            ARG_DEFAULT,
+            ARG_HELP,
+            DisplayOrder::SessionArgsMaxLength,
+        )
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<u32, Error> {
+        get_limit(matches, ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum time to live.
+mod max_ttl {
+    use super::*;
+
+    const ARG_NAME: &str = "max-ttl";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "1day";
+    const ARG_HELP: &str = "Maximum time to live of a deploy";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::MaxTtl as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<TimeDiff, Error> {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+            .parse()
+            .map_err(|error| Error::FailedToParseTimeDiff(ARG_NAME, error))
+    }
+}
+
+pub struct VerifyDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for VerifyDeploy {
+    const NAME: &'static str = "verify-deploy";
+    const ABOUT: &'static str =
+        "Reads a previously-saved deploy from a file and verifies its hashes, approvals and \
+        compliance with the network's limits without submitting it, printing a report and exiting \
+        with a non-zero code if any check fails";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(creation_common::input::arg().display_order(DisplayOrder::Input as usize))
+            .arg(creation_common::chain_name::arg().display_order(DisplayOrder::ChainName as usize))
+            .arg(max_deploy_size::arg())
+            .arg(max_ttl::arg())
+            .arg(max_dependencies::arg())
+            .arg(payment_args_max_length::arg())
+            .arg(session_args_max_length::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let input_path = creation_common::input::get(matches);
+        let deploy_limits = DeployLimits {
+            chain_name: creation_common::chain_name::get(matches).to_string(),
+            max_deploy_size: max_deploy_size::get(matches)?,
+            max_ttl: max_ttl::get(matches)?,
+            max_dependencies: max_dependencies::get(matches)?,
+            payment_args_max_length: payment_args_max_length::get(matches)?,
+            session_args_max_length: session_args_max_length::get(matches)?,
+        };
+
+        let report = casper_client::verify_deploy_file(input_path, &deploy_limits)?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        let exit_code = if report.is_valid() {
+            0
+        } else {
+            ErrorCode::ValidationFailure.exit_code()
+        };
+        Ok(Success::Exit(exit_code))
+    }
+}
@@ -2,16 +2,23 @@ use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
 
-use casper_client::Error;
-use casper_node::rpcs::state::GetAuctionInfo;
+use casper_client::{AuctionInfoSummary, Error};
+use casper_node::rpcs::state::{GetAuctionInfo, GetAuctionInfoResult};
 
-use crate::{command::ClientCommand, common, Success};
+use crate::{
+    command::ClientCommand,
+    common::{self, output_format::OutputFormat},
+    Success,
+};
 
 /// This struct defines the order in which the args are shown for this subcommand's help message.
 enum DisplayOrder {
     Verbose,
     NodeAddress,
     RpcId,
+    OutputFormat,
+    StakingFilter,
+    Pagination,
 }
 
 impl<'a, 'b> ClientCommand<'a, 'b> for GetAuctionInfo {
@@ -28,14 +35,39 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetAuctionInfo {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::output_format::arg(
+                DisplayOrder::OutputFormat as usize,
+            ))
+            .args(&common::staking_filter::args(
+                DisplayOrder::StakingFilter as usize,
+            ))
+            .args(&common::pagination::args(DisplayOrder::Pagination as usize))
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let output_format = common::output_format::get(matches);
+        let filter = common::staking_filter::get(matches)?;
+        let pagination = common::pagination::get(matches)?;
+
+        let response =
+            casper_client::get_auction_info(maybe_rpc_id, node_address, verbosity_level)?;
+        if output_format == OutputFormat::Json && filter.is_empty() && pagination.is_none() {
+            return Ok(Success::from(response));
+        }
 
-        casper_client::get_auction_info(maybe_rpc_id, node_address, verbosity_level)
-            .map(Success::from)
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result = serde_json::from_value::<GetAuctionInfoResult>(result)?;
+        let summary = AuctionInfoSummary::new(result, &filter, pagination);
+        let output = match output_format {
+            OutputFormat::Json => serde_json::to_string_pretty(&summary)?,
+            OutputFormat::Table => summary.to_table(),
+        };
+        Ok(Success::Output(output))
     }
 }
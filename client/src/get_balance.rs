@@ -16,6 +16,32 @@ enum DisplayOrder {
     PurseURef,
 }
 
+/// Handles providing the arg for and retrieval of the optional state root hash.
+mod state_root_hash {
+    use super::*;
+
+    const ARG_NAME: &str = "state-root-hash";
+    const ARG_SHORT: &str = "s";
+    const ARG_VALUE_NAME: &str = "HEX STRING";
+    const ARG_HELP: &str =
+        "Hex-encoded hash of the state root. If not given, the state root of the last block added \
+        to the chain as known at the given node will be used";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::StateRootHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
 /// Handles providing the arg for and retrieval of the purse URef.
 mod purse_uref {
     use super::*;
@@ -57,9 +83,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
-            .arg(common::state_root_hash::arg(
-                DisplayOrder::StateRootHash as usize,
-            ))
+            .arg(state_root_hash::arg())
             .arg(purse_uref::arg())
     }
 
@@ -67,7 +91,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
-        let state_root_hash = common::state_root_hash::get(&matches);
+        let state_root_hash = state_root_hash::get(&matches);
         let purse_uref = purse_uref::get(&matches);
 
         casper_client::get_balance(
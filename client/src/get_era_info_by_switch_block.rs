@@ -2,10 +2,14 @@ use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
 
-use casper_client::Error;
-use casper_node::rpcs::chain::GetEraInfoBySwitchBlock;
+use casper_client::{EraInfoSummary, Error};
+use casper_node::rpcs::chain::{GetEraInfoBySwitchBlock, GetEraInfoResult};
 
-use crate::{command::ClientCommand, common, Success};
+use crate::{
+    command::ClientCommand,
+    common::{self, output_format::OutputFormat},
+    Success,
+};
 
 /// This struct defines the order in which the args are shown for this subcommand's help message.
 enum DisplayOrder {
@@ -13,6 +17,9 @@ enum DisplayOrder {
     NodeAddress,
     RpcId,
     BlockIdentifier,
+    OutputFormat,
+    StakingFilter,
+    Pagination,
 }
 
 impl<'a, 'b> ClientCommand<'a, 'b> for GetEraInfoBySwitchBlock {
@@ -21,6 +28,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetEraInfoBySwitchBlock {
 
     fn build(display_order: usize) -> App<'a, 'b> {
         SubCommand::with_name(Self::NAME)
+            .alias("get-era-info")
             .about(Self::ABOUT)
             .display_order(display_order)
             .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
@@ -31,6 +39,13 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetEraInfoBySwitchBlock {
             .arg(common::block_identifier::arg(
                 DisplayOrder::BlockIdentifier as usize,
             ))
+            .arg(common::output_format::arg(
+                DisplayOrder::OutputFormat as usize,
+            ))
+            .args(&common::staking_filter::args(
+                DisplayOrder::StakingFilter as usize,
+            ))
+            .args(&common::pagination::args(DisplayOrder::Pagination as usize))
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -38,13 +53,30 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetEraInfoBySwitchBlock {
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
         let maybe_block_id = common::block_identifier::get(&matches);
+        let output_format = common::output_format::get(matches);
+        let filter = common::staking_filter::get(matches)?;
+        let pagination = common::pagination::get(matches)?;
 
-        casper_client::get_era_info_by_switch_block(
+        let response = casper_client::get_era_info_by_switch_block(
             maybe_rpc_id,
             node_address,
             verbosity_level,
             maybe_block_id,
-        )
-        .map(Success::from)
+        )?;
+        if output_format == OutputFormat::Json && filter.is_empty() && pagination.is_none() {
+            return Ok(Success::from(response));
+        }
+
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result = serde_json::from_value::<GetEraInfoResult>(result)?;
+        let summary = EraInfoSummary::new(result, &filter, pagination);
+        let output = match output_format {
+            OutputFormat::Json => serde_json::to_string_pretty(&summary)?,
+            OutputFormat::Table => summary.to_table(),
+        };
+        Ok(Success::Output(output))
     }
 }
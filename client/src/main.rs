@@ -9,29 +9,32 @@ mod get_auction_info;
 mod get_balance;
 mod get_era_info_by_switch_block;
 mod get_state_hash;
+mod get_validator_changes;
 mod keygen;
 mod query_state;
+mod watch;
 
 use std::process;
 
-use clap::{crate_description, crate_version, App};
+use clap::{crate_description, crate_version, App, ArgMatches};
 
-use casper_client::Error;
+use casper_client::{Error, ErrorReport};
 use casper_node::rpcs::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
-    info::GetDeploy,
+    info::{GetDeploy, GetValidatorChanges},
     state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
 };
 
-use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
+use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy, VerifyDeploy};
 
 use account_address::GenerateAccountHash as AccountAddress;
 use command::{ClientCommand, Success};
 use deploy::Transfer;
 use generate_completion::GenerateCompletion;
 use keygen::Keygen;
+use watch::{WatchBlock, WatchDeploy};
 
 const APP_NAME: &str = "Casper client";
 
@@ -41,9 +44,12 @@ enum DisplayOrder {
     MakeDeploy,
     SignDeploy,
     SendDeploy,
+    VerifyDeploy,
     Transfer,
     GetDeploy,
+    WatchDeploy,
     GetBlock,
+    WatchBlock,
     GetBlockTransfers,
     ListDeploys,
     GetStateRootHash,
@@ -51,6 +57,7 @@ enum DisplayOrder {
     GetBalance,
     GetEraInfo,
     GetAuctionInfo,
+    GetValidatorChanges,
     Keygen,
     GenerateCompletion,
     GetRpcs,
@@ -61,13 +68,18 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
     App::new(APP_NAME)
         .version(crate_version!())
         .about(crate_description!())
+        .arg(common::json_errors::arg())
+        .args(&common::transport::args())
         .subcommand(PutDeploy::build(DisplayOrder::PutDeploy as usize))
         .subcommand(MakeDeploy::build(DisplayOrder::MakeDeploy as usize))
         .subcommand(SignDeploy::build(DisplayOrder::SignDeploy as usize))
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
+        .subcommand(VerifyDeploy::build(DisplayOrder::VerifyDeploy as usize))
         .subcommand(Transfer::build(DisplayOrder::Transfer as usize))
         .subcommand(GetDeploy::build(DisplayOrder::GetDeploy as usize))
+        .subcommand(WatchDeploy::build(DisplayOrder::WatchDeploy as usize))
         .subcommand(GetBlock::build(DisplayOrder::GetBlock as usize))
+        .subcommand(WatchBlock::build(DisplayOrder::WatchBlock as usize))
         .subcommand(GetBlockTransfers::build(
             DisplayOrder::GetBlockTransfers as usize,
         ))
@@ -81,6 +93,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetEraInfo as usize,
         ))
         .subcommand(GetAuctionInfo::build(DisplayOrder::GetAuctionInfo as usize))
+        .subcommand(GetValidatorChanges::build(
+            DisplayOrder::GetValidatorChanges as usize,
+        ))
         .subcommand(Keygen::build(DisplayOrder::Keygen as usize))
         .subcommand(GenerateCompletion::build(
             DisplayOrder::GenerateCompletion as usize,
@@ -92,14 +107,24 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
 #[tokio::main]
 async fn main() {
     let arg_matches = cli().get_matches();
+    if let (_, Some(matches)) = arg_matches.subcommand() {
+        match common::transport::get(matches) {
+            Ok(transport_config) => casper_client::set_transport_config(transport_config),
+            Err(error) => exit_with_error(&error, matches),
+        }
+    }
+
     let (result, matches) = match arg_matches.subcommand() {
         (PutDeploy::NAME, Some(matches)) => (PutDeploy::run(matches), matches),
         (MakeDeploy::NAME, Some(matches)) => (MakeDeploy::run(matches), matches),
         (SignDeploy::NAME, Some(matches)) => (SignDeploy::run(matches), matches),
         (SendDeploy::NAME, Some(matches)) => (SendDeploy::run(matches), matches),
+        (VerifyDeploy::NAME, Some(matches)) => (VerifyDeploy::run(matches), matches),
         (Transfer::NAME, Some(matches)) => (Transfer::run(matches), matches),
         (GetDeploy::NAME, Some(matches)) => (GetDeploy::run(matches), matches),
+        (WatchDeploy::NAME, Some(matches)) => (WatchDeploy::run(matches), matches),
         (GetBlock::NAME, Some(matches)) => (GetBlock::run(matches), matches),
+        (WatchBlock::NAME, Some(matches)) => (WatchBlock::run(matches), matches),
         (GetBlockTransfers::NAME, Some(matches)) => (GetBlockTransfers::run(matches), matches),
         (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches), matches),
         (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches), matches),
@@ -109,6 +134,7 @@ async fn main() {
             (GetEraInfoBySwitchBlock::run(matches), matches)
         }
         (GetAuctionInfo::NAME, Some(matches)) => (GetAuctionInfo::run(matches), matches),
+        (GetValidatorChanges::NAME, Some(matches)) => (GetValidatorChanges::run(matches), matches),
         (Keygen::NAME, Some(matches)) => (Keygen::run(matches), matches),
         (GenerateCompletion::NAME, Some(matches)) => (GenerateCompletion::run(matches), matches),
         (ListRpcs::NAME, Some(matches)) => (ListRpcs::run(matches), matches),
@@ -120,23 +146,36 @@ async fn main() {
         }
     };
 
-    let mut verbosity_level = common::verbose::get(matches);
-    if verbosity_level == 0 {
-        verbosity_level += 1
-    }
-
     match &result {
         Ok(Success::Response(response)) => {
-            casper_client::pretty_print_at_level(&response, verbosity_level)
+            casper_client::pretty_print_at_level(&response, verbosity_level(matches))
         }
         Ok(Success::Output(output)) => println!("{}", output),
-        Err(Error::ResponseIsError(error)) => {
-            casper_client::pretty_print_at_level(&error, verbosity_level);
-            process::exit(1);
-        }
-        Err(error) => {
-            println!("{}", error);
-            process::exit(1);
-        }
+        Ok(Success::Exit(exit_code)) => process::exit(*exit_code),
+        Err(error) => exit_with_error(error, matches),
+    }
+}
+
+fn verbosity_level(matches: &ArgMatches) -> u64 {
+    let verbosity_level = common::verbose::get(matches);
+    if verbosity_level == 0 {
+        1
+    } else {
+        verbosity_level
+    }
+}
+
+fn exit_with_error(error: &Error, matches: &ArgMatches) -> ! {
+    if common::json_errors::get(matches) {
+        let report = ErrorReport::from(error);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).expect("should encode to JSON")
+        );
+    } else if let Error::ResponseIsError(rpc_error) = error {
+        casper_client::pretty_print_at_level(&rpc_error, verbosity_level(matches));
+    } else {
+        println!("{}", error);
     }
+    process::exit(error.code().exit_code());
 }
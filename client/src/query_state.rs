@@ -81,7 +81,8 @@ mod path {
     const ARG_NAME: &str = "query-path";
     const ARG_SHORT: &str = "q";
     const ARG_VALUE_NAME: &str = "PATH/FROM/KEY";
-    const ARG_HELP: &str = "The path from the key of the query";
+    const ARG_HELP: &str = "The path from the key of the query. Components are named keys or \
+        elements of stored values, e.g. 'named_key/entry[3]/field'";
 
     pub(super) fn arg() -> Arg<'static, 'static> {
         Arg::with_name(ARG_NAME)
@@ -0,0 +1,172 @@
+//! Commands which repeatedly poll the node and stream newline-delimited JSON updates to `stdout`
+//! until a terminal state is reached or the timeout elapses.
+
+mod block;
+mod deploy;
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::{Arg, ArgMatches};
+use serde_json::{json, Value};
+
+use casper_client::Error;
+use casper_node::types::TimeDiff;
+
+pub use block::WatchBlock;
+pub use deploy::WatchDeploy;
+
+/// Exit code used when the watched deploy was executed, but execution failed.
+const EXIT_CODE_EXECUTION_FAILED: i32 = 2;
+/// Exit code used when the watched deploy expired without being executed.
+const EXIT_CODE_EXPIRED: i32 = 3;
+/// Exit code used when the timeout elapsed before a terminal state was reached.
+const EXIT_CODE_TIMED_OUT: i32 = 4;
+
+/// Handles providing the arg for and retrieval of the polling interval.
+mod poll_interval {
+    use super::*;
+
+    const ARG_NAME: &str = "poll-interval";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "5s";
+    const ARG_HELP: &str =
+        "Time to wait between consecutive requests to the node, e.g. '500ms', '5s' or '1min'";
+
+    pub(super) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .default_value(ARG_DEFAULT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<Duration, Error> {
+        let value = matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME));
+        parse_duration(ARG_NAME, value)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the overall timeout.
+mod timeout {
+    use super::*;
+
+    const ARG_NAME: &str = "timeout";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "10min";
+    const ARG_HELP: &str =
+        "Maximum time to keep watching before giving up, e.g. '30s', '10min' or '1h'";
+
+    pub(super) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .default_value(ARG_DEFAULT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<Duration, Error> {
+        let value = matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME));
+        parse_duration(ARG_NAME, value)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the exit code flag.
+mod exit_code {
+    use super::*;
+
+    const ARG_NAME: &str = "exit-code";
+    const ARG_HELP: &str =
+        "If this flag is passed, the process exits with a non-zero code unless the watch ends in \
+        success: 2 if the deploy failed to execute, 3 if the deploy expired and 4 if the timeout \
+        elapsed first";
+
+    pub(super) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+fn parse_duration(arg_name: &'static str, value: &str) -> Result<Duration, Error> {
+    let time_diff = value
+        .parse::<TimeDiff>()
+        .map_err(|error| Error::FailedToParseTimeDiff(arg_name, error))?;
+    Ok(Duration::from_millis(time_diff.millis()))
+}
+
+/// The final state of a watch.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Outcome {
+    Succeeded,
+    ExecutionFailed,
+    Expired,
+    TimedOut,
+}
+
+impl Outcome {
+    fn exit_code(self, use_exit_code: bool) -> i32 {
+        if !use_exit_code {
+            return 0;
+        }
+        match self {
+            Outcome::Succeeded => 0,
+            Outcome::ExecutionFailed => EXIT_CODE_EXECUTION_FAILED,
+            Outcome::Expired => EXIT_CODE_EXPIRED,
+            Outcome::TimedOut => EXIT_CODE_TIMED_OUT,
+        }
+    }
+}
+
+/// A single update produced by one poll of the node.
+struct Update {
+    status: &'static str,
+    result: Value,
+    outcome: Option<Outcome>,
+}
+
+/// Repeatedly calls `poll` until it yields a terminal update or `timeout` elapses.
+///
+/// Each update which differs from the previously printed one is written to `stdout` as a single
+/// line of JSON.  On timeout, a final `"timeout"` line is written.
+fn watch<F>(poll_interval: Duration, timeout: Duration, mut poll: F) -> Result<Outcome, Error>
+where
+    F: FnMut() -> Result<Update, Error>,
+{
+    let deadline = Instant::now() + timeout;
+    let mut last_line = None;
+    loop {
+        let update = poll()?;
+        let line = json!({ "status": update.status, "result": update.result }).to_string();
+        if last_line.as_ref() != Some(&line) {
+            println!("{}", line);
+            last_line = Some(line);
+        }
+        if let Some(outcome) = update.outcome {
+            return Ok(outcome);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            println!("{}", json!({ "status": "timeout", "result": Value::Null }));
+            return Ok(Outcome::TimedOut);
+        }
+        thread::sleep(poll_interval.min(deadline - now));
+    }
+}
@@ -0,0 +1,118 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::Error;
+use casper_node::{rpcs::chain::GetBlockResult, types::Block};
+
+use super::{exit_code, poll_interval, timeout, Outcome, Update};
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    UntilHeight,
+    PollInterval,
+    Timeout,
+    ExitCode,
+}
+
+/// Handles providing the arg for and retrieval of the block height at which to stop watching.
+mod until_height {
+    use super::*;
+
+    const ARG_NAME: &str = "until-height";
+    const ARG_VALUE_NAME: &str = "INTEGER";
+    const ARG_HELP: &str =
+        "If provided, stop watching once a block at or above this height has been added to the \
+        chain.  If not provided, new blocks are printed until the timeout elapses";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::UntilHeight as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Result<Option<u64>, Error> {
+        matches
+            .value_of(ARG_NAME)
+            .map(|value| {
+                value
+                    .parse::<u64>()
+                    .map_err(|error| Error::FailedToParseInt(ARG_NAME, error))
+            })
+            .transpose()
+    }
+}
+
+pub struct WatchBlock;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for WatchBlock {
+    const NAME: &'static str = "watch-block";
+    const ABOUT: &'static str =
+        "Polls the network for the latest block, printing each new block as a line of JSON";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(until_height::arg())
+            .arg(poll_interval::arg(DisplayOrder::PollInterval as usize))
+            .arg(timeout::arg(DisplayOrder::Timeout as usize))
+            .arg(exit_code::arg(DisplayOrder::ExitCode as usize))
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let maybe_until_height = until_height::get(matches)?;
+        let poll_interval = poll_interval::get(matches)?;
+        let timeout = timeout::get(matches)?;
+        let use_exit_code = exit_code::get(matches);
+
+        let outcome = super::watch(poll_interval, timeout, || {
+            let response =
+                casper_client::get_block(maybe_rpc_id, node_address, verbosity_level, "")?;
+            let result = response
+                .get_result()
+                .cloned()
+                .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+            let get_block_result: GetBlockResult = serde_json::from_value(result.clone())?;
+
+            let reached_height = match (get_block_result.block, maybe_until_height) {
+                (Some(json_block), Some(until_height)) => {
+                    Block::from(json_block).height() >= until_height
+                }
+                _ => false,
+            };
+            let (status, outcome) = if reached_height {
+                ("reached_height", Some(Outcome::Succeeded))
+            } else {
+                ("latest_block", None)
+            };
+
+            Ok(Update {
+                status,
+                result,
+                outcome,
+            })
+        })?;
+
+        // Without a target height, running until the timeout is the expected way for this
+        // command to finish.
+        let outcome = match (outcome, maybe_until_height) {
+            (Outcome::TimedOut, None) => Outcome::Succeeded,
+            (outcome, _) => outcome,
+        };
+        Ok(Success::Exit(outcome.exit_code(use_exit_code)))
+    }
+}
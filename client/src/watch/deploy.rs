@@ -0,0 +1,131 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::Value;
+
+use casper_client::Error;
+use casper_node::{rpcs::info::GetDeployResult, types::Timestamp};
+use casper_types::ExecutionResult;
+
+use super::{exit_code, poll_interval, timeout, Outcome, Update};
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    DeployHash,
+    PollInterval,
+    Timeout,
+    ExitCode,
+}
+
+/// Handles providing the arg for and retrieval of the deploy hash.
+mod deploy_hash {
+    use super::*;
+
+    const ARG_NAME: &str = "deploy-hash";
+    const ARG_VALUE_NAME: &str = "HEX STRING";
+    const ARG_HELP: &str = "Hex-encoded deploy hash";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DeployHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+pub struct WatchDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for WatchDeploy {
+    const NAME: &'static str = "watch-deploy";
+    const ABOUT: &'static str =
+        "Polls the network for a deploy, printing each change of its state as a line of JSON \
+        until it has been executed or has expired";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(deploy_hash::arg())
+            .arg(poll_interval::arg(DisplayOrder::PollInterval as usize))
+            .arg(timeout::arg(DisplayOrder::Timeout as usize))
+            .arg(exit_code::arg(DisplayOrder::ExitCode as usize))
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let deploy_hash = deploy_hash::get(matches);
+        let poll_interval = poll_interval::get(matches)?;
+        let timeout = timeout::get(matches)?;
+        let use_exit_code = exit_code::get(matches);
+
+        let outcome = super::watch(poll_interval, timeout, || {
+            match casper_client::get_deploy(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                deploy_hash,
+            ) {
+                Ok(response) => {
+                    let result = response
+                        .get_result()
+                        .cloned()
+                        .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+                    deploy_update(result)
+                }
+                // The node doesn't know about the deploy (yet), e.g. it hasn't been gossiped to
+                // this node.  Keep polling until it shows up or the timeout elapses.
+                Err(Error::ResponseIsError(error)) => Ok(Update {
+                    status: "not_found",
+                    result: serde_json::to_value(&error)?,
+                    outcome: None,
+                }),
+                Err(error) => Err(error),
+            }
+        })?;
+
+        Ok(Success::Exit(outcome.exit_code(use_exit_code)))
+    }
+}
+
+/// Classifies a `GetDeployResult` given as JSON.
+fn deploy_update(result: Value) -> Result<Update, Error> {
+    let get_deploy_result: GetDeployResult = serde_json::from_value(result.clone())?;
+
+    let (status, outcome) = if get_deploy_result.execution_results.is_empty() {
+        if get_deploy_result.deploy.header().expired(Timestamp::now()) {
+            ("expired", Some(Outcome::Expired))
+        } else {
+            ("pending", None)
+        }
+    } else if get_deploy_result
+        .execution_results
+        .iter()
+        .all(|json_result| matches!(json_result.result, ExecutionResult::Success { .. }))
+    {
+        ("executed", Some(Outcome::Succeeded))
+    } else {
+        ("failed", Some(Outcome::ExecutionFailed))
+    };
+
+    Ok(Update {
+        status,
+        result,
+        outcome,
+    })
+}
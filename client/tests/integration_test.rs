@@ -9,16 +9,16 @@ use tower::builder::ServiceBuilder;
 use warp::{Filter, Rejection};
 use warp_json_rpc::Builder;
 
-use casper_node::crypto::Error as CryptoError;
+use casper_node::{crypto::Error as CryptoError, types::TimeDiff};
 use hex::FromHexError;
 
-use casper_client::{DeployStrParams, Error, PaymentStrParams, SessionStrParams};
+use casper_client::{ChainNameCheck, DeployStrParams, Error, PaymentStrParams, SessionStrParams};
 use casper_node::rpcs::{
-    account::{PutDeploy, PutDeployParams},
+    account::{self, PutDeploy, PutDeployParams},
     chain::{GetStateRootHash, GetStateRootHashParams},
-    info::{GetDeploy, GetDeployParams},
+    info::{GetDeploy, GetDeployParams, GetStatus},
     state::{GetBalance, GetBalanceParams},
-    RpcWithOptionalParams, RpcWithParams,
+    ErrorCode, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
 };
 
 const VALID_PURSE_UREF: &str =
@@ -41,6 +41,48 @@ where
         .map(|builder: Builder, _params: P| builder.success(()).unwrap())
 }
 
+/// Mimics the node's handling of "account_put_deploy", rejecting deploys whose time-to-live
+/// exceeds `max_ttl`.
+fn put_deploy_filter(
+    max_ttl: TimeDiff,
+) -> impl Filter<Extract = (Response<Body>,), Error = Rejection> + Copy {
+    warp_json_rpc::filters::json_rpc()
+        .and(warp_json_rpc::filters::method(PutDeploy::METHOD))
+        .and(warp_json_rpc::filters::params::<PutDeployParams>())
+        .map(move |builder: Builder, params: PutDeployParams| {
+            let validation = account::validate_time_to_live(&params.deploy, max_ttl);
+            match validation {
+                Ok(()) => builder.success(()).unwrap(),
+                Err(error) => builder.error(error).unwrap(),
+            }
+        })
+}
+
+/// Mimics the node's handling of "info_get_status" and "account_put_deploy" for a node on the
+/// chain named `chain_name`, rejecting deploys for any other chain.
+fn chain_name_filter(
+    chain_name: &'static str,
+) -> impl Filter<Extract = (Response<Body>,), Error = Rejection> + Copy {
+    let get_status = warp_json_rpc::filters::json_rpc()
+        .and(warp_json_rpc::filters::method(GetStatus::METHOD))
+        .map(move |builder: Builder| {
+            builder
+                .success(serde_json::json!({ "chainspec_name": chain_name }))
+                .unwrap()
+        });
+    let put_deploy = warp_json_rpc::filters::json_rpc()
+        .and(warp_json_rpc::filters::method(PutDeploy::METHOD))
+        .and(warp_json_rpc::filters::params::<PutDeployParams>())
+        .map(move |builder: Builder, params: PutDeployParams| {
+            if params.deploy.header().chain_name() == chain_name {
+                builder.success(()).unwrap()
+            } else {
+                builder.error(warp_json_rpc::Error::INVALID_PARAMS).unwrap()
+            }
+        });
+    get_status.or(put_deploy).unify()
+}
+
 fn test_filter_without_params(
     method: &'static str,
 ) -> impl Filter<Extract = (Response<Body>,), Error = Rejection> + Copy {
@@ -188,6 +230,26 @@ impl MockServerHandle {
         .map_err(ErrWrapper)
     }
 
+    fn put_deploy_with_chain_name_check(
+        &self,
+        deploy_params: DeployStrParams,
+        session_params: SessionStrParams,
+        payment_params: PaymentStrParams,
+        chain_name_check: ChainNameCheck,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::put_deploy_with_chain_name_check(
+            "1",
+            &self.url(),
+            0,
+            deploy_params,
+            session_params,
+            payment_params,
+            chain_name_check,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
     fn send_deploy_file(&self, input_path: &str) -> Result<(), ErrWrapper> {
         casper_client::send_deploy_file("1", &self.url(), 0, input_path)
             .map(|_| ())
@@ -199,6 +261,12 @@ impl MockServerHandle {
             .map(|_| ())
             .map_err(ErrWrapper)
     }
+
+    fn get_validator_changes(&self) -> Result<(), ErrWrapper> {
+        casper_client::get_validator_changes("1", &self.url(), 0)
+            .map(|_| ())
+            .map_err(ErrWrapper)
+    }
 }
 
 impl Drop for MockServerHandle {
@@ -300,24 +368,20 @@ mod get_balance {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
             server_handle.get_balance("", ""),
-            Err(Error::CryptoError {
-                context: "state_root_hash",
-                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
-            }
-            .into())
+            Err(Error::FailedToParseURef("purse_uref", URefFromStrError::InvalidPrefix).into())
         );
     }
 
     #[tokio::test(threaded_scheduler)]
-    async fn should_fail_with_empty_state_root_hash() {
+    async fn should_succeed_with_empty_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
             server_handle.get_balance("", VALID_PURSE_UREF),
-            Err(Error::CryptoError {
-                context: "state_root_hash",
-                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
-            }
-            .into())
+            // NOTE: this "success" means that we then fail to validate the response, but that
+            // is outside the scope of this test.
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
         );
     }
 
@@ -495,6 +559,8 @@ mod get_item {
 mod get_deploy {
     use super::*;
 
+    use casper_types::TransferFromStrError;
+
     #[tokio::test(threaded_scheduler)]
     async fn should_succeed_with_valid_hash() {
         let server_handle = MockServerHandle::spawn::<GetDeployParams>(GetDeploy::METHOD);
@@ -508,14 +574,13 @@ mod get_deploy {
     #[tokio::test(threaded_scheduler)]
     async fn should_fail_with_invalid_hash() {
         let server_handle = MockServerHandle::spawn::<GetDeployParams>(GetDeploy::METHOD);
-        assert_eq!(
-            server_handle.get_deploy("012345",),
-            Err(Error::CryptoError {
-                context: "deploy_hash",
-                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
-            }
-            .into())
-        );
+        assert!(matches!(
+            server_handle.get_deploy("012345"),
+            Err(ErrWrapper(Error::FailedToParseDeployHash(
+                "deploy_hash",
+                TransferFromStrError::Length(_)
+            )))
+        ));
     }
 }
 
@@ -531,6 +596,18 @@ mod get_auction_info {
     }
 }
 
+mod get_validator_changes {
+    use super::*;
+
+    use casper_node::rpcs::{info::GetValidatorChanges, RpcWithoutParams};
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed() {
+        let server_handle = MockServerHandle::spawn_without_params(GetValidatorChanges::METHOD);
+        assert_eq!(server_handle.get_validator_changes(), Ok(()));
+    }
+}
+
 mod make_deploy {
     use super::*;
 
@@ -794,6 +871,128 @@ mod put_deploy {
             Ok(())
         );
     }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_accept_deploy_within_max_ttl() {
+        let server_handle = MockServerHandle::spawn_with_filter(
+            put_deploy_filter(TimeDiff::from_seconds(60)),
+            DEFAULT_RATE_LIMIT,
+            DEFAULT_RATE_PER,
+        );
+        assert_eq!(
+            server_handle.put_deploy(
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_surface_rejection_of_excessive_ttl() {
+        let server_handle = MockServerHandle::spawn_with_filter(
+            put_deploy_filter(TimeDiff::from_seconds(60)),
+            DEFAULT_RATE_LIMIT,
+            DEFAULT_RATE_PER,
+        );
+        let deploy_params = DeployStrParams {
+            ttl: "2h",
+            ..deploy_params::test_data_valid()
+        };
+        let result = server_handle.put_deploy(
+            deploy_params,
+            session_params::test_data_with_package_hash(),
+            payment_params::test_data_with_name(),
+        );
+        match result {
+            Err(ErrWrapper(Error::ResponseIsError(error))) => {
+                assert_eq!(error.code, ErrorCode::ExcessiveTimeToLive as i64);
+                assert!(
+                    error.message.contains("exceeds limit of 1m"),
+                    "unexpected message: {}",
+                    error.message
+                );
+            }
+            other => panic!("expected the deploy to be rejected, got {:?}", other),
+        }
+    }
+
+    const OTHER_CHAIN_NAME: &str = "casper-test-chain-name-2";
+
+    fn put_deploy_to_other_chain(chain_name_check: ChainNameCheck) -> Result<(), ErrWrapper> {
+        let server_handle = MockServerHandle::spawn_with_filter(
+            chain_name_filter(OTHER_CHAIN_NAME),
+            DEFAULT_RATE_LIMIT,
+            DEFAULT_RATE_PER,
+        );
+        server_handle.put_deploy_with_chain_name_check(
+            deploy_params::test_data_valid(),
+            session_params::test_data_with_package_hash(),
+            payment_params::test_data_with_name(),
+            chain_name_check,
+        )
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_send_put_deploy_for_matching_chain_name() {
+        let server_handle = MockServerHandle::spawn_with_filter(
+            chain_name_filter(deploy_params::test_data_valid().chain_name),
+            DEFAULT_RATE_LIMIT,
+            DEFAULT_RATE_PER,
+        );
+        assert_eq!(
+            server_handle.put_deploy_with_chain_name_check(
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name(),
+                ChainNameCheck::Fail,
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_send_put_deploy_with_mismatched_chain_name_when_only_warning() {
+        let result = put_deploy_to_other_chain(ChainNameCheck::Warn);
+        // The deploy is sent as is, so the node rejects it.
+        match result {
+            Err(ErrWrapper(Error::ResponseIsError(_))) => (),
+            other => panic!("expected the node to reject the deploy, got {:?}", other),
+        }
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_send_put_deploy_when_only_warning_and_node_status_unavailable() {
+        // The node doesn't serve its status, so the chain name can't be checked.
+        let server_handle = MockServerHandle::spawn::<PutDeployParams>(PutDeploy::METHOD);
+        assert_eq!(
+            server_handle.put_deploy_with_chain_name_check(
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name(),
+                ChainNameCheck::Warn,
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_put_deploy_with_mismatched_chain_name() {
+        assert_eq!(
+            put_deploy_to_other_chain(ChainNameCheck::Fail),
+            Err(Error::ChainNameMismatch {
+                deploy_chain_name: deploy_params::test_data_valid().chain_name.to_string(),
+                node_chain_name: OTHER_CHAIN_NAME.to_string(),
+            }
+            .into())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fix_put_deploy_with_mismatched_chain_name() {
+        assert_eq!(put_deploy_to_other_chain(ChainNameCheck::Fix), Ok(()));
+    }
 }
 
 mod rate_limit {
@@ -881,3 +1080,46 @@ mod transfer {
         );
     }
 }
+
+mod failover {
+    use super::*;
+
+    use casper_node::rpcs::{state::GetAuctionInfo, RpcWithoutParams};
+
+    /// The address of a port on which nothing is listening.
+    const UNREACHABLE_NODE_ADDRESS: &str = "http://127.0.0.1:1";
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_over_to_next_node_address() {
+        let server_handle = MockServerHandle::spawn_without_params(GetAuctionInfo::METHOD);
+        let node_addresses = format!("{},{}", UNREACHABLE_NODE_ADDRESS, server_handle.url());
+        assert!(casper_client::get_auction_info("1", &node_addresses, 0).is_ok());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_if_no_node_address_is_reachable() {
+        let node_addresses = format!(
+            "{}, {}/",
+            UNREACHABLE_NODE_ADDRESS, UNREACHABLE_NODE_ADDRESS
+        );
+        let error = casper_client::get_auction_info("1", &node_addresses, 0)
+            .expect_err("should fail to get response");
+        assert!(
+            matches!(error, Error::FailedToGetResponse(_)),
+            "unexpected error: {:?}",
+            error
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_without_node_address() {
+        assert_eq!(
+            casper_client::get_auction_info("1", " , ", 0).map_err(ErrWrapper),
+            Err(Error::InvalidArgument(
+                "node_address",
+                "at least one node address must be provided".to_string()
+            )
+            .into())
+        );
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+pub mod authorization;
 pub mod engine_state;
 pub mod execution;
 pub mod resolvers;
@@ -0,0 +1,180 @@
+//! Authorization of the actions of an account by the keys which signed a deploy.
+//!
+//! Every deploy is authorized against the deployment threshold of its account before any of its
+//! code runs, regardless of whether its session is module bytes, stored code or a native transfer.
+//! Key management actions performed by the session are further authorized against the key
+//! management threshold.
+
+use std::collections::BTreeSet;
+
+use thiserror::Error;
+
+use casper_types::account::{AccountHash, ActionType};
+
+use crate::{
+    core::{engine_state, execution},
+    shared::account::Account,
+};
+
+/// Error authorizing an action of an account.
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AuthorizationFailure {
+    /// No authorization keys were given, or some are not associated keys of the account.
+    #[error("authorization keys are not associated with the account")]
+    UnknownKeys,
+    /// The total weight of the authorization keys is below the threshold of the action.
+    #[error("authorization keys have insufficient weight for the action")]
+    InsufficientWeight,
+}
+
+/// Checks that `authorization_keys` may perform an action of the given type on behalf of
+/// `account`.
+///
+/// All the keys must be associated keys of the account, and their total weight must meet the
+/// account's threshold for the action.
+pub fn authorize(
+    account: &Account,
+    authorization_keys: &BTreeSet<AccountHash>,
+    action_type: ActionType,
+) -> Result<(), AuthorizationFailure> {
+    if !account.can_authorize(authorization_keys) {
+        return Err(AuthorizationFailure::UnknownKeys);
+    }
+
+    let meets_threshold = match action_type {
+        ActionType::Deployment => account.can_deploy_with(authorization_keys),
+        ActionType::KeyManagement => account.can_manage_keys_with(authorization_keys),
+    };
+    if !meets_threshold {
+        return Err(AuthorizationFailure::InsufficientWeight);
+    }
+
+    Ok(())
+}
+
+/// Checks that `authorization_keys` may execute a deploy on behalf of `account`.
+pub fn authorize_deploy(
+    account: &Account,
+    authorization_keys: &BTreeSet<AccountHash>,
+) -> Result<(), engine_state::Error> {
+    match authorize(account, authorization_keys, ActionType::Deployment) {
+        Ok(()) => Ok(()),
+        Err(AuthorizationFailure::UnknownKeys) => Err(engine_state::Error::Authorization),
+        Err(AuthorizationFailure::InsufficientWeight) => {
+            Err(execution::Error::DeploymentAuthorizationFailure.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{account::Weight, AccessRights, URef};
+
+    use super::*;
+    use crate::shared::account::{ActionThresholds, AssociatedKeys};
+
+    const IDENTITY_KEY: AccountHash = AccountHash::new([1; 32]);
+    const KEY_1: AccountHash = AccountHash::new([2; 32]);
+    const KEY_2: AccountHash = AccountHash::new([3; 32]);
+    const UNKNOWN_KEY: AccountHash = AccountHash::new([4; 32]);
+
+    /// The associated keys of the test account, with their weights.
+    const KEYS: [(AccountHash, u8); 3] = [(IDENTITY_KEY, 1), (KEY_1, 2), (KEY_2, 3)];
+    const TOTAL_WEIGHT: u8 = 6;
+
+    fn account(deployment: u8, key_management: u8) -> Account {
+        let mut associated_keys = AssociatedKeys::default();
+        for (key, weight) in KEYS.iter() {
+            associated_keys.add_key(*key, Weight::new(*weight)).unwrap();
+        }
+        Account::new(
+            IDENTITY_KEY,
+            Default::default(),
+            URef::new([0; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::new(Weight::new(deployment), Weight::new(key_management)).unwrap(),
+        )
+    }
+
+    /// Returns every subset of the account's associated keys, with its total weight.
+    fn key_subsets() -> Vec<(BTreeSet<AccountHash>, u8)> {
+        (0..1 << KEYS.len())
+            .map(|mask: usize| {
+                KEYS.iter()
+                    .enumerate()
+                    .filter(|(index, _)| mask & (1 << index) != 0)
+                    .fold((BTreeSet::new(), 0), |(mut keys, weight), (_, (key, w))| {
+                        keys.insert(*key);
+                        (keys, weight + w)
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_authorize_by_weight_and_threshold() {
+        for deployment in 0..=TOTAL_WEIGHT + 1 {
+            for key_management in deployment..=TOTAL_WEIGHT + 1 {
+                let account = account(deployment, key_management);
+                for (keys, weight) in key_subsets() {
+                    for (action_type, threshold) in vec![
+                        (ActionType::Deployment, deployment),
+                        (ActionType::KeyManagement, key_management),
+                    ] {
+                        let expected = if keys.is_empty() {
+                            Err(AuthorizationFailure::UnknownKeys)
+                        } else if weight < threshold {
+                            Err(AuthorizationFailure::InsufficientWeight)
+                        } else {
+                            Ok(())
+                        };
+                        assert_eq!(
+                            authorize(&account, &keys, action_type),
+                            expected,
+                            "keys {:?} with weight {}, thresholds {}/{}",
+                            keys,
+                            weight,
+                            deployment,
+                            key_management
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_not_authorize_with_unknown_key() {
+        let account = account(1, 1);
+        for (mut keys, _) in key_subsets() {
+            keys.insert(UNKNOWN_KEY);
+            assert_eq!(
+                authorize(&account, &keys, ActionType::Deployment),
+                Err(AuthorizationFailure::UnknownKeys)
+            );
+            assert_eq!(
+                authorize(&account, &keys, ActionType::KeyManagement),
+                Err(AuthorizationFailure::UnknownKeys)
+            );
+        }
+    }
+
+    #[test]
+    fn should_map_deploy_authorization_failures() {
+        let account = account(3, 3);
+        assert!(authorize_deploy(&account, &vec![KEY_2].into_iter().collect()).is_ok());
+        assert!(matches!(
+            authorize_deploy(&account, &vec![UNKNOWN_KEY].into_iter().collect()),
+            Err(engine_state::Error::Authorization)
+        ));
+        assert!(
+            authorize_deploy(&account, &vec![IDENTITY_KEY, KEY_1].into_iter().collect()).is_ok()
+        );
+        assert!(matches!(
+            authorize_deploy(&account, &vec![KEY_1].into_iter().collect()),
+            Err(engine_state::Error::Exec(
+                execution::Error::DeploymentAuthorizationFailure
+            ))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Summaries of the contract packages and contracts installed under an account.
+
+use std::collections::BTreeSet;
+
+use casper_types::{
+    account::AccountHash, Contract, ContractHash, ContractPackage, ContractPackageHash,
+    ContractVersionKey, ProtocolVersion,
+};
+
+use crate::shared::newtypes::Blake2bHash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetAccountContractsRequest {
+    state_hash: Blake2bHash,
+    account_hash: AccountHash,
+}
+
+impl GetAccountContractsRequest {
+    pub fn new(state_hash: Blake2bHash, account_hash: AccountHash) -> Self {
+        GetAccountContractsRequest {
+            state_hash,
+            account_hash,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn account_hash(&self) -> AccountHash {
+        self.account_hash
+    }
+}
+
+/// Summary of a single version of a contract package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSummary {
+    contract_hash: ContractHash,
+    contract_version_key: ContractVersionKey,
+    is_enabled: bool,
+    protocol_version: ProtocolVersion,
+    entry_points: Vec<String>,
+}
+
+impl ContractSummary {
+    pub fn new(
+        contract_hash: ContractHash,
+        contract_version_key: ContractVersionKey,
+        contract_package: &ContractPackage,
+        contract: &Contract,
+    ) -> Self {
+        ContractSummary {
+            contract_hash,
+            contract_version_key,
+            is_enabled: contract_package.is_version_enabled(contract_version_key),
+            protocol_version: contract.protocol_version(),
+            entry_points: contract.entry_points().keys().cloned().collect(),
+        }
+    }
+
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    pub fn contract_version_key(&self) -> ContractVersionKey {
+        self.contract_version_key
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Names of the contract's entry points, in ascending order.
+    pub fn entry_points(&self) -> &[String] {
+        &self.entry_points
+    }
+}
+
+/// Summary of a contract package referenced by an account's named keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractPackageSummary {
+    contract_package_hash: ContractPackageHash,
+    named_keys: BTreeSet<String>,
+    is_locked: bool,
+    groups: Vec<String>,
+    versions: Vec<ContractSummary>,
+}
+
+impl ContractPackageSummary {
+    pub fn new(
+        contract_package_hash: ContractPackageHash,
+        named_keys: BTreeSet<String>,
+        contract_package: &ContractPackage,
+        versions: Vec<ContractSummary>,
+    ) -> Self {
+        ContractPackageSummary {
+            contract_package_hash,
+            named_keys,
+            is_locked: contract_package.is_locked(),
+            groups: contract_package
+                .groups()
+                .keys()
+                .map(|group| group.value().to_string())
+                .collect(),
+            versions,
+        }
+    }
+
+    pub fn contract_package_hash(&self) -> ContractPackageHash {
+        self.contract_package_hash
+    }
+
+    /// Names of the account's named keys referring to the package or to one of its contracts.
+    pub fn named_keys(&self) -> &BTreeSet<String> {
+        &self.named_keys
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    /// Labels of the package's user groups, in ascending order.
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// All versions of the package, including disabled ones, in ascending order.
+    pub fn versions(&self) -> &[ContractSummary] {
+        &self.versions
+    }
+}
+
+#[derive(Debug)]
+pub enum GetAccountContractsResult {
+    RootNotFound,
+    AccountNotFound,
+    Success {
+        contract_packages: Vec<ContractPackageSummary>,
+    },
+}
+
+impl GetAccountContractsResult {
+    pub fn contract_packages(&self) -> Option<&[ContractPackageSummary]> {
+        match self {
+            GetAccountContractsResult::RootNotFound
+            | GetAccountContractsResult::AccountNotFound => None,
+            GetAccountContractsResult::Success { contract_packages } => Some(contract_packages),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::BTreeSet;
 
 use casper_types::{account::AccountHash, DeployHash};
 
-use crate::core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use crate::{core::engine_state::executable_deploy_item::ExecutableDeployItem, shared::gas::Gas};
 
 type GasPrice = u64;
 
@@ -15,6 +15,11 @@ pub struct DeployItem {
     pub gas_price: GasPrice,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    /// Optional upper bound on the total gas spent by payment and session code, regardless of how
+    /// much the payment code paid for.  Any payment in excess of the gas spent is refunded.
+    ///
+    /// Deploys received by the node carry this in the `max_gas` field of their header.
+    pub max_gas: Option<Gas>,
 }
 
 impl DeployItem {
@@ -26,6 +31,7 @@ impl DeployItem {
         gas_price: GasPrice,
         authorization_keys: BTreeSet<AccountHash>,
         deploy_hash: DeployHash,
+        max_gas: Option<Gas>,
     ) -> Self {
         DeployItem {
             address,
@@ -34,6 +40,7 @@ impl DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            max_gas,
         }
     }
 }
@@ -1,22 +1,61 @@
+use crate::shared::gas::Gas;
+
 const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
 
+/// Determines how the cost of executing a deploy is handled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeeHandling {
+    /// Payment code is executed and the cost of the deploy is paid to the block proposer.
+    PayToProposer,
+    /// Payment code is not executed and no fees are charged.  Session code is still limited to
+    /// `session_gas_limit` gas.
+    NoFee {
+        /// The gas limit imposed on session code.
+        session_gas_limit: Gas,
+    },
+}
+
+impl Default for FeeHandling {
+    fn default() -> Self {
+        FeeHandling::PayToProposer
+    }
+}
+
 /// The runtime configuration of the execution engine
 #[derive(Debug, Copy, Clone)]
 pub struct EngineConfig {
     pub(crate) max_query_depth: u64,
+    pub(crate) fee_handling: FeeHandling,
 }
 
 impl Default for EngineConfig {
     fn default() -> Self {
         EngineConfig {
             max_query_depth: DEFAULT_MAX_QUERY_DEPTH,
+            fee_handling: FeeHandling::default(),
         }
     }
 }
 
 impl EngineConfig {
     /// Creates a new engine configuration with provided parameters.
-    pub fn new(max_query_depth: u64) -> EngineConfig {
-        EngineConfig { max_query_depth }
+    pub fn new(max_query_depth: u64, fee_handling: FeeHandling) -> EngineConfig {
+        EngineConfig {
+            max_query_depth,
+            fee_handling,
+        }
+    }
+
+    /// Returns how the cost of executing a deploy is handled.
+    pub fn fee_handling(&self) -> FeeHandling {
+        self.fee_handling
+    }
+
+    /// Returns the session gas limit if fees are disabled, or `None` otherwise.
+    pub(crate) fn no_fee_session_gas_limit(&self) -> Option<Gas> {
+        match self.fee_handling {
+            FeeHandling::PayToProposer => None,
+            FeeHandling::NoFee { session_gas_limit } => Some(session_gas_limit),
+        }
     }
 }
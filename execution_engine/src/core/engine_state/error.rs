@@ -1,7 +1,7 @@
 use datasize::DataSize;
 use thiserror::Error;
 
-use casper_types::{bytesrepr, system::mint, ProtocolVersion};
+use casper_types::{bytesrepr, system::mint, CLValueError, ExecutionError, ProtocolVersion};
 
 use crate::{
     core::{
@@ -83,6 +83,37 @@ impl From<GenesisError> for Error {
     }
 }
 
+impl From<&Error> for ExecutionError {
+    fn from(error: &Error) -> Self {
+        let exec_error = match error {
+            Error::Exec(exec_error) => exec_error,
+            _ => return ExecutionError::Other,
+        };
+        match exec_error {
+            execution::Error::Revert(api_error) => ExecutionError::from(*api_error),
+            execution::Error::SystemContract(system_error) => ExecutionError::from(*system_error),
+            execution::Error::GasLimit => ExecutionError::OutOfGas {
+                checkpoints: Vec::new(),
+                last_host_operation: None,
+            },
+            execution::Error::FunctionNotFound(name) | execution::Error::NoSuchMethod(name) => {
+                ExecutionError::MissingEntryPoint(name.clone())
+            }
+            execution::Error::TypeMismatch(type_mismatch) => ExecutionError::TypeMismatch {
+                expected: type_mismatch.expected.clone(),
+                found: type_mismatch.found.clone(),
+            },
+            execution::Error::CLValue(CLValueError::Type(type_mismatch)) => {
+                ExecutionError::TypeMismatch {
+                    expected: format!("{:?}", type_mismatch.expected),
+                    found: format!("{:?}", type_mismatch.found),
+                }
+            }
+            _ => ExecutionError::Other,
+        }
+    }
+}
+
 impl DataSize for Error {
     const IS_DYNAMIC: bool = true;
 
@@ -21,9 +21,10 @@ use serde::{Deserialize, Serialize};
 use casper_types::{
     bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::{ContractVersion, DEFAULT_ENTRY_POINT_NAME},
+    hashing,
     system::mint::ARG_AMOUNT,
     CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersionKey,
-    EntryPoint, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, U512,
+    DeployTarget, EntryPoint, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, U512,
 };
 
 use super::error;
@@ -140,6 +141,20 @@ impl ExecutableDeployItem {
         matches!(self, ExecutableDeployItem::Transfer { .. })
     }
 
+    /// Returns `true` if this item requests standard payment, i.e. it is empty module bytes
+    /// executed in the payment phase.
+    ///
+    /// Standard payment is executed natively by the host rather than as Wasm, and only reads the
+    /// `amount` argument.
+    pub fn is_standard_payment(&self, phase: Phase) -> bool {
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
+                phase == Phase::Payment && module_bytes.is_empty()
+            }
+            _ => false,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn get_deploy_metadata<R>(
         &self,
@@ -157,13 +172,13 @@ impl ExecutableDeployItem {
     {
         let (contract_package, contract, contract_hash, base_key) = match self {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
-                let is_payment = matches!(phase, Phase::Payment);
-                if is_payment && module_bytes.is_empty() {
+                if self.is_standard_payment(phase) {
                     return Ok(DeployMetadata::System {
                         base_key: account.account_hash().into(),
                         contract: Contract::default(),
                         contract_package: ContractPackage::default(),
                         entry_point: EntryPoint::default(),
+                        target: DeployTarget::StandardPayment,
                     });
                 }
 
@@ -172,6 +187,9 @@ impl ExecutableDeployItem {
                     module,
                     contract_package: ContractPackage::default(),
                     entry_point: EntryPoint::default(),
+                    target: DeployTarget::ModuleBytes {
+                        module_hash: hashing::blake2b256(module_bytes),
+                    },
                 });
             }
             ExecutableDeployItem::StoredContractByHash { .. }
@@ -270,6 +288,11 @@ impl ExecutableDeployItem {
                 error::Error::Exec(execution::Error::NoSuchMethod(entry_point_name.to_owned()))
             })?;
 
+        let target = DeployTarget::StoredContract {
+            contract_hash: contract_hash.into(),
+            entry_point: entry_point_name.to_owned(),
+        };
+
         if protocol_data
             .system_contracts()
             .contains(&contract_hash.into())
@@ -279,6 +302,7 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                target,
             });
         }
 
@@ -293,6 +317,7 @@ impl ExecutableDeployItem {
                 module,
                 contract_package,
                 entry_point,
+                target,
             }),
             EntryPointType::Contract => Ok(DeployMetadata::Contract {
                 module,
@@ -300,6 +325,7 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                target,
             }),
         }
     }
@@ -683,6 +709,7 @@ pub enum DeployMetadata {
         module: Module,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        target: DeployTarget,
     },
     Contract {
         // Contract hash
@@ -691,16 +718,27 @@ pub enum DeployMetadata {
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        target: DeployTarget,
     },
     System {
         base_key: Key,
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        target: DeployTarget,
     },
 }
 
 impl DeployMetadata {
+    /// Returns the code resolved to be invoked, as recorded in the deploy's `DeployInfo`.
+    pub fn target(&self) -> &DeployTarget {
+        match self {
+            DeployMetadata::Session { target, .. }
+            | DeployMetadata::Contract { target, .. }
+            | DeployMetadata::System { target, .. } => target,
+        }
+    }
+
     pub fn take_module(self) -> Option<Module> {
         match self {
             DeployMetadata::System { .. } => None,
@@ -1,9 +1,9 @@
 use std::mem;
 
-use casper_types::{ProtocolVersion, PublicKey, SecretKey};
+use casper_types::{Key, ProtocolVersion, PublicKey, SecretKey};
 
 use super::{deploy_item::DeployItem, execution_result::ExecutionResult};
-use crate::shared::newtypes::Blake2bHash;
+use crate::shared::{additive_map::AdditiveMap, newtypes::Blake2bHash, transform::Transform};
 
 #[derive(Debug)]
 pub struct ExecuteRequest {
@@ -12,6 +12,9 @@ pub struct ExecuteRequest {
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
     pub protocol_version: ProtocolVersion,
     pub proposer: PublicKey,
+    /// Effects which are yet to be committed on top of `parent_state_hash`, but which the deploys
+    /// should already observe.
+    pub pending_effects: AdditiveMap<Key, Transform>,
 }
 
 impl ExecuteRequest {
@@ -28,9 +31,17 @@ impl ExecuteRequest {
             deploys,
             protocol_version,
             proposer,
+            pending_effects: AdditiveMap::new(),
         }
     }
 
+    /// Executes the deploys as if `pending_effects` had already been committed on top of
+    /// `parent_state_hash`.
+    pub fn with_pending_effects(mut self, pending_effects: AdditiveMap<Key, Transform>) -> Self {
+        self.pending_effects = pending_effects;
+        self
+    }
+
     pub fn take_deploys(&mut self) -> Vec<Result<DeployItem, ExecutionResult>> {
         mem::replace(&mut self.deploys, vec![])
     }
@@ -49,6 +60,7 @@ impl Default for ExecuteRequest {
             deploys: vec![],
             protocol_version: Default::default(),
             proposer,
+            pending_effects: AdditiveMap::new(),
         }
     }
 }
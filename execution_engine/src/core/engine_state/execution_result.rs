@@ -1,9 +1,13 @@
 use std::collections::VecDeque;
 
-use casper_types::{bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, Key, TransferAddr};
+use casper_types::{
+    bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, ContractEvent, ExecutionError,
+    FeeBreakdown, Key, TransferAddr, U512,
+};
 
 use super::{error, execution_effect::ExecutionEffect, op::Op};
 use crate::{
+    core::execution::GasCheckpoints,
     shared::{
         additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
         stored_value::StoredValue, transform::Transform,
@@ -51,12 +55,22 @@ pub enum ExecutionResult {
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
         cost: Gas,
+        /// The host function calls made before the failure, reported if gas ran out.
+        gas_checkpoints: GasCheckpoints,
+        /// How the payment for the deploy was settled, if it was settled.
+        fee_breakdown: Option<FeeBreakdown>,
     },
     /// Execution was finished successfully
     Success {
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
         cost: Gas,
+        /// The value returned via `runtime::ret()` by the top-level entry point, if any.
+        ret: Option<CLValue>,
+        /// The events emitted via `runtime::emit_event()`, in order of emission.
+        events: Vec<ContractEvent>,
+        /// How the payment for the deploy was settled, if it was settled.
+        fee_breakdown: Option<FeeBreakdown>,
     },
 }
 
@@ -66,6 +80,9 @@ impl Default for ExecutionResult {
             effect: ExecutionEffect::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            ret: None,
+            events: Vec::default(),
+            fee_breakdown: None,
         }
     }
 }
@@ -92,6 +109,8 @@ impl ExecutionResult {
             effect: Default::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            gas_checkpoints: Default::default(),
+            fee_breakdown: None,
         }
     }
 
@@ -145,19 +164,31 @@ impl ExecutionResult {
                 error,
                 effect,
                 transfers,
+                gas_checkpoints,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                gas_checkpoints,
+                fee_breakdown,
             },
             ExecutionResult::Success {
-                effect, transfers, ..
+                effect,
+                transfers,
+                ret,
+                events,
+                fee_breakdown,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                ret,
+                events,
+                fee_breakdown,
             },
         }
     }
@@ -168,19 +199,31 @@ impl ExecutionResult {
                 error,
                 cost,
                 transfers,
+                gas_checkpoints,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                gas_checkpoints,
+                fee_breakdown,
             },
             ExecutionResult::Success {
-                cost, transfers, ..
+                cost,
+                transfers,
+                ret,
+                events,
+                fee_breakdown,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                ret,
+                events,
+                fee_breakdown,
             },
         }
     }
@@ -191,17 +234,92 @@ impl ExecutionResult {
                 error,
                 effect,
                 cost,
+                gas_checkpoints,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                gas_checkpoints,
+                fee_breakdown,
             },
-            ExecutionResult::Success { cost, effect, .. } => ExecutionResult::Success {
+            ExecutionResult::Success {
+                cost,
+                effect,
+                ret,
+                events,
+                fee_breakdown,
+                ..
+            } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                ret,
+                events,
+                fee_breakdown,
+            },
+        }
+    }
+
+    /// Returns the value returned by the top-level entry point, if execution succeeded and a value
+    /// was returned.
+    pub fn ret(&self) -> Option<&CLValue> {
+        match self {
+            ExecutionResult::Failure { .. } => None,
+            ExecutionResult::Success { ret, .. } => ret.as_ref(),
+        }
+    }
+
+    /// Returns the events emitted during execution.  These are only reported if execution
+    /// succeeded.
+    pub fn events(&self) -> &[ContractEvent] {
+        match self {
+            ExecutionResult::Failure { .. } => &[],
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
+    /// Returns how the payment for the deploy was settled, if it was settled.
+    pub fn fee_breakdown(&self) -> Option<&FeeBreakdown> {
+        match self {
+            ExecutionResult::Failure { fee_breakdown, .. } => fee_breakdown.as_ref(),
+            ExecutionResult::Success { fee_breakdown, .. } => fee_breakdown.as_ref(),
+        }
+    }
+
+    pub fn with_fee_breakdown(self, fee_breakdown: Option<FeeBreakdown>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
+                cost,
+                gas_checkpoints,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
+                cost,
+                gas_checkpoints,
+                fee_breakdown,
+            },
+            ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                ret,
+                events,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                ret,
+                events,
+                fee_breakdown,
             },
         }
     }
@@ -266,11 +384,20 @@ impl ExecutionResult {
             proposer_main_purse_balance_key,
         )?;
         let transfers = Vec::default();
+        // The whole of the maximum payment is forfeited to the proposer.
+        let fee_breakdown = FeeBreakdown {
+            payment_amount: max_payment_cost.value(),
+            gas_used: gas_cost.value(),
+            refund: U512::zero(),
+            proposer_reward: max_payment_cost.value(),
+        };
         Ok(ExecutionResult::Failure {
             error,
             effect,
             transfers,
             cost: gas_cost,
+            gas_checkpoints: Default::default(),
+            fee_breakdown: Some(fee_breakdown),
         })
     }
 
@@ -290,22 +417,42 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 effect,
                 transfers,
                 cost,
+                events,
+                fee_breakdown,
+                ..
             } => casper_types::ExecutionResult::Success {
                 effect: effect.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
+                events: events.clone(),
+                fee_breakdown: *fee_breakdown,
             },
             ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
-            } => casper_types::ExecutionResult::Failure {
-                effect: effect.into(),
-                transfers: transfers.clone(),
-                cost: cost.value(),
-                error_message: error.to_string(),
-            },
+                gas_checkpoints,
+                fee_breakdown,
+            } => {
+                let mut execution_error = ExecutionError::from(error);
+                if let ExecutionError::OutOfGas {
+                    checkpoints,
+                    last_host_operation,
+                } = &mut execution_error
+                {
+                    *checkpoints = gas_checkpoints.checkpoints().collect();
+                    *last_host_operation = gas_checkpoints.last_completed().map(String::from);
+                }
+                casper_types::ExecutionResult::Failure {
+                    effect: effect.into(),
+                    transfers: transfers.clone(),
+                    cost: cost.value(),
+                    error_message: error.to_string(),
+                    error: execution_error,
+                    fee_breakdown: *fee_breakdown,
+                }
+            }
         }
     }
 }
@@ -321,6 +468,7 @@ pub struct ExecutionResultBuilder {
     payment_execution_result: Option<ExecutionResult>,
     session_execution_result: Option<ExecutionResult>,
     finalize_execution_result: Option<ExecutionResult>,
+    fee_breakdown: Option<FeeBreakdown>,
 }
 
 impl Default for ExecutionResultBuilder {
@@ -329,6 +477,7 @@ impl Default for ExecutionResultBuilder {
             payment_execution_result: None,
             session_execution_result: None,
             finalize_execution_result: None,
+            fee_breakdown: None,
         }
     }
 }
@@ -359,6 +508,24 @@ impl ExecutionResultBuilder {
         self
     }
 
+    /// Records how finalizing payment settled the deploy's payment purse, as returned by the
+    /// handle payment contract's `finalize_payment`.
+    ///
+    /// Should be called once the payment and session execution results have been set.
+    pub fn set_payment_settlement(
+        &mut self,
+        refund: U512,
+        proposer_reward: U512,
+    ) -> &mut ExecutionResultBuilder {
+        self.fee_breakdown = Some(FeeBreakdown {
+            payment_amount: refund + proposer_reward,
+            gas_used: self.total_cost().value(),
+            refund,
+            proposer_reward,
+        });
+        self
+    }
+
     pub fn total_cost(&self) -> Gas {
         let payment_cost = self
             .payment_execution_result
@@ -381,6 +548,15 @@ impl ExecutionResultBuilder {
             .unwrap_or_default()
     }
 
+    /// Returns the events emitted by payment followed by those emitted by session.
+    pub fn events(&self) -> Vec<ContractEvent> {
+        self.payment_execution_result
+            .iter()
+            .chain(self.session_execution_result.iter())
+            .flat_map(|result| result.events().iter().cloned())
+            .collect()
+    }
+
     pub fn build<R: StateReader<Key, StoredValue>>(
         self,
         reader: &R,
@@ -388,13 +564,23 @@ impl ExecutionResultBuilder {
     ) -> Result<ExecutionResult, ExecutionResultBuilderError> {
         let transfers = self.transfers();
         let cost = self.total_cost();
+        let events = self.events();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
 
+        let session_ret = self
+            .session_execution_result
+            .as_ref()
+            .and_then(ExecutionResult::ret)
+            .cloned();
+
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
             transfers,
             cost,
+            ret: session_ret,
+            events,
+            fee_breakdown: None,
         };
 
         match self.payment_execution_result {
@@ -438,7 +624,9 @@ impl ExecutionResultBuilder {
         // Remove redundant writes to allow more opportunity to commute
         let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
 
-        Ok(ret.with_effect(reduced_effect))
+        Ok(ret
+            .with_effect(reduced_effect)
+            .with_fee_breakdown(self.fee_breakdown))
     }
 
     fn add_effects(
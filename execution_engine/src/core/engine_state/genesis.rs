@@ -14,37 +14,44 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::{
-        ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters,
+        ContractPackageStatus, ContractVersionInfo, ContractVersions, DisabledVersions, Groups,
+        NamedKeys, Parameters,
     },
     runtime_args,
     system::{
         auction::{
-            Bid, Bids, DelegationRate, Delegator, SeigniorageRecipient, SeigniorageRecipients,
-            SeigniorageRecipientsSnapshot, ValidatorWeights, ARG_DELEGATION_RATE, ARG_DELEGATOR,
-            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_VALIDATOR,
+            Bid, Bids, DelegationRate, Delegator, EraVestingSchedule, SeigniorageRecipient,
+            SeigniorageRecipients, SeigniorageRecipientsSnapshot, StakingStats, ValidatorWeights,
+            ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_ICON_HASH,
+            ARG_NAME, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_SOURCE_PURSE, ARG_URL, ARG_VALIDATOR,
             ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY, DELEGATION_RATE_DENOMINATOR,
             ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY, INITIAL_ERA_END_TIMESTAMP_MILLIS,
-            INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY, METHOD_ACTIVATE_BID, METHOD_ADD_BID,
-            METHOD_DELEGATE, METHOD_DISTRIBUTE, METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID,
-            METHOD_RUN_AUCTION, METHOD_SLASH, METHOD_UNDELEGATE, METHOD_WITHDRAW_BID,
-            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            INITIAL_ERA_ID, MAX_DELEGATION_RATE_CHANGE_KEY, MAX_DELEGATION_RATE_KEY,
+            MAX_DELEGATORS_PER_VALIDATOR_KEY, METHOD_ACTIVATE_BID, METHOD_ADD_BID,
+            METHOD_CANCEL_DELEGATOR_UNBOND, METHOD_CANCEL_VALIDATOR_UNBOND, METHOD_DELEGATE,
+            METHOD_DELEGATE_FROM_CONTRACT, METHOD_DISTRIBUTE, METHOD_GET_BID, METHOD_GET_DELEGATOR,
+            METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID, METHOD_RUN_AUCTION,
+            METHOD_SET_VALIDATOR_INFO, METHOD_SLASH, METHOD_UNDELEGATE,
+            METHOD_UNDELEGATE_FROM_CONTRACT, METHOD_WITHDRAW_BID,
+            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, STAKING_STATS_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         handle_payment::{
             self, ARG_ACCOUNT, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
             METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE,
         },
         mint::{
-            self, ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE, ARG_SOURCE,
-            ARG_TARGET, METHOD_BALANCE, METHOD_CREATE, METHOD_MINT, METHOD_READ_BASE_ROUND_REWARD,
-            METHOD_REDUCE_TOTAL_SUPPLY, METHOD_TRANSFER, ROUND_SEIGNIORAGE_RATE_KEY,
-            TOTAL_SUPPLY_KEY,
+            self, ARG_AMOUNT, ARG_ID, ARG_OWNER_PURSE, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE,
+            ARG_SOURCE, ARG_SPENDER, ARG_TARGET, METHOD_APPROVE, METHOD_BALANCE, METHOD_CREATE,
+            METHOD_MINT, METHOD_READ_BASE_ROUND_REWARD, METHOD_REDUCE_TOTAL_SUPPLY,
+            METHOD_TRANSFER, METHOD_TRANSFER_FROM, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY,
         },
         standard_payment::METHOD_PAY,
     },
-    AccessRights, CLType, CLTyped, CLValue, Contract, ContractHash, ContractPackage,
+    AccessRights, BlockTime, CLType, CLTyped, CLValue, Contract, ContractHash, ContractPackage,
     ContractPackageHash, ContractWasm, ContractWasmHash, DeployHash, EntryPoint, EntryPointAccess,
     EntryPointType, EntryPoints, Key, Parameter, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
-    SecretKey, URef, U512,
+    SecretKey, URef, URefAddr, U512,
 };
 
 use crate::{
@@ -125,10 +132,75 @@ enum GenesisAccountTag {
     Delegator = 2,
 }
 
+/// Era-based vesting of the stake of a genesis validator and of its genesis delegators.
+///
+/// The whole stake is locked for `cliff_eras` eras after genesis and then released in
+/// `release_eras` equal installments, one per era.
+#[derive(DataSize, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisVesting {
+    cliff_eras: u64,
+    release_eras: u64,
+}
+
+impl GenesisVesting {
+    pub fn new(cliff_eras: u64, release_eras: u64) -> Self {
+        Self {
+            cliff_eras,
+            release_eras,
+        }
+    }
+
+    pub fn cliff_eras(&self) -> u64 {
+        self.cliff_eras
+    }
+
+    pub fn release_eras(&self) -> u64 {
+        self.release_eras
+    }
+
+    /// Returns the vesting schedule of the given amount staked at genesis.
+    pub fn schedule(&self, staked_amount: U512) -> EraVestingSchedule {
+        EraVestingSchedule::new(
+            INITIAL_ERA_ID,
+            self.cliff_eras,
+            self.release_eras,
+            staked_amount,
+        )
+    }
+}
+
+impl ToBytes for GenesisVesting {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.cliff_eras.to_bytes()?);
+        buffer.extend(self.release_eras.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.cliff_eras.serialized_length() + self.release_eras.serialized_length()
+    }
+}
+
+impl FromBytes for GenesisVesting {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (cliff_eras, remainder) = FromBytes::from_bytes(bytes)?;
+        let (release_eras, remainder) = FromBytes::from_bytes(remainder)?;
+        Ok((GenesisVesting::new(cliff_eras, release_eras), remainder))
+    }
+}
+
+impl Distribution<GenesisVesting> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisVesting {
+        GenesisVesting::new(rng.gen(), rng.gen())
+    }
+}
+
 #[derive(DataSize, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GenesisValidator {
     bonded_amount: Motes,
     delegation_rate: DelegationRate,
+    vesting: Option<GenesisVesting>,
 }
 
 impl ToBytes for GenesisValidator {
@@ -136,11 +208,14 @@ impl ToBytes for GenesisValidator {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
         buffer.extend(self.bonded_amount.to_bytes()?);
         buffer.extend(self.delegation_rate.to_bytes()?);
+        buffer.extend(self.vesting.to_bytes()?);
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.bonded_amount.serialized_length() + self.delegation_rate.serialized_length()
+        self.bonded_amount.serialized_length()
+            + self.delegation_rate.serialized_length()
+            + self.vesting.serialized_length()
     }
 }
 
@@ -148,9 +223,11 @@ impl FromBytes for GenesisValidator {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (bonded_amount, remainder) = FromBytes::from_bytes(bytes)?;
         let (delegation_rate, remainder) = FromBytes::from_bytes(remainder)?;
+        let (vesting, remainder) = FromBytes::from_bytes(remainder)?;
         let genesis_validator = GenesisValidator {
             bonded_amount,
             delegation_rate,
+            vesting,
         };
         Ok((genesis_validator, remainder))
     }
@@ -161,9 +238,16 @@ impl GenesisValidator {
         Self {
             bonded_amount,
             delegation_rate,
+            vesting: None,
         }
     }
 
+    /// Sets the era-based vesting of the validator's stake, replacing the locked funds period.
+    pub fn with_vesting(mut self, vesting: GenesisVesting) -> Self {
+        self.vesting = Some(vesting);
+        self
+    }
+
     pub fn bonded_amount(&self) -> Motes {
         self.bonded_amount
     }
@@ -171,14 +255,23 @@ impl GenesisValidator {
     pub fn delegation_rate(&self) -> DelegationRate {
         self.delegation_rate
     }
+
+    pub fn vesting(&self) -> Option<GenesisVesting> {
+        self.vesting
+    }
 }
 
 impl Distribution<GenesisValidator> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisValidator {
         let bonded_amount = Motes::new(rng.gen());
         let delegation_rate = rng.gen();
+        let vesting = rng.gen();
 
-        GenesisValidator::new(bonded_amount, delegation_rate)
+        GenesisValidator {
+            bonded_amount,
+            delegation_rate,
+            vesting,
+        }
     }
 }
 
@@ -541,9 +634,11 @@ pub struct ExecConfig {
     system_config: SystemConfig,
     validator_slots: u32,
     auction_delay: u64,
-    locked_funds_period_millis: u64,
     round_seigniorage_rate: Ratio<u64>,
     unbonding_delay: u64,
+    max_delegation_rate: DelegationRate,
+    max_delegation_rate_change: DelegationRate,
+    max_delegators_per_validator: u32,
     genesis_timestamp_millis: u64,
 }
 
@@ -555,9 +650,11 @@ impl ExecConfig {
         system_config: SystemConfig,
         validator_slots: u32,
         auction_delay: u64,
-        locked_funds_period_millis: u64,
         round_seigniorage_rate: Ratio<u64>,
         unbonding_delay: u64,
+        max_delegation_rate: DelegationRate,
+        max_delegation_rate_change: DelegationRate,
+        max_delegators_per_validator: u32,
         genesis_timestamp_millis: u64,
     ) -> ExecConfig {
         ExecConfig {
@@ -566,9 +663,11 @@ impl ExecConfig {
             system_config,
             validator_slots,
             auction_delay,
-            locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            max_delegation_rate,
+            max_delegation_rate_change,
+            max_delegators_per_validator,
             genesis_timestamp_millis,
         }
     }
@@ -611,10 +710,6 @@ impl ExecConfig {
         self.auction_delay
     }
 
-    pub fn locked_funds_period_millis(&self) -> u64 {
-        self.locked_funds_period_millis
-    }
-
     pub fn round_seigniorage_rate(&self) -> Ratio<u64> {
         self.round_seigniorage_rate
     }
@@ -623,6 +718,18 @@ impl ExecConfig {
         self.unbonding_delay
     }
 
+    pub fn max_delegation_rate(&self) -> DelegationRate {
+        self.max_delegation_rate
+    }
+
+    pub fn max_delegation_rate_change(&self) -> DelegationRate {
+        self.max_delegation_rate_change
+    }
+
+    pub fn max_delegators_per_validator(&self) -> u32 {
+        self.max_delegators_per_validator
+    }
+
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
@@ -642,8 +749,6 @@ impl Distribution<ExecConfig> for Standard {
 
         let auction_delay = rng.gen();
 
-        let locked_funds_period_millis = rng.gen();
-
         let round_seigniorage_rate = Ratio::new(
             rng.gen_range(1..1_000_000_000),
             rng.gen_range(1..1_000_000_000),
@@ -651,6 +756,12 @@ impl Distribution<ExecConfig> for Standard {
 
         let unbonding_delay = rng.gen();
 
+        let max_delegation_rate = rng.gen_range(0..=DELEGATION_RATE_DENOMINATOR);
+
+        let max_delegation_rate_change = rng.gen_range(0..=max_delegation_rate);
+
+        let max_delegators_per_validator = rng.gen();
+
         let genesis_timestamp_millis = rng.gen();
 
         ExecConfig {
@@ -659,9 +770,11 @@ impl Distribution<ExecConfig> for Standard {
             system_config,
             validator_slots,
             auction_delay,
-            locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            max_delegation_rate,
+            max_delegation_rate_change,
+            max_delegators_per_validator,
             genesis_timestamp_millis,
         }
     }
@@ -695,6 +808,9 @@ pub enum GenesisError {
     InvalidDelegatedAmount {
         public_key: PublicKey,
     },
+    TooManyDelegators {
+        validator_public_key: PublicKey,
+    },
 }
 
 pub(crate) struct GenesisInstaller<S>
@@ -832,7 +948,7 @@ where
             named_keys
         };
 
-        let entry_points = self.mint_entry_points();
+        let entry_points = mint_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -859,7 +975,7 @@ where
             named_keys
         };
 
-        let entry_points = self.handle_payment_entry_points();
+        let entry_points = handle_payment_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -872,9 +988,7 @@ where
     }
 
     pub(crate) fn create_auction(&self) -> Result<ContractHash, GenesisError> {
-        let locked_funds_period_millis = self.exec_config.locked_funds_period_millis();
         let auction_delay: u64 = self.exec_config.auction_delay();
-        let genesis_timestamp_millis: u64 = self.exec_config.genesis_timestamp_millis();
 
         let mut named_keys = NamedKeys::new();
 
@@ -916,7 +1030,9 @@ where
                 }
 
                 let delegation_rate = genesis_validator.delegation_rate();
-                if delegation_rate > DELEGATION_RATE_DENOMINATOR {
+                if delegation_rate > DELEGATION_RATE_DENOMINATOR
+                    || delegation_rate > self.exec_config.max_delegation_rate()
+                {
                     return Err(GenesisError::InvalidDelegationRate {
                         public_key,
                         delegation_rate,
@@ -928,16 +1044,25 @@ where
                     staked_amount.value(),
                     DeployHash::new(public_key.to_account_hash().value()),
                 )?;
-                let release_timestamp_millis =
-                    genesis_timestamp_millis + locked_funds_period_millis;
+                let vesting = genesis_validator
+                    .validator()
+                    .and_then(GenesisValidator::vesting);
                 let founding_validator = {
-                    let mut bid = Bid::locked(
-                        public_key,
-                        purse_uref,
-                        staked_amount.value(),
-                        delegation_rate,
-                        release_timestamp_millis,
-                    );
+                    let mut bid = match vesting {
+                        Some(vesting) => Bid::vesting(
+                            public_key,
+                            purse_uref,
+                            staked_amount.value(),
+                            delegation_rate,
+                            vesting.schedule(staked_amount.value()),
+                        ),
+                        None => Bid::unlocked(
+                            public_key,
+                            purse_uref,
+                            staked_amount.value(),
+                            delegation_rate,
+                        ),
+                    };
 
                     // Set up delegator entries attached to genesis validators
                     for (
@@ -953,13 +1078,21 @@ where
                                 DeployHash::new(delegator_public_key.to_account_hash().value()),
                             )?;
 
-                            let delegator = Delegator::locked(
-                                delegator_public_key,
-                                delegator_delegated_amount.value(),
-                                purse_uref,
-                                validator_public_key,
-                                release_timestamp_millis,
-                            );
+                            let delegator = match vesting {
+                                Some(vesting) => Delegator::vesting(
+                                    delegator_public_key,
+                                    delegator_delegated_amount.value(),
+                                    purse_uref,
+                                    validator_public_key,
+                                    vesting.schedule(delegator_delegated_amount.value()),
+                                ),
+                                None => Delegator::unlocked(
+                                    delegator_public_key,
+                                    delegator_delegated_amount.value(),
+                                    purse_uref,
+                                    validator_public_key,
+                                ),
+                            };
 
                             if bid
                                 .delegators_mut()
@@ -974,6 +1107,14 @@ where
                         }
                     }
 
+                    if bid.delegator_count()
+                        > self.exec_config.max_delegators_per_validator() as usize
+                    {
+                        return Err(GenesisError::TooManyDelegators {
+                            validator_public_key: public_key,
+                        });
+                    }
+
                     bid
                 };
 
@@ -1029,6 +1170,21 @@ where
             initial_seigniorage_recipients_uref.into(),
         );
 
+        let staking_stats = StakingStats::from_bids(&validators)
+            .map_err(|_| GenesisError::CLValue(STAKING_STATS_KEY.to_string()))?;
+        let staking_stats_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            staking_stats_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(staking_stats)
+                    .map_err(|_| GenesisError::CLValue(STAKING_STATS_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(STAKING_STATS_KEY.into(), staking_stats_uref.into());
+
         for (validator_public_key, bid) in validators.into_iter() {
             let validator_account_hash = AccountHash::from(&validator_public_key);
             self.tracking_copy.borrow_mut().write(
@@ -1064,37 +1220,72 @@ where
         );
         named_keys.insert(AUCTION_DELAY_KEY.into(), auction_delay_uref.into());
 
-        let locked_funds_period_uref = self
+        let unbonding_delay = self.exec_config.unbonding_delay();
+        let unbonding_delay_uref = self
             .uref_address_generator
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
         self.tracking_copy.borrow_mut().write(
-            locked_funds_period_uref.into(),
+            unbonding_delay_uref.into(),
             StoredValue::CLValue(
-                CLValue::from_t(locked_funds_period_millis)
-                    .map_err(|_| GenesisError::CLValue(LOCKED_FUNDS_PERIOD_KEY.to_string()))?,
+                CLValue::from_t(unbonding_delay)
+                    .map_err(|_| GenesisError::CLValue(UNBONDING_DELAY_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(UNBONDING_DELAY_KEY.into(), unbonding_delay_uref.into());
+
+        let max_delegation_rate = self.exec_config.max_delegation_rate();
+        let max_delegation_rate_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            max_delegation_rate_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(max_delegation_rate)
+                    .map_err(|_| GenesisError::CLValue(MAX_DELEGATION_RATE_KEY.to_string()))?,
             ),
         );
         named_keys.insert(
-            LOCKED_FUNDS_PERIOD_KEY.into(),
-            locked_funds_period_uref.into(),
+            MAX_DELEGATION_RATE_KEY.into(),
+            max_delegation_rate_uref.into(),
         );
 
-        let unbonding_delay = self.exec_config.unbonding_delay();
-        let unbonding_delay_uref = self
+        let max_delegation_rate_change = self.exec_config.max_delegation_rate_change();
+        let max_delegation_rate_change_uref = self
             .uref_address_generator
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
         self.tracking_copy.borrow_mut().write(
-            unbonding_delay_uref.into(),
+            max_delegation_rate_change_uref.into(),
             StoredValue::CLValue(
-                CLValue::from_t(unbonding_delay)
-                    .map_err(|_| GenesisError::CLValue(UNBONDING_DELAY_KEY.to_string()))?,
+                CLValue::from_t(max_delegation_rate_change).map_err(|_| {
+                    GenesisError::CLValue(MAX_DELEGATION_RATE_CHANGE_KEY.to_string())
+                })?,
             ),
         );
-        named_keys.insert(UNBONDING_DELAY_KEY.into(), unbonding_delay_uref.into());
+        named_keys.insert(
+            MAX_DELEGATION_RATE_CHANGE_KEY.into(),
+            max_delegation_rate_change_uref.into(),
+        );
 
-        let entry_points = self.auction_entry_points();
+        let max_delegators_per_validator = self.exec_config.max_delegators_per_validator();
+        let max_delegators_per_validator_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            max_delegators_per_validator_uref.into(),
+            StoredValue::CLValue(CLValue::from_t(max_delegators_per_validator).map_err(|_| {
+                GenesisError::CLValue(MAX_DELEGATORS_PER_VALIDATOR_KEY.to_string())
+            })?),
+        );
+        named_keys.insert(
+            MAX_DELEGATORS_PER_VALIDATOR_KEY.into(),
+            max_delegators_per_validator_uref.into(),
+        );
+
+        let entry_points = auction_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1109,7 +1300,7 @@ where
     pub(crate) fn create_standard_payment(&self) -> ContractHash {
         let named_keys = NamedKeys::new();
 
-        let entry_points = self.standard_payment_entry_points();
+        let entry_points = standard_payment_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1261,7 +1452,13 @@ where
                 Groups::default(),
                 ContractPackageStatus::default(),
             );
-            contract_package.insert_contract_version(protocol_version.value().major, contract_hash);
+            let version_info = ContractVersionInfo::new(
+                contract_hash,
+                BlockTime::new(self.exec_config.genesis_timestamp_millis()),
+                self.virtual_system_account.account_hash(),
+                None,
+            );
+            contract_package.insert_contract_version(protocol_version.value().major, version_info);
             contract_package
         };
 
@@ -1279,260 +1476,382 @@ where
 
         (contract_package_hash, contract_hash)
     }
+}
 
-    fn mint_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
-
-        let entry_point = EntryPoint::new(
-            METHOD_MINT,
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::URef),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_REDUCE_TOTAL_SUPPLY,
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_CREATE,
-            Parameters::new(),
-            CLType::URef,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_BALANCE,
-            vec![Parameter::new(ARG_PURSE, CLType::URef)],
-            CLType::Option(Box::new(CLType::U512)),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_TRANSFER,
-            vec![
-                Parameter::new(ARG_SOURCE, CLType::URef),
-                Parameter::new(ARG_TARGET, CLType::URef),
-                Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
-            ],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_BASE_ROUND_REWARD,
-            Parameters::new(),
-            CLType::U512,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        entry_points
-    }
-
-    fn handle_payment_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
-
-        let get_payment_purse = EntryPoint::new(
-            METHOD_GET_PAYMENT_PURSE,
-            vec![],
-            CLType::URef,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(get_payment_purse);
-
-        let set_refund_purse = EntryPoint::new(
-            METHOD_SET_REFUND_PURSE,
-            vec![Parameter::new(ARG_PURSE, CLType::URef)],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(set_refund_purse);
-
-        let get_refund_purse = EntryPoint::new(
-            METHOD_GET_REFUND_PURSE,
-            vec![],
-            CLType::Option(Box::new(CLType::URef)),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(get_refund_purse);
-
-        let finalize_payment = EntryPoint::new(
-            METHOD_FINALIZE_PAYMENT,
-            vec![
-                Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ACCOUNT, CLType::ByteArray(32)),
-            ],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(finalize_payment);
-
-        entry_points
-    }
+/// Returns the entry points of the mint contract.
+pub(crate) fn mint_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
 
-    fn auction_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
+    let entry_point = EntryPoint::new(
+        METHOD_MINT,
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::URef),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_REDUCE_TOTAL_SUPPLY,
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CREATE,
+        Parameters::new(),
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_BALANCE,
+        vec![Parameter::new(ARG_PURSE, CLType::URef)],
+        CLType::Option(Box::new(CLType::U512)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_TRANSFER,
+        vec![
+            Parameter::new(ARG_SOURCE, CLType::URef),
+            Parameter::new(ARG_TARGET, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_APPROVE,
+        vec![
+            Parameter::new(ARG_OWNER_PURSE, CLType::URef),
+            Parameter::new(ARG_SPENDER, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_TRANSFER_FROM,
+        vec![
+            Parameter::new(ARG_OWNER_PURSE, URefAddr::cl_type()),
+            Parameter::new(ARG_TARGET, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_BASE_ROUND_REWARD,
+        Parameters::new(),
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    entry_points
+}
 
-        let entry_point = EntryPoint::new(
-            METHOD_GET_ERA_VALIDATORS,
-            vec![],
-            Option::<ValidatorWeights>::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ADD_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_WITHDRAW_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_UNDELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_RUN_AUCTION,
-            vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_SLASH,
-            vec![],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DISTRIBUTE,
-            vec![Parameter::new(
-                ARG_REWARD_FACTORS,
-                CLType::Map {
-                    key: Box::new(CLType::PublicKey),
-                    value: Box::new(CLType::U64),
-                },
-            )],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_ERA_ID,
-            vec![],
-            CLType::U64,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ACTIVATE_BID,
-            vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
+/// Returns the entry points of the handle payment contract.
+pub(crate) fn handle_payment_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let get_payment_purse = EntryPoint::new(
+        METHOD_GET_PAYMENT_PURSE,
+        vec![],
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(get_payment_purse);
+
+    let set_refund_purse = EntryPoint::new(
+        METHOD_SET_REFUND_PURSE,
+        vec![Parameter::new(ARG_PURSE, CLType::URef)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(set_refund_purse);
+
+    let get_refund_purse = EntryPoint::new(
+        METHOD_GET_REFUND_PURSE,
+        vec![],
+        CLType::Option(Box::new(CLType::URef)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(get_refund_purse);
+
+    let finalize_payment = EntryPoint::new(
+        METHOD_FINALIZE_PAYMENT,
+        vec![
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ACCOUNT, CLType::ByteArray(32)),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(finalize_payment);
+
+    entry_points
+}
 
-        entry_points
-    }
+/// Returns the entry points of the auction contract.
+pub(crate) fn auction_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let entry_point = EntryPoint::new(
+        METHOD_GET_ERA_VALIDATORS,
+        vec![],
+        Option::<ValidatorWeights>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ADD_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_WITHDRAW_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_UNDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DELEGATE_FROM_CONTRACT,
+        vec![
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+            Parameter::new(ARG_SOURCE_PURSE, URef::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_UNDELEGATE_FROM_CONTRACT,
+        vec![
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_RUN_AUCTION,
+        vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SLASH,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DISTRIBUTE,
+        vec![Parameter::new(
+            ARG_REWARD_FACTORS,
+            CLType::Map {
+                key: Box::new(CLType::PublicKey),
+                value: Box::new(CLType::U64),
+            },
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_ERA_ID,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ACTIVATE_BID,
+        vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CANCEL_VALIDATOR_UNBOND,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CANCEL_DELEGATOR_UNBOND,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_GET_BID,
+        vec![Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type())],
+        Option::<Bid>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_GET_DELEGATOR,
+        vec![
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+        ],
+        Option::<Delegator>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_VALIDATOR_INFO,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_NAME, String::cl_type()),
+            Parameter::new(ARG_URL, String::cl_type()),
+            Parameter::new(ARG_ICON_HASH, Option::<[u8; 32]>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    entry_points
+}
 
-    fn standard_payment_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
+/// Returns the entry points of the standard payment contract.
+pub(crate) fn standard_payment_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
 
-        let entry_point = EntryPoint::new(
-            METHOD_PAY.to_string(),
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U32),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Session,
-        );
-        entry_points.add_entry_point(entry_point);
+    let entry_point = EntryPoint::new(
+        METHOD_PAY.to_string(),
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U32),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    );
+    entry_points.add_entry_point(entry_point);
 
-        entry_points
-    }
+    entry_points
 }
 
 #[cfg(test)]
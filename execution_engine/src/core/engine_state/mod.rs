@@ -1,3 +1,4 @@
+pub mod account_contracts;
 pub mod balance;
 pub mod deploy_item;
 pub mod engine_config;
@@ -11,13 +12,16 @@ pub mod genesis;
 pub mod op;
 pub mod query;
 pub mod run_genesis_request;
+pub mod state_diff;
 pub mod step;
 pub mod system_contract_cache;
+pub mod system_contract_registry;
 mod transfer;
 pub mod upgrade;
 
 use std::{
     cell::RefCell,
+    cmp,
     collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
     iter::FromIterator,
@@ -34,21 +38,27 @@ use casper_types::{
     contracts::NamedKeys,
     system::{
         auction::{
-            EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
-            ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
-            LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            EraValidators, SeigniorageRecipientsSnapshot, ARG_ERA_END_TIMESTAMP_MILLIS,
+            ARG_EVICTED_VALIDATORS, ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS,
+            AUCTION_DELAY_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         handle_payment,
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
     },
-    AccessRights, ApiError, BlockTime, CLValue, Contract, DeployHash, DeployInfo, Key, KeyTag,
-    Phase, ProtocolVersion, PublicKey, RuntimeArgs, URef, U512,
+    AccessRights, ApiError, BlockTime, CLValue, Contract, ContractPackageHash, DeployHash,
+    DeployInfo, DeployTarget, Key, KeyTag, Phase, ProtocolVersion, PublicKey, RuntimeArgs, URef,
+    U512,
 };
 
 pub use self::{
+    account_contracts::{
+        ContractPackageSummary, ContractSummary, GetAccountContractsRequest,
+        GetAccountContractsResult,
+    },
     balance::{BalanceRequest, BalanceResult},
     deploy_item::DeployItem,
-    engine_config::EngineConfig,
+    engine_config::{EngineConfig, FeeHandling},
     era_validators::{GetEraValidatorsError, GetEraValidatorsRequest},
     error::{Error, RootNotFound},
     executable_deploy_item::ExecutableDeployItem,
@@ -57,6 +67,7 @@ pub use self::{
     execution_result::{ExecutionResult, ExecutionResults, ForcedTransferResult},
     genesis::{ExecConfig, GenesisAccount, GenesisResult},
     query::{GetBidsRequest, GetBidsResult, QueryRequest, QueryResult},
+    state_diff::{KeyChange, StateDiffRequest, StateDiffResult, StoredValueSummary},
     step::{RewardItem, SlashItem, StepRequest, StepResult},
     system_contract_cache::SystemContractCache,
     transfer::{TransferArgs, TransferRuntimeArgsBuilder, TransferTargetMode},
@@ -64,6 +75,7 @@ pub use self::{
 };
 use crate::{
     core::{
+        authorization,
         engine_state::{
             executable_deploy_item::DeployMetadata, execution_result::ExecutionResultBuilder,
             genesis::GenesisInstaller, upgrade::SystemUpgrader,
@@ -82,9 +94,10 @@ use crate::{
         wasm_prep::Preprocessor,
     },
     storage::{
-        global_state::{CommitResult, StateProvider},
+        global_state::{in_memory::InMemoryGlobalState, CommitResult, StateProvider},
         protocol_data::ProtocolData,
         trie::Trie,
+        trie_store,
     },
 };
 
@@ -279,6 +292,12 @@ where
             None => current_protocol_data.system_config(),
         };
 
+        // global state records keep the current length encoding unless the upgrade changes it;
+        // records written under a previous encoding remain readable
+        let new_length_encoding = upgrade_config
+            .new_length_encoding()
+            .unwrap_or_else(|| current_protocol_data.length_encoding());
+
         // 3.1.2.2 persist wasm CostTable
         let new_protocol_data = ProtocolData::new(
             *new_wasm_config,
@@ -287,7 +306,8 @@ where
             current_protocol_data.handle_payment(),
             current_protocol_data.standard_payment(),
             current_protocol_data.auction(),
-        );
+        )
+        .with_length_encoding(new_length_encoding);
 
         self.state
             .put_protocol_data(new_protocol_version, &new_protocol_data)
@@ -321,21 +341,9 @@ where
             tracking_copy.borrow_mut().write(auction_delay_key, value);
         }
 
-        if let Some(new_locked_funds_period) = upgrade_config.new_locked_funds_period_millis() {
-            let auction_contract = tracking_copy
-                .borrow_mut()
-                .get_contract(correlation_id, new_protocol_data.auction())?;
-
-            let locked_funds_period_key = auction_contract.named_keys()[LOCKED_FUNDS_PERIOD_KEY];
-            let value = StoredValue::CLValue(
-                CLValue::from_t(new_locked_funds_period)
-                    .map_err(|_| Error::Bytesrepr("new_locked_funds_period".to_string()))?,
-            );
-            tracking_copy
-                .borrow_mut()
-                .write(locked_funds_period_key, value);
-        }
-
+        // Pending unbonding requests don't need migrating: their maturity is evaluated against
+        // the stored unbonding delay on every step, so requests made eligible by a shorter delay
+        // are paid out at the next step, and a longer delay holds back all pending requests.
         if let Some(new_unbonding_delay) = upgrade_config.new_unbonding_delay() {
             let auction_contract = tracking_copy
                 .borrow_mut()
@@ -369,6 +377,41 @@ where
                 .write(locked_funds_period_key, value);
         }
 
+        // migrate bids written before their layout was versioned by rewriting all bids in the
+        // current layout
+        let bid_keys = tracking_copy
+            .borrow_mut()
+            .get_keys(correlation_id, &KeyTag::Bid)
+            .map_err(|err| Error::Exec(err.into()))?;
+        for key in bid_keys {
+            let maybe_bid = tracking_copy
+                .borrow_mut()
+                .get(correlation_id, &key)
+                .map_err(Into::into)?;
+            if let Some(StoredValue::Bid(bid)) = maybe_bid {
+                tracking_copy.borrow_mut().write(key, StoredValue::Bid(bid));
+            }
+        }
+
+        // likewise migrate the seigniorage recipients of the snapshotted eras
+        let auction_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, new_protocol_data.auction())?;
+        let snapshot_key = auction_contract.named_keys()[SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY];
+        let maybe_snapshot = tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &snapshot_key)
+            .map_err(Into::into)?;
+        if let Some(StoredValue::CLValue(cl_value)) = maybe_snapshot {
+            let snapshot_error =
+                || Error::Bytesrepr(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY.to_string());
+            let snapshot: SeigniorageRecipientsSnapshot =
+                cl_value.into_t().map_err(|_| snapshot_error())?;
+            let value =
+                StoredValue::CLValue(CLValue::from_t(snapshot).map_err(|_| snapshot_error())?);
+            tracking_copy.borrow_mut().write(snapshot_key, value);
+        }
+
         // apply the arbitrary modifications
         for (key, value) in upgrade_config.global_state_update() {
             tracking_copy.borrow_mut().write(*key, value.clone());
@@ -377,14 +420,14 @@ where
         let effects = tracking_copy.borrow().effect();
 
         // commit
-        let commit_result = self
-            .state
-            .commit(
+        let commit_result = trie_store::with_record_encoding(new_length_encoding, || {
+            self.state.commit(
                 correlation_id,
                 pre_state_hash,
                 effects.transforms.to_owned(),
             )
-            .map_err(Into::into)?;
+        })
+        .map_err(Into::into)?;
 
         // return result and effects
         Ok(UpgradeResult::from_commit_result(commit_result, effects))
@@ -400,6 +443,24 @@ where
         }
     }
 
+    /// Returns a tracking copy of the state under `hash` as it will be once `pending_effects` are
+    /// committed on top of it.
+    pub fn tracking_copy_with_pending_effects(
+        &self,
+        correlation_id: CorrelationId,
+        hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
+    ) -> Result<Option<TrackingCopy<S::Reader>>, Error> {
+        match self
+            .state
+            .checkout_with_pending_effects(correlation_id, hash, pending_effects)
+            .map_err(Into::into)?
+        {
+            Some(tc) => Ok(Some(TrackingCopy::new(tc))),
+            None => Ok(None),
+        }
+    }
+
     pub fn run_query(
         &self,
         correlation_id: CorrelationId,
@@ -428,6 +489,12 @@ where
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
     ) -> Result<ExecutionResults, RootNotFound> {
+        // Reject an unknown pre-state hash up front, even if none of the deploys would need to read
+        // global state.
+        if let Ok(None) = self.tracking_copy(exec_request.parent_state_hash) {
+            return Err(RootNotFound::new(exec_request.parent_state_hash));
+        }
+
         let executor = Executor::new(self.config);
 
         let deploys = exec_request.take_deploys();
@@ -442,6 +509,7 @@ where
                         &executor,
                         exec_request.protocol_version,
                         exec_request.parent_state_hash,
+                        &exec_request.pending_effects,
                         BlockTime::new(exec_request.block_time),
                         deploy_item,
                         exec_request.proposer,
@@ -451,6 +519,7 @@ where
                         &executor,
                         exec_request.protocol_version,
                         exec_request.parent_state_hash,
+                        &exec_request.pending_effects,
                         BlockTime::new(exec_request.block_time),
                         deploy_item,
                         exec_request.proposer,
@@ -485,15 +554,7 @@ where
             }
         };
 
-        // Authorize using provided authorization keys
-        if !account.can_authorize(authorization_keys) {
-            return Err(error::Error::Authorization);
-        }
-
-        // Check total key weight against deploy threshold
-        if !account.can_deploy_with(authorization_keys) {
-            return Err(execution::Error::DeploymentAuthorizationFailure.into());
-        }
+        authorization::authorize_deploy(&account, authorization_keys)?;
 
         Ok(account)
     }
@@ -524,6 +585,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
         blocktime: BlockTime,
         deploy_item: DeployItem,
         proposer: PublicKey,
@@ -541,7 +603,11 @@ where
             }
         };
 
-        let tracking_copy = match self.tracking_copy(prestate_hash) {
+        let tracking_copy = match self.tracking_copy_with_pending_effects(
+            correlation_id,
+            prestate_hash,
+            pending_effects,
+        ) {
             Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
             Ok(None) => return Err(RootNotFound::new(prestate_hash)),
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
@@ -623,9 +689,16 @@ where
 
         let gas_limit = Gas::new(U512::from(std::u64::MAX));
 
-        let wasmless_transfer_gas_cost = Gas::new(U512::from(
-            protocol_data.system_config().wasmless_transfer_cost(),
-        ));
+        // If fees are disabled, the transfer is free and no payment or finalization is executed.
+        let charge_fees = self.config.no_fee_session_gas_limit().is_none();
+
+        let wasmless_transfer_gas_cost = if charge_fees {
+            Gas::new(U512::from(
+                protocol_data.system_config().wasmless_transfer_cost(),
+            ))
+        } else {
+            Gas::default()
+        };
 
         let wasmless_transfer_motes = match Motes::from_gas(
             wasmless_transfer_gas_cost,
@@ -756,7 +829,9 @@ where
             };
 
         // Construct a payment code that will put cost of wasmless payment into payment purse
-        let payment_result = {
+        let payment_result = if !charge_fees {
+            ExecutionResult::default()
+        } else {
             // Check source purses minimum balance
             let source_uref = transfer_args.source();
             let source_purse_balance = if source_uref != account_main_purse {
@@ -918,6 +993,7 @@ where
             payment_result.with_cost(payment_gas)
         };
 
+        let transfer_id = transfer_args.arg_id();
         let runtime_args = match RuntimeArgs::try_from(transfer_args) {
             Ok(runtime_args) => runtime_args,
             Err(error) => {
@@ -952,7 +1028,9 @@ where
         // charge for anything that happens while calling transfer entrypoint.
         session_result = session_result.with_cost(Gas::default());
 
-        let finalize_result = {
+        let (payment_settlement, finalize_result) = if !charge_fees {
+            (None, ExecutionResult::default())
+        } else {
             let handle_payment_args = {
                 // Gas spent during payment code execution
                 let finalize_cost_motes = {
@@ -990,8 +1068,8 @@ where
             let tc = tracking_copy.borrow();
             let finalization_tc = Rc::new(RefCell::new(tc.fork()));
 
-            let (_ret, finalize_result): (Option<()>, ExecutionResult) = executor
-                .exec_system_contract(
+            let (payment_settlement, finalize_result): (Option<(U512, U512)>, ExecutionResult) =
+                executor.exec_system_contract(
                     DirectSystemContractCall::FinalizePayment,
                     system_module,
                     handle_payment_args,
@@ -1011,7 +1089,7 @@ where
                     SystemContractCache::clone(&self.system_contract_cache),
                 );
 
-            finalize_result
+            (payment_settlement, finalize_result)
         };
 
         // Create + persist deploy info.
@@ -1024,6 +1102,10 @@ where
                 account.account_hash(),
                 account.main_purse(),
                 cost,
+                transfer_id,
+                None,
+                Some(DeployTarget::Transfer),
+                None,
             );
             tracking_copy.borrow_mut().write(
                 Key::DeployInfo(deploy_item.deploy_hash),
@@ -1039,6 +1121,9 @@ where
         execution_result_builder.set_payment_execution_result(payment_result);
         execution_result_builder.set_session_execution_result(session_result);
         execution_result_builder.set_finalize_execution_result(finalize_result);
+        if let Some((refund, proposer_reward)) = payment_settlement {
+            execution_result_builder.set_payment_settlement(refund, proposer_reward);
+        }
 
         let execution_result = execution_result_builder
             .build(tracking_copy.borrow().reader(), correlation_id)
@@ -1054,6 +1139,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
         blocktime: BlockTime,
         deploy_item: DeployItem,
         proposer: PublicKey,
@@ -1083,7 +1169,11 @@ where
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         // do this second; as there is no reason to proceed if the prestate hash is invalid
-        let tracking_copy = match self.tracking_copy(prestate_hash) {
+        let tracking_copy = match self.tracking_copy_with_pending_effects(
+            correlation_id,
+            prestate_hash,
+            pending_effects,
+        ) {
             Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
             Ok(None) => return Err(RootNotFound::new(prestate_hash)),
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
@@ -1132,6 +1222,7 @@ where
         let session = deploy_item.session;
         let payment = deploy_item.payment;
         let deploy_hash = deploy_item.deploy_hash;
+        let max_gas = deploy_item.max_gas;
 
         // Create session code `A` from provided session bytes
         // validation_spec_1: valid wasm bytes
@@ -1178,9 +1269,13 @@ where
 
         let max_payment_cost = Motes::new(*MAX_PAYMENT);
 
+        // If fees are disabled, payment and finalization are skipped and session code runs with
+        // the configured gas limit.
+        let no_fee_session_gas_limit = self.config.no_fee_session_gas_limit();
+
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
-        if account_main_purse_balance < max_payment_cost {
+        if no_fee_session_gas_limit.is_none() && account_main_purse_balance < max_payment_cost {
             return Ok(ExecutionResult::precondition_failure(
                 Error::InsufficientPayment,
             ));
@@ -1200,7 +1295,10 @@ where
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
         // Execute provided payment code
-        let payment_result = {
+        let mut payment_target = None;
+        let payment_result = if no_fee_session_gas_limit.is_some() {
+            ExecutionResult::default()
+        } else {
             // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
             // gas_price)
             let payment_gas_limit = match Gas::from_motes(max_payment_cost, deploy_item.gas_price) {
@@ -1211,6 +1309,11 @@ where
                     ))
                 }
             };
+            // The deploy's own gas cap, if any, bounds payment code too.
+            let payment_gas_limit = match max_gas {
+                Some(max_gas) => cmp::min(payment_gas_limit, max_gas),
+                None => payment_gas_limit,
+            };
 
             // Create payment code module from bytes
             // validation_spec_1: valid wasm bytes
@@ -1230,6 +1333,8 @@ where
                 }
             };
 
+            payment_target = Some(payment_metadata.target().clone());
+
             // payment_code_spec_2: execute payment code
             let (
                 payment_module,
@@ -1243,7 +1348,7 @@ where
                     contract_package,
                     entry_point,
                     ..
-                } => (
+                } if payment.is_standard_payment(phase) => (
                     system_module.clone(),
                     base_key,                     // this is account key
                     account.named_keys().clone(), // standard payment uses account keys
@@ -1251,10 +1356,25 @@ where
                     entry_point,
                     true,
                 ),
+                DeployMetadata::System {
+                    base_key,
+                    contract,
+                    contract_package,
+                    entry_point,
+                    ..
+                } => (
+                    system_module.clone(),
+                    base_key, // this is contract key
+                    contract.named_keys().clone(),
+                    contract_package,
+                    entry_point,
+                    false,
+                ),
                 DeployMetadata::Session {
                     module,
                     contract_package,
                     entry_point,
+                    ..
                 } => (
                     module,
                     base_key, // this is account key
@@ -1269,6 +1389,7 @@ where
                     contract,
                     contract_package,
                     entry_point,
+                    ..
                 } => (
                     module,
                     base_key, // this is contract key
@@ -1445,6 +1566,8 @@ where
         let post_payment_tracking_copy = tracking_copy.borrow();
         let session_tracking_copy = Rc::new(RefCell::new(post_payment_tracking_copy.fork()));
 
+        let session_target = session_metadata.target().clone();
+
         // session_code_spec_2: execute session code
         let (
             session_module,
@@ -1458,6 +1581,7 @@ where
                 contract,
                 contract_package,
                 entry_point,
+                ..
             } => {
                 (
                     system_module.clone(),
@@ -1471,6 +1595,7 @@ where
                 module,
                 contract_package,
                 entry_point,
+                ..
             } => (
                 module,
                 base_key,
@@ -1484,6 +1609,7 @@ where
                 contract,
                 contract_package,
                 entry_point,
+                ..
             } => (
                 module,
                 base_key,
@@ -1500,8 +1626,9 @@ where
             // session_code_spec_1: gas limit = ((balance of handle payment payment purse) /
             // gas_price)
             // - (gas spent during payment execution)
-            let session_gas_limit: Gas =
-                match Gas::from_motes(payment_purse_balance, deploy_item.gas_price)
+            let session_gas_limit: Gas = match no_fee_session_gas_limit {
+                Some(gas_limit) => gas_limit,
+                None => match Gas::from_motes(payment_purse_balance, deploy_item.gas_price)
                     .and_then(|gas| gas.checked_sub(payment_result_cost))
                 {
                     Some(gas) => gas,
@@ -1510,10 +1637,20 @@ where
                             Error::GasConversionOverflow,
                         ))
                     }
-                };
+                },
+            };
+            // Session may only spend what is left of the deploy's gas cap after payment; whatever
+            // was paid beyond that is refunded during finalization.
+            let session_gas_limit = match max_gas {
+                Some(max_gas) => cmp::min(
+                    session_gas_limit,
+                    max_gas.checked_sub(payment_result_cost).unwrap_or_default(),
+                ),
+                None => session_gas_limit,
+            };
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-            executor.exec(
+            let session_result = executor.exec(
                 session_module,
                 session_entry_point,
                 session_args,
@@ -1531,7 +1668,14 @@ where
                 protocol_data,
                 system_contract_cache,
                 &session_package,
-            )
+            );
+
+            if no_fee_session_gas_limit.is_some() {
+                // Gas is still metered to bound session execution, but nothing is charged for it.
+                session_result.with_cost(Gas::default())
+            } else {
+                session_result
+            }
         };
         debug!("Session result: {:?}", session_result);
 
@@ -1545,6 +1689,10 @@ where
                 account.account_hash(),
                 account.main_purse(),
                 cost,
+                None,
+                session_result.ret().cloned(),
+                Some(session_target),
+                payment_target,
             );
             session_tracking_copy.borrow_mut().write(
                 Key::DeployInfo(deploy_hash),
@@ -1566,7 +1714,9 @@ where
         execution_result_builder.set_session_execution_result(session_result);
 
         // payment_code_spec_5: run finalize process
-        let finalize_result: ExecutionResult = {
+        let (payment_settlement, finalize_result) = if no_fee_session_gas_limit.is_some() {
+            (None, ExecutionResult::default())
+        } else {
             let post_session_tc = post_session_rc.borrow();
             let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
 
@@ -1607,8 +1757,8 @@ where
             let gas_limit = Gas::new(U512::from(std::u64::MAX));
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-            let (_ret, finalize_result): (Option<()>, ExecutionResult) = executor
-                .exec_system_contract(
+            let (payment_settlement, finalize_result): (Option<(U512, U512)>, ExecutionResult) =
+                executor.exec_system_contract(
                     DirectSystemContractCall::FinalizePayment,
                     system_module,
                     handle_payment_args,
@@ -1628,10 +1778,13 @@ where
                     system_contract_cache,
                 );
 
-            finalize_result
+            (payment_settlement, finalize_result)
         };
 
         execution_result_builder.set_finalize_execution_result(finalize_result);
+        if let Some((refund, proposer_reward)) = payment_settlement {
+            execution_result_builder.set_payment_settlement(refund, proposer_reward);
+        }
 
         // We panic here to indicate that the builder was not used properly.
         let ret = execution_result_builder
@@ -1647,15 +1800,22 @@ where
     pub fn apply_effect(
         &self,
         correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
         pre_state_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Error>
     where
         Error: From<S::Error>,
     {
-        self.state
-            .commit(correlation_id, pre_state_hash, effects)
-            .map_err(Error::from)
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        trie_store::with_record_encoding(protocol_data.length_encoding(), || {
+            self.state.commit(correlation_id, pre_state_hash, effects)
+        })
+        .map_err(Error::from)
     }
 
     pub fn read_trie(
@@ -1816,6 +1976,114 @@ where
         Ok(GetBidsResult::Success { bids })
     }
 
+    /// Returns summaries of the contract packages referred to by an account's named keys, either
+    /// directly or through one of their contracts.
+    pub fn get_account_contracts(
+        &self,
+        correlation_id: CorrelationId,
+        get_account_contracts_request: GetAccountContractsRequest,
+    ) -> Result<GetAccountContractsResult, Error> {
+        let mut tracking_copy =
+            match self.tracking_copy(get_account_contracts_request.state_hash())? {
+                Some(tracking_copy) => tracking_copy,
+                None => return Ok(GetAccountContractsResult::RootNotFound),
+            };
+
+        let account_key = Key::Account(get_account_contracts_request.account_hash());
+        let account = match tracking_copy
+            .get(correlation_id, &account_key)
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::Account(account)) => account,
+            _ => return Ok(GetAccountContractsResult::AccountNotFound),
+        };
+
+        let mut package_named_keys: BTreeMap<ContractPackageHash, BTreeSet<String>> =
+            BTreeMap::new();
+        for (name, key) in account.named_keys() {
+            let contract_package_hash = match key {
+                Key::Hash(addr) => {
+                    match tracking_copy.get(correlation_id, key).map_err(Into::into)? {
+                        Some(StoredValue::ContractPackage(_)) => ContractPackageHash::new(*addr),
+                        Some(StoredValue::Contract(contract)) => contract.contract_package_hash(),
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            package_named_keys
+                .entry(contract_package_hash)
+                .or_default()
+                .insert(name.clone());
+        }
+
+        let mut contract_packages = Vec::with_capacity(package_named_keys.len());
+        for (contract_package_hash, named_keys) in package_named_keys {
+            let contract_package = match tracking_copy
+                .get(correlation_id, &contract_package_hash.into())
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::ContractPackage(contract_package)) => contract_package,
+                _ => continue,
+            };
+
+            let mut versions = Vec::with_capacity(contract_package.versions().len());
+            for (contract_version_key, version_info) in contract_package.versions() {
+                let contract_hash = version_info.contract_hash();
+                if let Some(StoredValue::Contract(contract)) = tracking_copy
+                    .get(correlation_id, &contract_hash.into())
+                    .map_err(Into::into)?
+                {
+                    versions.push(ContractSummary::new(
+                        contract_hash,
+                        *contract_version_key,
+                        &contract_package,
+                        &contract,
+                    ));
+                }
+            }
+
+            contract_packages.push(ContractPackageSummary::new(
+                contract_package_hash,
+                named_keys,
+                &contract_package,
+                versions,
+            ));
+        }
+
+        Ok(GetAccountContractsResult::Success { contract_packages })
+    }
+
+    /// Returns the key-level changes between two state roots, ordered by key.
+    pub fn diff_state_roots(
+        &self,
+        correlation_id: CorrelationId,
+        state_diff_request: StateDiffRequest,
+    ) -> Result<StateDiffResult, Error> {
+        let pre_state_hash = state_diff_request.pre_state_hash();
+        let post_state_hash = state_diff_request.post_state_hash();
+        for state_hash in [pre_state_hash, post_state_hash].iter() {
+            if self
+                .state
+                .checkout(*state_hash)
+                .map_err(Into::into)?
+                .is_none()
+            {
+                return Ok(StateDiffResult::RootNotFound(*state_hash));
+            }
+        }
+
+        let changes = self
+            .state
+            .diff(correlation_id, pre_state_hash, post_state_hash)
+            .map_err(Into::into)?
+            .into_iter()
+            .map(KeyChange::from)
+            .collect();
+
+        Ok(StateDiffResult::Success { changes })
+    }
+
     pub fn commit_step(
         &self,
         correlation_id: CorrelationId,
@@ -2029,13 +2297,14 @@ where
         let effects = tracking_copy.borrow().effect();
 
         // commit
-        let commit_result = self
-            .state
-            .commit(
-                correlation_id,
-                step_request.pre_state_hash,
-                effects.transforms,
-            )
+        let commit_result =
+            trie_store::with_record_encoding(protocol_data.length_encoding(), || {
+                self.state.commit(
+                    correlation_id,
+                    step_request.pre_state_hash,
+                    effects.transforms,
+                )
+            })
             .map_err(Into::into)?;
 
         let post_state_hash = match commit_result {
@@ -2076,3 +2345,16 @@ where
         })
     }
 }
+
+impl EngineState<InMemoryGlobalState> {
+    /// Creates an independent copy of this engine state, backed by a fork of its global state.
+    ///
+    /// The copy starts with an empty system contract cache.
+    pub fn fork(&self) -> Result<Self, Error> {
+        let state = self
+            .state
+            .fork()
+            .map_err(|error| Error::Exec(error.into()))?;
+        Ok(EngineState::new(state, self.config))
+    }
+}
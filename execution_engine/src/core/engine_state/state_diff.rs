@@ -0,0 +1,130 @@
+//! Key-level changes between two global state roots.
+
+use casper_types::{bytesrepr::ToBytes, Key};
+
+use crate::{
+    shared::{newtypes::Blake2bHash, stored_value::StoredValue},
+    storage::trie_store::operations::TrieDiff,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateDiffRequest {
+    pre_state_hash: Blake2bHash,
+    post_state_hash: Blake2bHash,
+}
+
+impl StateDiffRequest {
+    pub fn new(pre_state_hash: Blake2bHash, post_state_hash: Blake2bHash) -> Self {
+        StateDiffRequest {
+            pre_state_hash,
+            post_state_hash,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn post_state_hash(&self) -> Blake2bHash {
+        self.post_state_hash
+    }
+}
+
+/// Summary of a value stored under a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredValueSummary {
+    type_name: String,
+    serialized_length: usize,
+    value_hash: Blake2bHash,
+}
+
+impl StoredValueSummary {
+    pub fn new(stored_value: &StoredValue) -> Self {
+        let value_hash = match stored_value.to_bytes() {
+            Ok(bytes) => Blake2bHash::new(&bytes),
+            Err(_) => Blake2bHash::new(&[]),
+        };
+        StoredValueSummary {
+            type_name: stored_value.type_name(),
+            serialized_length: stored_value.serialized_length(),
+            value_hash,
+        }
+    }
+
+    /// Name of the value's type, e.g. `Account` or the `CLType` of a `CLValue`.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn serialized_length(&self) -> usize {
+        self.serialized_length
+    }
+
+    /// Hash of the serialized value, distinguishing values of the same type and length.
+    pub fn value_hash(&self) -> Blake2bHash {
+        self.value_hash
+    }
+}
+
+/// Change to the value stored under a single key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyChange {
+    Added {
+        key: Key,
+        new: StoredValueSummary,
+    },
+    Removed {
+        key: Key,
+        old: StoredValueSummary,
+    },
+    Modified {
+        key: Key,
+        old: StoredValueSummary,
+        new: StoredValueSummary,
+    },
+}
+
+impl KeyChange {
+    pub fn key(&self) -> &Key {
+        match self {
+            KeyChange::Added { key, .. }
+            | KeyChange::Removed { key, .. }
+            | KeyChange::Modified { key, .. } => key,
+        }
+    }
+}
+
+impl From<TrieDiff<Key, StoredValue>> for KeyChange {
+    fn from(trie_diff: TrieDiff<Key, StoredValue>) -> Self {
+        match trie_diff {
+            TrieDiff::Added { key, value } => KeyChange::Added {
+                key,
+                new: StoredValueSummary::new(&value),
+            },
+            TrieDiff::Removed { key, value } => KeyChange::Removed {
+                key,
+                old: StoredValueSummary::new(&value),
+            },
+            TrieDiff::Modified { key, old, new } => KeyChange::Modified {
+                key,
+                old: StoredValueSummary::new(&old),
+                new: StoredValueSummary::new(&new),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StateDiffResult {
+    RootNotFound(Blake2bHash),
+    Success { changes: Vec<KeyChange> },
+}
+
+impl StateDiffResult {
+    pub fn changes(&self) -> Option<&[KeyChange]> {
+        match self {
+            StateDiffResult::RootNotFound(_) => None,
+            StateDiffResult::Success { changes } => Some(changes),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use casper_types::{
+    contracts::EntryPoints,
+    system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
+    ContractHash,
+};
+
+use crate::{core::engine_state::genesis, storage::protocol_data::ProtocolData};
+
+/// The system contracts installed in global state, keyed by their names.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct SystemContractRegistry(BTreeMap<String, ContractHash>);
+
+impl SystemContractRegistry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        SystemContractRegistry::default()
+    }
+
+    /// Registers `contract_hash` as the system contract called `name`.
+    ///
+    /// Returns the previously registered hash, if any.
+    pub fn insert(&mut self, name: &str, contract_hash: ContractHash) -> Option<ContractHash> {
+        self.0.insert(name.to_string(), contract_hash)
+    }
+
+    /// Returns the hash of the system contract called `name`.
+    pub fn get(&self, name: &str) -> Option<ContractHash> {
+        self.0.get(name).copied()
+    }
+
+    /// Returns an iterator over the registered system contract names and hashes.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ContractHash)> {
+        self.0
+            .iter()
+            .map(|(name, contract_hash)| (name.as_str(), *contract_hash))
+    }
+}
+
+impl From<&ProtocolData> for SystemContractRegistry {
+    fn from(protocol_data: &ProtocolData) -> Self {
+        let installed = protocol_data.system_contracts();
+        let mut registry = SystemContractRegistry::new();
+        for (name, contract_hash) in &[
+            (MINT, protocol_data.mint()),
+            (HANDLE_PAYMENT, protocol_data.handle_payment()),
+            (STANDARD_PAYMENT, protocol_data.standard_payment()),
+            (AUCTION, protocol_data.auction()),
+        ] {
+            if installed.contains(contract_hash) {
+                registry.insert(name, *contract_hash);
+            }
+        }
+        registry
+    }
+}
+
+/// Returns the entry points the engine natively defines for the system contract called `name`, or
+/// `None` if `name` is not a system contract.
+pub fn system_contract_entry_points(name: &str) -> Option<EntryPoints> {
+    match name {
+        MINT => Some(genesis::mint_entry_points()),
+        HANDLE_PAYMENT => Some(genesis::handle_payment_entry_points()),
+        STANDARD_PAYMENT => Some(genesis::standard_payment_entry_points()),
+        AUCTION => Some(genesis::auction_entry_points()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_register_installed_system_contracts() {
+        let mint_hash = ContractHash::new([1; 32]);
+        let protocol_data = ProtocolData::partial_with_mint(mint_hash);
+
+        let registry = SystemContractRegistry::from(&protocol_data);
+
+        assert_eq!(registry.get(MINT), Some(mint_hash));
+        assert_eq!(registry.get(AUCTION), None);
+        assert_eq!(registry.iter().count(), 1);
+    }
+
+    #[test]
+    fn should_define_entry_points_for_each_system_contract() {
+        for name in &[MINT, HANDLE_PAYMENT, STANDARD_PAYMENT, AUCTION] {
+            let entry_points =
+                system_contract_entry_points(name).expect("should have entry points");
+            assert!(entry_points.keys().next().is_some());
+        }
+        assert!(system_contract_entry_points("not a system contract").is_none());
+    }
+}
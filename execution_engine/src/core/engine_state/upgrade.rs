@@ -4,13 +4,19 @@ use num_rational::Ratio;
 use thiserror::Error;
 
 use casper_types::{
-    bytesrepr,
-    system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
-    ContractHash, Key, ProtocolVersion,
+    bytesrepr::{self, LengthEncoding},
+    contracts::ContractVersionInfo,
+    BlockTime, Contract, ContractHash, Key, ProtocolVersion, PublicKey,
 };
 
 use crate::{
-    core::{engine_state::execution_effect::ExecutionEffect, tracking_copy::TrackingCopy},
+    core::{
+        engine_state::{
+            execution_effect::ExecutionEffect,
+            system_contract_registry::{self, SystemContractRegistry},
+        },
+        tracking_copy::TrackingCopy,
+    },
     shared::{
         newtypes::{Blake2bHash, CorrelationId},
         stored_value::StoredValue,
@@ -84,10 +90,10 @@ pub struct UpgradeConfig {
     activation_point: Option<ActivationPoint>,
     new_validator_slots: Option<u32>,
     new_auction_delay: Option<u64>,
-    new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
+    new_length_encoding: Option<LengthEncoding>,
 }
 
 impl UpgradeConfig {
@@ -101,7 +107,6 @@ impl UpgradeConfig {
         activation_point: Option<ActivationPoint>,
         new_validator_slots: Option<u32>,
         new_auction_delay: Option<u64>,
-        new_locked_funds_period_millis: Option<u64>,
         new_round_seigniorage_rate: Option<Ratio<u64>>,
         new_unbonding_delay: Option<u64>,
         global_state_update: BTreeMap<Key, StoredValue>,
@@ -115,10 +120,10 @@ impl UpgradeConfig {
             activation_point,
             new_validator_slots,
             new_auction_delay,
-            new_locked_funds_period_millis,
             new_round_seigniorage_rate,
             new_unbonding_delay,
             global_state_update,
+            new_length_encoding: None,
         }
     }
 
@@ -154,10 +159,6 @@ impl UpgradeConfig {
         self.new_auction_delay
     }
 
-    pub fn new_locked_funds_period_millis(&self) -> Option<u64> {
-        self.new_locked_funds_period_millis
-    }
-
     pub fn new_round_seigniorage_rate(&self) -> Option<Ratio<u64>> {
         self.new_round_seigniorage_rate
     }
@@ -170,9 +171,19 @@ impl UpgradeConfig {
         &self.global_state_update
     }
 
+    /// The encoding used for the length prefixes of global state records written under the new
+    /// protocol version, if it differs from that of the current one.
+    pub fn new_length_encoding(&self) -> Option<LengthEncoding> {
+        self.new_length_encoding
+    }
+
     pub fn with_pre_state_hash(&mut self, pre_state_hash: Blake2bHash) {
         self.pre_state_hash = pre_state_hash;
     }
+
+    pub fn with_new_length_encoding(&mut self, new_length_encoding: LengthEncoding) {
+        self.new_length_encoding = Some(new_length_encoding);
+    }
 }
 
 #[derive(Clone, Error, Debug)]
@@ -213,22 +224,18 @@ where
     }
 
     /// Bump major version for system contracts.
+    ///
+    /// Each system contract is rebuilt natively, keeping its named keys and Wasm but taking its
+    /// entry points from the engine's own definitions, so no installer Wasm is needed to expose
+    /// new system entry points.
     pub(crate) fn upgrade_system_contracts_major_version(
         &self,
         correlation_id: CorrelationId,
     ) -> Result<(), ProtocolUpgradeError> {
-        self.store_contract(correlation_id, self.protocol_data.mint(), MINT)?;
-        self.store_contract(correlation_id, self.protocol_data.auction(), AUCTION)?;
-        self.store_contract(
-            correlation_id,
-            self.protocol_data.handle_payment(),
-            HANDLE_PAYMENT,
-        )?;
-        self.store_contract(
-            correlation_id,
-            self.protocol_data.standard_payment(),
-            STANDARD_PAYMENT,
-        )?;
+        let registry = SystemContractRegistry::from(&self.protocol_data);
+        for (contract_name, contract_hash) in registry.iter() {
+            self.store_contract(correlation_id, contract_hash, contract_name)?;
+        }
 
         Ok(())
     }
@@ -241,7 +248,7 @@ where
     ) -> Result<(), ProtocolUpgradeError> {
         let contract_key = Key::Hash(contract_hash.value());
 
-        let mut contract = if let StoredValue::Contract(contract) = self
+        let contract = if let StoredValue::Contract(contract) = self
             .tracking_copy
             .borrow_mut()
             .read(correlation_id, &contract_key)
@@ -286,9 +293,24 @@ where
             .map_err(|_| {
                 ProtocolUpgradeError::FailedToDisablePreviousVersion(contract_name.to_string())
             })?;
-        contract.set_protocol_version(self.new_protocol_version);
+        let entry_points = system_contract_registry::system_contract_entry_points(contract_name)
+            .unwrap_or_else(|| contract.entry_points().clone());
+        let contract = Contract::new(
+            contract.contract_package_hash(),
+            contract.contract_wasm_hash(),
+            contract.named_keys().clone(),
+            entry_points,
+            self.new_protocol_version,
+        );
+        // Upgrades are not executed in a block, so the new version has no creation time.
+        let version_info = ContractVersionInfo::new(
+            contract_hash,
+            BlockTime::default(),
+            PublicKey::System.to_account_hash(),
+            None,
+        );
         contract_package
-            .insert_contract_version(self.new_protocol_version.value().major, contract_hash);
+            .insert_contract_version(self.new_protocol_version.value().major, version_info);
 
         self.tracking_copy
             .borrow_mut()
@@ -3,8 +3,9 @@ use thiserror::Error;
 
 use casper_types::{
     account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
-    bytesrepr, system, AccessRights, ApiError, CLType, CLValueError, ContractPackageHash,
-    ContractVersionKey, Key, URef,
+    bytesrepr, system, AccessRights, ApiError, CLType, CLValueError, ContractHash,
+    ContractPackageHash, ContractVersionKey, ContractWasmError, ForgedReference, Key,
+    NamedKeyError, URef,
 };
 
 use crate::{
@@ -92,8 +93,17 @@ pub enum Error {
     KeyIsNotAURef(Key),
     #[error("Unexpected variant of a stored value")]
     UnexpectedStoredValueVariant,
-    #[error("A locked contract cannot be upgraded")]
+    #[error("A locked contract cannot be upgraded or have its groups changed")]
     LockedContract(ContractPackageHash),
+    #[error("Invalid named key name: {}", _0)]
+    InvalidNamedKeyName(NamedKeyError),
+    #[error("{}", _0)]
+    ContractWasm(ContractWasmError),
+    #[error("Non-reentrant entry point {entry_point_name} of contract {contract_hash} re-entered")]
+    ReentrantCall {
+        contract_hash: ContractHash,
+        entry_point_name: String,
+    },
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
@@ -143,6 +153,12 @@ impl From<bytesrepr::Error> for Error {
     }
 }
 
+impl From<ForgedReference> for Error {
+    fn from(ForgedReference(uref): ForgedReference) -> Self {
+        Error::ForgedReference(uref)
+    }
+}
+
 impl From<elements::Error> for Error {
     fn from(e: elements::Error) -> Self {
         Error::ParityWasm(e)
@@ -185,6 +201,12 @@ impl From<system::Error> for Error {
     }
 }
 
+impl From<ContractWasmError> for Error {
+    fn from(error: ContractWasmError) -> Self {
+        Error::ContractWasm(error)
+    }
+}
+
 impl From<CLValueError> for Error {
     fn from(e: CLValueError) -> Self {
         Error::CLValue(e)
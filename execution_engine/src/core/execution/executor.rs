@@ -19,7 +19,7 @@ use crate::{
             execution_effect::ExecutionEffect, execution_result::ExecutionResult,
             system_contract_cache::SystemContractCache, EngineConfig,
         },
-        execution::{address_generator::AddressGenerator, Error},
+        execution::{address_generator::AddressGenerator, Error, GasCheckpoints},
         runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
         runtime_context::{self, RuntimeContext},
         tracking_copy::TrackingCopy,
@@ -50,6 +50,8 @@ macro_rules! on_fail_charge {
                     effect: Default::default(),
                     transfers: $transfers,
                     cost: $cost,
+                    gas_checkpoints: Default::default(),
+                    fee_breakdown: None,
                 };
             }
         }
@@ -65,6 +67,8 @@ macro_rules! on_fail_charge {
                     effect: $effect,
                     transfers: $transfers,
                     cost: $cost,
+                    gas_checkpoints: Default::default(),
+                    fee_breakdown: None,
                 };
             }
         }
@@ -138,6 +142,8 @@ impl Executor {
         };
         let gas_counter: Gas = Gas::default();
         let transfers = Vec::default();
+        let gas_checkpoints = Rc::new(RefCell::new(GasCheckpoints::default()));
+        let events = Rc::new(RefCell::new(Vec::default()));
 
         // Snapshot of effects before execution, so in case of error
         // only nonce update can be returned.
@@ -164,10 +170,18 @@ impl Executor {
             phase,
             protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         );
 
         let mut runtime = Runtime::new(self.config, system_contract_cache, memory, module, context);
 
+        if entry_point_type == EntryPointType::Contract {
+            if let Some(contract_hash) = base_key.into_hash() {
+                runtime.push_call_stack(contract_hash.into(), &entry_point);
+            }
+        }
+
         let accounts_access_rights = {
             let keys: Vec<Key> = account.named_keys().values().cloned().collect();
             extract_access_rights_from_keys(keys)
@@ -176,7 +190,7 @@ impl Executor {
         on_fail_charge!(runtime_context::validate_entry_point_access_with(
             &contract_package,
             entry_point_access,
-            |uref| runtime_context::uref_has_access_rights(uref, &accounts_access_rights)
+            |uref| accounts_access_rights.has_access_rights(uref)
         ));
 
         if runtime.is_mint(base_key) {
@@ -187,11 +201,14 @@ impl Executor {
                 &args,
                 Default::default(),
             ) {
-                Ok(_value) => {
+                Ok(value) => {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        ret: Some(value),
+                        events: runtime.context().events().borrow().clone(),
+                        fee_breakdown: None,
                     };
                 }
                 Err(error) => {
@@ -200,6 +217,8 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                        fee_breakdown: None,
                     };
                 }
             }
@@ -211,11 +230,14 @@ impl Executor {
                 &args,
                 Default::default(),
             ) {
-                Ok(_value) => {
+                Ok(value) => {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        ret: Some(value),
+                        events: runtime.context().events().borrow().clone(),
+                        fee_breakdown: None,
                     };
                 }
                 Err(error) => {
@@ -224,6 +246,8 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                        fee_breakdown: None,
                     };
                 }
             }
@@ -235,11 +259,14 @@ impl Executor {
                 &args,
                 Default::default(),
             ) {
-                Ok(_value) => {
+                Ok(value) => {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        ret: Some(value),
+                        events: runtime.context().events().borrow().clone(),
+                        fee_breakdown: None,
                     }
                 }
                 Err(error) => {
@@ -248,21 +275,45 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                        fee_breakdown: None,
                     }
                 }
             }
         }
-        on_fail_charge!(
-            instance.invoke_export(entry_point_name, &[], &mut runtime),
-            runtime.context().gas_counter(),
-            effects_snapshot,
-            runtime.context().transfers().to_owned()
-        );
+        let ret = match instance.invoke_export(entry_point_name, &[], &mut runtime) {
+            // Returning without calling `runtime::ret()` yields no value; anything left in the host
+            // buffer is only the result of an earlier host call.
+            Ok(_) => None,
+            Err(error) => match error
+                .as_host_error()
+                .and_then(|host_error| host_error.downcast_ref::<Error>())
+            {
+                // Calling `runtime::ret()` traps, but is the expected way for the top-level
+                // entry point to hand a value back to the caller.
+                Some(Error::Ret(_)) => runtime.take_host_buffer(),
+                _ => {
+                    let exec_err: Error = error.into();
+                    warn!("Execution failed: {:?}", exec_err);
+                    return ExecutionResult::Failure {
+                        error: exec_err.into(),
+                        effect: effects_snapshot,
+                        transfers: runtime.context().transfers().to_owned(),
+                        cost: runtime.context().gas_counter(),
+                        gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                        fee_breakdown: None,
+                    };
+                }
+            },
+        };
 
         ExecutionResult::Success {
             effect: runtime.context().effect(),
             transfers: runtime.context().transfers().to_owned(),
             cost: runtime.context().gas_counter(),
+            ret,
+            events: runtime.context().events().borrow().clone(),
+            fee_breakdown: None,
         }
     }
 
@@ -331,6 +382,8 @@ impl Executor {
                     effect: Default::default(),
                     transfers: Vec::default(),
                     cost: Gas::default(),
+                    gas_checkpoints: Default::default(),
+                    fee_breakdown: None,
                 };
             }
         };
@@ -342,12 +395,17 @@ impl Executor {
                 effect: runtime.context().effect(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                ret: None,
+                events: runtime.context().events().borrow().clone(),
+                fee_breakdown: None,
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 effect: effects_snapshot,
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                fee_breakdown: None,
             },
         }
     }
@@ -464,6 +522,8 @@ impl Executor {
                     transfers,
                     cost: gas_counter,
                     error: error.into(),
+                    gas_checkpoints: Default::default(),
+                    fee_breakdown: None,
                 }
                 .take_without_ret()
             }
@@ -603,6 +663,8 @@ impl Executor {
 
         let gas_counter = Gas::default();
         let transfers = Vec::default();
+        let gas_checkpoints = Rc::new(RefCell::new(GasCheckpoints::default()));
+        let events = Rc::new(RefCell::new(Vec::default()));
 
         let runtime_context = RuntimeContext::new(
             tracking_copy,
@@ -625,6 +687,8 @@ impl Executor {
             phase,
             protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         );
 
         let (instance, memory) = instance_and_memory(
@@ -728,11 +792,14 @@ impl DirectSystemContractCall {
         };
 
         match result {
-            Ok(value) => match value.into_t() {
+            Ok(value) => match value.clone().into_t() {
                 Ok(ret) => ExecutionResult::Success {
                     effect: runtime.context().effect(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    ret: Some(value),
+                    events: runtime.context().events().borrow().clone(),
+                    fee_breakdown: None,
                 }
                 .take_with_ret(ret),
                 Err(error) => ExecutionResult::Failure {
@@ -740,6 +807,8 @@ impl DirectSystemContractCall {
                     effect: execution_effect,
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                    fee_breakdown: None,
                 }
                 .take_without_ret(),
             },
@@ -748,6 +817,8 @@ impl DirectSystemContractCall {
                 effect: execution_effect,
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                gas_checkpoints: runtime.context().gas_checkpoints().borrow().clone(),
+                fee_breakdown: None,
             }
             .take_without_ret(),
         }
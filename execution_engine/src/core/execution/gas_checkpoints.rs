@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+
+use casper_types::GasCheckpoint;
+
+use crate::shared::gas::Gas;
+
+/// The maximum number of checkpoints retained by [`GasCheckpoints`].
+pub const MAX_GAS_CHECKPOINTS: usize = 32;
+
+/// A timeline of the host function calls made while executing a deploy.
+///
+/// As the effects of a deploy which runs out of gas are discarded, this is reported alongside the
+/// failure instead.  Only the most recent [`MAX_GAS_CHECKPOINTS`] calls are retained.
+///
+/// Calls are recorded by the static name of the host function, so that recording them doesn't
+/// allocate.  They are only converted into [`GasCheckpoint`]s when reported.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct GasCheckpoints {
+    checkpoints: VecDeque<(&'static str, Gas)>,
+    last_completed: Option<&'static str>,
+}
+
+impl GasCheckpoints {
+    /// Records a call to `host_function`, made when `gas_used` had been used.
+    pub fn record_call(&mut self, host_function: &'static str, gas_used: Gas) {
+        if self.checkpoints.len() == MAX_GAS_CHECKPOINTS {
+            self.checkpoints.pop_front();
+        }
+        self.checkpoints.push_back((host_function, gas_used));
+    }
+
+    /// Records that a call to `host_function` completed successfully.
+    pub fn record_completion(&mut self, host_function: &'static str) {
+        self.last_completed = Some(host_function);
+    }
+
+    /// Returns the retained checkpoints, oldest first.
+    pub fn checkpoints(&self) -> impl Iterator<Item = GasCheckpoint> + '_ {
+        self.checkpoints
+            .iter()
+            .map(|(host_function, gas_used)| GasCheckpoint {
+                host_function: host_function.to_string(),
+                gas_used: gas_used.value(),
+            })
+    }
+
+    /// Returns the name of the last host function call which completed successfully.
+    pub fn last_completed(&self) -> Option<&'static str> {
+        self.last_completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U512;
+
+    use super::*;
+
+    #[test]
+    fn should_retain_most_recent_checkpoints() {
+        let mut gas_checkpoints = GasCheckpoints::default();
+        for gas_used in 0..MAX_GAS_CHECKPOINTS as u64 + 2 {
+            gas_checkpoints.record_call("host_function_write", Gas::new(gas_used.into()));
+        }
+        gas_checkpoints.record_completion("host_function_write");
+
+        let gas_used: Vec<U512> = gas_checkpoints
+            .checkpoints()
+            .map(|checkpoint| checkpoint.gas_used)
+            .collect();
+        let expected: Vec<U512> = (2..MAX_GAS_CHECKPOINTS as u64 + 2)
+            .map(U512::from)
+            .collect();
+        assert_eq!(gas_used, expected);
+        assert_eq!(
+            gas_checkpoints.last_completed(),
+            Some("host_function_write")
+        );
+    }
+}
@@ -2,6 +2,7 @@ mod address_generator;
 mod error;
 #[macro_use]
 mod executor;
+mod gas_checkpoints;
 #[cfg(test)]
 mod tests;
 
@@ -9,4 +10,5 @@ pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
     error::Error,
     executor::{DirectSystemContractCall, Executor},
+    gas_checkpoints::{GasCheckpoints, MAX_GAS_CHECKPOINTS},
 };
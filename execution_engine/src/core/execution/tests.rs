@@ -21,6 +21,9 @@ fn on_fail_charge_test_helper<T>(
         effect: Default::default(),
         transfers,
         cost: success_cost,
+        ret: None,
+        events: Vec::default(),
+        fee_breakdown: None,
     }
 }
 
@@ -67,6 +70,9 @@ fn on_fail_charge_with_action() {
             effect: Default::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            ret: None,
+            events: Vec::default(),
+            fee_breakdown: None,
         }
     };
     match f() {
@@ -28,7 +28,9 @@ pub fn create_module_resolver(
 
 #[cfg(test)]
 mod tests {
-    use casper_types::ProtocolVersion;
+    use wasmi::Signature;
+
+    use casper_types::{ProtocolVersion, HOST_FUNCTION_ALLOWED_PHASES};
 
     use super::*;
     use crate::shared::wasm_config::WasmConfig;
@@ -44,4 +46,18 @@ mod tests {
     fn protocol_version_1_always_resolves() {
         assert!(create_module_resolver(ProtocolVersion::V1_0_0, &WasmConfig::default()).is_ok());
     }
+
+    #[test]
+    fn phase_guarded_host_functions_should_resolve() {
+        let resolver = create_module_resolver(ProtocolVersion::V1_0_0, &WasmConfig::default())
+            .expect("should create resolver");
+        let signature = Signature::new(&[][..], None);
+        for (name, _) in HOST_FUNCTION_ALLOWED_PHASES {
+            assert!(
+                resolver.resolve_func(name, &signature).is_ok(),
+                "{} should resolve",
+                name
+            );
+        }
+    }
 }
@@ -168,6 +168,14 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
                 FunctionIndex::DisableContractVersion.into(),
             ),
+            "casper_lock_contract_package" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::LockContractPackage.into(),
+            ),
+            "casper_emit_event" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::EmitEvent.into(),
+            ),
             "casper_call_contract" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
                 FunctionIndex::CallContractFuncIndex.into(),
@@ -176,6 +184,18 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
                 FunctionIndex::CallVersionedContract.into(),
             ),
+            "casper_call_contract_with_gas_limit" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
+                FunctionIndex::CallContractWithGasLimit.into(),
+            ),
+            "casper_call_versioned_contract_with_gas_limit" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 11][..], Some(ValueType::I32)),
+                FunctionIndex::CallVersionedContractWithGasLimit.into(),
+            ),
+            "casper_transfer_from_purse_to_account_with_receipt" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
+                FunctionIndex::TransferFromPurseToAccountWithReceipt.into(),
+            ),
             "casper_get_named_arg_size" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
                 FunctionIndex::GetRuntimeArgsizeIndex.into(),
@@ -192,6 +212,10 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
                 FunctionIndex::ExtendContractUserGroupURefsIndex.into(),
             ),
+            "casper_provision_contract_user_group_urefs" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::ProvisionContractUserGroupURefsIndex.into(),
+            ),
             "casper_remove_contract_user_group_urefs" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
                 FunctionIndex::RemoveContractUserGroupURefsIndex.into(),
@@ -55,6 +55,13 @@ where
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
 
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, value: T) -> Result<URef, Error> {
+        let cl_value = CLValue::from_t(value).map_err(|_| Error::CLValue)?;
+        self.context
+            .new_uref(StoredValue::CLValue(cl_value))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn read_bid(&mut self, account_hash: &AccountHash) -> Result<Option<Bid>, Error> {
         match self.context.read_gs(&Key::Bid(*account_hash)) {
             Ok(Some(StoredValue::Bid(bid))) => Ok(Some(*bid)),
@@ -152,6 +159,12 @@ where
         self.context.named_keys_get(name).cloned()
     }
 
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
+        self.context
+            .put_key(name.to_string(), key)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
         self.context.get_keys(key_tag).map_err(|_| Error::Storage)
     }
@@ -6,13 +6,19 @@ use casper_types::{
     account,
     account::AccountHash,
     api_error,
-    bytesrepr::{self, ToBytes},
+    bytesrepr::ToBytes,
     contracts::{ContractPackageStatus, EntryPoints, NamedKeys},
+    host_function_allowed_phases,
     system::auction::{EraId, EraInfo},
     ContractHash, ContractPackageHash, ContractVersion, Group, Key, URef, U512,
 };
 
-use super::{args::Args, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
+use super::{
+    args::Args,
+    deserialize_from_wasm,
+    scoped_instrumenter::{self, ScopedInstrumenter},
+    Error, Runtime, HOST_FUNCTION_PHASES_PROTOCOL_VERSION,
+};
 use crate::{
     core::resolvers::v1_function_index::FunctionIndex,
     shared::{gas::Gas, host_function_costs::Cost, stored_value::StoredValue},
@@ -30,6 +36,14 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+
+        if self.context.protocol_version() >= HOST_FUNCTION_PHASES_PROTOCOL_VERSION {
+            let allowed_phases = host_function_allowed_phases(func.import_name());
+            if let Err(api_error) = self.context.phase().check_allowed(allowed_phases) {
+                return Err(Error::Revert(api_error).into());
+            }
+        }
+
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
 
         let host_function_costs = self
@@ -37,7 +51,16 @@ where
             .wasm_config()
             .take_host_function_costs();
 
-        match func {
+        let host_function = scoped_instrumenter::host_function_name(func);
+        if let Some(host_function) = host_function {
+            let gas_used = self.gas_counter();
+            self.context
+                .gas_checkpoints()
+                .borrow_mut()
+                .record_call(host_function, gas_used);
+        }
+
+        let result = match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key in Wasm memory
@@ -327,15 +350,15 @@ where
                 )?;
                 let account_hash: AccountHash = {
                     let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let amount: U512 = {
                     let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let id: Option<u64> = {
                     let bytes = self.bytes_from_mem(id_ptr, id_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
 
                 let ret = match self.transfer_to_account(account_hash, amount, id)? {
@@ -389,19 +412,19 @@ where
                 )?;
                 let source_purse = {
                     let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let account_hash: AccountHash = {
                     let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let amount: U512 = {
                     let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let id: Option<u64> = {
                     let bytes = self.bytes_from_mem(id_ptr, id_size as usize)?;
-                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                    deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
                 };
                 let ret = match self.transfer_from_purse_to_account(
                     source_purse,
@@ -422,6 +445,55 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::TransferFromPurseToAccountWithReceipt => {
+                // args(0) = pointer to array of bytes in Wasm memory of a source purse
+                // args(1) = length of array of bytes in Wasm memory of a source purse
+                // args(2) = pointer to array of bytes in Wasm memory of an account hash
+                // args(3) = length of array of bytes in Wasm memory of an account hash
+                // args(4) = pointer to array of bytes in Wasm memory of an amount
+                // args(5) = length of array of bytes in Wasm memory of an amount
+                // args(6) = pointer to array of bytes in Wasm memory of an id
+                // args(7) = length of array of bytes in Wasm memory of an id
+                // args(8) = pointer to receipt size (output)
+                let (
+                    source_ptr,
+                    source_size,
+                    key_ptr,
+                    key_size,
+                    amount_ptr,
+                    amount_size,
+                    id_ptr,
+                    id_size,
+                    output_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_account_with_receipt,
+                    [
+                        source_ptr,
+                        source_size,
+                        key_ptr,
+                        key_size,
+                        amount_ptr,
+                        amount_size,
+                        id_ptr,
+                        id_size,
+                        output_size_ptr,
+                    ],
+                )?;
+                let source_purse: URef = self.t_from_mem(source_ptr, source_size)?;
+                let account_hash: AccountHash = self.t_from_mem(key_ptr, key_size)?;
+                let amount: U512 = self.t_from_mem(amount_ptr, amount_size)?;
+                let id: Option<u64> = self.t_from_mem(id_ptr, id_size)?;
+                let ret = self.transfer_from_purse_to_account_with_receipt(
+                    source_purse,
+                    account_hash,
+                    amount,
+                    id,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::TransferFromPurseToPurseIndex => {
                 // args(0) = pointer to array of bytes in Wasm memory of a source purse
                 // args(1) = length of array of bytes in Wasm memory of a source purse
@@ -673,6 +745,34 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
             }
 
+            FunctionIndex::LockContractPackage => {
+                // args(0) = pointer to package hash in wasm memory
+                // args(1) = size of package hash in wasm memory
+                let (package_key_ptr, package_key_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.lock_contract_package,
+                    [package_key_ptr, package_key_size],
+                )?;
+                let contract_package_hash = self.t_from_mem(package_key_ptr, package_key_size)?;
+
+                let result = self.lock_contract_package(contract_package_hash)?;
+
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::EmitEvent => {
+                // args(0) = pointer to serialized event payload in wasm memory
+                // args(1) = size of serialized event payload in wasm memory
+                let (payload_ptr, payload_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    [payload_ptr, payload_size],
+                )?;
+                scoped_instrumenter.add_property("payload_size", payload_size);
+                self.emit_event(payload_ptr, payload_size)?;
+                Ok(None)
+            }
+
             FunctionIndex::CallContractFuncIndex => {
                 // args(0) = pointer to contract hash where contract is at in global state
                 // args(1) = size of contract hash
@@ -719,6 +819,7 @@ where
                     contract_hash,
                     &entry_point_name,
                     args_bytes,
+                    None,
                     result_size_ptr,
                     &mut scoped_instrumenter,
                 )?;
@@ -780,6 +881,136 @@ where
                     contract_version,
                     entry_point_name,
                     args_bytes,
+                    None,
+                    result_size_ptr,
+                    &mut scoped_instrumenter,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallContractWithGasLimit => {
+                // args(0) = pointer to contract hash where contract is at in global state
+                // args(1) = size of contract hash
+                // args(2) = pointer to entry point
+                // args(3) = size of entry point
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to gas sub-limit in Wasm memory
+                // args(7) = size of gas sub-limit
+                // args(8) = pointer to result size (output)
+                let (
+                    contract_hash_ptr,
+                    contract_hash_size,
+                    entry_point_name_ptr,
+                    entry_point_name_size,
+                    args_ptr,
+                    args_size,
+                    gas_limit_ptr,
+                    gas_limit_size,
+                    result_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_contract_with_gas_limit,
+                    [
+                        contract_hash_ptr,
+                        contract_hash_size,
+                        entry_point_name_ptr,
+                        entry_point_name_size,
+                        args_ptr,
+                        args_size,
+                        gas_limit_ptr,
+                        gas_limit_size,
+                        result_size_ptr,
+                    ],
+                )?;
+                scoped_instrumenter
+                    .add_property("entry_point_name_size", entry_point_name_size.to_string());
+                scoped_instrumenter.add_property("args_size", args_size.to_string());
+
+                let contract_hash: ContractHash =
+                    self.t_from_mem(contract_hash_ptr, contract_hash_size)?;
+                let entry_point_name: String =
+                    self.t_from_mem(entry_point_name_ptr, entry_point_name_size)?;
+                let args_bytes: Vec<u8> = {
+                    let args_size: u32 = args_size;
+                    self.bytes_from_mem(args_ptr, args_size as usize)?
+                };
+                let gas_limit: Gas = self.t_from_mem(gas_limit_ptr, gas_limit_size)?;
+
+                let ret = self.call_contract_host_buffer(
+                    contract_hash,
+                    &entry_point_name,
+                    args_bytes,
+                    Some(gas_limit),
+                    result_size_ptr,
+                    &mut scoped_instrumenter,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallVersionedContractWithGasLimit => {
+                // args(0) = pointer to contract_package_hash where contract is at in global state
+                // args(1) = size of contract_package_hash
+                // args(2) = pointer to contract version in wasm memory
+                // args(3) = size of contract version in wasm memory
+                // args(4) = pointer to method name in wasm memory
+                // args(5) = size of method name in wasm memory
+                // args(6) = pointer to function arguments in Wasm memory
+                // args(7) = size of arguments
+                // args(8) = pointer to gas sub-limit in Wasm memory
+                // args(9) = size of gas sub-limit
+                // args(10) = pointer to result size (output)
+                let (
+                    contract_package_hash_ptr,
+                    contract_package_hash_size,
+                    contract_version_ptr,
+                    contract_package_size,
+                    entry_point_name_ptr,
+                    entry_point_name_size,
+                    args_ptr,
+                    args_size,
+                    gas_limit_ptr,
+                    gas_limit_size,
+                    result_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_versioned_contract_with_gas_limit,
+                    [
+                        contract_package_hash_ptr,
+                        contract_package_hash_size,
+                        contract_version_ptr,
+                        contract_package_size,
+                        entry_point_name_ptr,
+                        entry_point_name_size,
+                        args_ptr,
+                        args_size,
+                        gas_limit_ptr,
+                        gas_limit_size,
+                        result_size_ptr,
+                    ],
+                )?;
+                scoped_instrumenter
+                    .add_property("entry_point_name_size", entry_point_name_size.to_string());
+                scoped_instrumenter.add_property("args_size", args_size.to_string());
+
+                let contract_package_hash: ContractPackageHash =
+                    self.t_from_mem(contract_package_hash_ptr, contract_package_hash_size)?;
+                let contract_version: Option<ContractVersion> =
+                    self.t_from_mem(contract_version_ptr, contract_package_size)?;
+                let entry_point_name: String =
+                    self.t_from_mem(entry_point_name_ptr, entry_point_name_size)?;
+                let args_bytes: Vec<u8> = {
+                    let args_size: u32 = args_size;
+                    self.bytes_from_mem(args_ptr, args_size as usize)?
+                };
+                let gas_limit: Gas = self.t_from_mem(gas_limit_ptr, gas_limit_size)?;
+
+                let ret = self.call_versioned_contract_host_buffer(
+                    contract_package_hash,
+                    contract_version,
+                    entry_point_name,
+                    args_bytes,
+                    Some(gas_limit),
                     result_size_ptr,
                     &mut scoped_instrumenter,
                 )?;
@@ -873,6 +1104,45 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::ProvisionContractUserGroupURefsIndex => {
+                // args(0) = pointer to package key in wasm memory
+                // args(1) = size of package key in wasm memory
+                // args(2) = pointer to label name
+                // args(3) = label size bytes
+                // args(4) = number of new urefs to add to the group
+                // args(5) = output of size value of host bytes data
+                let (
+                    package_ptr,
+                    package_size,
+                    label_ptr,
+                    label_size,
+                    num_new_urefs,
+                    value_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.provision_contract_user_group_urefs,
+                    [
+                        package_ptr,
+                        package_size,
+                        label_ptr,
+                        label_size,
+                        num_new_urefs,
+                        value_size_ptr,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("label_size", label_size.to_string());
+                scoped_instrumenter.add_property("num_new_urefs", num_new_urefs.to_string());
+                let ret = self.provision_contract_user_group_urefs(
+                    package_ptr,
+                    package_size,
+                    label_ptr,
+                    label_size,
+                    num_new_urefs,
+                    value_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::RemoveContractUserGroupURefsIndex => {
                 // args(0) = pointer to package key in wasm memory
                 // args(1) = size of package key in wasm memory
@@ -918,12 +1188,13 @@ where
                 let digest = account::blake2b(&input);
                 if digest.len() != out_size as usize {
                     let err_value = u32::from(api_error::ApiError::BufferTooSmall) as i32;
-                    return Ok(Some(RuntimeValue::I32(err_value)));
+                    Ok(Some(RuntimeValue::I32(err_value)))
+                } else {
+                    self.memory
+                        .set(out_ptr, &digest)
+                        .map_err(|error| Error::Interpreter(error.into()))?;
+                    Ok(Some(RuntimeValue::I32(0)))
                 }
-                self.memory
-                    .set(out_ptr, &digest)
-                    .map_err(|error| Error::Interpreter(error.into()))?;
-                Ok(Some(RuntimeValue::I32(0)))
             }
 
             FunctionIndex::RecordTransfer => {
@@ -968,6 +1239,16 @@ where
                 self.record_era_info(era_id, era_info)?;
                 Ok(Some(RuntimeValue::I32(0)))
             }
+        };
+
+        // Calls which trapped have returned early above, so only completed calls are recorded here.
+        if let Some(host_function) = host_function {
+            self.context
+                .gas_checkpoints()
+                .borrow_mut()
+                .record_completion(host_function);
         }
+
+        result
     }
 }
@@ -2,7 +2,7 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::mint::{Error, Mint, RuntimeProvider, StorageProvider, SystemProvider},
-    CLTyped, CLValue, Key, URef, U512,
+    CLTyped, CLValue, EntryPointType, Key, URef, URefAddr, U512,
 };
 
 use super::Runtime;
@@ -31,6 +31,20 @@ where
         self.context.get_caller()
     }
 
+    fn get_immediate_caller(&self) -> Option<AccountHash> {
+        // The mint itself is on the call stack if a deploy calls it directly.
+        let mint_contract_hash = self.protocol_data().mint();
+        let maybe_caller = self
+            .call_stack
+            .iter()
+            .rev()
+            .find(|element| element.contract_hash != mint_contract_hash);
+        match maybe_caller {
+            Some(element) if element.entry_point_type == EntryPointType::Contract => None,
+            _ => Some(self.context.get_caller()),
+        }
+    }
+
     fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
         self.context
             .put_key(name.to_string(), key)
@@ -112,6 +126,37 @@ where
             .metered_add_gs_unsafe(Key::Balance(uref.addr()), StoredValue::CLValue(cl_value))
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
+
+    fn read_allowance(
+        &mut self,
+        purse_addr: URefAddr,
+        spender: AccountHash,
+    ) -> Result<U512, Error> {
+        let maybe_value = self
+            .context
+            .read_gs_direct(&Key::allowance(purse_addr, spender))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))?;
+        match maybe_value {
+            Some(StoredValue::CLValue(value)) => CLValue::into_t(value).map_err(|_| Error::CLValue),
+            Some(_cl_value) => Err(Error::CLValue),
+            None => Ok(U512::zero()),
+        }
+    }
+
+    fn write_allowance(
+        &mut self,
+        purse_addr: URefAddr,
+        spender: AccountHash,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(amount).map_err(|_| Error::CLValue)?;
+        self.context
+            .metered_write_gs_unsafe(
+                Key::allowance(purse_addr, spender),
+                StoredValue::CLValue(cl_value),
+            )
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
 }
 
 impl<'a, R> SystemProvider for Runtime<'a, R>
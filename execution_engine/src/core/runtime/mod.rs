@@ -8,33 +8,35 @@ mod standard_payment_internal;
 
 use std::{
     cmp,
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
     iter::IntoIterator,
 };
 
-use itertools::Itertools;
 use parity_wasm::elements::Module;
+use tracing::warn;
 use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
 
 use casper_types::{
     account::{AccountHash, ActionType, Weight},
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, DeserializationLimits, FromBytes, ToBytes},
     contracts::{
-        self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
-        DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
+        self, Contract, ContractPackage, ContractPackageStatus, ContractVersion,
+        ContractVersionInfo, ContractVersions, DisabledVersions, EntryPoint, EntryPointAccess,
+        EntryPoints, Group, Groups, NamedKeys,
     },
     system::{
         self,
-        auction::{self, Auction, EraId, EraInfo},
+        auction::{self, Auction, EraId, EraInfo, ValidatorInfo},
         handle_payment::{self, HandlePayment},
         mint::{self, Mint},
         standard_payment::{self, StandardPayment},
         SystemContractType,
     },
-    AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
-    ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, U128, U256, U512,
+    AccessRights, AccessRightsValidator, ApiError, CLType, CLTyped, CLValue, ContractHash,
+    ContractPackageHash, ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase,
+    ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferReceipt, TransferResult,
+    TransferredTo, URef, URefAddr, U128, U256, U512,
 };
 
 use crate::{
@@ -44,7 +46,6 @@ use crate::{
         resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
         runtime::scoped_instrumenter::ScopedInstrumenter,
         runtime_context::{self, RuntimeContext},
-        Address,
     },
     shared::{
         account::Account,
@@ -56,6 +57,29 @@ use crate::{
     storage::{global_state::StateReader, protocol_data::ProtocolData},
 };
 
+/// The protocol version from which native standard payment is charged the fixed `pay` cost of the
+/// chainspec's standard payment costs.
+pub const STANDARD_PAYMENT_COST_PROTOCOL_VERSION: ProtocolVersion =
+    ProtocolVersion::from_parts(1, 1, 0);
+
+/// The protocol version from which host functions restricted to certain phases revert with
+/// `ApiError::InvalidPhase` when called in any other phase.
+pub const HOST_FUNCTION_PHASES_PROTOCOL_VERSION: ProtocolVersion =
+    ProtocolVersion::from_parts(1, 1, 0);
+
+/// The protocol version from which stored contract wasm is limited to the chainspec's
+/// `max_contract_wasm_size`.
+pub const MAX_CONTRACT_WASM_SIZE_PROTOCOL_VERSION: ProtocolVersion =
+    ProtocolVersion::from_parts(1, 1, 0);
+
+/// A contract entry point whose execution is in progress.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CallStackElement {
+    contract_hash: ContractHash,
+    entry_point_name: String,
+    entry_point_type: EntryPointType,
+}
+
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
     config: EngineConfig,
@@ -63,6 +87,8 @@ pub struct Runtime<'a, R> {
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    /// The contract entry points being executed, outermost first.
+    call_stack: Vec<CallStackElement>,
 }
 
 pub fn instance_and_memory(
@@ -98,46 +124,28 @@ pub fn key_to_tuple(key: Key) -> Option<([u8; 32], AccessRights)> {
         Key::Balance(_) => None,
         Key::Bid(_) => None,
         Key::Withdraw(_) => None,
+        Key::Allowance(_) => None,
     }
 }
 
-/// Groups a collection of urefs by their addresses and accumulates access
-/// rights per key
+/// Collects the access rights granted by a collection of urefs.
 pub fn extract_access_rights_from_urefs<I: IntoIterator<Item = URef>>(
     input: I,
-) -> HashMap<Address, HashSet<AccessRights>> {
-    input
-        .into_iter()
-        .map(|uref: URef| (uref.addr(), uref.access_rights()))
-        .group_by(|(key, _)| *key)
-        .into_iter()
-        .map(|(key, group)| {
-            (
-                key,
-                group.map(|(_, x)| x).collect::<HashSet<AccessRights>>(),
-            )
-        })
-        .collect()
+) -> AccessRightsValidator {
+    input.into_iter().collect()
 }
 
-/// Groups a collection of keys by their address and accumulates access rights
-/// per key.
+/// Collects the access rights granted by the urefs among a collection of keys.
 pub fn extract_access_rights_from_keys<I: IntoIterator<Item = Key>>(
     input: I,
-) -> HashMap<Address, HashSet<AccessRights>> {
-    input
-        .into_iter()
-        .map(key_to_tuple)
-        .flatten()
-        .group_by(|(key, _)| *key)
-        .into_iter()
-        .map(|(key, group)| {
-            (
-                key,
-                group.map(|(_, x)| x).collect::<HashSet<AccessRights>>(),
-            )
-        })
-        .collect()
+) -> AccessRightsValidator {
+    AccessRightsValidator::from_keys(input)
+}
+
+/// Deserializes bytes read from Wasm memory, bounding the allocations and nesting depth which the
+/// untrusted input can cause.
+fn deserialize_from_wasm<T: FromBytes>(bytes: Vec<u8>) -> Result<T, bytesrepr::Error> {
+    bytesrepr::deserialize_with_limit(bytes, DeserializationLimits::default())
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -979,9 +987,50 @@ where
             module,
             host_buffer: None,
             context,
+            call_stack: Vec::new(),
         }
     }
 
+    /// Records that `entry_point` of the contract at `contract_hash` is the entry point being
+    /// executed by this runtime.
+    pub(crate) fn push_call_stack(
+        &mut self,
+        contract_hash: ContractHash,
+        entry_point: &EntryPoint,
+    ) {
+        self.call_stack.push(CallStackElement {
+            contract_hash,
+            entry_point_name: entry_point.name().to_string(),
+            entry_point_type: entry_point.entry_point_type(),
+        });
+    }
+
+    /// Returns the call stack for a call from this runtime to `entry_point` of the contract at
+    /// `contract_hash`.
+    ///
+    /// Fails if `entry_point` is non-reentrant and is already being executed further up the call
+    /// stack.
+    fn call_stack_for(
+        &self,
+        contract_hash: ContractHash,
+        entry_point: &EntryPoint,
+    ) -> Result<Vec<CallStackElement>, Error> {
+        let element = CallStackElement {
+            contract_hash,
+            entry_point_name: entry_point.name().to_string(),
+            entry_point_type: entry_point.entry_point_type(),
+        };
+        if entry_point.is_non_reentrant() && self.call_stack.contains(&element) {
+            return Err(Error::ReentrantCall {
+                contract_hash: element.contract_hash,
+                entry_point_name: element.entry_point_name,
+            });
+        }
+        let mut call_stack = self.call_stack.clone();
+        call_stack.push(element);
+        Ok(call_stack)
+    }
+
     pub fn memory(&self) -> &MemoryRef {
         &self.memory
     }
@@ -1023,13 +1072,13 @@ where
 
     fn t_from_mem<T: FromBytes>(&self, ptr: u32, size: u32) -> Result<T, Error> {
         let bytes = self.bytes_from_mem(ptr, size as usize)?;
-        bytesrepr::deserialize(bytes).map_err(Into::into)
+        deserialize_from_wasm(bytes).map_err(Into::into)
     }
 
     /// Reads key (defined as `key_ptr` and `key_size` tuple) from Wasm memory.
     fn key_from_mem(&mut self, key_ptr: u32, key_size: u32) -> Result<Key, Error> {
         let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
-        bytesrepr::deserialize(bytes).map_err(Into::into)
+        deserialize_from_wasm(bytes).map_err(Into::into)
     }
 
     /// Reads `CLValue` (defined as `cl_value_ptr` and `cl_value_size` tuple) from Wasm memory.
@@ -1039,12 +1088,12 @@ where
         cl_value_size: u32,
     ) -> Result<CLValue, Error> {
         let bytes = self.bytes_from_mem(cl_value_ptr, cl_value_size as usize)?;
-        bytesrepr::deserialize(bytes).map_err(Into::into)
+        deserialize_from_wasm(bytes).map_err(Into::into)
     }
 
     fn string_from_mem(&self, ptr: u32, size: u32) -> Result<String, Trap> {
         let bytes = self.bytes_from_mem(ptr, size as usize)?;
-        bytesrepr::deserialize(bytes).map_err(|e| Error::BytesRepr(e).into())
+        deserialize_from_wasm(bytes).map_err(|e| Error::BytesRepr(e).into())
     }
 
     fn get_module_from_entry_points(
@@ -1080,7 +1129,7 @@ where
 
     fn is_valid_uref(&mut self, uref_ptr: u32, uref_size: u32) -> Result<bool, Trap> {
         let bytes = self.bytes_from_mem(uref_ptr, uref_size as usize)?;
-        let uref: URef = bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?;
+        let uref: URef = deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?;
         Ok(self.context.validate_uref(&uref).is_ok())
     }
 
@@ -1224,7 +1273,7 @@ where
             Ok(buf) => {
                 // Set the result field in the runtime and return the proper element of the `Error`
                 // enum indicating that the reason for exiting the module was a call to ret.
-                self.host_buffer = bytesrepr::deserialize(buf).ok();
+                self.host_buffer = deserialize_from_wasm(buf).ok();
 
                 let urefs = match &self.host_buffer {
                     Some(buf) => extract_urefs(buf),
@@ -1324,6 +1373,8 @@ where
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let gas_checkpoints = self.context.gas_checkpoints();
+        let events = self.context.events();
 
         let mint_context = RuntimeContext::new(
             self.context.state(),
@@ -1346,6 +1397,8 @@ where
             phase,
             *protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         );
 
         let mut mint_runtime = Runtime::new(
@@ -1355,6 +1408,7 @@ where
             self.module.clone(),
             mint_context,
         );
+        mint_runtime.call_stack = self.call_stack.clone();
 
         let system_config = protocol_data.system_config();
         let mint_costs = system_config.mint_costs();
@@ -1409,6 +1463,40 @@ where
                     mint_runtime.transfer(maybe_to, source, target, amount, id);
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
+            // Type: `fn approve(owner_purse: URef, spender: AccountHash, amount: U512) ->
+            // Result<(), Error>`
+            mint::METHOD_APPROVE => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.approve)?;
+
+                let owner_purse: URef =
+                    Self::get_named_argument(&runtime_args, mint::ARG_OWNER_PURSE)?;
+                let spender: AccountHash =
+                    Self::get_named_argument(&runtime_args, mint::ARG_SPENDER)?;
+                let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
+                let result: Result<(), mint::Error> =
+                    mint_runtime.approve(owner_purse, spender, amount);
+                if let Err(mint::Error::GasLimit) = result {
+                    return Err(execution::Error::GasLimit);
+                }
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+            // Type: `fn transfer_from(owner_purse: URefAddr, target: URef, amount: U512, id:
+            // Option<u64>) -> Result<(), Error>`
+            mint::METHOD_TRANSFER_FROM => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.transfer_from)?;
+
+                let owner_purse: URefAddr =
+                    Self::get_named_argument(&runtime_args, mint::ARG_OWNER_PURSE)?;
+                let target: URef = Self::get_named_argument(&runtime_args, mint::ARG_TARGET)?;
+                let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
+                let id: Option<u64> = Self::get_named_argument(&runtime_args, mint::ARG_ID)?;
+                let result: Result<(), mint::Error> =
+                    mint_runtime.transfer_from(owner_purse, target, amount, id);
+                if let Err(mint::Error::GasLimit) = result {
+                    return Err(execution::Error::GasLimit);
+                }
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
             // Type: `fn read_base_round_reward() -> Result<U512, Error>`
             mint::METHOD_READ_BASE_ROUND_REWARD => (|| {
                 mint_runtime.charge_system_contract_call(mint_costs.read_base_round_reward)?;
@@ -1469,6 +1557,8 @@ where
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let gas_checkpoints = self.context.gas_checkpoints();
+        let events = self.context.events();
 
         let runtime_context = RuntimeContext::new(
             self.context.state(),
@@ -1491,6 +1581,8 @@ where
             phase,
             *protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         );
 
         let mut runtime = Runtime::new(
@@ -1535,10 +1627,10 @@ where
                     Self::get_named_argument(&runtime_args, handle_payment::ARG_ACCOUNT)?;
                 let target: URef =
                     Self::get_named_argument(&runtime_args, handle_payment::ARG_TARGET)?;
-                runtime
+                let settlement = runtime
                     .finalize_payment(amount_spent, account, target)
                     .map_err(Self::reverter)?;
-                CLValue::from_t(()).map_err(Self::reverter)
+                CLValue::from_t(settlement).map_err(Self::reverter)
             })(),
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
@@ -1559,6 +1651,15 @@ where
     pub fn call_host_standard_payment(&mut self) -> Result<(), Error> {
         // NOTE: This method (unlike other call_host_* methods) already runs on its own runtime
         // context.
+        if self.context.protocol_version() >= STANDARD_PAYMENT_COST_PROTOCOL_VERSION {
+            let pay_cost = self
+                .protocol_data()
+                .system_config()
+                .standard_payment_costs()
+                .pay;
+            self.charge_system_contract_call(pay_cost)?;
+        }
+        // The purse operations performed on behalf of the caller are not charged for.
         let gas_counter = self.gas_counter();
         let amount: U512 =
             Self::get_named_argument(&self.context.args(), standard_payment::ARG_AMOUNT)?;
@@ -1567,6 +1668,21 @@ where
         result
     }
 
+    /// Returns the contract package of the stored contract being executed, or `None` if session
+    /// code is being executed.
+    fn get_contract_package_hash(&mut self) -> Result<Option<ContractPackageHash>, Error> {
+        if self.context.entry_point_type() != EntryPointType::Contract {
+            return Ok(None);
+        }
+        match self.context.base_key() {
+            contract_key @ Key::Hash(_) => {
+                let contract: Contract = self.context.read_gs_typed(&contract_key)?;
+                Ok(Some(contract.contract_package_hash()))
+            }
+            _ => Ok(None),
+        }
+    }
+
     pub fn call_host_auction(
         &mut self,
         protocol_version: ProtocolVersion,
@@ -1575,6 +1691,14 @@ where
         runtime_args: &RuntimeArgs,
         extra_keys: &[Key],
     ) -> Result<CLValue, Error> {
+        // Contract delegators are identified by the package of the calling contract.
+        let contract_package_hash = match entry_point_name {
+            auction::METHOD_DELEGATE_FROM_CONTRACT | auction::METHOD_UNDELEGATE_FROM_CONTRACT => {
+                self.get_contract_package_hash()?
+            }
+            _ => None,
+        };
+
         let access_rights = {
             let mut keys: Vec<Key> = named_keys.values().cloned().collect();
             keys.extend(extra_keys);
@@ -1596,6 +1720,8 @@ where
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let gas_checkpoints = self.context.gas_checkpoints();
+        let events = self.context.events();
 
         let runtime_context = RuntimeContext::new(
             self.context.state(),
@@ -1618,6 +1744,8 @@ where
             phase,
             *protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         );
 
         let mut runtime = Runtime::new(
@@ -1697,6 +1825,34 @@ where
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_DELEGATE_FROM_CONTRACT => (|| {
+                runtime.charge_system_contract_call(auction_costs.delegate)?;
+
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+                let source_purse =
+                    Self::get_named_argument(&runtime_args, auction::ARG_SOURCE_PURSE)?;
+
+                let result = runtime
+                    .delegate_from_contract(contract_package_hash, validator, amount, source_purse)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_UNDELEGATE_FROM_CONTRACT => (|| {
+                runtime.charge_system_contract_call(auction_costs.undelegate)?;
+
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+
+                let result = runtime
+                    .undelegate_from_contract(contract_package_hash, validator, amount)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             auction::METHOD_RUN_AUCTION => (|| {
                 runtime.charge_system_contract_call(auction_costs.run_auction)?;
 
@@ -1755,6 +1911,77 @@ where
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_CANCEL_VALIDATOR_UNBOND => (|| {
+                runtime.charge_system_contract_call(auction_costs.cancel_validator_unbond)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+
+                let result = runtime
+                    .cancel_validator_unbond(public_key, amount)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_CANCEL_DELEGATOR_UNBOND => (|| {
+                runtime.charge_system_contract_call(auction_costs.cancel_delegator_unbond)?;
+
+                let delegator = Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+
+                let result = runtime
+                    .cancel_delegator_unbond(delegator, validator, amount)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_GET_BID => (|| {
+                runtime.charge_system_contract_call(auction_costs.get_bid)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+
+                let result = runtime.get_bid(public_key).map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_GET_DELEGATOR => (|| {
+                runtime.charge_system_contract_call(auction_costs.get_delegator)?;
+
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let delegator = Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+
+                let result = runtime
+                    .get_delegator(validator, delegator)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SET_VALIDATOR_INFO => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_validator_info)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let name = Self::get_named_argument(&runtime_args, auction::ARG_NAME)?;
+                let url = Self::get_named_argument(&runtime_args, auction::ARG_URL)?;
+                let icon_hash = Self::get_named_argument(&runtime_args, auction::ARG_ICON_HASH)?;
+
+                let validator_info =
+                    ValidatorInfo::new(name, url, icon_hash).map_err(Self::reverter)?;
+                runtime.charge_system_contract_call(
+                    u64::from(auction_costs.set_validator_info_byte) * validator_info.size() as u64,
+                )?;
+
+                runtime
+                    .set_validator_info(public_key, validator_info)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
@@ -1804,6 +2031,7 @@ where
         self.execute_contract(
             key,
             context_key,
+            contract_hash,
             contract,
             args,
             entry_point,
@@ -1886,6 +2114,7 @@ where
         self.execute_contract(
             context_key,
             context_key,
+            contract_hash,
             contract,
             args,
             entry_point,
@@ -1914,10 +2143,12 @@ where
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn execute_contract(
         &mut self,
         key: Key,
         base_key: Key,
+        contract_hash: ContractHash,
         contract: Contract,
         args: RuntimeArgs,
         entry_point: EntryPoint,
@@ -1931,6 +2162,8 @@ where
             });
         }
 
+        let call_stack = self.call_stack_for(contract_hash, &entry_point)?;
+
         // TODO: should we be using named_keys_mut() instead?
         let mut named_keys = match entry_point.entry_point_type() {
             EntryPointType::Session => self.context.account().named_keys().clone(),
@@ -2051,6 +2284,8 @@ where
             self.context.phase(),
             *self.context.protocol_data(),
             self.context.transfers().to_owned(),
+            self.context.gas_checkpoints(),
+            self.context.events(),
         );
 
         let mut runtime = Runtime {
@@ -2060,6 +2295,7 @@ where
             module,
             host_buffer,
             context,
+            call_stack,
         };
 
         let result = instance.invoke_export(entry_point_name, &[], &mut runtime);
@@ -2100,8 +2336,7 @@ where
             match downcasted_error {
                 Some(Error::Ret(ref ret_urefs)) => {
                     // insert extra urefs returned from call
-                    let ret_urefs_map: HashMap<Address, HashSet<AccessRights>> =
-                        extract_access_rights_from_urefs(ret_urefs.clone());
+                    let ret_urefs_map = extract_access_rights_from_urefs(ret_urefs.clone());
                     self.context.access_rights_extend(ret_urefs_map);
                     // if ret has not set host_buffer consider it programmer error
                     if self.context.entry_point_type() == EntryPointType::Session
@@ -2121,11 +2356,54 @@ where
         Err(Error::Interpreter(error.into()))
     }
 
+    /// Runs `call` with the gas available to it capped at `sub_call_gas_limit` more than has been
+    /// used so far, if given.
+    ///
+    /// If `call` exhausts its sub-limit, every change it made is discarded, the whole sub-limit is
+    /// charged and `ApiError::OutOfSubCallGas` is returned so the caller can carry on.
+    fn call_with_gas_limit<F>(
+        &mut self,
+        sub_call_gas_limit: Option<Gas>,
+        call: F,
+    ) -> Result<Result<CLValue, ApiError>, Error>
+    where
+        F: FnOnce(&mut Self) -> Result<CLValue, Error>,
+    {
+        let gas_limit = self.context.gas_limit();
+        let sub_call_gas_limit = match sub_call_gas_limit.and_then(|sub_call_gas_limit| {
+            self.context.gas_counter().checked_add(sub_call_gas_limit)
+        }) {
+            Some(sub_call_gas_limit) if sub_call_gas_limit < gas_limit => sub_call_gas_limit,
+            // The caller's own limit is the tighter one, so running out of gas is fatal as usual.
+            _ => return call(self).map(Ok),
+        };
+
+        let checkpoint = self.context.state().borrow().checkpoint();
+        let transfers = self.context.transfers().to_owned();
+        let events = self.context.events();
+        let event_count = events.borrow().len();
+
+        self.context.set_gas_limit(sub_call_gas_limit);
+        let result = call(self);
+        self.context.set_gas_limit(gas_limit);
+
+        match result {
+            Err(Error::GasLimit) => {
+                self.context.state().borrow_mut().restore(checkpoint);
+                *self.context.transfers_mut() = transfers;
+                events.borrow_mut().truncate(event_count);
+                Ok(Err(ApiError::OutOfSubCallGas))
+            }
+            result => result.map(Ok),
+        }
+    }
+
     fn call_contract_host_buffer(
         &mut self,
         contract_hash: ContractHash,
         entry_point_name: &str,
         args_bytes: Vec<u8>,
+        sub_call_gas_limit: Option<Gas>,
         result_size_ptr: u32,
         scoped_instrumenter: &mut ScopedInstrumenter,
     ) -> Result<Result<(), ApiError>, Error> {
@@ -2133,19 +2411,26 @@ where
         if let Err(err) = self.check_host_buffer() {
             return Ok(Err(err));
         }
-        let args: RuntimeArgs = bytesrepr::deserialize(args_bytes)?;
+        let args: RuntimeArgs = deserialize_from_wasm(args_bytes)?;
         scoped_instrumenter.pause();
-        let result = self.call_contract(contract_hash, entry_point_name, args)?;
+        let result = self.call_with_gas_limit(sub_call_gas_limit, |runtime| {
+            runtime.call_contract(contract_hash, entry_point_name, args)
+        })?;
         scoped_instrumenter.unpause();
-        self.manage_call_contract_host_buffer(result_size_ptr, result)
+        match result {
+            Ok(result) => self.manage_call_contract_host_buffer(result_size_ptr, result),
+            Err(error) => Ok(Err(error)),
+        }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn call_versioned_contract_host_buffer(
         &mut self,
         contract_package_hash: ContractPackageHash,
         contract_version: Option<ContractVersion>,
         entry_point_name: String,
         args_bytes: Vec<u8>,
+        sub_call_gas_limit: Option<Gas>,
         result_size_ptr: u32,
         scoped_instrumenter: &mut ScopedInstrumenter,
     ) -> Result<Result<(), ApiError>, Error> {
@@ -2153,16 +2438,21 @@ where
         if let Err(err) = self.check_host_buffer() {
             return Ok(Err(err));
         }
-        let args: RuntimeArgs = bytesrepr::deserialize(args_bytes)?;
+        let args: RuntimeArgs = deserialize_from_wasm(args_bytes)?;
         scoped_instrumenter.pause();
-        let result = self.call_versioned_contract(
-            contract_package_hash,
-            contract_version,
-            entry_point_name,
-            args,
-        )?;
+        let result = self.call_with_gas_limit(sub_call_gas_limit, |runtime| {
+            runtime.call_versioned_contract(
+                contract_package_hash,
+                contract_version,
+                entry_point_name,
+                args,
+            )
+        })?;
         scoped_instrumenter.unpause();
-        self.manage_call_contract_host_buffer(result_size_ptr, result)
+        match result {
+            Ok(result) => self.manage_call_contract_host_buffer(result_size_ptr, result),
+            Err(error) => Ok(Err(error)),
+        }
     }
 
     fn check_host_buffer(&mut self) -> Result<(), ApiError> {
@@ -2281,6 +2571,12 @@ where
             .context
             .get_validated_contract_package(contract_package_hash)?;
 
+        // Return an error in trying to change the user groups of a frozen contract.  The groups
+        // of packages created locked remain mutable.
+        if contract_package.is_frozen() {
+            return Err(Error::LockedContract(contract_package_hash));
+        }
+
         let groups = contract_package.groups_mut();
         let new_group = Group::new(label);
 
@@ -2350,12 +2646,13 @@ where
         bytes_written_ptr: u32,
         version_ptr: u32,
     ) -> Result<Result<(), ApiError>, Error> {
-        self.context
-            .validate_key(&Key::from(contract_package_hash))?;
-
-        let mut contract_package: ContractPackage = self
+        let mut contract_package = match self
             .context
-            .get_validated_contract_package(contract_package_hash)?;
+            .get_contract_package_for_version_management(contract_package_hash)?
+        {
+            Ok(contract_package) => contract_package,
+            Err(error) => return Ok(Err(error.into())),
+        };
 
         let version = contract_package.current_contract_version();
 
@@ -2364,10 +2661,31 @@ where
             return Err(Error::LockedContract(contract_package_hash));
         }
 
+        for entry_point_name in entry_points.keys() {
+            let uses_any_type = entry_points
+                .get(entry_point_name)
+                .map_or(false, EntryPoint::uses_any_type);
+            if uses_any_type {
+                warn!(
+                    %entry_point_name,
+                    "entry point declares deprecated CLType::Any, use DynamicValue instead"
+                );
+            }
+        }
+
         let contract_wasm_hash = self.context.new_hash_address()?;
         let contract_wasm = {
             let module_bytes = self.get_module_from_entry_points(&entry_points)?;
-            ContractWasm::new(module_bytes)
+            if self.context.protocol_version() >= MAX_CONTRACT_WASM_SIZE_PROTOCOL_VERSION {
+                let max_contract_wasm_size = self
+                    .context
+                    .protocol_data()
+                    .wasm_config()
+                    .max_contract_wasm_size;
+                ContractWasm::try_new(module_bytes, max_contract_wasm_size as usize)?
+            } else {
+                ContractWasm::new(module_bytes)
+            }
         };
 
         let contract_hash = self.context.new_hash_address()?;
@@ -2392,8 +2710,13 @@ where
             protocol_version,
         );
 
-        let insert_contract_result =
-            contract_package.insert_contract_version(major, contract_hash.into());
+        let version_info = ContractVersionInfo::new(
+            ContractHash::new(contract_hash),
+            self.context.get_blocktime(),
+            self.context.get_caller(),
+            None,
+        );
+        let insert_contract_result = contract_package.insert_contract_version(major, version_info);
 
         self.context
             .metered_write_gs_unsafe(contract_wasm_hash, contract_wasm)?;
@@ -2441,12 +2764,14 @@ where
         contract_package_hash: ContractPackageHash,
         contract_hash: ContractHash,
     ) -> Result<Result<(), ApiError>, Error> {
-        let contract_package_key = contract_package_hash.into();
-        self.context.validate_key(&contract_package_key)?;
-
-        let mut contract_package: ContractPackage = self
+        let contract_package_key: Key = contract_package_hash.into();
+        let mut contract_package = match self
             .context
-            .get_validated_contract_package(contract_package_hash)?;
+            .get_contract_package_for_version_management(contract_package_hash)?
+        {
+            Ok(contract_package) => contract_package,
+            Err(error) => return Ok(Err(error.into())),
+        };
 
         // Return an error in trying to disable the (singular) version of a locked contract.
         if contract_package.is_locked() {
@@ -2463,6 +2788,32 @@ where
         Ok(Ok(()))
     }
 
+    fn lock_contract_package(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let mut contract_package = match self
+            .context
+            .get_contract_package_for_version_management(contract_package_hash)?
+        {
+            Ok(contract_package) => contract_package,
+            Err(error) => return Ok(Err(error.into())),
+        };
+
+        // Locking is permanent, so there is nothing to do for an already frozen package.  Packages
+        // created locked are frozen, so that their user groups can no longer be changed either.
+        if contract_package.is_frozen() {
+            return Ok(Ok(()));
+        }
+
+        contract_package.lock();
+
+        self.context
+            .metered_write_gs_unsafe(contract_package_hash, contract_package)?;
+
+        Ok(Ok(()))
+    }
+
     /// Writes function address (`hash_bytes`) into the Wasm memory (at
     /// `dest_ptr` pointer).
     fn function_address(&mut self, hash_bytes: [u8; 32], dest_ptr: u32) -> Result<(), Trap> {
@@ -2481,6 +2832,13 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Records an event with the payload read from Wasm memory, to be reported in the execution
+    /// result of the deploy.
+    fn emit_event(&mut self, payload_ptr: u32, payload_size: u32) -> Result<(), Trap> {
+        let payload = self.cl_value_from_mem(payload_ptr, payload_size)?;
+        self.context.emit_event(payload).map_err(Into::into)
+    }
+
     /// Writes `value` under `key` in GlobalState.
     fn write(
         &mut self,
@@ -2610,7 +2968,7 @@ where
             let source_serialized = self.bytes_from_mem(account_hash_ptr, account_hash_size)?;
             // Account hash deserialized
             let source: AccountHash =
-                bytesrepr::deserialize(source_serialized).map_err(Error::BytesRepr)?;
+                deserialize_from_wasm(source_serialized).map_err(Error::BytesRepr)?;
             source
         };
         let weight = Weight::new(weight_value);
@@ -2637,7 +2995,7 @@ where
             let source_serialized = self.bytes_from_mem(account_hash_ptr, account_hash_size)?;
             // Account hash deserialized
             let source: AccountHash =
-                bytesrepr::deserialize(source_serialized).map_err(Error::BytesRepr)?;
+                deserialize_from_wasm(source_serialized).map_err(Error::BytesRepr)?;
             source
         };
         match self.context.remove_associated_key(account_hash) {
@@ -2658,7 +3016,7 @@ where
             let source_serialized = self.bytes_from_mem(account_hash_ptr, account_hash_size)?;
             // Account hash deserialized
             let source: AccountHash =
-                bytesrepr::deserialize(source_serialized).map_err(Error::BytesRepr)?;
+                deserialize_from_wasm(source_serialized).map_err(Error::BytesRepr)?;
             source
         };
         let weight = Weight::new(weight_value);
@@ -2932,6 +3290,62 @@ where
         }
     }
 
+    /// Transfers `amount` of motes from `source` purse to `target` account, then writes a
+    /// [`TransferReceipt`] for the transfer to the host buffer and its size to `output_size_ptr`.
+    ///
+    /// This host function may only be called during the session phase, which is the only phase in
+    /// which transfers are recorded.  Transfers are not recorded at all if `source` is the target
+    /// account's main purse, in which case `ApiError::TransferNotRecorded` is returned after the
+    /// transfer.
+    fn transfer_from_purse_to_account_with_receipt(
+        &mut self,
+        source: URef,
+        target: AccountHash,
+        amount: U512,
+        id: Option<u64>,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let transfer_count = self.context.transfers().len();
+        if let Err(api_error) = self.transfer_from_purse_to_account(source, target, amount, id)? {
+            return Ok(Err(api_error));
+        }
+
+        let transfer_addr = match self.context.transfers().get(transfer_count) {
+            Some(transfer_addr) => *transfer_addr,
+            None => return Ok(Err(ApiError::TransferNotRecorded)),
+        };
+        let receipt = TransferReceipt::new(
+            transfer_addr,
+            self.context.account().account_hash(),
+            target,
+            amount,
+            self.context.get_blocktime(),
+            id,
+        );
+
+        let receipt_cl_value = match CLValue::from_t(receipt) {
+            Ok(cl_value) => cl_value,
+            Err(error) => return Ok(Err(error.into())),
+        };
+
+        let receipt_size = receipt_cl_value.inner_bytes().len() as i32;
+        if let Err(error) = self.write_host_buffer(receipt_cl_value) {
+            return Ok(Err(error));
+        }
+
+        let receipt_size_bytes = receipt_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &receipt_size_bytes) {
+            return Err(Error::Interpreter(error.into()));
+        }
+
+        Ok(Ok(()))
+    }
+
     /// Transfers `amount` of motes from `source` purse to `target` purse.
     #[allow(clippy::too_many_arguments)]
     fn transfer_from_purse_to_purse(
@@ -2947,22 +3361,22 @@ where
     ) -> Result<Result<(), mint::Error>, Error> {
         let source: URef = {
             let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
-            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+            deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
         };
 
         let target: URef = {
             let bytes = self.bytes_from_mem(target_ptr, target_size as usize)?;
-            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+            deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
         };
 
         let amount: U512 = {
             let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
-            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+            deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
         };
 
         let id: Option<u64> = {
             let bytes = self.bytes_from_mem(id_ptr, id_size as usize)?;
-            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+            deserialize_from_wasm(bytes).map_err(Error::BytesRepr)?
         };
 
         let mint_contract_key = self.get_mint_contract();
@@ -2998,7 +3412,7 @@ where
 
         let purse: URef = {
             let bytes = self.bytes_from_mem(purse_ptr, purse_size)?;
-            match bytesrepr::deserialize(bytes) {
+            match deserialize_from_wasm(bytes) {
                 Ok(purse) => purse,
                 Err(error) => return Ok(Err(error.into())),
             }
@@ -3107,7 +3521,7 @@ where
     #[cfg(feature = "test-support")]
     fn print(&mut self, text_ptr: u32, text_size: u32) -> Result<(), Trap> {
         let text = self.string_from_mem(text_ptr, text_size)?;
-        println!("{}", text);
+        crate::shared::output_capture::print(&text);
         Ok(())
     }
 
@@ -3185,6 +3599,12 @@ where
         let mut package: ContractPackage =
             self.context.get_validated_contract_package(package_key)?;
 
+        // Return an error in trying to change the user groups of a frozen contract.  The groups
+        // of packages created locked remain mutable.
+        if package.is_frozen() {
+            return Err(Error::LockedContract(package_key));
+        }
+
         let group_to_remove = Group::new(label);
         let groups = package.groups_mut();
 
@@ -3195,9 +3615,11 @@ where
 
         // Remove group if it is not referenced by at least one entry_point in active versions.
         let versions = package.versions();
-        for contract_hash in versions.values() {
+        for version_info in versions.values() {
             let entry_points = {
-                let contract: Contract = self.context.read_gs_typed(&Key::from(*contract_hash))?;
+                let contract: Contract = self
+                    .context
+                    .read_gs_typed(&Key::from(version_info.contract_hash()))?;
                 contract.entry_points().clone().take_entry_points()
             };
             for entry_point in entry_points {
@@ -3237,6 +3659,13 @@ where
         let mut contract_package = self
             .context
             .get_validated_contract_package(contract_package_hash)?;
+
+        // Return an error in trying to change the user groups of a frozen contract.  The groups
+        // of packages created locked remain mutable.
+        if contract_package.is_frozen() {
+            return Err(Error::LockedContract(contract_package_hash));
+        }
+
         let groups = contract_package.groups_mut();
 
         let group_label = Group::new(label);
@@ -3288,6 +3717,86 @@ where
         Ok(Ok(()))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn provision_contract_user_group_urefs(
+        &mut self,
+        package_ptr: u32,
+        package_size: u32,
+        label_ptr: u32,
+        label_size: u32,
+        num_new_urefs: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let contract_package_hash = self.t_from_mem(package_ptr, package_size)?;
+        let label: String = self.t_from_mem(label_ptr, label_size)?;
+        let mut contract_package = self
+            .context
+            .get_validated_contract_package(contract_package_hash)?;
+
+        // Return an error in trying to change the user groups of a frozen contract.  The groups
+        // of packages created locked remain mutable.
+        if contract_package.is_frozen() {
+            return Err(Error::LockedContract(contract_package_hash));
+        }
+
+        let groups = contract_package.groups_mut();
+
+        let group_label = Group::new(label);
+
+        // Ensure there are not too many urefs.  All limits are checked before any URef is created,
+        // so the group is either extended by all of the new URefs or left unchanged.
+        let total_urefs: usize = groups.values().map(|urefs| urefs.len()).sum();
+
+        if total_urefs + num_new_urefs as usize > contracts::MAX_TOTAL_UREFS {
+            return Ok(Err(contracts::Error::MaxTotalURefsExceeded.into()));
+        }
+
+        // Ensure given group exists and does not exceed limits
+        let group = match groups.get_mut(&group_label) {
+            Some(group)
+                if group.len() + num_new_urefs as usize > contracts::MAX_GROUPS as usize =>
+            {
+                // Ensures there are not too many groups to fit in amount of new urefs
+                return Ok(Err(contracts::Error::MaxTotalURefsExceeded.into()));
+            }
+            Some(group) => group,
+            None => return Ok(Err(contracts::Error::GroupDoesNotExist.into())),
+        };
+
+        // Proceed with creating new URefs
+        let mut new_urefs = Vec::with_capacity(num_new_urefs as usize);
+        for _ in 0..num_new_urefs {
+            let new_uref = self.context.new_unit_uref()?;
+            if !group.insert(new_uref) {
+                return Ok(Err(contracts::Error::URefAlreadyExists.into()));
+            }
+            new_urefs.push(new_uref);
+        }
+
+        // check we can write to the host buffer
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        // create CLValue for return value
+        let new_urefs_value = CLValue::from_t(new_urefs)?;
+        let value_size = new_urefs_value.inner_bytes().len();
+        // write return value to buffer
+        if let Err(err) = self.write_host_buffer(new_urefs_value) {
+            return Ok(Err(err));
+        }
+        // Write return value size to output location
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &output_size_bytes) {
+            return Err(Error::Interpreter(error.into()));
+        }
+
+        // Write updated package to the global state
+        self.context
+            .metered_write_gs_unsafe(contract_package_hash, contract_package)?;
+
+        Ok(Ok(()))
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn remove_contract_user_group_urefs(
         &mut self,
@@ -3307,6 +3816,12 @@ where
             .context
             .get_validated_contract_package(contract_package_hash)?;
 
+        // Return an error in trying to change the user groups of a frozen contract.  The groups
+        // of packages created locked remain mutable.
+        if contract_package.is_frozen() {
+            return Err(Error::LockedContract(contract_package_hash));
+        }
+
         let groups = contract_package.groups_mut();
         let group_label = Group::new(label);
 
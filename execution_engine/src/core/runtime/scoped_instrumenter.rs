@@ -88,61 +88,9 @@ impl ScopedInstrumenter {
 impl Drop for ScopedInstrumenter {
     fn drop(&mut self) {
         let duration = self.duration();
-        let host_function = match self.function_index {
-            FunctionIndex::GasFuncIndex => return,
-            FunctionIndex::WriteFuncIndex => "host_function_write",
-            FunctionIndex::ReadFuncIndex => "host_function_read_value",
-            FunctionIndex::AddFuncIndex => "host_function_add",
-            FunctionIndex::NewFuncIndex => "host_function_new_uref",
-            FunctionIndex::RetFuncIndex => "host_function_ret",
-            FunctionIndex::CallContractFuncIndex => "host_function_call_contract",
-            FunctionIndex::GetKeyFuncIndex => "host_function_get_key",
-            FunctionIndex::HasKeyFuncIndex => "host_function_has_key",
-            FunctionIndex::PutKeyFuncIndex => "host_function_put_key",
-            FunctionIndex::IsValidURefFnIndex => "host_function_is_valid_uref",
-            FunctionIndex::RevertFuncIndex => "host_function_revert",
-            FunctionIndex::AddAssociatedKeyFuncIndex => "host_function_add_associated_key",
-            FunctionIndex::RemoveAssociatedKeyFuncIndex => "host_function_remove_associated_key",
-            FunctionIndex::UpdateAssociatedKeyFuncIndex => "host_function_update_associated_key",
-            FunctionIndex::SetActionThresholdFuncIndex => "host_function_set_action_threshold",
-            FunctionIndex::LoadNamedKeysFuncIndex => "host_function_load_named_keys",
-            FunctionIndex::RemoveKeyFuncIndex => "host_function_remove_key",
-            FunctionIndex::GetCallerIndex => "host_function_get_caller",
-            FunctionIndex::GetBlocktimeIndex => "host_function_get_blocktime",
-            FunctionIndex::CreatePurseIndex => "host_function_create_purse",
-            FunctionIndex::TransferToAccountIndex => "host_function_transfer_to_account",
-            FunctionIndex::TransferFromPurseToAccountIndex => {
-                "host_function_transfer_from_purse_to_account"
-            }
-            FunctionIndex::TransferFromPurseToPurseIndex => {
-                "host_function_transfer_from_purse_to_purse"
-            }
-            FunctionIndex::GetBalanceIndex => "host_function_get_balance",
-            FunctionIndex::GetPhaseIndex => "host_function_get_phase",
-            FunctionIndex::GetSystemContractIndex => "host_function_get_system_contract",
-            FunctionIndex::GetMainPurseIndex => "host_function_get_main_purse",
-            FunctionIndex::ReadHostBufferIndex => "host_function_read_host_buffer",
-            FunctionIndex::CreateContractPackageAtHash => {
-                "host_function_create_contract_package_at_hash"
-            }
-            FunctionIndex::AddContractVersion => "host_function_add_contract_version",
-            FunctionIndex::DisableContractVersion => "host_remove_contract_version",
-            FunctionIndex::CallVersionedContract => "host_call_versioned_contract",
-            FunctionIndex::CreateContractUserGroup => "create_contract_user_group",
-            #[cfg(feature = "test-support")]
-            FunctionIndex::PrintIndex => "host_function_print",
-            FunctionIndex::GetRuntimeArgsizeIndex => "host_get_named_arg_size",
-            FunctionIndex::GetRuntimeArgIndex => "host_get_named_arg",
-            FunctionIndex::RemoveContractUserGroupIndex => "host_remove_contract_user_group",
-            FunctionIndex::ExtendContractUserGroupURefsIndex => {
-                "host_provision_contract_user_group_uref"
-            }
-            FunctionIndex::RemoveContractUserGroupURefsIndex => {
-                "host_remove_contract_user_group_urefs"
-            }
-            FunctionIndex::Blake2b => "host_blake2b",
-            FunctionIndex::RecordTransfer => "host_record_transfer",
-            FunctionIndex::RecordEraInfo => "host_record_era_info",
+        let host_function = match host_function_name(self.function_index) {
+            Some(host_function) => host_function,
+            None => return,
         };
 
         let mut properties = mem::take(&mut self.properties);
@@ -154,3 +102,75 @@ impl Drop for ScopedInstrumenter {
         log_host_function_metrics(host_function, properties);
     }
 }
+
+/// Returns the name under which calls to the given host function are reported, or `None` for the
+/// internal gas charging function.
+pub(super) fn host_function_name(function_index: FunctionIndex) -> Option<&'static str> {
+    let host_function = match function_index {
+        FunctionIndex::GasFuncIndex => return None,
+        FunctionIndex::WriteFuncIndex => "host_function_write",
+        FunctionIndex::ReadFuncIndex => "host_function_read_value",
+        FunctionIndex::AddFuncIndex => "host_function_add",
+        FunctionIndex::NewFuncIndex => "host_function_new_uref",
+        FunctionIndex::RetFuncIndex => "host_function_ret",
+        FunctionIndex::CallContractFuncIndex => "host_function_call_contract",
+        FunctionIndex::GetKeyFuncIndex => "host_function_get_key",
+        FunctionIndex::HasKeyFuncIndex => "host_function_has_key",
+        FunctionIndex::PutKeyFuncIndex => "host_function_put_key",
+        FunctionIndex::IsValidURefFnIndex => "host_function_is_valid_uref",
+        FunctionIndex::RevertFuncIndex => "host_function_revert",
+        FunctionIndex::AddAssociatedKeyFuncIndex => "host_function_add_associated_key",
+        FunctionIndex::RemoveAssociatedKeyFuncIndex => "host_function_remove_associated_key",
+        FunctionIndex::UpdateAssociatedKeyFuncIndex => "host_function_update_associated_key",
+        FunctionIndex::SetActionThresholdFuncIndex => "host_function_set_action_threshold",
+        FunctionIndex::LoadNamedKeysFuncIndex => "host_function_load_named_keys",
+        FunctionIndex::RemoveKeyFuncIndex => "host_function_remove_key",
+        FunctionIndex::GetCallerIndex => "host_function_get_caller",
+        FunctionIndex::GetBlocktimeIndex => "host_function_get_blocktime",
+        FunctionIndex::CreatePurseIndex => "host_function_create_purse",
+        FunctionIndex::TransferToAccountIndex => "host_function_transfer_to_account",
+        FunctionIndex::TransferFromPurseToAccountIndex => {
+            "host_function_transfer_from_purse_to_account"
+        }
+        FunctionIndex::TransferFromPurseToPurseIndex => {
+            "host_function_transfer_from_purse_to_purse"
+        }
+        FunctionIndex::GetBalanceIndex => "host_function_get_balance",
+        FunctionIndex::GetPhaseIndex => "host_function_get_phase",
+        FunctionIndex::GetSystemContractIndex => "host_function_get_system_contract",
+        FunctionIndex::GetMainPurseIndex => "host_function_get_main_purse",
+        FunctionIndex::ReadHostBufferIndex => "host_function_read_host_buffer",
+        FunctionIndex::CreateContractPackageAtHash => {
+            "host_function_create_contract_package_at_hash"
+        }
+        FunctionIndex::AddContractVersion => "host_function_add_contract_version",
+        FunctionIndex::DisableContractVersion => "host_remove_contract_version",
+        FunctionIndex::CallVersionedContract => "host_call_versioned_contract",
+        FunctionIndex::CreateContractUserGroup => "create_contract_user_group",
+        #[cfg(feature = "test-support")]
+        FunctionIndex::PrintIndex => "host_function_print",
+        FunctionIndex::GetRuntimeArgsizeIndex => "host_get_named_arg_size",
+        FunctionIndex::GetRuntimeArgIndex => "host_get_named_arg",
+        FunctionIndex::RemoveContractUserGroupIndex => "host_remove_contract_user_group",
+        FunctionIndex::ExtendContractUserGroupURefsIndex => {
+            "host_provision_contract_user_group_uref"
+        }
+        FunctionIndex::RemoveContractUserGroupURefsIndex => "host_remove_contract_user_group_urefs",
+        FunctionIndex::Blake2b => "host_blake2b",
+        FunctionIndex::RecordTransfer => "host_record_transfer",
+        FunctionIndex::RecordEraInfo => "host_record_era_info",
+        FunctionIndex::LockContractPackage => "host_lock_contract_package",
+        FunctionIndex::EmitEvent => "host_emit_event",
+        FunctionIndex::CallContractWithGasLimit => "host_call_contract_with_gas_limit",
+        FunctionIndex::CallVersionedContractWithGasLimit => {
+            "host_call_versioned_contract_with_gas_limit"
+        }
+        FunctionIndex::TransferFromPurseToAccountWithReceipt => {
+            "host_transfer_from_purse_to_account_with_receipt"
+        }
+        FunctionIndex::ProvisionContractUserGroupURefsIndex => {
+            "host_provision_contract_user_group_urefs"
+        }
+    };
+    Some(host_function)
+}
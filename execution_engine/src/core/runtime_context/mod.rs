@@ -1,6 +1,6 @@
 use std::{
     cell::RefCell,
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::BTreeSet,
     convert::{TryFrom, TryInto},
     fmt::Debug,
     rc::Rc,
@@ -13,19 +13,20 @@ use casper_types::{
     },
     bytesrepr,
     bytesrepr::ToBytes,
-    contracts::NamedKeys,
+    contracts::{self, NamedKeys},
     system::auction::EraInfo,
-    AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    DeployHash, DeployInfo, EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, KEY_HASH_LENGTH,
+    validate_named_key_name, AccessRights, AccessRightsValidator, ApiError, BlockTime, CLType,
+    CLValue, Contract, ContractEvent, ContractPackage, ContractPackageHash, DeployHash, DeployInfo,
+    EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
+    Transfer, TransferAddr, URef, KEY_HASH_LENGTH,
 };
 
 use crate::{
     core::{
+        authorization,
         engine_state::execution_effect::ExecutionEffect,
-        execution::{AddressGenerator, Error},
+        execution::{AddressGenerator, Error, GasCheckpoints},
         tracking_copy::{AddResult, TrackingCopy},
-        Address,
     },
     shared::{account::Account, gas::Gas, newtypes::CorrelationId, stored_value::StoredValue},
     storage::{global_state::StateReader, protocol_data::ProtocolData},
@@ -34,23 +35,6 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
-/// Checks whether given uref has enough access rights.
-pub(crate) fn uref_has_access_rights(
-    uref: &URef,
-    access_rights: &HashMap<Address, HashSet<AccessRights>>,
-) -> bool {
-    if let Some(known_rights) = access_rights.get(&uref.addr()) {
-        let new_rights = uref.access_rights();
-        // check if we have sufficient access rights
-        known_rights
-            .iter()
-            .any(|right| *right & new_rights == new_rights)
-    } else {
-        // URef is not known
-        false
-    }
-}
-
 pub fn validate_entry_point_access_with(
     contract_package: &ContractPackage,
     access: &EntryPointAccess,
@@ -84,7 +68,7 @@ pub struct RuntimeContext<'a, R> {
     // Enables look up of specific uref based on human-readable name
     named_keys: &'a mut NamedKeys,
     // Used to check uref is known before use (prevents forging urefs)
-    access_rights: HashMap<Address, HashSet<AccessRights>>,
+    access_rights: AccessRightsValidator,
     // Original account for read only tasks taken before execution
     account: &'a Account,
     args: RuntimeArgs,
@@ -105,6 +89,8 @@ pub struct RuntimeContext<'a, R> {
     protocol_data: ProtocolData,
     entry_point_type: EntryPointType,
     transfers: Vec<TransferAddr>,
+    gas_checkpoints: Rc<RefCell<GasCheckpoints>>,
+    events: Rc<RefCell<Vec<ContractEvent>>>,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -117,7 +103,7 @@ where
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         entry_point_type: EntryPointType,
         named_keys: &'a mut NamedKeys,
-        access_rights: HashMap<Address, HashSet<AccessRights>>,
+        access_rights: AccessRightsValidator,
         runtime_args: RuntimeArgs,
         authorization_keys: BTreeSet<AccountHash>,
         account: &'a Account,
@@ -134,6 +120,8 @@ where
         phase: Phase,
         protocol_data: ProtocolData,
         transfers: Vec<TransferAddr>,
+        gas_checkpoints: Rc<RefCell<GasCheckpoints>>,
+        events: Rc<RefCell<Vec<ContractEvent>>>,
     ) -> Self {
         RuntimeContext {
             tracking_copy,
@@ -156,6 +144,8 @@ where
             phase,
             protocol_data,
             transfers,
+            gas_checkpoints,
+            events,
         }
     }
 
@@ -260,6 +250,10 @@ where
                 self.named_keys.remove(name);
                 Ok(())
             }
+            Key::Allowance(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
         }
     }
 
@@ -275,11 +269,11 @@ where
         self.deploy_hash
     }
 
-    pub fn access_rights_extend(&mut self, access_rights: HashMap<Address, HashSet<AccessRights>>) {
+    pub fn access_rights_extend(&mut self, access_rights: AccessRightsValidator) {
         self.access_rights.extend(access_rights);
     }
 
-    pub fn access_rights(&self) -> &HashMap<Address, HashSet<AccessRights>> {
+    pub fn access_rights(&self) -> &AccessRightsValidator {
         &self.access_rights
     }
 
@@ -303,6 +297,31 @@ where
         Rc::clone(&self.transfer_address_generator)
     }
 
+    pub fn gas_checkpoints(&self) -> Rc<RefCell<GasCheckpoints>> {
+        Rc::clone(&self.gas_checkpoints)
+    }
+
+    /// Returns the events emitted so far while executing the deploy.
+    pub fn events(&self) -> Rc<RefCell<Vec<ContractEvent>>> {
+        Rc::clone(&self.events)
+    }
+
+    /// Records an event emitted by the account or contract currently running.
+    ///
+    /// Events aren't written to global state, but their payloads are subject to the same size
+    /// limit as values which are.
+    pub fn emit_event(&mut self, payload: CLValue) -> Result<(), Error> {
+        let max_cl_value_size = self.protocol_data().wasm_config().max_cl_value_size;
+        if payload.serialized_length() > max_cl_value_size as usize {
+            return Err(Error::Revert(ApiError::ValueTooLarge));
+        }
+        self.events.borrow_mut().push(ContractEvent {
+            emitter: self.base_key,
+            payload,
+        });
+        Ok(())
+    }
+
     pub(super) fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.tracking_copy)
     }
@@ -311,6 +330,10 @@ where
         self.gas_limit
     }
 
+    pub(crate) fn set_gas_limit(&mut self, new_gas_limit: Gas) {
+        self.gas_limit = new_gas_limit;
+    }
+
     pub fn gas_counter(&self) -> Gas {
         self.gas_counter
     }
@@ -365,6 +388,11 @@ where
 
     /// Puts `key` to the map of named keys of current context.
     pub fn put_key(&mut self, name: String, key: Key) -> Result<(), Error> {
+        let max_named_key_length = self.protocol_data.wasm_config().max_named_key_length;
+        validate_named_key_name(&name, max_named_key_length).map_err(Error::InvalidNamedKeyName)?;
+        if !self.named_keys.contains_key(&name) {
+            self.validate_named_keys_count(self.named_keys.len() + 1)?;
+        }
         // No need to perform actual validation on the base key because an account or contract (i.e.
         // the element stored under `base_key`) is allowed to add new named keys to itself.
         let named_key_value = StoredValue::CLValue(CLValue::from_t((name.clone(), key))?);
@@ -506,12 +534,7 @@ where
     }
 
     pub fn insert_uref(&mut self, uref: URef) {
-        let rights = uref.access_rights();
-        let entry = self
-            .access_rights
-            .entry(uref.addr())
-            .or_insert_with(|| std::iter::empty().collect());
-        entry.insert(rights);
+        self.access_rights.insert(uref);
     }
 
     pub fn effect(&self) -> ExecutionEffect {
@@ -612,11 +635,7 @@ where
         }
 
         // Check if the `key` is known
-        if uref_has_access_rights(uref, &self.access_rights) {
-            Ok(())
-        } else {
-            Err(Error::ForgedReference(*uref))
-        }
+        Ok(self.access_rights.validate_uref(uref)?)
     }
 
     pub fn deserialize_keys(&self, bytes: Vec<u8>) -> Result<Vec<Key>, Error> {
@@ -673,6 +692,7 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => true,
             Key::Withdraw(_) => true,
+            Key::Allowance(_) => true,
         }
     }
 
@@ -687,6 +707,7 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
+            Key::Allowance(_) => false,
         }
     }
 
@@ -701,6 +722,7 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
+            Key::Allowance(_) => false,
         }
     }
 
@@ -740,6 +762,42 @@ where
         false
     }
 
+    /// Checks that `count` named keys don't exceed the configured maximum.
+    fn validate_named_keys_count(&self, count: usize) -> Result<(), Error> {
+        if self.is_system_contract() {
+            return Ok(());
+        }
+
+        let max_named_keys_count = self.protocol_data().wasm_config().max_named_keys_count;
+        if count > max_named_keys_count as usize {
+            return Err(Error::Revert(ApiError::MaxNamedKeysExceeded));
+        }
+        Ok(())
+    }
+
+    /// Checks that `value` doesn't exceed the size limits configured for values written to global
+    /// state.
+    fn validate_value_limits(&self, value: &StoredValue) -> Result<(), Error> {
+        if self.is_system_contract() {
+            // System contracts are trusted not to exceed the limits.
+            return Ok(());
+        }
+
+        match value {
+            StoredValue::CLValue(cl_value) => {
+                let max_cl_value_size = self.protocol_data().wasm_config().max_cl_value_size;
+                if cl_value.serialized_length() > max_cl_value_size as usize {
+                    return Err(Error::Revert(ApiError::ValueTooLarge));
+                }
+                Ok(())
+            }
+            StoredValue::Contract(contract) => {
+                self.validate_named_keys_count(contract.named_keys().len())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Charges gas for specified amount of bytes used.
     fn charge_gas_storage(&mut self, bytes_count: usize) -> Result<(), Error> {
         if self.is_system_contract() {
@@ -776,6 +834,7 @@ where
         V: Into<StoredValue>,
     {
         let stored_value = value.into();
+        self.validate_value_limits(&stored_value)?;
 
         // Charge for amount as measured by serialized length
         let bytes_count = stored_value.serialized_length();
@@ -804,6 +863,7 @@ where
         key: Key,
         value: StoredValue,
     ) -> Result<(), Error> {
+        self.validate_value_limits(&value)?;
         let value_bytes_count = value.serialized_length();
         self.charge_gas_storage(value_bytes_count)?;
 
@@ -849,9 +909,12 @@ where
             return Err(AddKeyFailure::PermissionDenied.into());
         }
 
-        if !self
-            .account()
-            .can_manage_keys_with(&self.authorization_keys)
+        if authorization::authorize(
+            self.account(),
+            &self.authorization_keys,
+            ActionType::KeyManagement,
+        )
+        .is_err()
         {
             // Exit early if authorization keys weight doesn't exceed required
             // key management threshold
@@ -861,12 +924,14 @@ where
         // Converts an account's public key into a URef
         let key = Key::Account(self.account().account_hash());
 
+        let max_associated_keys = self.protocol_data().wasm_config().max_associated_keys;
+
         // Take an account out of the global state
         let account = {
             let mut account: Account = self.read_gs_typed(&key)?;
             // Exit early in case of error without updating global state
             account
-                .add_associated_key(account_hash, weight)
+                .add_associated_key(account_hash, weight, max_associated_keys)
                 .map_err(Error::from)?;
             account
         };
@@ -885,9 +950,12 @@ where
             return Err(RemoveKeyFailure::PermissionDenied.into());
         }
 
-        if !self
-            .account()
-            .can_manage_keys_with(&self.authorization_keys)
+        if authorization::authorize(
+            self.account(),
+            &self.authorization_keys,
+            ActionType::KeyManagement,
+        )
+        .is_err()
         {
             // Exit early if authorization keys weight doesn't exceed required
             // key management threshold
@@ -923,9 +991,12 @@ where
             return Err(UpdateKeyFailure::PermissionDenied.into());
         }
 
-        if !self
-            .account()
-            .can_manage_keys_with(&self.authorization_keys)
+        if authorization::authorize(
+            self.account(),
+            &self.authorization_keys,
+            ActionType::KeyManagement,
+        )
+        .is_err()
         {
             // Exit early if authorization keys weight doesn't exceed required
             // key management threshold
@@ -961,9 +1032,12 @@ where
             return Err(SetThresholdFailure::PermissionDeniedError.into());
         }
 
-        if !self
-            .account()
-            .can_manage_keys_with(&self.authorization_keys)
+        if authorization::authorize(
+            self.account(),
+            &self.authorization_keys,
+            ActionType::KeyManagement,
+        )
+        .is_err()
         {
             // Exit early if authorization keys weight doesn't exceed required
             // key management threshold
@@ -973,12 +1047,15 @@ where
         // Converts an account's public key into a URef
         let key = Key::Account(self.account().account_hash());
 
+        let max_action_threshold =
+            Weight::new(self.protocol_data().wasm_config().max_action_threshold);
+
         // Take an account out of the global state
         let mut account: Account = self.read_gs_typed(&key)?;
 
         // Exit early in case of error without updating global state
         account
-            .set_action_threshold(action_type, threshold)
+            .set_action_threshold(action_type, threshold, max_action_threshold)
             .map_err(Error::from)?;
 
         let account_value = self.account_to_validated_value(account)?;
@@ -1028,4 +1105,38 @@ where
         self.validate_uref(&contract_package.access_key())?;
         Ok(contract_package)
     }
+
+    /// Reads the contract package under `package_hash` for the purpose of managing its versions.
+    ///
+    /// The outer error covers failures to validate or read the package, while the inner one
+    /// reports an access key which is either absent from the current context or held without
+    /// sufficient access rights.
+    pub(crate) fn get_contract_package_for_version_management(
+        &mut self,
+        package_hash: ContractPackageHash,
+    ) -> Result<Result<ContractPackage, contracts::Error>, Error> {
+        let package_hash_key = Key::from(package_hash);
+        self.validate_key(&package_hash_key)?;
+        let contract_package: ContractPackage = self.read_gs_typed(&package_hash_key)?;
+        if let Err(error) = self.validate_access_key(&contract_package) {
+            return Ok(Err(error));
+        }
+        Ok(Ok(contract_package))
+    }
+
+    /// Checks that the access key of `contract_package` is known in the current context with at
+    /// least the access rights it was created with.
+    pub(crate) fn validate_access_key(
+        &self,
+        contract_package: &ContractPackage,
+    ) -> Result<(), contracts::Error> {
+        let access_key = contract_package.access_key();
+        if !self.access_rights.contains_addr(&access_key.addr()) {
+            return Err(contracts::Error::MissingAccessKey);
+        }
+        if !self.access_rights.has_access_rights(&access_key) {
+            return Err(contracts::Error::InvalidAccessKeyRights);
+        }
+        Ok(())
+    }
 }
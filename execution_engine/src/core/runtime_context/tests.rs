@@ -1,6 +1,6 @@
 use std::{
     cell::RefCell,
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::BTreeSet,
     iter::{self, FromIterator},
     rc::Rc,
 };
@@ -12,16 +12,18 @@ use casper_types::{
     account::{
         AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, Weight,
     },
-    bytesrepr::ToBytes,
+    bytesrepr::{Bytes, ToBytes},
     contracts::NamedKeys,
-    AccessRights, BlockTime, CLValue, Contract, DeployHash, EntryPointType, EntryPoints, Key,
-    Phase, ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
+    AccessRights, AccessRightsValidator, ApiError, BlockTime, CLValue, Contract, DeployHash,
+    EntryPointType, EntryPoints, Key, NamedKeyError, Phase, ProtocolVersion, RuntimeArgs, URef,
+    KEY_HASH_LENGTH, U512,
 };
 
-use super::{Address, Error, RuntimeContext};
+use super::{Error, RuntimeContext};
 use crate::{
     core::{
-        execution::AddressGenerator, runtime::extract_access_rights_from_keys,
+        execution::AddressGenerator,
+        runtime::{extract_access_rights_from_keys, extract_access_rights_from_urefs},
         tracking_copy::TrackingCopy,
     },
     shared::{
@@ -123,7 +125,7 @@ fn mock_runtime_context<'a>(
     account: &'a Account,
     base_key: Key,
     named_keys: &'a mut NamedKeys,
-    access_rights: HashMap<Address, HashSet<AccessRights>>,
+    access_rights: AccessRightsValidator,
     hash_address_generator: AddressGenerator,
     uref_address_generator: AddressGenerator,
     transfer_address_generator: AddressGenerator,
@@ -150,6 +152,8 @@ fn mock_runtime_context<'a>(
         Phase::Session,
         *TEST_PROTOCOL_DATA,
         Vec::default(),
+        Default::default(),
+        Default::default(),
     )
 }
 
@@ -172,7 +176,7 @@ fn assert_invalid_access<T: std::fmt::Debug>(result: Result<T, Error>, expecting
     }
 }
 
-fn test<T, F>(access_rights: HashMap<Address, HashSet<AccessRights>>, query: F) -> Result<T, Error>
+fn test<T, F>(access_rights: AccessRightsValidator, query: F) -> Result<T, Error>
 where
     F: FnOnce(RuntimeContext<InMemoryGlobalStateView>) -> Result<T, Error>,
 {
@@ -208,12 +212,31 @@ fn use_uref_valid() {
     query_result.expect("writing using valid uref should succeed");
 }
 
+#[test]
+fn use_uref_valid_after_extending_access_rights() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![uref]);
+    let returned_uref = uref
+        .into_uref()
+        .unwrap()
+        .with_access_rights(AccessRights::READ);
+    // Receiving a weaker copy of a known uref, e.g. as a return value, must not revoke the rights
+    // already held.
+    let value = StoredValue::CLValue(CLValue::from_t(43_i32).unwrap());
+    let query_result = test(access_rights, |mut rc| {
+        rc.access_rights_extend(extract_access_rights_from_urefs(vec![returned_uref]));
+        rc.metered_write_gs(uref, value)
+    });
+    query_result.expect("writing using valid uref should succeed");
+}
+
 #[test]
 fn use_uref_forged() {
     // Test fixture
     let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
     let uref = create_uref(&mut rng, AccessRights::READ_WRITE);
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let value = StoredValue::CLValue(CLValue::from_t(43_i32).unwrap());
     let query_result = test(access_rights, |mut rc| rc.metered_write_gs(uref, value));
 
@@ -224,7 +247,7 @@ fn use_uref_forged() {
 fn account_key_not_writeable() {
     let mut rng = rand::thread_rng();
     let acc_key = random_account_key(&mut rng);
-    let query_result = test(HashMap::new(), |mut rc| {
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
         rc.metered_write_gs(
             acc_key,
             StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()),
@@ -237,7 +260,7 @@ fn account_key_not_writeable() {
 fn account_key_readable_valid() {
     // Account key is readable if it is a "base" key - current context of the
     // execution.
-    let query_result = test(HashMap::new(), |mut rc| {
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
         let base_key = rc.base_key();
 
         let result = rc
@@ -258,7 +281,9 @@ fn account_key_readable_invalid() {
     let mut rng = rand::thread_rng();
     let other_acc_key = random_account_key(&mut rng);
 
-    let query_result = test(HashMap::new(), |mut rc| rc.read_gs(&other_acc_key));
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
+        rc.read_gs(&other_acc_key)
+    });
 
     assert_invalid_access(query_result, AccessRights::READ);
 }
@@ -298,7 +323,7 @@ fn account_key_addable_invalid() {
     let mut rng = rand::thread_rng();
     let other_acc_key = random_account_key(&mut rng);
 
-    let query_result = test(HashMap::new(), |mut rc| {
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
         rc.metered_add_gs(
             other_acc_key,
             StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()),
@@ -314,7 +339,9 @@ fn contract_key_readable_valid() {
     // execution.
     let mut rng = rand::thread_rng();
     let contract_key = random_contract_key(&mut rng);
-    let query_result = test(HashMap::new(), |mut rc| rc.read_gs(&contract_key));
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
+        rc.read_gs(&contract_key)
+    });
 
     assert!(query_result.is_ok());
 }
@@ -325,7 +352,7 @@ fn contract_key_not_writeable() {
     // execution.
     let mut rng = rand::thread_rng();
     let contract_key = random_contract_key(&mut rng);
-    let query_result = test(HashMap::new(), |mut rc| {
+    let query_result = test(AccessRightsValidator::new(), |mut rc| {
         rc.metered_write_gs(
             contract_key,
             StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()),
@@ -384,6 +411,8 @@ fn contract_key_addable_valid() {
         PHASE,
         Default::default(),
         Vec::default(),
+        Default::default(),
+        Default::default(),
     );
 
     runtime_context
@@ -457,6 +486,8 @@ fn contract_key_addable_invalid() {
         PHASE,
         Default::default(),
         Vec::default(),
+        Default::default(),
+        Default::default(),
     );
 
     let result = runtime_context.metered_add_gs(contract_key, named_uref_tuple);
@@ -545,7 +576,7 @@ fn hash_key_readable() {
         let key = random_hash(&mut rng);
         runtime_context.validate_readable(&key)
     };
-    let query_result = test(HashMap::new(), query);
+    let query_result = test(AccessRightsValidator::new(), query);
     assert!(query_result.is_ok())
 }
 
@@ -557,7 +588,7 @@ fn hash_key_writeable() {
         let key = random_hash(&mut rng);
         runtime_context.validate_writeable(&key)
     };
-    let query_result = test(HashMap::new(), query);
+    let query_result = test(AccessRightsValidator::new(), query);
     assert!(query_result.is_err())
 }
 
@@ -569,7 +600,7 @@ fn hash_key_addable_invalid() {
         let key = random_hash(&mut rng);
         runtime_context.validate_addable(&key)
     };
-    let query_result = test(HashMap::new(), query);
+    let query_result = test(AccessRightsValidator::new(), query);
     assert!(query_result.is_err())
 }
 
@@ -577,7 +608,7 @@ fn hash_key_addable_invalid() {
 fn manage_associated_keys() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
     // making sure `account_dirty` mutated
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         let account_hash = AccountHash::new([42; 32]);
         let weight = Weight::new(155);
@@ -644,7 +675,7 @@ fn manage_associated_keys() {
 fn action_thresholds_management() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
     // making sure `account_dirty` mutated
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         runtime_context
             .add_associated_key(AccountHash::new([42; 32]), Weight::new(254))
@@ -685,7 +716,7 @@ fn action_thresholds_management() {
 fn should_verify_ownership_before_adding_key() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
     // making sure `account_dirty` mutated
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         // Overwrites a `base_key` to a different one before doing any operation as
         // account `[0; 32]`
@@ -709,7 +740,7 @@ fn should_verify_ownership_before_adding_key() {
 fn should_verify_ownership_before_removing_a_key() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
     // making sure `account_dirty` mutated
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         // Overwrites a `base_key` to a different one before doing any operation as
         // account `[0; 32]`
@@ -733,7 +764,7 @@ fn should_verify_ownership_before_removing_a_key() {
 fn should_verify_ownership_before_setting_action_threshold() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
     // making sure `account_dirty` mutated
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         // Overwrites a `base_key` to a different one before doing any operation as
         // account `[0; 32]`
@@ -755,7 +786,7 @@ fn should_verify_ownership_before_setting_action_threshold() {
 
 #[test]
 fn can_roundtrip_key_value_pairs() {
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
         let deploy_hash = [1u8; 32];
         let mut uref_address_generator = AddressGenerator::new(&deploy_hash, Phase::Session);
@@ -785,7 +816,7 @@ fn remove_uref_works() {
     // which is one of the current RuntimeContext, and also puts that change
     // into the `TrackingCopy` so that it's later committed to the GlobalState.
 
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let deploy_hash = [1u8; 32];
     let (base_key, account) = mock_account(AccountHash::new([0u8; 32]));
     let hash_address_generator = AddressGenerator::new(&deploy_hash, Phase::Session);
@@ -821,7 +852,7 @@ fn remove_uref_works() {
 fn validate_valid_purse_of_an_account() {
     // Tests that URef which matches a purse of a given context gets validated
     let mock_purse = [42u8; 32];
-    let access_rights = HashMap::new();
+    let access_rights = AccessRightsValidator::new();
     let deploy_hash = [1u8; 32];
     let (base_key, account) = mock_account_with_purse(AccountHash::new([0u8; 32]), mock_purse);
     let mut named_keys = NamedKeys::new();
@@ -919,3 +950,84 @@ fn should_meter_for_gas_storage_add() {
 
     assert_eq!(gas_usage_after, gas_usage_before + expected_add_cost);
 }
+
+#[test]
+fn should_validate_name_on_put_key() {
+    let max_named_key_length = TEST_PROTOCOL_DATA.wasm_config().max_named_key_length;
+    let too_long_name = "a".repeat(max_named_key_length as usize + 1);
+    let longest_name = "a".repeat(max_named_key_length as usize);
+    let key = Key::Hash([1; 32]);
+
+    let result = test(AccessRightsValidator::new(), |mut rc| {
+        assert!(matches!(
+            rc.put_key(String::new(), key),
+            Err(Error::InvalidNamedKeyName(NamedKeyError::EmptyName))
+        ));
+        assert!(matches!(
+            rc.put_key(too_long_name.clone(), key),
+            Err(Error::InvalidNamedKeyName(
+                NamedKeyError::NameTooLong { .. }
+            ))
+        ));
+        assert!(matches!(
+            rc.put_key("foo\0".to_string(), key),
+            Err(Error::InvalidNamedKeyName(NamedKeyError::InvalidCharacter(
+                '\0'
+            )))
+        ));
+        assert!(!rc.named_keys_contains_key(&too_long_name));
+
+        rc.put_key(longest_name.clone(), key)?;
+        Ok(rc.named_keys_contains_key(&longest_name))
+    });
+
+    assert!(result.expect("should put key with valid name"));
+}
+
+#[test]
+fn should_not_write_cl_value_exceeding_max_size() {
+    let max_cl_value_size = TEST_PROTOCOL_DATA.wasm_config().max_cl_value_size as usize;
+    let oversized_value = StoredValue::CLValue(
+        CLValue::from_t(Bytes::from(vec![0u8; max_cl_value_size])).expect("should create CLValue"),
+    );
+    let small_value = StoredValue::CLValue(CLValue::from_t(Bytes::from(vec![0u8; 32])).unwrap());
+
+    let result = test(AccessRightsValidator::new(), |mut rc| {
+        assert!(matches!(
+            rc.new_uref(oversized_value),
+            Err(Error::Revert(ApiError::ValueTooLarge))
+        ));
+        rc.new_uref(small_value)
+    });
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn should_not_put_key_exceeding_max_named_keys_count() {
+    let max_named_keys_count = TEST_PROTOCOL_DATA.wasm_config().max_named_keys_count;
+    let deploy_hash = [1u8; 32];
+    let (base_key, account) = mock_account(AccountHash::new([0u8; 32]));
+    let key = Key::Hash([1; 32]);
+
+    let mut named_keys: NamedKeys = (0..max_named_keys_count)
+        .map(|index| (format!("key-{}", index), key))
+        .collect();
+    let mut rc = mock_runtime_context(
+        &account,
+        base_key,
+        &mut named_keys,
+        AccessRightsValidator::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+
+    assert!(matches!(
+        rc.put_key("one-too-many".to_string(), key),
+        Err(Error::Revert(ApiError::MaxNamedKeysExceeded))
+    ));
+    // Overwriting an existing named key doesn't increase the count.
+    rc.put_key("key-0".to_string(), Key::Hash([2; 32]))
+        .expect("should overwrite existing named key");
+}
@@ -7,13 +7,16 @@ mod tests;
 use std::{
     collections::{BTreeSet, HashMap, HashSet, VecDeque},
     convert::{From, TryInto},
-    iter,
+    iter::{self, Peekable},
+    slice,
 };
 
 use linked_hash_map::LinkedHashMap;
 use thiserror::Error;
 
-use casper_types::{bytesrepr, CLType, CLValue, CLValueError, Key, KeyTag, Tagged, U512};
+use casper_types::{
+    bytesrepr, CLType, CLValue, CLValueError, CLValuePathError, Key, KeyTag, Tagged, U512,
+};
 
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
@@ -86,6 +89,16 @@ impl Query {
         self.depth += 1;
     }
 
+    /// Follows path components into the elements of `cl_value` until either the path is exhausted
+    /// or an element of type `Key` is reached.
+    fn navigate_cl_value(&mut self, mut cl_value: CLValue) -> Result<CLValue, CLValuePathError> {
+        while !self.unvisited_names.is_empty() && cl_value.cl_type() != &CLType::Key {
+            let segment = self.next_name();
+            cl_value = cl_value.get_path_segment(segment)?;
+        }
+        Ok(cl_value)
+    }
+
     fn into_not_found_result(self, msg_prefix: &str) -> TrackingCopyQueryResult {
         let msg = format!("{} at path: {}", msg_prefix, self.current_path());
         TrackingCopyQueryResult::ValueNotFound(msg)
@@ -206,6 +219,15 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
     }
 }
 
+/// The mutations held by a [`TrackingCopy`] at a given point, used to discard any changes made
+/// after that point.
+pub(crate) struct TrackingCopyCheckpoint {
+    muts_cached: HashMap<Key, StoredValue>,
+    key_tag_muts_cached: HashMap<KeyTag, BTreeSet<Key>>,
+    ops: AdditiveMap<Key, Op>,
+    fns: AdditiveMap<Key, Transform>,
+}
+
 pub struct TrackingCopy<R> {
     reader: R,
     cache: TrackingCopyCache<HeapSize>,
@@ -267,6 +289,25 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         TrackingCopy::new(self)
     }
 
+    /// Captures the mutations made so far, so they can later be reinstated by
+    /// [`TrackingCopy::restore`].
+    pub(crate) fn checkpoint(&self) -> TrackingCopyCheckpoint {
+        TrackingCopyCheckpoint {
+            muts_cached: self.cache.muts_cached.clone(),
+            key_tag_muts_cached: self.cache.key_tag_muts_cached.clone(),
+            ops: self.ops.clone(),
+            fns: self.fns.clone(),
+        }
+    }
+
+    /// Discards all mutations made since `checkpoint` was taken.
+    pub(crate) fn restore(&mut self, checkpoint: TrackingCopyCheckpoint) {
+        self.cache.muts_cached = checkpoint.muts_cached;
+        self.cache.key_tag_muts_cached = checkpoint.key_tag_muts_cached;
+        self.ops = checkpoint.ops;
+        self.fns = checkpoint.fns;
+    }
+
     pub fn get(
         &mut self,
         correlation_id: CorrelationId,
@@ -469,21 +510,25 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                         return Ok(query.into_not_found_result(&msg_prefix));
                     }
                 }
-                StoredValue::CLValue(cl_value) if cl_value.cl_type() == &CLType::Key => {
-                    if let Ok(key) = cl_value.to_owned().into_t::<Key>() {
+                StoredValue::CLValue(cl_value) => {
+                    let cl_value = match query.navigate_cl_value(cl_value.to_owned()) {
+                        Ok(cl_value) => cl_value,
+                        Err(error) => {
+                            let msg_prefix = format!("Failed to navigate into CLValue: {}", error);
+                            return Ok(query.into_not_found_result(&msg_prefix));
+                        }
+                    };
+                    if query.unvisited_names.is_empty() {
+                        // The proofs cover the stored value holding the element returned.
+                        let value = StoredValue::CLValue(cl_value);
+                        return Ok(TrackingCopyQueryResult::Success { value, proofs });
+                    }
+                    if let Ok(key) = cl_value.into_t::<Key>() {
                         query.navigate(key);
                     } else {
                         return Ok(query.into_not_found_result("Failed to parse CLValue as Key"));
                     }
                 }
-                StoredValue::CLValue(cl_value) => {
-                    let msg_prefix = format!(
-                        "Query cannot continue as {:?} is not an account, contract nor key to \
-                        such.  Value found",
-                        cl_value
-                    );
-                    return Ok(query.into_not_found_result(&msg_prefix));
-                }
                 StoredValue::Contract(contract) => {
                     let name = query.next_name();
                     if let Some(key) = contract.named_keys().get(name) {
@@ -608,14 +653,11 @@ pub fn validate_query_proof(
     path: &[String],
     expected_value: &StoredValue,
 ) -> Result<(), ValidationError> {
-    if proofs.len() != path.len() + 1 {
-        return Err(ValidationError::PathLengthDifferentThanProofLessOne);
-    }
-
     let mut proofs_iter = proofs.iter();
 
-    // length check above means we are safe to unwrap here
-    let first_proof = proofs_iter.next().unwrap();
+    let first_proof = proofs_iter
+        .next()
+        .ok_or(ValidationError::PathLengthDifferentThanProofLessOne)?;
 
     if first_proof.key() != &expected_first_key.normalize() {
         return Err(ValidationError::UnexpectedKey);
@@ -625,19 +667,36 @@ pub fn validate_query_proof(
         return Err(ValidationError::InvalidProofHash);
     }
 
-    let mut proof_value = first_proof.value();
+    let mut proof_value = first_proof.value().to_owned();
+    let mut path = path.iter().peekable();
 
-    for (proof, path_component) in proofs_iter.zip(path.iter()) {
-        let named_keys = match proof_value {
-            StoredValue::Account(account) => account.named_keys(),
-            StoredValue::Contract(contract) => contract.named_keys(),
-            _ => return Err(ValidationError::PathCold),
+    while let Some(&path_component) = path.peek() {
+        let key = match &proof_value {
+            StoredValue::Account(account) => {
+                path.next();
+                account.named_keys().get(path_component).copied()
+            }
+            StoredValue::Contract(contract) => {
+                path.next();
+                contract.named_keys().get(path_component).copied()
+            }
+            StoredValue::CLValue(cl_value) => {
+                let cl_value = follow_cl_value_path(cl_value.to_owned(), &mut path)
+                    .map_err(|_| ValidationError::PathCold)?;
+                if path.peek().is_none() {
+                    proof_value = StoredValue::CLValue(cl_value);
+                    break;
+                }
+                Some(cl_value.into_t::<Key>()?)
+            }
+            _ => None,
         };
 
-        let key = match named_keys.get(path_component) {
-            Some(key) => key,
-            None => return Err(ValidationError::PathCold),
-        };
+        let key = key.ok_or(ValidationError::PathCold)?;
+
+        let proof = proofs_iter
+            .next()
+            .ok_or(ValidationError::PathLengthDifferentThanProofLessOne)?;
 
         if proof.key() != &key.normalize() {
             return Err(ValidationError::UnexpectedKey);
@@ -647,16 +706,35 @@ pub fn validate_query_proof(
             return Err(ValidationError::InvalidProofHash);
         }
 
-        proof_value = proof.value();
+        proof_value = proof.value().to_owned();
+    }
+
+    if proofs_iter.next().is_some() {
+        return Err(ValidationError::PathLengthDifferentThanProofLessOne);
     }
 
-    if proof_value != expected_value {
+    if &proof_value != expected_value {
         return Err(ValidationError::UnexpectedValue);
     }
 
     Ok(())
 }
 
+/// Follows path components into the elements of `cl_value` until either the path is exhausted or
+/// an element of type `Key` is reached, mirroring the navigation done by [`TrackingCopy::query`].
+fn follow_cl_value_path(
+    mut cl_value: CLValue,
+    path: &mut Peekable<slice::Iter<String>>,
+) -> Result<CLValue, CLValuePathError> {
+    while cl_value.cl_type() != &CLType::Key {
+        match path.next() {
+            Some(segment) => cl_value = cl_value.get_path_segment(segment)?,
+            None => break,
+        }
+    }
+    Ok(cl_value)
+}
+
 pub fn validate_balance_proof(
     hash: &Blake2bHash,
     balance_proof: &TrieMerkleProof<Key, StoredValue>,
@@ -1,4 +1,4 @@
-use std::{cell::Cell, iter, rc::Rc};
+use std::{cell::Cell, collections::BTreeMap, iter, rc::Rc};
 
 use assert_matches::assert_matches;
 use proptest::prelude::*;
@@ -168,6 +168,35 @@ fn tracking_copy_write() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_restore_discards_later_writes() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+    let two = StoredValue::CLValue(CLValue::from_t(2_i32).unwrap());
+
+    tc.write(k1, one.clone());
+    let checkpoint = tc.checkpoint();
+
+    tc.write(k1, two.clone());
+    tc.write(k2, two);
+    tc.restore(checkpoint);
+
+    assert_eq!(tc.fns.len(), 1);
+    assert_eq!(tc.fns.get(&k1), Some(&Transform::Write(one.clone())));
+    assert_eq!(tc.ops.len(), 1);
+    assert_eq!(tc.read(correlation_id, &k1).unwrap(), Some(one));
+    // `k2` is no longer cached as a mutation, so reading it goes back to the DB
+    let zero = StoredValue::CLValue(CLValue::from_t(0_i32).unwrap());
+    assert_eq!(tc.read(correlation_id, &k2).unwrap(), Some(zero));
+    assert_eq!(counter.get(), 1);
+}
+
 #[test]
 fn tracking_copy_add_i32() {
     let correlation_id = CorrelationId::new();
@@ -576,6 +605,91 @@ fn query_for_circular_references_should_fail() {
     }
 }
 
+#[test]
+fn query_should_navigate_into_cl_values() {
+    // a stored map with a single field
+    let target_key = Key::URef(URef::new([9; 32], AccessRights::READ));
+    let mut fields = BTreeMap::new();
+    fields.insert("field".to_string(), 7u64);
+    let target_value = StoredValue::CLValue(CLValue::from_t(fields).unwrap());
+
+    // a stored map holding a list of keys, one of which refers to the map above
+    let container_key = Key::URef(URef::new([10; 32], AccessRights::READ));
+    let mut entries = BTreeMap::new();
+    entries.insert("entry".to_string(), vec![Key::Hash([11; 32]), target_key]);
+    let container_value = StoredValue::CLValue(CLValue::from_t(entries).unwrap());
+
+    let contract_key = Key::Hash([1; 32]);
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert("container".to_string(), container_key);
+    let contract_value = StoredValue::Contract(Contract::new(
+        [2; 32].into(),
+        [3; 32].into(),
+        named_keys,
+        EntryPoints::default(),
+        ProtocolVersion::V1_0_0,
+    ));
+
+    let correlation_id = CorrelationId::new();
+    let (global_state, root_hash) = InMemoryGlobalState::from_pairs(
+        correlation_id,
+        &[
+            (target_key, target_value),
+            (container_key, container_value),
+            (contract_key, contract_value),
+        ],
+    )
+    .unwrap();
+    let view = global_state.checkout(root_hash).unwrap().unwrap();
+    let tracking_copy = TrackingCopy::new(view);
+
+    let path = vec![
+        "container".to_string(),
+        "entry[1]".to_string(),
+        "field".to_string(),
+    ];
+    let result = tracking_copy
+        .query(
+            correlation_id,
+            &EngineConfig::default(),
+            contract_key,
+            &path,
+        )
+        .expect("should query");
+    let (value, proofs) = if let TrackingCopyQueryResult::Success { value, proofs } = result {
+        (value, proofs)
+    } else {
+        panic!("query was not successful: {:?}", result)
+    };
+    let expected_value = StoredValue::CLValue(CLValue::from_t(7u64).unwrap());
+    assert_eq!(value, expected_value);
+    assert_eq!(proofs.len(), 3);
+
+    crate::core::validate_query_proof(&root_hash, &proofs, &contract_key, &path, &value)
+        .expect("should validate");
+
+    // elements which are not present are reported along with the path followed
+    let path = vec!["container".to_string(), "entry[2]".to_string()];
+    let result = tracking_copy
+        .query(
+            correlation_id,
+            &EngineConfig::default(),
+            contract_key,
+            &path,
+        )
+        .expect("should query");
+    if let TrackingCopyQueryResult::ValueNotFound(msg) = result {
+        assert!(msg.contains("no element [2]"), "{}", msg);
+        assert!(
+            msg.contains(&format!("at path: {:?}/container/entry[2]", contract_key)),
+            "{}",
+            msg
+        );
+    } else {
+        panic!("query should not have found a value: {:?}", result)
+    }
+}
+
 #[test]
 fn validate_query_proof_should_work() {
     // create account
@@ -9,6 +9,7 @@ pub mod logging;
 pub mod motes;
 pub mod newtypes;
 pub mod opcode_costs;
+pub mod output_capture;
 pub mod socket;
 pub mod storage_costs;
 pub mod stored_value;
@@ -87,11 +87,16 @@ impl Account {
         &self.action_thresholds
     }
 
+    /// Adds an associated key, provided the account holds fewer than `max_associated_keys` keys.
     pub fn add_associated_key(
         &mut self,
         account_hash: AccountHash,
         weight: Weight,
+        max_associated_keys: u32,
     ) -> Result<(), AddKeyFailure> {
+        if self.associated_keys.len() >= max_associated_keys as usize {
+            return Err(AddKeyFailure::MaxKeysLimit);
+        }
         self.associated_keys.add_key(account_hash, weight)
     }
 
@@ -157,11 +162,16 @@ impl Account {
         self.associated_keys.get(&account_hash)
     }
 
+    /// Sets the threshold for the given action, provided it doesn't exceed `max_action_threshold`.
     pub fn set_action_threshold(
         &mut self,
         action_type: ActionType,
         weight: Weight,
+        max_action_threshold: Weight,
     ) -> Result<(), SetThresholdFailure> {
+        if weight > max_action_threshold {
+            return Err(SetThresholdFailure::MaxThresholdExceeded);
+        }
         // Verify if new threshold weight exceeds total weight of allassociated
         // keys.
         self.can_set_threshold(weight)?;
@@ -300,13 +310,14 @@ mod tests {
 
     use casper_types::{
         account::{
-            AccountHash, ActionType, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure,
-            Weight,
+            AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure,
+            UpdateKeyFailure, Weight, ACCOUNT_HASH_LENGTH, MAX_ASSOCIATED_KEYS,
         },
         AccessRights, URef,
     };
 
     use super::*;
+    use crate::shared::wasm_config::DEFAULT_MAX_ACTION_THRESHOLD;
 
     #[test]
     fn associated_keys_can_authorize_keys() {
@@ -470,20 +481,96 @@ mod tests {
                 .expect("should create thresholds"),
         );
 
+        let max_action_threshold = Weight::new(DEFAULT_MAX_ACTION_THRESHOLD);
         assert_eq!(
             account
-                .set_action_threshold(ActionType::Deployment, Weight::new(1 + 2 + 3 + 4 + 1))
+                .set_action_threshold(
+                    ActionType::Deployment,
+                    Weight::new(1 + 2 + 3 + 4 + 1),
+                    max_action_threshold
+                )
                 .unwrap_err(),
             SetThresholdFailure::InsufficientTotalWeight,
         );
         assert_eq!(
             account
-                .set_action_threshold(ActionType::Deployment, Weight::new(1 + 2 + 3 + 4 + 245))
+                .set_action_threshold(
+                    ActionType::Deployment,
+                    Weight::new(1 + 2 + 3 + 4 + 245),
+                    max_action_threshold
+                )
                 .unwrap_err(),
             SetThresholdFailure::InsufficientTotalWeight,
         )
     }
 
+    #[test]
+    fn set_action_threshold_higher_than_maximum() {
+        let identity_key = AccountHash::new([1u8; 32]);
+        let mut account = Account::create(
+            identity_key,
+            NamedKeys::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+        );
+        account
+            .update_associated_key(identity_key, Weight::new(10))
+            .expect("should update identity key");
+
+        assert_eq!(
+            account
+                .set_action_threshold(ActionType::KeyManagement, Weight::new(6), Weight::new(5))
+                .unwrap_err(),
+            SetThresholdFailure::MaxThresholdExceeded,
+        );
+        account
+            .set_action_threshold(ActionType::KeyManagement, Weight::new(5), Weight::new(5))
+            .expect("should set threshold up to the maximum");
+        assert_eq!(
+            *account.action_thresholds().key_management(),
+            Weight::new(5)
+        );
+    }
+
+    #[test]
+    fn add_associated_key_up_to_maximum() {
+        let mut account = Account::create(
+            AccountHash::new([0u8; ACCOUNT_HASH_LENGTH]),
+            NamedKeys::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let max_associated_keys = MAX_ASSOCIATED_KEYS - 1;
+        for k in 1..max_associated_keys {
+            account
+                .add_associated_key(
+                    AccountHash::new([k as u8; ACCOUNT_HASH_LENGTH]),
+                    Weight::new(k as u8),
+                    max_associated_keys as u32,
+                )
+                .expect("should add key");
+        }
+        assert_eq!(account.associated_keys().count(), max_associated_keys);
+
+        let new_key = AccountHash::new([100u8; ACCOUNT_HASH_LENGTH]);
+        assert_eq!(
+            account.add_associated_key(new_key, Weight::new(100), max_associated_keys as u32),
+            Err(AddKeyFailure::MaxKeysLimit)
+        );
+        // A higher limit, e.g. after a protocol upgrade, leaves room for more keys.
+        assert_eq!(
+            account.add_associated_key(new_key, Weight::new(100), MAX_ASSOCIATED_KEYS as u32),
+            Ok(())
+        );
+        // No limit configured for the network can exceed `MAX_ASSOCIATED_KEYS`.
+        assert_eq!(
+            account.add_associated_key(
+                AccountHash::new([101u8; ACCOUNT_HASH_LENGTH]),
+                Weight::new(101),
+                u32::max_value()
+            ),
+            Err(AddKeyFailure::MaxKeysLimit)
+        );
+    }
+
     #[test]
     fn remove_key_would_violate_action_thresholds() {
         let identity_key = AccountHash::new([1u8; 32]);
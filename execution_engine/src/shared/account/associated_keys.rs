@@ -6,7 +6,7 @@ use casper_types::{
     account::{
         AccountHash, AddKeyFailure, RemoveKeyFailure, UpdateKeyFailure, Weight, MAX_ASSOCIATED_KEYS,
     },
-    bytesrepr::{Error, FromBytes, ToBytes},
+    bytesrepr::{self, Error, FromBytes, ToBytes},
 };
 
 #[derive(Default, PartialOrd, Ord, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
@@ -21,6 +21,10 @@ impl AssociatedKeys {
 
     /// Adds new AssociatedKey to the set.
     /// Returns true if added successfully, false otherwise.
+    ///
+    /// Only the hard limit of [`MAX_ASSOCIATED_KEYS`] is enforced here; the possibly lower limit
+    /// configured for the network is enforced by
+    /// [`Account::add_associated_key`](crate::shared::account::Account::add_associated_key).
     #[allow(clippy::map_entry)]
     pub fn add_key(&mut self, key: AccountHash, weight: Weight) -> Result<(), AddKeyFailure> {
         if self.0.len() == MAX_ASSOCIATED_KEYS {
@@ -124,7 +128,7 @@ impl ToBytes for AssociatedKeys {
 
 impl FromBytes for AssociatedKeys {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (num_keys, mut stream) = u32::from_bytes(bytes)?;
+        let (num_keys, mut stream) = bytesrepr::read_length_prefix(bytes)?;
         if num_keys as usize > MAX_ASSOCIATED_KEYS {
             return Err(Error::Formatting);
         }
@@ -172,7 +176,7 @@ mod tests {
 
     use casper_types::{
         account::{AccountHash, AddKeyFailure, Weight, ACCOUNT_HASH_LENGTH, MAX_ASSOCIATED_KEYS},
-        bytesrepr::{self, ToBytes},
+        bytesrepr::{self, LengthEncoding, ToBytes},
     };
 
     use super::AssociatedKeys;
@@ -346,6 +350,12 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&keys);
     }
 
+    #[test]
+    fn associated_keys_serialization_roundtrip_with_leb128_lengths() {
+        let keys = AssociatedKeys::new(AccountHash::new([1; ACCOUNT_HASH_LENGTH]), Weight::new(1));
+        bytesrepr::test_serialization_roundtrip_with_encoding(&keys, LengthEncoding::Leb128);
+    }
+
     #[test]
     fn should_not_panic_deserializing_malicious_data() {
         let malicious_map: BTreeMap<AccountHash, Weight> = (1usize..=(MAX_ASSOCIATED_KEYS + 1))
@@ -24,6 +24,10 @@ const DEFAULT_CALL_CONTRACT_COST: u32 = 4_500;
 const DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT: u32 = 420;
 
 const DEFAULT_CREATE_PURSE_COST: u32 = 170_000;
+
+const DEFAULT_EMIT_EVENT_COST: u32 = 10_000;
+const DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT: u32 = 590;
+
 const DEFAULT_GET_BALANCE_COST: u32 = 3_800;
 const DEFAULT_GET_BLOCKTIME_COST: u32 = 330;
 const DEFAULT_GET_CALLER_COST: u32 = 380;
@@ -227,6 +231,12 @@ pub struct HostFunctionCosts {
     pub remove_contract_user_group_urefs: HostFunction<[Cost; 6]>,
     pub print: HostFunction<[Cost; 2]>,
     pub blake2b: HostFunction<[Cost; 4]>,
+    pub lock_contract_package: HostFunction<[Cost; 2]>,
+    pub emit_event: HostFunction<[Cost; 2]>,
+    pub call_contract_with_gas_limit: HostFunction<[Cost; 9]>,
+    pub call_versioned_contract_with_gas_limit: HostFunction<[Cost; 11]>,
+    pub transfer_from_purse_to_account_with_receipt: HostFunction<[Cost; 9]>,
+    pub provision_contract_user_group_urefs: HostFunction<[Cost; 6]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -344,59 +354,85 @@ impl Default for HostFunctionCosts {
                 [NOT_USED, DEFAULT_PRINT_TEXT_SIZE_WEIGHT],
             ),
             blake2b: HostFunction::default(),
+            lock_contract_package: HostFunction::default(),
+            emit_event: HostFunction::new(
+                DEFAULT_EMIT_EVENT_COST,
+                [NOT_USED, DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT],
+            ),
+            call_contract_with_gas_limit: HostFunction::new(
+                DEFAULT_CALL_CONTRACT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            call_versioned_contract_with_gas_limit: HostFunction::default(),
+            transfer_from_purse_to_account_with_receipt: HostFunction::fixed(
+                DEFAULT_TRANSFER_FROM_PURSE_TO_ACCOUNT_COST,
+            ),
+            provision_contract_user_group_urefs: HostFunction::default(),
         }
     }
 }
 
-impl ToBytes for HostFunctionCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-        ret.append(&mut self.read_value.to_bytes()?);
-        ret.append(&mut self.read_value_local.to_bytes()?);
-        ret.append(&mut self.write.to_bytes()?);
-        ret.append(&mut self.write_local.to_bytes()?);
-        ret.append(&mut self.add.to_bytes()?);
-        ret.append(&mut self.new_uref.to_bytes()?);
-        ret.append(&mut self.load_named_keys.to_bytes()?);
-        ret.append(&mut self.ret.to_bytes()?);
-        ret.append(&mut self.get_key.to_bytes()?);
-        ret.append(&mut self.has_key.to_bytes()?);
-        ret.append(&mut self.put_key.to_bytes()?);
-        ret.append(&mut self.remove_key.to_bytes()?);
-        ret.append(&mut self.revert.to_bytes()?);
-        ret.append(&mut self.is_valid_uref.to_bytes()?);
-        ret.append(&mut self.add_associated_key.to_bytes()?);
-        ret.append(&mut self.remove_associated_key.to_bytes()?);
-        ret.append(&mut self.update_associated_key.to_bytes()?);
-        ret.append(&mut self.set_action_threshold.to_bytes()?);
-        ret.append(&mut self.get_caller.to_bytes()?);
-        ret.append(&mut self.get_blocktime.to_bytes()?);
-        ret.append(&mut self.create_purse.to_bytes()?);
-        ret.append(&mut self.transfer_to_account.to_bytes()?);
-        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
-        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
-        ret.append(&mut self.get_balance.to_bytes()?);
-        ret.append(&mut self.get_phase.to_bytes()?);
-        ret.append(&mut self.get_system_contract.to_bytes()?);
-        ret.append(&mut self.get_main_purse.to_bytes()?);
-        ret.append(&mut self.read_host_buffer.to_bytes()?);
-        ret.append(&mut self.create_contract_package_at_hash.to_bytes()?);
-        ret.append(&mut self.create_contract_user_group.to_bytes()?);
-        ret.append(&mut self.add_contract_version.to_bytes()?);
-        ret.append(&mut self.disable_contract_version.to_bytes()?);
-        ret.append(&mut self.call_contract.to_bytes()?);
-        ret.append(&mut self.call_versioned_contract.to_bytes()?);
-        ret.append(&mut self.get_named_arg_size.to_bytes()?);
-        ret.append(&mut self.get_named_arg.to_bytes()?);
-        ret.append(&mut self.remove_contract_user_group.to_bytes()?);
-        ret.append(&mut self.provision_contract_user_group_uref.to_bytes()?);
-        ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
-        ret.append(&mut self.print.to_bytes()?);
-        ret.append(&mut self.blake2b.to_bytes()?);
-        Ok(ret)
+impl HostFunctionCosts {
+    /// Serializes the costs of the host functions which were available at genesis into `buffer`.
+    pub(crate) fn write_legacy_fields(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        buffer.append(&mut self.read_value.to_bytes()?);
+        buffer.append(&mut self.read_value_local.to_bytes()?);
+        buffer.append(&mut self.write.to_bytes()?);
+        buffer.append(&mut self.write_local.to_bytes()?);
+        buffer.append(&mut self.add.to_bytes()?);
+        buffer.append(&mut self.new_uref.to_bytes()?);
+        buffer.append(&mut self.load_named_keys.to_bytes()?);
+        buffer.append(&mut self.ret.to_bytes()?);
+        buffer.append(&mut self.get_key.to_bytes()?);
+        buffer.append(&mut self.has_key.to_bytes()?);
+        buffer.append(&mut self.put_key.to_bytes()?);
+        buffer.append(&mut self.remove_key.to_bytes()?);
+        buffer.append(&mut self.revert.to_bytes()?);
+        buffer.append(&mut self.is_valid_uref.to_bytes()?);
+        buffer.append(&mut self.add_associated_key.to_bytes()?);
+        buffer.append(&mut self.remove_associated_key.to_bytes()?);
+        buffer.append(&mut self.update_associated_key.to_bytes()?);
+        buffer.append(&mut self.set_action_threshold.to_bytes()?);
+        buffer.append(&mut self.get_caller.to_bytes()?);
+        buffer.append(&mut self.get_blocktime.to_bytes()?);
+        buffer.append(&mut self.create_purse.to_bytes()?);
+        buffer.append(&mut self.transfer_to_account.to_bytes()?);
+        buffer.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
+        buffer.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
+        buffer.append(&mut self.get_balance.to_bytes()?);
+        buffer.append(&mut self.get_phase.to_bytes()?);
+        buffer.append(&mut self.get_system_contract.to_bytes()?);
+        buffer.append(&mut self.get_main_purse.to_bytes()?);
+        buffer.append(&mut self.read_host_buffer.to_bytes()?);
+        buffer.append(&mut self.create_contract_package_at_hash.to_bytes()?);
+        buffer.append(&mut self.create_contract_user_group.to_bytes()?);
+        buffer.append(&mut self.add_contract_version.to_bytes()?);
+        buffer.append(&mut self.disable_contract_version.to_bytes()?);
+        buffer.append(&mut self.call_contract.to_bytes()?);
+        buffer.append(&mut self.call_versioned_contract.to_bytes()?);
+        buffer.append(&mut self.get_named_arg_size.to_bytes()?);
+        buffer.append(&mut self.get_named_arg.to_bytes()?);
+        buffer.append(&mut self.remove_contract_user_group.to_bytes()?);
+        buffer.append(&mut self.provision_contract_user_group_uref.to_bytes()?);
+        buffer.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
+        buffer.append(&mut self.print.to_bytes()?);
+        buffer.append(&mut self.blake2b.to_bytes()?);
+        Ok(())
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the serialized length of the costs written by
+    /// [`write_legacy_fields`](Self::write_legacy_fields).
+    pub(crate) fn legacy_fields_serialized_length(&self) -> usize {
         self.read_value.serialized_length()
             + self.read_value_local.serialized_length()
             + self.write.serialized_length()
@@ -440,10 +476,45 @@ impl ToBytes for HostFunctionCosts {
             + self.print.serialized_length()
             + self.blake2b.serialized_length()
     }
-}
 
-impl FromBytes for HostFunctionCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    /// Serializes the costs of the host functions added since genesis into `buffer`.
+    pub(crate) fn write_appended_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        buffer.append(&mut self.lock_contract_package.to_bytes()?);
+        buffer.append(&mut self.emit_event.to_bytes()?);
+        buffer.append(&mut self.call_contract_with_gas_limit.to_bytes()?);
+        buffer.append(&mut self.call_versioned_contract_with_gas_limit.to_bytes()?);
+        buffer.append(
+            &mut self
+                .transfer_from_purse_to_account_with_receipt
+                .to_bytes()?,
+        );
+        buffer.append(&mut self.provision_contract_user_group_urefs.to_bytes()?);
+        Ok(())
+    }
+
+    /// Returns the serialized length of the costs written by
+    /// [`write_appended_fields`](Self::write_appended_fields).
+    pub(crate) fn appended_fields_serialized_length(&self) -> usize {
+        self.lock_contract_package.serialized_length()
+            + self.emit_event.serialized_length()
+            + self.call_contract_with_gas_limit.serialized_length()
+            + self
+                .call_versioned_contract_with_gas_limit
+                .serialized_length()
+            + self
+                .transfer_from_purse_to_account_with_receipt
+                .serialized_length()
+            + self.provision_contract_user_group_urefs.serialized_length()
+    }
+
+    /// Deserializes the costs written by [`write_legacy_fields`](Self::write_legacy_fields), using
+    /// the default costs for the host functions added since genesis.
+    pub(crate) fn legacy_fields_from_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (read_value, rem) = FromBytes::from_bytes(bytes)?;
         let (read_value_local, rem) = FromBytes::from_bytes(rem)?;
         let (write, rem) = FromBytes::from_bytes(rem)?;
@@ -530,10 +601,57 @@ impl FromBytes for HostFunctionCosts {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                ..HostFunctionCosts::default()
             },
             rem,
         ))
     }
+
+    /// Deserializes the costs written by [`write_appended_fields`](Self::write_appended_fields)
+    /// into `self`.
+    pub(crate) fn appended_fields_from_bytes(
+        self,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (lock_contract_package, rem) = FromBytes::from_bytes(bytes)?;
+        let (emit_event, rem) = FromBytes::from_bytes(rem)?;
+        let (call_contract_with_gas_limit, rem) = FromBytes::from_bytes(rem)?;
+        let (call_versioned_contract_with_gas_limit, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_account_with_receipt, rem) = FromBytes::from_bytes(rem)?;
+        let (provision_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            HostFunctionCosts {
+                lock_contract_package,
+                emit_event,
+                call_contract_with_gas_limit,
+                call_versioned_contract_with_gas_limit,
+                transfer_from_purse_to_account_with_receipt,
+                provision_contract_user_group_urefs,
+                ..self
+            },
+            rem,
+        ))
+    }
+}
+
+impl ToBytes for HostFunctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        self.write_legacy_fields(&mut ret)?;
+        self.write_appended_fields(&mut ret)?;
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_fields_serialized_length() + self.appended_fields_serialized_length()
+    }
+}
+
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (host_function_costs, rem) = HostFunctionCosts::legacy_fields_from_bytes(bytes)?;
+        host_function_costs.appended_fields_from_bytes(rem)
+    }
 }
 
 impl Distribution<HostFunctionCosts> for Standard {
@@ -581,6 +699,12 @@ impl Distribution<HostFunctionCosts> for Standard {
             remove_contract_user_group_urefs: rng.gen(),
             print: rng.gen(),
             blake2b: rng.gen(),
+            lock_contract_package: rng.gen(),
+            emit_event: rng.gen(),
+            call_contract_with_gas_limit: rng.gen(),
+            call_versioned_contract_with_gas_limit: rng.gen(),
+            transfer_from_purse_to_account_with_receipt: rng.gen(),
+            provision_contract_user_group_urefs: rng.gen(),
         }
     }
 }
@@ -639,6 +763,12 @@ pub mod gens {
             remove_contract_user_group_urefs in host_function_cost_arb(),
             print in host_function_cost_arb(),
             blake2b in host_function_cost_arb(),
+            lock_contract_package in host_function_cost_arb(),
+            emit_event in host_function_cost_arb(),
+            call_contract_with_gas_limit in host_function_cost_arb(),
+            call_versioned_contract_with_gas_limit in host_function_cost_arb(),
+            transfer_from_purse_to_account_with_receipt in host_function_cost_arb(),
+            provision_contract_user_group_urefs in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -683,6 +813,12 @@ pub mod gens {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                lock_contract_package,
+                emit_event,
+                call_contract_with_gas_limit,
+                call_versioned_contract_with_gas_limit,
+                transfer_from_purse_to_account_with_receipt,
+                provision_contract_user_group_urefs,
             }
         }
     }
@@ -0,0 +1,60 @@
+//! Capturing of debug output emitted by contracts via the `casper_print` host function.
+//!
+//! Output is always written to stdout as before, and is additionally recorded while running
+//! inside [`capture`], which allows test harnesses to inspect what a contract printed.
+use std::cell::RefCell;
+
+thread_local! {
+    static CAPTURED_OUTPUT: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+/// Runs `f`, returning its result along with all the lines printed by contracts on the current
+/// thread while it ran.
+///
+/// Calls may be nested; each call only receives the output emitted during its own invocation.
+pub fn capture<R, F: FnOnce() -> R>(f: F) -> (R, Vec<String>) {
+    let previous = CAPTURED_OUTPUT.with(|captured| captured.replace(Some(Vec::new())));
+    let result = f();
+    let output = CAPTURED_OUTPUT
+        .with(|captured| captured.replace(previous))
+        .unwrap_or_default();
+    (result, output)
+}
+
+/// Prints `text` to stdout, recording it if called from within [`capture`].
+#[cfg(feature = "test-support")]
+pub(crate) fn print(text: &str) {
+    println!("{}", text);
+    CAPTURED_OUTPUT.with(|captured| {
+        if let Some(output) = captured.borrow_mut().as_mut() {
+            output.push(text.to_string());
+        }
+    });
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_capture_only_within_scope() {
+        print("not captured");
+        let ((), output) = capture(|| {
+            print("first");
+            print("second");
+        });
+        assert_eq!(output, vec!["first".to_string(), "second".to_string()]);
+    }
+
+    #[test]
+    fn should_capture_nested_output_separately() {
+        let (inner, outer) = capture(|| {
+            print("outer");
+            let ((), inner) = capture(|| print("inner"));
+            print("outer again");
+            inner
+        });
+        assert_eq!(inner, vec!["inner".to_string()]);
+        assert_eq!(outer, vec!["outer".to_string(), "outer again".to_string()]);
+    }
+}
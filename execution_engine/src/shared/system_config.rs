@@ -95,33 +95,51 @@ impl Distribution<SystemConfig> for Standard {
     }
 }
 
-impl ToBytes for SystemConfig {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        ret.append(&mut self.wasmless_transfer_cost.to_bytes()?);
-        ret.append(&mut self.auction_costs.to_bytes()?);
-        ret.append(&mut self.mint_costs.to_bytes()?);
-        ret.append(&mut self.handle_payment_costs.to_bytes()?);
-        ret.append(&mut self.standard_payment_costs.to_bytes()?);
-
-        Ok(ret)
+impl SystemConfig {
+    /// Serializes the costs which were configurable at genesis into `buffer`.
+    pub(crate) fn write_legacy_fields(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        buffer.append(&mut self.wasmless_transfer_cost.to_bytes()?);
+        self.auction_costs.write_legacy_fields(buffer)?;
+        self.mint_costs.write_legacy_fields(buffer)?;
+        buffer.append(&mut self.handle_payment_costs.to_bytes()?);
+        buffer.append(&mut self.standard_payment_costs.to_bytes()?);
+        Ok(())
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the serialized length of the costs written by
+    /// [`write_legacy_fields`](Self::write_legacy_fields).
+    pub(crate) fn legacy_fields_serialized_length(&self) -> usize {
         self.wasmless_transfer_cost.serialized_length()
-            + self.auction_costs.serialized_length()
-            + self.mint_costs.serialized_length()
+            + self.auction_costs.legacy_fields_serialized_length()
+            + self.mint_costs.legacy_fields_serialized_length()
             + self.handle_payment_costs.serialized_length()
             + self.standard_payment_costs.serialized_length()
     }
-}
 
-impl FromBytes for SystemConfig {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+    /// Serializes the costs which became configurable since genesis into `buffer`.
+    pub(crate) fn write_appended_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        self.auction_costs.write_appended_fields(buffer)?;
+        self.mint_costs.write_appended_fields(buffer)
+    }
+
+    /// Returns the serialized length of the costs written by
+    /// [`write_appended_fields`](Self::write_appended_fields).
+    pub(crate) fn appended_fields_serialized_length(&self) -> usize {
+        self.auction_costs.appended_fields_serialized_length()
+            + self.mint_costs.appended_fields_serialized_length()
+    }
+
+    /// Deserializes the costs written by [`write_legacy_fields`](Self::write_legacy_fields), using
+    /// the defaults for the costs which became configurable since genesis.
+    pub(crate) fn legacy_fields_from_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (wasmless_transfer_cost, rem) = FromBytes::from_bytes(bytes)?;
-        let (auction_costs, rem) = FromBytes::from_bytes(rem)?;
-        let (mint_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (auction_costs, rem) = AuctionCosts::legacy_fields_from_bytes(rem)?;
+        let (mint_costs, rem) = MintCosts::legacy_fields_from_bytes(rem)?;
         let (handle_payment_costs, rem) = FromBytes::from_bytes(rem)?;
         let (standard_payment_costs, rem) = FromBytes::from_bytes(rem)?;
         Ok((
@@ -135,6 +153,46 @@ impl FromBytes for SystemConfig {
             rem,
         ))
     }
+
+    /// Deserializes the costs written by [`write_appended_fields`](Self::write_appended_fields)
+    /// into `self`.
+    pub(crate) fn appended_fields_from_bytes(
+        self,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (auction_costs, rem) = self.auction_costs.appended_fields_from_bytes(bytes)?;
+        let (mint_costs, rem) = self.mint_costs.appended_fields_from_bytes(rem)?;
+        Ok((
+            SystemConfig {
+                auction_costs,
+                mint_costs,
+                ..self
+            },
+            rem,
+        ))
+    }
+}
+
+impl ToBytes for SystemConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.write_legacy_fields(&mut ret)?;
+        self.write_appended_fields(&mut ret)?;
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_fields_serialized_length() + self.appended_fields_serialized_length()
+    }
+}
+
+impl FromBytes for SystemConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (system_config, rem) = SystemConfig::legacy_fields_from_bytes(bytes)?;
+        system_config.appended_fields_from_bytes(rem)
+    }
 }
 
 #[cfg(any(feature = "gens", test))]
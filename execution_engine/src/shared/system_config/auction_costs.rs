@@ -16,6 +16,12 @@ pub const DEFAULT_WITHDRAW_DELEGATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_READ_ERA_ID_COST: u32 = 10_000;
 pub const DEFAULT_ACTIVATE_BID_COST: u32 = 10_000;
+pub const DEFAULT_CANCEL_VALIDATOR_UNBOND_COST: u32 = 10_000;
+pub const DEFAULT_CANCEL_DELEGATOR_UNBOND_COST: u32 = 10_000;
+pub const DEFAULT_GET_BID_COST: u32 = 10_000;
+pub const DEFAULT_GET_DELEGATOR_COST: u32 = 10_000;
+pub const DEFAULT_SET_VALIDATOR_INFO_COST: u32 = 10_000;
+pub const DEFAULT_SET_VALIDATOR_INFO_BYTE_COST: u32 = 630;
 
 /// Description of costs of calling auction entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -33,6 +39,13 @@ pub struct AuctionCosts {
     pub withdraw_validator_reward: u32,
     pub read_era_id: u32,
     pub activate_bid: u32,
+    pub cancel_validator_unbond: u32,
+    pub cancel_delegator_unbond: u32,
+    pub get_bid: u32,
+    pub get_delegator: u32,
+    pub set_validator_info: u32,
+    /// Cost per byte of the name, URL and icon hash set by `set_validator_info`.
+    pub set_validator_info_byte: u32,
 }
 
 impl Default for AuctionCosts {
@@ -51,32 +64,42 @@ impl Default for AuctionCosts {
             withdraw_validator_reward: DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST,
             read_era_id: DEFAULT_READ_ERA_ID_COST,
             activate_bid: DEFAULT_ACTIVATE_BID_COST,
+            cancel_validator_unbond: DEFAULT_CANCEL_VALIDATOR_UNBOND_COST,
+            cancel_delegator_unbond: DEFAULT_CANCEL_DELEGATOR_UNBOND_COST,
+            get_bid: DEFAULT_GET_BID_COST,
+            get_delegator: DEFAULT_GET_DELEGATOR_COST,
+            set_validator_info: DEFAULT_SET_VALIDATOR_INFO_COST,
+            set_validator_info_byte: DEFAULT_SET_VALIDATOR_INFO_BYTE_COST,
         }
     }
 }
 
-impl ToBytes for AuctionCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        ret.append(&mut self.get_era_validators.to_bytes()?);
-        ret.append(&mut self.read_seigniorage_recipients.to_bytes()?);
-        ret.append(&mut self.add_bid.to_bytes()?);
-        ret.append(&mut self.withdraw_bid.to_bytes()?);
-        ret.append(&mut self.delegate.to_bytes()?);
-        ret.append(&mut self.undelegate.to_bytes()?);
-        ret.append(&mut self.run_auction.to_bytes()?);
-        ret.append(&mut self.slash.to_bytes()?);
-        ret.append(&mut self.distribute.to_bytes()?);
-        ret.append(&mut self.withdraw_delegator_reward.to_bytes()?);
-        ret.append(&mut self.withdraw_validator_reward.to_bytes()?);
-        ret.append(&mut self.read_era_id.to_bytes()?);
-        ret.append(&mut self.activate_bid.to_bytes()?);
-
-        Ok(ret)
+impl AuctionCosts {
+    /// Serializes the costs of the auction entry points which were available at genesis into
+    /// `buffer`.
+    pub(crate) fn write_legacy_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), casper_types::bytesrepr::Error> {
+        buffer.append(&mut self.get_era_validators.to_bytes()?);
+        buffer.append(&mut self.read_seigniorage_recipients.to_bytes()?);
+        buffer.append(&mut self.add_bid.to_bytes()?);
+        buffer.append(&mut self.withdraw_bid.to_bytes()?);
+        buffer.append(&mut self.delegate.to_bytes()?);
+        buffer.append(&mut self.undelegate.to_bytes()?);
+        buffer.append(&mut self.run_auction.to_bytes()?);
+        buffer.append(&mut self.slash.to_bytes()?);
+        buffer.append(&mut self.distribute.to_bytes()?);
+        buffer.append(&mut self.withdraw_delegator_reward.to_bytes()?);
+        buffer.append(&mut self.withdraw_validator_reward.to_bytes()?);
+        buffer.append(&mut self.read_era_id.to_bytes()?);
+        buffer.append(&mut self.activate_bid.to_bytes()?);
+        Ok(())
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the serialized length of the costs written by
+    /// [`write_legacy_fields`](Self::write_legacy_fields).
+    pub(crate) fn legacy_fields_serialized_length(&self) -> usize {
         self.get_era_validators.serialized_length()
             + self.read_seigniorage_recipients.serialized_length()
             + self.add_bid.serialized_length()
@@ -91,10 +114,37 @@ impl ToBytes for AuctionCosts {
             + self.read_era_id.serialized_length()
             + self.activate_bid.serialized_length()
     }
-}
 
-impl FromBytes for AuctionCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+    /// Serializes the costs of the auction entry points added since genesis into `buffer`.
+    pub(crate) fn write_appended_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), casper_types::bytesrepr::Error> {
+        buffer.append(&mut self.cancel_validator_unbond.to_bytes()?);
+        buffer.append(&mut self.cancel_delegator_unbond.to_bytes()?);
+        buffer.append(&mut self.get_bid.to_bytes()?);
+        buffer.append(&mut self.get_delegator.to_bytes()?);
+        buffer.append(&mut self.set_validator_info.to_bytes()?);
+        buffer.append(&mut self.set_validator_info_byte.to_bytes()?);
+        Ok(())
+    }
+
+    /// Returns the serialized length of the costs written by
+    /// [`write_appended_fields`](Self::write_appended_fields).
+    pub(crate) fn appended_fields_serialized_length(&self) -> usize {
+        self.cancel_validator_unbond.serialized_length()
+            + self.cancel_delegator_unbond.serialized_length()
+            + self.get_bid.serialized_length()
+            + self.get_delegator.serialized_length()
+            + self.set_validator_info.serialized_length()
+            + self.set_validator_info_byte.serialized_length()
+    }
+
+    /// Deserializes the costs written by [`write_legacy_fields`](Self::write_legacy_fields), using
+    /// the default costs for the auction entry points added since genesis.
+    pub(crate) fn legacy_fields_from_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
         let (get_era_validators, rem) = FromBytes::from_bytes(bytes)?;
         let (read_seigniorage_recipients, rem) = FromBytes::from_bytes(rem)?;
         let (add_bid, rem) = FromBytes::from_bytes(rem)?;
@@ -123,12 +173,61 @@ impl FromBytes for AuctionCosts {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                ..Self::default()
+            },
+            rem,
+        ))
+    }
+
+    /// Deserializes the costs written by [`write_appended_fields`](Self::write_appended_fields)
+    /// into `self`.
+    pub(crate) fn appended_fields_from_bytes(
+        self,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (cancel_validator_unbond, rem) = FromBytes::from_bytes(bytes)?;
+        let (cancel_delegator_unbond, rem) = FromBytes::from_bytes(rem)?;
+        let (get_bid, rem) = FromBytes::from_bytes(rem)?;
+        let (get_delegator, rem) = FromBytes::from_bytes(rem)?;
+        let (set_validator_info, rem) = FromBytes::from_bytes(rem)?;
+        let (set_validator_info_byte, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            Self {
+                cancel_validator_unbond,
+                cancel_delegator_unbond,
+                get_bid,
+                get_delegator,
+                set_validator_info,
+                set_validator_info_byte,
+                ..self
             },
             rem,
         ))
     }
 }
 
+impl ToBytes for AuctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.write_legacy_fields(&mut ret)?;
+        self.write_appended_fields(&mut ret)?;
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_fields_serialized_length() + self.appended_fields_serialized_length()
+    }
+}
+
+impl FromBytes for AuctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (auction_costs, rem) = AuctionCosts::legacy_fields_from_bytes(bytes)?;
+        auction_costs.appended_fields_from_bytes(rem)
+    }
+}
+
 impl Distribution<AuctionCosts> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AuctionCosts {
         AuctionCosts {
@@ -145,6 +244,12 @@ impl Distribution<AuctionCosts> for Standard {
             withdraw_validator_reward: rng.gen(),
             read_era_id: rng.gen(),
             activate_bid: rng.gen(),
+            cancel_validator_unbond: rng.gen(),
+            cancel_delegator_unbond: rng.gen(),
+            get_bid: rng.gen(),
+            get_delegator: rng.gen(),
+            set_validator_info: rng.gen(),
+            set_validator_info_byte: rng.gen(),
         }
     }
 }
@@ -170,6 +275,12 @@ pub mod gens {
             withdraw_validator_reward in num::u32::ANY,
             read_era_id in num::u32::ANY,
             activate_bid in num::u32::ANY,
+            cancel_validator_unbond in num::u32::ANY,
+            cancel_delegator_unbond in num::u32::ANY,
+            get_bid in num::u32::ANY,
+            get_delegator in num::u32::ANY,
+            set_validator_info in num::u32::ANY,
+            set_validator_info_byte in num::u32::ANY,
         ) -> AuctionCosts {
             AuctionCosts {
                 get_era_validators,
@@ -185,6 +296,12 @@ pub mod gens {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                cancel_validator_unbond,
+                cancel_delegator_unbond,
+                get_bid,
+                get_delegator,
+                set_validator_info,
+                set_validator_info_byte,
             }
         }
     }
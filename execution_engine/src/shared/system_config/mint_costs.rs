@@ -9,6 +9,8 @@ pub const DEFAULT_CREATE_COST: u32 = 10_000;
 pub const DEFAULT_BALANCE_COST: u32 = 10_000;
 pub const DEFAULT_TRANSFER_COST: u32 = 10_000;
 pub const DEFAULT_READ_BASE_ROUND_REWARD_COST: u32 = 10_000;
+pub const DEFAULT_APPROVE_COST: u32 = 10_000;
+pub const DEFAULT_TRANSFER_FROM_COST: u32 = 10_000;
 
 /// Description of costs of calling mint entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -19,6 +21,8 @@ pub struct MintCosts {
     pub balance: u32,
     pub transfer: u32,
     pub read_base_round_reward: u32,
+    pub approve: u32,
+    pub transfer_from: u32,
 }
 
 impl Default for MintCosts {
@@ -30,25 +34,30 @@ impl Default for MintCosts {
             balance: DEFAULT_BALANCE_COST,
             transfer: DEFAULT_TRANSFER_COST,
             read_base_round_reward: DEFAULT_READ_BASE_ROUND_REWARD_COST,
+            approve: DEFAULT_APPROVE_COST,
+            transfer_from: DEFAULT_TRANSFER_FROM_COST,
         }
     }
 }
 
-impl ToBytes for MintCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        ret.append(&mut self.mint.to_bytes()?);
-        ret.append(&mut self.reduce_total_supply.to_bytes()?);
-        ret.append(&mut self.create.to_bytes()?);
-        ret.append(&mut self.balance.to_bytes()?);
-        ret.append(&mut self.transfer.to_bytes()?);
-        ret.append(&mut self.read_base_round_reward.to_bytes()?);
-
-        Ok(ret)
+impl MintCosts {
+    /// Serializes the costs of the mint entry points which were available at genesis into `buffer`.
+    pub(crate) fn write_legacy_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), casper_types::bytesrepr::Error> {
+        buffer.append(&mut self.mint.to_bytes()?);
+        buffer.append(&mut self.reduce_total_supply.to_bytes()?);
+        buffer.append(&mut self.create.to_bytes()?);
+        buffer.append(&mut self.balance.to_bytes()?);
+        buffer.append(&mut self.transfer.to_bytes()?);
+        buffer.append(&mut self.read_base_round_reward.to_bytes()?);
+        Ok(())
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the serialized length of the costs written by
+    /// [`write_legacy_fields`](Self::write_legacy_fields).
+    pub(crate) fn legacy_fields_serialized_length(&self) -> usize {
         self.mint.serialized_length()
             + self.reduce_total_supply.serialized_length()
             + self.create.serialized_length()
@@ -56,17 +65,34 @@ impl ToBytes for MintCosts {
             + self.transfer.serialized_length()
             + self.read_base_round_reward.serialized_length()
     }
-}
 
-impl FromBytes for MintCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+    /// Serializes the costs of the mint entry points added since genesis into `buffer`.
+    pub(crate) fn write_appended_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), casper_types::bytesrepr::Error> {
+        buffer.append(&mut self.approve.to_bytes()?);
+        buffer.append(&mut self.transfer_from.to_bytes()?);
+        Ok(())
+    }
+
+    /// Returns the serialized length of the costs written by
+    /// [`write_appended_fields`](Self::write_appended_fields).
+    pub(crate) fn appended_fields_serialized_length(&self) -> usize {
+        self.approve.serialized_length() + self.transfer_from.serialized_length()
+    }
+
+    /// Deserializes the costs written by [`write_legacy_fields`](Self::write_legacy_fields), using
+    /// the default costs for the mint entry points added since genesis.
+    pub(crate) fn legacy_fields_from_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
         let (mint, rem) = FromBytes::from_bytes(bytes)?;
         let (reduce_total_supply, rem) = FromBytes::from_bytes(rem)?;
         let (create, rem) = FromBytes::from_bytes(rem)?;
         let (balance, rem) = FromBytes::from_bytes(rem)?;
         let (transfer, rem) = FromBytes::from_bytes(rem)?;
         let (read_base_round_reward, rem) = FromBytes::from_bytes(rem)?;
-
         Ok((
             Self {
                 mint,
@@ -75,10 +101,51 @@ impl FromBytes for MintCosts {
                 balance,
                 transfer,
                 read_base_round_reward,
+                ..Self::default()
             },
             rem,
         ))
     }
+
+    /// Deserializes the costs written by [`write_appended_fields`](Self::write_appended_fields)
+    /// into `self`.
+    pub(crate) fn appended_fields_from_bytes(
+        self,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (approve, rem) = FromBytes::from_bytes(bytes)?;
+        let (transfer_from, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            Self {
+                approve,
+                transfer_from,
+                ..self
+            },
+            rem,
+        ))
+    }
+}
+
+impl ToBytes for MintCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.write_legacy_fields(&mut ret)?;
+        self.write_appended_fields(&mut ret)?;
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_fields_serialized_length() + self.appended_fields_serialized_length()
+    }
+}
+
+impl FromBytes for MintCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (mint_costs, rem) = MintCosts::legacy_fields_from_bytes(bytes)?;
+        mint_costs.appended_fields_from_bytes(rem)
+    }
 }
 
 impl Distribution<MintCosts> for Standard {
@@ -90,6 +157,8 @@ impl Distribution<MintCosts> for Standard {
             balance: rng.gen(),
             transfer: rng.gen(),
             read_base_round_reward: rng.gen(),
+            approve: rng.gen(),
+            transfer_from: rng.gen(),
         }
     }
 }
@@ -108,6 +177,8 @@ pub mod gens {
             balance in num::u32::ANY,
             transfer in num::u32::ANY,
             read_base_round_reward in num::u32::ANY,
+            approve in num::u32::ANY,
+            transfer_from in num::u32::ANY,
         ) -> MintCosts {
             MintCosts {
                 mint,
@@ -116,6 +187,8 @@ pub mod gens {
                 balance,
                 transfer,
                 read_base_round_reward,
+                approve,
+                transfer_from,
             }
         }
     }
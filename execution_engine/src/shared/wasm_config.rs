@@ -2,7 +2,10 @@ use datasize::DataSize;
 use rand::{distributions::Standard, prelude::*, Rng};
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    account::MAX_ASSOCIATED_KEYS,
+    bytesrepr::{self, FromBytes, ToBytes},
+};
 
 use super::{
     host_function_costs::HostFunctionCosts, opcode_costs::OpcodeCosts, storage_costs::StorageCosts,
@@ -10,6 +13,12 @@ use super::{
 
 pub const DEFAULT_WASM_MAX_MEMORY: u32 = 64;
 pub const DEFAULT_MAX_STACK_HEIGHT: u32 = 64 * 1024;
+pub const DEFAULT_MAX_NAMED_KEY_LENGTH: u32 = 128;
+pub const DEFAULT_MAX_CL_VALUE_SIZE: u32 = 8 * 1024 * 1024;
+pub const DEFAULT_MAX_NAMED_KEYS_COUNT: u32 = 10_000;
+pub const DEFAULT_MAX_ASSOCIATED_KEYS: u32 = MAX_ASSOCIATED_KEYS as u32;
+pub const DEFAULT_MAX_ACTION_THRESHOLD: u8 = u8::MAX;
+pub const DEFAULT_MAX_CONTRACT_WASM_SIZE: u32 = 1_048_576;
 
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
 pub struct WasmConfig {
@@ -17,6 +26,20 @@ pub struct WasmConfig {
     pub max_memory: u32,
     /// Max stack height (native WebAssembly stack limiter)
     pub max_stack_height: u32,
+    /// Maximum length in bytes of a name under which a key can be stored in named keys.
+    pub max_named_key_length: u32,
+    /// Maximum serialized size in bytes of a `CLValue` written to global state.
+    pub max_cl_value_size: u32,
+    /// Maximum number of named keys an account or contract can hold.
+    pub max_named_keys_count: u32,
+    /// Maximum number of associated keys an account can hold.
+    ///
+    /// Limits above [`MAX_ASSOCIATED_KEYS`] have no effect.
+    pub max_associated_keys: u32,
+    /// Maximum weight to which an account's action thresholds can be set.
+    pub max_action_threshold: u8,
+    /// Maximum size in bytes of a contract's WASM stored in global state.
+    pub max_contract_wasm_size: u32,
     /// Wasm opcode costs table
     opcode_costs: OpcodeCosts,
     /// Storage costs
@@ -26,9 +49,16 @@ pub struct WasmConfig {
 }
 
 impl WasmConfig {
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         max_memory: u32,
         max_stack_height: u32,
+        max_named_key_length: u32,
+        max_cl_value_size: u32,
+        max_named_keys_count: u32,
+        max_associated_keys: u32,
+        max_action_threshold: u8,
+        max_contract_wasm_size: u32,
         opcode_costs: OpcodeCosts,
         storage_costs: StorageCosts,
         host_function_costs: HostFunctionCosts,
@@ -36,6 +66,12 @@ impl WasmConfig {
         Self {
             max_memory,
             max_stack_height,
+            max_named_key_length,
+            max_cl_value_size,
+            max_named_keys_count,
+            max_associated_keys,
+            max_action_threshold,
+            max_contract_wasm_size,
             opcode_costs,
             storage_costs,
             host_function_costs,
@@ -60,6 +96,12 @@ impl Default for WasmConfig {
         Self {
             max_memory: DEFAULT_WASM_MAX_MEMORY,
             max_stack_height: DEFAULT_MAX_STACK_HEIGHT,
+            max_named_key_length: DEFAULT_MAX_NAMED_KEY_LENGTH,
+            max_cl_value_size: DEFAULT_MAX_CL_VALUE_SIZE,
+            max_named_keys_count: DEFAULT_MAX_NAMED_KEYS_COUNT,
+            max_associated_keys: DEFAULT_MAX_ASSOCIATED_KEYS,
+            max_action_threshold: DEFAULT_MAX_ACTION_THRESHOLD,
+            max_contract_wasm_size: DEFAULT_MAX_CONTRACT_WASM_SIZE,
             opcode_costs: OpcodeCosts::default(),
             storage_costs: StorageCosts::default(),
             host_function_costs: HostFunctionCosts::default(),
@@ -67,36 +109,62 @@ impl Default for WasmConfig {
     }
 }
 
-impl ToBytes for WasmConfig {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        ret.append(&mut self.max_memory.to_bytes()?);
-        ret.append(&mut self.max_stack_height.to_bytes()?);
-        ret.append(&mut self.opcode_costs.to_bytes()?);
-        ret.append(&mut self.storage_costs.to_bytes()?);
-        ret.append(&mut self.host_function_costs.to_bytes()?);
-
-        Ok(ret)
+impl WasmConfig {
+    /// Serializes the settings which were configurable at genesis into `buffer`.
+    pub(crate) fn write_legacy_fields(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        buffer.append(&mut self.max_memory.to_bytes()?);
+        buffer.append(&mut self.max_stack_height.to_bytes()?);
+        buffer.append(&mut self.opcode_costs.to_bytes()?);
+        buffer.append(&mut self.storage_costs.to_bytes()?);
+        self.host_function_costs.write_legacy_fields(buffer)
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the serialized length of the settings written by
+    /// [`write_legacy_fields`](Self::write_legacy_fields).
+    pub(crate) fn legacy_fields_serialized_length(&self) -> usize {
         self.max_memory.serialized_length()
             + self.max_stack_height.serialized_length()
             + self.opcode_costs.serialized_length()
             + self.storage_costs.serialized_length()
-            + self.host_function_costs.serialized_length()
+            + self.host_function_costs.legacy_fields_serialized_length()
     }
-}
 
-impl FromBytes for WasmConfig {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    /// Serializes the settings which became configurable since genesis into `buffer`.
+    pub(crate) fn write_appended_fields(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        buffer.append(&mut self.max_named_key_length.to_bytes()?);
+        buffer.append(&mut self.max_cl_value_size.to_bytes()?);
+        buffer.append(&mut self.max_named_keys_count.to_bytes()?);
+        buffer.append(&mut self.max_associated_keys.to_bytes()?);
+        buffer.append(&mut self.max_action_threshold.to_bytes()?);
+        buffer.append(&mut self.max_contract_wasm_size.to_bytes()?);
+        self.host_function_costs.write_appended_fields(buffer)
+    }
+
+    /// Returns the serialized length of the settings written by
+    /// [`write_appended_fields`](Self::write_appended_fields).
+    pub(crate) fn appended_fields_serialized_length(&self) -> usize {
+        self.max_named_key_length.serialized_length()
+            + self.max_cl_value_size.serialized_length()
+            + self.max_named_keys_count.serialized_length()
+            + self.max_associated_keys.serialized_length()
+            + self.max_action_threshold.serialized_length()
+            + self.max_contract_wasm_size.serialized_length()
+            + self.host_function_costs.appended_fields_serialized_length()
+    }
+
+    /// Deserializes the settings written by [`write_legacy_fields`](Self::write_legacy_fields),
+    /// using the defaults for the settings which became configurable since genesis.
+    pub(crate) fn legacy_fields_from_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (max_memory, rem) = FromBytes::from_bytes(bytes)?;
         let (max_stack_height, rem) = FromBytes::from_bytes(rem)?;
         let (opcode_costs, rem) = FromBytes::from_bytes(rem)?;
         let (storage_costs, rem) = FromBytes::from_bytes(rem)?;
-        let (host_function_costs, rem) = FromBytes::from_bytes(rem)?;
-
+        let (host_function_costs, rem) = HostFunctionCosts::legacy_fields_from_bytes(rem)?;
         Ok((
             WasmConfig {
                 max_memory,
@@ -104,17 +172,75 @@ impl FromBytes for WasmConfig {
                 opcode_costs,
                 storage_costs,
                 host_function_costs,
+                ..WasmConfig::default()
+            },
+            rem,
+        ))
+    }
+
+    /// Deserializes the settings written by [`write_appended_fields`](Self::write_appended_fields)
+    /// into `self`.
+    pub(crate) fn appended_fields_from_bytes(
+        self,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (max_named_key_length, rem) = FromBytes::from_bytes(bytes)?;
+        let (max_cl_value_size, rem) = FromBytes::from_bytes(rem)?;
+        let (max_named_keys_count, rem) = FromBytes::from_bytes(rem)?;
+        let (max_associated_keys, rem) = FromBytes::from_bytes(rem)?;
+        let (max_action_threshold, rem) = FromBytes::from_bytes(rem)?;
+        let (max_contract_wasm_size, rem) = FromBytes::from_bytes(rem)?;
+        let (host_function_costs, rem) =
+            self.host_function_costs.appended_fields_from_bytes(rem)?;
+        Ok((
+            WasmConfig {
+                max_named_key_length,
+                max_cl_value_size,
+                max_named_keys_count,
+                max_associated_keys,
+                max_action_threshold,
+                max_contract_wasm_size,
+                host_function_costs,
+                ..self
             },
             rem,
         ))
     }
 }
 
+impl ToBytes for WasmConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.write_legacy_fields(&mut ret)?;
+        self.write_appended_fields(&mut ret)?;
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_fields_serialized_length() + self.appended_fields_serialized_length()
+    }
+}
+
+impl FromBytes for WasmConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasm_config, rem) = WasmConfig::legacy_fields_from_bytes(bytes)?;
+        wasm_config.appended_fields_from_bytes(rem)
+    }
+}
+
 impl Distribution<WasmConfig> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> WasmConfig {
         WasmConfig {
             max_memory: rng.gen(),
             max_stack_height: rng.gen(),
+            max_named_key_length: rng.gen(),
+            max_cl_value_size: rng.gen(),
+            max_named_keys_count: rng.gen(),
+            max_associated_keys: rng.gen(),
+            max_action_threshold: rng.gen(),
+            max_contract_wasm_size: rng.gen(),
             opcode_costs: rng.gen(),
             storage_costs: rng.gen(),
             host_function_costs: rng.gen(),
@@ -136,6 +262,12 @@ pub mod gens {
         pub fn wasm_config_arb() (
             max_memory in num::u32::ANY,
             max_stack_height in num::u32::ANY,
+            max_named_key_length in num::u32::ANY,
+            max_cl_value_size in num::u32::ANY,
+            max_named_keys_count in num::u32::ANY,
+            max_associated_keys in num::u32::ANY,
+            max_action_threshold in num::u8::ANY,
+            max_contract_wasm_size in num::u32::ANY,
             opcode_costs in opcode_costs_arb(),
             storage_costs in storage_costs_arb(),
             host_function_costs in host_function_costs_arb(),
@@ -143,6 +275,12 @@ pub mod gens {
             WasmConfig {
                 max_memory,
                 max_stack_height,
+                max_named_key_length,
+                max_cl_value_size,
+                max_named_keys_count,
+                max_associated_keys,
+                max_action_threshold,
+                max_contract_wasm_size,
                 opcode_costs,
                 storage_costs,
                 host_function_costs,
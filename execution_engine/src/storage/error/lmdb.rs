@@ -17,6 +17,9 @@ pub enum Error {
 
     #[error("Another thread panicked while holding a lock")]
     Poison,
+
+    #[error("Pending effects can't be applied: {0}")]
+    InvalidPendingEffects(String),
 }
 
 impl wasmi::HostError for Error {}
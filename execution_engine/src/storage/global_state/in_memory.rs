@@ -1,4 +1,4 @@
-use std::{ops::Deref, sync::Arc};
+use std::{collections::BTreeMap, ops::Deref, sync::Arc};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -10,7 +10,10 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{
+        commit, merge_pending_keys, pending_values, read_pending, CommitResult, StateProvider,
+        StateReader,
+    },
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
@@ -23,7 +26,7 @@ use crate::storage::{
         in_memory::InMemoryTrieStore,
         operations::{
             self, keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
-            WriteResult,
+            TrieDiff, WriteResult,
         },
     },
 };
@@ -40,6 +43,9 @@ pub struct InMemoryGlobalStateView {
     pub environment: Arc<InMemoryEnvironment>,
     pub store: Arc<InMemoryTrieStore>,
     pub root_hash: Blake2bHash,
+    /// Values written by effects not yet committed on top of `root_hash`, which shadow the
+    /// committed state on reads.
+    pub pending: Option<Arc<BTreeMap<Key, StoredValue>>>,
 }
 
 impl InMemoryGlobalState {
@@ -79,6 +85,20 @@ impl InMemoryGlobalState {
         }
     }
 
+    /// Creates an independent copy of this state, sharing none of its underlying storage.
+    ///
+    /// Tries and protocol data committed to either state after forking are not visible in the
+    /// other.
+    pub fn fork(&self) -> Result<Self, error::Error> {
+        let environment = Arc::new(self.environment.fork()?);
+        Ok(InMemoryGlobalState::new(
+            environment,
+            Arc::clone(&self.trie_store),
+            Arc::clone(&self.protocol_data_store),
+            self.empty_root_hash,
+        ))
+    }
+
     /// Creates a state from a given set of `Key, StoredValue` pairs.
     pub fn from_pairs(
         correlation_id: CorrelationId,
@@ -119,6 +139,9 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
+        if let Some(value) = read_pending(&self.pending, key) {
+            return Ok(Some(value));
+        }
         let txn = self.environment.create_read_txn()?;
         let ret = match read::<
             Key,
@@ -189,6 +212,7 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
             }
         }
         txn.commit()?;
+        merge_pending_keys(&self.pending, prefix, &mut ret)?;
         Ok(ret)
     }
 }
@@ -206,11 +230,29 @@ impl StateProvider for InMemoryGlobalState {
             environment: Arc::clone(&self.environment),
             store: Arc::clone(&self.trie_store),
             root_hash: prestate_hash,
+            pending: None,
         });
         txn.commit()?;
         Ok(maybe_state)
     }
 
+    fn checkout_with_pending_effects(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
+    ) -> Result<Option<Self::Reader>, Self::Error> {
+        let mut view = match self.checkout(state_hash)? {
+            Some(view) => view,
+            None => return Ok(None),
+        };
+        if !pending_effects.is_empty() {
+            let values = pending_values(&view, correlation_id, pending_effects)?;
+            view.pending = Some(Arc::new(values));
+        }
+        Ok(Some(view))
+    }
+
     fn commit(
         &self,
         correlation_id: CorrelationId,
@@ -298,12 +340,30 @@ impl StateProvider for InMemoryGlobalState {
         txn.commit()?;
         Ok(missing_descendants)
     }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<Vec<TrieDiff<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let diffs = operations::diff::<Key, StoredValue, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &pre_state_hash,
+            &post_state_hash,
+        )?;
+        txn.commit()?;
+        Ok(diffs)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::shared::newtypes::Blake2bHash;
-    use casper_types::{account::AccountHash, CLValue};
+    use casper_types::{account::AccountHash, bytesrepr::ToBytes, CLValue};
 
     use super::*;
 
@@ -445,6 +505,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checkout_with_pending_effects_reads_as_if_committed() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = test_pairs_updated
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, Transform::Write(value)))
+            .collect();
+
+        let pending_checkout = state
+            .checkout_with_pending_effects(correlation_id, root_hash, &effects)
+            .unwrap()
+            .unwrap();
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+
+        for TestPair { key, .. } in test_pairs_updated.iter().cloned() {
+            assert_eq!(
+                updated_checkout.read(correlation_id, &key).unwrap(),
+                pending_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+
+        let prefix = [test_pairs_updated[0].key.to_bytes().unwrap()[0]];
+        let mut pending_keys = pending_checkout
+            .keys_with_prefix(correlation_id, &prefix)
+            .unwrap();
+        let mut updated_keys = updated_checkout
+            .keys_with_prefix(correlation_id, &prefix)
+            .unwrap();
+        pending_keys.sort();
+        updated_keys.sort();
+        assert_eq!(updated_keys, pending_keys);
+    }
+
+    #[test]
+    fn checkout_with_pending_effects_fails_on_invalid_effects() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+
+        let mut effects = AdditiveMap::new();
+        effects.insert(
+            Key::Account(AccountHash::new([3u8; 32])),
+            Transform::AddInt32(1),
+        );
+
+        let result = state.checkout_with_pending_effects(correlation_id, root_hash, &effects);
+        assert!(matches!(
+            result,
+            Err(error::Error::InvalidPendingEffects(_))
+        ));
+    }
+
     #[test]
     fn initial_state_has_the_expected_hash() {
         let correlation_id = CorrelationId::new();
@@ -1,4 +1,4 @@
-use std::{ops::Deref, sync::Arc};
+use std::{collections::BTreeMap, ops::Deref, sync::Arc};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -10,7 +10,10 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error,
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{
+        commit, merge_pending_keys, pending_values, read_pending, CommitResult, StateProvider,
+        StateReader,
+    },
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
@@ -19,7 +22,8 @@ use crate::storage::{
     trie_store::{
         lmdb::LmdbTrieStore,
         operations::{
-            keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
+            self, keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
+            TrieDiff,
         },
     },
 };
@@ -36,6 +40,9 @@ pub struct LmdbGlobalStateView {
     pub environment: Arc<LmdbEnvironment>,
     pub store: Arc<LmdbTrieStore>,
     pub root_hash: Blake2bHash,
+    /// Values written by effects not yet committed on top of `root_hash`, which shadow the
+    /// committed state on reads.
+    pub pending: Option<Arc<BTreeMap<Key, StoredValue>>>,
 }
 
 impl LmdbGlobalState {
@@ -85,6 +92,9 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
+        if let Some(value) = read_pending(&self.pending, key) {
+            return Ok(Some(value));
+        }
         let txn = self.environment.create_read_txn()?;
         let ret = match read::<Key, StoredValue, lmdb::RoTransaction, LmdbTrieStore, Self::Error>(
             correlation_id,
@@ -149,6 +159,7 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
             }
         }
         txn.commit()?;
+        merge_pending_keys(&self.pending, prefix, &mut ret)?;
         Ok(ret)
     }
 }
@@ -165,11 +176,29 @@ impl StateProvider for LmdbGlobalState {
             environment: Arc::clone(&self.environment),
             store: Arc::clone(&self.trie_store),
             root_hash: state_hash,
+            pending: None,
         });
         txn.commit()?;
         Ok(maybe_state)
     }
 
+    fn checkout_with_pending_effects(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
+    ) -> Result<Option<Self::Reader>, Self::Error> {
+        let mut view = match self.checkout(state_hash)? {
+            Some(view) => view,
+            None => return Ok(None),
+        };
+        if !pending_effects.is_empty() {
+            let values = pending_values(&view, correlation_id, pending_effects)?;
+            view.pending = Some(Arc::new(values));
+        }
+        Ok(Some(view))
+    }
+
     fn commit(
         &self,
         correlation_id: CorrelationId,
@@ -256,6 +285,24 @@ impl StateProvider for LmdbGlobalState {
         txn.commit()?;
         Ok(missing_descendants)
     }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<Vec<TrieDiff<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let diffs = operations::diff::<Key, StoredValue, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &pre_state_hash,
+            &post_state_hash,
+        )?;
+        txn.commit()?;
+        Ok(diffs)
+    }
 }
 
 #[cfg(test)]
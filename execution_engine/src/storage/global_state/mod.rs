@@ -1,7 +1,7 @@
 pub mod in_memory;
 pub mod lmdb;
 
-use std::{fmt, hash::BuildHasher};
+use std::{collections::BTreeMap, fmt, hash::BuildHasher, sync::Arc};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -10,14 +10,18 @@ use crate::shared::{
     transform::{self, Transform},
     TypeMismatch,
 };
-use casper_types::{bytesrepr, Key, ProtocolVersion};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, ProtocolVersion,
+};
 
 use crate::storage::{
+    error,
     protocol_data::ProtocolData,
     transaction_source::{Transaction, TransactionSource},
     trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
+        operations::{read, write, ReadResult, TrieDiff, WriteResult},
         TrieStore,
     },
 };
@@ -88,6 +92,18 @@ pub trait StateProvider {
     /// Checkouts to the post state of a specific block.
     fn checkout(&self, state_hash: Blake2bHash) -> Result<Option<Self::Reader>, Self::Error>;
 
+    /// Checkouts to the post state of a specific block, overlaid with the values which
+    /// `pending_effects` will write once committed on top of it.
+    ///
+    /// This allows executing against the outcome of effects whose commit is still in progress.
+    /// Merkle proofs read from the returned view only cover the committed state.
+    fn checkout_with_pending_effects(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        pending_effects: &AdditiveMap<Key, Transform>,
+    ) -> Result<Option<Self::Reader>, Self::Error>;
+
     /// Applies changes and returns a new post state hash.
     /// block_hash is used for computing a deterministic and unique keys.
     fn commit(
@@ -130,6 +146,80 @@ pub trait StateProvider {
         correlation_id: CorrelationId,
         trie_keys: Vec<Blake2bHash>,
     ) -> Result<Vec<Blake2bHash>, Self::Error>;
+
+    /// Returns the changes between the states under `pre_state_hash` and `post_state_hash`,
+    /// ordered by key.
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<Vec<TrieDiff<Key, StoredValue>>, Self::Error>;
+}
+
+/// Returns the values which `effects` will write once committed on top of the state viewed by
+/// `reader`, without writing them to the trie.
+///
+/// Fails in the same cases as [`commit`] would.
+pub(crate) fn pending_values<R>(
+    reader: &R,
+    correlation_id: CorrelationId,
+    effects: &AdditiveMap<Key, Transform>,
+) -> Result<BTreeMap<Key, StoredValue>, error::Error>
+where
+    R: StateReader<Key, StoredValue, Error = error::Error>,
+{
+    let mut values = BTreeMap::new();
+    for (key, transform) in effects.iter() {
+        if let Transform::Identity = transform {
+            continue;
+        }
+        let value = match (reader.read(correlation_id, key)?, transform.clone()) {
+            (None, Transform::Write(new_value)) => new_value,
+            (None, _) => {
+                return Err(error::Error::InvalidPendingEffects(
+                    CommitResult::KeyNotFound(*key).to_string(),
+                ))
+            }
+            (Some(current_value), transform) => {
+                transform.apply(current_value).map_err(|error| {
+                    error::Error::InvalidPendingEffects(CommitResult::from(error).to_string())
+                })?
+            }
+        };
+        values.insert(*key, value);
+    }
+    Ok(values)
+}
+
+/// Returns the value under `key` in `pending`, if any.
+pub(crate) fn read_pending(
+    pending: &Option<Arc<BTreeMap<Key, StoredValue>>>,
+    key: &Key,
+) -> Option<StoredValue> {
+    pending
+        .as_ref()
+        .and_then(|pending| pending.get(key))
+        .cloned()
+}
+
+/// Merges the keys in `pending` matching `prefix` into `keys` read from the committed state.
+pub(crate) fn merge_pending_keys(
+    pending: &Option<Arc<BTreeMap<Key, StoredValue>>>,
+    prefix: &[u8],
+    keys: &mut Vec<Key>,
+) -> Result<(), bytesrepr::Error> {
+    let pending = match pending {
+        Some(pending) => pending,
+        None => return Ok(()),
+    };
+    keys.retain(|key| !pending.contains_key(key));
+    for key in pending.keys() {
+        if key.to_bytes()?.starts_with(prefix) {
+            keys.push(*key);
+        }
+    }
+    Ok(())
 }
 
 pub fn commit<'a, R, S, H, E>(
@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use casper_types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, LengthEncoding, ToBytes},
     ContractHash, HashAddr,
 };
 
@@ -9,6 +9,8 @@ use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
 
 const DEFAULT_ADDRESS: [u8; 32] = [0; 32];
 pub const DEFAULT_WASMLESS_TRANSFER_COST: u32 = 10_000;
+/// The version of the layout of the fields appended to protocol data since genesis.
+const PROTOCOL_DATA_LAYOUT_VERSION: u8 = 1;
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -19,6 +21,7 @@ pub struct ProtocolData {
     handle_payment: ContractHash,
     standard_payment: ContractHash,
     auction: ContractHash,
+    length_encoding: LengthEncoding,
 }
 
 /// Provides a default instance with non existing urefs and empty costs table.
@@ -34,6 +37,7 @@ impl Default for ProtocolData {
             handle_payment: DEFAULT_ADDRESS.into(),
             standard_payment: DEFAULT_ADDRESS.into(),
             auction: DEFAULT_ADDRESS.into(),
+            length_encoding: LengthEncoding::U32,
         }
     }
 }
@@ -55,6 +59,16 @@ impl ProtocolData {
             handle_payment,
             standard_payment,
             auction,
+            length_encoding: LengthEncoding::U32,
+        }
+    }
+
+    /// Returns a copy of this [`ProtocolData`] value using `length_encoding` for the length
+    /// prefixes of global state records.
+    pub fn with_length_encoding(self, length_encoding: LengthEncoding) -> Self {
+        ProtocolData {
+            length_encoding,
+            ..self
         }
     }
 
@@ -111,6 +125,12 @@ impl ProtocolData {
         self.auction
     }
 
+    /// Gets the encoding used for the length prefixes of global state records written under this
+    /// protocol version.
+    pub fn length_encoding(&self) -> LengthEncoding {
+        self.length_encoding
+    }
+
     /// Retrieves all valid system contracts stored in protocol version
     pub fn system_contracts(&self) -> Vec<ContractHash> {
         let mut vec = Vec::with_capacity(4);
@@ -151,34 +171,59 @@ impl ToBytes for ProtocolData {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
 
-        ret.append(&mut self.wasm_config.to_bytes()?);
-        ret.append(&mut self.system_config.to_bytes()?);
+        // The fields present at genesis come first, so that protocol data stored since then can
+        // still be read.
+        self.wasm_config.write_legacy_fields(&mut ret)?;
+        self.system_config.write_legacy_fields(&mut ret)?;
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.handle_payment.to_bytes()?);
         ret.append(&mut self.standard_payment.to_bytes()?);
         ret.append(&mut self.auction.to_bytes()?);
+        ret.push(PROTOCOL_DATA_LAYOUT_VERSION);
+        ret.append(&mut self.length_encoding.to_bytes()?);
+        self.wasm_config.write_appended_fields(&mut ret)?;
+        self.system_config.write_appended_fields(&mut ret)?;
 
         Ok(ret)
     }
 
     fn serialized_length(&self) -> usize {
-        self.wasm_config.serialized_length()
-            + self.system_config.serialized_length()
+        self.wasm_config.legacy_fields_serialized_length()
+            + self.system_config.legacy_fields_serialized_length()
             + self.mint.serialized_length()
             + self.handle_payment.serialized_length()
             + self.standard_payment.serialized_length()
             + self.auction.serialized_length()
+            + PROTOCOL_DATA_LAYOUT_VERSION.serialized_length()
+            + self.length_encoding.serialized_length()
+            + self.wasm_config.appended_fields_serialized_length()
+            + self.system_config.appended_fields_serialized_length()
     }
 }
 
 impl FromBytes for ProtocolData {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (wasm_config, rem) = WasmConfig::from_bytes(bytes)?;
-        let (system_config, rem) = FromBytes::from_bytes(rem)?;
+        let (wasm_config, rem) = WasmConfig::legacy_fields_from_bytes(bytes)?;
+        let (system_config, rem) = SystemConfig::legacy_fields_from_bytes(rem)?;
         let (mint, rem) = HashAddr::from_bytes(rem)?;
         let (handle_payment, rem) = HashAddr::from_bytes(rem)?;
         let (standard_payment, rem) = HashAddr::from_bytes(rem)?;
         let (auction, rem) = HashAddr::from_bytes(rem)?;
+        // Protocol data stored before its layout was versioned ends here; such protocol versions
+        // always used `u32` length prefixes, and the defaults for all settings which became
+        // configurable since.
+        let (wasm_config, system_config, length_encoding, rem) = if rem.is_empty() {
+            (wasm_config, system_config, LengthEncoding::U32, rem)
+        } else {
+            let (version, rem) = u8::from_bytes(rem)?;
+            if version != PROTOCOL_DATA_LAYOUT_VERSION {
+                return Err(bytesrepr::Error::Formatting);
+            }
+            let (length_encoding, rem) = LengthEncoding::from_bytes(rem)?;
+            let (wasm_config, rem) = wasm_config.appended_fields_from_bytes(rem)?;
+            let (system_config, rem) = system_config.appended_fields_from_bytes(rem)?;
+            (wasm_config, system_config, length_encoding, rem)
+        };
 
         Ok((
             ProtocolData {
@@ -188,6 +233,7 @@ impl FromBytes for ProtocolData {
                 standard_payment: standard_payment.into(),
                 auction: auction.into(),
                 system_config,
+                length_encoding,
             },
             rem,
         ))
@@ -196,12 +242,12 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{prop_compose, prop_oneof, strategy::Just};
 
     use crate::shared::{
         system_config::gens::system_config_arb, wasm_config::gens::wasm_config_arb,
     };
-    use casper_types::gens;
+    use casper_types::{bytesrepr::LengthEncoding, gens};
 
     use super::ProtocolData;
 
@@ -213,6 +259,7 @@ pub(crate) mod gens {
             handle_payment in gens::u8_slice_32(),
             standard_payment in gens::u8_slice_32(),
             auction in gens::u8_slice_32(),
+            length_encoding in prop_oneof![Just(LengthEncoding::U32), Just(LengthEncoding::Leb128)],
         ) -> ProtocolData {
             ProtocolData {
                 wasm_config,
@@ -221,6 +268,7 @@ pub(crate) mod gens {
                 handle_payment: handle_payment.into(),
                 standard_payment: standard_payment.into(),
                 auction: auction.into(),
+                length_encoding,
             }
         }
     }
@@ -231,9 +279,12 @@ mod tests {
     use proptest::proptest;
 
     use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
-    use casper_types::{bytesrepr, ContractHash};
+    use casper_types::{
+        bytesrepr::{self, FromBytes, LengthEncoding, ToBytes},
+        ContractHash,
+    };
 
-    use super::{gens, ProtocolData};
+    use super::{gens, ProtocolData, PROTOCOL_DATA_LAYOUT_VERSION};
 
     #[test]
     fn should_return_all_system_contracts() {
@@ -301,6 +352,45 @@ mod tests {
         assert_eq!(actual[2], auction_reference);
     }
 
+    fn legacy_serialized_length(protocol_data: &ProtocolData) -> usize {
+        protocol_data.wasm_config.legacy_fields_serialized_length()
+            + protocol_data
+                .system_config
+                .legacy_fields_serialized_length()
+            + 4 * protocol_data.mint.serialized_length()
+    }
+
+    #[test]
+    fn should_deserialize_protocol_data_written_before_layout_was_versioned() {
+        let protocol_data = ProtocolData::new(
+            WasmConfig::default(),
+            SystemConfig::default(),
+            [1u8; 32].into(),
+            [2u8; 32].into(),
+            [3u8; 32].into(),
+            [4u8; 32].into(),
+        );
+        let mut legacy_bytes = protocol_data.to_bytes().unwrap();
+        legacy_bytes.truncate(legacy_serialized_length(&protocol_data));
+
+        let (deserialized, remainder) = ProtocolData::from_bytes(&legacy_bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(deserialized, protocol_data);
+        assert_eq!(deserialized.length_encoding(), LengthEncoding::U32);
+    }
+
+    #[test]
+    fn should_not_deserialize_protocol_data_with_unknown_layout_version() {
+        let protocol_data = ProtocolData::default();
+        let mut bytes = protocol_data.to_bytes().unwrap();
+        bytes[legacy_serialized_length(&protocol_data)] = PROTOCOL_DATA_LAYOUT_VERSION + 1;
+
+        assert_eq!(
+            ProtocolData::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
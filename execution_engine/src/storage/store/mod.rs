@@ -14,6 +14,22 @@ pub trait Store<K, V> {
 
     fn handle(&self) -> Self::Handle;
 
+    /// Serializes a value for storage.
+    fn serialize_value(value: &V) -> Result<Vec<u8>, bytesrepr::Error>
+    where
+        V: ToBytes,
+    {
+        value.to_bytes()
+    }
+
+    /// Deserializes a stored value.
+    fn deserialize_value(value_bytes: Vec<u8>) -> Result<V, bytesrepr::Error>
+    where
+        V: FromBytes,
+    {
+        bytesrepr::deserialize(value_bytes)
+    }
+
     fn get<T>(&self, txn: &T, key: &K) -> Result<Option<V>, Self::Error>
     where
         T: Readable<Handle = Self::Handle>,
@@ -25,7 +41,7 @@ pub trait Store<K, V> {
         match txn.read(handle, &key.to_bytes()?)? {
             None => Ok(None),
             Some(value_bytes) => {
-                let value = bytesrepr::deserialize(value_bytes.into())?;
+                let value = Self::deserialize_value(value_bytes.into())?;
                 Ok(Some(value))
             }
         }
@@ -39,7 +55,7 @@ pub trait Store<K, V> {
         Self::Error: From<T::Error>,
     {
         let handle = self.handle();
-        txn.write(handle, &key.to_bytes()?, &value.to_bytes()?)
+        txn.write(handle, &key.to_bytes()?, &Self::serialize_value(value)?)
             .map_err(Into::into)
     }
 }
@@ -132,6 +132,18 @@ impl InMemoryEnvironment {
         Default::default()
     }
 
+    /// Creates an independent copy of this environment, containing a snapshot of its current data.
+    ///
+    /// Subsequent writes to either environment are not visible in the other.
+    pub fn fork(&self) -> Result<Self, Error> {
+        let data = {
+            let data_lock = self.data.lock()?;
+            Arc::new(Mutex::new(data_lock.to_owned()))
+        };
+        let write_mutex = Arc::new(Mutex::new(WriteCapability));
+        Ok(InMemoryEnvironment { data, write_mutex })
+    }
+
     pub fn data(&self, name: Option<&str>) -> Result<Option<BytesMap>, PoisonError> {
         let data = self.data.lock()?;
         let name = name.map(ToString::to_string);
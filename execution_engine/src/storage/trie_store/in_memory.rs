@@ -97,6 +97,8 @@
 //! }
 //! ```
 
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
 use super::{Blake2bHash, Store, Trie, TrieStore, NAME};
 use crate::storage::{error::in_memory::Error, transaction_source::in_memory::InMemoryEnvironment};
 
@@ -124,6 +126,20 @@ impl<K, V> Store<Blake2bHash, Trie<K, V>> for InMemoryTrieStore {
     fn handle(&self) -> Self::Handle {
         self.maybe_name.to_owned()
     }
+
+    fn serialize_value(trie: &Trie<K, V>) -> Result<Vec<u8>, bytesrepr::Error>
+    where
+        Trie<K, V>: ToBytes,
+    {
+        super::serialize_record(trie)
+    }
+
+    fn deserialize_value(record: Vec<u8>) -> Result<Trie<K, V>, bytesrepr::Error>
+    where
+        Trie<K, V>: FromBytes,
+    {
+        super::deserialize_record(record)
+    }
 }
 
 impl<K, V> TrieStore<K, V> for InMemoryTrieStore {}
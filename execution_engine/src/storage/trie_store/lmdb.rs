@@ -106,6 +106,8 @@
 
 use lmdb::{Database, DatabaseFlags};
 
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
 use crate::shared::newtypes::Blake2bHash;
 
 use crate::storage::{
@@ -156,6 +158,20 @@ impl<K, V> Store<Blake2bHash, Trie<K, V>> for LmdbTrieStore {
     fn handle(&self) -> Self::Handle {
         self.db
     }
+
+    fn serialize_value(trie: &Trie<K, V>) -> Result<Vec<u8>, bytesrepr::Error>
+    where
+        Trie<K, V>: ToBytes,
+    {
+        trie_store::serialize_record(trie)
+    }
+
+    fn deserialize_value(record: Vec<u8>) -> Result<Trie<K, V>, bytesrepr::Error>
+    where
+        Trie<K, V>: FromBytes,
+    {
+        trie_store::deserialize_record(record)
+    }
 }
 
 impl<K, V> TrieStore<K, V> for LmdbTrieStore {}
@@ -8,11 +8,66 @@ pub(crate) mod operations;
 #[cfg(test)]
 mod tests;
 
+use std::cell::Cell;
+
+use casper_types::bytesrepr::{self, FromBytes, LengthEncoding, ToBytes};
+
 use crate::shared::newtypes::Blake2bHash;
 
 use crate::storage::{store::Store, trie::Trie};
 
 const NAME: &str = "TRIE_STORE";
 
+/// Tag prefixing stored tries whose length prefixes are encoded using [`LengthEncoding::Leb128`].
+///
+/// Tries stored without it hold their canonical serialization, which always begins with a trie
+/// tag, so records written under either encoding can coexist in the same store.
+const COMPACT_RECORD_TAG: u8 = u8::max_value();
+
+thread_local! {
+    static RECORD_ENCODING: Cell<LengthEncoding> = Cell::new(LengthEncoding::U32);
+}
+
 /// An entity which persists [`Trie`] values at their hashes.
 pub trait TrieStore<K, V>: Store<Blake2bHash, Trie<K, V>> {}
+
+/// Calls `f` with tries written to any [`TrieStore`] on this thread having their length prefixes
+/// encoded using `encoding`.
+///
+/// Trie hashes are always computed over the canonical serialization, so the encoding affects only
+/// the size of the stored records and never the resulting state root hashes.  Tries can be read
+/// regardless of the encoding they were written with.
+pub fn with_record_encoding<R>(encoding: LengthEncoding, f: impl FnOnce() -> R) -> R {
+    let previous = RECORD_ENCODING.with(|current| current.replace(encoding));
+    let result = f();
+    RECORD_ENCODING.with(|current| current.set(previous));
+    result
+}
+
+fn serialize_record<T: ToBytes>(trie: &T) -> Result<Vec<u8>, bytesrepr::Error> {
+    match RECORD_ENCODING.with(Cell::get) {
+        LengthEncoding::U32 => trie.to_bytes(),
+        LengthEncoding::Leb128 => {
+            let mut record = vec![COMPACT_RECORD_TAG];
+            record.append(&mut bytesrepr::serialize_with_encoding(
+                trie,
+                LengthEncoding::Leb128,
+            )?);
+            Ok(record)
+        }
+    }
+}
+
+fn deserialize_record<T: FromBytes>(record: Vec<u8>) -> Result<T, bytesrepr::Error> {
+    match record.split_first() {
+        Some((&COMPACT_RECORD_TAG, compact_bytes)) => {
+            let (trie, remainder) =
+                bytesrepr::from_bytes_with_encoding(compact_bytes, LengthEncoding::Leb128)?;
+            if !remainder.is_empty() {
+                return Err(bytesrepr::Error::LeftOverBytes);
+            }
+            Ok(trie)
+        }
+        _ => bytesrepr::deserialize(record),
+    }
+}
@@ -3,7 +3,7 @@ mod tests;
 
 use std::{
     cmp,
-    collections::{HashSet, VecDeque},
+    collections::{BTreeMap, HashSet, VecDeque},
     convert::TryInto,
     mem,
 };
@@ -294,6 +294,162 @@ where
     Ok(missing_descendants)
 }
 
+/// A change to the value stored under a single key between two tries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrieDiff<K, V> {
+    /// The key is only present in the second trie.
+    Added { key: K, value: V },
+    /// The key is only present in the first trie.
+    Removed { key: K, value: V },
+    /// The key is present in both tries, but with different values.
+    Modified { key: K, old: V, new: V },
+}
+
+impl<K, V> TrieDiff<K, V> {
+    /// Returns the key which changed.
+    pub fn key(&self) -> &K {
+        match self {
+            TrieDiff::Added { key, .. }
+            | TrieDiff::Removed { key, .. }
+            | TrieDiff::Modified { key, .. } => key,
+        }
+    }
+}
+
+/// Returns the changes between the tries rooted at `left_root` and `right_root`, ordered by key.
+///
+/// Subtries with equal hashes at the same position in both tries are skipped without being read,
+/// so the cost is proportional to the size of the change rather than to the size of the tries.
+pub fn diff<K, V, T, S, E>(
+    _correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    left_root: &Blake2bHash,
+    right_root: &Blake2bHash,
+) -> Result<Vec<TrieDiff<K, V>>, E>
+where
+    K: ToBytes + FromBytes + Ord + std::fmt::Debug,
+    V: ToBytes + FromBytes + Eq + std::fmt::Debug,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let mut left_leaves = BTreeMap::new();
+    let mut right_leaves = BTreeMap::new();
+    let mut pairs_to_visit = vec![(Some(*left_root), Some(*right_root))];
+    while let Some((maybe_left, maybe_right)) = pairs_to_visit.pop() {
+        if maybe_left == maybe_right {
+            continue;
+        }
+        let left: Option<Trie<K, V>> = match maybe_left {
+            Some(hash) => store.get(txn, &hash)?,
+            None => None,
+        };
+        let right: Option<Trie<K, V>> = match maybe_right {
+            Some(hash) => store.get(txn, &hash)?,
+            None => None,
+        };
+        match (left, right) {
+            // Both sides branch at the same position; only descend into differing pointers.
+            (
+                Some(Trie::Node {
+                    pointer_block: left_pointers,
+                }),
+                Some(Trie::Node {
+                    pointer_block: right_pointers,
+                }),
+            ) => {
+                for index in 0..RADIX {
+                    let left_hash = left_pointers[index].as_ref().map(|pointer| *pointer.hash());
+                    let right_hash = right_pointers[index]
+                        .as_ref()
+                        .map(|pointer| *pointer.hash());
+                    pairs_to_visit.push((left_hash, right_hash));
+                }
+            }
+            (
+                Some(Trie::Extension {
+                    affix: left_affix,
+                    pointer: left_pointer,
+                }),
+                Some(Trie::Extension {
+                    affix: right_affix,
+                    pointer: right_pointer,
+                }),
+            ) if left_affix == right_affix => {
+                pairs_to_visit.push((
+                    Some(left_pointer.into_hash()),
+                    Some(right_pointer.into_hash()),
+                ));
+            }
+            // The shapes differ, so the leaves below each side are compared by key instead.
+            (left, right) => {
+                if let Some(trie) = left {
+                    collect_leaves(txn, store, trie, &mut left_leaves)?;
+                }
+                if let Some(trie) = right {
+                    collect_leaves(txn, store, trie, &mut right_leaves)?;
+                }
+            }
+        }
+    }
+
+    let mut diffs = Vec::new();
+    for (key, old) in left_leaves {
+        match right_leaves.remove(&key) {
+            Some(new) if new == old => (),
+            Some(new) => diffs.push(TrieDiff::Modified { key, old, new }),
+            None => diffs.push(TrieDiff::Removed { key, value: old }),
+        }
+    }
+    diffs.extend(
+        right_leaves
+            .into_iter()
+            .map(|(key, value)| TrieDiff::Added { key, value }),
+    );
+    diffs.sort_by(|left, right| left.key().cmp(right.key()));
+    Ok(diffs)
+}
+
+/// Adds all the leaves of the subtrie `trie` to `leaves`.
+fn collect_leaves<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    trie: Trie<K, V>,
+    leaves: &mut BTreeMap<K, V>,
+) -> Result<(), E>
+where
+    K: ToBytes + FromBytes + Ord,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let mut tries_to_visit = vec![trie];
+    while let Some(trie) = tries_to_visit.pop() {
+        match trie {
+            Trie::Leaf { key, value } => {
+                leaves.insert(key, value);
+            }
+            Trie::Node { pointer_block } => {
+                for (_, pointer) in pointer_block.to_indexed_pointers() {
+                    if let Some(trie) = store.get(txn, pointer.hash())? {
+                        tries_to_visit.push(trie);
+                    }
+                }
+            }
+            Trie::Extension { pointer, .. } => {
+                if let Some(trie) = store.get(txn, pointer.hash())? {
+                    tries_to_visit.push(trie);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 struct TrieScan<K, V> {
     tip: Trie<K, V>,
     parents: Parents<K, V>,
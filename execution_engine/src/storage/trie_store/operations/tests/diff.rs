@@ -0,0 +1,118 @@
+use crate::shared::newtypes::{Blake2bHash, CorrelationId};
+
+use crate::storage::{
+    error::in_memory,
+    transaction_source::{Transaction, TransactionSource},
+    trie::Trie,
+    trie_store::operations::{
+        self,
+        tests::{
+            write_leaves, InMemoryTestContext, TestKey, TestValue, EMPTY_HASHED_TEST_TRIES,
+            TEST_LEAVES, TEST_LEAVES_UPDATED, TEST_TRIE_GENERATORS,
+        },
+        TrieDiff, WriteResult,
+    },
+};
+
+fn diff(
+    context: &InMemoryTestContext,
+    left_root: &Blake2bHash,
+    right_root: &Blake2bHash,
+) -> Vec<TrieDiff<TestKey, TestValue>> {
+    let txn = context.environment.create_read_txn().unwrap();
+    let diffs = operations::diff::<TestKey, TestValue, _, _, in_memory::Error>(
+        CorrelationId::new(),
+        &txn,
+        &context.store,
+        left_root,
+        right_root,
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    diffs
+}
+
+fn sorted_leaves(leaves: &[Trie<TestKey, TestValue>]) -> Vec<(TestKey, TestValue)> {
+    let mut pairs = leaves
+        .iter()
+        .map(|leaf| match leaf {
+            Trie::Leaf { key, value } => (*key, *value),
+            _ => panic!("leaves should contain only leaves"),
+        })
+        .collect::<Vec<_>>();
+    pairs.sort_by_key(|(key, _)| *key);
+    pairs
+}
+
+#[test]
+fn in_memory_diff_of_equal_roots_should_be_empty() {
+    let context = InMemoryTestContext::new(EMPTY_HASHED_TEST_TRIES).unwrap();
+    for generator in TEST_TRIE_GENERATORS.iter() {
+        let (root_hash, tries) = generator().unwrap();
+        context.update(&tries).unwrap();
+        assert!(diff(&context, &root_hash, &root_hash).is_empty());
+    }
+}
+
+#[test]
+fn in_memory_diff_should_report_added_and_removed_leaves() {
+    let context = InMemoryTestContext::new(EMPTY_HASHED_TEST_TRIES).unwrap();
+    let mut states = Vec::new();
+    for generator in TEST_TRIE_GENERATORS.iter() {
+        let (root_hash, tries) = generator().unwrap();
+        context.update(&tries).unwrap();
+        states.push(root_hash);
+    }
+
+    for (older, older_root) in states.iter().enumerate() {
+        for (newer, newer_root) in states.iter().enumerate().skip(older + 1) {
+            let changed = sorted_leaves(&TEST_LEAVES[older..newer]);
+
+            let expected_added = changed
+                .iter()
+                .map(|(key, value)| TrieDiff::Added {
+                    key: *key,
+                    value: *value,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(diff(&context, older_root, newer_root), expected_added);
+
+            let expected_removed = changed
+                .iter()
+                .map(|(key, value)| TrieDiff::Removed {
+                    key: *key,
+                    value: *value,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(diff(&context, newer_root, older_root), expected_removed);
+        }
+    }
+}
+
+#[test]
+fn in_memory_diff_should_report_modified_leaves() {
+    const NUM_UPDATED: usize = 2;
+
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[TEST_LEAVES.len()]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    let write_results = write_leaves::<_, _, _, _, in_memory::Error>(
+        CorrelationId::new(),
+        &context.environment,
+        &context.store,
+        &root_hash,
+        &TEST_LEAVES_UPDATED[..NUM_UPDATED],
+    )
+    .unwrap();
+    let updated_root_hash = match write_results.last() {
+        Some(WriteResult::Written(hash)) => *hash,
+        other => panic!("expected written leaves but got {:?}", other),
+    };
+
+    let expected = sorted_leaves(&TEST_LEAVES[..NUM_UPDATED])
+        .into_iter()
+        .zip(sorted_leaves(&TEST_LEAVES_UPDATED[..NUM_UPDATED]))
+        .map(|((key, old), (_, new))| TrieDiff::Modified { key, old, new })
+        .collect::<Vec<_>>();
+    assert_eq!(diff(&context, &root_hash, &updated_root_hash), expected);
+}
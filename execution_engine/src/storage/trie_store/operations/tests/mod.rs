@@ -1,3 +1,4 @@
+mod diff;
 mod keys;
 mod proptests;
 mod read;
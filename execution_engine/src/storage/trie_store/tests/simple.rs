@@ -1,17 +1,18 @@
 use lmdb::DatabaseFlags;
 use tempfile::tempdir;
 
-use casper_types::bytesrepr::{self, Bytes, FromBytes, ToBytes};
+use casper_types::bytesrepr::{self, Bytes, FromBytes, LengthEncoding, ToBytes};
 
 use super::TestData;
 use crate::storage::{
     error::{self, in_memory},
     store::StoreExt,
     transaction_source::{
-        in_memory::InMemoryEnvironment, lmdb::LmdbEnvironment, Transaction, TransactionSource,
+        in_memory::InMemoryEnvironment, lmdb::LmdbEnvironment, Readable, Transaction,
+        TransactionSource,
     },
     trie::Trie,
-    trie_store::{in_memory::InMemoryTrieStore, lmdb::LmdbTrieStore, TrieStore},
+    trie_store::{self, in_memory::InMemoryTrieStore, lmdb::LmdbTrieStore, TrieStore},
     DEFAULT_TEST_MAX_DB_SIZE, DEFAULT_TEST_MAX_READERS,
 };
 
@@ -600,3 +601,64 @@ fn lmdb_uncommitted_transactions_across_sub_databases_do_not_persist() {
         .is_ok()
     )
 }
+
+fn compact_and_fixed_records_coexist<'a, S, X, E>(store: &S, env: &'a X) -> Result<(), E>
+where
+    S: TrieStore<Bytes, Bytes>,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S::Error: From<X::Error>,
+    E: From<S::Error> + From<X::Error> + From<bytesrepr::Error>,
+{
+    let data = super::create_data();
+    let (fixed, compact) = data.split_at(3);
+
+    let mut txn = env.create_read_write_txn()?;
+    store.put_many(&mut txn, fixed.iter().map(Into::into))?;
+    trie_store::with_record_encoding(LengthEncoding::Leb128, || {
+        store.put_many(&mut txn, compact.iter().map(Into::into))
+    })?;
+    txn.commit()?;
+
+    let txn = env.create_read_txn()?;
+    for TestData(hash, trie) in &data {
+        assert_eq!(store.get(&txn, hash)?, Some(trie.to_owned()));
+    }
+    for TestData(hash, trie) in compact {
+        let record = txn
+            .read(store.handle(), &hash.to_bytes()?)?
+            .expect("should have record");
+        let mut expected_record = vec![u8::max_value()];
+        expected_record.append(&mut bytesrepr::serialize_with_encoding(
+            trie,
+            LengthEncoding::Leb128,
+        )?);
+        assert_eq!(record, Bytes::from(expected_record));
+    }
+    txn.commit()?;
+
+    Ok(())
+}
+
+#[test]
+fn in_memory_compact_and_fixed_records_coexist() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+
+    assert!(compact_and_fixed_records_coexist::<_, _, in_memory::Error>(&store, &env).is_ok());
+}
+
+#[test]
+fn lmdb_compact_and_fixed_records_coexist() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(
+        &tmp_dir.path().to_path_buf(),
+        DEFAULT_TEST_MAX_DB_SIZE,
+        DEFAULT_TEST_MAX_READERS,
+    )
+    .unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+
+    assert!(compact_and_fixed_records_coexist::<_, _, error::Error>(&store, &env).is_ok());
+
+    tmp_dir.close().unwrap();
+}
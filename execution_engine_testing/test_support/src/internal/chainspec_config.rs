@@ -0,0 +1,169 @@
+//! Parsing of the node's chainspec files into a genesis request.
+//!
+//! Only the parts of the chainspec which affect genesis are read; the remaining sections, such as
+//! `[highway]` and `[deploys]`, are ignored.
+
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use num_rational::Ratio;
+use serde::Deserialize;
+
+use casper_execution_engine::{
+    core::engine_state::{
+        genesis::{ExecConfig, GenesisAccount, GenesisValidator},
+        run_genesis_request::RunGenesisRequest,
+    },
+    shared::{
+        motes::Motes, newtypes::Blake2bHash, system_config::SystemConfig, wasm_config::WasmConfig,
+    },
+};
+use casper_types::{system::auction::DelegationRate, ProtocolVersion, PublicKey};
+
+/// The accounts file, which must live in the same directory as the chainspec.
+const ACCOUNTS_FILENAME: &str = "accounts.toml";
+
+#[derive(Deserialize)]
+struct ChainspecConfig {
+    protocol: ProtocolConfig,
+    core: CoreConfig,
+    wasm: WasmConfig,
+    system_costs: SystemConfig,
+}
+
+#[derive(Deserialize)]
+struct ProtocolConfig {
+    version: ProtocolVersion,
+    activation_point: ActivationPoint,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ActivationPoint {
+    EraId(u64),
+    Genesis(String),
+}
+
+#[derive(Deserialize)]
+struct CoreConfig {
+    validator_slots: u32,
+    auction_delay: u64,
+    unbonding_delay: u64,
+    max_delegation_rate: DelegationRate,
+    max_delegation_rate_change: DelegationRate,
+    max_delegators_per_validator: u32,
+    round_seigniorage_rate: (u64, u64),
+}
+
+#[derive(Deserialize)]
+struct AccountsConfig {
+    #[serde(default)]
+    accounts: Vec<AccountConfig>,
+    #[serde(default)]
+    delegators: Vec<DelegatorConfig>,
+}
+
+#[derive(Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+struct AccountConfig {
+    public_key: PublicKey,
+    balance: Motes,
+    validator: Option<ValidatorConfig>,
+}
+
+#[derive(Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+struct ValidatorConfig {
+    bonded_amount: Motes,
+    #[serde(default)]
+    delegation_rate: DelegationRate,
+}
+
+#[derive(Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+struct DelegatorConfig {
+    validator_public_key: PublicKey,
+    delegator_public_key: PublicKey,
+    balance: Motes,
+    delegated_amount: Motes,
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+fn parse_toml<T: for<'de> Deserialize<'de>>(path: &Path, bytes: &[u8]) -> Result<T, String> {
+    toml::from_slice(bytes).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Returns the genesis accounts listed in the accounts file in `root`, in the same order the node
+/// uses, or no accounts if there is no such file.
+fn read_genesis_accounts(root: &Path) -> Result<Vec<GenesisAccount>, String> {
+    let accounts_path = root.join(ACCOUNTS_FILENAME);
+    if !accounts_path.is_file() {
+        return Ok(vec![]);
+    }
+    let mut accounts_config: AccountsConfig =
+        parse_toml(&accounts_path, &read_file(&accounts_path)?)?;
+    accounts_config.accounts.sort_unstable();
+    accounts_config.delegators.sort_unstable();
+
+    let accounts = accounts_config.accounts.into_iter().map(|account| {
+        let validator = account.validator.map(|validator| {
+            GenesisValidator::new(validator.bonded_amount, validator.delegation_rate)
+        });
+        GenesisAccount::account(account.public_key, account.balance, validator)
+    });
+    let delegators = accounts_config.delegators.into_iter().map(|delegator| {
+        GenesisAccount::delegator(
+            delegator.validator_public_key,
+            delegator.delegator_public_key,
+            delegator.balance,
+            delegator.delegated_amount,
+        )
+    });
+    Ok(accounts.chain(delegators).collect())
+}
+
+/// Parses the chainspec at `chainspec_path`, along with the accounts file beside it, into a
+/// genesis request equivalent to the one the node would run.
+///
+/// The genesis config hash is the hash of the chainspec file.
+pub(crate) fn parse_run_genesis_request(
+    chainspec_path: &Path,
+) -> Result<RunGenesisRequest, String> {
+    let chainspec_bytes = read_file(chainspec_path)?;
+    let chainspec: ChainspecConfig = parse_toml(chainspec_path, &chainspec_bytes)?;
+    let root = chainspec_path.parent().unwrap_or_else(|| Path::new(""));
+    let accounts = read_genesis_accounts(root)?;
+
+    // As in the node, a protocol version activated at an era ID rather than a timestamp has a
+    // genesis timestamp of zero.
+    let genesis_timestamp = match &chainspec.protocol.activation_point {
+        ActivationPoint::EraId(_) => Duration::default(),
+        ActivationPoint::Genesis(timestamp) => humantime::parse_rfc3339_weak(timestamp)
+            .map_err(|error| format!("invalid activation_point: {}", error))?
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| format!("invalid activation_point: {}", error))?,
+    };
+    let (numerator, denominator) = chainspec.core.round_seigniorage_rate;
+
+    let exec_config = ExecConfig::new(
+        accounts,
+        chainspec.wasm,
+        chainspec.system_costs,
+        chainspec.core.validator_slots,
+        chainspec.core.auction_delay,
+        Ratio::new(numerator, denominator),
+        chainspec.core.unbonding_delay,
+        chainspec.core.max_delegation_rate,
+        chainspec.core.max_delegation_rate_change,
+        chainspec.core.max_delegators_per_validator,
+        genesis_timestamp.as_millis() as u64,
+    );
+    Ok(RunGenesisRequest::new(
+        Blake2bHash::new(&chainspec_bytes),
+        chainspec.protocol.version,
+        exec_config,
+    ))
+}
@@ -2,7 +2,7 @@ use std::{collections::BTreeSet, path::Path};
 
 use casper_execution_engine::{
     core::engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
-    shared::newtypes::Blake2bHash,
+    shared::{gas::Gas, newtypes::Blake2bHash},
 };
 use casper_types::{
     account::AccountHash, ContractHash, ContractVersion, DeployHash, HashAddr, RuntimeArgs,
@@ -18,6 +18,7 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    pub max_gas: Option<Gas>,
 }
 
 pub struct DeployItemBuilder {
@@ -199,6 +200,11 @@ impl DeployItemBuilder {
         self
     }
 
+    pub fn with_max_gas(mut self, max_gas: Gas) -> Self {
+        self.deploy_item.max_gas = Some(max_gas);
+        self
+    }
+
     pub fn with_deploy_hash(mut self, hash: [u8; 32]) -> Self {
         let digest: Blake2bHash = hash.into();
         self.deploy_item.deploy_hash = DeployHash::new(digest.value());
@@ -222,6 +228,7 @@ impl DeployItemBuilder {
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
+            max_gas: self.deploy_item.max_gas,
         }
     }
 }
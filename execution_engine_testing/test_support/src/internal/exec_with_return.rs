@@ -81,7 +81,7 @@ where
     let access_rights = {
         let mut ret = runtime::extract_access_rights_from_keys(named_keys.values().cloned());
         let extras = runtime::extract_access_rights_from_urefs(extra_urefs.into_iter());
-        ret.extend(extras.into_iter());
+        ret.extend(extras);
         ret
     };
 
@@ -123,6 +123,8 @@ where
         phase,
         protocol_data,
         transfers,
+        Default::default(),
+        Default::default(),
     );
 
     let wasm_bytes = utils::read_wasm_file_bytes(wasm_file);
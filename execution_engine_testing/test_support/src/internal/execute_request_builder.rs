@@ -1,12 +1,12 @@
-use std::convert::TryInto;
-
 use rand::Rng;
 
-use casper_execution_engine::core::engine_state::{
-    deploy_item::DeployItem, execute_request::ExecuteRequest,
+use casper_execution_engine::{
+    core::engine_state::{deploy_item::DeployItem, execute_request::ExecuteRequest},
+    shared::newtypes::Blake2bHash,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, ContractVersion, ProtocolVersion, RuntimeArgs,
+    account::AccountHash, runtime_args, ContractHash, ContractVersion, DeployHash, ProtocolVersion,
+    RuntimeArgs,
 };
 
 use crate::internal::{
@@ -15,9 +15,22 @@ use crate::internal::{
 
 const ARG_AMOUNT: &str = "amount";
 
+/// Default seed used to derive deploy hashes when [`ExecuteRequestBuilder::with_auto_deploy_hash`]
+/// is used without an explicit seed.
+pub const DEFAULT_DEPLOY_HASH_SEED: u64 = 0;
+
+/// Returns the deploy hash derived from `seed` and `sequence`.
+fn derive_deploy_hash(seed: u64, sequence: u64) -> DeployHash {
+    let mut data = seed.to_le_bytes().to_vec();
+    data.extend_from_slice(&sequence.to_le_bytes());
+    DeployHash::new(Blake2bHash::new(&data).value())
+}
+
 #[derive(Debug)]
 pub struct ExecuteRequestBuilder {
     execute_request: ExecuteRequest,
+    deploy_hash_seed: Option<u64>,
+    deploy_hash_sequence: u64,
 }
 
 impl ExecuteRequestBuilder {
@@ -34,8 +47,11 @@ impl ExecuteRequestBuilder {
         self
     }
 
-    pub fn with_pre_state_hash(mut self, pre_state_hash: &[u8]) -> Self {
-        self.execute_request.parent_state_hash = pre_state_hash.try_into().unwrap();
+    /// Executes the request against the given state root rather than the latest post-state hash
+    /// tracked by the [`WasmTestBuilder`](crate::internal::WasmTestBuilder), e.g. to replay
+    /// deploys on top of a historical state.
+    pub fn with_pre_state_hash(mut self, pre_state_hash: Blake2bHash) -> Self {
+        self.execute_request.parent_state_hash = pre_state_hash;
         self
     }
 
@@ -54,7 +70,29 @@ impl ExecuteRequestBuilder {
         self
     }
 
-    pub fn build(self) -> ExecuteRequest {
+    /// Replaces the deploy hash of every deploy in the request at build time with a unique hash
+    /// derived from [`DEFAULT_DEPLOY_HASH_SEED`] and the builder's sequence counter.
+    pub fn with_auto_deploy_hash(self) -> Self {
+        self.with_deploy_hash_seed(DEFAULT_DEPLOY_HASH_SEED)
+    }
+
+    /// Like [`ExecuteRequestBuilder::with_auto_deploy_hash`], but derives the deploy hashes from
+    /// the given `seed`.
+    ///
+    /// The sequence counter restarts for every builder, so requests built by separate builders
+    /// within a test should use distinct seeds.
+    pub fn with_deploy_hash_seed(mut self, seed: u64) -> Self {
+        self.deploy_hash_seed = Some(seed);
+        self
+    }
+
+    pub fn build(mut self) -> ExecuteRequest {
+        if let Some(seed) = self.deploy_hash_seed {
+            for deploy in self.execute_request.deploys.iter_mut().flatten() {
+                deploy.deploy_hash = derive_deploy_hash(seed, self.deploy_hash_sequence);
+                self.deploy_hash_sequence += 1;
+            }
+        }
         self.execute_request
     }
 
@@ -145,6 +183,65 @@ impl Default for ExecuteRequestBuilder {
             proposer: *DEFAULT_PROPOSER_PUBLIC_KEY,
             ..Default::default()
         };
-        ExecuteRequestBuilder { execute_request }
+        ExecuteRequestBuilder {
+            execute_request,
+            deploy_hash_seed: None,
+            deploy_hash_sequence: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn deploy_item() -> DeployItem {
+        DeployItemBuilder::new()
+            .with_empty_payment_bytes(RuntimeArgs::new())
+            .with_session_bytes(vec![], RuntimeArgs::new())
+            .build()
+    }
+
+    fn deploy_hashes(execute_request: &ExecuteRequest) -> Vec<DeployHash> {
+        execute_request
+            .deploys
+            .iter()
+            .flatten()
+            .map(|deploy| deploy.deploy_hash)
+            .collect()
+    }
+
+    #[test]
+    fn auto_deploy_hashes_should_be_unique() {
+        let first = ExecuteRequestBuilder::new()
+            .push_deploy(deploy_item())
+            .push_deploy(deploy_item())
+            .with_auto_deploy_hash()
+            .build();
+        let second = ExecuteRequestBuilder::new()
+            .push_deploy(deploy_item())
+            .with_deploy_hash_seed(DEFAULT_DEPLOY_HASH_SEED + 1)
+            .build();
+
+        let mut hashes = deploy_hashes(&first);
+        hashes.extend(deploy_hashes(&second));
+        let unique: BTreeSet<_> = hashes.iter().collect();
+        assert_eq!(unique.len(), 3);
+    }
+
+    #[test]
+    fn auto_deploy_hashes_should_be_deterministic_per_seed() {
+        let build = |seed| {
+            ExecuteRequestBuilder::new()
+                .push_deploy(deploy_item())
+                .push_deploy(deploy_item())
+                .with_deploy_hash_seed(seed)
+                .build()
+        };
+
+        assert_eq!(deploy_hashes(&build(1)), deploy_hashes(&build(1)));
+        assert_ne!(deploy_hashes(&build(1)), deploy_hashes(&build(2)));
     }
 }
@@ -0,0 +1,131 @@
+//! Tracking of gas costs against a baseline file, to catch gas regressions.
+//!
+//! Baseline checks are disabled unless the `CASPER_GAS_BASELINE` environment variable is set to
+//! the path of a JSON file mapping test-defined names to gas costs.  When enabled, every checked
+//! cost must be within `CASPER_GAS_BASELINE_TOLERANCE` percent (default
+//! [`DEFAULT_GAS_BASELINE_TOLERANCE_PERCENT`]) of its recorded value.
+//!
+//! If `CASPER_GAS_BASELINE_UPDATE` is also set, measured costs are written to the baseline file
+//! rather than being checked against it.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use once_cell::sync::Lazy;
+
+use casper_execution_engine::shared::gas::Gas;
+use casper_types::U512;
+
+/// Environment variable holding the path to the gas baseline file.
+pub const GAS_BASELINE_PATH_ENV_VAR: &str = "CASPER_GAS_BASELINE";
+/// Environment variable which, if set, causes the gas baseline file to be updated.
+pub const GAS_BASELINE_UPDATE_ENV_VAR: &str = "CASPER_GAS_BASELINE_UPDATE";
+/// Environment variable holding the allowed deviation from the baseline in percent.
+pub const GAS_BASELINE_TOLERANCE_ENV_VAR: &str = "CASPER_GAS_BASELINE_TOLERANCE";
+/// The allowed deviation from the baseline in percent if not set via environment variable.
+pub const DEFAULT_GAS_BASELINE_TOLERANCE_PERCENT: u64 = 1;
+
+/// Serializes access to the baseline file between tests running in parallel.
+static BASELINE_FILE_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Returns `true` if `actual` differs from `expected` by no more than `tolerance`.
+pub fn is_within_tolerance(actual: Gas, expected: Gas, tolerance: Gas) -> bool {
+    let difference = if actual > expected {
+        actual - expected
+    } else {
+        expected - actual
+    };
+    difference <= tolerance
+}
+
+/// Checks `actual` against the baseline entry `name`, or records it if updating the baseline.
+///
+/// Does nothing if baseline checks are disabled.
+pub(crate) fn check(name: &str, actual: Gas) {
+    let path = match env::var_os(GAS_BASELINE_PATH_ENV_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => return,
+    };
+
+    let _guard = BASELINE_FILE_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let mut baseline = read_baseline(&path);
+
+    if env::var_os(GAS_BASELINE_UPDATE_ENV_VAR).is_some() {
+        baseline.insert(name.to_string(), actual.value().as_u64());
+        write_baseline(&path, &baseline);
+        return;
+    }
+
+    let expected = match baseline.get(name) {
+        Some(expected) => Gas::from(*expected),
+        None => panic!(
+            "no gas baseline for '{}' in {}; set {} to record it",
+            name,
+            path.display(),
+            GAS_BASELINE_UPDATE_ENV_VAR
+        ),
+    };
+    let tolerance_percent = tolerance_percent();
+    let tolerance = Gas::new(expected.value() * U512::from(tolerance_percent) / U512::from(100));
+
+    if !is_within_tolerance(actual, expected, tolerance) {
+        panic!(
+            "gas cost of '{}' is {}, which deviates from the baseline of {} by more than {}%",
+            name, actual, expected, tolerance_percent
+        );
+    }
+}
+
+fn tolerance_percent() -> u64 {
+    match env::var(GAS_BASELINE_TOLERANCE_ENV_VAR) {
+        Ok(value) => value.parse().unwrap_or_else(|error| {
+            panic!(
+                "invalid value of {} '{}': {}",
+                GAS_BASELINE_TOLERANCE_ENV_VAR, value, error
+            )
+        }),
+        Err(_) => DEFAULT_GAS_BASELINE_TOLERANCE_PERCENT,
+    }
+}
+
+fn read_baseline(path: &Path) -> BTreeMap<String, u64> {
+    if !path.exists() {
+        return BTreeMap::new();
+    }
+    let contents = fs::read(path)
+        .unwrap_or_else(|error| panic!("failed to read {}: {}", path.display(), error));
+    serde_json::from_slice(&contents)
+        .unwrap_or_else(|error| panic!("failed to parse {}: {}", path.display(), error))
+}
+
+fn write_baseline(path: &Path, baseline: &BTreeMap<String, u64>) {
+    let contents = serde_json::to_vec_pretty(baseline).expect("should serialize gas baseline");
+    fs::write(path, contents)
+        .unwrap_or_else(|error| panic!("failed to write {}: {}", path.display(), error));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_check_tolerance_in_both_directions() {
+        let expected = Gas::from(1000u64);
+        let tolerance = Gas::from(10u64);
+        assert!(is_within_tolerance(Gas::from(1000u64), expected, tolerance));
+        assert!(is_within_tolerance(Gas::from(990u64), expected, tolerance));
+        assert!(is_within_tolerance(Gas::from(1010u64), expected, tolerance));
+        assert!(!is_within_tolerance(Gas::from(989u64), expected, tolerance));
+        assert!(!is_within_tolerance(
+            Gas::from(1011u64),
+            expected,
+            tolerance
+        ));
+    }
+}
@@ -1,9 +1,12 @@
 mod additive_map_diff;
+mod chainspec_config;
 mod deploy_item_builder;
 pub mod exec_with_return;
 mod execute_request_builder;
+pub mod gas_baseline;
 mod step_request_builder;
 mod upgrade_request_builder;
+mod upgrade_scenario;
 pub mod utils;
 mod wasm_test_builder;
 
@@ -19,7 +22,11 @@ use casper_execution_engine::{
         motes::Motes, newtypes::Blake2bHash, system_config::SystemConfig, wasm_config::WasmConfig,
     },
 };
-use casper_types::{account::AccountHash, ProtocolVersion, PublicKey, SecretKey, U512};
+use casper_types::{
+    account::AccountHash,
+    system::auction::{DelegationRate, DELEGATION_RATE_DENOMINATOR},
+    ProtocolVersion, PublicKey, SecretKey, U512,
+};
 
 use super::DEFAULT_ACCOUNT_INITIAL_BALANCE;
 
@@ -28,16 +35,21 @@ pub use deploy_item_builder::DeployItemBuilder;
 pub use execute_request_builder::ExecuteRequestBuilder;
 pub use step_request_builder::StepRequestBuilder;
 pub use upgrade_request_builder::UpgradeRequestBuilder;
+pub use upgrade_scenario::{UpgradeScenario, UpgradeScenarioResult, UpgradeStep};
 pub use wasm_test_builder::{
     InMemoryWasmTestBuilder, LmdbWasmTestBuilder, WasmTestBuilder, WasmTestResult,
 };
 
 pub const DEFAULT_VALIDATOR_SLOTS: u32 = 5;
 pub const DEFAULT_AUCTION_DELAY: u64 = 3;
-/// Default lock-in period of 90 days
-pub const DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS: u64 = 90 * 24 * 60 * 60 * 1000;
 /// Default number of eras that need to pass to be able to withdraw unbonded funds.
 pub const DEFAULT_UNBONDING_DELAY: u64 = 14;
+/// Default upper bound of a validator's delegation rate.
+pub const DEFAULT_MAX_DELEGATION_RATE: DelegationRate = DELEGATION_RATE_DENOMINATOR;
+/// Default maximum change of a validator's delegation rate per era.
+pub const DEFAULT_MAX_DELEGATION_RATE_CHANGE: DelegationRate = DELEGATION_RATE_DENOMINATOR;
+/// Default maximum number of delegators per validator.
+pub const DEFAULT_MAX_DELEGATORS_PER_VALIDATOR: u32 = u32::MAX;
 
 /// Default round seigniorage rate represented as a fractional number.
 ///
@@ -99,9 +111,11 @@ pub static DEFAULT_EXEC_CONFIG: Lazy<ExecConfig> = Lazy::new(|| {
         *DEFAULT_SYSTEM_CONFIG,
         DEFAULT_VALIDATOR_SLOTS,
         DEFAULT_AUCTION_DELAY,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_MAX_DELEGATION_RATE,
+        DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     )
 });
@@ -9,7 +9,7 @@ use casper_execution_engine::{
         wasm_config::WasmConfig,
     },
 };
-use casper_types::{Key, ProtocolVersion};
+use casper_types::{bytesrepr::LengthEncoding, Key, ProtocolVersion};
 
 #[derive(Default)]
 pub struct UpgradeRequestBuilder {
@@ -21,10 +21,10 @@ pub struct UpgradeRequestBuilder {
     activation_point: Option<ActivationPoint>,
     new_validator_slots: Option<u32>,
     new_auction_delay: Option<u64>,
-    new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
+    new_length_encoding: Option<LengthEncoding>,
 }
 
 impl UpgradeRequestBuilder {
@@ -61,14 +61,6 @@ impl UpgradeRequestBuilder {
         self
     }
 
-    pub fn with_new_locked_funds_period_millis(
-        mut self,
-        new_locked_funds_period_millis: u64,
-    ) -> Self {
-        self.new_locked_funds_period_millis = Some(new_locked_funds_period_millis);
-        self
-    }
-
     pub fn with_new_round_seigniorage_rate(mut self, rate: Ratio<u64>) -> Self {
         self.new_round_seigniorage_rate = Some(rate);
         self
@@ -97,8 +89,13 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_length_encoding(mut self, new_length_encoding: LengthEncoding) -> Self {
+        self.new_length_encoding = Some(new_length_encoding);
+        self
+    }
+
     pub fn build(self) -> UpgradeConfig {
-        UpgradeConfig::new(
+        let mut upgrade_config = UpgradeConfig::new(
             self.pre_state_hash,
             self.current_protocol_version,
             self.new_protocol_version,
@@ -107,10 +104,13 @@ impl UpgradeRequestBuilder {
             self.activation_point,
             self.new_validator_slots,
             self.new_auction_delay,
-            self.new_locked_funds_period_millis,
             self.new_round_seigniorage_rate,
             self.new_unbonding_delay,
             self.global_state_update,
-        )
+        );
+        if let Some(new_length_encoding) = self.new_length_encoding {
+            upgrade_config.with_new_length_encoding(new_length_encoding);
+        }
+        upgrade_config
     }
 }
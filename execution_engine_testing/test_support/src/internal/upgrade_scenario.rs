@@ -0,0 +1,145 @@
+use casper_execution_engine::{
+    core::{
+        engine_state::{self, upgrade::ActivationPoint},
+        execution,
+    },
+    shared::newtypes::Blake2bHash,
+    storage::global_state::StateProvider,
+};
+use casper_types::{ProtocolVersion, RuntimeArgs};
+
+use crate::internal::{
+    ExecuteRequestBuilder, UpgradeRequestBuilder, WasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+
+/// A single protocol upgrade applied as part of an [`UpgradeScenario`].
+pub struct UpgradeStep {
+    new_protocol_version: ProtocolVersion,
+    activation_point: Option<ActivationPoint>,
+    upgrade_request: UpgradeRequestBuilder,
+    installer: Option<(String, RuntimeArgs)>,
+}
+
+impl UpgradeStep {
+    /// Creates a step upgrading to `new_protocol_version` without any config changes.
+    pub fn new(new_protocol_version: ProtocolVersion) -> Self {
+        UpgradeStep {
+            new_protocol_version,
+            activation_point: None,
+            upgrade_request: UpgradeRequestBuilder::new(),
+            installer: None,
+        }
+    }
+
+    /// Sets the activation point of the upgrade.  Defaults to the 1-based index of the step.
+    pub fn with_activation_point(mut self, activation_point: ActivationPoint) -> Self {
+        self.activation_point = Some(activation_point);
+        self
+    }
+
+    /// Sets the config changes applied by the upgrade.
+    ///
+    /// The pre-state hash, protocol versions and activation point of `upgrade_request` are
+    /// overwritten by the scenario.
+    pub fn with_upgrade_request(mut self, upgrade_request: UpgradeRequestBuilder) -> Self {
+        self.upgrade_request = upgrade_request;
+        self
+    }
+
+    /// Sets session code to be executed by the default account right after the upgrade, using the
+    /// new protocol version.
+    pub fn with_installer(mut self, session_file: &str, session_args: RuntimeArgs) -> Self {
+        self.installer = Some((session_file.to_string(), session_args));
+        self
+    }
+}
+
+/// An ordered list of protocol upgrades applied one after another.
+pub struct UpgradeScenario {
+    current_protocol_version: ProtocolVersion,
+    steps: Vec<UpgradeStep>,
+}
+
+impl UpgradeScenario {
+    /// Creates an empty scenario starting at `current_protocol_version`.
+    pub fn new(current_protocol_version: ProtocolVersion) -> Self {
+        UpgradeScenario {
+            current_protocol_version,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends a step to the scenario.
+    pub fn with_step(mut self, step: UpgradeStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Applies all steps in order, panicking if any upgrade or installer execution fails.
+    pub fn run<S>(self, builder: &mut WasmTestBuilder<S>) -> UpgradeScenarioResult
+    where
+        S: StateProvider,
+        engine_state::Error: From<S::Error>,
+        S::Error: Into<execution::Error>,
+    {
+        let mut current_protocol_version = self.current_protocol_version;
+        let mut post_state_hashes = Vec::with_capacity(self.steps.len());
+
+        for (index, step) in self.steps.into_iter().enumerate() {
+            let activation_point = step.activation_point.unwrap_or(index as u64 + 1);
+            let mut upgrade_request = step
+                .upgrade_request
+                .with_current_protocol_version(current_protocol_version)
+                .with_new_protocol_version(step.new_protocol_version)
+                .with_activation_point(activation_point)
+                .build();
+
+            builder
+                .upgrade_with_upgrade_request(&mut upgrade_request)
+                .expect_upgrade_success();
+
+            if let Some((session_file, session_args)) = step.installer {
+                let exec_request = ExecuteRequestBuilder::standard(
+                    *DEFAULT_ACCOUNT_ADDR,
+                    &session_file,
+                    session_args,
+                )
+                .with_protocol_version(step.new_protocol_version)
+                .build();
+                builder.exec(exec_request).expect_success().commit();
+            }
+
+            current_protocol_version = step.new_protocol_version;
+            post_state_hashes.push(builder.get_post_state_hash());
+        }
+
+        UpgradeScenarioResult {
+            protocol_version: current_protocol_version,
+            post_state_hashes,
+        }
+    }
+}
+
+/// The outcome of running an [`UpgradeScenario`].
+#[derive(Debug)]
+pub struct UpgradeScenarioResult {
+    protocol_version: ProtocolVersion,
+    post_state_hashes: Vec<Blake2bHash>,
+}
+
+impl UpgradeScenarioResult {
+    /// Returns the protocol version after the last step.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the post-state hash after each step, in step order.
+    pub fn post_state_hashes(&self) -> &[Blake2bHash] {
+        &self.post_state_hashes
+    }
+
+    /// Returns the post-state hash after the step at `index`.
+    pub fn post_state_hash(&self, index: usize) -> Blake2bHash {
+        self.post_state_hashes[index]
+    }
+}
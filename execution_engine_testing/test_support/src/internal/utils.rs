@@ -20,10 +20,14 @@ use casper_execution_engine::{
 };
 use casper_types::Key;
 
-use super::{DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY};
+use super::{
+    DEFAULT_MAX_DELEGATION_RATE, DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+    DEFAULT_MAX_DELEGATORS_PER_VALIDATOR, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
+    DEFAULT_UNBONDING_DELAY,
+};
 use crate::internal::{
-    DEFAULT_AUCTION_DELAY, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_CONFIG_HASH,
-    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION,
+    chainspec_config, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_CHAIN_NAME,
+    DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_PROTOCOL_VERSION,
     DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
 };
 
@@ -137,9 +141,11 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
     let system_config = *DEFAULT_SYSTEM_CONFIG;
     let validator_slots = DEFAULT_VALIDATOR_SLOTS;
     let auction_delay = DEFAULT_AUCTION_DELAY;
-    let locked_funds_period_millis = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let max_delegation_rate = DEFAULT_MAX_DELEGATION_RATE;
+    let max_delegation_rate_change = DEFAULT_MAX_DELEGATION_RATE_CHANGE;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
     let genesis_timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     ExecConfig::new(
         accounts,
@@ -147,9 +153,11 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
         system_config,
         validator_slots,
         auction_delay,
-        locked_funds_period_millis,
         round_seigniorage_rate,
         unbonding_delay,
+        max_delegation_rate,
+        max_delegation_rate_change,
+        max_delegators_per_validator,
         genesis_timestamp_millis,
     )
 }
@@ -172,6 +180,28 @@ pub fn create_run_genesis_request(accounts: Vec<GenesisAccount>) -> RunGenesisRe
     )
 }
 
+/// Creates a genesis request from the node chainspec at `chainspec_path` and the `accounts.toml`
+/// file in the same directory.
+///
+/// Panics if either file can't be read or parsed.
+pub fn create_run_genesis_request_from_chainspec<T: AsRef<Path>>(
+    chainspec_path: T,
+) -> RunGenesisRequest {
+    chainspec_config::parse_run_genesis_request(chainspec_path.as_ref())
+        .unwrap_or_else(|error| panic!("failed to parse chainspec: {}", error))
+}
+
+/// Runs genesis as configured by the node chainspec at `chainspec_path` and the `accounts.toml`
+/// file in the same directory.
+///
+/// Panics if either file can't be read or parsed, or if genesis fails.
+pub fn run_genesis_from_chainspec<T: AsRef<Path>>(chainspec_path: T) -> InMemoryWasmTestBuilder {
+    let run_genesis_request = create_run_genesis_request_from_chainspec(chainspec_path);
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
 pub fn get_exec_costs<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
     exec_response: I,
 ) -> Vec<Gas> {
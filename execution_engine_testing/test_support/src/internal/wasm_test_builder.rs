@@ -22,8 +22,10 @@ use casper_execution_engine::{
             execution_result::ExecutionResult,
             run_genesis_request::RunGenesisRequest,
             step::{StepRequest, StepResult},
-            BalanceResult, EngineConfig, EngineState, GenesisResult, GetBidsRequest, QueryRequest,
-            QueryResult, UpgradeConfig, UpgradeResult,
+            BalanceResult, ContractPackageSummary, EngineConfig, EngineState, GenesisResult,
+            GetAccountContractsRequest, GetAccountContractsResult, GetBidsRequest, KeyChange,
+            QueryRequest, QueryResult, RootNotFound, StateDiffRequest, StateDiffResult,
+            UpgradeConfig, UpgradeResult,
         },
         execution,
     },
@@ -33,6 +35,7 @@ use casper_execution_engine::{
         gas::Gas,
         logging::{self, Settings, Style},
         newtypes::{Blake2bHash, CorrelationId},
+        output_capture,
         stored_value::StoredValue,
         transform::Transform,
         utils::OS_PAGE_SIZE,
@@ -54,19 +57,22 @@ use casper_types::{
     runtime_args,
     system::{
         auction::{
-            Bids, EraId, EraValidators, UnbondingPurses, ValidatorWeights,
-            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS, AUCTION_DELAY_KEY, ERA_ID_KEY,
-            METHOD_RUN_AUCTION,
+            validator_info_key_name, Bid, Bids, Delegator, EraId, EraValidators,
+            SeigniorageRecipientsSnapshot, StakingStats, UnbondingPurse, UnbondingPurses,
+            ValidatorInfo, ValidatorWeights, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
+            AUCTION_DELAY_KEY, ERA_ID_KEY, METHOD_RUN_AUCTION, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+            STAKING_STATS_KEY,
         },
         mint::TOTAL_SUPPLY_KEY,
     },
-    CLTyped, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
-    DeployHash, DeployInfo, Key, KeyTag, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef,
-    U512,
+    CLTyped, CLValue, Contract, ContractEvent, ContractHash, ContractPackage, ContractPackageHash,
+    ContractWasm, DeployHash, DeployInfo, ExecutionError, FeeBreakdown, Key, KeyTag,
+    ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, U512,
 };
 
 use crate::internal::{
-    utils, ExecuteRequestBuilder, DEFAULT_PROPOSER_ADDR, DEFAULT_PROTOCOL_VERSION, SYSTEM_ADDR,
+    gas_baseline, utils, ExecuteRequestBuilder, DEFAULT_PROPOSER_ADDR, DEFAULT_PROTOCOL_VERSION,
+    SYSTEM_ADDR,
 };
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
@@ -91,9 +97,17 @@ pub struct WasmTestBuilder<S> {
     engine_state: Rc<EngineState<S>>,
     /// [`ExecutionResult`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     exec_results: Vec<Vec<Rc<ExecutionResult>>>,
+    /// Lines printed by contracts via `runtime::print` during each exec call
+    exec_outputs: Vec<Vec<String>>,
     upgrade_results: Vec<Result<UpgradeResult, engine_state::Error>>,
     genesis_hash: Option<Blake2bHash>,
     post_state_hash: Option<Blake2bHash>,
+    /// Pre-state hash of the latest exec call, onto which [`WasmTestBuilder::commit`] applies its
+    /// effects
+    exec_pre_state_hash: Option<Blake2bHash>,
+    /// Protocol version of the latest genesis or exec call, under which
+    /// [`WasmTestBuilder::commit`] applies its effects
+    exec_protocol_version: Option<ProtocolVersion>,
     /// Cached transform maps after subsequent successful runs i.e. `transforms[0]` is for first
     /// exec call etc.
     transforms: Vec<AdditiveMap<Key, Transform>>,
@@ -120,8 +134,13 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
+        Self::new_with_config(EngineConfig::default())
+    }
+}
+
+impl InMemoryWasmTestBuilder {
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
         Self::initialize_logging();
-        let engine_config = EngineConfig::default();
 
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
         let engine_state = EngineState::new(global_state, engine_config);
@@ -129,9 +148,12 @@ impl Default for InMemoryWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_results: Vec::new(),
+            exec_outputs: Vec::new(),
             upgrade_results: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
+            exec_pre_state_hash: None,
+            exec_protocol_version: None,
             transforms: Vec::new(),
             genesis_account: None,
             genesis_transforms: None,
@@ -143,6 +165,21 @@ impl Default for InMemoryWasmTestBuilder {
     }
 }
 
+impl InMemoryWasmTestBuilder {
+    /// Creates an independent copy of this builder, forking its global state.
+    ///
+    /// Unlike [`Clone::clone`], which shares the underlying engine state, anything executed,
+    /// committed or upgraded on the returned builder has no effect on this one and vice versa.
+    /// This allows tests to run a common setup once and then assert divergent outcomes from it.
+    pub fn fork(&self) -> Self {
+        let engine_state = self.engine_state.fork().expect("should fork engine state");
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            ..self.clone()
+        }
+    }
+}
+
 // TODO: Deriving `Clone` for `WasmTestBuilder<S>` doesn't work correctly (unsure why), so
 // implemented by hand here.  Try to derive in the future with a different compiler version.
 impl<S> Clone for WasmTestBuilder<S> {
@@ -150,9 +187,12 @@ impl<S> Clone for WasmTestBuilder<S> {
         WasmTestBuilder {
             engine_state: Rc::clone(&self.engine_state),
             exec_results: self.exec_results.clone(),
+            exec_outputs: self.exec_outputs.clone(),
             upgrade_results: self.upgrade_results.clone(),
             genesis_hash: self.genesis_hash,
             post_state_hash: self.post_state_hash,
+            exec_pre_state_hash: self.exec_pre_state_hash,
+            exec_protocol_version: self.exec_protocol_version,
             transforms: self.transforms.clone(),
             genesis_account: self.genesis_account.clone(),
             genesis_transforms: self.genesis_transforms.clone(),
@@ -222,9 +262,12 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_results: Vec::new(),
+            exec_outputs: Vec::new(),
             upgrade_results: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
+            exec_pre_state_hash: None,
+            exec_protocol_version: None,
             transforms: Vec::new(),
             genesis_account: None,
             genesis_transforms: None,
@@ -286,9 +329,12 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_results: Vec::new(),
+            exec_outputs: Vec::new(),
             upgrade_results: Vec::new(),
             genesis_hash: None,
             post_state_hash: Some(post_state_hash),
+            exec_pre_state_hash: None,
+            exec_protocol_version: None,
             transforms: Vec::new(),
             genesis_account: None,
             genesis_transforms: None,
@@ -322,9 +368,12 @@ where
         WasmTestBuilder {
             engine_state: result.0.engine_state,
             exec_results: Vec::new(),
+            exec_outputs: Vec::new(),
             upgrade_results: Vec::new(),
             genesis_hash: result.0.genesis_hash,
             post_state_hash: result.0.post_state_hash,
+            exec_pre_state_hash: None,
+            exec_protocol_version: result.0.exec_protocol_version,
             transforms: Vec::new(),
             genesis_account: result.0.genesis_account,
             mint_contract_hash: result.0.mint_contract_hash,
@@ -368,6 +417,7 @@ where
 
             self.genesis_hash = Some(state_root_hash);
             self.post_state_hash = Some(state_root_hash);
+            self.exec_protocol_version = Some(run_genesis_request.protocol_version());
             self.mint_contract_hash = Some(protocol_data.mint());
             self.handle_payment_contract_hash = Some(protocol_data.handle_payment());
             self.standard_payment_hash = Some(protocol_data.standard_payment());
@@ -447,42 +497,53 @@ where
         total_supply
     }
 
-    pub fn exec(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
-        let exec_request = {
-            let hash = self
-                .post_state_hash
-                .clone()
-                .expect("expected post_state_hash");
-            exec_request.parent_state_hash = hash;
-            exec_request
-        };
-        let maybe_exec_results = self
-            .engine_state
-            .run_execute(CorrelationId::new(), exec_request);
-        assert!(maybe_exec_results.is_ok());
-        // Parse deploy results
-        let execution_results = maybe_exec_results.as_ref().unwrap();
+    /// Returns the total supply tracked by the mint as of the latest post-state hash.
+    pub fn get_total_supply(&self) -> U512 {
+        self.total_supply(None)
+    }
+
+    /// Runs an execute request against the latest post-state hash, or against the pre-state hash
+    /// set via `ExecuteRequestBuilder::with_pre_state_hash` if any.
+    pub fn exec(&mut self, exec_request: ExecuteRequest) -> &mut Self {
+        self.try_exec(exec_request)
+            .expect("should execute against an existing pre-state hash")
+    }
+
+    /// Like [`WasmTestBuilder::exec`], but returns an error rather than panicking if the pre-state
+    /// hash doesn't exist in global state.
+    pub fn try_exec(
+        &mut self,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<&mut Self, RootNotFound> {
+        // An explicitly set pre-state hash takes precedence over the latest post-state hash.
+        if exec_request.parent_state_hash == ExecuteRequest::default().parent_state_hash {
+            exec_request.parent_state_hash =
+                self.post_state_hash.expect("expected post_state_hash");
+        }
+        self.exec_pre_state_hash = Some(exec_request.parent_state_hash);
+        self.exec_protocol_version = Some(exec_request.protocol_version);
+        let (execution_results, output) = output_capture::capture(|| {
+            self.engine_state
+                .run_execute(CorrelationId::new(), exec_request)
+        });
+        let execution_results = execution_results?;
         // Cache transformations
         self.transforms.extend(
             execution_results
                 .iter()
                 .map(|res| res.effect().transforms.clone()),
         );
-        self.exec_results.push(
-            maybe_exec_results
-                .unwrap()
-                .into_iter()
-                .map(Rc::new)
-                .collect(),
-        );
-        self
+        self.exec_results
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self.exec_outputs.push(output);
+        Ok(self)
     }
 
-    /// Commit effects of previous exec call on the latest post-state hash.
+    /// Commit effects of previous exec call on the state it was executed against.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
-            .post_state_hash
-            .clone()
+            .exec_pre_state_hash
+            .or(self.post_state_hash)
             .expect("Should have genesis hash");
 
         let effects = self.transforms.last().cloned().unwrap_or_default();
@@ -496,8 +557,16 @@ where
         pre_state_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> CommitResult {
+        let protocol_version = self
+            .exec_protocol_version
+            .unwrap_or(*DEFAULT_PROTOCOL_VERSION);
         self.engine_state
-            .apply_effect(CorrelationId::new(), pre_state_hash, effects)
+            .apply_effect(
+                CorrelationId::new(),
+                protocol_version,
+                pre_state_hash,
+                effects,
+            )
             .expect("should commit")
     }
 
@@ -675,6 +744,26 @@ where
         self.exec_results.get(index)
     }
 
+    /// Returns the lines printed by contracts via `runtime::print` during the exec call at
+    /// `index`.
+    pub fn get_exec_output(&self, index: usize) -> Option<&Vec<String>> {
+        self.exec_outputs.get(index)
+    }
+
+    /// Asserts that some line printed during the exec call at `exec_index` contains `text`.
+    pub fn expect_output_contains(&mut self, exec_index: usize, text: &str) -> &mut Self {
+        let output = self
+            .get_exec_output(exec_index)
+            .unwrap_or_else(|| panic!("should have exec output at index {}", exec_index));
+        if !output.iter().any(|line| line.contains(text)) {
+            panic!(
+                "Expected output of exec request {} to contain {:?}, but it was: {:#?}",
+                exec_index, text, output
+            );
+        }
+        self
+    }
+
     pub fn get_exec_results_count(&self) -> usize {
         self.exec_results.len()
     }
@@ -826,11 +915,75 @@ where
         exec_result.cost()
     }
 
+    /// Asserts that the gas cost of the first deploy of the exec request at `exec_index` differs
+    /// from `expected` by no more than `tolerance`.
+    pub fn expect_gas_cost_within(
+        &mut self,
+        exec_index: usize,
+        expected: Gas,
+        tolerance: Gas,
+    ) -> &mut Self {
+        let actual = self.exec_gas_cost(exec_index);
+        if !gas_baseline::is_within_tolerance(actual, expected, tolerance) {
+            panic!(
+                "Expected gas cost of exec request {} to be {} +/- {}, but it was {}",
+                exec_index, expected, tolerance, actual
+            );
+        }
+        self
+    }
+
+    /// Checks the gas cost of the first deploy of the exec request at `exec_index` against the
+    /// entry `name` of the gas baseline file.
+    ///
+    /// Does nothing unless gas baseline tracking is enabled; see [`gas_baseline`].
+    pub fn check_gas_baseline(&mut self, name: &str, exec_index: usize) -> &mut Self {
+        let actual = self.exec_gas_cost(exec_index);
+        gas_baseline::check(name, actual);
+        self
+    }
+
+    fn exec_gas_cost(&self, exec_index: usize) -> Gas {
+        *self
+            .exec_costs(exec_index)
+            .first()
+            .expect("should have exec result")
+    }
+
     pub fn exec_error_message(&self, index: usize) -> Option<String> {
         let response = self.get_exec_result(index)?;
         Some(utils::get_error_message(response))
     }
 
+    /// Returns the structured cause of the first failure in the exec result at `index`.
+    pub fn exec_error(&self, index: usize) -> Option<ExecutionError> {
+        self.get_exec_result(index)?
+            .iter()
+            .find_map(|result| match result.as_ref() {
+                ExecutionResult::Failure { error, .. } => Some(ExecutionError::from(error)),
+                ExecutionResult::Success { .. } => None,
+            })
+    }
+
+    /// Returns the events emitted while executing the deploys of the exec result at `index`.
+    pub fn exec_events(&self, index: usize) -> Vec<ContractEvent> {
+        self.get_exec_result(index)
+            .expect("should have exec response")
+            .iter()
+            .flat_map(|result| result.events().iter().cloned())
+            .collect()
+    }
+
+    /// Returns how the payment for the first deploy of the exec result at `index` was settled,
+    /// if it was settled.
+    pub fn exec_fee_breakdown(&self, index: usize) -> Option<FeeBreakdown> {
+        self.get_exec_result(index)
+            .expect("should have exec response")
+            .first()?
+            .fee_breakdown()
+            .copied()
+    }
+
     pub fn exec_commit_finish(&mut self, execute_request: ExecuteRequest) -> WasmTestResult<S> {
         self.exec(execute_request)
             .expect_success()
@@ -863,6 +1016,68 @@ where
         get_bids_result.bids().cloned().unwrap()
     }
 
+    /// Returns summaries of the contract packages installed under an account's named keys.
+    pub fn get_account_contracts(&self, account_hash: AccountHash) -> Vec<ContractPackageSummary> {
+        let get_account_contracts_request =
+            GetAccountContractsRequest::new(self.get_post_state_hash(), account_hash);
+
+        match self
+            .engine_state
+            .get_account_contracts(CorrelationId::new(), get_account_contracts_request)
+            .expect("should get account contracts")
+        {
+            GetAccountContractsResult::Success { contract_packages } => contract_packages,
+            other => panic!("expected account contracts but got {:?}", other),
+        }
+    }
+
+    /// Returns the key-level changes between two state roots, ordered by key.
+    pub fn diff_state_roots(
+        &self,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Vec<KeyChange> {
+        let state_diff_request = StateDiffRequest::new(pre_state_hash, post_state_hash);
+
+        match self
+            .engine_state
+            .diff_state_roots(CorrelationId::new(), state_diff_request)
+            .expect("should diff state roots")
+        {
+            StateDiffResult::Success { changes } => changes,
+            other => panic!("expected state diff but got {:?}", other),
+        }
+    }
+
+    /// Returns the bid of a single validator, read directly from its `Key::Bid` entry.
+    pub fn get_bid(&self, public_key: PublicKey) -> Option<Bid> {
+        match self.query(None, Key::Bid(AccountHash::from(&public_key)), &[]) {
+            Ok(StoredValue::Bid(bid)) => Some(*bid),
+            Ok(other) => panic!("expected bid value but got {}", other.type_name()),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the delegation made by `delegator` to `validator`, if both exist.
+    pub fn get_delegator(&self, validator: PublicKey, delegator: PublicKey) -> Option<Delegator> {
+        self.get_bid(validator)?
+            .delegators()
+            .get(&delegator)
+            .cloned()
+    }
+
+    /// Returns the public metadata set by a validator, if any.
+    pub fn get_validator_info(&self, public_key: PublicKey) -> Option<ValidatorInfo> {
+        let name = validator_info_key_name(&AccountHash::from(&public_key));
+        match self.query(None, self.get_auction_contract_hash().into(), &[name]) {
+            Ok(StoredValue::CLValue(cl_value)) => {
+                Some(cl_value.into_t().expect("should be validator info"))
+            }
+            Ok(other) => panic!("expected validator info but got {}", other.type_name()),
+            Err(_) => None,
+        }
+    }
+
     pub fn get_withdraws(&mut self) -> UnbondingPurses {
         let correlation_id = CorrelationId::new();
         let state_root_hash = self.get_post_state_hash();
@@ -895,6 +1110,16 @@ where
         ret
     }
 
+    /// Returns the pending unbonding requests from the bid of a single validator, read directly
+    /// from its `Key::Withdraw` entry.
+    pub fn get_unbonding_purses(&self, validator_account_hash: AccountHash) -> Vec<UnbondingPurse> {
+        match self.query(None, Key::Withdraw(validator_account_hash), &[]) {
+            Ok(StoredValue::Withdraw(unbonding_purses)) => unbonding_purses,
+            Ok(other) => panic!("expected withdraw value but got {}", other.type_name()),
+            Err(_) => Vec::new(),
+        }
+    }
+
     pub fn get_value<T>(&mut self, contract_hash: ContractHash, name: &str) -> T
     where
         T: FromBytes + CLTyped,
@@ -924,4 +1149,23 @@ where
         let auction_contract = self.get_auction_contract_hash();
         self.get_value(auction_contract, AUCTION_DELAY_KEY)
     }
+
+    pub fn get_staking_stats(&mut self) -> StakingStats {
+        let auction_contract = self.get_auction_contract_hash();
+        self.get_value(auction_contract, STAKING_STATS_KEY)
+    }
+
+    /// Returns the seigniorage recipients of the eras covered by the auction delay.
+    pub fn get_seigniorage_recipients_snapshot(&mut self) -> SeigniorageRecipientsSnapshot {
+        let auction_contract = self.get_auction_contract_hash();
+        self.get_value(auction_contract, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY)
+    }
+
+    /// Asserts that the stored staking statistics match those computed from all bids.
+    pub fn assert_staking_stats_consistent(&mut self) -> &mut Self {
+        let bids = self.get_bids();
+        let expected = StakingStats::from_bids(&bids).expect("should compute staking stats");
+        assert_eq!(self.get_staking_stats(), expected);
+        self
+    }
 }
@@ -9,7 +9,8 @@ use clap::{crate_version, App};
 use casper_engine_test_support::internal::{
     DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, ARG_AMOUNT, DEFAULT_ACCOUNTS,
     DEFAULT_ACCOUNT_ADDR, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_CONFIG_HASH,
-    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PAYMENT,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_MAX_DELEGATION_RATE,
+    DEFAULT_MAX_DELEGATION_RATE_CHANGE, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR, DEFAULT_PAYMENT,
     DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
     DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
 };
@@ -73,9 +74,11 @@ fn main() {
         *DEFAULT_SYSTEM_CONFIG,
         DEFAULT_VALIDATOR_SLOTS,
         DEFAULT_AUCTION_DELAY,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_MAX_DELEGATION_RATE,
+        DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     );
     let run_genesis_request = RunGenesisRequest::new(
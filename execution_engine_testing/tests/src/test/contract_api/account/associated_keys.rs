@@ -1,20 +1,29 @@
+use assert_matches::assert_matches;
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     internal::{
-        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
         DEFAULT_RUN_GENESIS_REQUEST,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
+use casper_execution_engine::core::{
+    engine_state::{upgrade::ActivationPoint, Error},
+    execution,
+    runtime::HOST_FUNCTION_PHASES_PROTOCOL_VERSION,
+};
 use casper_types::{
     account::{AccountHash, Weight},
-    runtime_args, RuntimeArgs, U512,
+    runtime_args, ApiError, Phase, ProtocolVersion, RuntimeArgs, U512,
 };
 
 const CONTRACT_ADD_UPDATE_ASSOCIATED_KEY: &str = "add_update_associated_key.wasm";
 const CONTRACT_REMOVE_ASSOCIATED_KEY: &str = "remove_associated_key.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
 const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
 const ARG_ACCOUNT: &str = "account";
 
@@ -81,3 +90,87 @@ fn should_manage_associated_key() {
     let is_error = builder.is_error();
     assert!(!is_error);
 }
+
+fn exec_add_associated_key_as_payment(
+    builder: &mut InMemoryWasmTestBuilder,
+    protocol_version: ProtocolVersion,
+) -> Error {
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([1; 32])
+            .with_payment_code(
+                CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+                runtime_args! { ARG_ACCOUNT => ACCOUNT_1_ADDR, },
+            )
+            .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_KEY])
+            .build();
+
+        ExecuteRequestBuilder::new()
+            .push_deploy(deploy)
+            .with_protocol_version(protocol_version)
+            .build()
+    };
+
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_result(0)
+        .expect("there should be a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        default_account.get_associated_key_weight(ACCOUNT_1_ADDR),
+        None,
+        "key should not have been added"
+    );
+
+    error.clone()
+}
+
+#[ignore]
+#[test]
+fn should_revert_when_managing_associated_keys_in_payment_phase() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(HOST_FUNCTION_PHASES_PROTOCOL_VERSION)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let error =
+        exec_add_associated_key_as_payment(&mut builder, HOST_FUNCTION_PHASES_PROTOCOL_VERSION);
+    let expected_phase = Phase::Payment as u8;
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::InvalidPhase(phase))) if phase == expected_phase
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_check_phase_of_associated_key_calls_before_activation() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // The payment code never pays, so the deploy still fails, but not because of its phase.
+    let error = exec_add_associated_key_as_payment(&mut builder, *DEFAULT_PROTOCOL_VERSION);
+    assert!(
+        !matches!(
+            error,
+            Error::Exec(execution::Error::Revert(ApiError::InvalidPhase(_)))
+        ),
+        "{:?}",
+        error
+    );
+}
@@ -16,8 +16,30 @@ use casper_types::{
     RuntimeArgs, U512,
 };
 
+/// The kinds of session code a deploy can carry, all of which are subject to the same
+/// authorization checks.
+#[derive(Clone, Copy, Debug)]
+enum SessionKind {
+    ModuleBytes,
+    StoredContractByName,
+    StoredVersionedContractByName,
+    Transfer,
+}
+
+const SESSION_KINDS: [SessionKind; 4] = [
+    SessionKind::ModuleBytes,
+    SessionKind::StoredContractByName,
+    SessionKind::StoredVersionedContractByName,
+    SessionKind::Transfer,
+];
+
 const CONTRACT_ADD_UPDATE_ASSOCIATED_KEY: &str = "add_update_associated_key.wasm";
 const CONTRACT_AUTHORIZED_KEYS: &str = "authorized_keys.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_HASH_KEY_NAME: &str = "do_nothing_hash";
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const DO_NOTHING_ENTRY_POINT: &str = "delegate";
 const ARG_KEY_MANAGEMENT_THRESHOLD: &str = "key_management_threshold";
 const ARG_DEPLOY_THRESHOLD: &str = "deploy_threshold";
 const ARG_ACCOUNT: &str = "account";
@@ -567,3 +589,126 @@ fn should_not_authorize_transfer_without_deploy_key_threshold() {
 
     builder.exec(transfer_request).expect_success().commit();
 }
+
+#[ignore]
+#[test]
+fn should_authorize_all_session_kinds_alike() {
+    // identity key (w: 1), KEY_1 (w: 2), KEY_2 (w: 2); deploy threshold 3, key management 4
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    for key in &[KEY_1, KEY_2] {
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+            runtime_args! { ARG_ACCOUNT => *key, },
+        )
+        .build();
+        builder.exec(add_key_request).expect_success().commit();
+    }
+    let store_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(store_request).expect_success().commit();
+    let update_thresholds_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUTHORIZED_KEYS,
+        runtime_args! {
+            ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(4),
+            ARG_DEPLOY_THRESHOLD => Weight::new(3),
+        },
+    )
+    .build();
+    builder
+        .exec(update_thresholds_request)
+        .expect_success()
+        .commit();
+
+    let cases: Vec<(Vec<AccountHash>, Option<Error>)> = vec![
+        (
+            vec![*DEFAULT_ACCOUNT_ADDR],
+            Some(execution::Error::DeploymentAuthorizationFailure.into()),
+        ),
+        (
+            vec![KEY_1],
+            Some(execution::Error::DeploymentAuthorizationFailure.into()),
+        ),
+        (vec![*DEFAULT_ACCOUNT_ADDR, KEY_1], None),
+        (vec![KEY_1, KEY_2], None),
+        (vec![*DEFAULT_ACCOUNT_ADDR, KEY_1, KEY_2], None),
+        (vec![KEY_3], Some(Error::Authorization)),
+        (vec![KEY_1, KEY_2, KEY_3], Some(Error::Authorization)),
+    ];
+
+    let mut deploy_hash = [0u8; 32];
+    for (authorization_keys, expected_error) in cases {
+        for session_kind in SESSION_KINDS.iter() {
+            deploy_hash[0] += 1;
+            let deploy_item = DeployItemBuilder::new()
+                .with_address(*DEFAULT_ACCOUNT_ADDR)
+                .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, });
+            let deploy_item = match session_kind {
+                SessionKind::ModuleBytes => {
+                    deploy_item.with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+                }
+                SessionKind::StoredContractByName => deploy_item.with_stored_session_named_key(
+                    DO_NOTHING_HASH_KEY_NAME,
+                    DO_NOTHING_ENTRY_POINT,
+                    RuntimeArgs::default(),
+                ),
+                SessionKind::StoredVersionedContractByName => deploy_item
+                    .with_stored_versioned_contract_by_name(
+                        DO_NOTHING_PACKAGE_HASH_KEY_NAME,
+                        None,
+                        DO_NOTHING_ENTRY_POINT,
+                        RuntimeArgs::default(),
+                    ),
+                SessionKind::Transfer => {
+                    let id: Option<u64> = None;
+                    deploy_item.with_transfer_args(runtime_args! {
+                        mint::ARG_TARGET => KEY_2,
+                        mint::ARG_AMOUNT => U512::one(),
+                        mint::ARG_ID => id,
+                    })
+                }
+            }
+            .with_deploy_hash(deploy_hash)
+            .with_authorization_keys(&authorization_keys)
+            .build();
+
+            builder
+                .exec(ExecuteRequestBuilder::from_deploy_item(deploy_item).build())
+                .commit();
+
+            let result = builder
+                .get_exec_results()
+                .last()
+                .and_then(|results| results.first())
+                .expect("should have result");
+            match &expected_error {
+                None => assert!(
+                    result.is_success(),
+                    "{:?} deploy signed by {:?} should succeed: {:?}",
+                    session_kind,
+                    authorization_keys,
+                    result
+                ),
+                Some(expected_error) => {
+                    assert!(
+                        result.has_precondition_failure(),
+                        "{:?} deploy signed by {:?} should fail authorization: {:?}",
+                        session_kind,
+                        authorization_keys,
+                        result
+                    );
+                    assert_eq!(
+                        result.as_error().map(ToString::to_string),
+                        Some(expected_error.to_string()),
+                    );
+                }
+            }
+        }
+    }
+}
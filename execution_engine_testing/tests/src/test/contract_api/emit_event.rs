@@ -0,0 +1,72 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, CLValue, ContractEvent, ContractHash, Key, RuntimeArgs};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const HASH_KEY_NAME: &str = "emit_event_hash";
+const ENTRY_POINT_EMIT: &str = "emit";
+const ARG_MESSAGE: &str = "message";
+
+#[ignore]
+#[test]
+fn should_report_emitted_events_in_execution_result() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        runtime_args! { ARG_MESSAGE => "from session" },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_emitter = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let expected_session_events = vec![
+        ContractEvent {
+            emitter: account_emitter,
+            payload: CLValue::from_t("from session".to_string()).unwrap(),
+        },
+        ContractEvent {
+            emitter: account_emitter,
+            payload: CLValue::from_t(42u64).unwrap(),
+        },
+    ];
+    assert_eq!(builder.exec_events(0), expected_session_events);
+
+    // The events are carried through to the execution result stored by the node.
+    let exec_result = &builder.get_exec_result(0).expect("should have exec result")[0];
+    match casper_types::ExecutionResult::from(exec_result.as_ref()) {
+        casper_types::ExecutionResult::Success { events, .. } => {
+            assert_eq!(events, expected_session_events)
+        }
+        casper_types::ExecutionResult::Failure { .. } => panic!("should succeed"),
+    }
+
+    let contract_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .expect("should have contract hash")
+        .into_hash()
+        .map(ContractHash::new)
+        .expect("should be a hash");
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_EMIT,
+        runtime_args! { ARG_MESSAGE => "from contract" },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let expected_contract_events = vec![ContractEvent {
+        emitter: Key::from(contract_hash),
+        payload: CLValue::from_t("from contract".to_string()).unwrap(),
+    }];
+    assert_eq!(builder.exec_events(1), expected_contract_events);
+}
@@ -0,0 +1,110 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::stored_value::StoredValue;
+use casper_types::{
+    runtime_args, ApiError, CLValue, ContractHash, ExecutionError, RuntimeArgs, U512,
+};
+
+const CONTRACT_GAS_SUB_LIMIT: &str = "gas_sub_limit.wasm";
+const ENTRY_POINT_CALL_BURN_GAS: &str = "call_burn_gas";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_GAS_LIMIT: &str = "gas_limit";
+const COUNTER_KEY_NAME: &str = "counter";
+const HASH_KEY_NAME: &str = "gas_sub_limit_hash";
+const RESULT_KEY_NAME: &str = "sub_call_result";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GAS_SUB_LIMIT,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .expect("should have contract hash")
+        .into_hash()
+        .map(ContractHash::new)
+        .expect("should be a hash");
+
+    (builder, contract_hash)
+}
+
+fn named_value(builder: &InMemoryWasmTestBuilder, name: &str) -> Option<CLValue> {
+    let key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)?;
+    match builder.query(None, key, &[]).expect("should query") {
+        StoredValue::CLValue(cl_value) => Some(cl_value),
+        _ => panic!("should be a CLValue"),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_return_error_to_caller_and_discard_effects_when_sub_limit_exhausted() {
+    let (mut builder, contract_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_CALL_BURN_GAS,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_GAS_LIMIT => U512::from(10_000_000u64),
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let result: u32 = named_value(&builder, RESULT_KEY_NAME)
+        .expect("caller should have recorded the sub-call result")
+        .into_t()
+        .unwrap();
+    assert_eq!(ApiError::from(result), ApiError::OutOfSubCallGas);
+
+    // The callee's write to the counter was discarded along with the rest of its effects.
+    let counter: u64 = named_value(&builder, COUNTER_KEY_NAME)
+        .expect("should have counter")
+        .into_t()
+        .unwrap();
+    assert_eq!(counter, 0);
+}
+
+#[ignore]
+#[test]
+fn should_fail_deploy_when_sub_limit_exceeds_remaining_gas() {
+    let (mut builder, contract_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_CALL_BURN_GAS,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_GAS_LIMIT => *DEFAULT_PAYMENT * 2,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    assert_matches!(builder.exec_error(1), Some(ExecutionError::OutOfGas { .. }));
+    assert!(named_value(&builder, RESULT_KEY_NAME).is_none());
+}
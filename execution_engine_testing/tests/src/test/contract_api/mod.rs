@@ -1,6 +1,8 @@
 mod account;
 mod blake2b;
 mod create_purse;
+mod emit_event;
+mod gas_sub_limit;
 mod get_arg;
 mod get_blocktime;
 mod get_caller;
@@ -8,6 +10,8 @@ mod get_phase;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
+mod print;
+mod reentrancy_guard;
 mod revert;
 mod subcall;
 mod transfer;
@@ -15,3 +19,4 @@ mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod transfer_with_receipt;
@@ -0,0 +1,60 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::execute_request::ExecuteRequest;
+use casper_types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_PRINT_MESSAGE: &str = "print_message.wasm";
+const ARG_MESSAGE: &str = "message";
+
+fn print_message_request(message: &str) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PRINT_MESSAGE,
+        runtime_args! { ARG_MESSAGE => message.to_string() },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_capture_printed_output_per_exec_request() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(print_message_request("hello"))
+        .expect_success()
+        .commit()
+        .exec(print_message_request("world"))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        builder.get_exec_output(0).expect("should have output"),
+        &vec!["before message".to_string(), "hello".to_string()]
+    );
+    assert_eq!(
+        builder.get_exec_output(1).expect("should have output"),
+        &vec!["before message".to_string(), "world".to_string()]
+    );
+    assert!(builder.get_exec_output(2).is_none());
+
+    builder
+        .expect_output_contains(0, "hell")
+        .expect_output_contains(1, "world");
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "Expected output of exec request 0 to contain \"goodbye\"")]
+fn should_panic_if_output_does_not_contain_text() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(print_message_request("hello"))
+        .expect_success()
+        .expect_output_contains(0, "goodbye");
+}
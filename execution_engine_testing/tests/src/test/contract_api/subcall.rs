@@ -74,6 +74,11 @@ fn should_charge_gas_for_subcall() {
         no_subcall_cost < do_nothing_cost,
         "do nothing in a subcall should cost more than no subcall"
     );
+
+    builder
+        .check_gas_baseline("subcall_do_nothing", 0)
+        .check_gas_baseline("subcall_do_something", 1)
+        .check_gas_baseline("subcall_no_subcall", 2);
 }
 
 #[ignore]
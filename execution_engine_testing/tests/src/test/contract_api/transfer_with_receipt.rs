@@ -0,0 +1,70 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::stored_value::StoredValue;
+use casper_types::{
+    account::AccountHash, runtime_args, BlockTime, RuntimeArgs, TransferReceipt, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT_WITH_RECEIPT: &str = "transfer_to_account_with_receipt.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ID: &str = "id";
+const RECEIPT_KEY_NAME: &str = "transfer_receipt";
+const BLOCK_TIME: u64 = 42_000;
+const TRANSFER_ID: Option<u64> = Some(7);
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+
+#[ignore]
+#[test]
+fn should_return_receipt_matching_recorded_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let amount = U512::from(100_000_000u64);
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT_WITH_RECEIPT,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => amount,
+            ARG_ID => TRANSFER_ID,
+        },
+    )
+    .with_block_time(BLOCK_TIME)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+    let receipt_key = *default_account
+        .named_keys()
+        .get(RECEIPT_KEY_NAME)
+        .expect("should have stored receipt");
+    let receipt: TransferReceipt = match builder.query(None, receipt_key, &[]) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should be a receipt"),
+        other => panic!("unexpected query result: {:?}", other),
+    };
+
+    assert_eq!(receipt.from, *DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(receipt.to, ACCOUNT_1_ADDR);
+    assert_eq!(receipt.amount, amount);
+    assert_eq!(receipt.timestamp, BlockTime::new(BLOCK_TIME));
+    assert_eq!(receipt.id, TRANSFER_ID);
+
+    let transfer = builder
+        .get_transfer(receipt.transfer_addr)
+        .expect("receipt should identify a recorded transfer");
+    assert_eq!(transfer.from, receipt.from);
+    assert_eq!(transfer.to, Some(receipt.to));
+    assert_eq!(transfer.source, default_account.main_purse());
+    assert_eq!(transfer.amount, receipt.amount);
+    assert_eq!(transfer.id, receipt.id);
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("target account should have been created");
+    assert_eq!(builder.get_purse_balance(account_1.main_purse()), amount);
+}
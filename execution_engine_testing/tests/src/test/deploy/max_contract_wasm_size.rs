@@ -0,0 +1,97 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_WASM_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{upgrade::ActivationPoint, Error},
+    execution,
+    runtime::MAX_CONTRACT_WASM_SIZE_PROTOCOL_VERSION,
+};
+use casper_types::{ContractWasmError, ProtocolVersion, RuntimeArgs};
+
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const SMALL_MAX_CONTRACT_WASM_SIZE: u32 = 16;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+#[ignore]
+#[test]
+fn should_store_contract_wasm_within_size_limit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+}
+
+/// Upgrades to `new_protocol_version` with a small `max_contract_wasm_size` and stores a contract.
+fn store_contract_with_small_size_limit(
+    new_protocol_version: ProtocolVersion,
+) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let mut new_wasm_config = *DEFAULT_WASM_CONFIG;
+    new_wasm_config.max_contract_wasm_size = SMALL_MAX_CONTRACT_WASM_SIZE;
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_wasm_config(new_wasm_config)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .with_protocol_version(new_protocol_version)
+    .build();
+
+    builder.exec(exec_request).commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_not_store_contract_wasm_exceeding_size_limit() {
+    let builder = store_contract_with_small_size_limit(MAX_CONTRACT_WASM_SIZE_PROTOCOL_VERSION);
+
+    let response = builder
+        .get_exec_result(0)
+        .expect("there should be a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::ContractWasm(ContractWasmError::TooLarge { max, .. }))
+            if *max == SMALL_MAX_CONTRACT_WASM_SIZE as usize
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_limit_contract_wasm_size_before_protocol_version_1_1_0() {
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    assert!(new_protocol_version < MAX_CONTRACT_WASM_SIZE_PROTOCOL_VERSION);
+
+    let mut builder = store_contract_with_small_size_limit(new_protocol_version);
+    builder.expect_success();
+}
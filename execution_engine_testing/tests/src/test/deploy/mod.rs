@@ -1,4 +1,7 @@
 mod context_association;
+mod max_contract_wasm_size;
+mod max_gas;
+mod no_fee;
 mod non_standard_payment;
 mod preconditions;
 mod receipts;
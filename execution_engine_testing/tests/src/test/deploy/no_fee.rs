@@ -0,0 +1,142 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{EngineConfig, Error, FeeHandling},
+        execution,
+    },
+    shared::gas::Gas,
+};
+use casper_types::{account::AccountHash, runtime_args, system::mint, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_ENDLESS_LOOP: &str = "endless_loop.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const SESSION_GAS_LIMIT: u64 = 1_000_000_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new(
+        5,
+        FeeHandling::NoFee {
+            session_gas_limit: Gas::new(U512::from(SESSION_GAS_LIMIT)),
+        },
+    );
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder
+}
+
+fn default_account_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let main_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    builder.get_purse_balance(main_purse)
+}
+
+#[ignore]
+#[test]
+fn should_execute_deploy_without_charging_fees() {
+    let mut builder = setup();
+
+    let transfer_amount = U512::from(1_000_000);
+    let initial_balance = default_account_balance(&builder);
+    let initial_proposer_balance = builder.get_proposer_purse_balance();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => transfer_amount },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.last_exec_gas_cost(), Gas::default());
+    assert_eq!(
+        default_account_balance(&builder),
+        initial_balance - transfer_amount,
+        "only the transferred amount should be deducted"
+    );
+    assert_eq!(
+        builder.get_proposer_purse_balance(),
+        initial_proposer_balance,
+        "proposer should not be paid"
+    );
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    assert_eq!(builder.get_purse_balance(account_1_purse), transfer_amount);
+}
+
+#[ignore]
+#[test]
+fn should_enforce_session_gas_limit_without_fees() {
+    let mut builder = setup();
+
+    let initial_balance = default_account_balance(&builder);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ENDLESS_LOOP,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_result(0)
+        .expect("there should be a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(error, Error::Exec(execution::Error::GasLimit));
+    assert_eq!(execution_result.cost(), Gas::default());
+
+    assert_eq!(default_account_balance(&builder), initial_balance);
+}
+
+#[ignore]
+#[test]
+fn should_execute_wasmless_transfer_without_charging_fees() {
+    let mut builder = setup();
+
+    let transfer_amount = U512::from(1_000_000);
+    let initial_balance = default_account_balance(&builder);
+    let initial_proposer_balance = builder.get_proposer_purse_balance();
+
+    let exec_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => transfer_amount,
+            mint::ARG_ID => <Option<u64>>::None
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.last_exec_gas_cost(), Gas::default());
+    assert_eq!(
+        default_account_balance(&builder),
+        initial_balance - transfer_amount,
+        "only the transferred amount should be deducted"
+    );
+    assert_eq!(
+        builder.get_proposer_purse_balance(),
+        initial_proposer_balance,
+        "proposer should not be paid"
+    );
+}
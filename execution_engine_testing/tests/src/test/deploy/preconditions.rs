@@ -7,11 +7,13 @@ use casper_engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_execution_engine::core::engine_state::Error;
+use casper_execution_engine::{core::engine_state::Error, shared::newtypes::Blake2bHash};
 use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
 
 const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
 const ARG_AMOUNT: &str = "amount";
+const ARG_TARGET: &str = "target";
+const ARG_ID: &str = "id";
 
 #[ignore]
 #[test]
@@ -119,3 +121,59 @@ fn should_raise_precondition_authorization_failure_invalid_authorized_keys() {
     let precondition_failure = utils::get_precondition_failure(response);
     assert_matches!(precondition_failure, Error::Authorization);
 }
+
+#[ignore]
+#[test]
+fn should_execute_against_explicit_pre_state_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let genesis_hash = builder.get_genesis_hash();
+
+    let transfer_request = |amount: u64| {
+        ExecuteRequestBuilder::transfer(
+            *DEFAULT_ACCOUNT_ADDR,
+            runtime_args! {
+                ARG_TARGET => ACCOUNT_1_ADDR,
+                ARG_AMOUNT => U512::from(amount),
+                ARG_ID => <Option<u64>>::None
+            },
+        )
+    };
+
+    builder
+        .exec(transfer_request(1_000_000_000).build())
+        .expect_success()
+        .commit();
+
+    // Replay a different transfer on top of genesis, discarding the first one.
+    builder
+        .exec(
+            transfer_request(2_000_000_000)
+                .with_pre_state_hash(genesis_hash)
+                .build(),
+        )
+        .expect_success()
+        .commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        U512::from(2_000_000_000u64)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_root_not_found_for_unknown_pre_state_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let unknown_hash = Blake2bHash::new(&[1u8; 32]);
+    let exec_request = ExecuteRequestBuilder::new()
+        .with_pre_state_hash(unknown_hash)
+        .build();
+
+    assert!(builder.try_exec(exec_request).is_err());
+}
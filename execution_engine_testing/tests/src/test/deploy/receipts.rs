@@ -3,13 +3,15 @@ use std::collections::{BTreeMap, BTreeSet};
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
 use casper_execution_engine::storage::protocol_data::DEFAULT_WASMLESS_TRANSFER_COST;
 use casper_types::{
-    account::AccountHash, runtime_args, AccessRights, DeployHash, PublicKey, RuntimeArgs,
-    SecretKey, Transfer, TransferAddr, U512,
+    account::AccountHash, hashing, runtime_args, AccessRights, CLValue, ContractHash, DeployHash,
+    DeployTarget, PublicKey, RuntimeArgs, SecretKey, Transfer, TransferAddr, U512,
 };
 
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
@@ -25,6 +27,12 @@ const TRANSFER_ARG_TARGETS: &str = "targets";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNTS_STORED: &str = "transfer_purse_to_accounts_stored.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNTS_SUBCALL: &str = "transfer_purse_to_accounts_subcall.wasm";
 
+const CONTRACT_PURSE_HOLDER_STORED: &str = "purse_holder_stored.wasm";
+const PURSE_HOLDER_STORED_CONTRACT_NAME: &str = "purse_holder_stored";
+const ENTRY_POINT_VERSION: &str = "version";
+const ARG_IS_LOCKED: &str = "is_locked";
+const PURSE_HOLDER_VERSION: &str = "1.0.0";
+
 static ALICE_KEY: Lazy<PublicKey> = Lazy::new(|| SecretKey::ed25519([3; 32]).into());
 static BOB_KEY: Lazy<PublicKey> = Lazy::new(|| SecretKey::ed25519([5; 32]).into());
 static CAROL_KEY: Lazy<PublicKey> = Lazy::new(|| SecretKey::ed25519([7; 32]).into());
@@ -89,6 +97,8 @@ fn should_record_wasmless_transfer() {
     assert_eq!(deploy_info.source, default_account.main_purse());
 
     assert_eq!(deploy_info.gas, U512::from(DEFAULT_WASMLESS_TRANSFER_COST));
+    assert_eq!(deploy_info.session_target, Some(DeployTarget::Transfer));
+    assert_eq!(deploy_info.payment_target, None);
 
     let transfers = deploy_info.transfers;
     assert_eq!(transfers.len(), 1);
@@ -156,6 +166,18 @@ fn should_record_wasm_transfer() {
     assert_eq!(deploy_info.from, *DEFAULT_ACCOUNT_ADDR);
     assert_eq!(deploy_info.source, default_account.main_purse());
     assert_ne!(deploy_info.gas, U512::zero());
+    assert_eq!(
+        deploy_info.session_target,
+        Some(DeployTarget::ModuleBytes {
+            module_hash: hashing::blake2b256(utils::read_wasm_file_bytes(
+                CONTRACT_TRANSFER_PURSE_TO_ACCOUNT
+            )),
+        })
+    );
+    assert_eq!(
+        deploy_info.payment_target,
+        Some(DeployTarget::StandardPayment)
+    );
 
     let transfers = deploy_info.transfers;
     assert_eq!(transfers.len(), 1);
@@ -529,3 +551,72 @@ fn should_record_wasm_transfers_with_subcall() {
         assert_eq!(transfer_counts.get(&expected).cloned(), EXPECTED_COUNT);
     }
 }
+
+#[ignore]
+#[test]
+fn should_record_session_return_value() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PURSE_HOLDER_STORED,
+        runtime_args! { ARG_IS_LOCKED => false },
+    )
+    .build();
+
+    builder.exec(install_request).commit().expect_success();
+
+    let install_result = builder
+        .get_exec_result(0)
+        .expect("should have install result")[0]
+        .clone();
+    assert_eq!(install_result.ret(), None);
+
+    let contract_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .named_keys()
+        .get(PURSE_HOLDER_STORED_CONTRACT_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_VERSION,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let call_deploy_hash = {
+        let deploy_items: Vec<DeployHash> = call_request
+            .deploys()
+            .iter()
+            .map(Result::as_ref)
+            .filter_map(Result::ok)
+            .map(|deploy_item| deploy_item.deploy_hash)
+            .collect();
+        deploy_items[0]
+    };
+
+    builder.exec(call_request).commit().expect_success();
+
+    let expected_ret = CLValue::from_t(PURSE_HOLDER_VERSION).expect("should create CLValue");
+
+    let call_result = builder.get_exec_result(1).expect("should have call result")[0].clone();
+    assert_eq!(call_result.ret(), Some(&expected_ret));
+
+    let deploy_info = builder
+        .get_deploy_info(call_deploy_hash)
+        .expect("should have deploy info");
+    assert_eq!(deploy_info.ret, Some(expected_ret));
+    assert_eq!(
+        deploy_info.session_target,
+        Some(DeployTarget::StoredContract {
+            contract_hash,
+            entry_point: ENTRY_POINT_VERSION.to_string(),
+        })
+    );
+}
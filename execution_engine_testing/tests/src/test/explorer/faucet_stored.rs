@@ -4,7 +4,9 @@ use casper_engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_types::{account::AccountHash, runtime_args, ApiError, RuntimeArgs, U512};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, ExecutionError, RuntimeArgs, U512,
+};
 
 const FAUCET: &str = "faucet";
 const CALL_FAUCET: &str = "call_faucet";
@@ -119,4 +121,8 @@ fn should_fail_if_already_funded() {
         "should have reverted with user error 1 (already funded) but received {}",
         error_message,
     );
+    assert_eq!(
+        builder.exec_error(2),
+        Some(ExecutionError::ApiError(ApiError::User(1).into()))
+    );
 }
@@ -0,0 +1,103 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+
+static ALICE_KEY: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static ALICE_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*ALICE_KEY));
+static BOB_KEY: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([5; SecretKey::ED25519_LENGTH]).into());
+static BOB_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*BOB_KEY));
+
+static TRANSFER_AMOUNT: Lazy<U512> = Lazy::new(|| U512::from(100_000_000));
+
+fn transfer_to(builder: &mut InMemoryWasmTestBuilder, target: AccountHash) {
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => target,
+            TRANSFER_ARG_AMOUNT => *TRANSFER_AMOUNT,
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit().expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fork_builder_into_divergent_branches() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let genesis_post_state_hash = builder.get_post_state_hash();
+
+    let mut alice_branch = builder.fork();
+    let mut bob_branch = builder.fork();
+    assert_eq!(alice_branch.get_post_state_hash(), genesis_post_state_hash);
+    assert_eq!(
+        alice_branch.get_auction_contract_hash(),
+        builder.get_auction_contract_hash()
+    );
+
+    transfer_to(&mut alice_branch, *ALICE_ADDR);
+    transfer_to(&mut bob_branch, *BOB_ADDR);
+
+    assert!(alice_branch.get_account(*ALICE_ADDR).is_some());
+    assert!(alice_branch.get_account(*BOB_ADDR).is_none());
+    assert!(bob_branch.get_account(*BOB_ADDR).is_some());
+    assert!(bob_branch.get_account(*ALICE_ADDR).is_none());
+    assert_ne!(
+        alice_branch.get_post_state_hash(),
+        bob_branch.get_post_state_hash()
+    );
+
+    // The original builder is unaffected by either branch.
+    assert_eq!(builder.get_post_state_hash(), genesis_post_state_hash);
+    assert!(builder.get_account(*ALICE_ADDR).is_none());
+    assert!(builder.get_account(*BOB_ADDR).is_none());
+    assert!(builder.get_exec_results().is_empty());
+    assert_eq!(alice_branch.get_exec_results().len(), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_share_protocol_data_between_forks() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgraded_branch = builder.fork();
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(1)
+        .build();
+    upgraded_branch
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let get_protocol_data = |builder: &InMemoryWasmTestBuilder| {
+        builder
+            .get_engine_state()
+            .get_protocol_data(new_protocol_version)
+            .expect("should have result")
+    };
+    assert!(get_protocol_data(&upgraded_branch).is_some());
+    assert!(get_protocol_data(&builder).is_none());
+}
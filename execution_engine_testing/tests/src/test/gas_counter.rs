@@ -6,16 +6,28 @@ use parity_wasm::{
 
 use casper_engine_test_support::{
     internal::{
-        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
-        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_WASM_CONFIG,
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        ARG_AMOUNT, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_WASM_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
 use casper_execution_engine::{
-    core::engine_state::Error,
-    shared::{gas::Gas, wasm_prep::PreprocessingError},
+    core::{
+        engine_state::{upgrade::ActivationPoint, Error},
+        execution::{self, MAX_GAS_CHECKPOINTS},
+    },
+    shared::{
+        gas::Gas, opcode_costs::OpcodeCosts, wasm_config::WasmConfig, wasm_prep::PreprocessingError,
+    },
+};
+use casper_types::{
+    contracts::DEFAULT_ENTRY_POINT_NAME, runtime_args, ExecutionError, ExecutionResult,
+    ProtocolVersion, RuntimeArgs,
 };
-use casper_types::{contracts::DEFAULT_ENTRY_POINT_NAME, runtime_args, RuntimeArgs};
+
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+const CONTRACT_ENDLESS_LOOP: &str = "endless_loop.wasm";
 
 /// Creates minimal session code that does nothing
 fn make_minimal_do_nothing() -> Vec<u8> {
@@ -130,20 +142,14 @@ fn should_fail_to_overflow_gas_counter() {
     );
 }
 
-#[ignore]
-#[test]
-fn should_correctly_measure_gas_for_opcodes() {
-    let opcode_costs = DEFAULT_WASM_CONFIG.opcode_costs();
-
+/// Returns hand written Wasm instructions paired with their expected cost under `opcode_costs`.
+///
+/// The first element of the tuple represents an option where `Some` case represents a metered
+/// instruction and `None` an instruction that's not accounted for.
+fn make_metered_opcodes(opcode_costs: OpcodeCosts) -> Vec<(Option<u32>, Instruction)> {
     const GROW_PAGES: u32 = 1;
 
-    // A vector of expected cost of given WASM instruction.
-    // First element of the tuple represents and option where Some case represents metered
-    // instruction and None an instruction that's not accounted for.
-    //
-    // The idea here is to execute hand written WASM and compare the execution result's gas counter
-    // with the expected gathered from here.
-    let opcodes = vec![
+    vec![
         (Some(opcode_costs.nop), Instruction::Nop),
         (
             Some(opcode_costs.current_memory),
@@ -200,7 +206,17 @@ fn should_correctly_measure_gas_for_opcodes() {
         (Some(opcode_costs.control_flow), Instruction::Drop), /* Discard the result */
         // Sentinel instruction that's required to be present but it's not accounted for
         (None, Instruction::End),
-    ];
+    ]
+}
+
+#[ignore]
+#[test]
+fn should_correctly_measure_gas_for_opcodes() {
+    let opcode_costs = DEFAULT_WASM_CONFIG.opcode_costs();
+
+    // The idea here is to execute hand written WASM and compare the execution result's gas counter
+    // with the expected gathered from here.
+    let opcodes = make_metered_opcodes(opcode_costs);
 
     let instructions = opcodes.iter().map(|(_, instr)| instr.clone()).collect();
     let accounted_opcodes: Vec<_> = opcodes.iter().filter_map(|(cost, _)| *cost).collect();
@@ -259,3 +275,186 @@ fn should_correctly_measure_gas_for_opcodes() {
         accounted_opcodes
     );
 }
+
+/// Executes `session_bytes` at `protocol_version` and returns the gas spent by the session alone.
+fn measure_session_gas(
+    builder: &mut InMemoryWasmTestBuilder,
+    session_bytes: Vec<u8>,
+    protocol_version: ProtocolVersion,
+    deploy_hash: [u8; 32],
+) -> Gas {
+    let make_exec_request = |session_bytes: Vec<u8>| {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_bytes(session_bytes, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash(deploy_hash)
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item)
+            .with_protocol_version(protocol_version)
+            .build()
+    };
+
+    let payment_cost = {
+        let mut forked_builder = builder.clone();
+        forked_builder
+            .exec(make_exec_request(make_minimal_do_nothing()))
+            .commit()
+            .expect_success();
+        forked_builder.last_exec_gas_cost()
+    };
+
+    builder
+        .exec(make_exec_request(session_bytes))
+        .commit()
+        .expect_success();
+
+    builder.last_exec_gas_cost() - payment_cost
+}
+
+#[ignore]
+#[test]
+fn should_measure_gas_for_opcodes_using_upgraded_opcode_costs() {
+    let old_opcode_costs = DEFAULT_WASM_CONFIG.opcode_costs();
+    let new_opcode_costs = OpcodeCosts {
+        bit: old_opcode_costs.bit * 2,
+        add: old_opcode_costs.add * 2,
+        mul: old_opcode_costs.mul * 2,
+        div: old_opcode_costs.div * 2,
+        load: old_opcode_costs.load * 2,
+        store: old_opcode_costs.store * 2,
+        op_const: old_opcode_costs.op_const * 2,
+        local: old_opcode_costs.local * 2,
+        global: old_opcode_costs.global * 2,
+        control_flow: old_opcode_costs.control_flow * 2,
+        integer_comparison: old_opcode_costs.integer_comparison * 2,
+        conversion: old_opcode_costs.conversion * 2,
+        unreachable: old_opcode_costs.unreachable * 2,
+        nop: old_opcode_costs.nop * 2,
+        current_memory: old_opcode_costs.current_memory * 2,
+        grow_memory: old_opcode_costs.grow_memory * 2,
+        regular: old_opcode_costs.regular * 2,
+    };
+
+    let old_opcodes = make_metered_opcodes(old_opcode_costs);
+    let new_opcodes = make_metered_opcodes(new_opcode_costs);
+    let expected_old_cost: Gas = old_opcodes
+        .iter()
+        .filter_map(|(cost, _)| *cost)
+        .map(Gas::from)
+        .sum();
+    let expected_new_cost: Gas = new_opcodes
+        .iter()
+        .filter_map(|(cost, _)| *cost)
+        .map(Gas::from)
+        .sum();
+    assert_ne!(expected_old_cost, expected_new_cost);
+
+    // Only the costs differ between the two tables, so both produce the same Wasm module.
+    let session_bytes =
+        make_session_code_with(old_opcodes.into_iter().map(|(_, instr)| instr).collect());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let old_gas_cost = measure_session_gas(
+        &mut builder,
+        session_bytes.clone(),
+        *DEFAULT_PROTOCOL_VERSION,
+        [44; 32],
+    );
+    assert_eq!(old_gas_cost, expected_old_cost);
+
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_wasm_config = WasmConfig::new(
+        DEFAULT_WASM_CONFIG.max_memory,
+        DEFAULT_WASM_CONFIG.max_stack_height,
+        DEFAULT_WASM_CONFIG.max_named_key_length,
+        DEFAULT_WASM_CONFIG.max_cl_value_size,
+        DEFAULT_WASM_CONFIG.max_named_keys_count,
+        DEFAULT_WASM_CONFIG.max_associated_keys,
+        DEFAULT_WASM_CONFIG.max_action_threshold,
+        DEFAULT_WASM_CONFIG.max_contract_wasm_size,
+        new_opcode_costs,
+        DEFAULT_WASM_CONFIG.storage_costs(),
+        DEFAULT_WASM_CONFIG.take_host_function_costs(),
+    );
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_wasm_config(new_wasm_config)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let new_gas_cost = measure_session_gas(
+        &mut builder,
+        session_bytes.clone(),
+        new_protocol_version,
+        [45; 32],
+    );
+    assert_eq!(new_gas_cost, expected_new_cost);
+
+    // Executing the same code again under the upgraded table is charged identically.
+    let repeated_gas_cost =
+        measure_session_gas(&mut builder, session_bytes, new_protocol_version, [46; 32]);
+    assert_eq!(repeated_gas_cost, new_gas_cost);
+}
+
+#[ignore]
+#[test]
+fn should_report_gas_checkpoints_when_running_out_of_gas() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ENDLESS_LOOP,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder.exec(exec_request).commit();
+
+    let responses = builder.get_exec_result(0).expect("should have response");
+    let response = responses.get(0).expect("should have first element");
+    assert_matches!(
+        response.as_error(),
+        Some(Error::Exec(execution::Error::GasLimit))
+    );
+
+    let execution_result = ExecutionResult::from(response.as_ref());
+    let (checkpoints, last_host_operation) = match execution_result {
+        ExecutionResult::Failure {
+            error:
+                ExecutionError::OutOfGas {
+                    checkpoints,
+                    last_host_operation,
+                },
+            ..
+        } => (checkpoints, last_host_operation),
+        other => panic!("expected out of gas failure, got {:?}", other),
+    };
+
+    assert!(!checkpoints.is_empty());
+    assert!(checkpoints.len() <= MAX_GAS_CHECKPOINTS);
+    assert!(checkpoints
+        .windows(2)
+        .all(|pair| pair[0].gas_used <= pair[1].gas_used));
+    assert_matches!(
+        last_host_operation.as_deref(),
+        Some("host_function_get_main_purse") | Some("host_function_write")
+    );
+}
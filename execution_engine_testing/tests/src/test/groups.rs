@@ -915,3 +915,58 @@ fn should_not_call_uncallable_session_from_deploy() {
     };
     builder.exec(exec_request_3).expect_success().commit();
 }
+
+#[ignore]
+#[test]
+fn should_list_account_contracts() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GROUPS,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder.exec(exec_request).expect_success().commit();
+
+    let package_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(PACKAGE_HASH_KEY)
+        .expect("should have contract package")
+        .into_hash()
+        .expect("should be a hash");
+
+    let contract_packages = builder.get_account_contracts(*DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(contract_packages.len(), 1);
+    let contract_package = &contract_packages[0];
+    assert_eq!(
+        contract_package.contract_package_hash().value(),
+        package_hash
+    );
+    assert!(contract_package.named_keys().contains(PACKAGE_HASH_KEY));
+    assert!(!contract_package.is_locked());
+    assert_eq!(contract_package.groups(), &["Group 1".to_string()]);
+
+    assert_eq!(contract_package.versions().len(), 1);
+    let contract = &contract_package.versions()[0];
+    assert_eq!(
+        contract.contract_version_key().contract_version(),
+        CONTRACT_INITIAL_VERSION
+    );
+    assert!(contract.is_enabled());
+    let mut expected_entry_points = vec![
+        RESTRICTED_SESSION,
+        RESTRICTED_CONTRACT,
+        RESTRICTED_SESSION_CALLER,
+        UNRESTRICTED_CONTRACT_CALLER,
+        RESTRICTED_CONTRACT_CALLER_AS_SESSION,
+        UNCALLABLE_SESSION,
+        UNCALLABLE_CONTRACT,
+        CALL_RESTRICTED_ENTRY_POINTS,
+    ];
+    expected_entry_points.sort_unstable();
+    assert_eq!(contract.entry_points(), expected_entry_points.as_slice());
+}
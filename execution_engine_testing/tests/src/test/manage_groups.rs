@@ -10,10 +10,13 @@ use casper_engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_execution_engine::core::{
+    engine_state::{execute_request::ExecuteRequest, Error},
+    execution,
+};
 use casper_types::{
-    contracts::{self, CONTRACT_INITIAL_VERSION, MAX_GROUPS},
-    runtime_args, Group, Key, RuntimeArgs,
+    contracts::{self, CONTRACT_INITIAL_VERSION, MAX_GROUPS, MAX_TOTAL_UREFS},
+    runtime_args, ApiError, Group, Key, RuntimeArgs, URef,
 };
 
 const CONTRACT_GROUPS: &str = "manage_groups.wasm";
@@ -22,6 +25,7 @@ const PACKAGE_ACCESS_KEY: &str = "package_access_key";
 const CREATE_GROUP: &str = "create_group";
 const REMOVE_GROUP: &str = "remove_group";
 const EXTEND_GROUP_UREFS: &str = "extend_group_urefs";
+const TRY_EXTEND_GROUP_UREFS: &str = "try_extend_group_urefs";
 const REMOVE_GROUP_UREFS: &str = "remove_group_urefs";
 const GROUP_NAME_ARG: &str = "group_name";
 const UREFS_ARG: &str = "urefs";
@@ -511,3 +515,196 @@ fn should_limit_max_urefs_while_extending() {
     let error = assert_matches!(error, Error::Exec(execution::Error::Revert(e)) => e);
     assert_eq!(error, &contracts::Error::MaxTotalURefsExceeded.into());
 }
+
+fn install_manage_groups(builder: &mut InMemoryWasmTestBuilder) -> Key {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GROUPS,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let account = builder
+        .query(None, Key::Account(*DEFAULT_ACCOUNT_ADDR), &[])
+        .expect("should query account")
+        .as_account()
+        .cloned()
+        .expect("should be account");
+    *account
+        .named_keys()
+        .get(PACKAGE_HASH_KEY)
+        .expect("should have contract package")
+}
+
+fn create_group_request(
+    group_name: &str,
+    total_new_urefs: u64,
+    deploy_hash: [u8; 32],
+) -> ExecuteRequest {
+    let args = runtime_args! {
+        GROUP_NAME_ARG => group_name,
+        TOTAL_NEW_UREFS_ARG => total_new_urefs,
+        TOTAL_EXISTING_UREFS_ARG => 0u64,
+    };
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_stored_versioned_contract_by_name(
+            PACKAGE_HASH_KEY,
+            Some(CONTRACT_INITIAL_VERSION),
+            CREATE_GROUP,
+            args,
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn extend_group_request(
+    entry_point: &str,
+    group_name: &str,
+    mut args: RuntimeArgs,
+    deploy_hash: [u8; 32],
+) -> ExecuteRequest {
+    args.insert(GROUP_NAME_ARG, group_name)
+        .expect("should insert group name");
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_stored_versioned_contract_by_name(
+            PACKAGE_HASH_KEY,
+            Some(CONTRACT_INITIAL_VERSION),
+            entry_point,
+            args,
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn group_urefs(builder: &InMemoryWasmTestBuilder, package_hash: Key) -> BTreeSet<URef> {
+    builder
+        .query(None, package_hash, &[])
+        .expect("should have result")
+        .as_contract_package()
+        .expect("should be package")
+        .groups()
+        .get(&Group::new(GROUP_1_NAME))
+        .cloned()
+        .expect("should have group")
+}
+
+fn last_revert_error(builder: &InMemoryWasmTestBuilder) -> ApiError {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last response");
+    let exec_response = response.last().expect("should have response");
+    let error = exec_response.as_error().expect("should have error");
+    *assert_matches!(error, Error::Exec(execution::Error::Revert(e)) => e)
+}
+
+#[ignore]
+#[test]
+fn should_limit_max_groups_while_creating() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let package_hash = install_manage_groups(&mut builder);
+
+    for index in 0..MAX_GROUPS {
+        let exec_request = create_group_request(&format!("Group {}", index), 1, [index + 1; 32]);
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let contract_package = builder
+        .query(None, package_hash, &[])
+        .expect("should have result")
+        .as_contract_package()
+        .cloned()
+        .expect("should be package");
+    assert_eq!(contract_package.groups().len(), MAX_GROUPS as usize);
+
+    let exec_request = create_group_request("One too many", 1, [MAX_GROUPS + 1; 32]);
+    builder.exec(exec_request).commit();
+
+    assert_eq!(
+        last_revert_error(&builder),
+        contracts::Error::MaxGroupsExceeded.into()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_limit_max_total_urefs_while_creating() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let package_hash = install_manage_groups(&mut builder);
+
+    let exec_request = create_group_request(GROUP_1_NAME, MAX_TOTAL_UREFS as u64 + 1, [3; 32]);
+    builder.exec(exec_request).commit();
+
+    assert_eq!(
+        last_revert_error(&builder),
+        contracts::Error::MaxTotalURefsExceeded.into()
+    );
+
+    let contract_package = builder
+        .query(None, package_hash, &[])
+        .expect("should have result")
+        .as_contract_package()
+        .cloned()
+        .expect("should be package");
+    assert!(contract_package.groups().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_extend_group_partially_when_exceeding_limits() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let package_hash = install_manage_groups(&mut builder);
+
+    let exec_request = create_group_request(GROUP_1_NAME, 2, [2; 32]);
+    builder.exec(exec_request).expect_success().commit();
+    let urefs_before = group_urefs(&builder, package_hash);
+    assert_eq!(urefs_before.len(), 2);
+
+    // The contract ignores the error, so the deploy succeeds and any partial change would persist.
+    let exec_request = extend_group_request(
+        TRY_EXTEND_GROUP_UREFS,
+        GROUP_1_NAME,
+        runtime_args! { TOTAL_NEW_UREFS_ARG => MAX_GROUPS - 1 },
+        [3; 32],
+    );
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(group_urefs(&builder, package_hash), urefs_before);
+}
+
+#[ignore]
+#[test]
+fn should_not_truncate_number_of_new_urefs_while_extending() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let package_hash = install_manage_groups(&mut builder);
+
+    let exec_request = create_group_request(GROUP_1_NAME, 1, [2; 32]);
+    builder.exec(exec_request).expect_success().commit();
+
+    // 257 would be truncated to 1 new URef if cast to a `u8`.
+    let exec_request = extend_group_request(
+        EXTEND_GROUP_UREFS,
+        GROUP_1_NAME,
+        runtime_args! { TOTAL_NEW_UREFS_ARG => u64::from(u8::max_value()) + 2 },
+        [3; 32],
+    );
+    builder.exec(exec_request).commit();
+
+    assert_eq!(last_revert_error(&builder), ApiError::InvalidArgument);
+    assert_eq!(group_urefs(&builder, package_hash).len(), 1);
+}
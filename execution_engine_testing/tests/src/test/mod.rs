@@ -4,12 +4,14 @@ mod contract_context;
 mod counter;
 mod deploy;
 mod explorer;
+mod fork;
 mod gas_counter;
 mod get_balance;
 mod groups;
 mod host_function_costs;
 mod manage_groups;
 mod regression;
+mod state_diff;
 mod step;
 mod storage_costs;
 mod system_contracts;
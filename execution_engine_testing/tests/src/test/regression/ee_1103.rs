@@ -4,8 +4,7 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, SYSTEM_ADDR,
-        TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS, SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
     },
     MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
@@ -147,8 +146,7 @@ fn validator_scores_should_reflect_delegates() {
         delegator_3_fund_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
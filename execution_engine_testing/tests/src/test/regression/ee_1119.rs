@@ -4,8 +4,7 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, SYSTEM_ADDR,
+        DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_GENESIS_TIMESTAMP_MILLIS, SYSTEM_ADDR,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
@@ -16,12 +15,9 @@ use casper_execution_engine::{
 use casper_types::{
     account::AccountHash,
     runtime_args,
-    system::{
-        auction::{
-            Bids, DelegationRate, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR,
-            ARG_VALIDATOR_PUBLIC_KEYS, METHOD_SLASH,
-        },
-        mint::TOTAL_SUPPLY_KEY,
+    system::auction::{
+        Bids, DelegationRate, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR,
+        ARG_VALIDATOR_PUBLIC_KEYS, METHOD_SLASH,
     },
     PublicKey, RuntimeArgs, SecretKey, U512,
 };
@@ -119,10 +115,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
     //
     // Unlock funds of genesis validators
     //
-    builder.run_auction(
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        Vec::new(),
-    );
+    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
 
     //
     // Partial unbond through undelegate on other genesis validator
@@ -218,8 +211,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
     //
     // Slash - only `withdraw_bid` amount is slashed
     //
-    let total_supply_before_slashing: U512 =
-        builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
+    let total_supply_before_slashing: U512 = builder.get_total_supply();
 
     let slash_request_2 = ExecuteRequestBuilder::contract_call_by_hash(
         *SYSTEM_ADDR,
@@ -245,8 +237,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
     assert!(validator_1_bid.inactive());
     assert!(validator_1_bid.staked_amount().is_zero());
 
-    let total_supply_after_slashing: U512 =
-        builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
+    let total_supply_after_slashing: U512 = builder.get_total_supply();
     assert_eq!(
         total_supply_before_slashing - total_supply_after_slashing,
         U512::from(VALIDATOR_1_STAKE + UNDELEGATE_AMOUNT_1),
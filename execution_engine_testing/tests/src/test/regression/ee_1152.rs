@@ -4,8 +4,7 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, StepRequestBuilder,
-        DEFAULT_ACCOUNTS, DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_ACCOUNTS, DEFAULT_GENESIS_TIMESTAMP_MILLIS, TIMESTAMP_MILLIS_INCREMENT,
     },
     AccountHash, DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
     MINIMUM_ACCOUNT_CREATION_BALANCE,
@@ -116,8 +115,7 @@ fn should_run_ee_1152_regression_test() {
 
     builder.exec(delegate_request_1).expect_success().commit();
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     // In reality a step request is made, but to simplify the test I'm just calling the auction part
     // only.
@@ -18,7 +18,12 @@ use casper_execution_engine::{
         host_function_costs::HostFunctionCosts,
         opcode_costs::OpcodeCosts,
         storage_costs::StorageCosts,
-        wasm_config::{WasmConfig, DEFAULT_MAX_STACK_HEIGHT, DEFAULT_WASM_MAX_MEMORY},
+        wasm_config::{
+            WasmConfig, DEFAULT_MAX_ACTION_THRESHOLD, DEFAULT_MAX_ASSOCIATED_KEYS,
+            DEFAULT_MAX_CL_VALUE_SIZE, DEFAULT_MAX_CONTRACT_WASM_SIZE,
+            DEFAULT_MAX_NAMED_KEYS_COUNT, DEFAULT_MAX_NAMED_KEY_LENGTH, DEFAULT_MAX_STACK_HEIGHT,
+            DEFAULT_WASM_MAX_MEMORY,
+        },
     },
 };
 use casper_types::{
@@ -33,6 +38,12 @@ static DOUBLED_WASM_MEMORY_LIMIT: Lazy<WasmConfig> = Lazy::new(|| {
     WasmConfig::new(
         DEFAULT_WASM_MAX_MEMORY * 2,
         DEFAULT_MAX_STACK_HEIGHT,
+        DEFAULT_MAX_NAMED_KEY_LENGTH,
+        DEFAULT_MAX_CL_VALUE_SIZE,
+        DEFAULT_MAX_NAMED_KEYS_COUNT,
+        DEFAULT_MAX_ASSOCIATED_KEYS,
+        DEFAULT_MAX_ACTION_THRESHOLD,
+        DEFAULT_MAX_CONTRACT_WASM_SIZE,
         OpcodeCosts::default(),
         StorageCosts::default(),
         HostFunctionCosts::default(),
@@ -0,0 +1,87 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::KeyChange;
+use casper_types::{
+    account::AccountHash, runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+
+static ALICE_KEY: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static ALICE_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*ALICE_KEY));
+
+static TRANSFER_AMOUNT: Lazy<U512> = Lazy::new(|| U512::from(100_000_000));
+
+#[ignore]
+#[test]
+fn should_diff_state_roots() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let pre_state_hash = builder.get_post_state_hash();
+
+    assert!(builder
+        .diff_state_roots(pre_state_hash, pre_state_hash)
+        .is_empty());
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => *ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => *TRANSFER_AMOUNT,
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit().expect_success();
+    let post_state_hash = builder.get_post_state_hash();
+
+    let changes = builder.diff_state_roots(pre_state_hash, post_state_hash);
+    assert!(
+        changes.windows(2).all(|pair| pair[0].key() < pair[1].key()),
+        "changes should be ordered by key"
+    );
+
+    let alice_account_key = Key::Account(*ALICE_ADDR);
+    match changes
+        .iter()
+        .find(|change| *change.key() == alice_account_key)
+    {
+        Some(KeyChange::Added { new, .. }) => assert_eq!(new.type_name(), "Account"),
+        other => panic!("expected Alice's account to be added but got {:?}", other),
+    }
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+    let default_balance_key = Key::Balance(default_account.main_purse().addr());
+    match changes
+        .iter()
+        .find(|change| *change.key() == default_balance_key)
+    {
+        Some(KeyChange::Modified { old, new, .. }) => {
+            assert_eq!(old.type_name(), new.type_name());
+            assert_ne!(old.value_hash(), new.value_hash());
+        }
+        other => panic!(
+            "expected default balance to be modified but got {:?}",
+            other
+        ),
+    }
+
+    let reverse_changes = builder.diff_state_roots(post_state_hash, pre_state_hash);
+    assert_eq!(reverse_changes.len(), changes.len());
+    match reverse_changes
+        .iter()
+        .find(|change| *change.key() == alice_account_key)
+    {
+        Some(KeyChange::Removed { old, .. }) => assert_eq!(old.type_name(), "Account"),
+        other => panic!("expected Alice's account to be removed but got {:?}", other),
+    }
+}
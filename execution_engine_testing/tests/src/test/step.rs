@@ -1,5 +1,3 @@
-use std::convert::TryFrom;
-
 use num_traits::Zero;
 use once_cell::sync::Lazy;
 
@@ -15,14 +13,8 @@ use casper_execution_engine::{
     storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
-    system::{
-        auction::{
-            Bids, DelegationRate, SeigniorageRecipientsSnapshot, BLOCK_REWARD,
-            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
-        },
-        mint::TOTAL_SUPPLY_KEY,
-    },
-    CLValue, ContractHash, Key, ProtocolVersion, PublicKey, SecretKey, U512,
+    system::auction::{Bids, DelegationRate, SeigniorageRecipientsSnapshot, BLOCK_REWARD},
+    ProtocolVersion, PublicKey, SecretKey,
 };
 
 static ACCOUNT_1_PK: Lazy<PublicKey> =
@@ -35,19 +27,6 @@ static ACCOUNT_2_PK: Lazy<PublicKey> =
 const ACCOUNT_2_BALANCE: u64 = 200_000_000;
 const ACCOUNT_2_BOND: u64 = 200_000_000;
 
-fn get_named_key(
-    builder: &mut InMemoryWasmTestBuilder,
-    contract_hash: ContractHash,
-    name: &str,
-) -> Key {
-    *builder
-        .get_contract(contract_hash)
-        .expect("should have contract")
-        .named_keys()
-        .get(name)
-        .expect("should have bid purses")
-}
-
 fn initialize_builder() -> WasmTestBuilder<InMemoryGlobalState> {
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -93,10 +72,8 @@ fn should_step() {
         .with_next_era_id(1)
         .build();
 
-    let auction_hash = builder.get_auction_contract_hash();
-
     let before_auction_seigniorage: SeigniorageRecipientsSnapshot =
-        builder.get_value(auction_hash, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY);
+        builder.get_seigniorage_recipients_snapshot();
 
     let bids_before_slashing: Bids = builder.get_bids();
     assert!(
@@ -118,6 +95,7 @@ fn should_step() {
     let account_1_bid = bids_after_slashing.get(&ACCOUNT_1_PK).unwrap();
     assert!(account_1_bid.inactive());
     assert!(account_1_bid.staked_amount().is_zero());
+    builder.assert_staking_stats_consistent();
 
     let bids_after_slashing: Bids = builder.get_bids();
     assert_ne!(
@@ -127,7 +105,7 @@ fn should_step() {
 
     // seigniorage snapshot should have changed after auction
     let after_auction_seigniorage: SeigniorageRecipientsSnapshot =
-        builder.get_value(auction_hash, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY);
+        builder.get_seigniorage_recipients_snapshot();
     assert!(
         !before_auction_seigniorage
             .keys()
@@ -141,23 +119,9 @@ fn should_step() {
 #[test]
 fn should_adjust_total_supply() {
     let mut builder = initialize_builder();
-    let maybe_post_state_hash = Some(builder.get_post_state_hash());
-
-    let mint_hash = builder.get_mint_contract_hash();
 
     // should check total supply before step
-    let total_supply_key = get_named_key(&mut builder, mint_hash, TOTAL_SUPPLY_KEY)
-        .into_uref()
-        .expect("should be uref");
-
-    let starting_total_supply = CLValue::try_from(
-        builder
-            .query(maybe_post_state_hash, total_supply_key.into(), &[])
-            .expect("should have total supply"),
-    )
-    .expect("should be a CLValue")
-    .into_t::<U512>()
-    .expect("should be U512");
+    let starting_total_supply = builder.get_total_supply();
 
     // slash
     let step_request = StepRequestBuilder::new()
@@ -171,17 +135,9 @@ fn should_adjust_total_supply() {
         .build();
 
     builder.step(step_request);
-    let maybe_post_state_hash = Some(builder.get_post_state_hash());
 
     // should check total supply after step
-    let modified_total_supply = CLValue::try_from(
-        builder
-            .query(maybe_post_state_hash, total_supply_key.into(), &[])
-            .expect("should have total supply"),
-    )
-    .expect("should be a CLValue")
-    .into_t::<U512>()
-    .expect("should be U512");
+    let modified_total_supply = builder.get_total_supply();
 
     assert!(
         modified_total_supply < starting_total_supply,
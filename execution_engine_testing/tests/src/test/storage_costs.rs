@@ -18,7 +18,12 @@ use casper_execution_engine::{
         opcode_costs::OpcodeCosts,
         storage_costs::StorageCosts,
         stored_value::StoredValue,
-        wasm_config::{WasmConfig, DEFAULT_MAX_STACK_HEIGHT, DEFAULT_WASM_MAX_MEMORY},
+        wasm_config::{
+            WasmConfig, DEFAULT_MAX_ACTION_THRESHOLD, DEFAULT_MAX_ASSOCIATED_KEYS,
+            DEFAULT_MAX_CL_VALUE_SIZE, DEFAULT_MAX_CONTRACT_WASM_SIZE,
+            DEFAULT_MAX_NAMED_KEYS_COUNT, DEFAULT_MAX_NAMED_KEY_LENGTH, DEFAULT_MAX_STACK_HEIGHT,
+            DEFAULT_WASM_MAX_MEMORY,
+        },
     },
 };
 use casper_types::{
@@ -126,11 +131,23 @@ static NEW_HOST_FUNCTION_COSTS: Lazy<HostFunctionCosts> = Lazy::new(|| HostFunct
     remove_contract_user_group_urefs: HostFunction::fixed(0),
     print: HostFunction::fixed(0),
     blake2b: HostFunction::fixed(0),
+    lock_contract_package: HostFunction::fixed(0),
+    emit_event: HostFunction::fixed(0),
+    call_contract_with_gas_limit: HostFunction::fixed(0),
+    call_versioned_contract_with_gas_limit: HostFunction::fixed(0),
+    transfer_from_purse_to_account_with_receipt: HostFunction::fixed(0),
+    provision_contract_user_group_urefs: HostFunction::fixed(0),
 });
 static STORAGE_COSTS_ONLY: Lazy<WasmConfig> = Lazy::new(|| {
     WasmConfig::new(
         DEFAULT_WASM_MAX_MEMORY,
         DEFAULT_MAX_STACK_HEIGHT,
+        DEFAULT_MAX_NAMED_KEY_LENGTH,
+        DEFAULT_MAX_CL_VALUE_SIZE,
+        DEFAULT_MAX_NAMED_KEYS_COUNT,
+        DEFAULT_MAX_ASSOCIATED_KEYS,
+        DEFAULT_MAX_ACTION_THRESHOLD,
+        DEFAULT_MAX_CONTRACT_WASM_SIZE,
         NEW_OPCODE_COSTS,
         StorageCosts::default(),
         *NEW_HOST_FUNCTION_COSTS,
@@ -1,15 +1,20 @@
-use std::{collections::BTreeSet, iter::FromIterator};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter::FromIterator,
+};
 
-use assert_matches::assert_matches;
 use num_traits::{One, Zero};
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
-        SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_MAX_DELEGATION_RATE, DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG, SYSTEM_ADDR,
+        TIMESTAMP_MILLIS_INCREMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
@@ -17,7 +22,8 @@ use casper_execution_engine::{
     core::{
         engine_state::{
             self,
-            genesis::{GenesisAccount, GenesisValidator},
+            genesis::{ExecConfig, GenesisAccount, GenesisValidator, GenesisVesting},
+            run_genesis_request::RunGenesisRequest,
         },
         execution,
     },
@@ -27,16 +33,18 @@ use casper_types::{
     self,
     account::AccountHash,
     api_error::ApiError,
+    bytesrepr::{FromBytes, ToBytes},
     runtime_args,
     system::{
         self,
         auction::{
-            self, Bids, DelegationRate, EraId, EraValidators, UnbondingPurses, ValidatorWeights,
-            ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_VALIDATOR,
-            ERA_ID_KEY, INITIAL_ERA_ID,
+            self, Bid, Bids, DelegationRate, Delegator, EraId, EraValidators, UnbondingPurses,
+            ValidatorInfo, ValidatorWeights, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR,
+            ARG_ICON_HASH, ARG_NAME, ARG_PUBLIC_KEY, ARG_URL, ARG_VALIDATOR, INITIAL_ERA_ID,
+            MAX_VALIDATOR_NAME_LENGTH,
         },
     },
-    PublicKey, RuntimeArgs, SecretKey, U512,
+    CLTyped, CLValue, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 
 const ARG_TARGET: &str = "target";
@@ -47,6 +55,13 @@ const CONTRACT_ADD_BID: &str = "add_bid.wasm";
 const CONTRACT_WITHDRAW_BID: &str = "withdraw_bid.wasm";
 const CONTRACT_DELEGATE: &str = "delegate.wasm";
 const CONTRACT_UNDELEGATE: &str = "undelegate.wasm";
+const CONTRACT_CANCEL_VALIDATOR_UNBOND: &str = "cancel_validator_unbond.wasm";
+const CONTRACT_CANCEL_DELEGATOR_UNBOND: &str = "cancel_delegator_unbond.wasm";
+const CONTRACT_AUCTION_BIDS: &str = "auction_bids.wasm";
+const CONTRACT_SET_VALIDATOR_INFO: &str = "set_validator_info.wasm";
+
+const ARG_ENTRY_POINT: &str = "entry_point";
+const RESULT_KEY: &str = "result";
 
 const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE + 1000;
 
@@ -114,26 +129,6 @@ const DELEGATOR_2_BALANCE: u64 = DEFAULT_ACCOUNT_INITIAL_BALANCE;
 
 const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 0;
 
-const EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS: u64 =
-    DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
-
-const WEEK_TIMESTAMPS: [u64; 14] = [
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS,
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + WEEK_MILLIS,
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 2),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 3),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 4),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 5),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 6),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 7),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 8),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 9),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 10),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 11),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 12),
-    EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS + (WEEK_MILLIS * 13),
-];
-
 #[ignore]
 #[test]
 fn should_run_add_bid() {
@@ -167,6 +162,7 @@ fn should_run_add_bid() {
 
     builder.exec(exec_request_1).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
@@ -192,6 +188,7 @@ fn should_run_add_bid() {
 
     builder.exec(exec_request_2).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
@@ -215,6 +212,7 @@ fn should_run_add_bid() {
     .build();
     builder.exec(exec_request_3).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
@@ -230,6 +228,10 @@ fn should_run_add_bid() {
         .get(&BID_ACCOUNT_1_ADDR)
         .expect("should have unbond");
     assert_eq!(unbond_list.len(), 1);
+    assert_eq!(
+        &builder.get_unbonding_purses(*BID_ACCOUNT_1_ADDR),
+        unbond_list
+    );
     assert_eq!(unbond_list[0].unbonder_public_key(), &*BID_ACCOUNT_1_PK);
     assert_eq!(unbond_list[0].validator_public_key(), &*BID_ACCOUNT_1_PK);
     // `WITHDRAW_BID_AMOUNT_2` is in unbonding list
@@ -297,6 +299,7 @@ fn should_run_delegate_and_undelegate() {
 
     let auction_hash = builder.get_auction_contract_hash();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let active_bid = bids.get(&NON_FOUNDER_VALIDATOR_1_PK).unwrap();
@@ -327,6 +330,7 @@ fn should_run_delegate_and_undelegate() {
 
     builder.exec(exec_request_1).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
@@ -348,6 +352,7 @@ fn should_run_delegate_and_undelegate() {
 
     builder.exec(exec_request_2).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
@@ -370,6 +375,7 @@ fn should_run_delegate_and_undelegate() {
     .build();
     builder.exec(exec_request_3).commit().expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
@@ -458,7 +464,6 @@ fn should_calculate_era_validators() {
     )
     .build();
 
-    let auction_hash = builder.get_auction_contract_hash();
     let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 2, "founding validators {:?}", bids);
 
@@ -491,15 +496,12 @@ fn should_calculate_era_validators() {
 
     builder.exec(add_bid_request_1).commit().expect_success();
 
-    let pre_era_id: EraId = builder.get_value(auction_hash, ERA_ID_KEY);
+    let pre_era_id: EraId = builder.get_era();
     assert_eq!(pre_era_id, 0);
 
-    builder.run_auction(
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        Vec::new(),
-    );
+    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
 
-    let post_era_id: EraId = builder.get_value(auction_hash, ERA_ID_KEY);
+    let post_era_id: EraId = builder.get_era();
     assert_eq!(post_era_id, 1);
 
     let era_validators: EraValidators = builder.get_era_validators();
@@ -606,28 +608,15 @@ fn should_get_first_seigniorage_recipients() {
     assert_eq!(bids.len(), 2);
 
     let founding_validator_1 = bids.get(&ACCOUNT_1_PK).expect("should have account 1 pk");
-    assert_eq!(
-        founding_validator_1
-            .vesting_schedule()
-            .map(|vesting_schedule| vesting_schedule.initial_release_timestamp_millis()),
-        Some(DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS)
-    );
+    assert!(founding_validator_1.vesting_schedule().is_none());
 
     let founding_validator_2 = bids.get(&ACCOUNT_2_PK).expect("should have account 2 pk");
-    assert_eq!(
-        founding_validator_2
-            .vesting_schedule()
-            .map(|vesting_schedule| vesting_schedule.initial_release_timestamp_millis()),
-        Some(DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS)
-    );
+    assert!(founding_validator_2.vesting_schedule().is_none());
 
     builder.exec(transfer_request_1).commit().expect_success();
 
     // run_auction should be executed first
-    builder.run_auction(
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        Vec::new(),
-    );
+    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
 
     let mut era_validators: EraValidators = builder.get_era_validators();
     let snapshot_size = DEFAULT_AUCTION_DELAY as usize + 1;
@@ -663,24 +652,14 @@ fn should_get_first_seigniorage_recipients() {
 
 #[ignore]
 #[test]
-fn should_release_founder_stake() {
-    // ACCOUNT_1_BOND / 14 = 7_142
-    const EXPECTED_WEEKLY_RELEASE: u64 = 7_142;
-
-    const EXPECTED_REMAINDER: u64 = 12;
-
-    const EXPECTED_LOCKED_AMOUNTS: [u64; 14] = [
-        92858, 85716, 78574, 71432, 64290, 57148, 50006, 42864, 35722, 28580, 21438, 14296, 7154, 0,
-    ];
-
-    let expected_locked_amounts: Vec<U512> = EXPECTED_LOCKED_AMOUNTS
-        .iter()
-        .cloned()
-        .map(U512::from)
-        .collect();
-
-    let expect_unbond_success = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
-        let partial_unbond = ExecuteRequestBuilder::standard(
+fn should_release_founder_stake_according_to_era_vesting_schedule() {
+    const CLIFF_ERAS: u64 = 2;
+    const RELEASE_ERAS: u64 = 4;
+    // ACCOUNT_1_BOND / RELEASE_ERAS
+    const EXPECTED_ERA_RELEASE: u64 = 25_000;
+
+    let withdraw_bid = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
+        let request = ExecuteRequestBuilder::standard(
             *ACCOUNT_1_ADDR,
             CONTRACT_WITHDRAW_BID,
             runtime_args! {
@@ -689,35 +668,21 @@ fn should_release_founder_stake() {
             },
         )
         .build();
-
-        builder.exec(partial_unbond).commit().expect_success();
+        builder.exec(request).commit();
     };
 
-    let expect_unbond_failure = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
-        let full_unbond = ExecuteRequestBuilder::standard(
-            *ACCOUNT_1_ADDR,
-            CONTRACT_WITHDRAW_BID,
-            runtime_args! {
-                ARG_PUBLIC_KEY => *ACCOUNT_1_PK,
-                ARG_AMOUNT => U512::from(amount),
-            },
-        )
-        .build();
-
-        builder.exec(full_unbond).commit();
-
-        let error = {
-            let response = builder
-                .get_exec_results()
-                .last()
-                .expect("should have last exec result");
-            let exec_response = response.last().expect("should have response");
-            exec_response.as_error().expect("should have error")
-        };
-        assert_matches!(
+    let expect_funds_locked = |builder: &mut InMemoryWasmTestBuilder| {
+        let response = builder
+            .get_exec_results()
+            .last()
+            .expect("should have last exec result");
+        let exec_response = response.last().expect("should have response");
+        let error = exec_response.as_error().expect("should have error");
+        assert!(matches!(
             error,
-            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(15)))
-        );
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if *auction_error == system::auction::Error::ValidatorFundsLocked as u8
+        ));
     };
 
     let accounts = {
@@ -725,10 +690,10 @@ fn should_release_founder_stake() {
         let account_1 = GenesisAccount::account(
             *ACCOUNT_1_PK,
             Motes::new(ACCOUNT_1_BALANCE.into()),
-            Some(GenesisValidator::new(
-                Motes::new(ACCOUNT_1_BOND.into()),
-                DelegationRate::zero(),
-            )),
+            Some(
+                GenesisValidator::new(Motes::new(ACCOUNT_1_BOND.into()), DelegationRate::zero())
+                    .with_vesting(GenesisVesting::new(CLIFF_ERAS, RELEASE_ERAS)),
+            ),
         );
         tmp.push(account_1);
         tmp
@@ -752,99 +717,46 @@ fn should_release_founder_stake() {
 
     builder.exec(fund_system_account).commit().expect_success();
 
-    // Check bid and its vesting schedule
     {
         let bids: Bids = builder.get_bids();
-        assert_eq!(bids.len(), 1);
-
         let entry = bids.get(&ACCOUNT_1_PK).unwrap();
-        let vesting_schedule = entry.vesting_schedule().unwrap();
-
-        let initial_release = vesting_schedule.initial_release_timestamp_millis();
-        assert_eq!(initial_release, EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS);
-
-        let locked_amounts = vesting_schedule.locked_amounts().map(|arr| arr.to_vec());
-        assert!(locked_amounts.is_none());
-    }
-
-    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
+        assert!(entry.vesting_schedule().is_none());
 
-    {
-        // Attempt unbond of one mote
-        expect_unbond_failure(&mut builder, u64::one());
+        let era_vesting_schedule = entry.era_vesting_schedule().unwrap();
+        assert_eq!(era_vesting_schedule.cliff_eras(), CLIFF_ERAS);
+        assert_eq!(era_vesting_schedule.release_eras(), RELEASE_ERAS);
+        assert_eq!(
+            era_vesting_schedule.initial_locked_amount(),
+            U512::from(ACCOUNT_1_BOND)
+        );
     }
 
-    builder.run_auction(WEEK_TIMESTAMPS[0], Vec::new());
-
-    // Check bid and its vesting schedule
-    {
-        let bids: Bids = builder.get_bids();
-        assert_eq!(bids.len(), 1);
-
-        let entry = bids.get(&ACCOUNT_1_PK).unwrap();
-        let vesting_schedule = entry.vesting_schedule().unwrap();
-
-        let initial_release = vesting_schedule.initial_release_timestamp_millis();
-        assert_eq!(initial_release, EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS);
+    // Everything is locked until the cliff.
+    for _ in 0..CLIFF_ERAS {
+        withdraw_bid(&mut builder, u64::one());
+        expect_funds_locked(&mut builder);
 
-        let locked_amounts = vesting_schedule.locked_amounts().map(|arr| arr.to_vec());
-        assert_eq!(locked_amounts, Some(expected_locked_amounts));
+        builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     }
 
     let mut total_unbonded = 0;
 
-    {
-        // Attempt full unbond
-        expect_unbond_failure(&mut builder, ACCOUNT_1_BOND);
-
-        // Attempt unbond of released amount
-        expect_unbond_success(&mut builder, EXPECTED_WEEKLY_RELEASE);
-
-        total_unbonded += EXPECTED_WEEKLY_RELEASE;
-
-        assert_eq!(ACCOUNT_1_BOND - total_unbonded, EXPECTED_LOCKED_AMOUNTS[0])
-    }
-
-    for i in 1..13 {
-        // Run auction forward by almost a week
-        builder.run_auction(WEEK_TIMESTAMPS[i] - 1, Vec::new());
-
-        // Attempt unbond of 1 mote
-        expect_unbond_failure(&mut builder, u64::one());
-
-        // Run auction forward by one millisecond
-        builder.run_auction(WEEK_TIMESTAMPS[i], Vec::new());
-
-        // Attempt unbond of more than weekly release
-        expect_unbond_failure(&mut builder, EXPECTED_WEEKLY_RELEASE + 1);
-
-        // Attempt unbond of released amount
-        expect_unbond_success(&mut builder, EXPECTED_WEEKLY_RELEASE);
-
-        total_unbonded += EXPECTED_WEEKLY_RELEASE;
-
-        assert_eq!(ACCOUNT_1_BOND - total_unbonded, EXPECTED_LOCKED_AMOUNTS[i])
-    }
-
-    {
-        // Run auction forward by almost a week
-        builder.run_auction(WEEK_TIMESTAMPS[13] - 1, Vec::new());
-
-        // Attempt unbond of 1 mote
-        expect_unbond_failure(&mut builder, u64::one());
+    for _ in 0..RELEASE_ERAS - 1 {
+        withdraw_bid(&mut builder, EXPECTED_ERA_RELEASE + 1);
+        expect_funds_locked(&mut builder);
 
-        // Run auction forward by one millisecond
-        builder.run_auction(WEEK_TIMESTAMPS[13], Vec::new());
+        withdraw_bid(&mut builder, EXPECTED_ERA_RELEASE);
+        builder.expect_success();
+        total_unbonded += EXPECTED_ERA_RELEASE;
 
-        // Attempt unbond of released amount + remainder
-        expect_unbond_success(&mut builder, EXPECTED_WEEKLY_RELEASE + EXPECTED_REMAINDER);
-
-        total_unbonded += EXPECTED_WEEKLY_RELEASE + EXPECTED_REMAINDER;
-
-        assert_eq!(ACCOUNT_1_BOND - total_unbonded, EXPECTED_LOCKED_AMOUNTS[13])
+        builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     }
 
-    assert_eq!(ACCOUNT_1_BOND, total_unbonded);
+    // The last installment releases the remaining stake.
+    let remaining_stake = ACCOUNT_1_BOND - total_unbonded;
+    assert_eq!(remaining_stake, EXPECTED_ERA_RELEASE);
+    withdraw_bid(&mut builder, remaining_stake);
+    builder.expect_success();
 }
 
 #[ignore]
@@ -1023,10 +935,7 @@ fn should_calculate_era_validators_multiple_new_bids() {
     builder.exec(add_bid_request_2).commit().expect_success();
 
     // run auction and compute validators for new era
-    builder.run_auction(
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        Vec::new(),
-    );
+    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     // Verify first era validators
     let new_validator_weights: ValidatorWeights = builder
         .get_validator_weights(new_era)
@@ -1121,8 +1030,7 @@ fn undelegated_funds_should_be_released() {
         delegator_1_validator_1_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1245,8 +1153,7 @@ fn fully_undelegated_funds_should_be_released() {
         delegator_1_validator_1_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1404,8 +1311,7 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         validator_1_partial_withdraw_bid,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1420,6 +1326,7 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
     }
 
+    builder.assert_staking_stats_consistent();
     let bids_before: Bids = builder.get_bids();
     let validator_1_bid = bids_before
         .get(&*VALIDATOR_1)
@@ -1456,6 +1363,7 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         .commit()
         .expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids_after: Bids = builder.get_bids();
     let validator_1_bid = bids_after.get(&VALIDATOR_1).unwrap();
     assert!(validator_1_bid.inactive());
@@ -1641,8 +1549,7 @@ fn should_undelegate_delegators_when_validator_fully_unbonds() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1668,6 +1575,7 @@ fn should_undelegate_delegators_when_validator_fully_unbonds() {
         .commit()
         .expect_success();
 
+    builder.assert_staking_stats_consistent();
     let bids_after: Bids = builder.get_bids();
     let validator_1_bid = bids_after.get(&VALIDATOR_1).unwrap();
     assert!(validator_1_bid.inactive());
@@ -2122,6 +2030,7 @@ fn should_setup_genesis_delegators() {
         U512::from(DELEGATOR_1_BALANCE)
     );
 
+    builder.assert_staking_stats_consistent();
     let bids: Bids = builder.get_bids();
     assert_eq!(
         bids.keys().cloned().collect::<BTreeSet<_>>(),
@@ -2144,16 +2053,16 @@ fn should_setup_genesis_delegators() {
 
 #[ignore]
 #[test]
-fn should_not_partially_undelegate_uninitialized_vesting_schedule() {
+fn should_not_partially_undelegate_before_vesting_cliff() {
     let accounts = {
         let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
         let validator_1 = GenesisAccount::account(
             *VALIDATOR_1,
             Motes::new(VALIDATOR_1_STAKE.into()),
-            Some(GenesisValidator::new(
-                Motes::new(VALIDATOR_1_STAKE.into()),
-                DelegationRate::zero(),
-            )),
+            Some(
+                GenesisValidator::new(Motes::new(VALIDATOR_1_STAKE.into()), DelegationRate::zero())
+                    .with_vesting(GenesisVesting::new(1, 1)),
+            ),
         );
         let delegator_1 = GenesisAccount::delegator(
             *VALIDATOR_1,
@@ -2216,16 +2125,16 @@ fn should_not_partially_undelegate_uninitialized_vesting_schedule() {
 
 #[ignore]
 #[test]
-fn should_not_fully_undelegate_uninitialized_vesting_schedule() {
+fn should_not_fully_undelegate_before_vesting_cliff() {
     let accounts = {
         let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
         let validator_1 = GenesisAccount::account(
             *VALIDATOR_1,
             Motes::new(VALIDATOR_1_STAKE.into()),
-            Some(GenesisValidator::new(
-                Motes::new(VALIDATOR_1_STAKE.into()),
-                DelegationRate::zero(),
-            )),
+            Some(
+                GenesisValidator::new(Motes::new(VALIDATOR_1_STAKE.into()), DelegationRate::zero())
+                    .with_vesting(GenesisVesting::new(1, 1)),
+            ),
         );
         let delegator_1 = GenesisAccount::delegator(
             *VALIDATOR_1,
@@ -2288,140 +2197,14 @@ fn should_not_fully_undelegate_uninitialized_vesting_schedule() {
 
 #[ignore]
 #[test]
-fn should_not_undelegate_vfta_holder_stake() {
-    let accounts = {
-        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
-        let validator_1 = GenesisAccount::account(
-            *VALIDATOR_1,
-            Motes::new(VALIDATOR_1_STAKE.into()),
-            Some(GenesisValidator::new(
-                Motes::new(VALIDATOR_1_STAKE.into()),
-                DelegationRate::zero(),
-            )),
-        );
-        let delegator_1 = GenesisAccount::delegator(
-            *VALIDATOR_1,
-            *DELEGATOR_1,
-            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
-            Motes::new(DELEGATOR_1_STAKE.into()),
-        );
-        tmp.push(validator_1);
-        tmp.push(delegator_1);
-        tmp
-    };
-
-    let run_genesis_request = utils::create_run_genesis_request(accounts);
-
-    let mut builder = InMemoryWasmTestBuilder::default();
-
-    builder.run_genesis(&run_genesis_request);
-
-    let post_genesis_requests = {
-        let fund_delegator_account = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            CONTRACT_TRANSFER_TO_ACCOUNT,
-            runtime_args! {
-                ARG_TARGET => *DELEGATOR_1_ADDR,
-                ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE)
-            },
-        )
-        .build();
-
-        let fund_system_account = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            CONTRACT_TRANSFER_TO_ACCOUNT,
-            runtime_args! {
-                ARG_TARGET => *SYSTEM_ADDR,
-                ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE)
-            },
-        )
-        .build();
-
-        vec![fund_system_account, fund_delegator_account]
-    };
-
-    for post_genesis_request in post_genesis_requests {
-        builder.exec(post_genesis_request).commit().expect_success();
-    }
-
-    {
-        let bids: Bids = builder.get_bids();
-        let delegator = bids
-            .get(&*VALIDATOR_1)
-            .expect("should have validator")
-            .delegators()
-            .get(&*DELEGATOR_1)
-            .expect("should have delegator");
-        let vesting_schedule = delegator
-            .vesting_schedule()
-            .expect("should have vesting schedule");
-        assert_eq!(vesting_schedule.locked_amounts(), None);
-    }
-
-    builder.run_auction(WEEK_TIMESTAMPS[0], Vec::new());
-
-    let partial_unbond = ExecuteRequestBuilder::standard(
-        *DELEGATOR_1_ADDR,
-        CONTRACT_UNDELEGATE,
-        runtime_args! {
-            auction::ARG_VALIDATOR => *VALIDATOR_1,
-            auction::ARG_DELEGATOR => *DELEGATOR_1,
-            ARG_AMOUNT => U512::from(DELEGATOR_1_STAKE - 1),
-        },
-    )
-    .build();
-
-    {
-        let bids: Bids = builder.get_bids();
-        let delegator = bids
-            .get(&*VALIDATOR_1)
-            .expect("should have validator")
-            .delegators()
-            .get(&*DELEGATOR_1)
-            .expect("should have delegator");
-        let vesting_schedule = delegator
-            .vesting_schedule()
-            .expect("should have vesting schedule");
-        assert!(matches!(vesting_schedule.locked_amounts(), Some(_)));
-    }
-
-    builder.exec(partial_unbond).commit();
-    let error = {
-        let response = builder
-            .get_exec_results()
-            .last()
-            .expect("should have last exec result");
-        let exec_response = response.last().expect("should have response");
-        exec_response.as_error().expect("should have error")
-    };
-
-    assert!(matches!(
-        error,
-        engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
-        if *auction_error == system::auction::Error::DelegatorFundsLocked as u8
-    ));
-}
-
-#[ignore]
-#[test]
-fn should_release_vfta_holder_stake() {
-    const EXPECTED_WEEKLY_RELEASE: u64 = DELEGATOR_1_STAKE / 14;
-
-    const EXPECTED_REMAINDER: u64 = 12;
-
-    const EXPECTED_LOCKED_AMOUNTS: [u64; 14] = [
-        1392858, 1285716, 1178574, 1071432, 964290, 857148, 750006, 642864, 535722, 428580, 321438,
-        214296, 107154, 0,
-    ];
-
-    let expected_locked_amounts: Vec<U512> = EXPECTED_LOCKED_AMOUNTS
-        .iter()
-        .cloned()
-        .map(U512::from)
-        .collect();
-
-    let expect_undelegate_success = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
-        let partial_unbond = ExecuteRequestBuilder::standard(
+fn should_release_genesis_delegator_stake_according_to_era_vesting_schedule() {
+    const CLIFF_ERAS: u64 = 2;
+    const RELEASE_ERAS: u64 = 4;
+    // DELEGATOR_1_STAKE / RELEASE_ERAS
+    const EXPECTED_ERA_RELEASE: u64 = 375_000;
+
+    let undelegate = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
+        let request = ExecuteRequestBuilder::standard(
             *DELEGATOR_1_ADDR,
             CONTRACT_UNDELEGATE,
             runtime_args! {
@@ -2431,42 +2214,21 @@ fn should_release_vfta_holder_stake() {
             },
         )
         .build();
-
-        builder.exec(partial_unbond).commit().expect_success();
+        builder.exec(request).commit();
     };
 
-    let expect_undelegate_failure = |builder: &mut InMemoryWasmTestBuilder, amount: u64| {
-        let full_undelegate = ExecuteRequestBuilder::standard(
-            *DELEGATOR_1_ADDR,
-            CONTRACT_UNDELEGATE,
-            runtime_args! {
-                auction::ARG_VALIDATOR => *ACCOUNT_1_PK,
-                auction::ARG_DELEGATOR => *DELEGATOR_1,
-                ARG_AMOUNT => U512::from(amount),
-            },
-        )
-        .build();
-
-        builder.exec(full_undelegate).commit();
-
-        let error = {
-            let response = builder
-                .get_exec_results()
-                .last()
-                .expect("should have last exec result");
-            let exec_response = response.last().expect("should have response");
-            exec_response.as_error().expect("should have error")
-        };
-
-        assert!(
-            matches!(
-                error,
-                engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
-                if *auction_error == system::auction::Error::DelegatorFundsLocked as u8
-            ),
-            "{:?}",
-            error
-        );
+    let expect_funds_locked = |builder: &mut InMemoryWasmTestBuilder| {
+        let response = builder
+            .get_exec_results()
+            .last()
+            .expect("should have last exec result");
+        let exec_response = response.last().expect("should have response");
+        let error = exec_response.as_error().expect("should have error");
+        assert!(matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if *auction_error == system::auction::Error::DelegatorFundsLocked as u8
+        ));
     };
 
     let accounts = {
@@ -2474,10 +2236,10 @@ fn should_release_vfta_holder_stake() {
         let account_1 = GenesisAccount::account(
             *ACCOUNT_1_PK,
             Motes::new(ACCOUNT_1_BALANCE.into()),
-            Some(GenesisValidator::new(
-                Motes::new(ACCOUNT_1_BOND.into()),
-                DelegationRate::zero(),
-            )),
+            Some(
+                GenesisValidator::new(Motes::new(ACCOUNT_1_BOND.into()), DelegationRate::zero())
+                    .with_vesting(GenesisVesting::new(CLIFF_ERAS, RELEASE_ERAS)),
+            ),
         );
         let delegator_1 = GenesisAccount::delegator(
             *ACCOUNT_1_PK,
@@ -2522,113 +2284,52 @@ fn should_release_vfta_holder_stake() {
 
     builder.exec(fund_system_account).commit().expect_success();
 
-    // Check bid and its vesting schedule
     {
         let bids: Bids = builder.get_bids();
-        assert_eq!(bids.len(), 1);
-
-        let bid_entry = bids.get(&ACCOUNT_1_PK).unwrap();
-        let entry = bid_entry.delegators().get(&*DELEGATOR_1).unwrap();
-
-        let vesting_schedule = entry.vesting_schedule().unwrap();
-
-        let initial_release = vesting_schedule.initial_release_timestamp_millis();
-        assert_eq!(initial_release, EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS);
-
-        let locked_amounts = vesting_schedule.locked_amounts().map(|arr| arr.to_vec());
-        assert!(locked_amounts.is_none());
-    }
-
-    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
+        let delegator = bids
+            .get(&ACCOUNT_1_PK)
+            .expect("should have validator")
+            .delegators()
+            .get(&*DELEGATOR_1)
+            .expect("should have delegator");
+        assert!(delegator.vesting_schedule().is_none());
 
-    {
-        // Attempt unbond of one mote
-        expect_undelegate_failure(&mut builder, u64::one());
+        let era_vesting_schedule = delegator.era_vesting_schedule().unwrap();
+        assert_eq!(era_vesting_schedule.cliff_eras(), CLIFF_ERAS);
+        assert_eq!(era_vesting_schedule.release_eras(), RELEASE_ERAS);
+        assert_eq!(
+            era_vesting_schedule.initial_locked_amount(),
+            U512::from(DELEGATOR_1_STAKE)
+        );
     }
 
-    builder.run_auction(WEEK_TIMESTAMPS[0], Vec::new());
-
-    // Check bid and its vesting schedule
-    {
-        let bids: Bids = builder.get_bids();
-        assert_eq!(bids.len(), 1);
-
-        let bid_entry = bids.get(&ACCOUNT_1_PK).unwrap();
-        let entry = bid_entry.delegators().get(&*DELEGATOR_1).unwrap();
-
-        let vesting_schedule = entry.vesting_schedule().unwrap();
+    // Everything is locked until the cliff.
+    for _ in 0..CLIFF_ERAS {
+        undelegate(&mut builder, u64::one());
+        expect_funds_locked(&mut builder);
 
-        let initial_release = vesting_schedule.initial_release_timestamp_millis();
-        assert_eq!(initial_release, EXPECTED_INITIAL_RELEASE_TIMESTAMP_MILLIS);
-
-        let locked_amounts = vesting_schedule.locked_amounts().map(|arr| arr.to_vec());
-        assert_eq!(locked_amounts, Some(expected_locked_amounts));
+        builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     }
 
-    let mut total_unbonded = 0;
-
-    {
-        // Attempt full unbond
-        expect_undelegate_failure(&mut builder, DELEGATOR_1_STAKE);
+    let mut total_undelegated = 0;
 
-        // Attempt unbond of released amount
-        expect_undelegate_success(&mut builder, EXPECTED_WEEKLY_RELEASE);
+    for _ in 0..RELEASE_ERAS - 1 {
+        undelegate(&mut builder, EXPECTED_ERA_RELEASE + 1);
+        expect_funds_locked(&mut builder);
 
-        total_unbonded += EXPECTED_WEEKLY_RELEASE;
+        undelegate(&mut builder, EXPECTED_ERA_RELEASE);
+        builder.expect_success();
+        total_undelegated += EXPECTED_ERA_RELEASE;
 
-        assert_eq!(
-            DELEGATOR_1_STAKE - total_unbonded,
-            EXPECTED_LOCKED_AMOUNTS[0]
-        )
+        builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     }
 
-    for i in 1..13 {
-        // Run auction forward by almost a week
-        builder.run_auction(WEEK_TIMESTAMPS[i] - 1, Vec::new());
-
-        // Attempt unbond of 1 mote
-        expect_undelegate_failure(&mut builder, u64::one());
-
-        // Run auction forward by one millisecond
-        builder.run_auction(WEEK_TIMESTAMPS[i], Vec::new());
-
-        // Attempt unbond of more than weekly release
-        expect_undelegate_failure(&mut builder, EXPECTED_WEEKLY_RELEASE + 1);
-
-        // Attempt unbond of released amount
-        expect_undelegate_success(&mut builder, EXPECTED_WEEKLY_RELEASE);
-
-        total_unbonded += EXPECTED_WEEKLY_RELEASE;
-
-        assert_eq!(
-            DELEGATOR_1_STAKE - total_unbonded,
-            EXPECTED_LOCKED_AMOUNTS[i]
-        )
-    }
-
-    {
-        // Run auction forward by almost a week
-        builder.run_auction(WEEK_TIMESTAMPS[13] - 1, Vec::new());
-
-        // Attempt unbond of 1 mote
-        expect_undelegate_failure(&mut builder, u64::one());
-
-        // Run auction forward by one millisecond
-        builder.run_auction(WEEK_TIMESTAMPS[13], Vec::new());
-
-        // Attempt unbond of released amount + remainder
-        expect_undelegate_success(&mut builder, EXPECTED_WEEKLY_RELEASE + EXPECTED_REMAINDER);
-
-        total_unbonded += EXPECTED_WEEKLY_RELEASE + EXPECTED_REMAINDER;
-
-        assert_eq!(
-            DELEGATOR_1_STAKE - total_unbonded,
-            EXPECTED_LOCKED_AMOUNTS[13]
-        )
-    }
-
-    assert_eq!(DELEGATOR_1_STAKE, total_unbonded);
-}
+    // The last installment releases the remaining stake.
+    let remaining_stake = DELEGATOR_1_STAKE - total_undelegated;
+    assert_eq!(remaining_stake, EXPECTED_ERA_RELEASE);
+    undelegate(&mut builder, remaining_stake);
+    builder.expect_success();
+}
 
 #[ignore]
 #[test]
@@ -2775,6 +2476,7 @@ fn should_reset_delegators_stake_after_slashing() {
 
     // Check bids before slashing
 
+    builder.assert_staking_stats_consistent();
     let bids_1: Bids = builder.get_bids();
 
     let validator_1_delegator_stakes_1: U512 = bids_1
@@ -2810,6 +2512,7 @@ fn should_reset_delegators_stake_after_slashing() {
     builder.exec(slash_request_1).expect_success().commit();
 
     // Compare bids after slashing validator 2
+    builder.assert_staking_stats_consistent();
     let bids_2: Bids = builder.get_bids();
     assert_ne!(bids_1, bids_2);
 
@@ -2857,6 +2560,7 @@ fn should_reset_delegators_stake_after_slashing() {
     builder.exec(slash_request_2).expect_success().commit();
 
     // Compare bids after slashing validator 2
+    builder.assert_staking_stats_consistent();
     let bids_3: Bids = builder.get_bids();
     assert_ne!(bids_3, bids_2);
     assert_ne!(bids_3, bids_1);
@@ -2920,3 +2624,1165 @@ fn should_validate_genesis_delegators_bond_amount() {
 
     builder.run_genesis(&run_genesis_request);
 }
+
+#[ignore]
+#[test]
+fn should_enforce_delegation_rate_bounds_and_cooldown() {
+    const MAX_DELEGATION_RATE: DelegationRate = 50;
+    const MAX_DELEGATION_RATE_CHANGE: DelegationRate = 5;
+
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            *BID_ACCOUNT_1_PK,
+            Motes::new(BID_ACCOUNT_1_BALANCE.into()),
+            None,
+        );
+        tmp.push(account_1);
+        tmp
+    };
+
+    let exec_config = ExecConfig::new(
+        accounts,
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        MAX_DELEGATION_RATE,
+        MAX_DELEGATION_RATE_CHANGE,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let add_bid_request = |delegation_rate: DelegationRate| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_ADD_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => *BID_ACCOUNT_1_PK,
+                ARG_AMOUNT => U512::from(BID_AMOUNT_2),
+                ARG_DELEGATION_RATE => delegation_rate,
+            },
+        )
+        .build()
+    };
+
+    let assert_auction_error = |builder: &mut InMemoryWasmTestBuilder, expected: auction::Error| {
+        let response = builder
+            .get_exec_results()
+            .last()
+            .expect("should have last exec result");
+        let exec_response = response.last().expect("should have response");
+        let error = exec_response.as_error().expect("should have error");
+        assert!(
+            matches!(
+                error,
+                engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+                if *auction_error == expected as u8
+            ),
+            "expected {:?}, got {:?}",
+            expected,
+            error
+        );
+    };
+
+    let delegation_rate = |builder: &mut InMemoryWasmTestBuilder| {
+        *builder
+            .get_bids()
+            .get(&*BID_ACCOUNT_1_PK)
+            .expect("should have bid")
+            .delegation_rate()
+    };
+
+    // The initial bid may not exceed the maximum rate, but is otherwise unrestricted.
+    builder
+        .exec(add_bid_request(MAX_DELEGATION_RATE + 1))
+        .commit();
+    assert_auction_error(&mut builder, auction::Error::DelegationRateTooLarge);
+
+    builder
+        .exec(add_bid_request(ADD_BID_DELEGATION_RATE_1))
+        .commit()
+        .expect_success();
+    assert_eq!(delegation_rate(&mut builder), ADD_BID_DELEGATION_RATE_1);
+
+    // A change larger than the allowed change per era is rejected.
+    builder
+        .exec(add_bid_request(
+            ADD_BID_DELEGATION_RATE_1 + MAX_DELEGATION_RATE_CHANGE + 1,
+        ))
+        .commit();
+    assert_auction_error(&mut builder, auction::Error::DelegationRateChangeTooLarge);
+
+    builder
+        .exec(add_bid_request(
+            ADD_BID_DELEGATION_RATE_1 + MAX_DELEGATION_RATE_CHANGE,
+        ))
+        .commit()
+        .expect_success();
+    assert_eq!(
+        delegation_rate(&mut builder),
+        ADD_BID_DELEGATION_RATE_1 + MAX_DELEGATION_RATE_CHANGE
+    );
+
+    // A second change within the same era is rejected, while topping up at the current rate is
+    // still allowed.
+    builder
+        .exec(add_bid_request(ADD_BID_DELEGATION_RATE_1))
+        .commit();
+    assert_auction_error(&mut builder, auction::Error::DelegationRateChangeCooldown);
+
+    builder
+        .exec(add_bid_request(
+            ADD_BID_DELEGATION_RATE_1 + MAX_DELEGATION_RATE_CHANGE,
+        ))
+        .commit()
+        .expect_success();
+
+    // Once the era advances, the rate may be changed again.
+    builder.run_auction(
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + TIMESTAMP_MILLIS_INCREMENT,
+        Vec::new(),
+    );
+
+    builder
+        .exec(add_bid_request(ADD_BID_DELEGATION_RATE_1))
+        .commit()
+        .expect_success();
+    assert_eq!(delegation_rate(&mut builder), ADD_BID_DELEGATION_RATE_1);
+}
+
+fn assert_last_auction_error(builder: &InMemoryWasmTestBuilder, expected: auction::Error) {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result");
+    let exec_response = response.last().expect("should have response");
+    let error = exec_response.as_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if *auction_error == expected as u8
+        ),
+        "expected {:?}, got {:?}",
+        expected,
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_cancel_validator_unbond() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            *BID_ACCOUNT_1_PK,
+            Motes::new(BID_ACCOUNT_1_BALANCE.into()),
+            None,
+        );
+        tmp.push(account_1);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *BID_ACCOUNT_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let withdraw_bid_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_WITHDRAW_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *BID_ACCOUNT_1_PK,
+            ARG_AMOUNT => U512::from(WITHDRAW_BID_AMOUNT_2),
+        },
+    )
+    .build();
+
+    builder.exec(add_bid_request).commit().expect_success();
+    builder.exec(withdraw_bid_request).commit().expect_success();
+
+    let cancel_request = |amount: u64| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_CANCEL_VALIDATOR_UNBOND,
+            runtime_args! {
+                ARG_PUBLIC_KEY => *BID_ACCOUNT_1_PK,
+                ARG_AMOUNT => U512::from(amount),
+            },
+        )
+        .build()
+    };
+
+    let staked_amount = |builder: &mut InMemoryWasmTestBuilder| {
+        *builder
+            .get_bids()
+            .get(&*BID_ACCOUNT_1_PK)
+            .expect("should have bid")
+            .staked_amount()
+    };
+
+    let pending_unbonds = |builder: &mut InMemoryWasmTestBuilder| -> Vec<U512> {
+        builder
+            .get_withdraws()
+            .get(&*BID_ACCOUNT_1_ADDR)
+            .map(|unbond_list| {
+                unbond_list
+                    .iter()
+                    .map(|unbonding_purse| *unbonding_purse.amount())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    assert_eq!(
+        staked_amount(&mut builder),
+        U512::from(ADD_BID_AMOUNT_1 - WITHDRAW_BID_AMOUNT_2)
+    );
+    assert_eq!(
+        pending_unbonds(&mut builder),
+        vec![U512::from(WITHDRAW_BID_AMOUNT_2)]
+    );
+
+    // Partial cancellation shrinks the pending unbond.
+    const CANCEL_AMOUNT: u64 = 5_000;
+    builder
+        .exec(cancel_request(CANCEL_AMOUNT))
+        .commit()
+        .expect_success();
+    assert_eq!(
+        staked_amount(&mut builder),
+        U512::from(ADD_BID_AMOUNT_1 - WITHDRAW_BID_AMOUNT_2 + CANCEL_AMOUNT)
+    );
+    assert_eq!(
+        pending_unbonds(&mut builder),
+        vec![U512::from(WITHDRAW_BID_AMOUNT_2 - CANCEL_AMOUNT)]
+    );
+
+    // More than is pending can't be cancelled.
+    builder.exec(cancel_request(WITHDRAW_BID_AMOUNT_2)).commit();
+    assert_last_auction_error(&builder, auction::Error::UnbondTooLarge);
+
+    // Cancelling the rest removes the pending unbond altogether.
+    builder
+        .exec(cancel_request(WITHDRAW_BID_AMOUNT_2 - CANCEL_AMOUNT))
+        .commit()
+        .expect_success();
+    assert_eq!(staked_amount(&mut builder), U512::from(ADD_BID_AMOUNT_1));
+    assert!(pending_unbonds(&mut builder).is_empty());
+
+    builder.exec(cancel_request(CANCEL_AMOUNT)).commit();
+    assert_last_auction_error(&builder, auction::Error::UnbondingRequestNotFound);
+
+    let bonding_purse = *builder
+        .get_bids()
+        .get(&*BID_ACCOUNT_1_PK)
+        .expect("should have bid")
+        .bonding_purse();
+    assert_eq!(
+        builder.get_purse_balance(bonding_purse),
+        U512::from(ADD_BID_AMOUNT_1)
+    );
+
+    builder.assert_staking_stats_consistent();
+}
+
+#[ignore]
+#[test]
+fn should_cancel_delegator_unbond() {
+    let system_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let delegator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *BID_ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let delegator_1_validator_1_delegate_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        system_fund_request,
+        delegator_1_fund_request,
+        validator_1_fund_request,
+        validator_1_add_bid_request,
+        delegator_1_validator_1_delegate_request,
+    ];
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    let undelegate_request = |amount: u64| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_UNDELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+                ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+            },
+        )
+        .build()
+    };
+
+    let cancel_request = |amount: u64| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_CANCEL_DELEGATOR_UNBOND,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+                ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+            },
+        )
+        .build()
+    };
+
+    let delegated_amount = |builder: &mut InMemoryWasmTestBuilder| {
+        builder
+            .get_bids()
+            .get(&*NON_FOUNDER_VALIDATOR_1_PK)
+            .expect("should have bid")
+            .delegators()
+            .get(&*BID_ACCOUNT_1_PK)
+            .map(|delegator| *delegator.staked_amount())
+    };
+
+    let pending_unbonds = |builder: &mut InMemoryWasmTestBuilder| -> Vec<U512> {
+        builder
+            .get_withdraws()
+            .get(&*NON_FOUNDER_VALIDATOR_1_ADDR)
+            .map(|unbond_list| {
+                unbond_list
+                    .iter()
+                    .map(|unbonding_purse| *unbonding_purse.amount())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let bonding_purse = builder
+        .get_bids()
+        .get(&*NON_FOUNDER_VALIDATOR_1_PK)
+        .expect("should have bid")
+        .delegators()
+        .get(&*BID_ACCOUNT_1_PK)
+        .map(|delegator| *delegator.bonding_purse())
+        .expect("should have delegator");
+
+    builder
+        .exec(undelegate_request(UNDELEGATE_AMOUNT_1))
+        .commit()
+        .expect_success();
+
+    // Partial cancellation while still delegating.
+    const CANCEL_AMOUNT_1: u64 = 10_000;
+    builder
+        .exec(cancel_request(CANCEL_AMOUNT_1))
+        .commit()
+        .expect_success();
+    let remaining_delegation = DELEGATE_AMOUNT_1 - UNDELEGATE_AMOUNT_1 + CANCEL_AMOUNT_1;
+    assert_eq!(
+        delegated_amount(&mut builder),
+        Some(U512::from(remaining_delegation))
+    );
+    assert_eq!(
+        pending_unbonds(&mut builder),
+        vec![U512::from(UNDELEGATE_AMOUNT_1 - CANCEL_AMOUNT_1)]
+    );
+
+    // Undelegating everything removes the delegator from the bid.
+    builder
+        .exec(undelegate_request(remaining_delegation))
+        .commit()
+        .expect_success();
+    assert_eq!(delegated_amount(&mut builder), None);
+    assert_eq!(
+        pending_unbonds(&mut builder),
+        vec![
+            U512::from(UNDELEGATE_AMOUNT_1 - CANCEL_AMOUNT_1),
+            U512::from(remaining_delegation)
+        ]
+    );
+
+    // Cancelling across both requests restores the delegator, most recent request first.
+    const CANCEL_AMOUNT_2: u64 = 5_000;
+    builder
+        .exec(cancel_request(remaining_delegation + CANCEL_AMOUNT_2))
+        .commit()
+        .expect_success();
+    assert_eq!(
+        delegated_amount(&mut builder),
+        Some(U512::from(remaining_delegation + CANCEL_AMOUNT_2))
+    );
+    let released_amount = UNDELEGATE_AMOUNT_1 - CANCEL_AMOUNT_1 - CANCEL_AMOUNT_2;
+    assert_eq!(
+        pending_unbonds(&mut builder),
+        vec![U512::from(released_amount)]
+    );
+
+    let restored_bonding_purse = builder
+        .get_bids()
+        .get(&*NON_FOUNDER_VALIDATOR_1_PK)
+        .expect("should have bid")
+        .delegators()
+        .get(&*BID_ACCOUNT_1_PK)
+        .map(|delegator| *delegator.bonding_purse())
+        .expect("should have delegator");
+    assert_eq!(restored_bonding_purse, bonding_purse);
+
+    // Only the remaining unbond is paid out once the unbonding delay has elapsed.
+    let delegator_1_main_purse = builder
+        .get_account(*BID_ACCOUNT_1_ADDR)
+        .expect("should have account")
+        .main_purse();
+    let delegator_1_balance_before = builder.get_purse_balance(delegator_1_main_purse);
+
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+    for _ in 0..=DEFAULT_UNBONDING_DELAY {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    assert!(pending_unbonds(&mut builder).is_empty());
+    assert_eq!(
+        builder.get_purse_balance(delegator_1_main_purse),
+        delegator_1_balance_before + U512::from(released_amount)
+    );
+    assert_eq!(
+        builder.get_purse_balance(bonding_purse),
+        U512::from(remaining_delegation + CANCEL_AMOUNT_2)
+    );
+
+    builder.assert_staking_stats_consistent();
+}
+
+fn auction_bids_result<T: FromBytes + CLTyped>(builder: &InMemoryWasmTestBuilder) -> T {
+    builder
+        .query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[RESULT_KEY.to_string()],
+        )
+        .expect("should have result")
+        .as_cl_value()
+        .cloned()
+        .map(CLValue::into_t)
+        .expect("should be cl value")
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_get_single_bid_and_delegator() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            *ACCOUNT_1_PK,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Some(GenesisValidator::new(Motes::new(ACCOUNT_1_BOND.into()), 80)),
+        );
+        let account_2 = GenesisAccount::account(
+            *ACCOUNT_2_PK,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(ACCOUNT_2_BOND.into()),
+                DelegationRate::zero(),
+            )),
+        );
+        let delegator_1 = GenesisAccount::delegator(
+            *ACCOUNT_1_PK,
+            *DELEGATOR_1,
+            Motes::new(DELEGATOR_1_BALANCE.into()),
+            Motes::new(DELEGATOR_1_STAKE.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp.push(delegator_1);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let bids = builder.get_bids();
+
+    // Builder helpers read a single bid without going through the whole `Bids` map.
+    assert_eq!(
+        builder.get_bid(*ACCOUNT_1_PK).as_ref(),
+        bids.get(&*ACCOUNT_1_PK)
+    );
+    assert_eq!(
+        builder.get_bid(*ACCOUNT_2_PK).as_ref(),
+        bids.get(&*ACCOUNT_2_PK)
+    );
+    assert!(builder.get_bid(*DELEGATOR_1).is_none());
+    assert_eq!(
+        builder
+            .get_delegator(*ACCOUNT_1_PK, *DELEGATOR_1)
+            .map(|delegator| *delegator.staked_amount()),
+        Some(U512::from(DELEGATOR_1_STAKE))
+    );
+    assert!(builder.get_delegator(*ACCOUNT_2_PK, *DELEGATOR_1).is_none());
+
+    // The auction entry points return the same values.
+    let get_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => auction::METHOD_GET_BID,
+            ARG_PUBLIC_KEY => *ACCOUNT_1_PK,
+        },
+    )
+    .build();
+    builder.exec(get_bid_request).commit().expect_success();
+
+    let bid: Option<Bid> = auction_bids_result(&builder);
+    assert_eq!(bid, builder.get_bid(*ACCOUNT_1_PK));
+
+    let get_missing_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => auction::METHOD_GET_BID,
+            ARG_PUBLIC_KEY => *DELEGATOR_1,
+        },
+    )
+    .build();
+    builder
+        .exec(get_missing_bid_request)
+        .commit()
+        .expect_success();
+
+    let bid: Option<Bid> = auction_bids_result(&builder);
+    assert!(bid.is_none());
+
+    let get_delegator_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => auction::METHOD_GET_DELEGATOR,
+            ARG_VALIDATOR => *ACCOUNT_1_PK,
+            ARG_DELEGATOR => *DELEGATOR_1,
+        },
+    )
+    .build();
+    builder
+        .exec(get_delegator_request)
+        .commit()
+        .expect_success();
+
+    let delegator: Option<Delegator> = auction_bids_result(&builder);
+    assert_eq!(
+        delegator,
+        builder.get_delegator(*ACCOUNT_1_PK, *DELEGATOR_1)
+    );
+
+    // Asking for a delegator of a validator without a bid is an error.
+    let get_delegator_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => auction::METHOD_GET_DELEGATOR,
+            ARG_VALIDATOR => *DELEGATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_1,
+        },
+    )
+    .build();
+    builder.exec(get_delegator_request).commit();
+
+    let error = {
+        let response = builder
+            .get_exec_results()
+            .last()
+            .expect("should have last exec result");
+        let exec_response = response.last().expect("should have response");
+        exec_response.as_error().expect("should have error")
+    };
+    assert!(matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+        if *auction_error == auction::Error::ValidatorNotFound as u8
+    ));
+}
+
+#[ignore]
+#[test]
+fn should_set_validator_info() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            *BID_ACCOUNT_1_PK,
+            Motes::new(BID_ACCOUNT_1_BALANCE.into()),
+            None,
+        );
+        let account_2 = GenesisAccount::account(
+            *BID_ACCOUNT_2_PK,
+            Motes::new(BID_ACCOUNT_2_BALANCE.into()),
+            None,
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let set_validator_info_request =
+        |account_hash: AccountHash, public_key: PublicKey, name: String, icon_hash| {
+            ExecuteRequestBuilder::standard(
+                account_hash,
+                CONTRACT_SET_VALIDATOR_INFO,
+                runtime_args! {
+                    ARG_PUBLIC_KEY => public_key,
+                    ARG_NAME => name,
+                    ARG_URL => String::from("https://validator.example"),
+                    ARG_ICON_HASH => icon_hash,
+                },
+            )
+            .build()
+        };
+
+    // Only validators with a bid can publish their info.
+    builder
+        .exec(set_validator_info_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            String::from("Validator 1"),
+            None::<[u8; 32]>,
+        ))
+        .commit();
+    assert_last_auction_error(&builder, auction::Error::ValidatorNotFound);
+    assert_eq!(builder.get_validator_info(*BID_ACCOUNT_1_PK), None);
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *BID_ACCOUNT_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+    builder.exec(add_bid_request).commit().expect_success();
+
+    builder
+        .exec(set_validator_info_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            String::from("Validator 1"),
+            None,
+        ))
+        .commit()
+        .expect_success();
+    assert_eq!(
+        builder.get_validator_info(*BID_ACCOUNT_1_PK),
+        Some(
+            ValidatorInfo::new(
+                String::from("Validator 1"),
+                String::from("https://validator.example"),
+                None
+            )
+            .unwrap()
+        )
+    );
+
+    // Setting the info again overwrites it.
+    builder
+        .exec(set_validator_info_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            String::from("Renamed validator 1"),
+            Some([1; 32]),
+        ))
+        .commit()
+        .expect_success();
+    let validator_info = builder
+        .get_validator_info(*BID_ACCOUNT_1_PK)
+        .expect("should have validator info");
+    assert_eq!(validator_info.name(), "Renamed validator 1");
+    assert_eq!(validator_info.icon_hash(), Some(&[1; 32]));
+
+    // A validator can't set the info of another validator.
+    builder
+        .exec(set_validator_info_request(
+            *BID_ACCOUNT_2_ADDR,
+            *BID_ACCOUNT_1_PK,
+            String::from("Impostor"),
+            None,
+        ))
+        .commit();
+    assert_last_auction_error(&builder, auction::Error::InvalidPublicKey);
+
+    // Names longer than the limit are rejected.
+    builder
+        .exec(set_validator_info_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            "a".repeat(MAX_VALIDATOR_NAME_LENGTH + 1),
+            None,
+        ))
+        .commit();
+    assert_last_auction_error(&builder, auction::Error::ValidatorNameTooLong);
+    assert_eq!(
+        builder
+            .get_validator_info(*BID_ACCOUNT_1_PK)
+            .expect("should have validator info")
+            .name(),
+        "Renamed validator 1"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_delegate_and_undelegate_from_contract() {
+    const CONTRACT_STAKING_POOL: &str = "staking_pool.wasm";
+    const STAKING_POOL_HASH_KEY_NAME: &str = "staking_pool_hash";
+    const STAKING_POOL_PURSE_KEY_NAME: &str = "staking_pool_purse";
+    const ENTRY_POINT_DELEGATE: &str = "delegate";
+    const ENTRY_POINT_UNDELEGATE: &str = "undelegate";
+    const ENTRY_POINT_DELEGATE_FROM_SESSION: &str = "delegate_from_session";
+
+    let system_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let install_staking_pool_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_STAKING_POOL,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        system_fund_request,
+        validator_1_fund_request,
+        validator_1_add_bid_request,
+        install_staking_pool_request,
+    ];
+
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+    let staking_pool_hash: ContractHash = default_account.named_keys()[STAKING_POOL_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should have staking pool hash")
+        .into();
+    let staking_pool_purse = default_account.named_keys()[STAKING_POOL_PURSE_KEY_NAME]
+        .into_uref()
+        .expect("should have staking pool purse");
+
+    let staking_pool_args = runtime_args! {
+        ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+        ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+    };
+
+    // Delegating from a purse outside of a stored contract's context is rejected.
+    builder
+        .exec(
+            ExecuteRequestBuilder::contract_call_by_hash(
+                *DEFAULT_ACCOUNT_ADDR,
+                staking_pool_hash,
+                ENTRY_POINT_DELEGATE_FROM_SESSION,
+                staking_pool_args.clone(),
+            )
+            .build(),
+        )
+        .commit();
+    assert_last_auction_error(&builder, auction::Error::InvalidContext);
+
+    builder
+        .exec(
+            ExecuteRequestBuilder::contract_call_by_hash(
+                *DEFAULT_ACCOUNT_ADDR,
+                staking_pool_hash,
+                ENTRY_POINT_DELEGATE,
+                staking_pool_args,
+            )
+            .build(),
+        )
+        .commit()
+        .expect_success();
+
+    assert_eq!(builder.get_purse_balance(staking_pool_purse), U512::zero());
+
+    let bids: Bids = builder.get_bids();
+    let validator_1_bid = bids
+        .get(&NON_FOUNDER_VALIDATOR_1_PK)
+        .expect("should have validator 1 bid");
+    assert!(validator_1_bid.delegators().is_empty());
+    let contract_delegators = validator_1_bid.contract_delegators();
+    assert_eq!(contract_delegators.len(), 1);
+    let contract_delegator = contract_delegators
+        .values()
+        .next()
+        .expect("should have contract delegator");
+    assert_eq!(
+        *contract_delegator.staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1)
+    );
+    assert_eq!(
+        contract_delegator.unbond_purse().addr(),
+        staking_pool_purse.addr()
+    );
+    assert_eq!(
+        validator_1_bid.total_staked_amount().unwrap(),
+        U512::from(ADD_BID_AMOUNT_1 + DELEGATE_AMOUNT_1)
+    );
+
+    for _ in 0..5 {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    builder
+        .exec(
+            ExecuteRequestBuilder::contract_call_by_hash(
+                *DEFAULT_ACCOUNT_ADDR,
+                staking_pool_hash,
+                ENTRY_POINT_UNDELEGATE,
+                runtime_args! {
+                    ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+                    ARG_AMOUNT => U512::from(UNDELEGATE_AMOUNT_1),
+                },
+            )
+            .build(),
+        )
+        .commit()
+        .expect_success();
+
+    let staking_pool_balance_before = builder.get_purse_balance(staking_pool_purse);
+
+    for _ in 0..=DEFAULT_UNBONDING_DELAY {
+        assert_eq!(
+            builder.get_purse_balance(staking_pool_purse),
+            staking_pool_balance_before
+        );
+
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    assert_eq!(
+        builder.get_purse_balance(staking_pool_purse),
+        staking_pool_balance_before + U512::from(UNDELEGATE_AMOUNT_1)
+    );
+}
+
+fn run_genesis_request_with_max_delegators(
+    accounts: Vec<GenesisAccount>,
+    max_delegators_per_validator: u32,
+) -> RunGenesisRequest {
+    let exec_config = ExecConfig::new(
+        accounts,
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        DEFAULT_MAX_DELEGATION_RATE,
+        DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+        max_delegators_per_validator,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+#[ignore]
+#[test]
+fn should_enforce_max_delegators_per_validator() {
+    const MAX_DELEGATORS_PER_VALIDATOR: u32 = 2;
+
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let validator_1 = GenesisAccount::account(
+            *VALIDATOR_1,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                VALIDATOR_1_DELEGATION_RATE,
+            )),
+        );
+        tmp.push(validator_1);
+        for public_key in [*DELEGATOR_1, *DELEGATOR_2, *BID_ACCOUNT_1_PK].iter() {
+            tmp.push(GenesisAccount::account(
+                *public_key,
+                Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+                None,
+            ));
+        }
+        tmp
+    };
+
+    let run_genesis_request =
+        run_genesis_request_with_max_delegators(accounts, MAX_DELEGATORS_PER_VALIDATOR);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let delegate_request = |delegator: PublicKey, amount: u64| {
+        ExecuteRequestBuilder::standard(
+            AccountHash::from(&delegator),
+            CONTRACT_DELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => *VALIDATOR_1,
+                ARG_DELEGATOR => delegator,
+            },
+        )
+        .build()
+    };
+
+    let validator_1_bid = |builder: &mut InMemoryWasmTestBuilder| {
+        builder
+            .get_bids()
+            .remove(&*VALIDATOR_1)
+            .expect("should have bid")
+    };
+
+    builder
+        .exec(delegate_request(*DELEGATOR_1, DELEGATOR_1_STAKE))
+        .commit()
+        .expect_success();
+    builder
+        .exec(delegate_request(*DELEGATOR_2, DELEGATOR_2_STAKE))
+        .commit()
+        .expect_success();
+
+    let full_bid = validator_1_bid(&mut builder);
+    assert_eq!(
+        full_bid.delegator_count(),
+        MAX_DELEGATORS_PER_VALIDATOR as usize
+    );
+
+    // The serialized bid grows by exactly one map entry per delegator, so its size is bounded by
+    // the delegator limit.
+    let mut bid_without_delegators = full_bid.clone();
+    *bid_without_delegators.delegators_mut() = BTreeMap::new();
+    let delegator_entries_length: usize = full_bid
+        .delegators()
+        .iter()
+        .map(|(public_key, delegator)| {
+            public_key.serialized_length() + delegator.serialized_length()
+        })
+        .sum();
+    assert_eq!(
+        full_bid.serialized_length(),
+        bid_without_delegators.serialized_length() + delegator_entries_length
+    );
+
+    // A new delegator is rejected once the validator is full, leaving the bid unchanged.
+    builder
+        .exec(delegate_request(*BID_ACCOUNT_1_PK, DELEGATE_AMOUNT_1))
+        .commit();
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result");
+    let error = response
+        .last()
+        .expect("should have response")
+        .as_error()
+        .expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if *auction_error == auction::Error::TooManyDelegators as u8
+        ),
+        "expected TooManyDelegators, got {:?}",
+        error
+    );
+    assert_eq!(validator_1_bid(&mut builder), full_bid);
+
+    // Existing delegators may still top up.
+    builder
+        .exec(delegate_request(*DELEGATOR_1, DELEGATE_AMOUNT_2))
+        .commit()
+        .expect_success();
+
+    // Once a delegator fully undelegates, there is room for a new one.
+    let undelegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_2_ADDR,
+        CONTRACT_UNDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATOR_2_STAKE),
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_2,
+        },
+    )
+    .build();
+    builder.exec(undelegate_request).commit().expect_success();
+
+    builder
+        .exec(delegate_request(*BID_ACCOUNT_1_PK, DELEGATE_AMOUNT_1))
+        .commit()
+        .expect_success();
+
+    let bid = validator_1_bid(&mut builder);
+    assert_eq!(bid.delegator_count(), MAX_DELEGATORS_PER_VALIDATOR as usize);
+    assert!(bid.delegators().contains_key(&*BID_ACCOUNT_1_PK));
+    assert!(!bid.delegators().contains_key(&*DELEGATOR_2));
+}
+
+#[should_panic(expected = "TooManyDelegators")]
+#[ignore]
+#[test]
+fn should_validate_delegator_count_of_genesis_validator() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            *ACCOUNT_1_PK,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(ACCOUNT_1_BOND.into()),
+                DelegationRate::zero(),
+            )),
+        );
+        let delegator_1 = GenesisAccount::delegator(
+            *ACCOUNT_1_PK,
+            *DELEGATOR_1,
+            Motes::new(DELEGATOR_1_BALANCE.into()),
+            Motes::new(DELEGATOR_1_STAKE.into()),
+        );
+        let delegator_2 = GenesisAccount::delegator(
+            *ACCOUNT_1_PK,
+            *DELEGATOR_2,
+            Motes::new(DELEGATOR_2_BALANCE.into()),
+            Motes::new(DELEGATOR_2_STAKE.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(delegator_1);
+        tmp.push(delegator_2);
+        tmp
+    };
+
+    let run_genesis_request = run_genesis_request_with_max_delegators(accounts, 1);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+}
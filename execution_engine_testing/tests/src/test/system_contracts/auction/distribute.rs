@@ -6,9 +6,8 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_RUN_GENESIS_REQUEST,
-        SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_RUN_GENESIS_REQUEST, SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
     },
     DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
@@ -232,8 +231,7 @@ fn should_distribute_delegation_rate_zero() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -373,6 +371,8 @@ fn should_distribute_delegation_rate_zero() {
         Some(SeigniorageAllocation::Delegator { delegator_public_key, amount, .. })
         if *delegator_public_key == *DELEGATOR_2 && *amount == expected_delegator_1_balance
     ));
+
+    builder.assert_staking_stats_consistent();
 }
 
 #[ignore]
@@ -470,8 +470,7 @@ fn should_withdraw_bids_after_distribute() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -647,6 +646,8 @@ fn should_withdraw_bids_after_distribute() {
         Some(SeigniorageAllocation::Delegator { delegator_public_key, amount, .. })
         if *delegator_public_key == *DELEGATOR_2 && *amount == expected_delegator_1_balance
     ));
+
+    builder.assert_staking_stats_consistent();
 }
 
 #[ignore]
@@ -744,8 +745,7 @@ fn should_distribute_rewards_after_restaking_delegated_funds() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1120,8 +1120,7 @@ fn should_distribute_reinvested_rewards_by_different_factor() {
         validator_3_add_bid_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1511,8 +1510,7 @@ fn should_distribute_delegation_rate_half() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1707,8 +1705,7 @@ fn should_distribute_delegation_rate_full() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -1908,8 +1905,7 @@ fn should_distribute_uneven_delegation_rate_zero() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -2110,8 +2106,7 @@ fn should_distribute_by_factor() {
         validator_3_add_bid_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -2321,8 +2316,7 @@ fn should_distribute_by_factor_regardless_of_stake() {
         validator_3_add_bid_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -2533,8 +2527,7 @@ fn should_distribute_by_factor_uneven() {
         validator_3_add_bid_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -2814,8 +2807,7 @@ fn should_distribute_with_multiple_validators_and_delegators() {
         delegator_3_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -3140,8 +3132,7 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
         delegator_1_validator_3_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -3329,6 +3320,8 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
             expected_delegator_1_validator_3_payout,
         ))
     );
+
+    builder.assert_staking_stats_consistent();
 }
 
 #[ignore]
@@ -3498,8 +3491,7 @@ fn should_increase_total_supply_after_distribute() {
         delegator_1_validator_3_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
@@ -3652,8 +3644,7 @@ fn should_distribute_delegation_rate_full_after_upgrading() {
         delegator_2_delegate_request,
     ];
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
 
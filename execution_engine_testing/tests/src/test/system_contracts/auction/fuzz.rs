@@ -0,0 +1,342 @@
+//! Property-based tests executing random sequences of auction entry points and checking the
+//! system contracts' invariants after every step.
+
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use proptest::{collection, prelude::*};
+
+use casper_engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS, SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::{core::engine_state::genesis::GenesisAccount, shared::motes::Motes};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        DelegationRate, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY,
+        ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEYS, METHOD_SLASH,
+    },
+    PublicKey, RuntimeArgs, SecretKey, URef, U512,
+};
+
+const ARG_TARGET: &str = "target";
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_ADD_BID: &str = "add_bid.wasm";
+const CONTRACT_WITHDRAW_BID: &str = "withdraw_bid.wasm";
+const CONTRACT_DELEGATE: &str = "delegate.wasm";
+const CONTRACT_UNDELEGATE: &str = "undelegate.wasm";
+
+const SYSTEM_TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE;
+
+const VALIDATOR_COUNT: usize = 3;
+const DELEGATOR_COUNT: usize = 2;
+const MAX_OPERATIONS: usize = 12;
+const MAX_AMOUNT: u64 = 1_000_000_000;
+const MAX_DELEGATION_RATE: DelegationRate = 100;
+const CASES: u32 = 16;
+
+static VALIDATORS: Lazy<Vec<PublicKey>> = Lazy::new(|| {
+    (0..VALIDATOR_COUNT)
+        .map(|index| SecretKey::ed25519([100 + index as u8; SecretKey::ED25519_LENGTH]).into())
+        .collect()
+});
+static DELEGATORS: Lazy<Vec<PublicKey>> = Lazy::new(|| {
+    (0..DELEGATOR_COUNT)
+        .map(|index| SecretKey::ed25519([150 + index as u8; SecretKey::ED25519_LENGTH]).into())
+        .collect()
+});
+
+/// A single call into the auction contract, with actors given as indices into `VALIDATORS` and
+/// `DELEGATORS`.
+#[derive(Clone, Debug)]
+enum Operation {
+    AddBid {
+        validator: usize,
+        amount: u64,
+        delegation_rate: DelegationRate,
+    },
+    WithdrawBid {
+        validator: usize,
+        amount: u64,
+    },
+    Delegate {
+        delegator: usize,
+        validator: usize,
+        amount: u64,
+    },
+    Undelegate {
+        delegator: usize,
+        validator: usize,
+        amount: u64,
+    },
+    Slash {
+        validator: usize,
+    },
+    RunAuction,
+}
+
+fn operation_arb() -> impl Strategy<Value = Operation> {
+    let validator = 0..VALIDATOR_COUNT;
+    let delegator = 0..DELEGATOR_COUNT;
+    let amount = 1..=MAX_AMOUNT;
+
+    prop_oneof![
+        3 => (validator.clone(), amount.clone(), 0..=MAX_DELEGATION_RATE).prop_map(
+            |(validator, amount, delegation_rate)| Operation::AddBid {
+                validator,
+                amount,
+                delegation_rate,
+            }
+        ),
+        2 => (validator.clone(), amount.clone())
+            .prop_map(|(validator, amount)| Operation::WithdrawBid { validator, amount }),
+        3 => (delegator.clone(), validator.clone(), amount.clone()).prop_map(
+            |(delegator, validator, amount)| Operation::Delegate {
+                delegator,
+                validator,
+                amount,
+            }
+        ),
+        2 => (delegator, validator.clone(), amount).prop_map(|(delegator, validator, amount)| {
+            Operation::Undelegate {
+                delegator,
+                validator,
+                amount,
+            }
+        }),
+        1 => validator.prop_map(|validator| Operation::Slash { validator }),
+        1 => Just(Operation::RunAuction),
+    ]
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut accounts = DEFAULT_ACCOUNTS.clone();
+    for public_key in VALIDATORS.iter().chain(DELEGATORS.iter()) {
+        accounts.push(GenesisAccount::account(
+            *public_key,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            None,
+        ));
+    }
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+
+    let fund_system_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+    builder.exec(fund_system_request).commit().expect_success();
+
+    builder
+}
+
+/// Returns the amount burned from the total supply when slashing `validator`: its own stake plus
+/// every pending unbond held under its key.
+fn slashable_amount(builder: &mut InMemoryWasmTestBuilder, validator: &PublicKey) -> U512 {
+    let staked_amount = builder
+        .get_bids()
+        .get(validator)
+        .map(|bid| *bid.staked_amount())
+        .unwrap_or_default();
+    let unbonding_amount: U512 = builder
+        .get_unbonding_purses(AccountHash::from(validator))
+        .iter()
+        .map(|unbonding_purse| *unbonding_purse.amount())
+        .sum();
+    staked_amount + unbonding_amount
+}
+
+/// Applies `operation`.  User-facing entry points are allowed to fail, e.g. when withdrawing more
+/// than is staked; system entry points must succeed.
+fn execute(
+    builder: &mut InMemoryWasmTestBuilder,
+    operation: &Operation,
+    timestamp_millis: &mut u64,
+) {
+    let exec_request = match *operation {
+        Operation::AddBid {
+            validator,
+            amount,
+            delegation_rate,
+        } => ExecuteRequestBuilder::standard(
+            AccountHash::from(&VALIDATORS[validator]),
+            CONTRACT_ADD_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => VALIDATORS[validator],
+                ARG_AMOUNT => U512::from(amount),
+                ARG_DELEGATION_RATE => delegation_rate,
+            },
+        )
+        .build(),
+        Operation::WithdrawBid { validator, amount } => ExecuteRequestBuilder::standard(
+            AccountHash::from(&VALIDATORS[validator]),
+            CONTRACT_WITHDRAW_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => VALIDATORS[validator],
+                ARG_AMOUNT => U512::from(amount),
+            },
+        )
+        .build(),
+        Operation::Delegate {
+            delegator,
+            validator,
+            amount,
+        } => ExecuteRequestBuilder::standard(
+            AccountHash::from(&DELEGATORS[delegator]),
+            CONTRACT_DELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => VALIDATORS[validator],
+                ARG_DELEGATOR => DELEGATORS[delegator],
+            },
+        )
+        .build(),
+        Operation::Undelegate {
+            delegator,
+            validator,
+            amount,
+        } => ExecuteRequestBuilder::standard(
+            AccountHash::from(&DELEGATORS[delegator]),
+            CONTRACT_UNDELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => VALIDATORS[validator],
+                ARG_DELEGATOR => DELEGATORS[delegator],
+            },
+        )
+        .build(),
+        Operation::Slash { validator } => {
+            let auction_hash = builder.get_auction_contract_hash();
+            let slash_request = ExecuteRequestBuilder::contract_call_by_hash(
+                *SYSTEM_ADDR,
+                auction_hash,
+                METHOD_SLASH,
+                runtime_args! {
+                    ARG_VALIDATOR_PUBLIC_KEYS => vec![VALIDATORS[validator]],
+                },
+            )
+            .build();
+            builder.exec(slash_request).expect_success().commit();
+            return;
+        }
+        Operation::RunAuction => {
+            *timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+            builder.run_auction(*timestamp_millis, Vec::new());
+            return;
+        }
+    };
+    builder.exec(exec_request).commit();
+}
+
+/// Checks the auction's invariants against the builder's latest post-state.
+fn check_invariants(builder: &mut InMemoryWasmTestBuilder) {
+    builder.assert_staking_stats_consistent();
+
+    let bids = builder.get_bids();
+    let unbonding_purses = builder.get_withdraws();
+    let current_era = builder.get_era();
+
+    // Every bonding purse must cover the stakes and pending unbonds drawn from it.
+    let mut obligations: BTreeMap<URef, U512> = BTreeMap::new();
+    for bid in bids.values() {
+        *obligations.entry(*bid.bonding_purse()).or_default() += *bid.staked_amount();
+        for delegator in bid.delegators().values() {
+            *obligations.entry(*delegator.bonding_purse()).or_default() +=
+                *delegator.staked_amount();
+        }
+        for contract_delegator in bid.contract_delegators().values() {
+            *obligations
+                .entry(*contract_delegator.bonding_purse())
+                .or_default() += *contract_delegator.staked_amount();
+        }
+    }
+
+    for (validator_account_hash, unbonding_list) in unbonding_purses.iter() {
+        for unbonding_purse in unbonding_list {
+            assert_eq!(
+                AccountHash::from(unbonding_purse.validator_public_key()),
+                *validator_account_hash,
+                "unbond {:?} is queued under the wrong validator",
+                unbonding_purse
+            );
+            assert!(
+                *unbonding_purse.amount() > U512::zero(),
+                "unbond {:?} has no amount",
+                unbonding_purse
+            );
+            assert!(
+                unbonding_purse.era_of_creation() <= current_era,
+                "unbond {:?} was created after era {}",
+                unbonding_purse,
+                current_era
+            );
+            *obligations
+                .entry(*unbonding_purse.bonding_purse())
+                .or_default() += *unbonding_purse.amount();
+        }
+    }
+
+    for (bonding_purse, obligation) in obligations {
+        let balance = builder.get_purse_balance(bonding_purse);
+        assert!(
+            balance >= obligation,
+            "bonding purse {:?} holds {} but owes {}",
+            bonding_purse,
+            balance,
+            obligation
+        );
+    }
+}
+
+fn run_operations(operations: &[Operation]) {
+    let mut builder = setup();
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+    check_invariants(&mut builder);
+
+    for operation in operations {
+        // Only slashing may change the total supply; gas payments just move motes around.
+        let expected_burn = match operation {
+            Operation::Slash { validator } => {
+                slashable_amount(&mut builder, &VALIDATORS[*validator])
+            }
+            _ => U512::zero(),
+        };
+        let total_supply_before = builder.total_supply(None);
+
+        execute(&mut builder, operation, &mut timestamp_millis);
+
+        assert_eq!(
+            builder.total_supply(None),
+            total_supply_before - expected_burn,
+            "total supply not conserved by {:?}",
+            operation
+        );
+        check_invariants(&mut builder);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[ignore]
+    #[test]
+    fn should_preserve_invariants_for_random_auction_operations(
+        operations in collection::vec(operation_arb(), 1..=MAX_OPERATIONS)
+    ) {
+        run_operations(&operations);
+    }
+}
@@ -1,2 +1,3 @@
 mod bids;
 mod distribute;
+mod fuzz;
@@ -5,16 +5,15 @@ use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
         DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
-        DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY, SYSTEM_ADDR,
-        TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_UNBONDING_DELAY, SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
     },
     DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
 use casper_execution_engine::{
     core::{
         engine_state::{
-            genesis::{GenesisAccount, GenesisValidator},
+            genesis::{GenesisAccount, GenesisValidator, GenesisVesting},
             Error as EngineError,
         },
         execution::Error,
@@ -28,7 +27,7 @@ use casper_types::{
         self, Bids, DelegationRate, UnbondingPurses, ARG_VALIDATOR_PUBLIC_KEYS, INITIAL_ERA_ID,
         METHOD_SLASH,
     },
-    ApiError, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
+    ApiError, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, URef, U512,
 };
 
 const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
@@ -143,10 +142,7 @@ fn should_run_successful_bond_and_unbond_and_slashing() {
 
     let unbond_era_1 = unbond_list[0].era_of_creation();
 
-    builder.run_auction(
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
-        Vec::new(),
-    );
+    builder.run_auction(DEFAULT_GENESIS_TIMESTAMP_MILLIS, Vec::new());
     let unbond_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbond_purses.len(), 1);
 
@@ -259,10 +255,13 @@ fn should_fail_unbonding_validator_with_locked_funds() {
         let account = GenesisAccount::account(
             account_1_public_key,
             Motes::new(account_1_balance),
-            Some(GenesisValidator::new(
-                Motes::new(GENESIS_VALIDATOR_STAKE.into()),
-                DelegationRate::zero(),
-            )),
+            Some(
+                GenesisValidator::new(
+                    Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+                    DelegationRate::zero(),
+                )
+                .with_vesting(GenesisVesting::new(1, 1)),
+            ),
         );
         tmp.push(account);
         tmp
@@ -345,8 +344,7 @@ fn should_fail_unbonding_validator_without_bonding_first() {
 fn should_run_successful_bond_and_unbond_with_release() {
     let default_public_key_arg = *DEFAULT_ACCOUNT_PUBLIC_KEY;
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
@@ -500,8 +498,7 @@ fn should_run_successful_bond_and_unbond_with_release() {
 fn should_run_successful_unbond_funds_after_changing_unbonding_delay() {
     let default_public_key_arg = *DEFAULT_ACCOUNT_PUBLIC_KEY;
 
-    let mut timestamp_millis =
-        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
@@ -686,3 +683,152 @@ fn should_run_successful_unbond_funds_after_changing_unbonding_delay() {
         U512::from(GENESIS_ACCOUNT_STAKE) - unbond_amount, // remaining funds
     );
 }
+
+/// Bonds and then partially unbonds the default account in the initial era, returning the purse
+/// the unbonded funds are paid out to, its balance before the payout and the unbonded amount.
+fn setup_pending_unbond(builder: &mut InMemoryWasmTestBuilder) -> (URef, U512, U512) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            "target" => *SYSTEM_ADDR,
+            "amount" => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(GENESIS_ACCOUNT_STAKE),
+            ARG_PUBLIC_KEY => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+            ARG_DELEGATION_RATE => DELEGATION_RATE,
+        },
+    )
+    .build();
+    builder.exec(add_bid_request).expect_success().commit();
+
+    let unbond_amount = U512::from(GENESIS_ACCOUNT_STAKE) - 1;
+    let withdraw_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_WITHDRAW_BID,
+        runtime_args! {
+            ARG_AMOUNT => unbond_amount,
+            ARG_PUBLIC_KEY => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+        },
+    )
+    .build();
+    builder.exec(withdraw_bid_request).expect_success().commit();
+
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
+    let unbond_list = unbond_purses
+        .get(&*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have unbond");
+    assert_eq!(unbond_list.len(), 1);
+    assert_eq!(unbond_list[0].era_of_creation(), INITIAL_ERA_ID);
+
+    let unbonding_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let balance_before = builder.get_purse_balance(unbonding_purse);
+
+    (unbonding_purse, balance_before, unbond_amount)
+}
+
+fn upgrade_unbonding_delay(builder: &mut InMemoryWasmTestBuilder, new_unbonding_delay: u64) {
+    let old_protocol_version = *DEFAULT_PROTOCOL_VERSION;
+    let sem_ver = old_protocol_version.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(old_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(0)
+        .with_new_unbonding_delay(new_unbonding_delay)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+}
+
+fn has_pending_unbond(builder: &mut InMemoryWasmTestBuilder) -> bool {
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
+    unbond_purses
+        .get(&*DEFAULT_ACCOUNT_ADDR)
+        .map(|unbond_list| !unbond_list.is_empty())
+        .unwrap_or_default()
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_pending_unbond_at_next_step_after_unbonding_delay_decreases() {
+    let timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let (unbonding_purse, balance_before, unbond_amount) = setup_pending_unbond(&mut builder);
+
+    // Three eras pass, which is far short of the default unbonding delay.
+    for _ in 0..3 {
+        builder.run_auction(timestamp_millis, Vec::new());
+    }
+    assert!(has_pending_unbond(&mut builder));
+
+    // The request was created long enough ago to already be eligible under the new delay.
+    let new_unbonding_delay = 2;
+    assert!(new_unbonding_delay < DEFAULT_UNBONDING_DELAY);
+    upgrade_unbonding_delay(&mut builder, new_unbonding_delay);
+
+    // The upgrade itself pays nothing out...
+    assert!(has_pending_unbond(&mut builder));
+    assert_eq!(builder.get_purse_balance(unbonding_purse), balance_before);
+
+    // ...but the pending request is re-evaluated against the new delay at the next step.
+    builder.run_auction(timestamp_millis, Vec::new());
+    assert!(!has_pending_unbond(&mut builder));
+    assert_eq!(
+        builder.get_purse_balance(unbonding_purse),
+        balance_before + unbond_amount
+    );
+}
+
+#[ignore]
+#[test]
+fn should_hold_pending_unbond_for_new_duration_after_unbonding_delay_increases() {
+    let timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let (unbonding_purse, balance_before, unbond_amount) = setup_pending_unbond(&mut builder);
+
+    // Advance to the era in which the request would be paid out under the default delay.
+    for _ in 0..DEFAULT_UNBONDING_DELAY {
+        builder.run_auction(timestamp_millis, Vec::new());
+    }
+    assert!(has_pending_unbond(&mut builder));
+
+    let extra_eras = 3;
+    upgrade_unbonding_delay(&mut builder, DEFAULT_UNBONDING_DELAY + extra_eras);
+
+    // The request is held back for the additional eras...
+    for _ in 0..extra_eras {
+        builder.run_auction(timestamp_millis, Vec::new());
+        assert!(has_pending_unbond(&mut builder));
+        assert_eq!(builder.get_purse_balance(unbonding_purse), balance_before);
+    }
+
+    // ...and paid out once the new delay has elapsed.
+    builder.run_auction(timestamp_millis, Vec::new());
+    assert!(!has_pending_unbond(&mut builder));
+    assert_eq!(
+        builder.get_purse_balance(unbonding_purse),
+        balance_before + unbond_amount
+    );
+}
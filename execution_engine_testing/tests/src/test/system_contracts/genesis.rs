@@ -1,11 +1,15 @@
+use std::path::Path;
+
 use num_traits::Zero;
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     internal::{
-        InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
-        DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+        utils, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_MAX_DELEGATION_RATE, DEFAULT_MAX_DELEGATION_RATE_CHANGE,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR, DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_WASM_CONFIG,
     },
     AccountHash,
 };
@@ -19,6 +23,7 @@ use casper_execution_engine::{
 use casper_types::{system::auction::DelegationRate, ProtocolVersion, PublicKey, SecretKey, U512};
 
 const GENESIS_CONFIG_HASH: [u8; 32] = [127; 32];
+const PRODUCTION_CHAINSPEC_PATH: &str = "../../resources/production/chainspec.toml";
 const ACCOUNT_1_BONDED_AMOUNT: u64 = 1_000_000;
 const ACCOUNT_2_BONDED_AMOUNT: u64 = 2_000_000;
 const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
@@ -67,9 +72,11 @@ fn should_run_genesis() {
     let system_config = *DEFAULT_SYSTEM_CONFIG;
     let validator_slots = DEFAULT_VALIDATOR_SLOTS;
     let auction_delay = DEFAULT_AUCTION_DELAY;
-    let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let max_delegation_rate = DEFAULT_MAX_DELEGATION_RATE;
+    let max_delegation_rate_change = DEFAULT_MAX_DELEGATION_RATE_CHANGE;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
     let genesis_timestamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let exec_config = ExecConfig::new(
@@ -78,9 +85,11 @@ fn should_run_genesis() {
         system_config,
         validator_slots,
         auction_delay,
-        locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        max_delegation_rate,
+        max_delegation_rate_change,
+        max_delegators_per_validator,
         genesis_timestamp,
     );
     let run_genesis_request =
@@ -138,9 +147,11 @@ fn should_track_total_token_supply_in_mint() {
     let protocol_version = ProtocolVersion::V1_0_0;
     let validator_slots = DEFAULT_VALIDATOR_SLOTS;
     let auction_delay = DEFAULT_AUCTION_DELAY;
-    let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let max_delegation_rate = DEFAULT_MAX_DELEGATION_RATE;
+    let max_delegation_rate_change = DEFAULT_MAX_DELEGATION_RATE_CHANGE;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
     let genesis_tiemstamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     let ee_config = ExecConfig::new(
         accounts.clone(),
@@ -148,9 +159,11 @@ fn should_track_total_token_supply_in_mint() {
         system_config,
         validator_slots,
         auction_delay,
-        locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        max_delegation_rate,
+        max_delegation_rate_change,
+        max_delegators_per_validator,
         genesis_tiemstamp,
     );
     let run_genesis_request =
@@ -175,3 +188,33 @@ fn should_track_total_token_supply_in_mint() {
         "unexpected total supply"
     )
 }
+
+#[ignore]
+#[test]
+fn should_run_genesis_from_production_chainspec() {
+    let chainspec_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(PRODUCTION_CHAINSPEC_PATH);
+    let run_genesis_request = utils::create_run_genesis_request_from_chainspec(&chainspec_path);
+    assert_eq!(
+        run_genesis_request.protocol_version(),
+        ProtocolVersion::from_parts(1, 1, 2)
+    );
+
+    let genesis_accounts = run_genesis_request.ee_config().accounts();
+    let validator_count = genesis_accounts
+        .iter()
+        .filter(|account| account.is_validator())
+        .count();
+    assert!(validator_count > 0, "chainspec should have validators");
+
+    let mut builder = utils::run_genesis_from_chainspec(&chainspec_path);
+
+    let bids = builder.get_bids();
+    assert_eq!(bids.len(), validator_count);
+
+    let delegator_count: usize = bids.values().map(|bid| bid.delegators().len()).sum();
+    let expected_delegator_count = genesis_accounts
+        .iter()
+        .filter(|account| account.is_delegator())
+        .count();
+    assert_eq!(delegator_count, expected_delegator_count);
+}
@@ -9,7 +9,8 @@ use casper_engine_test_support::{
 };
 use casper_execution_engine::shared::account::Account;
 use casper_types::{
-    account::AccountHash, runtime_args, system::handle_payment, Key, RuntimeArgs, URef, U512,
+    account::AccountHash, runtime_args, system::handle_payment, FeeBreakdown, Key, RuntimeArgs,
+    URef, U512,
 };
 
 const CONTRACT_FINALIZE_PAYMENT: &str = "finalize_payment.wasm";
@@ -162,6 +163,49 @@ fn finalize_payment_should_refund_to_specified_purse() {
     );
 }
 
+#[ignore]
+#[test]
+fn finalize_payment_should_report_fee_breakdown() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // The payment is settled whether or not session code succeeds.
+    for (index, (deploy_hash, session_code)) in
+        [([1; 32], "do_nothing.wasm"), ([2; 32], "revert.wasm")]
+            .iter()
+            .enumerate()
+    {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash(*deploy_hash)
+            .with_session_code(session_code, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .build();
+        let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+        let proposer_starting_balance = builder.get_proposer_purse_balance();
+        builder.exec(exec_request).commit();
+
+        let fee_breakdown = builder
+            .exec_fee_breakdown(index)
+            .expect("should have fee breakdown");
+        assert_eq!(
+            fee_breakdown,
+            FeeBreakdown {
+                payment_amount: *DEFAULT_PAYMENT,
+                gas_used: builder.exec_costs(index)[0].value(),
+                refund: U512::zero(),
+                proposer_reward: *DEFAULT_PAYMENT,
+            }
+        );
+        assert_eq!(
+            builder.get_proposer_purse_balance() - proposer_starting_balance,
+            fee_breakdown.proposer_reward
+        );
+    }
+}
+
 // ------------- utility functions -------------------- //
 
 fn get_handle_payment_payment_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
@@ -0,0 +1,281 @@
+use std::convert::TryFrom;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::core::{engine_state, execution};
+use casper_types::{
+    account::AccountHash, runtime_args, system::mint, ApiError, CLValue, ContractHash, Key,
+    RuntimeArgs, URef, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_MINT_APPROVE: &str = "mint_approve.wasm";
+const CONTRACT_MINT_TRANSFER_FROM: &str = "mint_transfer_from.wasm";
+const CONTRACT_MINT_TRANSFER_FROM_STORED: &str = "mint_transfer_from_stored.wasm";
+const ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
+const HASH_KEY_NAME_TRANSFER_FROM_STORED: &str = "mint_transfer_from_stored";
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_2_ADDR: AccountHash = AccountHash::new([2u8; 32]);
+const ARG_TARGET: &str = "target";
+
+const ALLOWANCE_AMOUNT: u64 = 1_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for account_hash in &[ACCOUNT_1_ADDR, ACCOUNT_2_ADDR] {
+        let fund_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! {
+                ARG_TARGET => *account_hash,
+                mint::ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE),
+            },
+        )
+        .build();
+        builder.exec(fund_request).expect_success().commit();
+    }
+
+    builder
+}
+
+fn default_account_purse(builder: &InMemoryWasmTestBuilder) -> URef {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse()
+}
+
+fn approve(builder: &mut InMemoryWasmTestBuilder, spender: AccountHash, amount: U512) {
+    let approve_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MINT_APPROVE,
+        runtime_args! {
+            mint::ARG_SPENDER => spender,
+            mint::ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+}
+
+fn transfer_from(builder: &mut InMemoryWasmTestBuilder, spender: AccountHash, amount: U512) {
+    let owner_purse = default_account_purse(builder);
+    let transfer_from_request = ExecuteRequestBuilder::standard(
+        spender,
+        CONTRACT_MINT_TRANSFER_FROM,
+        runtime_args! {
+            mint::ARG_OWNER_PURSE => owner_purse.addr(),
+            mint::ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_from_request).commit();
+}
+
+fn allowance(builder: &InMemoryWasmTestBuilder, spender: AccountHash) -> U512 {
+    let owner_purse = default_account_purse(builder);
+    let stored_value = builder
+        .query(None, Key::allowance(owner_purse.addr(), spender), &[])
+        .expect("should have allowance");
+    CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be U512")
+}
+
+fn assert_mint_error(builder: &InMemoryWasmTestBuilder, expected: mint::Error) {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result");
+    let exec_response = response.last().expect("should have response");
+    let error = exec_response.as_error().expect("should have error");
+    assert!(matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::Revert(ApiError::Mint(mint_error)))
+        if *mint_error == expected as u8
+    ));
+}
+
+#[ignore]
+#[test]
+fn should_transfer_from_within_allowance() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT)
+    );
+
+    let owner_balance_before = builder.get_purse_balance(default_account_purse(&builder));
+
+    let transfer_amount = U512::from(ALLOWANCE_AMOUNT / 4);
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, transfer_amount);
+    builder.expect_success();
+
+    let owner_balance_after = builder.get_purse_balance(default_account_purse(&builder));
+    assert_eq!(owner_balance_before - owner_balance_after, transfer_amount);
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT) - transfer_amount
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_after_allowance_is_exhausted() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+    builder.expect_success();
+    assert_eq!(allowance(&builder, ACCOUNT_1_ADDR), U512::zero());
+
+    let owner_balance_before = builder.get_purse_balance(default_account_purse(&builder));
+
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, U512::one());
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+
+    let owner_balance_after = builder.get_purse_balance(default_account_purse(&builder));
+    assert_eq!(owner_balance_before, owner_balance_after);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_more_than_allowance() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+
+    transfer_from(
+        &mut builder,
+        ACCOUNT_1_ADDR,
+        U512::from(ALLOWANCE_AMOUNT + 1),
+    );
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_after_allowance_is_revoked() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::zero());
+    assert_eq!(allowance(&builder, ACCOUNT_1_ADDR), U512::zero());
+
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, U512::one());
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+}
+
+#[ignore]
+#[test]
+fn should_replace_allowance_on_subsequent_approval() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+    approve(
+        &mut builder,
+        ACCOUNT_1_ADDR,
+        U512::from(ALLOWANCE_AMOUNT / 2),
+    );
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT / 2)
+    );
+
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_without_approval() {
+    let mut builder = setup();
+
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, U512::one());
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_using_another_spenders_allowance() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+
+    transfer_from(&mut builder, ACCOUNT_2_ADDR, U512::one());
+    assert_mint_error(&builder, mint::Error::InsufficientAllowance);
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_more_than_owner_balance() {
+    let mut builder = setup();
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::max_value());
+
+    let owner_balance = builder.get_purse_balance(default_account_purse(&builder));
+    transfer_from(&mut builder, ACCOUNT_1_ADDR, owner_balance + U512::one());
+    assert_mint_error(&builder, mint::Error::InsufficientFunds);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_within_a_contract_called_by_the_spender() {
+    let mut builder = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MINT_TRANSFER_FROM_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+    let contract_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .named_keys()
+        .get(HASH_KEY_NAME_TRANSFER_FROM_STORED)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    approve(&mut builder, ACCOUNT_1_ADDR, U512::from(ALLOWANCE_AMOUNT));
+
+    let owner_purse = default_account_purse(&builder);
+    let owner_balance_before = builder.get_purse_balance(owner_purse);
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        ACCOUNT_1_ADDR,
+        contract_hash,
+        ENTRY_POINT_TRANSFER_FROM,
+        runtime_args! {
+            mint::ARG_OWNER_PURSE => owner_purse.addr(),
+            mint::ARG_AMOUNT => U512::one(),
+        },
+    )
+    .build();
+    builder.exec(transfer_from_request).commit();
+
+    assert_mint_error(&builder, mint::Error::InvalidCaller);
+    assert_eq!(
+        allowance(&builder, ACCOUNT_1_ADDR),
+        U512::from(ALLOWANCE_AMOUNT)
+    );
+    assert_eq!(builder.get_purse_balance(owner_purse), owner_balance_before);
+}
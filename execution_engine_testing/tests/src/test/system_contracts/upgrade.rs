@@ -1,12 +1,18 @@
 use std::collections::BTreeMap;
 
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::internal::{
-    InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
-    DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_CONFIG,
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, UpgradeScenario,
+    UpgradeStep, DEFAULT_ACCOUNT_ADDR, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
+    DEFAULT_WASM_CONFIG,
 };
 
 use casper_execution_engine::{
-    core::engine_state::upgrade::ActivationPoint,
+    core::{
+        engine_state::{upgrade::ActivationPoint, Error as EngineError},
+        execution::Error,
+    },
     shared::{
         host_function_costs::HostFunctionCosts,
         opcode_costs::{
@@ -19,17 +25,24 @@ use casper_execution_engine::{
         },
         storage_costs::StorageCosts,
         stored_value::StoredValue,
-        wasm_config::{WasmConfig, DEFAULT_MAX_STACK_HEIGHT, DEFAULT_WASM_MAX_MEMORY},
+        wasm_config::{
+            WasmConfig, DEFAULT_MAX_ACTION_THRESHOLD, DEFAULT_MAX_ASSOCIATED_KEYS,
+            DEFAULT_MAX_CL_VALUE_SIZE, DEFAULT_MAX_CONTRACT_WASM_SIZE,
+            DEFAULT_MAX_NAMED_KEYS_COUNT, DEFAULT_MAX_NAMED_KEY_LENGTH, DEFAULT_MAX_STACK_HEIGHT,
+            DEFAULT_WASM_MAX_MEMORY,
+        },
     },
 };
 use casper_types::{
+    account::AccountHash,
+    bytesrepr::LengthEncoding,
+    contracts::EntryPoints,
+    runtime_args,
     system::{
-        auction::{
-            AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
-        },
+        auction::{AUCTION_DELAY_KEY, METHOD_GET_BID, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY},
         mint::ROUND_SEIGNIORAGE_RATE_KEY,
     },
-    CLValue, ProtocolVersion, U512,
+    ApiError, CLValue, Contract, Key, ProtocolVersion, RuntimeArgs, U512,
 };
 use num_rational::Ratio;
 
@@ -61,6 +74,12 @@ fn get_upgraded_wasm_config() -> WasmConfig {
     WasmConfig::new(
         DEFAULT_WASM_MAX_MEMORY,
         DEFAULT_MAX_STACK_HEIGHT * 2,
+        DEFAULT_MAX_NAMED_KEY_LENGTH,
+        DEFAULT_MAX_CL_VALUE_SIZE,
+        DEFAULT_MAX_NAMED_KEYS_COUNT,
+        DEFAULT_MAX_ASSOCIATED_KEYS,
+        DEFAULT_MAX_ACTION_THRESHOLD,
+        DEFAULT_MAX_CONTRACT_WASM_SIZE,
         opcode_cost,
         storage_costs,
         host_function_costs,
@@ -103,6 +122,40 @@ fn should_upgrade_only_protocol_version() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_preserve_auction_records_when_migrating_their_layout() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let bids_before = builder.get_bids();
+    assert!(!bids_before.is_empty());
+    let snapshot_before = builder.get_seigniorage_recipients_snapshot();
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .build()
+    };
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    assert_eq!(builder.get_bids(), bids_before);
+    assert_eq!(
+        builder.get_seigniorage_recipients_snapshot(),
+        snapshot_before
+    );
+}
+
 #[ignore]
 #[test]
 fn should_allow_only_wasm_costs_patch_version() {
@@ -397,61 +450,6 @@ fn should_upgrade_only_auction_delay() {
     )
 }
 
-#[ignore]
-#[test]
-fn should_upgrade_only_locked_funds_period() {
-    let mut builder = InMemoryWasmTestBuilder::default();
-
-    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
-
-    let sem_ver = PROTOCOL_VERSION.value();
-    let new_protocol_version =
-        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
-
-    let locked_funds_period_key = builder
-        .get_contract(builder.get_auction_contract_hash())
-        .expect("auction should exist")
-        .named_keys()[LOCKED_FUNDS_PERIOD_KEY];
-
-    let before_locked_funds_period_millis: u64 = builder
-        .query(None, locked_funds_period_key, &[])
-        .expect("should have locked funds period")
-        .as_cl_value()
-        .expect("should be a CLValue")
-        .clone()
-        .into_t()
-        .expect("should be u64");
-
-    let new_locked_funds_period_millis = before_locked_funds_period_millis + 1;
-
-    let mut upgrade_request = {
-        UpgradeRequestBuilder::new()
-            .with_current_protocol_version(PROTOCOL_VERSION)
-            .with_new_protocol_version(new_protocol_version)
-            .with_activation_point(DEFAULT_ACTIVATION_POINT)
-            .with_new_locked_funds_period_millis(new_locked_funds_period_millis)
-            .build()
-    };
-
-    builder
-        .upgrade_with_upgrade_request(&mut upgrade_request)
-        .expect_upgrade_success();
-
-    let after_locked_funds_period_millis: u64 = builder
-        .query(None, locked_funds_period_key, &[])
-        .expect("should have locked funds period")
-        .as_cl_value()
-        .expect("should be a CLValue")
-        .clone()
-        .into_t()
-        .expect("should be u64");
-
-    assert_eq!(
-        new_locked_funds_period_millis, after_locked_funds_period_millis,
-        "Should have upgraded locked funds period"
-    )
-}
-
 #[ignore]
 #[test]
 fn should_upgrade_only_round_seigniorage_rate() {
@@ -634,3 +632,344 @@ fn should_apply_global_state_upgrade() {
         "Should have modified locked funds period"
     );
 }
+
+#[ignore]
+#[test]
+fn should_apply_multi_step_upgrade_scenario() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let patch_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let minor_protocol_version = ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor + 1, 0);
+    let final_protocol_version = ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor + 1, 1);
+
+    let auction_named_keys = builder
+        .get_contract(builder.get_auction_contract_hash())
+        .expect("auction should exist")
+        .named_keys()
+        .clone();
+    let validator_slot_key = auction_named_keys[VALIDATOR_SLOTS_KEY];
+    let unbonding_delay_key = auction_named_keys[UNBONDING_DELAY_KEY];
+
+    let query_u64 = |builder: &InMemoryWasmTestBuilder, post_state, key| -> u64 {
+        builder
+            .query(Some(post_state), key, &[])
+            .expect("should have value")
+            .as_cl_value()
+            .expect("should be CLValue")
+            .clone()
+            .into_t()
+            .expect("should be u64")
+    };
+    let query_validator_slots = |builder: &InMemoryWasmTestBuilder, post_state| -> u32 {
+        builder
+            .query(Some(post_state), validator_slot_key, &[])
+            .expect("should have validator slots")
+            .as_cl_value()
+            .expect("should be CLValue")
+            .clone()
+            .into_t()
+            .expect("should be u32")
+    };
+
+    let genesis_post_state = builder.get_post_state_hash();
+    let before_validator_slots = query_validator_slots(&builder, genesis_post_state);
+    let new_validator_slots = before_validator_slots + 1;
+    let new_unbonding_delay = DEFAULT_UNBONDING_DELAY + 1;
+
+    let result = UpgradeScenario::new(PROTOCOL_VERSION)
+        .with_step(
+            UpgradeStep::new(patch_protocol_version).with_upgrade_request(
+                UpgradeRequestBuilder::new().with_new_validator_slots(new_validator_slots),
+            ),
+        )
+        .with_step(
+            UpgradeStep::new(minor_protocol_version)
+                .with_upgrade_request(
+                    UpgradeRequestBuilder::new().with_new_wasm_config(get_upgraded_wasm_config()),
+                )
+                .with_installer("do_nothing.wasm", RuntimeArgs::default()),
+        )
+        .with_step(
+            UpgradeStep::new(final_protocol_version).with_upgrade_request(
+                UpgradeRequestBuilder::new().with_new_unbonding_delay(new_unbonding_delay),
+            ),
+        )
+        .run(&mut builder);
+
+    assert_eq!(result.protocol_version(), final_protocol_version);
+    assert_eq!(result.post_state_hashes().len(), 3);
+    assert_eq!(result.post_state_hash(2), builder.get_post_state_hash());
+
+    // Each step builds on the changes of the previous ones.
+    assert_eq!(
+        query_validator_slots(&builder, result.post_state_hash(0)),
+        new_validator_slots
+    );
+    assert_eq!(
+        query_u64(&builder, result.post_state_hash(1), unbonding_delay_key),
+        DEFAULT_UNBONDING_DELAY
+    );
+    assert_eq!(
+        query_validator_slots(&builder, result.post_state_hash(2)),
+        new_validator_slots
+    );
+    assert_eq!(
+        query_u64(&builder, result.post_state_hash(2), unbonding_delay_key),
+        new_unbonding_delay
+    );
+
+    let minor_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(minor_protocol_version)
+        .expect("should have result")
+        .expect("should have protocol data");
+    assert_eq!(
+        *minor_protocol_data.wasm_config(),
+        get_upgraded_wasm_config()
+    );
+
+    let final_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(final_protocol_version)
+        .expect("should have result")
+        .expect("should have protocol data");
+    assert_eq!(
+        *final_protocol_data.wasm_config(),
+        get_upgraded_wasm_config()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_refresh_system_contract_entry_points_on_major_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let patch_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let major_protocol_version = ProtocolVersion::from_parts(sem_ver.major + 1, 0, 0);
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let genesis_auction = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    assert!(genesis_auction.has_entry_point(METHOD_GET_BID));
+
+    // Simulate an auction installed before `get_bid` existed.
+    let mut outdated_entry_points = EntryPoints::new();
+    for entry_point in genesis_auction.entry_points().clone().take_entry_points() {
+        if entry_point.name() != METHOD_GET_BID {
+            outdated_entry_points.add_entry_point(entry_point);
+        }
+    }
+    let outdated_auction = Contract::new(
+        genesis_auction.contract_package_hash(),
+        genesis_auction.contract_wasm_hash(),
+        genesis_auction.named_keys().clone(),
+        outdated_entry_points,
+        PROTOCOL_VERSION,
+    );
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::from(auction_hash),
+        StoredValue::Contract(outdated_auction),
+    );
+
+    UpgradeScenario::new(PROTOCOL_VERSION)
+        .with_step(
+            UpgradeStep::new(patch_protocol_version).with_upgrade_request(
+                UpgradeRequestBuilder::new().with_global_state_update(update_map),
+            ),
+        )
+        .run(&mut builder);
+
+    let outdated_auction = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    assert!(!outdated_auction.has_entry_point(METHOD_GET_BID));
+
+    UpgradeScenario::new(patch_protocol_version)
+        .with_step(UpgradeStep::new(major_protocol_version))
+        .run(&mut builder);
+
+    let upgraded_auction = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    assert!(upgraded_auction.has_entry_point(METHOD_GET_BID));
+    assert_eq!(
+        upgraded_auction.entry_points(),
+        genesis_auction.entry_points()
+    );
+    assert_eq!(upgraded_auction.named_keys(), genesis_auction.named_keys());
+    assert_eq!(upgraded_auction.protocol_version(), major_protocol_version);
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_length_encoding_without_changing_state_roots() {
+    const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42; 32]);
+    const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut fixed_builder = InMemoryWasmTestBuilder::default();
+    let mut compact_builder = InMemoryWasmTestBuilder::default();
+
+    for (builder, length_encoding) in [
+        (&mut fixed_builder, None),
+        (&mut compact_builder, Some(LengthEncoding::Leb128)),
+    ]
+    .iter_mut()
+    {
+        builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+        let mut upgrade_request_builder = UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT);
+        if let Some(length_encoding) = *length_encoding {
+            upgrade_request_builder =
+                upgrade_request_builder.with_new_length_encoding(length_encoding);
+        }
+        let mut upgrade_request = upgrade_request_builder.build();
+
+        builder
+            .upgrade_with_upgrade_request(&mut upgrade_request)
+            .expect_upgrade_success();
+
+        let upgraded_protocol_data = builder
+            .get_engine_state()
+            .get_protocol_data(new_protocol_version)
+            .expect("should have result")
+            .expect("should have protocol data");
+        assert_eq!(
+            upgraded_protocol_data.length_encoding(),
+            length_encoding.unwrap_or_default()
+        );
+
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! { "target" => ACCOUNT_1_ADDR, "amount" => U512::from(1_000_000_000u64) },
+        )
+        .with_protocol_version(new_protocol_version)
+        .build();
+
+        builder.exec(exec_request).expect_success().commit();
+
+        assert!(
+            builder.get_account(ACCOUNT_1_ADDR).is_some(),
+            "should read back account created after the upgrade"
+        );
+    }
+
+    assert_eq!(
+        fixed_builder.get_post_state_hash(),
+        compact_builder.get_post_state_hash(),
+        "length encoding of stored records should not affect state roots"
+    );
+}
+
+/// Upgrades to a new minor version whose wasm config is produced by `update_wasm_config`, returning
+/// the new protocol version.
+fn upgrade_wasm_config(
+    builder: &mut InMemoryWasmTestBuilder,
+    update_wasm_config: impl FnOnce(&mut WasmConfig),
+) -> ProtocolVersion {
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version = ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor + 1, 0);
+
+    let mut new_wasm_config = *DEFAULT_WASM_CONFIG;
+    update_wasm_config(&mut new_wasm_config);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_wasm_config(new_wasm_config)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    new_protocol_version
+}
+
+fn get_revert_error(builder: &InMemoryWasmTestBuilder, index: usize) -> ApiError {
+    let response = builder
+        .get_exec_result(index)
+        .expect("should have a response")
+        .to_owned();
+    let exec_result = response[0].as_error().expect("should have error");
+    *assert_matches!(exec_result, EngineError::Exec(Error::Revert(e)) => e, "{:?}", exec_result)
+}
+
+#[ignore]
+#[test]
+fn should_enforce_upgraded_max_associated_keys() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let new_protocol_version = upgrade_wasm_config(&mut builder, |wasm_config| {
+        wasm_config.max_associated_keys = 2;
+    });
+
+    let add_key_request = |account: AccountHash| {
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            "add_update_associated_key.wasm",
+            runtime_args! { "account" => account },
+        )
+        .with_protocol_version(new_protocol_version)
+        .build()
+    };
+
+    builder
+        .exec(add_key_request(AccountHash::new([1; 32])))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(add_key_request(AccountHash::new([2; 32])))
+        .commit();
+
+    assert_eq!(get_revert_error(&builder, 1), ApiError::MaxKeysLimit);
+}
+
+#[ignore]
+#[test]
+fn should_enforce_upgraded_max_action_threshold() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let new_protocol_version = upgrade_wasm_config(&mut builder, |wasm_config| {
+        wasm_config.max_action_threshold = 100;
+    });
+
+    // Sets the key management threshold to 101.
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        "key_management_thresholds.wasm",
+        runtime_args! { "stage" => String::from("init") },
+    )
+    .with_protocol_version(new_protocol_version)
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert_eq!(
+        get_revert_error(&builder, 0),
+        ApiError::MaxThresholdExceeded
+    );
+}
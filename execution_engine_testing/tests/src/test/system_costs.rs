@@ -10,7 +10,10 @@ use casper_engine_test_support::{
     AccountHash, DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
 use casper_execution_engine::{
-    core::engine_state::{genesis::GenesisValidator, upgrade::ActivationPoint, GenesisAccount},
+    core::{
+        engine_state::{genesis::GenesisValidator, upgrade::ActivationPoint, GenesisAccount},
+        runtime::STANDARD_PAYMENT_COST_PROTOCOL_VERSION,
+    },
     shared::{
         gas::Gas,
         host_function_costs::{Cost, HostFunction, HostFunctionCosts},
@@ -30,11 +33,16 @@ use casper_execution_engine::{
                 MintCosts, DEFAULT_BALANCE_COST, DEFAULT_MINT_COST,
                 DEFAULT_REDUCE_TOTAL_SUPPLY_COST, DEFAULT_TRANSFER_COST,
             },
-            standard_payment_costs::StandardPaymentCosts,
+            standard_payment_costs::{StandardPaymentCosts, DEFAULT_PAY_COST},
             SystemConfig,
         },
         wasm,
-        wasm_config::{WasmConfig, DEFAULT_MAX_STACK_HEIGHT, DEFAULT_WASM_MAX_MEMORY},
+        wasm_config::{
+            WasmConfig, DEFAULT_MAX_ACTION_THRESHOLD, DEFAULT_MAX_ASSOCIATED_KEYS,
+            DEFAULT_MAX_CL_VALUE_SIZE, DEFAULT_MAX_CONTRACT_WASM_SIZE,
+            DEFAULT_MAX_NAMED_KEYS_COUNT, DEFAULT_MAX_NAMED_KEY_LENGTH, DEFAULT_MAX_STACK_HEIGHT,
+            DEFAULT_WASM_MAX_MEMORY,
+        },
     },
     storage::protocol_data::DEFAULT_WASMLESS_TRANSFER_COST,
 };
@@ -755,6 +763,57 @@ fn should_verify_do_nothing_charges_only_for_standard_payment() {
     assert_eq!(builder.last_exec_gas_cost(), Gas::new(U512::zero()));
 }
 
+#[ignore]
+#[test]
+fn should_charge_fixed_cost_for_standard_payment_once_activated() {
+    const NEW_PAY_COST: u32 = DEFAULT_PAY_COST * 2;
+
+    let new_system_config = SystemConfig::new(
+        DEFAULT_WASMLESS_TRANSFER_COST,
+        AuctionCosts::default(),
+        MintCosts::default(),
+        HandlePaymentCosts::default(),
+        StandardPaymentCosts { pay: NEW_PAY_COST },
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*OLD_PROTOCOL_VERSION)
+        .with_new_protocol_version(STANDARD_PAYMENT_COST_PROTOCOL_VERSION)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_system_config(new_system_config)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let do_nothing_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_bytes(wasm::do_nothing_bytes(), RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash([42; 32])
+            .build();
+
+        ExecuteRequestBuilder::from_deploy_item(deploy_item)
+            .with_protocol_version(STANDARD_PAYMENT_COST_PROTOCOL_VERSION)
+            .build()
+    };
+
+    builder.exec(do_nothing_request).commit().expect_success();
+
+    assert_eq!(
+        builder.last_exec_gas_cost(),
+        Gas::new(U512::from(NEW_PAY_COST))
+    );
+}
+
 #[ignore]
 #[test]
 fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
@@ -829,11 +888,23 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         remove_contract_user_group_urefs: HostFunction::fixed(0),
         print: HostFunction::fixed(0),
         blake2b: HostFunction::fixed(0),
+        lock_contract_package: HostFunction::fixed(0),
+        emit_event: HostFunction::fixed(0),
+        call_contract_with_gas_limit: HostFunction::fixed(0),
+        call_versioned_contract_with_gas_limit: HostFunction::fixed(0),
+        transfer_from_purse_to_account_with_receipt: HostFunction::fixed(0),
+        provision_contract_user_group_urefs: HostFunction::fixed(0),
     };
 
     let new_wasm_config = WasmConfig::new(
         DEFAULT_WASM_MAX_MEMORY,
         DEFAULT_MAX_STACK_HEIGHT,
+        DEFAULT_MAX_NAMED_KEY_LENGTH,
+        DEFAULT_MAX_CL_VALUE_SIZE,
+        DEFAULT_MAX_NAMED_KEYS_COUNT,
+        DEFAULT_MAX_ASSOCIATED_KEYS,
+        DEFAULT_MAX_ACTION_THRESHOLD,
+        DEFAULT_MAX_CONTRACT_WASM_SIZE,
         new_opcode_costs,
         new_storage_costs,
         new_host_function_costs,
@@ -1,11 +1,17 @@
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
-    DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::{
+    core::{engine_state, execution},
+    shared::stored_value::StoredValue,
 };
-use casper_execution_engine::shared::stored_value::StoredValue;
 use casper_types::{
-    contracts::{ContractVersion, CONTRACT_INITIAL_VERSION},
-    runtime_args, CLValue, ContractPackageHash, RuntimeArgs,
+    account::AccountHash,
+    contracts::{self, ContractVersion, CONTRACT_INITIAL_VERSION},
+    runtime_args, ApiError, CLValue, ContractHash, ContractPackageHash, RuntimeArgs, U512,
 };
 
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
@@ -32,6 +38,22 @@ const ARG_CONTRACT_PACKAGE: &str = "contract_package";
 const ARG_VERSION: &str = "version";
 const ARG_NEW_PURSE_NAME: &str = "new_purse_name";
 const ARG_IS_LOCKED: &str = "is_locked";
+const DO_NOTHING_ACCESS_KEY_NAME: &str = "do_nothing_access";
+const CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME: &str = "contract_package_access";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ARG_ACTION: &str = "action";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ACCESS_KEY_NAME: &str = "access_key_name";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ACTION_ADD_VERSION: &str = "add_version";
+const ACTION_DISABLE_VERSION: &str = "disable_version";
+const ACTION_ATTENUATE_ACCESS_KEY: &str = "attenuate_access_key";
+const ACTION_LOCK_PACKAGE: &str = "lock_package";
+const ACTION_CREATE_GROUP: &str = "create_group";
+const ARG_GROUP_LABEL: &str = "group_label";
+const GROUP_LABEL: &str = "group";
 
 /// Performs define and execution of versioned contracts, calling them directly from hash
 #[ignore]
@@ -645,3 +667,311 @@ fn should_fail_upgrade_for_locked_contract() {
         assert!(builder.exec(exec_request).is_error());
     }
 }
+
+/// Stores the "do nothing" contract under the default account and funds `ACCOUNT_1_ADDR`.
+///
+/// Returns the hashes of the stored contract package and of its initial contract version.
+fn setup_do_nothing_package() -> (InMemoryWasmTestBuilder, ContractPackageHash, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let store_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", DO_NOTHING_STORED_CONTRACT_NAME),
+        RuntimeArgs::default(),
+    )
+    .build();
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE),
+        },
+    )
+    .build();
+
+    builder.exec(store_request).expect_success().commit();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let package_hash: ContractPackageHash = account
+        .named_keys()
+        .get(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash")
+        .into_hash()
+        .expect("should be hash")
+        .into();
+    let contract_hash: ContractHash = account
+        .named_keys()
+        .get(DO_NOTHING_HASH_KEY_NAME)
+        .expect("should have contract hash")
+        .into_hash()
+        .expect("should be hash")
+        .into();
+
+    (builder, package_hash, contract_hash)
+}
+
+fn assert_last_contract_header_error(
+    builder: &InMemoryWasmTestBuilder,
+    expected_error: contracts::Error,
+) {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last response");
+    let error = response
+        .last()
+        .expect("should have response")
+        .as_error()
+        .expect("should have error");
+    let expected_api_error = ApiError::from(expected_error);
+    assert_matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::Revert(api_error))
+            if *api_error == expected_api_error
+    );
+}
+
+fn assert_last_locked_contract_error(
+    builder: &InMemoryWasmTestBuilder,
+    expected_package_hash: ContractPackageHash,
+) {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last response");
+    let error = response
+        .last()
+        .expect("should have response")
+        .as_error()
+        .expect("should have error");
+    assert_matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::LockedContract(package_hash))
+            if *package_hash == expected_package_hash
+    );
+}
+
+fn lock_package(builder: &mut InMemoryWasmTestBuilder, package_hash: ContractPackageHash) {
+    let lock_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_LOCK_PACKAGE,
+            ARG_CONTRACT_PACKAGE => package_hash,
+        },
+    )
+    .build();
+
+    builder.exec(lock_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_add_contract_version_without_access_key() {
+    let (mut builder, package_hash, _contract_hash) = setup_do_nothing_package();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_ADD_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert_last_contract_header_error(&builder, contracts::Error::MissingAccessKey);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert_eq!(contract_package.versions().len(), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_disable_contract_version_without_access_key() {
+    let (mut builder, package_hash, contract_hash) = setup_do_nothing_package();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_DISABLE_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+            ARG_CONTRACT_HASH => contract_hash,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert_last_contract_header_error(&builder, contracts::Error::MissingAccessKey);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert!(contract_package.disabled_versions().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_manage_contract_versions_with_read_only_access_key() {
+    let (mut builder, package_hash, contract_hash) = setup_do_nothing_package();
+
+    let attenuate_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_ATTENUATE_ACCESS_KEY,
+            ARG_ACCESS_KEY_NAME => DO_NOTHING_ACCESS_KEY_NAME,
+        },
+    )
+    .build();
+
+    builder.exec(attenuate_request).expect_success().commit();
+
+    let add_version_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_ADD_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+        },
+    )
+    .build();
+
+    builder.exec(add_version_request).commit();
+
+    assert_last_contract_header_error(&builder, contracts::Error::InvalidAccessKeyRights);
+
+    let disable_version_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_DISABLE_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+            ARG_CONTRACT_HASH => contract_hash,
+        },
+    )
+    .build();
+
+    builder.exec(disable_version_request).commit();
+
+    assert_last_contract_header_error(&builder, contracts::Error::InvalidAccessKeyRights);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert_eq!(contract_package.versions().len(), 1);
+    assert!(contract_package.disabled_versions().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_add_contract_version_to_locked_package() {
+    let (mut builder, package_hash, _contract_hash) = setup_do_nothing_package();
+
+    lock_package(&mut builder, package_hash);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert!(contract_package.is_locked());
+
+    let add_version_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_ADD_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+        },
+    )
+    .build();
+
+    builder.exec(add_version_request).commit();
+
+    assert_last_locked_contract_error(&builder, package_hash);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert_eq!(contract_package.versions().len(), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_change_groups_of_locked_package() {
+    let (mut builder, package_hash, contract_hash) = setup_do_nothing_package();
+
+    lock_package(&mut builder, package_hash);
+
+    let create_group_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_CREATE_GROUP,
+            ARG_CONTRACT_PACKAGE => package_hash,
+            ARG_GROUP_LABEL => GROUP_LABEL,
+        },
+    )
+    .build();
+
+    builder.exec(create_group_request).commit();
+
+    assert_last_locked_contract_error(&builder, package_hash);
+
+    let disable_version_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_DISABLE_VERSION,
+            ARG_CONTRACT_PACKAGE => package_hash,
+            ARG_CONTRACT_HASH => contract_hash,
+        },
+    )
+    .build();
+
+    builder.exec(disable_version_request).commit();
+
+    assert_last_locked_contract_error(&builder, package_hash);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert!(contract_package.groups().is_empty());
+    assert!(contract_package.disabled_versions().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_lock_package_without_access_key() {
+    let (mut builder, package_hash, _contract_hash) = setup_do_nothing_package();
+
+    let lock_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        &format!("{}.wasm", CONTRACT_PACKAGE_ACCESS_CONTRACT_NAME),
+        runtime_args! {
+            ARG_ACTION => ACTION_LOCK_PACKAGE,
+            ARG_CONTRACT_PACKAGE => package_hash,
+        },
+    )
+    .build();
+
+    builder.exec(lock_request).commit();
+
+    assert_last_contract_header_error(&builder, contracts::Error::MissingAccessKey);
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert!(!contract_package.is_locked());
+}
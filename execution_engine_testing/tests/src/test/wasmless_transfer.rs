@@ -13,6 +13,7 @@ use casper_execution_engine::{
         execution::Error as ExecError,
     },
     shared::{
+        account::ActionThresholds,
         gas::Gas,
         motes::Motes,
         system_config::{
@@ -23,7 +24,7 @@ use casper_execution_engine::{
     storage::protocol_data::DEFAULT_WASMLESS_TRANSFER_COST,
 };
 use casper_types::{
-    account::AccountHash,
+    account::{AccountHash, Weight},
     runtime_args,
     system::{handle_payment, mint},
     AccessRights, ApiError, Key, ProtocolVersion, RuntimeArgs, URef, U512,
@@ -598,6 +599,105 @@ fn transfer_wasmless_should_create_target_if_it_doesnt_exist() {
     );
 }
 
+#[ignore]
+#[test]
+fn transfer_wasmless_should_create_target_with_default_keys_and_thresholds() {
+    let wasmless_transfer_gas_cost = Gas::from(DEFAULT_WASMLESS_TRANSFER_COST);
+    let wasmless_transfer_cost = Motes::from_gas(
+        wasmless_transfer_gas_cost,
+        WASMLESS_TRANSFER_FIXED_GAS_PRICE,
+    )
+    .expect("gas overflow");
+
+    let create_account_2: bool = false;
+    let mut builder = init_wasmless_transform_builder(create_account_2);
+    let account_1_to_account_2_amount: U512 = wasmless_transfer_cost.value() * 2;
+    let account_2_to_account_1_amount: U512 = U512::one();
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1")
+        .main_purse();
+
+    let runtime_args = runtime_args! {
+       mint::ARG_TARGET => ACCOUNT_2_ADDR,
+       mint::ARG_AMOUNT => account_1_to_account_2_amount,
+       mint::ARG_ID => <Option<u64>>::None
+    };
+
+    let no_wasm_transfer_request_1 = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(ACCOUNT_1_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args)
+            .with_authorization_keys(&[ACCOUNT_1_ADDR])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+
+    builder
+        .exec(no_wasm_transfer_request_1)
+        .expect_success()
+        .commit();
+
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("account 2 should exist");
+
+    assert_eq!(account_2.account_hash(), ACCOUNT_2_ADDR);
+    assert!(
+        account_2.named_keys().is_empty(),
+        "account 2 should not have named keys"
+    );
+    assert_eq!(
+        account_2.associated_keys().collect::<Vec<_>>(),
+        vec![(&ACCOUNT_2_ADDR, &Weight::new(1))],
+        "account 2 should only have its own key associated"
+    );
+    assert_eq!(
+        account_2.action_thresholds(),
+        &ActionThresholds::default(),
+        "account 2 should have default action thresholds"
+    );
+
+    // The newly created account should be able to authorize a wasmless transfer of its own.
+    let account_1_balance_before = builder.get_purse_balance(account_1_purse);
+
+    let runtime_args = runtime_args! {
+       mint::ARG_TARGET => ACCOUNT_1_ADDR,
+       mint::ARG_AMOUNT => account_2_to_account_1_amount,
+       mint::ARG_ID => <Option<u64>>::None
+    };
+
+    let no_wasm_transfer_request_2 = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(ACCOUNT_2_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args)
+            .with_authorization_keys(&[ACCOUNT_2_ADDR])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+
+    builder
+        .exec(no_wasm_transfer_request_2)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        account_1_to_account_2_amount
+            - account_2_to_account_1_amount
+            - wasmless_transfer_cost.value(),
+        "account 2 ending balance incorrect"
+    );
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        account_1_balance_before + account_2_to_account_1_amount,
+        "account 1 ending balance incorrect"
+    );
+}
+
 fn get_default_account_named_uref(builder: &mut InMemoryWasmTestBuilder, name: &str) -> URef {
     let default_account = builder
         .get_account(*DEFAULT_ACCOUNT_ADDR)
@@ -19,7 +19,7 @@ use tracing::{error, info, trace};
 use crate::config;
 use casper_node::{
     logging,
-    reactor::{initializer, joiner, validator, ReactorExit, Runner},
+    reactor::{initializer, joiner, validator, Finalize, ReactorExit, Runner},
     setup_signal_hooks,
     types::ExitCode,
     utils::WithDir,
@@ -196,7 +196,9 @@ impl Cli {
                 let mut validator_runner =
                     Runner::<validator::Reactor>::with_metrics(config, &mut rng, &registry).await?;
 
-                match validator_runner.run(&mut rng).await {
+                let reactor_exit = validator_runner.run(&mut rng).await;
+                validator_runner.into_inner().finalize().await;
+                match reactor_exit {
                     ReactorExit::ProcessShouldExit(exit_code) => Ok(exit_code as i32),
                     reactor_exit => {
                         error!("validator should not exit with {:?}", reactor_exit);
@@ -32,6 +32,7 @@ pub(crate) mod storage;
 
 use crate::{
     effect::{EffectBuilder, Effects},
+    types::ComponentHealth,
     NodeRng,
 };
 
@@ -76,3 +77,13 @@ pub trait Component<REv> {
         event: Self::Event,
     ) -> Effects<Self::Event>;
 }
+
+/// A component able to report on its own health.
+///
+/// Reports are aggregated by the reactor into a `HealthSnapshot`, which is served via the status
+/// endpoints and logged periodically.  Computing a report must be cheap, as it is not allowed to
+/// block the reactor.
+pub(crate) trait HealthReport {
+    /// Returns the current health of the component.
+    fn health(&self) -> ComponentHealth;
+}
@@ -6,6 +6,7 @@ use std::{
     collections::{BTreeMap, HashMap, VecDeque},
     convert::Infallible,
     fmt::Debug,
+    time::Instant,
 };
 
 use datasize::DataSize;
@@ -13,7 +14,7 @@ use itertools::Itertools;
 use prometheus::Registry;
 use semver::Version;
 use smallvec::SmallVec;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 use casper_execution_engine::{
     core::engine_state::{
@@ -42,7 +43,7 @@ use crate::{
     },
     types::{
         Block, BlockHash, BlockHeader, BlockLike, Deploy, DeployHash, DeployHeader, FinalizedBlock,
-        NodeId,
+        NodeId, TimeDiff,
     },
     NodeRng,
 };
@@ -109,6 +110,10 @@ impl InitialState {
 
 type BlockHeight = u64;
 
+/// The maximum number of executed deploys whose effects may be awaiting their commit before
+/// execution of the next deploy is held back.
+const MAX_UNCOMMITTED_DEPLOYS: usize = 1;
+
 /// The Block executor component.
 #[derive(DataSize, Debug, Default)]
 pub(crate) struct BlockExecutor {
@@ -123,7 +128,13 @@ pub(crate) struct BlockExecutor {
     parent_map: HashMap<BlockHeight, ExecutedBlockSummary>,
     /// Finalized blocks waiting for their pre-state hash to start executing.
     exec_queue: HashMap<BlockHeight, (FinalizedBlock, VecDeque<Deploy>)>,
-    /// Metrics to track current chain height.
+    /// Blocks currently being executed.
+    #[data_size(skip)]
+    executing: HashMap<BlockHeight, State>,
+    /// How far execution of a block may lag behind its finalization before this is announced, or
+    /// `None` if lagging is expected, e.g. while catching up with the network.
+    execution_lag_threshold: Option<TimeDiff>,
+    /// Metrics to track current chain height and execution times.
     #[data_size(skip)]
     metrics: BlockExecutorMetrics,
 }
@@ -133,6 +144,7 @@ impl BlockExecutor {
         initial_state_root_hash: Digest,
         initial_block_header: Option<&BlockHeader>,
         protocol_version: Version,
+        execution_lag_threshold: Option<TimeDiff>,
         registry: Registry,
     ) -> Self {
         let metrics = BlockExecutorMetrics::new(registry).unwrap();
@@ -145,6 +157,8 @@ impl BlockExecutor {
             ),
             parent_map: HashMap::new(),
             exec_queue: HashMap::new(),
+            executing: HashMap::new(),
+            execution_lag_threshold,
             metrics,
         }
     }
@@ -226,10 +240,13 @@ impl BlockExecutor {
         // The state hash of the last execute-commit cycle is used as the block's post state
         // hash.
         let next_height = state.finalized_block.height() + 1;
-        // Update the metric.
+        // Update the metrics.
         self.metrics
             .chain_height
             .set(state.finalized_block.height() as i64);
+        self.metrics
+            .block_execution_time
+            .observe(state.block_start.elapsed().as_secs_f64());
         let block = self.create_block(
             state.finalized_block,
             state.state_root_hash,
@@ -250,78 +267,183 @@ impl BlockExecutor {
         effects
     }
 
-    /// Executes the first deploy in `state.remaining_deploys`, or creates the executed block if
-    /// there are no remaining deploys left.
-    fn execute_next_deploy_or_create_block<REv: ReactorEventT>(
+    /// Runs the step if `state.finalized_block` is a switch block, or creates the executed block
+    /// otherwise.
+    fn run_step_or_create_block<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
-        mut state: Box<State>,
+        state: Box<State>,
     ) -> Effects<Event> {
-        let next_deploy = match state.remaining_deploys.pop_front() {
-            Some(deploy) => deploy,
+        let era_end = match state.finalized_block.era_report() {
+            Some(era_end) => era_end,
+            // Not at a switch block, so we don't need to have next_era_validators when
+            // constructing the next block
+            None => return self.finalize_block_execution(effect_builder, state, None),
+        };
+        let reward_items = era_end
+            .rewards
+            .iter()
+            .map(|(&vid, &value)| RewardItem::new(vid, value))
+            .collect();
+        let slash_items = era_end
+            .equivocators
+            .iter()
+            .map(|&vid| SlashItem::new(vid))
+            .collect();
+        let evict_items = era_end
+            .inactive_validators
+            .iter()
+            .map(|&vid| EvictItem::new(vid))
+            .collect();
+        let era_end_timestamp_millis = state.finalized_block.timestamp().millis();
+        let request = StepRequest {
+            pre_state_hash: state.state_root_hash.into(),
+            protocol_version: self.protocol_version,
+            reward_items,
+            slash_items,
+            evict_items,
+            run_auction: true,
+            next_era_id: state.finalized_block.era_id().successor().into(),
+            era_end_timestamp_millis,
+        };
+        effect_builder
+            .run_step(request)
+            .event(|result| Event::RunStepResult { state, result })
+    }
+
+    /// Commits the effects of the earliest executed but uncommitted deploy and executes the next
+    /// deploy of the block at `height`, unless either is already in progress.  Once all deploys
+    /// are executed and committed, runs the step or creates the executed block.
+    ///
+    /// The next deploy is executed against the committed state overlaid with the uncommitted
+    /// effects, so that writing these to the trie and computing the new state root hash overlaps
+    /// with its execution.
+    fn advance_block_execution<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        height: BlockHeight,
+    ) -> Effects<Event> {
+        let protocol_version = self.protocol_version;
+        let state = match self.executing.get_mut(&height) {
+            Some(state) => state,
             None => {
-                let era_end = match state.finalized_block.era_report() {
-                    Some(era_end) => era_end,
-                    // Not at a switch block, so we don't need to have next_era_validators when
-                    // constructing the next block
-                    None => return self.finalize_block_execution(effect_builder, state, None),
-                };
-                let reward_items = era_end
-                    .rewards
-                    .iter()
-                    .map(|(&vid, &value)| RewardItem::new(vid, value))
-                    .collect();
-                let slash_items = era_end
-                    .equivocators
-                    .iter()
-                    .map(|&vid| SlashItem::new(vid))
-                    .collect();
-                let evict_items = era_end
-                    .inactive_validators
-                    .iter()
-                    .map(|&vid| EvictItem::new(vid))
-                    .collect();
-                let era_end_timestamp_millis = state.finalized_block.timestamp().millis();
-                let request = StepRequest {
-                    pre_state_hash: state.state_root_hash.into(),
-                    protocol_version: self.protocol_version,
-                    reward_items,
-                    slash_items,
-                    evict_items,
-                    run_auction: true,
-                    next_era_id: state.finalized_block.era_id().successor().into(),
-                    era_end_timestamp_millis,
-                };
-                return effect_builder
-                    .run_step(request)
-                    .event(|result| Event::RunStepResult { state, result });
+                error!(%height, "no execution in progress for block");
+                return Effects::new();
             }
         };
+
+        if state.is_done() {
+            let state = self
+                .executing
+                .remove(&height)
+                .expect("should have state of executing block");
+            return self.run_step_or_create_block(effect_builder, Box::new(state));
+        }
+
+        let mut effects = Effects::new();
+        if !state.commit_in_flight {
+            if let Some(execution_effects) = state.pending_effects.front() {
+                state.commit_in_flight = true;
+                let pre_state_hash = state.state_root_hash;
+                effects.extend(
+                    effect_builder
+                        .request_commit(protocol_version, pre_state_hash, execution_effects.clone())
+                        .event(move |commit_result| Event::CommitExecutionEffects {
+                            height,
+                            pre_state_hash,
+                            commit_result,
+                        }),
+                );
+            }
+        }
+
+        if state.execution_in_flight || state.pending_effects.len() > MAX_UNCOMMITTED_DEPLOYS {
+            return effects;
+        }
+        let next_deploy = match state.remaining_deploys.pop_front() {
+            Some(deploy) => deploy,
+            None => return effects,
+        };
         let deploy_hash = *next_deploy.id();
         let deploy_header = next_deploy.header().clone();
         let deploy_item = DeployItem::from(next_deploy);
+        state.execution_in_flight = true;
+        state.deploy_start = Instant::now();
 
+        let pre_state_hash = state.state_root_hash;
         let execute_request = ExecuteRequest::new(
-            state.state_root_hash.into(),
+            pre_state_hash.into(),
             state.finalized_block.timestamp().millis(),
             vec![Ok(deploy_item)],
-            self.protocol_version,
+            protocol_version,
             state.finalized_block.proposer(),
-        );
+        )
+        .with_pending_effects(state.uncommitted_effects());
 
         // TODO: this is currently working coincidentally because we are passing only one
         // deploy_item per exec. The execution results coming back from the ee lacks the
         // mapping between deploy_hash and execution result, and this outer logic is enriching it
         // with the deploy hash. If we were passing multiple deploys per exec the relation between
         // the deploy and the execution results would be lost.
-        effect_builder
-            .request_execute(execute_request)
-            .event(move |result| Event::DeployExecutionResult {
-                state,
-                deploy_hash,
-                deploy_header,
-                result,
-            })
+        effects.extend(
+            effect_builder
+                .request_execute(execute_request)
+                .event(move |result| Event::DeployExecutionResult {
+                    height,
+                    pre_state_hash,
+                    deploy_hash,
+                    deploy_header,
+                    result,
+                }),
+        );
+        effects
+    }
+
+    /// Starts executing the deploys of `finalized_block` on top of `state_root_hash`.
+    ///
+    /// If the block was finalized longer ago than the configured threshold, this is announced
+    /// along with an estimate of how long its execution will take.
+    fn start_block_execution<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        finalized_block: FinalizedBlock,
+        deploys: VecDeque<Deploy>,
+        state_root_hash: Digest,
+    ) -> Effects<Event> {
+        let mut effects = Effects::new();
+        let lag = finalized_block.timestamp().elapsed();
+        if matches!(self.execution_lag_threshold, Some(threshold) if lag > threshold) {
+            let height = finalized_block.height();
+            let estimated_duration = self.metrics.estimated_execution_time(deploys.len());
+            warn!(
+                %height,
+                %lag,
+                %estimated_duration,
+                "block execution lags behind finalization"
+            );
+            effects.extend(
+                effect_builder
+                    .announce_execution_lagging(height, lag, estimated_duration)
+                    .ignore(),
+            );
+        }
+
+        let height = finalized_block.height();
+        let now = Instant::now();
+        let state = State {
+            finalized_block,
+            remaining_deploys: deploys,
+            execution_results: HashMap::new(),
+            state_root_hash,
+            pending_effects: VecDeque::new(),
+            commit_in_flight: false,
+            execution_in_flight: false,
+            block_start: now,
+            deploy_start: now,
+        };
+        let _ = self.executing.insert(height, state);
+        effects.extend(self.advance_block_execution(effect_builder, height));
+        effects
     }
 
     fn handle_get_deploys_result<REv: ReactorEventT>(
@@ -331,13 +453,7 @@ impl BlockExecutor {
         deploys: VecDeque<Deploy>,
     ) -> Effects<Event> {
         if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
-            let state = Box::new(State {
-                finalized_block,
-                remaining_deploys: deploys,
-                execution_results: HashMap::new(),
-                state_root_hash,
-            });
-            self.execute_next_deploy_or_create_block(effect_builder, state)
+            self.start_block_execution(effect_builder, finalized_block, deploys, state_root_hash)
         } else {
             // Didn't find parent in the `parent_map` cache.
             // Read it from the storage.
@@ -371,13 +487,12 @@ impl BlockExecutor {
                 debug!("no pre-state hash for height {}", height);
                 // re-check the parent map - the parent might have been executed in the meantime!
                 if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
-                    let state = Box::new(State {
+                    self.start_block_execution(
+                        effect_builder,
                         finalized_block,
-                        remaining_deploys: deploys,
-                        execution_results: HashMap::new(),
+                        deploys,
                         state_root_hash,
-                    });
-                    self.execute_next_deploy_or_create_block(effect_builder, state)
+                    )
                 } else {
                     // The parent block has not been executed yet; delay handling.
                     self.exec_queue.insert(height, (finalized_block, deploys));
@@ -395,15 +510,26 @@ impl BlockExecutor {
         }
     }
 
-    /// Commits the execution effects.
-    fn commit_execution_effects<REv: ReactorEventT>(
+    /// Records the result of executing a deploy and queues its effects to be committed.
+    fn handle_deploy_execution_result<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
-        mut state: Box<State>,
+        height: BlockHeight,
         deploy_hash: DeployHash,
         deploy_header: DeployHeader,
         execution_results: ExecutionResults,
     ) -> Effects<Event> {
+        let state = match self.executing.get_mut(&height) {
+            Some(state) => state,
+            None => {
+                error!(%height, %deploy_hash, "no execution in progress for block of deploy");
+                return Effects::new();
+            }
+        };
+        self.metrics
+            .deploy_execution_time
+            .observe(state.deploy_start.elapsed().as_secs_f64());
+
         let ee_execution_result = execution_results
             .into_iter()
             .exactly_one()
@@ -433,12 +559,29 @@ impl BlockExecutor {
                 effect
             }
         };
-        effect_builder
-            .request_commit(state.state_root_hash, execution_effect.transforms)
-            .event(|commit_result| Event::CommitExecutionEffects {
-                state,
-                commit_result,
-            })
+        state.pending_effects.push_back(execution_effect.transforms);
+        state.execution_in_flight = false;
+        self.advance_block_execution(effect_builder, height)
+    }
+
+    /// Advances the state root hash of the block at `height` past the committed effects.
+    fn handle_commit_execution_effects<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        height: BlockHeight,
+        state_root_hash: Digest,
+    ) -> Effects<Event> {
+        let state = match self.executing.get_mut(&height) {
+            Some(state) => state,
+            None => {
+                error!(%height, "no execution in progress for block of committed effects");
+                return Effects::new();
+            }
+        };
+        let _ = state.pending_effects.pop_front();
+        state.commit_in_flight = false;
+        state.state_root_hash = state_root_hash;
+        self.advance_block_execution(effect_builder, height)
     }
 
     fn create_block(
@@ -567,17 +710,18 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
             }
 
             Event::DeployExecutionResult {
-                state,
+                height,
+                pre_state_hash,
                 deploy_hash,
                 deploy_header,
                 result,
             } => {
-                trace!(?state, %deploy_hash, ?result, "deploy execution result");
+                trace!(%height, %pre_state_hash, %deploy_hash, ?result, "deploy execution result");
                 // As for now a given state is expected to exist.
                 let execution_results = result.unwrap();
-                self.commit_execution_effects(
+                self.handle_deploy_execution_result(
                     effect_builder,
-                    state,
+                    height,
                     deploy_hash,
                     deploy_header,
                     execution_results,
@@ -585,15 +729,19 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
             }
 
             Event::CommitExecutionEffects {
-                mut state,
+                height,
+                pre_state_hash,
                 commit_result,
             } => {
-                trace!(?state, ?commit_result, "commit result");
+                trace!(%height, %pre_state_hash, ?commit_result, "commit result");
                 match commit_result {
                     Ok(CommitResult::Success { state_root }) => {
                         debug!(?state_root, "commit succeeded");
-                        state.state_root_hash = state_root.into();
-                        self.execute_next_deploy_or_create_block(effect_builder, state)
+                        self.handle_commit_execution_effects(
+                            effect_builder,
+                            height,
+                            state_root.into(),
+                        )
                     }
                     _ => {
                         // When commit fails we panic as we'll not be able to execute the next
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, VecDeque},
     fmt::Display,
+    time::Instant,
 };
 
 use derive_more::From;
@@ -10,9 +11,10 @@ use casper_execution_engine::{
         engine_state,
         engine_state::{step::StepResult, ExecutionResults, RootNotFound},
     },
+    shared::{additive_map::AdditiveMap, transform::Transform},
     storage::global_state::CommitResult,
 };
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, Key};
 
 use crate::{
     crypto::hash::Digest,
@@ -48,8 +50,10 @@ pub enum Event {
     },
     /// The result of executing a single deploy.
     DeployExecutionResult {
-        /// State of this request.
-        state: Box<State>,
+        /// Height of the block being executed.
+        height: u64,
+        /// The committed state root hash the deploy was executed on top of.
+        pre_state_hash: Digest,
         /// The ID of the deploy currently being executed.
         deploy_hash: DeployHash,
         /// The header of the deploy currently being executed.
@@ -59,8 +63,10 @@ pub enum Event {
     },
     /// The result of committing a single set of transforms after executing a single deploy.
     CommitExecutionEffects {
-        /// State of this request.
-        state: Box<State>,
+        /// Height of the block being executed.
+        height: u64,
+        /// The state root hash the effects were committed on top of.
+        pre_state_hash: Digest,
         /// Commit result for execution request.
         commit_result: Result<CommitResult, engine_state::Error>,
     },
@@ -97,7 +103,8 @@ impl Display for Event {
                 finalized_block.height()
             ),
             Event::DeployExecutionResult {
-                state,
+                height,
+                pre_state_hash,
                 deploy_hash,
                 result: Ok(_),
                 ..
@@ -105,12 +112,11 @@ impl Display for Event {
                 f,
                 "execution result for {} of finalized block with height {} with \
                 pre-state hash {}: success",
-                deploy_hash,
-                state.finalized_block.height(),
-                state.state_root_hash
+                deploy_hash, height, pre_state_hash
             ),
             Event::DeployExecutionResult {
-                state,
+                height,
+                pre_state_hash,
                 deploy_hash,
                 result: Err(_),
                 ..
@@ -118,31 +124,27 @@ impl Display for Event {
                 f,
                 "execution result for {} of finalized block with height {} with \
                 pre-state hash {}: root not found",
-                deploy_hash,
-                state.finalized_block.height(),
-                state.state_root_hash
+                deploy_hash, height, pre_state_hash
             ),
             Event::CommitExecutionEffects {
-                state,
+                height,
+                pre_state_hash,
                 commit_result: Ok(CommitResult::Success { state_root, .. }),
             } => write!(
                 f,
                 "commit execution effects of finalized block with height {} with \
                 pre-state hash {}: success with post-state hash {}",
-                state.finalized_block.height(),
-                state.state_root_hash,
-                state_root,
+                height, pre_state_hash, state_root,
             ),
             Event::CommitExecutionEffects {
-                state,
+                height,
+                pre_state_hash,
                 commit_result,
             } => write!(
                 f,
                 "commit execution effects of finalized block with height {} with \
                 pre-state hash {}: failed {:?}",
-                state.finalized_block.height(),
-                state.state_root_hash,
-                commit_result,
+                height, pre_state_hash, commit_result,
             ),
             Event::RunStepResult { state, result } => write!(
                 f,
@@ -171,4 +173,36 @@ pub struct State {
     /// Current state root hash of global storage.  Is initialized with the parent block's
     /// state hash, and is updated after each commit.
     pub state_root_hash: Digest,
+    /// Effects of executed deploys, in execution order, which are not yet committed on top of
+    /// `state_root_hash`.  The front entry is being committed if `commit_in_flight` is set.
+    pub pending_effects: VecDeque<AdditiveMap<Key, Transform>>,
+    /// Whether the front of `pending_effects` is currently being committed.
+    pub commit_in_flight: bool,
+    /// Whether a deploy is currently being executed.
+    pub execution_in_flight: bool,
+    /// When execution of the block started.
+    pub block_start: Instant,
+    /// When execution of the deploy currently being executed started.
+    pub deploy_start: Instant,
+}
+
+impl State {
+    /// Returns whether all deploys are executed and all their effects committed.
+    pub fn is_done(&self) -> bool {
+        self.remaining_deploys.is_empty()
+            && self.pending_effects.is_empty()
+            && !self.commit_in_flight
+            && !self.execution_in_flight
+    }
+
+    /// Returns the combined effects of all executed but uncommitted deploys.
+    pub fn uncommitted_effects(&self) -> AdditiveMap<Key, Transform> {
+        let mut uncommitted_effects = AdditiveMap::new();
+        for effects in &self.pending_effects {
+            for (key, transform) in effects.iter() {
+                uncommitted_effects.insert_add(*key, transform.clone());
+            }
+        }
+        uncommitted_effects
+    }
 }
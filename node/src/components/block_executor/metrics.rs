@@ -1,29 +1,79 @@
-use prometheus::{IntGauge, Registry};
+use std::time::Duration;
 
-use crate::unregister_metric;
+use prometheus::{Histogram, HistogramOpts, IntGauge, Registry};
+
+use crate::{types::TimeDiff, unregister_metric};
+
+/// Value of upper bound of the first histogram bucket, in seconds.
+const EXPONENTIAL_BUCKET_START: f64 = 0.01;
+
+/// Multiplier of previous upper bound for next bound.
+const EXPONENTIAL_BUCKET_FACTOR: f64 = 2.0;
+
+/// Bucket count, with the last bucket going to +Inf.
+/// - start = 0.01, factor = 2.0, count = 12
+/// - start * factor ^ count = 0.01 * 2.0 ^ 12 = 40.96
+const EXPONENTIAL_BUCKET_COUNT: usize = 12;
 
 #[derive(Debug, Clone)]
 pub(super) struct BlockExecutorMetrics {
     /// The current chain height.
     pub(super) chain_height: IntGauge,
+    /// Time taken to execute a single deploy.
+    pub(super) deploy_execution_time: Histogram,
+    /// Time taken to execute a whole block, including the step at a switch block.
+    pub(super) block_execution_time: Histogram,
     /// registry component.
     registry: Registry,
 }
 
+fn new_histogram(name: &str, help: &str) -> Result<Histogram, prometheus::Error> {
+    let buckets = prometheus::exponential_buckets(
+        EXPONENTIAL_BUCKET_START,
+        EXPONENTIAL_BUCKET_FACTOR,
+        EXPONENTIAL_BUCKET_COUNT,
+    )?;
+    Histogram::with_opts(HistogramOpts::new(name, help).buckets(buckets))
+}
+
 impl BlockExecutorMetrics {
     pub(super) fn new(registry: Registry) -> Result<Self, prometheus::Error> {
         let chain_height = IntGauge::new("chain_height", "current chain height")?;
+        let deploy_execution_time = new_histogram(
+            "block_executor_deploy_execution_time",
+            "time in seconds to execute a single deploy",
+        )?;
+        let block_execution_time = new_histogram(
+            "block_executor_block_execution_time",
+            "time in seconds to execute a finalized block",
+        )?;
         registry.register(Box::new(chain_height.clone()))?;
+        registry.register(Box::new(deploy_execution_time.clone()))?;
+        registry.register(Box::new(block_execution_time.clone()))?;
         Ok(BlockExecutorMetrics {
             chain_height,
+            deploy_execution_time,
+            block_execution_time,
             registry,
         })
     }
+
+    /// Estimates the time to execute `deploy_count` deploys from the average time taken so far.
+    pub(super) fn estimated_execution_time(&self, deploy_count: usize) -> TimeDiff {
+        let sample_count = self.deploy_execution_time.get_sample_count();
+        if sample_count == 0 {
+            return TimeDiff::default();
+        }
+        let average_secs = self.deploy_execution_time.get_sample_sum() / sample_count as f64;
+        TimeDiff::from(Duration::from_secs_f64(average_secs * deploy_count as f64))
+    }
 }
 
 impl Drop for BlockExecutorMetrics {
     fn drop(&mut self) {
         unregister_metric!(self.registry, self.chain_height);
+        unregister_metric!(self.registry, self.deploy_execution_time);
+        unregister_metric!(self.registry, self.block_execution_time);
     }
 }
 
@@ -2,7 +2,17 @@
 //!
 //! The block proposer stores deploy hashes in memory, tracking their suitability for inclusion into
 //! a new block. Upon request, it returns a list of candidates that can be included.
+//!
+//! The total size of the pending deploys held in memory is capped. Deploys arriving while the cap
+//! would be exceeded are spilled: only a reference is kept, while the deploy itself is loaded again
+//! from storage once finalization or expiry of pending deploys frees up space. The number of
+//! spilled deploys is capped as well, with further deploys being rejected.
+//!
+//! Pending deploys which are nearing expiry without having been finalized are announced for
+//! rebroadcast, so that they are gossiped again in case the initial gossip missed the peers which
+//! would have included them in a block.
 
+mod config;
 mod deploy_sets;
 mod event;
 mod metrics;
@@ -11,7 +21,7 @@ mod metrics;
 mod tests;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     convert::Infallible,
     time::Duration,
 };
@@ -20,19 +30,24 @@ use datasize::DataSize;
 use prometheus::{self, Registry};
 use tracing::{debug, error, info, trace, warn};
 
+use casper_execution_engine::shared::gas::Gas;
+use casper_types::PublicKey;
+
 use crate::{
-    components::Component,
+    components::{Component, HealthReport},
     effect::{
+        announcements::BlockProposerAnnouncement,
         requests::{BlockProposerRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
     types::{
         appendable_block::{AddError, AppendableBlock},
         chainspec::DeployConfig,
-        Chainspec, DeployHash, DeployHeader, ProtoBlock, Timestamp,
+        Chainspec, ComponentHealth, DeployHash, DeployHeader, ProtoBlock, Timestamp,
     },
     NodeRng,
 };
+pub use config::Config;
 pub(crate) use deploy_sets::BlockProposerDeploySets;
 pub(crate) use event::{DeployType, Event};
 use metrics::BlockProposerMetrics;
@@ -80,6 +95,10 @@ enum BlockProposerState {
         state_key: Vec<u8>,
         /// The deploy config from the current chainspec.
         deploy_config: DeployConfig,
+        /// The cost of a wasmless transfer from the current chainspec.
+        transfer_cost: Gas,
+        /// The block proposer configuration.
+        local_config: Config,
     },
     /// Normal operation.
     Ready(BlockProposerReady),
@@ -92,9 +111,15 @@ impl BlockProposer {
         effect_builder: EffectBuilder<REv>,
         next_finalized_block: BlockHeight,
         chainspec: &Chainspec,
+        local_config: Config,
     ) -> Result<(Self, Effects<Event>), prometheus::Error>
     where
-        REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
+        REv: From<Event>
+            + From<StorageRequest>
+            + From<StateStoreRequest>
+            + From<BlockProposerAnnouncement>
+            + Send
+            + 'static,
     {
         info!(%next_finalized_block, "creating block proposer");
         // load the state from storage or use a fresh instance if loading fails.
@@ -111,6 +136,8 @@ impl BlockProposer {
                 pending: Vec::new(),
                 state_key,
                 deploy_config: chainspec.deploy_config,
+                transfer_cost: Gas::from(chainspec.system_costs_config.wasmless_transfer_cost()),
+                local_config,
             },
             metrics: BlockProposerMetrics::new(registry)?,
         };
@@ -121,7 +148,12 @@ impl BlockProposer {
 
 impl<REv> Component<REv> for BlockProposer
 where
-    REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
+    REv: From<Event>
+        + From<StorageRequest>
+        + From<StateStoreRequest>
+        + From<BlockProposerAnnouncement>
+        + Send
+        + 'static,
 {
     type Event = Event;
     type ConstructionError = Infallible;
@@ -143,6 +175,8 @@ where
                     ref mut pending,
                     state_key,
                     deploy_config,
+                    transfer_cost,
+                    local_config,
                 },
                 Event::Loaded {
                     finalized_deploys,
@@ -156,8 +190,19 @@ where
                     ),
                     unhandled_finalized: Default::default(),
                     deploy_config: *deploy_config,
+                    transfer_cost: *transfer_cost,
+                    local_config: *local_config,
                     state_key: state_key.clone(),
                     request_queue: Default::default(),
+                    pending_bytes: 0,
+                    account_quotas: Default::default(),
+                    spilled: Default::default(),
+                    spilled_hashes: Default::default(),
+                    restoring_spilled: false,
+                    deploys_spilled: 0,
+                    deploys_restored: 0,
+                    rebroadcast: Default::default(),
+                    deploys_rebroadcast: 0,
                 };
 
                 // Replay postponed events onto new state.
@@ -191,6 +236,13 @@ where
                 self.metrics
                     .pending_deploys
                     .set(ready_state.sets.pending.len() as i64);
+                self.metrics
+                    .spilled_deploys
+                    .set(ready_state.spilled.len() as i64);
+                self.metrics
+                    .update_spill_totals(ready_state.deploys_spilled, ready_state.deploys_restored);
+                self.metrics
+                    .update_rebroadcast_total(ready_state.deploys_rebroadcast);
             }
         };
 
@@ -198,6 +250,72 @@ where
     }
 }
 
+impl HealthReport for BlockProposer {
+    fn health(&self) -> ComponentHealth {
+        match self.state {
+            BlockProposerState::Initializing { .. } => {
+                ComponentHealth::Degraded("waiting for finalized deploys to be loaded".to_string())
+            }
+            BlockProposerState::Ready(_) => ComponentHealth::Healthy,
+        }
+    }
+}
+
+/// A pending deploy which did not fit into memory and has to be loaded from storage again.
+#[derive(DataSize, Debug)]
+struct SpilledDeploy {
+    hash: DeployHash,
+    /// The account the deploy is from, counting towards its pending deploy quota.
+    account: PublicKey,
+    /// The serialized size of the deploy.
+    size: usize,
+    /// When the deploy expires.
+    expires: Timestamp,
+}
+
+/// The deploys from a single account which are pending or spilled to storage.
+#[derive(DataSize, Debug, Default)]
+struct AccountUsage {
+    /// The number of deploys.
+    deploys: usize,
+    /// The total serialized size of the deploys.
+    bytes: usize,
+}
+
+/// The usage of the pending deploy quota of each account, updated as deploys are added and removed.
+#[derive(DataSize, Debug, Default)]
+struct AccountQuotas(HashMap<PublicKey, AccountUsage>);
+
+impl AccountQuotas {
+    /// Returns `true` if adding a deploy of the given size would exceed the account's quota.
+    fn would_exceed(&self, account: &PublicKey, size: usize, config: &Config) -> bool {
+        let (deploys, bytes) = self
+            .0
+            .get(account)
+            .map_or((0, 0), |usage| (usage.deploys, usage.bytes));
+        deploys >= config.max_pending_deploys_per_account as usize
+            || bytes + size > config.max_pending_deploy_bytes_per_account as usize
+    }
+
+    /// Records a deploy of the given size from the account.
+    fn charge(&mut self, account: PublicKey, size: usize) {
+        let usage = self.0.entry(account).or_default();
+        usage.deploys += 1;
+        usage.bytes += size;
+    }
+
+    /// Releases a deploy of the given size from the account.
+    fn release(&mut self, account: &PublicKey, size: usize) {
+        if let Some(usage) = self.0.get_mut(account) {
+            usage.deploys -= 1;
+            usage.bytes -= size;
+            if usage.deploys == 0 {
+                self.0.remove(account);
+            }
+        }
+    }
+}
+
 /// State of operational block proposer.
 #[derive(DataSize, Debug)]
 struct BlockProposerReady {
@@ -209,10 +327,33 @@ struct BlockProposerReady {
     unhandled_finalized: HashSet<DeployHash>,
     /// We don't need the whole Chainspec here, just the deploy config.
     deploy_config: DeployConfig,
+    /// The cost of a wasmless transfer.
+    #[data_size(skip)]
+    transfer_cost: Gas,
+    /// The block proposer configuration.
+    local_config: Config,
     /// Key for storing the block proposer state.
     state_key: Vec<u8>,
     /// The queue of requests awaiting being handled.
     request_queue: RequestQueue,
+    /// Total size in bytes of the pending deploys held in memory.
+    pending_bytes: usize,
+    /// The quota usage of the accounts with pending or spilled deploys.
+    account_quotas: AccountQuotas,
+    /// Pending deploys spilled to storage, in the order they are to be restored.
+    spilled: VecDeque<SpilledDeploy>,
+    /// The hashes of the deploys in `spilled`.
+    spilled_hashes: HashSet<DeployHash>,
+    /// Whether spilled deploys are currently being loaded from storage.
+    restoring_spilled: bool,
+    /// Total number of deploys spilled to storage.
+    deploys_spilled: u64,
+    /// Total number of deploys restored from storage.
+    deploys_restored: u64,
+    /// Pending deploys which have already been announced for rebroadcast.
+    rebroadcast: HashSet<DeployHash>,
+    /// Total number of deploys announced for rebroadcast.
+    deploys_rebroadcast: u64,
 }
 
 impl BlockProposerReady {
@@ -222,7 +363,8 @@ impl BlockProposerReady {
         event: Event,
     ) -> Effects<Event>
     where
-        REv: Send + From<StateStoreRequest>,
+        REv:
+            Send + From<StorageRequest> + From<StateStoreRequest> + From<BlockProposerAnnouncement>,
     {
         match event {
             Event::Request(BlockProposerRequest::RequestProtoBlock(request)) => {
@@ -255,13 +397,42 @@ impl BlockProposerReady {
                 Effects::new()
             }
             Event::Prune => {
-                let pruned = self.prune(Timestamp::now());
+                let current_instant = Timestamp::now();
+                let pruned = self.prune(current_instant);
                 debug!(%pruned, "pruned deploys from buffer");
 
                 // Re-trigger timer after `PRUNE_INTERVAL`.
-                effect_builder
+                let mut effects = effect_builder
                     .set_timeout(PRUNE_INTERVAL)
-                    .event(|_| Event::Prune)
+                    .event(|_| Event::Prune);
+                effects.extend(self.restore_spilled_deploys(effect_builder));
+
+                let to_rebroadcast = self.take_deploys_to_rebroadcast(current_instant);
+                if !to_rebroadcast.is_empty() {
+                    debug!(count = to_rebroadcast.len(), "rebroadcasting deploys");
+                    effects.extend(
+                        effect_builder
+                            .announce_deploys_for_rebroadcast(to_rebroadcast)
+                            .ignore(),
+                    );
+                }
+                effects
+            }
+            Event::SpilledDeploysLoaded { deploys } => {
+                self.restoring_spilled = false;
+                let current_instant = Timestamp::now();
+                for (hash, maybe_deploy_type) in deploys {
+                    match maybe_deploy_type {
+                        Some(deploy_type) => {
+                            self.deploys_restored += 1;
+                            // If new deploys took up the space in the meantime, this spills the
+                            // deploy again.
+                            self.add_deploy_or_transfer(current_instant, hash, deploy_type);
+                        }
+                        None => warn!(%hash, "could not restore spilled deploy from storage"),
+                    }
+                }
+                self.restore_spilled_deploys(effect_builder)
             }
             Event::Loaded { .. } => {
                 // This should never happen, but we can just ignore the event and carry on.
@@ -294,6 +465,7 @@ impl BlockProposerReady {
                             deploys,
                         ));
                     }
+                    effects.extend(self.restore_spilled_deploys(effect_builder));
                     effects
                 }
             }
@@ -325,12 +497,146 @@ impl BlockProposerReady {
         // only add the deploy if it isn't contained in a finalized block
         if self.sets.finalized_deploys.contains_key(&hash) {
             info!(%hash, "deploy rejected from the buffer");
+        } else if self.spilled_hashes.contains(&hash) {
+            debug!(%hash, "deploy already spilled to storage");
+        } else if !self.sets.pending.contains_key(&hash)
+            && self.account_quotas.would_exceed(
+                deploy_or_transfer.header().account(),
+                deploy_or_transfer.size(),
+                &self.local_config,
+            )
+        {
+            info!(
+                %hash,
+                account = %deploy_or_transfer.header().account(),
+                "deploy rejected from the buffer, account exceeded its pending deploy quota"
+            );
+        } else if !self.sets.pending.is_empty()
+            && !self.sets.pending.contains_key(&hash)
+            && self.pending_bytes + deploy_or_transfer.size()
+                > self.local_config.max_pending_deploy_bytes as usize
+        {
+            if self.spilled.len() >= self.local_config.max_spilled_deploys as usize {
+                info!(%hash, "deploy rejected from the buffer, too many deploys spilled to storage");
+                return;
+            }
+            info!(%hash, "pending deploys exceed their size limit, spilling deploy to storage");
+            let account = *deploy_or_transfer.header().account();
+            let size = deploy_or_transfer.size();
+            self.account_quotas.charge(account, size);
+            self.spilled.push_back(SpilledDeploy {
+                hash,
+                account,
+                size,
+                expires: deploy_or_transfer.header().expires(),
+            });
+            self.spilled_hashes.insert(hash);
+            self.deploys_spilled += 1;
         } else {
-            self.sets.pending.insert(hash, deploy_or_transfer);
+            self.pending_bytes += deploy_or_transfer.size();
+            self.account_quotas.charge(
+                *deploy_or_transfer.header().account(),
+                deploy_or_transfer.size(),
+            );
+            if let Some(replaced) = self.sets.pending.insert(hash, deploy_or_transfer) {
+                self.pending_bytes -= replaced.size();
+                self.account_quotas
+                    .release(replaced.header().account(), replaced.size());
+            }
             info!(%hash, "added deploy to the buffer");
         }
     }
 
+    /// Loads as many spilled deploys from storage as fit into the space left by pending deploys.
+    ///
+    /// Deploys are restored in the order they were spilled, one batch at a time.
+    fn restore_spilled_deploys<REv>(&mut self, effect_builder: EffectBuilder<REv>) -> Effects<Event>
+    where
+        REv: Send + From<StorageRequest>,
+    {
+        if self.restoring_spilled {
+            return Effects::new();
+        }
+
+        let hashes = self.take_restorable_spilled();
+        if hashes.is_empty() {
+            return Effects::new();
+        }
+
+        debug!(
+            count = hashes.len(),
+            "restoring spilled deploys from storage"
+        );
+        self.restoring_spilled = true;
+        effect_builder
+            .get_deploys_from_storage(hashes.iter().copied().collect())
+            .event(move |deploys| Event::SpilledDeploysLoaded {
+                deploys: hashes
+                    .into_iter()
+                    .zip(deploys)
+                    .map(|(hash, maybe_deploy)| {
+                        let deploy_type = maybe_deploy.and_then(|deploy| deploy.deploy_type().ok());
+                        (hash, deploy_type)
+                    })
+                    .collect(),
+            })
+    }
+
+    /// Removes and returns the hashes of the spilled deploys which fit into the space left by
+    /// pending deploys, stopping at the first one which does not fit.
+    ///
+    /// If no deploys are pending, the first spilled deploy is always returned, as a deploy is never
+    /// spilled into an empty buffer, even if it exceeds the limit on its own.
+    fn take_restorable_spilled(&mut self) -> Vec<DeployHash> {
+        let mut available = (self.local_config.max_pending_deploy_bytes as usize)
+            .saturating_sub(self.pending_bytes);
+        let mut hashes = Vec::new();
+        while let Some(spilled) = self.spilled.front() {
+            if spilled.size > available && !(hashes.is_empty() && self.sets.pending.is_empty()) {
+                break;
+            }
+            available = available.saturating_sub(spilled.size);
+            // The deploy is charged to its account again once it has been restored.
+            self.account_quotas.release(&spilled.account, spilled.size);
+            hashes.push(spilled.hash);
+            self.spilled_hashes.remove(&spilled.hash);
+            self.spilled.pop_front();
+        }
+        hashes
+    }
+
+    /// Returns the hashes of the pending deploys which should be rebroadcast, marking them as such.
+    ///
+    /// A deploy is rebroadcast once it has no more than the configured percentage of its TTL left
+    /// without having been finalized. Each deploy is only rebroadcast once, and spilled deploys
+    /// are only considered once they have been restored.
+    fn take_deploys_to_rebroadcast(&mut self, current_instant: Timestamp) -> Vec<DeployHash> {
+        let remaining_ttl_percent = u64::from(self.local_config.rebroadcast_remaining_ttl_percent);
+        if remaining_ttl_percent == 0 {
+            return Vec::new();
+        }
+
+        let sets = &self.sets;
+        self.rebroadcast
+            .retain(|hash| sets.pending.contains_key(hash));
+
+        let mut hashes = Vec::new();
+        for (hash, deploy_type) in &self.sets.pending {
+            if self.rebroadcast.contains(hash) || self.contains_finalized(hash) {
+                continue;
+            }
+            let header = deploy_type.header();
+            let remaining_ttl = header.expires().saturating_diff(current_instant);
+            if remaining_ttl.millis() * 100 <= header.ttl().millis() * remaining_ttl_percent {
+                hashes.push(*hash);
+            }
+        }
+
+        self.rebroadcast.extend(hashes.iter().copied());
+        self.deploys_rebroadcast += hashes.len() as u64;
+        hashes
+    }
+
     /// Notifies the block proposer that a block has been finalized.
     fn finalized_deploys<I>(&mut self, deploys: I)
     where
@@ -339,6 +645,9 @@ impl BlockProposerReady {
         for deploy_hash in deploys.into_iter() {
             match self.sets.pending.remove(&deploy_hash) {
                 Some(deploy_type) => {
+                    self.pending_bytes -= deploy_type.size();
+                    self.account_quotas
+                        .release(deploy_type.header().account(), deploy_type.size());
                     self.sets
                         .finalized_deploys
                         .insert(deploy_hash, deploy_type.take_header());
@@ -401,18 +710,38 @@ impl BlockProposerReady {
         past_deploys: HashSet<DeployHash>,
         random_bit: bool,
     ) -> ProtoBlock {
-        let mut appendable_block = AppendableBlock::new(deploy_config, block_timestamp);
+        let mut appendable_block =
+            AppendableBlock::new(deploy_config, self.transfer_cost, block_timestamp);
+
+        // If no wasm deploys can be included, we propose a block containing only transfers, which
+        // is not subject to the block gas limit.
+        let transfers_only = self.local_config.transfer_fast_path
+            && !self.sets.pending.iter().any(|(hash, deploy_type)| {
+                !deploy_type.is_transfer()
+                    && self.is_proposable(hash, deploy_type.header(), &past_deploys)
+            });
+        let block_gas_limit = Gas::from(deploy_config.block_gas_limit);
 
         // We prioritize transfers over deploys, so we try to include them first.
         for (hash, deploy_type) in &self.sets.pending {
             if !deploy_type.is_transfer()
-                || !self.deps_resolved(&deploy_type.header(), &past_deploys)
-                || past_deploys.contains(hash)
-                || self.contains_finalized(hash)
+                || !self.is_proposable(hash, deploy_type.header(), &past_deploys)
             {
                 continue;
             }
 
+            // Unless this is a transfers-only block, transfers must leave room for deploys within
+            // the block gas limit if the chainspec counts them toward it.
+            if deploy_config.transfers_count_toward_block_gas_limit
+                && !transfers_only
+                && appendable_block
+                    .total_gas()
+                    .checked_add(self.transfer_cost)
+                    .map_or(true, |gas| gas > block_gas_limit)
+            {
+                break;
+            }
+
             if let Err(err) = appendable_block.add(*hash, deploy_type) {
                 match err {
                     // We added the maximum number of transfers.
@@ -427,12 +756,14 @@ impl BlockProposerReady {
             }
         }
 
+        if transfers_only {
+            return appendable_block.into_proto_block(random_bit);
+        }
+
         // Now we try to add other deploys to the block.
         for (hash, deploy_type) in &self.sets.pending {
             if deploy_type.is_transfer()
-                || !self.deps_resolved(&deploy_type.header(), &past_deploys)
-                || past_deploys.contains(hash)
-                || self.contains_finalized(hash)
+                || !self.is_proposable(hash, deploy_type.header(), &past_deploys)
             {
                 continue;
             }
@@ -465,9 +796,43 @@ impl BlockProposerReady {
         appendable_block.into_proto_block(random_bit)
     }
 
+    /// Returns `true` if the deploy's dependencies are resolved and it has not been included in a
+    /// past or finalized block yet.
+    fn is_proposable(
+        &self,
+        hash: &DeployHash,
+        header: &DeployHeader,
+        past_deploys: &HashSet<DeployHash>,
+    ) -> bool {
+        self.deps_resolved(header, past_deploys)
+            && !past_deploys.contains(hash)
+            && !self.contains_finalized(hash)
+    }
+
     /// Prunes expired deploy information from the BlockProposer, returns the total deploys pruned.
     fn prune(&mut self, current_instant: Timestamp) -> usize {
-        self.sets.prune(current_instant)
+        let initial_spilled = self.spilled.len();
+        let spilled_hashes = &mut self.spilled_hashes;
+        let account_quotas = &mut self.account_quotas;
+        self.spilled.retain(|spilled| {
+            let keep = spilled.expires >= current_instant;
+            if !keep {
+                spilled_hashes.remove(&spilled.hash);
+                account_quotas.release(&spilled.account, spilled.size);
+            }
+            keep
+        });
+        for deploy_type in self
+            .sets
+            .pending
+            .values()
+            .filter(|deploy_type| deploy_type.header().expired(current_instant))
+        {
+            self.pending_bytes -= deploy_type.size();
+            self.account_quotas
+                .release(deploy_type.header().account(), deploy_type.size());
+        }
+        self.sets.prune(current_instant) + initial_spilled - self.spilled.len()
     }
 
     fn contains_finalized(&self, dep: &DeployHash) -> bool {
@@ -0,0 +1,82 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// Default maximum number of pending deploys per account.
+fn default_max_pending_deploys_per_account() -> u32 {
+    100
+}
+
+/// Default maximum total size in bytes of the pending deploys per account.
+fn default_max_pending_deploy_bytes_per_account() -> u64 {
+    16 * 1024 * 1024
+}
+
+/// Default maximum total size in bytes of the deploys pending inclusion in a block held in memory.
+fn default_max_pending_deploy_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+/// Default maximum number of pending deploys spilled to storage.
+fn default_max_spilled_deploys() -> u32 {
+    100_000
+}
+
+/// Default for whether blocks containing only transfers are proposed when no wasm deploys are
+/// pending.
+fn default_transfer_fast_path() -> bool {
+    true
+}
+
+/// Default percentage of its TTL a pending deploy may have left before it is rebroadcast.
+fn default_rebroadcast_remaining_ttl_percent() -> u8 {
+    25
+}
+
+/// Block proposer configuration.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Maximum number of deploys from a single account which may be pending inclusion in a block.
+    /// Further deploys from that account are rejected until some of its pending deploys have been
+    /// finalized or have expired.
+    #[serde(default = "default_max_pending_deploys_per_account")]
+    pub max_pending_deploys_per_account: u32,
+    /// Maximum total size in bytes of the deploys from a single account which may be pending
+    /// inclusion in a block, including those spilled to storage. Further deploys from that account
+    /// are rejected until some of its pending deploys have been finalized or have expired.
+    #[serde(default = "default_max_pending_deploy_bytes_per_account")]
+    pub max_pending_deploy_bytes_per_account: u64,
+    /// Maximum total size in bytes of the deploys pending inclusion in a block which are held in
+    /// memory. Further deploys are spilled to storage and restored once space frees up.
+    #[serde(default = "default_max_pending_deploy_bytes")]
+    pub max_pending_deploy_bytes: u64,
+    /// Maximum number of pending deploys which may be spilled to storage. Further deploys which do
+    /// not fit into memory are rejected.
+    #[serde(default = "default_max_spilled_deploys")]
+    pub max_spilled_deploys: u32,
+    /// Whether to propose a block containing only transfers when no wasm deploys are pending. Such
+    /// a block is filled with up to `block_max_transfer_count` transfers regardless of the
+    /// block gas limit, whereas in other blocks transfers are only added while their total
+    /// cost stays within the block gas limit.
+    #[serde(default = "default_transfer_fast_path")]
+    pub transfer_fast_path: bool,
+    /// Percentage of its TTL a pending deploy may have left before it is gossiped again, in case
+    /// the initial gossip missed the peers which would have included it in a block. Each deploy
+    /// is rebroadcast at most once, and a value of 0 disables rebroadcasting.
+    #[serde(default = "default_rebroadcast_remaining_ttl_percent")]
+    pub rebroadcast_remaining_ttl_percent: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_pending_deploys_per_account: default_max_pending_deploys_per_account(),
+            max_pending_deploy_bytes_per_account: default_max_pending_deploy_bytes_per_account(),
+            max_pending_deploy_bytes: default_max_pending_deploy_bytes(),
+            max_spilled_deploys: default_max_spilled_deploys(),
+            transfer_fast_path: default_transfer_fast_path(),
+            rebroadcast_remaining_ttl_percent: default_rebroadcast_remaining_ttl_percent(),
+        }
+    }
+}
@@ -93,6 +93,11 @@ pub enum Event {
     },
     /// The block proposer has been asked to prune stale deploys
     Prune,
+    /// Deploys previously spilled to storage have been loaded again.
+    SpilledDeploysLoaded {
+        /// The spilled deploys, `None` for those which could not be loaded.
+        deploys: Vec<(DeployHash, Option<DeployType>)>,
+    },
     /// A proto block has been finalized. We should never propose its deploys again.
     FinalizedProtoBlock {
         block: ProtoBlock,
@@ -114,6 +119,11 @@ impl Display for Event {
             ),
             Event::BufferDeploy { hash, .. } => write!(f, "block-proposer add {}", hash),
             Event::Prune => write!(f, "block-proposer prune"),
+            Event::SpilledDeploysLoaded { deploys } => write!(
+                f,
+                "block-proposer loaded {} spilled deploys from storage",
+                deploys.len()
+            ),
             Event::FinalizedProtoBlock { block, height } => {
                 write!(
                     f,
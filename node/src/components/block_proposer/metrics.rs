@@ -1,5 +1,5 @@
 use datasize::DataSize;
-use prometheus::{self, IntGauge, Registry};
+use prometheus::{self, IntCounter, IntGauge, Registry};
 
 use crate::unregister_metric;
 
@@ -9,6 +9,18 @@ pub(super) struct BlockProposerMetrics {
     /// Amount of pending deploys
     #[data_size(skip)]
     pub(super) pending_deploys: IntGauge,
+    /// Amount of pending deploys spilled to storage
+    #[data_size(skip)]
+    pub(super) spilled_deploys: IntGauge,
+    /// Total number of deploys spilled to storage
+    #[data_size(skip)]
+    pub(super) deploys_spilled_total: IntCounter,
+    /// Total number of deploys restored from storage
+    #[data_size(skip)]
+    pub(super) deploys_restored_total: IntCounter,
+    /// Total number of pending deploys announced for rebroadcast
+    #[data_size(skip)]
+    pub(super) deploys_rebroadcast_total: IntCounter,
     /// Registry stored to allow deregistration later.
     #[data_size(skip)]
     registry: Registry,
@@ -18,16 +30,58 @@ impl BlockProposerMetrics {
     /// Creates a new instance of the block proposer metrics.
     pub fn new(registry: Registry) -> Result<Self, prometheus::Error> {
         let pending_deploys = IntGauge::new("pending_deploy", "amount of pending deploys")?;
+        let spilled_deploys = IntGauge::new(
+            "spilled_deploy",
+            "amount of pending deploys spilled to storage",
+        )?;
+        let deploys_spilled_total = IntCounter::new(
+            "deploys_spilled_total",
+            "total number of pending deploys spilled to storage",
+        )?;
+        let deploys_restored_total = IntCounter::new(
+            "deploys_restored_total",
+            "total number of spilled deploys restored from storage",
+        )?;
+        let deploys_rebroadcast_total = IntCounter::new(
+            "deploys_rebroadcast_total",
+            "total number of pending deploys nearing expiry announced for rebroadcast",
+        )?;
         registry.register(Box::new(pending_deploys.clone()))?;
+        registry.register(Box::new(spilled_deploys.clone()))?;
+        registry.register(Box::new(deploys_spilled_total.clone()))?;
+        registry.register(Box::new(deploys_restored_total.clone()))?;
+        registry.register(Box::new(deploys_rebroadcast_total.clone()))?;
         Ok(BlockProposerMetrics {
             pending_deploys,
+            spilled_deploys,
+            deploys_spilled_total,
+            deploys_restored_total,
+            deploys_rebroadcast_total,
             registry,
         })
     }
+
+    /// Brings the spill counters up to the given running totals.
+    pub(super) fn update_spill_totals(&self, deploys_spilled: u64, deploys_restored: u64) {
+        self.deploys_spilled_total
+            .inc_by(deploys_spilled as i64 - self.deploys_spilled_total.get());
+        self.deploys_restored_total
+            .inc_by(deploys_restored as i64 - self.deploys_restored_total.get());
+    }
+
+    /// Brings the rebroadcast counter up to the given running total.
+    pub(super) fn update_rebroadcast_total(&self, deploys_rebroadcast: u64) {
+        self.deploys_rebroadcast_total
+            .inc_by(deploys_rebroadcast as i64 - self.deploys_rebroadcast_total.get());
+    }
 }
 
 impl Drop for BlockProposerMetrics {
     fn drop(&mut self) {
         unregister_metric!(self.registry, self.pending_deploys);
+        unregister_metric!(self.registry, self.spilled_deploys);
+        unregister_metric!(self.registry, self.deploys_spilled_total);
+        unregister_metric!(self.registry, self.deploys_restored_total);
+        unregister_metric!(self.registry, self.deploys_rebroadcast_total);
     }
 }
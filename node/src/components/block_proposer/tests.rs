@@ -48,6 +48,7 @@ fn generate_transfer(
         timestamp,
         ttl,
         gas_price,
+        None,
         dependencies,
         chain_name,
         payment,
@@ -82,6 +83,7 @@ fn generate_deploy(
         timestamp,
         ttl,
         gas_price,
+        None,
         dependencies,
         chain_name,
         payment,
@@ -94,9 +96,21 @@ fn create_test_proposer() -> BlockProposerReady {
     BlockProposerReady {
         sets: Default::default(),
         deploy_config: Default::default(),
+        // Transfers are free unless a test specifies otherwise.
+        transfer_cost: Gas::default(),
+        local_config: Default::default(),
         state_key: b"block-proposer-test".to_vec(),
         request_queue: Default::default(),
         unhandled_finalized: Default::default(),
+        pending_bytes: 0,
+        account_quotas: Default::default(),
+        spilled: Default::default(),
+        spilled_hashes: Default::default(),
+        restoring_spilled: false,
+        deploys_spilled: 0,
+        deploys_restored: 0,
+        rebroadcast: Default::default(),
+        deploys_rebroadcast: 0,
     }
 }
 
@@ -457,6 +471,7 @@ fn should_propose_deploy_if_block_size_limit_met() {
         proposed_count: 2,
         remaining_pending_count: 0,
         max_block_size: Some(2 * DEPLOY_APPROX_MIN_SIZE),
+        ..Default::default()
     });
 }
 
@@ -472,6 +487,7 @@ fn should_not_propose_deploy_if_block_size_limit_within_threshold() {
         proposed_count: 4,
         remaining_pending_count: 0,
         max_block_size: Some(2 * DEPLOY_APPROX_MIN_SIZE),
+        ..Default::default()
     });
 }
 
@@ -487,6 +503,7 @@ fn should_not_propose_deploy_if_block_size_limit_passed() {
         proposed_count: 4,
         remaining_pending_count: 1,
         max_block_size: Some(2 * DEPLOY_APPROX_MIN_SIZE),
+        ..Default::default()
     });
 }
 
@@ -502,6 +519,69 @@ fn should_allow_transfers_to_exceed_block_size_limit() {
         proposed_count: 42,
         remaining_pending_count: 21,
         max_block_size: Some(2 * DEPLOY_APPROX_MIN_SIZE),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn should_fill_transfers_only_block_regardless_of_gas_limit() {
+    test_proposer_with(TestArgs {
+        transfer_count: 30,
+        max_transfer_count: 20,
+        transfer_cost: Gas::from(10u32),
+        transfers_count_toward_block_gas_limit: true,
+        block_gas_limit: 100,
+        proposed_count: 20,
+        remaining_pending_count: 10,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn should_limit_transfers_by_gas_when_deploys_are_pending() {
+    test_proposer_with(TestArgs {
+        transfer_count: 30,
+        max_transfer_count: 20,
+        transfer_cost: Gas::from(10u32),
+        transfers_count_toward_block_gas_limit: true,
+        deploy_count: 5,
+        max_deploy_count: 5,
+        payment_amount: default_gas_payment(),
+        block_gas_limit: 105,
+        proposed_count: 15,
+        remaining_pending_count: 20,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn should_limit_transfers_by_gas_without_transfer_fast_path() {
+    test_proposer_with(TestArgs {
+        transfer_count: 30,
+        max_transfer_count: 20,
+        transfer_cost: Gas::from(10u32),
+        transfers_count_toward_block_gas_limit: true,
+        disable_transfer_fast_path: true,
+        block_gas_limit: 100,
+        proposed_count: 10,
+        remaining_pending_count: 20,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn should_not_limit_transfers_by_gas_unless_enabled_in_chainspec() {
+    test_proposer_with(TestArgs {
+        transfer_count: 30,
+        max_transfer_count: 20,
+        transfer_cost: Gas::from(10u32),
+        deploy_count: 5,
+        max_deploy_count: 5,
+        payment_amount: default_gas_payment(),
+        block_gas_limit: 105,
+        proposed_count: 25,
+        remaining_pending_count: 10,
+        ..Default::default()
     });
 }
 
@@ -525,6 +605,12 @@ struct TestArgs {
     proposed_count: usize,
     /// Block size limit in bytes.
     max_block_size: Option<usize>,
+    /// Gas cost of a single transfer.
+    transfer_cost: Gas,
+    /// Whether to disable proposing blocks containing only transfers.
+    disable_transfer_fast_path: bool,
+    /// Whether the gas of transfers counts toward the block gas limit.
+    transfers_count_toward_block_gas_limit: bool,
 }
 
 /// Test the block_proposer by generating deploys and transfers with variable limits, asserting
@@ -540,6 +626,9 @@ fn test_proposer_with(
         remaining_pending_count,
         proposed_count,
         max_block_size,
+        transfer_cost,
+        disable_transfer_fast_path,
+        transfers_count_toward_block_gas_limit,
     }: TestArgs,
 ) -> BlockProposerReady {
     let creation_time = Timestamp::from(100);
@@ -549,11 +638,14 @@ fn test_proposer_with(
 
     let mut rng = crate::new_rng();
     let mut proposer = create_test_proposer();
+    proposer.transfer_cost = transfer_cost;
+    proposer.local_config.transfer_fast_path = !disable_transfer_fast_path;
     let mut config = proposer.deploy_config;
     // defaults are 10, 1000 respectively
     config.block_max_deploy_count = max_deploy_count;
     config.block_max_transfer_count = max_transfer_count;
     config.block_gas_limit = block_gas_limit;
+    config.transfers_count_toward_block_gas_limit = transfers_count_toward_block_gas_limit;
     if let Some(max_block_size) = max_block_size {
         config.max_block_size = max_block_size as u32;
     }
@@ -670,3 +762,340 @@ fn should_return_deploy_dependencies() {
     assert_eq!(deploys2.len(), 1);
     assert!(deploys2.contains(deploy2.id()));
 }
+
+#[test]
+fn should_enforce_per_account_pending_deploy_quota() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let secret_key = SecretKey::random(&mut rng);
+    let generate_deploy_from_account = |gas_price| {
+        Deploy::new(
+            creation_time,
+            ttl,
+            gas_price,
+            None,
+            vec![],
+            "chain".to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: runtime_args! { ARG_AMOUNT => default_gas_payment().value() },
+            },
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: RuntimeArgs::new(),
+            },
+            &secret_key,
+        )
+    };
+    let deploy1 = generate_deploy_from_account(1);
+    let deploy2 = generate_deploy_from_account(2);
+    let deploy3 = generate_deploy_from_account(3);
+    let other_deploy = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        DEFAULT_TEST_GAS_PRICE,
+    );
+
+    let mut proposer = create_test_proposer();
+    proposer.local_config.max_pending_deploys_per_account = 2;
+
+    proposer.add_deploy_or_transfer(block_time, *deploy1.id(), deploy1.deploy_type().unwrap());
+    proposer.add_deploy_or_transfer(block_time, *deploy2.id(), deploy2.deploy_type().unwrap());
+    // the third deploy from the same account exceeds the quota
+    proposer.add_deploy_or_transfer(block_time, *deploy3.id(), deploy3.deploy_type().unwrap());
+    // deploys from other accounts are unaffected
+    proposer.add_deploy_or_transfer(
+        block_time,
+        *other_deploy.id(),
+        other_deploy.deploy_type().unwrap(),
+    );
+    assert_eq!(proposer.sets.pending.len(), 3);
+    assert!(!proposer.sets.pending.contains_key(deploy3.id()));
+
+    // once a deploy from the account is finalized, there is room for another one
+    proposer.finalized_deploys(vec![*deploy1.id()]);
+    proposer.add_deploy_or_transfer(block_time, *deploy3.id(), deploy3.deploy_type().unwrap());
+    assert!(proposer.sets.pending.contains_key(deploy3.id()));
+}
+
+#[test]
+fn should_enforce_per_account_pending_deploy_bytes_quota() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+    let expired_time = Timestamp::from(201);
+
+    let mut rng = crate::new_rng();
+    let secret_key = SecretKey::random(&mut rng);
+    let generate_deploy_from_account = |gas_price| {
+        Deploy::new(
+            creation_time,
+            ttl,
+            gas_price,
+            None,
+            vec![],
+            "chain".to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: runtime_args! { ARG_AMOUNT => default_gas_payment().value() },
+            },
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: RuntimeArgs::new(),
+            },
+            &secret_key,
+        )
+    };
+    let deploy1 = generate_deploy_from_account(1);
+    let deploy2 = generate_deploy_from_account(2);
+    let deploy3 = generate_deploy_from_account(3);
+    let size1 = deploy1.deploy_type().unwrap().size();
+    let size2 = deploy2.deploy_type().unwrap().size();
+
+    let mut proposer = create_test_proposer();
+    proposer.local_config.max_pending_deploy_bytes_per_account = (size1 + size2) as u64;
+
+    proposer.add_deploy_or_transfer(block_time, *deploy1.id(), deploy1.deploy_type().unwrap());
+    proposer.add_deploy_or_transfer(block_time, *deploy2.id(), deploy2.deploy_type().unwrap());
+    // the third deploy from the same account exceeds the size quota
+    proposer.add_deploy_or_transfer(block_time, *deploy3.id(), deploy3.deploy_type().unwrap());
+    assert_eq!(proposer.sets.pending.len(), 2);
+    assert!(!proposer.sets.pending.contains_key(deploy3.id()));
+
+    // re-adding a pending deploy doesn't count towards the quota twice
+    proposer.add_deploy_or_transfer(block_time, *deploy2.id(), deploy2.deploy_type().unwrap());
+    let usage = &proposer.account_quotas.0[&PublicKey::from(&secret_key)];
+    assert_eq!(usage.deploys, 2);
+    assert_eq!(usage.bytes, size1 + size2);
+
+    // the quota is released once the deploys are finalized or expire
+    proposer.finalized_deploys(vec![*deploy1.id()]);
+    proposer.prune(expired_time);
+    assert!(proposer.sets.pending.is_empty());
+    assert!(proposer.account_quotas.0.is_empty());
+}
+
+#[test]
+fn should_spill_deploys_exceeding_pending_size_limit() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let deploys: Vec<_> = (0..3)
+        .map(|_| {
+            generate_deploy(
+                &mut rng,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                DEFAULT_TEST_GAS_PRICE,
+            )
+        })
+        .collect();
+    let total_size: usize = deploys
+        .iter()
+        .map(|deploy| deploy.deploy_type().unwrap().size())
+        .sum();
+
+    let mut proposer = create_test_proposer();
+    // there is only room for two deploys in memory
+    proposer.local_config.max_pending_deploy_bytes = total_size as u64 - 1;
+
+    for deploy in &deploys {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+    assert_eq!(proposer.sets.pending.len(), 2);
+    assert!(!proposer.sets.pending.contains_key(deploys[2].id()));
+    assert_eq!(proposer.spilled.len(), 1);
+    assert_eq!(proposer.deploys_spilled, 1);
+
+    // adding the spilled deploy again has no effect
+    proposer.add_deploy_or_transfer(
+        block_time,
+        *deploys[2].id(),
+        deploys[2].deploy_type().unwrap(),
+    );
+    assert_eq!(proposer.spilled.len(), 1);
+    assert_eq!(proposer.deploys_spilled, 1);
+
+    // nothing can be restored while the pending deploys use up the space
+    assert!(proposer.take_restorable_spilled().is_empty());
+
+    // once a pending deploy is finalized, the spilled one fits again
+    proposer.finalized_deploys(vec![*deploys[0].id()]);
+    assert_eq!(
+        proposer.pending_bytes,
+        deploys[1].deploy_type().unwrap().size()
+    );
+    assert_eq!(proposer.take_restorable_spilled(), vec![*deploys[2].id()]);
+    assert!(proposer.spilled.is_empty());
+    proposer.add_deploy_or_transfer(
+        block_time,
+        *deploys[2].id(),
+        deploys[2].deploy_type().unwrap(),
+    );
+    assert!(proposer.sets.pending.contains_key(deploys[2].id()));
+}
+
+#[test]
+fn should_reject_deploys_once_spill_limit_reached() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let deploys: Vec<_> = (0..3)
+        .map(|_| {
+            generate_deploy(
+                &mut rng,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                DEFAULT_TEST_GAS_PRICE,
+            )
+        })
+        .collect();
+
+    let mut proposer = create_test_proposer();
+    // there is only room for one deploy in memory and one in storage
+    proposer.local_config.max_pending_deploy_bytes = 1;
+    proposer.local_config.max_spilled_deploys = 1;
+
+    for deploy in &deploys {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+    assert_eq!(proposer.sets.pending.len(), 1);
+    assert!(proposer.sets.pending.contains_key(deploys[0].id()));
+    assert_eq!(proposer.spilled.len(), 1);
+    assert!(proposer.spilled_hashes.contains(deploys[1].id()));
+    assert!(!proposer.spilled_hashes.contains(deploys[2].id()));
+    assert_eq!(proposer.deploys_spilled, 1);
+}
+
+#[test]
+fn should_count_and_prune_spilled_deploys() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+    let expired_time = Timestamp::from(220);
+
+    let mut rng = crate::new_rng();
+    let secret_key = SecretKey::random(&mut rng);
+    let generate_deploy_from_account = |gas_price| {
+        Deploy::new(
+            creation_time,
+            ttl,
+            gas_price,
+            None,
+            vec![],
+            "chain".to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: runtime_args! { ARG_AMOUNT => default_gas_payment().value() },
+            },
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: RuntimeArgs::new(),
+            },
+            &secret_key,
+        )
+    };
+    let deploy1 = generate_deploy_from_account(1);
+    let deploy2 = generate_deploy_from_account(2);
+    let deploy3 = generate_deploy_from_account(3);
+
+    let mut proposer = create_test_proposer();
+    proposer.local_config.max_pending_deploys_per_account = 2;
+    // there is only room for one deploy in memory
+    proposer.local_config.max_pending_deploy_bytes = deploy1.deploy_type().unwrap().size() as u64;
+
+    // spilled deploys count towards the account's quota
+    proposer.add_deploy_or_transfer(block_time, *deploy1.id(), deploy1.deploy_type().unwrap());
+    proposer.add_deploy_or_transfer(block_time, *deploy2.id(), deploy2.deploy_type().unwrap());
+    proposer.add_deploy_or_transfer(block_time, *deploy3.id(), deploy3.deploy_type().unwrap());
+    assert_eq!(proposer.sets.pending.len(), 1);
+    assert_eq!(proposer.spilled.len(), 1);
+    assert_eq!(proposer.spilled[0].hash, *deploy2.id());
+
+    // expired spilled deploys are pruned
+    assert_eq!(proposer.prune(expired_time), 2);
+    assert!(proposer.spilled.is_empty());
+    assert!(proposer.spilled_hashes.is_empty());
+    assert_eq!(proposer.pending_bytes, 0);
+}
+
+#[test]
+fn should_rebroadcast_deploys_nearing_expiry() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let deploy = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        DEFAULT_TEST_GAS_PRICE,
+    );
+
+    let mut proposer = create_test_proposer();
+    proposer.local_config.rebroadcast_remaining_ttl_percent = 25;
+    proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+
+    // plenty of the deploy's TTL remains
+    assert!(proposer.take_deploys_to_rebroadcast(block_time).is_empty());
+
+    // a quarter of the TTL remains, so the deploy is rebroadcast, but only once
+    assert_eq!(
+        proposer.take_deploys_to_rebroadcast(Timestamp::from(175)),
+        vec![*deploy.id()]
+    );
+    assert!(proposer
+        .take_deploys_to_rebroadcast(Timestamp::from(190))
+        .is_empty());
+    assert_eq!(proposer.deploys_rebroadcast, 1);
+
+    // once finalized, the deploy is forgotten
+    proposer.finalized_deploys(vec![*deploy.id()]);
+    assert!(proposer
+        .take_deploys_to_rebroadcast(Timestamp::from(190))
+        .is_empty());
+    assert!(proposer.rebroadcast.is_empty());
+}
+
+#[test]
+fn should_not_rebroadcast_if_disabled() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let deploy = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        DEFAULT_TEST_GAS_PRICE,
+    );
+
+    let mut proposer = create_test_proposer();
+    proposer.local_config.rebroadcast_remaining_ttl_percent = 0;
+    proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+
+    assert!(proposer
+        .take_deploys_to_rebroadcast(Timestamp::from(190))
+        .is_empty());
+    assert_eq!(proposer.deploys_rebroadcast, 0);
+}
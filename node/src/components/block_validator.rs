@@ -21,6 +21,8 @@ use derive_more::{Display, From};
 use smallvec::{smallvec, SmallVec};
 use tracing::info;
 
+use casper_execution_engine::shared::gas::Gas;
+
 use crate::{
     components::{block_proposer::DeployType, Component},
     effect::{
@@ -200,8 +202,14 @@ where
                         effects.extend(fetch_effects);
 
                         let deploy_config = self.chainspec.deploy_config;
+                        let transfer_cost =
+                            Gas::from(self.chainspec.system_costs_config.wasmless_transfer_cost());
                         entry.insert(BlockValidationState {
-                            appendable_block: AppendableBlock::new(deploy_config, block_timestamp),
+                            appendable_block: AppendableBlock::new(
+                                deploy_config,
+                                transfer_cost,
+                                block_timestamp,
+                            ),
                             missing_deploys,
                             responders: smallvec![responder],
                             sources: VecDeque::new(), /* This is empty b/c we create the first
@@ -51,7 +51,8 @@ use crate::{
     reactor::ReactorExit,
     types::{
         chainspec::{Error, ProtocolConfig, CHAINSPEC_NAME},
-        ActivationPoint, Block, BlockHash, BlockHeader, Chainspec, ChainspecInfo, ExitCode,
+        ActivationPoint, Block, BlockHash, BlockHeader, Chainspec, ChainspecInfo, DeployLimits,
+        ExitCode,
     },
     utils::{self, Loadable},
     NodeRng,
@@ -465,7 +466,6 @@ impl ChainspecLoader {
             Some(self.chainspec.protocol_config.activation_point.era_id().0),
             Some(self.chainspec.core_config.validator_slots),
             Some(self.chainspec.core_config.auction_delay),
-            Some(self.chainspec.core_config.locked_funds_period.millis()),
             Some(self.chainspec.core_config.round_seigniorage_rate),
             Some(self.chainspec.core_config.unbonding_delay),
             global_state_update,
@@ -611,6 +611,12 @@ where
             Event::Request(ChainspecLoaderRequest::GetChainspecInfo(responder)) => {
                 responder.respond(self.new_chainspec_info()).ignore()
             }
+            Event::Request(ChainspecLoaderRequest::GetDeployLimits(responder)) => responder
+                .respond(DeployLimits::new(
+                    self.chainspec.network_config.name.clone(),
+                    &self.chainspec.deploy_config,
+                ))
+                .ignore(),
             Event::CheckForNextUpgrade => self.check_for_next_upgrade(effect_builder),
             Event::GotNextUpgrade(next_upgrade) => self.handle_got_next_upgrade(next_upgrade),
             Event::PutToStorage { version } => {
@@ -30,28 +30,31 @@ use tracing::error;
 use casper_types::{PublicKey, U512};
 
 use crate::{
-    components::Component,
+    components::{Component, HealthReport},
     crypto::hash::Digest,
     effect::{
         announcements::ConsensusAnnouncement,
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
             ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, LinearChainRequest,
-            NetworkRequest, StorageRequest,
+            NetworkRequest, StateStoreRequest, StorageRequest,
         },
         EffectBuilder, Effects,
     },
     fatal,
     protocol::Message,
     reactor::ReactorEvent,
-    types::{ActivationPoint, Block, BlockHash, BlockHeader, ProtoBlock, Timestamp},
+    types::{
+        ActivationPoint, Block, BlockHash, BlockHeader, ComponentHealth, ProtoBlock, Timestamp,
+    },
     NodeRng,
 };
 
 use crate::effect::EffectExt;
 pub use config::Config;
+pub use consensus_protocol::ValidatorLiveness;
 pub(crate) use consensus_protocol::{BlockContext, EraReport};
-pub(crate) use era_supervisor::{EraId, EraSupervisor};
+pub(crate) use era_supervisor::{EraId, EraSupervisor, PersistedEraState};
 pub(crate) use protocols::highway::HighwayProtocol;
 use traits::NodeIdT;
 
@@ -130,6 +133,8 @@ pub enum Event<I> {
         /// This is empty except if the activation era still needs to be instantiated: Its
         /// validator set is read from the global state, not from a key block.
         validators: BTreeMap<PublicKey, U512>,
+        /// The era states persisted before the node was restarted.
+        era_states: HashMap<EraId, PersistedEraState>,
         timestamp: Timestamp,
     },
     /// An event instructing us to shutdown if the latest era received no votes.
@@ -256,6 +261,7 @@ pub trait ReactorEventT<I>:
     + From<BlockExecutorRequest>
     + From<BlockValidationRequest<ProtoBlock, I>>
     + From<StorageRequest>
+    + From<StateStoreRequest>
     + From<ContractRuntimeRequest>
     + From<ChainspecLoaderRequest>
     + From<LinearChainRequest<I>>
@@ -272,6 +278,7 @@ impl<REv, I> ReactorEventT<I> for REv where
         + From<BlockExecutorRequest>
         + From<BlockValidationRequest<ProtoBlock, I>>
         + From<StorageRequest>
+        + From<StateStoreRequest>
         + From<ContractRuntimeRequest>
         + From<ChainspecLoaderRequest>
         + From<LinearChainRequest<I>>
@@ -348,12 +355,14 @@ where
                 key_blocks,
                 booking_blocks,
                 validators,
+                era_states,
                 timestamp,
             } => {
                 let mut effects = handling_es.handle_initialize_eras(
                     key_blocks,
                     booking_blocks,
                     validators,
+                    era_states,
                     timestamp,
                 );
 
@@ -383,6 +392,26 @@ where
             Event::ConsensusRequest(ConsensusRequest::Status(responder)) => {
                 handling_es.status(responder)
             }
+            Event::ConsensusRequest(ConsensusRequest::ValidatorLiveness(responder)) => {
+                handling_es.validator_liveness(responder)
+            }
+        }
+    }
+}
+
+impl<I> HealthReport for EraSupervisor<I>
+where
+    I: NodeIdT,
+{
+    fn health(&self) -> ComponentHealth {
+        if !self.is_initialized() {
+            ComponentHealth::Degraded("eras not initialized yet".to_string())
+        } else if self.stop_for_upgrade() {
+            ComponentHealth::Degraded("stopping for upgrade".to_string())
+        } else if self.is_paused() {
+            ComponentHealth::Degraded("paused until block execution catches up".to_string())
+        } else {
+            ComponentHealth::Healthy
         }
     }
 }
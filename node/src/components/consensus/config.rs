@@ -9,7 +9,10 @@ use casper_types::SecretKey;
 use crate::{
     components::consensus::EraId,
     crypto::hash::Digest,
-    types::{chainspec::HighwayConfig, Chainspec, TimeDiff, Timestamp},
+    types::{
+        chainspec::{EraEndPolicy, HighwayConfig},
+        Chainspec, TimeDiff, Timestamp,
+    },
     utils::External,
 };
 
@@ -27,6 +30,10 @@ pub struct Config {
     /// The maximum number of blocks by which execution is allowed to lag behind finalization.
     /// If it is more than that, consensus will pause, and resume once the executor has caught up.
     pub max_execution_delay: u64,
+    /// If true, the node only follows consensus: it never creates units and keeps only as much
+    /// protocol state as needed to detect finality and validate evidence.
+    #[serde(default)]
+    pub observer_mode: bool,
 }
 
 impl Default for Config {
@@ -36,6 +43,7 @@ impl Default for Config {
             unit_hashes_folder: Default::default(),
             pending_vertex_timeout: "10sec".parse().unwrap(),
             max_execution_delay: 3,
+            observer_mode: false,
         }
     }
 }
@@ -46,6 +54,7 @@ pub(crate) struct ProtocolConfig {
     pub(crate) highway_config: HighwayConfig,
     pub(crate) era_duration: TimeDiff,
     pub(crate) minimum_era_height: u64,
+    pub(crate) era_end_policy: EraEndPolicy,
     /// Number of eras before an auction actually defines the set of validators.
     /// If you bond with a sufficient bid in era N, you will be a validator in era N +
     /// auction_delay + 1
@@ -70,6 +79,7 @@ impl From<&Chainspec> for ProtocolConfig {
             highway_config: chainspec.highway_config,
             era_duration: chainspec.core_config.era_duration,
             minimum_era_height: chainspec.core_config.minimum_era_height,
+            era_end_policy: chainspec.core_config.era_end_policy,
             auction_delay: chainspec.core_config.auction_delay,
             unbonding_delay: chainspec.core_config.unbonding_delay,
             protocol_version: chainspec.protocol_config.version.clone(),
@@ -2,6 +2,7 @@ use std::{any::Any, collections::BTreeMap, fmt::Debug, path::PathBuf};
 
 use anyhow::Error;
 use datasize::DataSize;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -51,6 +52,23 @@ pub struct EraReport<VID> {
     pub(crate) inactive_validators: Vec<VID>,
 }
 
+/// Statistics about a single validator's participation in the current era.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorLiveness<VID> {
+    /// The validator's ID.
+    pub(crate) validator_id: VID,
+    /// The number of units created by the validator, as seen by this node.
+    pub(crate) units_produced: u64,
+    /// The number of rounds since the start of the era in which the validator created no units.
+    pub(crate) rounds_missed: u64,
+    /// The round exponent of the validator's latest unit, if any.
+    pub(crate) round_exponent: Option<u8>,
+    /// The average time between proposal and finalization of the blocks proposed by the
+    /// validator, if any were finalized.
+    pub(crate) average_finalization_latency: Option<TimeDiff>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct TerminalBlockData<C: Context> {
     /// The rewards for participating in consensus.
@@ -202,4 +220,16 @@ pub(crate) trait ConsensusProtocol<I, C: Context>: Send {
 
     // TODO: Make this lees Highway-specific.
     fn next_round_length(&self) -> Option<TimeDiff>;
+
+    /// Returns our round exponent for the next round, if we are an active validator.
+    fn next_round_exp(&self) -> Option<u8>;
+
+    /// Restores our round exponent as persisted before a restart. Has no effect unless we are an
+    /// active validator.
+    ///
+    /// Our latest unit is not restored here: It is recovered from the unit hash file.
+    fn restore_active_validator(&mut self, round_exp: Option<u8>, now: Timestamp);
+
+    /// Returns the liveness statistics of all validators in this instance.
+    fn validator_liveness(&self) -> Vec<ValidatorLiveness<C::ValidatorId>>;
 }
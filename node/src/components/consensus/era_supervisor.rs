@@ -7,6 +7,7 @@
 
 mod era;
 mod era_id;
+mod persisted_state;
 
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
@@ -39,7 +40,7 @@ use crate::{
             config::ProtocolConfig,
             consensus_protocol::{
                 BlockContext, ConsensusProtocol, EraReport, FinalizedBlock as CpFinalizedBlock,
-                ProtocolOutcome, ProtocolOutcomes,
+                ProtocolOutcome, ProtocolOutcomes, ValidatorLiveness,
             },
             metrics::ConsensusMetrics,
             traits::{ConsensusValueT, NodeIdT},
@@ -61,7 +62,8 @@ use crate::{
     NodeRng,
 };
 
-pub use self::{era::Era, era_id::EraId};
+use self::persisted_state::create_storage_key;
+pub use self::{era::Era, era_id::EraId, persisted_state::PersistedEraState};
 
 /// The delay in milliseconds before we shutdown after the number of faulty validators exceeded the
 /// fault tolerance threshold.
@@ -125,6 +127,9 @@ pub struct EraSupervisor<I> {
     is_initialized: bool,
     /// TODO: Remove once the era supervisor is removed from the Joiner reactor.
     pub(crate) enqueued_requests: VecDeque<ConsensusRequest>,
+    /// The state of each active era as last persisted in storage, or as restored from storage on
+    /// startup.
+    persisted_era_states: HashMap<EraId, PersistedEraState>,
 }
 
 impl<I> Debug for EraSupervisor<I> {
@@ -191,12 +196,21 @@ where
             next_executed_height: 0,
             is_initialized: false,
             enqueued_requests: Default::default(),
+            persisted_era_states: Default::default(),
         };
 
         let bonded_eras = era_supervisor.bonded_eras();
         let era_ids: Vec<EraId> = era_supervisor
             .iter_past(current_era, era_supervisor.bonded_eras() * 3)
             .collect();
+        let state_keys: Vec<(EraId, Vec<u8>)> = era_supervisor
+            .iter_past(current_era, era_supervisor.bonded_eras() * 2)
+            .map(|era_id| {
+                let instance_id = instance_id(&era_supervisor.protocol_config, era_id);
+                let state_key = create_storage_key(&instance_id, &public_signing_key);
+                (era_id, state_key)
+            })
+            .collect();
 
         // Asynchronously collect the information needed to initialize all recent eras.
         let effects = async move {
@@ -215,9 +229,19 @@ where
             )
             .await;
 
+            let mut era_states = HashMap::new();
+            for (era_id, state_key) in state_keys {
+                if let Some(era_state) = effect_builder
+                    .load_state::<PersistedEraState>(state_key.into())
+                    .await
+                {
+                    era_states.insert(era_id, era_state);
+                }
+            }
+
             if current_era > activation_era_id + bonded_eras * 2 {
                 // All eras can be initialized using the key blocks only.
-                (key_blocks, booking_blocks, Default::default())
+                (key_blocks, booking_blocks, Default::default(), era_states)
             } else {
                 // We need the validator set for the activation era from some protocol state.
                 let state_root_hash = if activation_era_id == current_era {
@@ -252,14 +276,15 @@ where
                     .expect("get validator map from global state")
                     .remove(&activation_era_id.0)
                     .expect("get validators for activation era");
-                (key_blocks, booking_blocks, validators)
+                (key_blocks, booking_blocks, validators, era_states)
             }
         }
         .event(
-            move |(key_blocks, booking_blocks, validators)| Event::InitializeEras {
+            move |(key_blocks, booking_blocks, validators, era_states)| Event::InitializeEras {
                 key_blocks,
                 booking_blocks,
                 validators,
+                era_states,
                 timestamp,
             },
         );
@@ -352,7 +377,10 @@ where
         // Activate the era if this node was already running when the era began, it is still
         // ongoing based on its minimum duration, and we are one of the validators.
         let our_id = self.public_signing_key;
-        let should_activate = if !validators.contains_key(&our_id) {
+        let should_activate = if self.config.observer_mode {
+            info!(era = era_id.0, %our_id, "not voting; observer mode");
+            false
+        } else if !validators.contains_key(&our_id) {
             info!(era = era_id.0, %our_id, "not voting; not a validator");
             false
         } else if !self.finished_joining {
@@ -379,6 +407,15 @@ where
             timestamp,
         );
 
+        let persisted_era_state = self.persisted_era_states.get(&era_id);
+        if let Some(era_state) = persisted_era_state {
+            // Restore the faults observed before a restart, so we don't wait for evidence against
+            // validators we already know to be faulty.
+            for vid in era_state.faulty() {
+                consensus.mark_faulty(vid);
+            }
+        }
+
         if should_activate {
             let secret = Keypair::new(self.secret_signing_key.clone(), our_id);
             let unit_hash_file = self.unit_hashes_folder.join(format!(
@@ -391,7 +428,10 @@ where
                 secret,
                 timestamp,
                 Some(unit_hash_file),
-            ))
+            ));
+            if let Some(era_state) = persisted_era_state {
+                consensus.restore_active_validator(era_state.round_exp(), timestamp);
+            }
         }
 
         let era = Era::new(
@@ -403,6 +443,17 @@ where
             validators,
         );
         let _ = self.active_eras.insert(era_id, era);
+        // Observers don't need the units of past eras: Their switch blocks are already finalized,
+        // so only the evidence is retained, to validate accusations in later eras.
+        if self.config.observer_mode {
+            if let Some(era) = era_id
+                .checked_sub(1)
+                .and_then(|prev_era_id| self.active_eras.get_mut(&prev_era_id))
+            {
+                trace!(era = era_id.0 - 1, "clearing past era in observer mode");
+                era.consensus.set_evidence_only();
+            }
+        }
         let oldest_bonded_era_id = oldest_bonded_era(&self.protocol_config, era_id);
         // Clear the obsolete data from the era whose validators are unbonded now. We only retain
         // the information necessary to validate evidence that units in still-bonded eras may refer
@@ -419,6 +470,7 @@ where
         if let Some(obsolete_era_id) = oldest_evidence_era_id.checked_sub(1) {
             trace!(era = obsolete_era_id.0, "removing obsolete era");
             self.active_eras.remove(&obsolete_era_id);
+            self.persisted_era_states.remove(&obsolete_era_id);
         }
 
         outcomes
@@ -447,18 +499,29 @@ where
         let secret = Keypair::new(self.secret_signing_key.clone(), self.public_signing_key);
         let public_key = self.public_signing_key;
         let unit_hashes_folder = self.unit_hashes_folder.clone();
+        let persisted_era_state = self.persisted_era_states.get(&self.current_era).cloned();
+        let observer_mode = self.config.observer_mode;
         self.active_eras
             .get_mut(&self.current_era)
             .map(|era| {
-                if era.validators().contains_key(&public_key) {
+                if !observer_mode && era.validators().contains_key(&public_key) {
                     let instance_id = *era.consensus.instance_id();
                     let unit_hash_file = unit_hashes_folder.join(format!(
                         "unit_hash_{:?}_{}.dat",
                         instance_id,
                         public_key.to_hex()
                     ));
-                    era.consensus
-                        .activate_validator(public_key, secret, now, Some(unit_hash_file))
+                    let outcomes = era.consensus.activate_validator(
+                        public_key,
+                        secret,
+                        now,
+                        Some(unit_hash_file),
+                    );
+                    if let Some(era_state) = persisted_era_state {
+                        era.consensus
+                            .restore_active_validator(era_state.round_exp(), now);
+                    }
+                    outcomes
                 } else {
                     Vec::new()
                 }
@@ -477,13 +540,18 @@ where
         self.update_consensus_pause();
     }
 
+    /// Returns whether the last executed block is too far behind the last finalized block, in
+    /// which case consensus is paused.
+    pub(crate) fn is_paused(&self) -> bool {
+        self.next_block_height
+            .saturating_sub(self.next_executed_height)
+            > self.config.max_execution_delay
+    }
+
     /// Pauses or unpauses consensus: Whenever the last executed block is too far behind the last
     /// finalized block, we suspend consensus.
     fn update_consensus_pause(&mut self) {
-        let paused = self
-            .next_block_height
-            .saturating_sub(self.next_executed_height)
-            > self.config.max_execution_delay;
+        let paused = self.is_paused();
         match self.active_eras.get_mut(&self.current_era) {
             Some(era) => era.set_paused(paused),
             None => error!(era = self.current_era.0, "current era not initialized"),
@@ -512,9 +580,11 @@ where
         key_blocks: HashMap<EraId, BlockHeader>,
         booking_blocks: HashMap<EraId, BlockHash>,
         activation_era_validators: BTreeMap<PublicKey, U512>,
+        era_states: HashMap<EraId, PersistedEraState>,
         timestamp: Timestamp,
     ) -> HashMap<EraId, ProtocolOutcomes<I, ClContext>> {
         let mut result_map = HashMap::new();
+        self.persisted_era_states = era_states;
 
         for era_id in self.iter_past(self.current_era, self.bonded_eras() * 2) {
             let newly_slashed;
@@ -885,12 +955,14 @@ where
         key_blocks: HashMap<EraId, BlockHeader>,
         booking_blocks: HashMap<EraId, BlockHash>,
         validators: BTreeMap<PublicKey, U512>,
+        era_states: HashMap<EraId, PersistedEraState>,
         timestamp: Timestamp,
     ) -> Effects<Event<I>> {
         let result_map = self.era_supervisor.handle_initialize_eras(
             key_blocks,
             booking_blocks,
             validators,
+            era_states,
             timestamp,
         );
 
@@ -996,10 +1068,46 @@ where
     where
         T: IntoIterator<Item = ProtocolOutcome<I, ClContext>>,
     {
-        outcomes
+        let effects: Effects<Event<I>> = outcomes
             .into_iter()
             .flat_map(|result| self.handle_consensus_outcome(era_id, result))
-            .collect()
+            .collect();
+        #[cfg(not(feature = "fast-sync"))]
+        let effects = effects
+            .into_iter()
+            .chain(self.persist_era_state(era_id))
+            .collect();
+        effects
+    }
+
+    /// Saves the essential state of the era to storage if it has changed since it was last saved,
+    /// so that it can be restored after a restart.
+    ///
+    /// Only eras in which we are an active validator are persisted: The restored state only
+    /// affects the units we create.
+    #[cfg(not(feature = "fast-sync"))]
+    fn persist_era_state(&mut self, era_id: EraId) -> Effects<Event<I>> {
+        let era = match self.era_supervisor.active_eras.get(&era_id) {
+            Some(era) if era.consensus.is_active() => era,
+            _ => return Effects::new(),
+        };
+        let era_state = PersistedEraState::new(&*era.consensus);
+        let persisted_era_states = &mut self.era_supervisor.persisted_era_states;
+        if persisted_era_states.get(&era_id) == Some(&era_state) {
+            return Effects::new();
+        }
+        let state_key = create_storage_key(
+            era.consensus.instance_id(),
+            &self.era_supervisor.public_signing_key,
+        );
+        persisted_era_states.insert(era_id, era_state.clone());
+        let effect_builder = self.effect_builder;
+        async move {
+            if !effect_builder.save_state(state_key.into(), era_state).await {
+                error!(era = era_id.0, "failed to persist era state");
+            }
+        }
+        .ignore()
     }
 
     /// Returns `true` if any of the most recent eras has evidence against the validator with key
@@ -1125,6 +1233,10 @@ where
                 self.era_supervisor
                     .metrics
                     .finalized_block(&finalized_block);
+                self.era_supervisor.metrics.validator_liveness(
+                    era.consensus.next_round_exp(),
+                    &era.consensus.validator_liveness(),
+                );
                 // Announce the finalized proto block.
                 let mut effects = self
                     .effect_builder
@@ -1321,6 +1433,26 @@ where
         responder.respond((public_key, round_length)).ignore()
     }
 
+    pub(super) fn validator_liveness(
+        &self,
+        responder: Responder<(EraId, Option<u8>, Vec<ValidatorLiveness<PublicKey>>)>,
+    ) -> Effects<Event<I>> {
+        let current_era = self.era_supervisor.current_era;
+        let (round_exp, liveness) =
+            self.era_supervisor
+                .active_eras
+                .get(&current_era)
+                .map_or((None, Vec::new()), |era| {
+                    (
+                        era.consensus.next_round_exp(),
+                        era.consensus.validator_liveness(),
+                    )
+                });
+        responder
+            .respond((current_era, round_exp, liveness))
+            .ignore()
+    }
+
     fn disconnect(&self, sender: I) -> Effects<Event<I>> {
         self.effect_builder
             .announce_disconnect_from_peer(sender)
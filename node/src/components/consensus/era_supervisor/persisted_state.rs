@@ -0,0 +1,59 @@
+use std::collections::BTreeSet;
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{AsymmetricType, PublicKey};
+
+use crate::{
+    components::consensus::{cl_context::ClContext, consensus_protocol::ConsensusProtocol},
+    crypto::hash::Digest,
+};
+
+/// The essential state of an era, persisted in storage so that it can be restored after a restart.
+///
+/// Our own latest unit is not part of it: That is already persisted in the unit hash file.
+#[derive(DataSize, Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedEraState {
+    /// The validators known to be faulty in this era.
+    faulty: BTreeSet<PublicKey>,
+    /// Our round exponent for the next round.
+    round_exp: Option<u8>,
+}
+
+impl PersistedEraState {
+    /// Returns the current state of the given consensus instance.
+    pub(crate) fn new<I>(consensus: &dyn ConsensusProtocol<I, ClContext>) -> Self {
+        PersistedEraState {
+            faulty: consensus
+                .validators_with_evidence()
+                .into_iter()
+                .cloned()
+                .collect(),
+            round_exp: consensus.next_round_exp(),
+        }
+    }
+
+    /// Returns the validators known to be faulty in this era.
+    pub(crate) fn faulty(&self) -> &BTreeSet<PublicKey> {
+        &self.faulty
+    }
+
+    /// Returns our round exponent for the next round.
+    pub(crate) fn round_exp(&self) -> Option<u8> {
+        self.round_exp
+    }
+}
+
+/// Creates the key under which the state of the era with the given instance ID is persisted.
+///
+/// The key includes our own public key, so that the state is not reused if the node's signing key
+/// changes.
+pub(crate) fn create_storage_key(instance_id: &Digest, our_id: &PublicKey) -> Vec<u8> {
+    format!(
+        "era_supervisor_state:instance_id={},validator={}",
+        instance_id,
+        our_id.to_hex()
+    )
+    .into()
+}
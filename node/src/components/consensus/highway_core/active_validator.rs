@@ -610,6 +610,10 @@ impl<C: Context> ActiveValidator<C> {
     pub(crate) fn next_round_length(&self) -> TimeDiff {
         state::round_len(self.next_round_exp)
     }
+
+    pub(crate) fn next_round_exp(&self) -> u8 {
+        self.next_round_exp
+    }
 }
 
 #[cfg(test)]
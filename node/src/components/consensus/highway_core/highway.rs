@@ -633,6 +633,10 @@ impl<C: Context> Highway<C> {
             .as_ref()
             .map(|av| av.next_round_length())
     }
+
+    pub(crate) fn next_round_exp(&self) -> Option<u8> {
+        self.active_validator.as_ref().map(|av| av.next_round_exp())
+    }
 }
 
 #[cfg(test)]
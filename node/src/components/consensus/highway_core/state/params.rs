@@ -1,6 +1,7 @@
 use datasize::DataSize;
 
 use super::{round_len, TimeDiff, Timestamp};
+use crate::types::chainspec::EraEndPolicy;
 
 /// Protocol parameters for Highway.
 #[derive(Debug, DataSize, Clone)]
@@ -14,6 +15,7 @@ pub(crate) struct Params {
     end_height: u64,
     start_timestamp: Timestamp,
     end_timestamp: Timestamp,
+    era_end_policy: EraEndPolicy,
     endorsement_evidence_limit: u64,
 }
 
@@ -35,7 +37,7 @@ impl Params {
     ///   maximum round length.
     /// * `end_height`, `end_timestamp`: The last block will be the first one that has at least the
     ///   specified height _and_ is no earlier than the specified timestamp. No children of this
-    ///   block can be proposed.
+    ///   block can be proposed. Use `with_era_end_policy` to require only one of the two instead.
     #[allow(clippy::too_many_arguments)] // FIXME
     pub(crate) fn new(
         seed: u64,
@@ -63,10 +65,18 @@ impl Params {
             end_height,
             start_timestamp,
             end_timestamp,
+            era_end_policy: EraEndPolicy::HeightAndDuration,
             endorsement_evidence_limit,
         }
     }
 
+    /// Sets whether the last block needs to reach both the end height and the end timestamp, or
+    /// only one of them.
+    pub(crate) fn with_era_end_policy(mut self, era_end_policy: EraEndPolicy) -> Params {
+        self.era_end_policy = era_end_policy;
+        self
+    }
+
     /// Returns the random seed.
     pub(crate) fn seed(&self) -> u64 {
         self.seed
@@ -120,6 +130,12 @@ impl Params {
         self.end_timestamp
     }
 
+    /// Returns whether the last block needs to reach both the end height and the end timestamp, or
+    /// only one of them.
+    pub(crate) fn era_end_policy(&self) -> EraEndPolicy {
+        self.era_end_policy
+    }
+
     /// Returns the maximum number of additional units included in evidence for conflicting
     /// endorsements. If you endorse two conflicting forks at sequence numbers that differ by more
     /// than this, you get away with it and are not marked faulty.
@@ -129,8 +145,10 @@ impl Params {
 
     /// Returns the minimum lenght of the era.
     pub(crate) fn min_era_length(&self) -> TimeDiff {
-        (TimeDiff::from(1 << self.min_round_exp) * self.end_height)
-            .max(self.end_timestamp - self.start_timestamp)
+        self.era_end_policy.min_era_length(
+            TimeDiff::from(1 << self.min_round_exp) * self.end_height,
+            self.end_timestamp - self.start_timestamp,
+        )
     }
 }
 
@@ -150,4 +168,9 @@ impl Params {
         self.end_height = new_end_height;
         self
     }
+
+    pub(crate) fn with_end_timestamp(mut self, new_end_timestamp: Timestamp) -> Params {
+        self.end_timestamp = new_end_timestamp;
+        self
+    }
 }
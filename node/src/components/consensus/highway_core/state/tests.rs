@@ -9,13 +9,18 @@ use datasize::DataSize;
 use rand::{Rng, RngCore};
 
 use super::*;
-use crate::components::consensus::{
-    highway_core::{
-        evidence::EvidenceError,
-        highway::Dependency,
-        highway_testing::{TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT, TEST_INSTANCE_ID},
+use crate::{
+    components::consensus::{
+        highway_core::{
+            evidence::EvidenceError,
+            highway::Dependency,
+            highway_testing::{
+                TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT, TEST_INSTANCE_ID,
+            },
+        },
+        traits::{ConsensusValueT, ValidatorSecret},
     },
-    traits::{ConsensusValueT, ValidatorSecret},
+    types::chainspec::EraEndPolicy,
 };
 
 pub(crate) const WEIGHTS: &[Weight] = &[Weight(3), Weight(4), Weight(5)];
@@ -753,6 +758,56 @@ fn is_terminal_block() -> Result<(), AddUnitError<TestContext>> {
     Ok(())
 }
 
+#[test]
+fn is_terminal_block_height_and_duration() -> Result<(), AddUnitError<TestContext>> {
+    // The era duration hasn't passed, so reaching the era height doesn't end the era.
+    let params = test_params(0)
+        .with_end_timestamp(Timestamp::from(1_000_000))
+        .with_era_end_policy(EraEndPolicy::HeightAndDuration);
+    let mut state = State::new(WEIGHTS, params, vec![]);
+
+    let a0 = add_unit!(state, ALICE, 0x00; N, N, N)?;
+    let b0 = add_unit!(state, BOB, 0x01; a0, N, N)?;
+    let c0 = add_unit!(state, CAROL, 0x02; a0, b0, N)?;
+    let a1 = add_unit!(state, ALICE, 0x03; a0, b0, c0)?;
+    let a2 = add_unit!(state, ALICE, 0x04; a1, b0, c0)?;
+    assert_eq!(TEST_ERA_HEIGHT - 1, state.block(&a2).height);
+    assert!(!state.is_terminal_block(&a2));
+    let a3 = add_unit!(state, ALICE, 0x05; a2, b0, c0)?;
+    assert!(!state.is_terminal_block(&a3));
+    Ok(())
+}
+
+#[test]
+fn is_terminal_block_height_or_duration() -> Result<(), AddUnitError<TestContext>> {
+    // The era duration hasn't passed, but reaching the era height ends the era.
+    let params = test_params(0)
+        .with_end_timestamp(Timestamp::from(1_000_000))
+        .with_era_end_policy(EraEndPolicy::HeightOrDuration);
+    let mut state = State::new(WEIGHTS, params.clone(), vec![]);
+
+    let a0 = add_unit!(state, ALICE, 0x00; N, N, N)?;
+    let b0 = add_unit!(state, BOB, 0x01; a0, N, N)?;
+    let c0 = add_unit!(state, CAROL, 0x02; a0, b0, N)?;
+    let a1 = add_unit!(state, ALICE, 0x03; a0, b0, c0)?;
+    assert!(!state.is_terminal_block(&a1));
+    let a2 = add_unit!(state, ALICE, 0x04; a1, b0, c0)?;
+    assert_eq!(TEST_ERA_HEIGHT - 1, state.block(&a2).height);
+    assert!(state.is_terminal_block(&a2));
+
+    // Once the era duration has passed, the era ends even though the era height isn't reached.
+    let b0_timestamp = state.unit(&b0).timestamp;
+    let params = params.with_end_timestamp(b0_timestamp);
+    let mut state = State::new(WEIGHTS, params, vec![]);
+
+    let a0 = add_unit!(state, ALICE, 0x00; N, N, N)?;
+    assert!(!state.is_terminal_block(&a0));
+    let b0 = add_unit!(state, BOB, 0x01; a0, N, N)?;
+    assert_eq!(1, state.block(&b0).height);
+    assert!(state.is_terminal_block(&b0));
+    Ok(())
+}
+
 #[test]
 fn conflicting_endorsements() -> Result<(), AddUnitError<TestContext>> {
     if TODO_ENDORSEMENT_EVIDENCE_DISABLED {
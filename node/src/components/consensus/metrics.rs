@@ -1,10 +1,16 @@
-use prometheus::{Gauge, IntGauge, Registry};
+use prometheus::{Gauge, IntGauge, IntGaugeVec, Opts, Registry};
+
+use casper_types::{AsymmetricType, PublicKey};
 
 use crate::{
+    components::consensus::ValidatorLiveness,
     types::{FinalizedBlock, Timestamp},
     unregister_metric,
 };
 
+/// The label identifying the validator in per-validator metrics.
+const VALIDATOR_LABEL: &str = "validator";
+
 /// Network metrics to track Consensus
 #[derive(Debug)]
 pub(super) struct ConsensusMetrics {
@@ -18,6 +24,15 @@ pub(super) struct ConsensusMetrics {
     time_of_last_finalized_block: IntGauge,
     /// The Current era.
     pub(super) current_era: IntGauge,
+    /// Our round exponent for the next round, or -1 if we are not an active validator.
+    round_exponent: IntGauge,
+    /// Number of units created by each validator in the current era.
+    validator_units_produced: IntGaugeVec,
+    /// Number of rounds in the current era in which each validator created no units.
+    validator_rounds_missed: IntGaugeVec,
+    /// Average time, in milliseconds, between proposal and finalization of each validator's
+    /// blocks in the current era.
+    validator_finalization_latency: IntGaugeVec,
     /// registry component.
     registry: Registry,
 }
@@ -39,17 +54,51 @@ impl ConsensusMetrics {
             "timestamp of the most recently finalized block",
         )?;
         let current_era = IntGauge::new("current_era", "The current era")?;
+        let round_exponent = IntGauge::new(
+            "round_exponent",
+            "our round exponent for the next round, or -1 if we are not an active validator",
+        )?;
+        let validator_units_produced = IntGaugeVec::new(
+            Opts::new(
+                "validator_units_produced",
+                "the number of units created by the validator in the current era",
+            ),
+            &[VALIDATOR_LABEL],
+        )?;
+        let validator_rounds_missed = IntGaugeVec::new(
+            Opts::new(
+                "validator_rounds_missed",
+                "the number of rounds in the current era in which the validator created no units",
+            ),
+            &[VALIDATOR_LABEL],
+        )?;
+        let validator_finalization_latency = IntGaugeVec::new(
+            Opts::new(
+                "validator_finalization_latency",
+                "the average amount of time, in milliseconds, between proposal and finalization of \
+                the validator's blocks in the current era",
+            ),
+            &[VALIDATOR_LABEL],
+        )?;
         registry.register(Box::new(finalization_time.clone()))?;
         registry.register(Box::new(finalized_block_count.clone()))?;
         registry.register(Box::new(current_era.clone()))?;
         registry.register(Box::new(time_of_last_proposed_block.clone()))?;
         registry.register(Box::new(time_of_last_finalized_block.clone()))?;
+        registry.register(Box::new(round_exponent.clone()))?;
+        registry.register(Box::new(validator_units_produced.clone()))?;
+        registry.register(Box::new(validator_rounds_missed.clone()))?;
+        registry.register(Box::new(validator_finalization_latency.clone()))?;
         Ok(ConsensusMetrics {
             finalization_time,
             finalized_block_count,
             time_of_last_proposed_block,
             time_of_last_finalized_block,
             current_era,
+            round_exponent,
+            validator_units_produced,
+            validator_rounds_missed,
+            validator_finalization_latency,
             registry: registry.clone(),
         })
     }
@@ -69,6 +118,34 @@ impl ConsensusMetrics {
         self.time_of_last_proposed_block
             .set(Timestamp::now().millis() as i64);
     }
+
+    /// Updates our round exponent and the per-validator liveness metrics of the current era.
+    pub(super) fn validator_liveness(
+        &mut self,
+        next_round_exp: Option<u8>,
+        liveness: &[ValidatorLiveness<PublicKey>],
+    ) {
+        self.round_exponent
+            .set(next_round_exp.map_or(-1, i64::from));
+        // Drop validators of previous eras.
+        self.validator_units_produced.reset();
+        self.validator_rounds_missed.reset();
+        self.validator_finalization_latency.reset();
+        for validator in liveness {
+            let label = validator.validator_id.to_hex();
+            self.validator_units_produced
+                .with_label_values(&[&label])
+                .set(validator.units_produced as i64);
+            self.validator_rounds_missed
+                .with_label_values(&[&label])
+                .set(validator.rounds_missed as i64);
+            if let Some(latency) = validator.average_finalization_latency {
+                self.validator_finalization_latency
+                    .with_label_values(&[&label])
+                    .set(latency.millis() as i64);
+            }
+        }
+    }
 }
 
 impl Drop for ConsensusMetrics {
@@ -78,5 +155,9 @@ impl Drop for ConsensusMetrics {
         unregister_metric!(self.registry, self.current_era);
         unregister_metric!(self.registry, self.time_of_last_finalized_block);
         unregister_metric!(self.registry, self.time_of_last_proposed_block);
+        unregister_metric!(self.registry, self.round_exponent);
+        unregister_metric!(self.registry, self.validator_units_produced);
+        unregister_metric!(self.registry, self.validator_rounds_missed);
+        unregister_metric!(self.registry, self.validator_finalization_latency);
     }
 }
@@ -1,3 +1,4 @@
+mod liveness;
 mod participation;
 mod round_success_meter;
 mod synchronizer;
@@ -23,7 +24,9 @@ use casper_types::{system::auction::BLOCK_REWARD, U512};
 use crate::{
     components::consensus::{
         config::{Config, ProtocolConfig},
-        consensus_protocol::{BlockContext, ConsensusProtocol, ProtocolOutcome, ProtocolOutcomes},
+        consensus_protocol::{
+            BlockContext, ConsensusProtocol, ProtocolOutcome, ProtocolOutcomes, ValidatorLiveness,
+        },
         highway_core::{
             active_validator::Effect as AvEffect,
             finality_detector::{FinalityDetector, FttExceeded},
@@ -34,10 +37,12 @@ use crate::{
         traits::{ConsensusValueT, Context, NodeIdT},
         ActionId, TimerId,
     },
-    types::{TimeDiff, Timestamp},
+    types::{chainspec::EraEndPolicy, TimeDiff, Timestamp},
 };
 
-use self::{round_success_meter::RoundSuccessMeter, synchronizer::Synchronizer};
+use self::{
+    liveness::LivenessTracker, round_success_meter::RoundSuccessMeter, synchronizer::Synchronizer,
+};
 
 /// Never allow more than this many units in a piece of evidence for conflicting endorsements,
 /// even if eras are longer than this.
@@ -69,8 +74,12 @@ where
     highway: Highway<C>,
     /// A tracker for whether we are keeping up with the current round exponent or not.
     round_success_meter: RoundSuccessMeter<C>,
+    /// Per-validator liveness statistics for this era.
+    liveness: LivenessTracker,
     synchronizer: Synchronizer<I, C>,
     evidence_only: bool,
+    /// If true, we never create units and skip the bookkeeping only needed by active validators.
+    observer: bool,
 }
 
 impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
@@ -129,9 +138,14 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         // a validator to create during an era. After that, they can endorse two conflicting forks
         // without getting slashed.
         let min_round_len = 1 << highway_config.minimum_round_exponent;
-        let min_rounds_per_era = protocol_config
-            .minimum_era_height
-            .max(1 + protocol_config.era_duration.millis() / min_round_len);
+        let min_rounds_per_era = match protocol_config.era_end_policy {
+            EraEndPolicy::HeightAndDuration => protocol_config
+                .minimum_era_height
+                .max(1 + protocol_config.era_duration.millis() / min_round_len),
+            EraEndPolicy::HeightOrDuration => protocol_config
+                .minimum_era_height
+                .min(1 + protocol_config.era_duration.millis() / min_round_len),
+        };
         let endorsement_evidence_limit =
             (2 * min_rounds_per_era).min(MAX_ENDORSEMENT_EVIDENCE_LIMIT);
 
@@ -146,7 +160,8 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
             era_start_time,
             era_start_time + protocol_config.era_duration,
             endorsement_evidence_limit,
-        );
+        )
+        .with_era_end_policy(protocol_config.era_end_policy);
 
         let mut outcomes = vec![
             ProtocolOutcome::ScheduleTimer(
@@ -190,12 +205,14 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
             finality_detector: FinalityDetector::new(ftt),
             highway: Highway::new(instance_id, validators, params),
             round_success_meter,
+            liveness: LivenessTracker::new(),
             synchronizer: Synchronizer::new(
                 config.pending_vertex_timeout,
                 validators_count,
                 instance_id,
             ),
             evidence_only: false,
+            observer: config.observer_mode,
         });
         (hw_proto, outcomes)
     }
@@ -214,6 +231,7 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         match effect {
             AvEffect::NewVertex(vv) => {
                 self.calculate_round_exponent(&vv);
+                self.liveness.add_vertex(vv.inner());
                 self.process_new_vertex(vv.into())
             }
             AvEffect::ScheduleTimer(timestamp) => {
@@ -262,7 +280,24 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
 
     fn detect_finality(&mut self) -> ProtocolOutcomes<I, C> {
         let faulty_weight = match self.finality_detector.run(&self.highway) {
-            Ok(iter) => return iter.map(ProtocolOutcome::FinalizedBlock).collect(),
+            Ok(iter) => {
+                let finalized_blocks: Vec<_> = iter.collect();
+                let now = Timestamp::now();
+                for finalized_block in &finalized_blocks {
+                    if let Some(idx) = self
+                        .highway
+                        .validators()
+                        .get_index(&finalized_block.proposer)
+                    {
+                        let latency = now.saturating_diff(finalized_block.timestamp);
+                        self.liveness.finalized_block(idx, latency);
+                    }
+                }
+                return finalized_blocks
+                    .into_iter()
+                    .map(ProtocolOutcome::FinalizedBlock)
+                    .collect();
+            }
             Err(FttExceeded(weight)) => weight.0,
         };
         error!(
@@ -394,8 +429,11 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         // Check whether we should change the round exponent.
         // It's important to do it before the vertex is added to the state - this way if the last
         // round has finished, we now have all the vertices from that round in the state, and no
-        // newer ones.
-        self.calculate_round_exponent(&vv);
+        // newer ones. Observers never create units, so they don't need a round exponent.
+        if !self.observer {
+            self.calculate_round_exponent(&vv);
+        }
+        self.liveness.add_vertex(vv.inner());
         let av_effects = self.highway.add_valid_vertex(vv, now);
         self.process_av_effects(av_effects)
     }
@@ -766,6 +804,10 @@ where
         timestamp: Timestamp,
         unit_hash_file: Option<PathBuf>,
     ) -> ProtocolOutcomes<I, C> {
+        if self.observer {
+            info!(?our_id, "not activating validator in observer mode");
+            return vec![];
+        }
         let ftt = self.finality_detector.fault_tolerance_threshold();
         let av_effects =
             self.highway
@@ -878,4 +920,23 @@ where
     fn next_round_length(&self) -> Option<TimeDiff> {
         self.highway.next_round_length()
     }
+
+    fn next_round_exp(&self) -> Option<u8> {
+        self.highway.next_round_exp()
+    }
+
+    fn restore_active_validator(&mut self, round_exp: Option<u8>, now: Timestamp) {
+        if !self.is_active() {
+            return;
+        }
+        if let Some(round_exp) = round_exp {
+            let round_exp = self.round_success_meter.restore_exponent(round_exp, now);
+            self.highway.set_round_exp(round_exp);
+        }
+    }
+
+    fn validator_liveness(&self) -> Vec<ValidatorLiveness<C::ValidatorId>> {
+        self.liveness
+            .validator_liveness(&self.highway, Timestamp::now())
+    }
 }
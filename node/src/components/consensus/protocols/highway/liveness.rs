@@ -0,0 +1,124 @@
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use datasize::DataSize;
+
+use crate::{
+    components::consensus::{
+        consensus_protocol::ValidatorLiveness,
+        highway_core::{
+            highway::{Highway, Vertex},
+            state,
+            validators::ValidatorIndex,
+        },
+        traits::Context,
+    },
+    types::{TimeDiff, Timestamp},
+};
+
+/// The rounds in which a validator created at least one unit.
+#[derive(Clone, Copy, DataSize, Debug)]
+struct Rounds {
+    /// The number of distinct rounds with a unit by the validator.
+    count: u64,
+    /// The ID of the latest round with a unit by the validator.
+    latest_round_id: Timestamp,
+    /// The round exponent of the validator's latest unit.
+    round_exp: u8,
+}
+
+/// The finalization latencies of the blocks proposed by a validator.
+#[derive(Clone, Copy, DataSize, Debug, Default)]
+struct Latencies {
+    /// The number of finalized blocks.
+    count: u64,
+    /// The sum of the times between proposal and finalization of these blocks.
+    total: TimeDiff,
+}
+
+/// Collects per-validator liveness statistics over the course of a Highway era.
+#[derive(DataSize, Debug, Default)]
+pub(crate) struct LivenessTracker {
+    rounds: BTreeMap<ValidatorIndex, Rounds>,
+    latencies: BTreeMap<ValidatorIndex, Latencies>,
+}
+
+impl LivenessTracker {
+    /// Creates a new tracker with no recorded activity.
+    pub(crate) fn new() -> Self {
+        LivenessTracker::default()
+    }
+
+    /// Registers a vertex that is being added to the protocol state.
+    ///
+    /// A validator's units are always added in order, so their round IDs never decrease.
+    pub(crate) fn add_vertex<C: Context>(&mut self, vertex: &Vertex<C>) {
+        let wunit = match vertex.unit() {
+            Some(swunit) => swunit.wire_unit(),
+            None => return,
+        };
+        let round_id = state::round_id(wunit.timestamp, wunit.round_exp);
+        match self.rounds.entry(wunit.creator) {
+            Entry::Vacant(entry) => {
+                entry.insert(Rounds {
+                    count: 1,
+                    latest_round_id: round_id,
+                    round_exp: wunit.round_exp,
+                });
+            }
+            Entry::Occupied(mut entry) => {
+                let rounds = entry.get_mut();
+                if rounds.latest_round_id < round_id {
+                    rounds.count += 1;
+                    rounds.latest_round_id = round_id;
+                }
+                rounds.round_exp = wunit.round_exp;
+            }
+        }
+    }
+
+    /// Registers a newly finalized block proposed by `proposer`, `latency` after its timestamp.
+    pub(crate) fn finalized_block(&mut self, proposer: ValidatorIndex, latency: TimeDiff) {
+        let latencies = self.latencies.entry(proposer).or_default();
+        latencies.count += 1;
+        latencies.total += latency;
+    }
+
+    /// Returns the liveness statistics of every validator in the era, as of `now`.
+    ///
+    /// A round counts as missed if the validator didn't create any unit in it. The number of
+    /// rounds in the era so far is computed using the validator's latest round exponent, or the
+    /// era's initial one if the validator hasn't created any units yet.
+    pub(crate) fn validator_liveness<C: Context>(
+        &self,
+        highway: &Highway<C>,
+        now: Timestamp,
+    ) -> Vec<ValidatorLiveness<C::ValidatorId>> {
+        let state = highway.state();
+        let elapsed = now.saturating_diff(state.params().start_timestamp());
+        highway
+            .validators()
+            .enumerate_ids()
+            .map(|(idx, validator_id)| {
+                let units_produced = state.panorama()[idx]
+                    .correct()
+                    .map_or(0, |hash| state.unit(hash).seq_number + 1);
+                let rounds = self.rounds.get(&idx);
+                let round_exp = rounds.map_or(state.params().init_round_exp(), |r| r.round_exp);
+                let rounds_elapsed = elapsed.millis() >> round_exp;
+                let rounds_missed = rounds_elapsed.saturating_sub(rounds.map_or(0, |r| r.count));
+                let average_finalization_latency = self
+                    .latencies
+                    .get(&idx)
+                    .filter(|latencies| latencies.count > 0)
+                    .map(|latencies| latencies.total / latencies.count);
+                ValidatorLiveness {
+                    validator_id: validator_id.clone(),
+                    units_produced,
+                    rounds_missed,
+                    round_exponent: rounds.map(|r| r.round_exp),
+                    average_finalization_latency,
+                }
+            })
+            .collect()
+    }
+}
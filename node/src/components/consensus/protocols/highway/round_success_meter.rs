@@ -49,6 +49,16 @@ impl<C: Context> RoundSuccessMeter<C> {
         self.proposals = Vec::new();
     }
 
+    /// Switches to the given round exponent, e.g. as persisted before a restart, clamped to the
+    /// allowed range. Returns the new round exponent.
+    pub fn restore_exponent(&mut self, round_exp: u8, timestamp: Timestamp) -> u8 {
+        let round_exp = round_exp.max(self.min_round_exp).min(self.max_round_exp);
+        if round_exp != self.current_round_exp {
+            self.change_exponent(round_exp, timestamp);
+        }
+        round_exp
+    }
+
     fn check_proposals_success(&self, state: &State<C>, proposal_h: &C::Hash) -> bool {
         let total_w = state.total_weight();
 
@@ -281,4 +291,30 @@ mod tests {
         }
         assert_eq!(round_success_meter.new_exponent(), TEST_MIN_ROUND_EXP);
     }
+
+    #[test]
+    fn restore_exponent_clamps_to_allowed_range() {
+        let now = crate::types::Timestamp::now();
+        let mut round_success_meter: super::RoundSuccessMeter<ClContext> =
+            super::RoundSuccessMeter::new(
+                TEST_ROUND_EXP,
+                TEST_MIN_ROUND_EXP,
+                TEST_MAX_ROUND_EXP,
+                now,
+            );
+        assert_eq!(
+            round_success_meter.restore_exponent(TEST_ROUND_EXP + 2, now),
+            TEST_ROUND_EXP + 2
+        );
+        assert_eq!(round_success_meter.new_exponent(), TEST_ROUND_EXP + 2);
+        assert_eq!(
+            round_success_meter.restore_exponent(TEST_MAX_ROUND_EXP + 1, now),
+            TEST_MAX_ROUND_EXP
+        );
+        assert_eq!(
+            round_success_meter.restore_exponent(TEST_MIN_ROUND_EXP - 1, now),
+            TEST_MIN_ROUND_EXP
+        );
+        assert_eq!(round_success_meter.new_exponent(), TEST_MIN_ROUND_EXP);
+    }
 }
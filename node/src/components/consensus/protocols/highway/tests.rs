@@ -57,6 +57,19 @@ pub(crate) fn new_test_highway_protocol<I1, I2, T>(
     weights: I1,
     init_slashed: I2,
 ) -> Box<dyn ConsensusProtocol<NodeId, ClContext>>
+where
+    I1: IntoIterator<Item = (PublicKey, T)>,
+    I2: IntoIterator<Item = PublicKey>,
+    T: Into<U512>,
+{
+    new_test_highway_protocol_with_observer_mode(weights, init_slashed, false)
+}
+
+pub(crate) fn new_test_highway_protocol_with_observer_mode<I1, I2, T>(
+    weights: I1,
+    init_slashed: I2,
+    observer_mode: bool,
+) -> Box<dyn ConsensusProtocol<NodeId, ClContext>>
 where
     I1: IntoIterator<Item = (PublicKey, T)>,
     I2: IntoIterator<Item = PublicKey>,
@@ -72,6 +85,7 @@ where
         unit_hashes_folder: Default::default(),
         pending_vertex_timeout: "1min".parse().unwrap(),
         max_execution_delay: 3,
+        observer_mode,
     };
     // Timestamp of the genesis era start and test start.
     let start_timestamp: Timestamp = 0.into();
@@ -217,6 +231,71 @@ fn send_a_valid_wire_unit() {
     }
 }
 
+#[test]
+fn report_validator_liveness() {
+    let creator: ValidatorIndex = ALICE;
+    let validators = vec![(*ALICE_PUBLIC_KEY, 100), (*BOB_PUBLIC_KEY, 100)];
+    let state: State<ClContext> = new_test_state(validators.iter().map(|(_pk, w)| *w), 0);
+    let panorama: Panorama<ClContext> = Panorama::from(vec![N, N]);
+    let seq_number = panorama.next_seq_num(&state, creator);
+    let timestamp = 0.into();
+    let wunit: WireUnit<ClContext> = WireUnit {
+        panorama,
+        creator,
+        instance_id: ClContext::hash(INSTANCE_ID_DATA),
+        value: Some(CandidateBlock::new(
+            ProtoBlock::new(vec![], vec![], timestamp, false),
+            vec![],
+            None,
+        )),
+        seq_number,
+        timestamp,
+        round_exp: 14,
+        endorsed: BTreeSet::new(),
+    };
+    let alice_keypair: Keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let highway_message: HighwayMessage<ClContext> = HighwayMessage::NewVertex(Vertex::Unit(
+        SignedWireUnit::new(wunit.into_hashed(), &alice_keypair),
+    ));
+    let mut highway_protocol = new_test_highway_protocol(validators, vec![]);
+    let sender = NodeId(123);
+    let msg = bincode::serialize(&highway_message).unwrap();
+
+    let mut finalized = false;
+    let mut outcomes = highway_protocol.handle_message(sender, msg);
+    while let Some(outcome) = outcomes.pop() {
+        match outcome {
+            ProtocolOutcome::CreatedGossipMessage(_) => (),
+            ProtocolOutcome::FinalizedBlock(_) => finalized = true,
+            ProtocolOutcome::QueueAction(ACTION_ID_VERTEX) => {
+                outcomes.extend(highway_protocol.handle_action(ACTION_ID_VERTEX))
+            }
+            outcome => panic!("Unexpected outcome: {:?}", outcome),
+        }
+    }
+
+    // We are not an active validator, so we don't have a round exponent.
+    assert_eq!(highway_protocol.next_round_exp(), None);
+
+    let liveness = highway_protocol.validator_liveness();
+    assert_eq!(liveness.len(), 2);
+
+    let alice = &liveness[0];
+    assert_eq!(alice.validator_id, *ALICE_PUBLIC_KEY);
+    assert_eq!(alice.units_produced, 1);
+    assert_eq!(alice.round_exponent, Some(14));
+    assert_eq!(alice.average_finalization_latency.is_some(), finalized);
+
+    // Bob hasn't created any units, so he missed every round since the start of the era, and at
+    // least as many as Alice, who created a unit in the first one.
+    let bob = &liveness[1];
+    assert_eq!(bob.validator_id, *BOB_PUBLIC_KEY);
+    assert_eq!(bob.units_produced, 0);
+    assert_eq!(bob.round_exponent, None);
+    assert_eq!(bob.average_finalization_latency, None);
+    assert!(bob.rounds_missed > alice.rounds_missed);
+}
+
 #[test]
 fn detect_doppelganger() {
     let creator: ValidatorIndex = ALICE;
@@ -269,3 +348,20 @@ fn detect_doppelganger() {
     }
     panic!("failed to return DoppelgangerDetected effect");
 }
+
+#[test]
+fn observer_should_not_activate_validator() {
+    let validators = vec![(*ALICE_PUBLIC_KEY, 100), (*BOB_PUBLIC_KEY, 100)];
+    let mut highway_protocol =
+        new_test_highway_protocol_with_observer_mode(validators, vec![], true);
+    let alice_keypair: Keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let outcomes = highway_protocol.activate_validator(
+        *ALICE_PUBLIC_KEY,
+        alice_keypair,
+        Timestamp::zero(),
+        None,
+    );
+    assert!(outcomes.is_empty());
+    assert!(!highway_protocol.is_active());
+    assert!(highway_protocol.next_round_length().is_none());
+}
@@ -14,7 +14,7 @@ use std::{
 use datasize::DataSize;
 use derive_more::From;
 use lmdb::DatabaseFlags;
-use prometheus::{self, Histogram, HistogramOpts, Registry};
+use prometheus::{self, Histogram, HistogramOpts, IntGauge, Registry};
 use serde::Serialize;
 use thiserror::Error;
 use tokio::task;
@@ -22,22 +22,25 @@ use tracing::{error, trace};
 
 use casper_execution_engine::{
     core::engine_state::{
-        genesis::GenesisResult, EngineConfig, EngineState, Error, GetEraValidatorsError,
-        GetEraValidatorsRequest,
+        genesis::GenesisResult, EngineConfig, EngineState, Error, FeeHandling,
+        GetEraValidatorsError, GetEraValidatorsRequest,
+    },
+    shared::{
+        gas::Gas,
+        newtypes::{Blake2bHash, CorrelationId},
     },
-    shared::newtypes::{Blake2bHash, CorrelationId},
     storage::{
         error::lmdb::Error as StorageLmdbError, global_state::lmdb::LmdbGlobalState,
         protocol_data_store::lmdb::LmdbProtocolDataStore,
         transaction_source::lmdb::LmdbEnvironment, trie_store::lmdb::LmdbTrieStore,
     },
 };
-use casper_types::{system::auction::ValidatorWeights, ProtocolVersion};
+use casper_types::{system::auction::ValidatorWeights, ProtocolVersion, U512};
 
 use crate::{
-    components::Component,
+    components::{Component, HealthReport},
     effect::{requests::ContractRuntimeRequest, EffectBuilder, EffectExt, Effects},
-    types::Chainspec,
+    types::{Chainspec, ComponentHealth, TimeDiff},
     utils::WithDir,
     NodeRng, StorageConfig,
 };
@@ -46,7 +49,10 @@ use crate::{
 #[derive(DataSize)]
 pub struct ContractRuntime {
     engine_state: Arc<EngineState<LmdbGlobalState>>,
+    #[data_size(skip)]
+    environment: Arc<LmdbEnvironment>,
     metrics: Arc<ContractRuntimeMetrics>,
+    execution_lag_threshold: TimeDiff,
 }
 
 impl Debug for ContractRuntime {
@@ -87,6 +93,7 @@ pub struct ContractRuntimeMetrics {
     missing_trie_keys: Histogram,
     put_trie: Histogram,
     read_trie: Histogram,
+    execute_queue_depth: IntGauge,
 }
 
 /// Value of upper bound of histogram.
@@ -130,6 +137,8 @@ const PUT_TRIE_NAME: &str = "contract_runtime_put_trie";
 const PUT_TRIE_HELP: &str = "tracking run of engine_state.put_trie in seconds.";
 const MISSING_TRIE_KEYS_NAME: &str = "contract_runtime_missing_trie_keys";
 const MISSING_TRIE_KEYS_HELP: &str = "tracking run of engine_state.missing_trie_keys in seconds.";
+const EXECUTE_QUEUE_DEPTH_NAME: &str = "contract_runtime_execute_queue_depth";
+const EXECUTE_QUEUE_DEPTH_HELP: &str = "number of execute requests which have not completed yet.";
 
 /// Create prometheus Histogram and register.
 fn register_histogram_metric(
@@ -151,6 +160,9 @@ fn register_histogram_metric(
 impl ContractRuntimeMetrics {
     /// Constructor of metrics which creates and registers metrics objects for use.
     fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let execute_queue_depth =
+            IntGauge::new(EXECUTE_QUEUE_DEPTH_NAME, EXECUTE_QUEUE_DEPTH_HELP)?;
+        registry.register(Box::new(execute_queue_depth.clone()))?;
         Ok(ContractRuntimeMetrics {
             run_execute: register_histogram_metric(registry, RUN_EXECUTE_NAME, RUN_EXECUTE_HELP)?,
             apply_effect: register_histogram_metric(
@@ -189,6 +201,7 @@ impl ContractRuntimeMetrics {
                 MISSING_TRIE_KEYS_NAME,
                 MISSING_TRIE_KEYS_HELP,
             )?,
+            execute_queue_depth,
         })
     }
 }
@@ -232,6 +245,7 @@ where
                 trace!(?execute_request, "execute");
                 let engine_state = Arc::clone(&self.engine_state);
                 let metrics = Arc::clone(&self.metrics);
+                metrics.execute_queue_depth.inc();
                 async move {
                     let correlation_id = CorrelationId::new();
                     let result = task::spawn_blocking(move || {
@@ -239,6 +253,7 @@ where
                         let execution_result =
                             engine_state.run_execute(correlation_id, *execute_request);
                         metrics.run_execute.observe(start.elapsed().as_secs_f64());
+                        metrics.execute_queue_depth.dec();
                         execution_result
                     })
                     .await
@@ -249,6 +264,7 @@ where
                 .ignore()
             }
             Event::Request(ContractRuntimeRequest::Commit {
+                protocol_version,
                 state_root_hash,
                 effects,
                 responder,
@@ -262,6 +278,7 @@ where
                         let start = Instant::now();
                         let apply_result = engine_state.apply_effect(
                             correlation_id,
+                            protocol_version,
                             state_root_hash.into(),
                             effects,
                         );
@@ -562,6 +579,17 @@ where
     }
 }
 
+impl HealthReport for ContractRuntime {
+    fn health(&self) -> ComponentHealth {
+        match self.environment.env().begin_ro_txn() {
+            Ok(_) => ComponentHealth::Healthy,
+            Err(error) => {
+                ComponentHealth::Unhealthy(format!("cannot read global state: {}", error))
+            }
+        }
+    }
+}
+
 /// Error returned from mis-configuring the contract runtime component.
 #[derive(Debug, Error)]
 pub enum ConfigError {
@@ -571,14 +599,34 @@ pub enum ConfigError {
     /// Error initializing metrics.
     #[error("failed to initialize metrics for contract runtime: {0}")]
     Prometheus(#[from] prometheus::Error),
+    /// The chainspec enables `no_fee_mode`, but the node config does not allow it.
+    #[error(
+        "chainspec enables no_fee_mode, but contract_runtime.allow_no_fee_mode is not set in the \
+        node config"
+    )]
+    NoFeeModeNotAllowed,
 }
 
 impl ContractRuntime {
     pub(crate) fn new(
         storage_config: WithDir<StorageConfig>,
         contract_runtime_config: &Config,
+        chainspec: &Chainspec,
         registry: &Registry,
     ) -> Result<Self, ConfigError> {
+        let fee_handling = if chainspec.deploy_config.no_fee_mode {
+            // Disabling fees is only permitted if the node operator has explicitly opted in, so a
+            // public chainspec can't accidentally enable it.
+            if !contract_runtime_config.allow_no_fee_mode() {
+                return Err(ConfigError::NoFeeModeNotAllowed);
+            }
+            FeeHandling::NoFee {
+                session_gas_limit: Gas::new(U512::from(chainspec.deploy_config.block_gas_limit)),
+            }
+        } else {
+            FeeHandling::PayToProposer
+        };
+
         let path = storage_config.with_dir(storage_config.value().path.clone());
         let environment = Arc::new(LmdbEnvironment::new(
             path.as_path(),
@@ -598,18 +646,28 @@ impl ContractRuntime {
             DatabaseFlags::empty(),
         )?);
 
-        let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)?;
-        let engine_config = EngineConfig::new(contract_runtime_config.max_query_depth());
+        let global_state =
+            LmdbGlobalState::empty(Arc::clone(&environment), trie_store, protocol_data_store)?;
+        let engine_config =
+            EngineConfig::new(contract_runtime_config.max_query_depth(), fee_handling);
 
         let engine_state = Arc::new(EngineState::new(global_state, engine_config));
 
         let metrics = Arc::new(ContractRuntimeMetrics::new(registry)?);
         Ok(ContractRuntime {
             engine_state,
+            environment,
             metrics,
+            execution_lag_threshold: contract_runtime_config.execution_lag_threshold(),
         })
     }
 
+    /// Returns how far execution of a block may lag behind its finalization before this is
+    /// announced.
+    pub(crate) fn execution_lag_threshold(&self) -> TimeDiff {
+        self.execution_lag_threshold
+    }
+
     /// Commits a genesis using a chainspec
     fn commit_genesis(&self, chainspec: Arc<Chainspec>) -> Result<GenesisResult, Error> {
         let correlation_id = CorrelationId::new();
@@ -641,3 +699,17 @@ impl ContractRuntime {
         }
     }
 }
+
+// Testing code. The functions below allow direct inspection of global state and should only ever be
+// used when writing tests.
+#[cfg(test)]
+impl ContractRuntime {
+    /// Runs a global state query directly against the engine state, bypassing the event queue.
+    pub(crate) fn query_global_state(
+        &self,
+        query_request: casper_execution_engine::core::engine_state::QueryRequest,
+    ) -> Result<casper_execution_engine::core::engine_state::QueryResult, Error> {
+        self.engine_state
+            .run_query(CorrelationId::new(), query_request)
+    }
+}
@@ -3,9 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use casper_execution_engine::shared::utils;
 
+use crate::types::TimeDiff;
+
 const DEFAULT_MAX_GLOBAL_STATE_SIZE: usize = 805_306_368_000; // 750 GiB
 const DEFAULT_MAX_READERS: u32 = 512;
 const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
+const DEFAULT_ALLOW_NO_FEE_MODE: bool = false;
+const DEFAULT_EXECUTION_LAG_THRESHOLD: TimeDiff = TimeDiff::from_seconds(30);
 
 /// Contract runtime configuration.
 #[derive(Clone, Copy, DataSize, Debug, Deserialize, Serialize)]
@@ -26,6 +30,14 @@ pub struct Config {
     ///
     /// Defaults to 5.
     max_query_depth: Option<u64>,
+    /// Whether this node may run a chainspec which enables `no_fee_mode`.
+    ///
+    /// Defaults to false.
+    allow_no_fee_mode: Option<bool>,
+    /// How far execution of a block may lag behind its finalization before this is announced.
+    ///
+    /// Defaults to 30 seconds.
+    execution_lag_threshold: Option<TimeDiff>,
 }
 
 impl Config {
@@ -44,6 +56,15 @@ impl Config {
     pub(crate) fn max_query_depth(&self) -> u64 {
         self.max_query_depth.unwrap_or(DEFAULT_MAX_QUERY_DEPTH)
     }
+
+    pub(crate) fn allow_no_fee_mode(&self) -> bool {
+        self.allow_no_fee_mode.unwrap_or(DEFAULT_ALLOW_NO_FEE_MODE)
+    }
+
+    pub(crate) fn execution_lag_threshold(&self) -> TimeDiff {
+        self.execution_lag_threshold
+            .unwrap_or(DEFAULT_EXECUTION_LAG_THRESHOLD)
+    }
 }
 
 impl Default for Config {
@@ -52,6 +73,8 @@ impl Default for Config {
             max_global_state_size: Some(DEFAULT_MAX_GLOBAL_STATE_SIZE),
             max_readers: Some(DEFAULT_MAX_READERS),
             max_query_depth: Some(DEFAULT_MAX_QUERY_DEPTH),
+            allow_no_fee_mode: Some(DEFAULT_ALLOW_NO_FEE_MODE),
+            execution_lag_threshold: Some(DEFAULT_EXECUTION_LAG_THRESHOLD),
         }
     }
 }
@@ -14,7 +14,7 @@ use std::{
     collections::HashSet,
     convert::Infallible,
     fmt::{self, Debug, Formatter},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tracing::{debug, error, warn};
 
@@ -22,7 +22,7 @@ use crate::{
     components::Component,
     effect::{
         announcements::GossiperAnnouncement,
-        requests::{NetworkRequest, StorageRequest},
+        requests::{NetworkInfoRequest, NetworkRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
     protocol::Message as NodeMessage,
@@ -37,12 +37,17 @@ use gossip_table::{GossipAction, GossipTable};
 pub use message::Message;
 use metrics::GossiperMetrics;
 
+/// The minimum interval between two requests for the number of connected peers, used to adapt the
+/// infection target.
+const PEER_COUNT_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
 /// A helper trait whose bounds represent the requirements for a reactor event that `Gossiper` can
 /// work with.
 pub trait ReactorEventT<T>:
     From<Event<T>>
     + From<NetworkRequest<NodeId, Message<T>>>
     + From<NetworkRequest<NodeId, NodeMessage>>
+    + From<NetworkInfoRequest<NodeId>>
     + From<StorageRequest>
     + From<GossiperAnnouncement<T>>
     + Send
@@ -60,6 +65,7 @@ where
     REv: From<Event<T>>
         + From<NetworkRequest<NodeId, Message<T>>>
         + From<NetworkRequest<NodeId, NodeMessage>>
+        + From<NetworkInfoRequest<NodeId>>
         + From<StorageRequest>
         + From<GossiperAnnouncement<T>>
         + Send
@@ -102,6 +108,10 @@ where
     REv: ReactorEventT<T>,
 {
     table: GossipTable<T::Id>,
+    config: Config,
+    /// When we last requested the number of connected peers, if ever.
+    #[data_size(skip)]
+    last_peer_count_request: Option<Instant>,
     gossip_timeout: Duration,
     get_from_peer_timeout: Duration,
     #[data_size(skip)] // Not well supported by datasize.
@@ -136,12 +146,18 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
             !T::ID_IS_COMPLETE_ITEM,
             "this should only be called for types where T::ID_IS_COMPLETE_ITEM is false"
         );
+        let metrics = GossiperMetrics::new(name, registry)?;
+        metrics
+            .infection_target
+            .set(i64::from(config.infection_target()));
         Ok(Gossiper {
             table: GossipTable::new(config),
+            config,
+            last_peer_count_request: None,
             gossip_timeout: Duration::from_secs(config.gossip_request_timeout_secs()),
             get_from_peer_timeout: Duration::from_secs(config.get_remainder_timeout_secs()),
             get_from_holder: Box::new(get_from_holder),
-            metrics: GossiperMetrics::new(name, registry)?,
+            metrics,
         })
     }
 
@@ -159,17 +175,67 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
             T::ID_IS_COMPLETE_ITEM,
             "this should only be called for types where T::ID_IS_COMPLETE_ITEM is true"
         );
+        let metrics = GossiperMetrics::new(name, registry)?;
+        metrics
+            .infection_target
+            .set(i64::from(config.infection_target()));
         Ok(Gossiper {
             table: GossipTable::new(config),
+            config,
+            last_peer_count_request: None,
             gossip_timeout: Duration::from_secs(config.gossip_request_timeout_secs()),
             get_from_peer_timeout: Duration::from_secs(config.get_remainder_timeout_secs()),
             get_from_holder: Box::new(|_, item, _| {
                 panic!("gossiper should never try to get {}", item)
             }),
-            metrics: GossiperMetrics::new(name, registry)?,
+            metrics,
         })
     }
 
+    /// Returns the number of peers each item is currently gossiped to.
+    pub(crate) fn infection_target(&self) -> usize {
+        self.table.infection_target()
+    }
+
+    /// Requests the number of connected peers if the infection target adapts to it and it hasn't
+    /// been requested within `PEER_COUNT_REFRESH_INTERVAL`.
+    fn maybe_request_peer_count(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Effects<Event<T>> {
+        if !self.config.is_infection_target_adaptive() {
+            return Effects::new();
+        }
+        let now = Instant::now();
+        if let Some(last_request) = self.last_peer_count_request {
+            if now.duration_since(last_request) < PEER_COUNT_REFRESH_INTERVAL {
+                return Effects::new();
+            }
+        }
+        self.last_peer_count_request = Some(now);
+        effect_builder
+            .network_peers::<NodeId>()
+            .event(|peers| Event::GotPeerCount {
+                peer_count: peers.len(),
+            })
+    }
+
+    /// Adapts the infection target to the number of connected peers.
+    fn got_peer_count(&mut self, peer_count: usize) -> Effects<Event<T>> {
+        let infection_target = usize::from(self.config.effective_infection_target(peer_count));
+        if infection_target != self.table.infection_target() {
+            debug!(
+                %peer_count,
+                old = %self.table.infection_target(),
+                new = %infection_target,
+                "adapting gossip infection target"
+            );
+            self.table.set_infection_target(infection_target);
+            self.metrics.infection_target.set(infection_target as i64);
+        }
+        Effects::new()
+    }
+
     /// Handles a new item received from a peer or client for which we should begin gossiping.
     ///
     /// Note that this doesn't include items gossiped to us; those are handled in `handle_gossip()`.
@@ -193,6 +259,29 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
         }
     }
 
+    /// Handles a request to gossip an item we hold again, e.g. because it appears not to have
+    /// reached the peers it should have.
+    ///
+    /// Items currently being gossiped are not gossiped again.
+    fn handle_rebroadcast_item(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        item_id: T::Id,
+    ) -> Effects<Event<T>> {
+        debug!(item=%item_id, "rebroadcasting gossip item");
+        if let Some(should_gossip) = self.table.rebroadcast(&item_id) {
+            self.metrics.items_rebroadcast.inc();
+            self.gossip(
+                effect_builder,
+                item_id,
+                should_gossip.count,
+                should_gossip.exclude_peers,
+            )
+        } else {
+            Effects::new()
+        }
+    }
+
     /// Gossips the given item ID to `count` random peers excluding the indicated ones.
     fn gossip(
         &mut self,
@@ -341,6 +430,9 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
         match action {
             GossipAction::ShouldGossip(should_gossip) => {
                 self.metrics.items_received.inc();
+                if should_gossip.is_already_held {
+                    self.metrics.duplicate_items_received.inc();
+                }
                 // Gossip the item ID.
                 let mut effects = self.gossip(
                     effect_builder,
@@ -354,7 +446,7 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
                     debug!(item=%item_id, "announcing new complete gossip item received");
                     effects.extend(
                         effect_builder
-                            .announce_complete_item_received_via_gossip(item_id)
+                            .announce_complete_item_received_via_gossip(item_id, sender.clone())
                             .ignore(),
                     );
                 }
@@ -387,6 +479,12 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
                 effects
             }
             GossipAction::Noop | GossipAction::AwaitingRemainder => {
+                self.metrics.duplicate_items_received.inc();
+                if !T::ID_IS_COMPLETE_ITEM {
+                    // We already hold or are already getting the full item, so we avoid fetching it
+                    // again from the sender.
+                    self.metrics.item_transfers_avoided.inc();
+                }
                 // Send a response to the sender indicating we already hold the item.
                 let reply = Message::GossipResponse {
                     item_id,
@@ -407,6 +505,10 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
     ) -> Effects<Event<T>> {
         let mut effects: Effects<_> = Effects::new();
         let action = if is_already_held {
+            if !T::ID_IS_COMPLETE_ITEM {
+                // `sender` already holds the full item, so we avoid sending it.
+                self.metrics.item_transfers_avoided.inc();
+            }
             self.table.already_infected(&item_id, sender)
         } else {
             if !T::ID_IS_COMPLETE_ITEM {
@@ -452,7 +554,18 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Gossiper<T, REv> {
         requester: NodeId,
     ) -> Effects<Event<T>> {
         match NodeMessage::new_get_response(&item) {
-            Ok(message) => effect_builder.send_message(requester, message).ignore(),
+            Ok(message) => {
+                if let NodeMessage::GetResponse {
+                    serialized_item, ..
+                } = &message
+                {
+                    self.metrics.items_sent.inc();
+                    self.metrics
+                        .item_bytes_sent
+                        .inc_by(serialized_item.len() as i64);
+                }
+                effect_builder.send_message(requester, message).ignore()
+            }
             Err(error) => {
                 error!("failed to create get-response: {}", error);
                 Effects::new()
@@ -499,10 +612,13 @@ where
         _rng: &mut NodeRng,
         event: Self::Event,
     ) -> Effects<Self::Event> {
-        let effects = match event {
+        let mut effects = match event {
             Event::ItemReceived { item_id, source } => {
                 self.handle_item_received(effect_builder, item_id, source)
             }
+            Event::RebroadcastItem { item_id } => {
+                self.handle_rebroadcast_item(effect_builder, item_id)
+            }
             Event::GossipedTo {
                 item_id,
                 requested_count,
@@ -529,7 +645,9 @@ where
                 Ok(item) => self.got_from_holder(effect_builder, item, requester),
                 Err(error) => self.failed_to_get_from_holder(item_id, error),
             },
+            Event::GotPeerCount { peer_count } => self.got_peer_count(peer_count),
         };
+        effects.extend(self.maybe_request_peer_count(effect_builder));
         self.update_gossip_table_metrics();
         effects
     }
@@ -540,6 +658,7 @@ impl<T: Item + 'static, REv: ReactorEventT<T>> Debug for Gossiper<T, REv> {
         formatter
             .debug_struct("Gossiper")
             .field("table", &self.table)
+            .field("config", &self.config)
             .field("gossip_timeout", &self.gossip_timeout)
             .field("get_from_peer_timeout", &self.get_from_peer_timeout)
             .finish()
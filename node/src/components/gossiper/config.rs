@@ -9,6 +9,7 @@ use tracing::error;
 use super::Error;
 
 const DEFAULT_INFECTION_TARGET: u8 = 3;
+const DEFAULT_MAX_INFECTION_TARGET: u8 = 3;
 const DEFAULT_SATURATION_LIMIT_PERCENT: u8 = 80;
 pub(super) const MAX_SATURATION_LIMIT_PERCENT: u8 = 99;
 pub(super) const DEFAULT_FINISHED_ENTRY_DURATION_SECS: u64 = 60;
@@ -25,7 +26,16 @@ const SMALL_TIMEOUTS_GET_REMAINDER_TIMEOUT_SECS: u64 = 1;
 #[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
 pub struct Config {
     /// Target number of peers to infect with a given piece of data.
+    ///
+    /// This is the lower bound of the effective target, which grows with the logarithm of the
+    /// number of connected peers up to `max_infection_target`.
     infection_target: u8,
+    /// Upper bound of the effective number of peers to infect with a given piece of data.
+    ///
+    /// Setting this to the value of `infection_target` or lower disables adapting the target to
+    /// the number of connected peers, which is the default.
+    #[serde(default = "default_max_infection_target")]
+    max_infection_target: u8,
     /// The saturation limit as a percentage, with a maximum value of 99.  Used as a termination
     /// condition.
     ///
@@ -62,6 +72,7 @@ impl Config {
         }
         Ok(Config {
             infection_target,
+            max_infection_target: DEFAULT_MAX_INFECTION_TARGET,
             saturation_limit_percent,
             finished_entry_duration_secs,
             gossip_request_timeout_secs,
@@ -83,6 +94,26 @@ impl Config {
         self.infection_target
     }
 
+    /// Returns whether the infection target adapts to the number of connected peers.
+    pub(crate) fn is_infection_target_adaptive(&self) -> bool {
+        self.max_infection_target > self.infection_target
+    }
+
+    /// Returns the number of peers to infect given the number of currently connected peers.
+    ///
+    /// This is `log2(peer_count)` rounded up, bounded below by `infection_target` and above by
+    /// `max_infection_target`.
+    pub(crate) fn effective_infection_target(&self, peer_count: usize) -> u8 {
+        let log_peer_count = if peer_count <= 1 {
+            0
+        } else {
+            (peer_count as f64).log2().ceil() as u8
+        };
+        log_peer_count
+            .min(self.max_infection_target)
+            .max(self.infection_target)
+    }
+
     pub(crate) fn saturation_limit_percent(&self) -> u8 {
         self.saturation_limit_percent
     }
@@ -104,6 +135,7 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             infection_target: DEFAULT_INFECTION_TARGET,
+            max_infection_target: DEFAULT_MAX_INFECTION_TARGET,
             saturation_limit_percent: DEFAULT_SATURATION_LIMIT_PERCENT,
             finished_entry_duration_secs: DEFAULT_FINISHED_ENTRY_DURATION_SECS,
             gossip_request_timeout_secs: DEFAULT_GOSSIP_REQUEST_TIMEOUT_SECS,
@@ -112,6 +144,11 @@ impl Default for Config {
     }
 }
 
+/// Default upper bound of the effective infection target.
+fn default_max_infection_target() -> u8 {
+    DEFAULT_MAX_INFECTION_TARGET
+}
+
 /// Deserializes a `usize` but fails if it's not in the range 0..100.
 fn deserialize_saturation_limit_percent<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
@@ -141,6 +178,7 @@ mod tests {
         // saturation_limit_percent > MAX_SATURATION_LIMIT_PERCENT
         let invalid_config = Config {
             infection_target: 3,
+            max_infection_target: DEFAULT_MAX_INFECTION_TARGET,
             saturation_limit_percent: MAX_SATURATION_LIMIT_PERCENT + 1,
             finished_entry_duration_secs: DEFAULT_FINISHED_ENTRY_DURATION_SECS,
             gossip_request_timeout_secs: DEFAULT_GOSSIP_REQUEST_TIMEOUT_SECS,
@@ -161,4 +199,34 @@ mod tests {
         )
         .is_err())
     }
+
+    #[test]
+    fn effective_infection_target_should_grow_with_peer_count_within_bounds() {
+        let config = Config {
+            max_infection_target: 10,
+            ..Default::default()
+        };
+        assert!(config.is_infection_target_adaptive());
+        assert_eq!(
+            config.effective_infection_target(0),
+            DEFAULT_INFECTION_TARGET
+        );
+        assert_eq!(
+            config.effective_infection_target(5),
+            DEFAULT_INFECTION_TARGET
+        );
+        assert_eq!(config.effective_infection_target(9), 4);
+        assert_eq!(config.effective_infection_target(100), 7);
+        assert_eq!(config.effective_infection_target(1_000_000), 10);
+    }
+
+    #[test]
+    fn infection_target_should_be_fixed_by_default() {
+        let config = Config::default();
+        assert!(!config.is_infection_target_adaptive());
+        assert_eq!(
+            config.effective_infection_target(1_000_000),
+            DEFAULT_INFECTION_TARGET
+        );
+    }
 }
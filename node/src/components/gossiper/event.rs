@@ -19,6 +19,8 @@ pub enum Event<T: Item> {
         item_id: T::Id,
         source: Source<NodeId>,
     },
+    /// An item we hold should be gossiped again, even if gossiping it has already finished.
+    RebroadcastItem { item_id: T::Id },
     /// The network component gossiped to the included peers.
     GossipedTo {
         item_id: T::Id,
@@ -40,6 +42,8 @@ pub enum Event<T: Item> {
         requester: NodeId,
         result: Box<Result<T, String>>,
     },
+    /// The number of connected peers, used to adapt the infection target.
+    GotPeerCount { peer_count: usize },
 }
 
 impl<T: Item> Display for Event<T> {
@@ -48,6 +52,9 @@ impl<T: Item> Display for Event<T> {
             Event::ItemReceived { item_id, source } => {
                 write!(formatter, "new item {} received from {}", item_id, source)
             }
+            Event::RebroadcastItem { item_id } => {
+                write!(formatter, "rebroadcast item {}", item_id)
+            }
             Event::GossipedTo { item_id, peers, .. } => write!(
                 formatter,
                 "gossiped {} to {}",
@@ -76,6 +83,9 @@ impl<T: Item> Display for Event<T> {
                     write!(formatter, "failed to get {} from holder component", item_id)
                 }
             }
+            Event::GotPeerCount { peer_count } => {
+                write!(formatter, "got peer count of {}", peer_count)
+            }
         }
     }
 }
@@ -184,8 +184,11 @@ pub(crate) struct GossipTable<T> {
     paused: HashMap<T, State>,
     /// Timeouts for removal of items from the `paused` cache.
     paused_timeouts: Timeouts<T>,
-    /// See `Config::infection_target`.
+    /// See `Config::infection_target`.  Adjusted via `set_infection_target` as the number of
+    /// connected peers changes.
     infection_target: usize,
+    /// See `Config::saturation_limit_percent`.
+    saturation_limit_percent: usize,
     /// Derived from `Config::saturation_limit_percent` - we gossip data while the number of
     /// holders doesn't exceed `holders_limit`.
     holders_limit: usize,
@@ -208,21 +211,42 @@ impl<T> GossipTable<T> {
     pub fn items_paused(&self) -> usize {
         self.paused.len()
     }
+
+    /// The current number of peers to infect with a given piece of data.
+    pub fn infection_target(&self) -> usize {
+        self.infection_target
+    }
+
+    /// Sets the number of peers to infect with a given piece of data, and updates the holders limit
+    /// accordingly.
+    ///
+    /// Entries already being gossiped are judged against the new values from their next action on.
+    pub(crate) fn set_infection_target(&mut self, infection_target: usize) {
+        self.infection_target = infection_target;
+        self.holders_limit = holders_limit(infection_target, self.saturation_limit_percent);
+    }
+}
+
+/// Returns the number of holders above which we stop gossiping, i.e. the number implying
+/// `saturation_limit_percent` if we failed to newly infect `infection_target` peers.
+fn holders_limit(infection_target: usize, saturation_limit_percent: usize) -> usize {
+    (100 * infection_target) / (100 - saturation_limit_percent)
 }
 
 impl<T: Copy + Eq + Hash + Display> GossipTable<T> {
     /// Returns a new `GossipTable` using the provided configuration.
     pub(crate) fn new(config: Config) -> Self {
-        let holders_limit = (100 * usize::from(config.infection_target()))
-            / (100 - usize::from(config.saturation_limit_percent()));
+        let infection_target = usize::from(config.infection_target());
+        let saturation_limit_percent = usize::from(config.saturation_limit_percent());
         GossipTable {
             current: HashMap::new(),
             finished: HashSet::new(),
             finished_timeouts: Timeouts::new(),
             paused: HashMap::new(),
             paused_timeouts: Timeouts::new(),
-            infection_target: usize::from(config.infection_target()),
-            holders_limit,
+            infection_target,
+            saturation_limit_percent,
+            holders_limit: holders_limit(infection_target, saturation_limit_percent),
             finished_entry_duration: Duration::from_secs(config.finished_entry_duration_secs()),
         }
     }
@@ -321,6 +345,38 @@ impl<T: Copy + Eq + Hash + Display> GossipTable<T> {
         convert_action(action)
     }
 
+    /// We hold data which should be gossiped again from scratch, e.g. because it appears not to
+    /// have reached the peers it should have.
+    ///
+    /// Data currently being gossiped is left alone.  Otherwise any finished or paused entry is
+    /// discarded and gossiping restarts as if the data were new.
+    ///
+    /// Returns whether we should gossip it, and a list of peers to exclude.
+    pub(crate) fn rebroadcast(&mut self, data_id: &T) -> Option<ShouldGossip> {
+        self.purge_finished();
+
+        if self.current.contains_key(data_id) {
+            debug!(item=%data_id, "no further action: item currently being gossiped");
+            return None;
+        }
+
+        let _ = self.finished.remove(data_id);
+        let _ = self.paused.remove(data_id);
+
+        let mut state = State {
+            held_by_us: true,
+            ..State::default()
+        };
+        let is_new = false;
+        let action = state.action(self.infection_target, self.holders_limit, is_new);
+        let _ = self.current.insert(*data_id, state);
+        debug!(item=%data_id, %action, "gossiping item should restart");
+        match action {
+            GossipAction::ShouldGossip(should_gossip) => Some(should_gossip),
+            _ => None,
+        }
+    }
+
     /// We got a response from a peer we gossiped to indicating we infected it (it didn't previously
     /// know of this data).
     ///
@@ -1030,6 +1086,41 @@ mod tests {
         assert!(gossip_table.paused.contains_key(&data_id));
     }
 
+    #[test]
+    fn should_restart_gossiping_on_rebroadcast() {
+        let mut rng = crate::new_rng();
+        let node_ids = random_node_ids(&mut rng);
+        let data_id: u64 = rng.gen();
+
+        let mut gossip_table = GossipTable::new(Config::default());
+
+        // Rebroadcasting data currently being gossiped has no effect.
+        let _ = gossip_table.new_complete_data(&data_id, None);
+        assert!(gossip_table.rebroadcast(&data_id).is_none());
+
+        // Finish via infection limit, after which new complete data is ignored but a rebroadcast
+        // gossips it again to peers which may already hold it.
+        for node_id in &node_ids[0..EXPECTED_DEFAULT_INFECTION_TARGET] {
+            let _ = gossip_table.we_infected(&data_id, node_id.clone());
+        }
+        assert!(gossip_table.finished.contains(&data_id));
+        assert!(gossip_table.new_complete_data(&data_id, None).is_none());
+
+        let expected = ShouldGossip {
+            count: EXPECTED_DEFAULT_INFECTION_TARGET,
+            exclude_peers: HashSet::new(),
+            is_already_held: true,
+        };
+        assert_eq!(Some(expected), gossip_table.rebroadcast(&data_id));
+        assert!(!gossip_table.finished.contains(&data_id));
+        assert!(gossip_table.rebroadcast(&data_id).is_none());
+
+        // Paused data is gossiped again too.
+        gossip_table.pause(&data_id);
+        assert!(gossip_table.rebroadcast(&data_id).is_some());
+        assert!(!gossip_table.paused.contains_key(&data_id));
+    }
+
     #[test]
     fn should_purge() {
         let mut rng = crate::new_rng();
@@ -1061,6 +1152,41 @@ mod tests {
         assert!(!gossip_table.paused.contains_key(&data_id));
     }
 
+    #[test]
+    fn should_apply_updated_infection_target() {
+        const INFECTION_TARGET: usize = 5;
+
+        let mut rng = crate::new_rng();
+        let node_ids = random_node_ids(&mut rng);
+        let data_id: u64 = rng.gen();
+
+        let mut gossip_table = GossipTable::new(Config::default());
+        gossip_table.set_infection_target(INFECTION_TARGET);
+        assert_eq!(INFECTION_TARGET, gossip_table.infection_target());
+        assert_eq!(25, gossip_table.holders_limit);
+
+        // Check new complete data is gossiped to the updated number of peers.
+        let action = gossip_table.new_complete_data(&data_id, None);
+        let expected = Some(ShouldGossip {
+            count: INFECTION_TARGET,
+            exclude_peers: HashSet::new(),
+            is_already_held: false,
+        });
+        assert_eq!(expected, action);
+
+        // Check gossiping doesn't finish after infecting the default number of peers.
+        for node_id in &node_ids[0..EXPECTED_DEFAULT_INFECTION_TARGET] {
+            let _ = gossip_table.we_infected(&data_id, node_id.clone());
+        }
+        assert!(!gossip_table.finished.contains(&data_id));
+
+        // Check it finishes after infecting the updated number of peers.
+        for node_id in &node_ids[EXPECTED_DEFAULT_INFECTION_TARGET..INFECTION_TARGET] {
+            let _ = gossip_table.we_infected(&data_id, node_id.clone());
+        }
+        assert!(gossip_table.finished.contains(&data_id));
+    }
+
     #[bench]
     fn benchmark_purging(bencher: &mut Bencher) {
         const ENTRY_COUNT: usize = 10_000;
@@ -11,6 +11,19 @@ pub struct GossiperMetrics {
     pub(super) times_gossiped: IntCounter,
     /// Number of times the process had to pause due to running out of peers.
     pub(super) times_ran_out_of_peers: IntCounter,
+    /// Total number of full items sent to peers which requested them.
+    pub(super) items_sent: IntCounter,
+    /// Total number of bytes of serialized full items sent to peers.
+    pub(super) item_bytes_sent: IntCounter,
+    /// Total number of full item transfers avoided by gossiping only the item ID, since the
+    /// receiving peer already held or was already fetching the item.
+    pub(super) item_transfers_avoided: IntCounter,
+    /// Total number of items gossiped again from scratch on request.
+    pub(super) items_rebroadcast: IntCounter,
+    /// Total number of gossip requests received for items already held or already being fetched.
+    pub(super) duplicate_items_received: IntCounter,
+    /// Current number of peers each item is gossiped to.
+    pub(super) infection_target: IntGauge,
     /// Number of items in the gossip table that are paused.
     pub(super) table_items_paused: IntGauge,
     /// Number of items in the gossip table that are currently being gossiped.
@@ -39,6 +52,43 @@ impl GossiperMetrics {
                 name
             ),
         )?;
+        let items_sent = IntCounter::new(
+            format!("{}_items_sent", name),
+            format!("number of full items sent to peers by the {}", name),
+        )?;
+        let item_bytes_sent = IntCounter::new(
+            format!("{}_item_bytes_sent", name),
+            format!(
+                "number of bytes of serialized full items sent to peers by the {}",
+                name
+            ),
+        )?;
+        let item_transfers_avoided = IntCounter::new(
+            format!("{}_item_transfers_avoided", name),
+            format!(
+                "number of full item transfers the {} avoided since the peer already held the item",
+                name
+            ),
+        )?;
+        let items_rebroadcast = IntCounter::new(
+            format!("{}_items_rebroadcast", name),
+            format!("number of items the {} was asked to gossip again", name),
+        )?;
+        let duplicate_items_received = IntCounter::new(
+            format!("{}_duplicate_items_received", name),
+            format!(
+                "number of gossip requests received by the {} for items it already held or was \
+                already fetching",
+                name
+            ),
+        )?;
+        let infection_target = IntGauge::new(
+            format!("{}_infection_target", name),
+            format!(
+                "current number of peers each item is gossiped to by the {}",
+                name
+            ),
+        )?;
         let table_items_paused = IntGauge::new(
             format!("{}_table_items_paused", name),
             format!(
@@ -64,6 +114,12 @@ impl GossiperMetrics {
         registry.register(Box::new(items_received.clone()))?;
         registry.register(Box::new(times_gossiped.clone()))?;
         registry.register(Box::new(times_ran_out_of_peers.clone()))?;
+        registry.register(Box::new(items_sent.clone()))?;
+        registry.register(Box::new(item_bytes_sent.clone()))?;
+        registry.register(Box::new(item_transfers_avoided.clone()))?;
+        registry.register(Box::new(items_rebroadcast.clone()))?;
+        registry.register(Box::new(duplicate_items_received.clone()))?;
+        registry.register(Box::new(infection_target.clone()))?;
         registry.register(Box::new(table_items_paused.clone()))?;
         registry.register(Box::new(table_items_current.clone()))?;
         registry.register(Box::new(table_items_finished.clone()))?;
@@ -72,6 +128,12 @@ impl GossiperMetrics {
             items_received,
             times_gossiped,
             times_ran_out_of_peers,
+            items_sent,
+            item_bytes_sent,
+            item_transfers_avoided,
+            items_rebroadcast,
+            duplicate_items_received,
+            infection_target,
             table_items_paused,
             table_items_current,
             table_items_finished,
@@ -85,6 +147,12 @@ impl Drop for GossiperMetrics {
         unregister_metric!(self.registry, self.items_received);
         unregister_metric!(self.registry, self.times_gossiped);
         unregister_metric!(self.registry, self.times_ran_out_of_peers);
+        unregister_metric!(self.registry, self.items_sent);
+        unregister_metric!(self.registry, self.item_bytes_sent);
+        unregister_metric!(self.registry, self.item_transfers_avoided);
+        unregister_metric!(self.registry, self.items_rebroadcast);
+        unregister_metric!(self.registry, self.duplicate_items_received);
+        unregister_metric!(self.registry, self.infection_target);
         unregister_metric!(self.registry, self.table_items_paused);
         unregister_metric!(self.registry, self.table_items_current);
         unregister_metric!(self.registry, self.table_items_finished);
@@ -1,6 +1,6 @@
 #![cfg(test)]
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::{self, Debug, Display, Formatter},
     iter,
 };
@@ -29,7 +29,7 @@ use crate::{
             ControlAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
             NetworkAnnouncement, RpcServerAnnouncement,
         },
-        requests::ContractRuntimeRequest,
+        requests::{ContractRuntimeRequest, NetworkInfoRequest},
         Responder,
     },
     protocol::Message as NodeMessage,
@@ -58,6 +58,8 @@ enum Event {
     #[from]
     NetworkRequest(NetworkRequest<NodeId, NodeMessage>),
     #[from]
+    NetworkInfoRequest(#[serde(skip_serializing)] NetworkInfoRequest<NodeId>),
+    #[from]
     ControlAnnouncement(ControlAnnouncement),
     #[from]
     NetworkAnnouncement(#[serde(skip_serializing)] NetworkAnnouncement<NodeId, NodeMessage>),
@@ -107,6 +109,7 @@ impl Display for Event {
             Event::DeployAcceptor(event) => write!(formatter, "deploy acceptor: {}", event),
             Event::DeployGossiper(event) => write!(formatter, "deploy gossiper: {}", event),
             Event::NetworkRequest(req) => write!(formatter, "network request: {}", req),
+            Event::NetworkInfoRequest(req) => write!(formatter, "network info request: {}", req),
             Event::ControlAnnouncement(ctrl_ann) => write!(formatter, "control: {}", ctrl_ann),
             Event::NetworkAnnouncement(ann) => write!(formatter, "network announcement: {}", ann),
             Event::RpcServerAnnouncement(ann) => {
@@ -164,14 +167,17 @@ impl reactor::Reactor for Reactor {
         let storage_withdir = WithDir::new(storage_tempdir.path(), storage_config);
         let storage = Storage::new(&storage_withdir, None, Version::new(1, 0, 0)).unwrap();
 
+        let chainspec = Chainspec::from_resources("local");
         let contract_runtime_config = contract_runtime::Config::default();
-        let contract_runtime =
-            ContractRuntime::new(storage_withdir, &contract_runtime_config, &registry).unwrap();
+        let contract_runtime = ContractRuntime::new(
+            storage_withdir,
+            &contract_runtime_config,
+            &chainspec,
+            &registry,
+        )
+        .unwrap();
 
-        let deploy_acceptor = DeployAcceptor::new(
-            deploy_acceptor::Config::new(false),
-            &Chainspec::from_resources("local"),
-        );
+        let deploy_acceptor = DeployAcceptor::new(deploy_acceptor::Config::new(false), &chainspec);
         let deploy_gossiper = Gossiper::new_for_partial_items(
             "deploy_gossiper",
             config,
@@ -219,6 +225,14 @@ impl reactor::Reactor for Reactor {
                 self.network
                     .handle_event(effect_builder, rng, request.into()),
             ),
+            Event::NetworkInfoRequest(NetworkInfoRequest::GetPeers { responder }) => {
+                // The in-memory network doesn't track connected peers, so the gossiper keeps its
+                // configured minimum infection target.
+                responder.respond(BTreeMap::new()).ignore()
+            }
+            Event::NetworkInfoRequest(NetworkInfoRequest::GetClockDrift { responder }) => {
+                responder.respond(None).ignore()
+            }
             Event::ControlAnnouncement(ctrl_ann) => {
                 unreachable!("unhandled control announcement: {}", ctrl_ann)
             }
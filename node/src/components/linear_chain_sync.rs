@@ -121,12 +121,7 @@ impl<I: Clone + PartialEq + 'static> LinearChainSync<I> {
             Ok((linear_chain_sync, timeout_event))
         } else {
             let acceptable_drift = chainspec.highway_config.max_round_length();
-            // Shortest era is the maximum of the two.
-            let shortest_era: TimeDiff = std::cmp::max(
-                chainspec.highway_config.min_round_length()
-                    * chainspec.core_config.minimum_era_height,
-                chainspec.core_config.era_duration,
-            );
+            let shortest_era = shortest_era(chainspec);
             let state = match init_hash {
                 Some(init_hash) => State::sync_trusted_hash(
                     init_hash,
@@ -162,11 +157,7 @@ impl<I: Clone + PartialEq + 'static> LinearChainSync<I> {
         let state_key = create_state_key(chainspec);
         info!(?state, "reusing previous state");
         let acceptable_drift = chainspec.highway_config.max_round_length();
-        // Shortest era is the maximum of the two.
-        let shortest_era: TimeDiff = std::cmp::max(
-            chainspec.highway_config.min_round_length() * chainspec.core_config.minimum_era_height,
-            chainspec.core_config.era_duration,
-        );
+        let shortest_era = shortest_era(chainspec);
         Ok(LinearChainSync {
             peers: PeersState::new(),
             state,
@@ -904,6 +895,14 @@ where
         )
 }
 
+/// Returns the minimum length of an era, given the chainspec's era end conditions.
+fn shortest_era(chainspec: &Chainspec) -> TimeDiff {
+    chainspec.core_config.era_end_policy.min_era_length(
+        chainspec.highway_config.min_round_length() * chainspec.core_config.minimum_era_height,
+        chainspec.core_config.era_duration,
+    )
+}
+
 /// Returns key in the database, under which the LinearChainSync's state is stored.
 fn create_state_key(chainspec: &Chainspec) -> Vec<u8> {
     format!(
@@ -1006,6 +1006,8 @@ impl<REv: ReactorEventT<P>, P: PayloadT> Component<REv> for Network<REv, P> {
                         .collect();
                     responder.respond(peers).ignore()
                 }
+                // Handshakes don't carry timestamps on this network, so drift is never estimated.
+                NetworkInfoRequest::GetClockDrift { responder } => responder.respond(None).ignore(),
             },
         }
     }
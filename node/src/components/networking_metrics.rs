@@ -14,6 +14,16 @@ pub(super) struct NetworkingMetrics {
     pub(super) queued_messages: IntGauge,
     /// Number of connected peers.
     pub(super) peers: IntGauge,
+    /// Total size of outgoing frames before compression.
+    pub(super) bytes_before_compression: IntCounter,
+    /// Total size of outgoing frames after compression.
+    pub(super) bytes_after_compression: IntCounter,
+    /// Total size of incoming frames after decompression.
+    pub(super) bytes_decompressed: IntCounter,
+    /// Number of incoming frames rejected for exceeding the maximum frame length.
+    pub(super) oversized_frames: IntCounter,
+    /// Estimated drift of our clock relative to our peers, in milliseconds.
+    pub(super) clock_drift_millis: IntGauge,
 
     // Potentially temporary metrics, not supported by all networking components:
     /// Number of do-nothing futures that have not finished executing for read requests.
@@ -45,6 +55,27 @@ impl NetworkingMetrics {
             "number of messages waiting to be sent out",
         )?;
         let peers = IntGauge::new("peers", "Number of connected peers.")?;
+        let bytes_before_compression = IntCounter::new(
+            "net_bytes_before_compression",
+            "total size of compressed outgoing frames before compression",
+        )?;
+        let bytes_after_compression = IntCounter::new(
+            "net_bytes_after_compression",
+            "total size of compressed outgoing frames after compression",
+        )?;
+        let bytes_decompressed = IntCounter::new(
+            "net_bytes_decompressed",
+            "total size of compressed incoming frames after decompression",
+        )?;
+        let oversized_frames = IntCounter::new(
+            "net_oversized_frames",
+            "number of incoming frames rejected for exceeding the maximum frame length",
+        )?;
+
+        let clock_drift_millis = IntGauge::new(
+            "net_clock_drift_millis",
+            "estimated drift of our clock relative to our peers in milliseconds, positive if ahead",
+        )?;
 
         let read_futures_in_flight = prometheus::Gauge::new(
             "owm_read_futures_in_flight",
@@ -68,6 +99,11 @@ impl NetworkingMetrics {
         registry.register(Box::new(open_connections.clone()))?;
         registry.register(Box::new(queued_messages.clone()))?;
         registry.register(Box::new(peers.clone()))?;
+        registry.register(Box::new(bytes_before_compression.clone()))?;
+        registry.register(Box::new(bytes_after_compression.clone()))?;
+        registry.register(Box::new(bytes_decompressed.clone()))?;
+        registry.register(Box::new(oversized_frames.clone()))?;
+        registry.register(Box::new(clock_drift_millis.clone()))?;
 
         registry.register(Box::new(read_futures_in_flight.clone()))?;
         registry.register(Box::new(read_futures_total.clone()))?;
@@ -80,6 +116,11 @@ impl NetworkingMetrics {
             open_connections,
             queued_messages,
             peers,
+            bytes_before_compression,
+            bytes_after_compression,
+            bytes_decompressed,
+            oversized_frames,
+            clock_drift_millis,
             read_futures_in_flight,
             read_futures_total,
             write_futures_in_flight,
@@ -96,6 +137,11 @@ impl Drop for NetworkingMetrics {
         unregister_metric!(self.registry, self.open_connections);
         unregister_metric!(self.registry, self.queued_messages);
         unregister_metric!(self.registry, self.peers);
+        unregister_metric!(self.registry, self.bytes_before_compression);
+        unregister_metric!(self.registry, self.bytes_after_compression);
+        unregister_metric!(self.registry, self.bytes_decompressed);
+        unregister_metric!(self.registry, self.oversized_frames);
+        unregister_metric!(self.registry, self.clock_drift_millis);
         unregister_metric!(self.registry, self.read_futures_in_flight);
         unregister_metric!(self.registry, self.read_futures_total);
         unregister_metric!(self.registry, self.write_futures_in_flight);
@@ -12,11 +12,16 @@
 //! reactor, and an external facing http server that exposes various uri routes and converts
 //! HTTP requests into the appropriate component events.
 //!
-//! Currently this component supports two endpoints, each of which takes no arguments:
+//! Currently this component supports four endpoints, each of which takes no arguments:
 //! /status : a human readable JSON equivalent of the info-get-status rpc method.
 //!     example: curl -X GET 'http://<ip>:8888/status'
 //! /metrics : time series data collected from the internals of the node being queried.
 //!     example: curl -X GET 'http://<ip>:8888/metrics'
+//! /health : liveness probe, responds with 200 OK as long as the server is running.
+//!     example: curl -X GET 'http://<ip>:8888/health'
+//! /ready : readiness probe, responds with 200 OK once all components report ready, otherwise
+//!     503 Service Unavailable, along with a JSON summary of each component's readiness.
+//!     example: curl -X GET 'http://<ip>:8888/ready'
 
 mod config;
 mod event;
@@ -35,8 +40,8 @@ use super::Component;
 use crate::{
     effect::{
         requests::{
-            ChainspecLoaderRequest, ConsensusRequest, MetricsRequest, NetworkInfoRequest,
-            StorageRequest,
+            ChainspecLoaderRequest, ConsensusRequest, HealthRequest, MetricsRequest,
+            NetworkInfoRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects,
     },
@@ -59,6 +64,7 @@ pub trait ReactorEventT:
     + From<ChainspecLoaderRequest>
     + From<ConsensusRequest>
     + From<MetricsRequest>
+    + From<HealthRequest>
     + Send
 {
 }
@@ -71,6 +77,7 @@ impl<REv> ReactorEventT for REv where
         + From<ChainspecLoaderRequest>
         + From<ConsensusRequest>
         + From<MetricsRequest>
+        + From<HealthRequest>
         + Send
         + 'static
 {
@@ -102,6 +109,8 @@ impl RestServer {
             api_version,
             shutdown_receiver,
             config.qps_limit,
+            config.ready_min_peers,
+            config.ready_max_block_age,
         ));
 
         Ok(RestServer {
@@ -126,14 +135,29 @@ where
     ) -> Effects<Self::Event> {
         match event {
             Event::RestRequest(RestRequest::GetStatus { responder }) => async move {
-                let (last_added_block, peers, chainspec_info, consensus_status) = join!(
+                let (
+                    last_added_block,
+                    peers,
+                    clock_drift_millis,
+                    chainspec_info,
+                    consensus_status,
+                    health,
+                ) = join!(
                     effect_builder.get_highest_block_from_storage(),
                     effect_builder.network_peers(),
+                    effect_builder.network_clock_drift(),
                     effect_builder.get_chainspec_info(),
-                    effect_builder.consensus_status()
+                    effect_builder.consensus_status(),
+                    effect_builder.get_health_snapshot()
+                );
+                let status_feed = StatusFeed::new(
+                    last_added_block,
+                    peers,
+                    clock_drift_millis,
+                    chainspec_info,
+                    consensus_status,
+                    health,
                 );
-                let status_feed =
-                    StatusFeed::new(last_added_block, peers, chainspec_info, consensus_status);
                 responder.respond(status_feed).await;
             }
             .ignore(),
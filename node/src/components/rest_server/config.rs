@@ -1,6 +1,8 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use crate::types::TimeDiff;
+
 /// Default binding address for the REST HTTP server.
 ///
 /// Uses a fixed port per node, but binds on any interface.
@@ -8,6 +10,16 @@ const DEFAULT_ADDRESS: &str = "0.0.0.0:0";
 /// Default rate limit in qps.
 const DEFAULT_QPS_LIMIT: u64 = 100;
 
+/// Default minimum number of connected peers for the node to be reported as ready.
+fn default_ready_min_peers() -> usize {
+    1
+}
+
+/// Default maximum age of the last added block for the node to be reported as ready.
+fn default_ready_max_block_age() -> TimeDiff {
+    TimeDiff::from_seconds(300)
+}
+
 /// REST HTTP server configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
@@ -18,6 +30,14 @@ pub struct Config {
 
     /// Max rate limit in qps.
     pub qps_limit: u64,
+
+    /// Minimum number of connected peers for the "ready" endpoint to report the node as ready.
+    #[serde(default = "default_ready_min_peers")]
+    pub ready_min_peers: usize,
+
+    /// Maximum age of the last added block for the "ready" endpoint to report the node as ready.
+    #[serde(default = "default_ready_max_block_age")]
+    pub ready_max_block_age: TimeDiff,
 }
 
 impl Config {
@@ -26,6 +46,8 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             qps_limit: DEFAULT_QPS_LIMIT,
+            ready_min_peers: default_ready_min_peers(),
+            ready_max_block_age: default_ready_max_block_age(),
         }
     }
 }
@@ -15,7 +15,7 @@ use super::ReactorEventT;
 use crate::{
     effect::{requests::RestRequest, EffectBuilder},
     reactor::QueueKind,
-    types::GetStatusResult,
+    types::{GetReadinessResult, GetStatusResult, TimeDiff, Timestamp},
 };
 
 /// The status URL path.
@@ -24,6 +24,12 @@ pub const STATUS_API_PATH: &str = "status";
 /// The metrics URL path.
 pub const METRICS_API_PATH: &str = "metrics";
 
+/// The health (liveness) URL path.
+pub const HEALTH_API_PATH: &str = "health";
+
+/// The readiness URL path.
+pub const READY_API_PATH: &str = "ready";
+
 pub(super) fn create_status_filter<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
     api_version: Version,
@@ -45,6 +51,46 @@ pub(super) fn create_status_filter<REv: ReactorEventT>(
         .boxed()
 }
 
+pub(super) fn create_health_filter() -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(HEALTH_API_PATH))
+        .map(|| reply::with_status("ok", StatusCode::OK).into_response())
+        .boxed()
+}
+
+pub(super) fn create_ready_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    min_peers: usize,
+    max_block_age: TimeDiff,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(READY_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .make_request(
+                    |responder| RestRequest::GetStatus { responder },
+                    QueueKind::Api,
+                )
+                .map(move |status_feed| {
+                    let body = GetReadinessResult::new(
+                        &status_feed,
+                        min_peers,
+                        max_block_age,
+                        Timestamp::now(),
+                    );
+                    let status_code = if body.ready {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::SERVICE_UNAVAILABLE
+                    };
+                    Ok::<_, Rejection>(
+                        reply::with_status(reply::json(&body), status_code).into_response(),
+                    )
+                })
+        })
+        .boxed()
+}
+
 pub(super) fn create_metrics_filter<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
 ) -> BoxedFilter<(Response<Body>,)> {
@@ -9,7 +9,7 @@ use tracing::{info, warn};
 use warp::Filter;
 
 use super::{filters, ReactorEventT};
-use crate::effect::EffectBuilder;
+use crate::{effect::EffectBuilder, types::TimeDiff};
 
 /// Run the REST HTTP server.
 ///
@@ -20,12 +20,18 @@ pub(super) async fn run<REv: ReactorEventT>(
     api_version: Version,
     shutdown_receiver: oneshot::Receiver<()>,
     qps_limit: u64,
+    ready_min_peers: usize,
+    ready_max_block_age: TimeDiff,
 ) {
     // REST filters.
     let rest_status = filters::create_status_filter(effect_builder, api_version);
     let rest_metrics = filters::create_metrics_filter(effect_builder);
+    let rest_health = filters::create_health_filter();
+    let rest_ready =
+        filters::create_ready_filter(effect_builder, ready_min_peers, ready_max_block_age);
 
-    let service = warp_json_rpc::service(rest_status.or(rest_metrics));
+    let service =
+        warp_json_rpc::service(rest_status.or(rest_metrics).or(rest_health).or(rest_ready));
 
     // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
     let make_svc =
@@ -43,8 +43,8 @@ use crate::{
     effect::{
         announcements::RpcServerAnnouncement,
         requests::{
-            ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, LinearChainRequest,
-            MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
+            ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, HealthRequest,
+            LinearChainRequest, MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
@@ -66,6 +66,7 @@ pub trait ReactorEventT:
     + From<ConsensusRequest>
     + From<LinearChainRequest<NodeId>>
     + From<MetricsRequest>
+    + From<HealthRequest>
     + From<NetworkInfoRequest<NodeId>>
     + From<StorageRequest>
     + Send
@@ -81,6 +82,7 @@ impl<REv> ReactorEventT for REv where
         + From<ConsensusRequest>
         + From<LinearChainRequest<NodeId>>
         + From<MetricsRequest>
+        + From<HealthRequest>
         + From<NetworkInfoRequest<NodeId>>
         + From<StorageRequest>
         + Send
@@ -234,6 +236,26 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetSwitchBlock {
+                maybe_era_id: Some(era_id),
+                responder,
+            }) => effect_builder
+                .get_switch_block_at_era_id_from_storage(era_id)
+                .event(move |result| Event::GetSwitchBlockResult {
+                    maybe_era_id: Some(era_id),
+                    result: Box::new(result),
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::GetSwitchBlock {
+                maybe_era_id: None,
+                responder,
+            }) => effect_builder
+                .get_highest_switch_block_from_storage()
+                .event(move |result| Event::GetSwitchBlockResult {
+                    maybe_era_id: None,
+                    result: Box::new(result),
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::QueryProtocolData {
                 protocol_version,
                 responder,
@@ -278,6 +300,12 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetAccountDeploys { query, responder }) => effect_builder
+                .get_account_deploys_from_storage(query)
+                .event(move |result| Event::GetAccountDeploysResult {
+                    result,
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::GetPeers { responder }) => effect_builder
                 .network_peers()
                 .event(move |peers| Event::GetPeersResult {
@@ -285,14 +313,29 @@ where
                     main_responder: responder,
                 }),
             Event::RpcRequest(RpcRequest::GetStatus { responder }) => async move {
-                let (last_added_block, peers, chainspec_info, consensus_status) = join!(
+                let (
+                    last_added_block,
+                    peers,
+                    clock_drift_millis,
+                    chainspec_info,
+                    consensus_status,
+                    health,
+                ) = join!(
                     effect_builder.get_highest_block_from_storage(),
                     effect_builder.network_peers(),
+                    effect_builder.network_clock_drift(),
                     effect_builder.get_chainspec_info(),
-                    effect_builder.consensus_status()
+                    effect_builder.consensus_status(),
+                    effect_builder.get_health_snapshot()
+                );
+                let status_feed = StatusFeed::new(
+                    last_added_block,
+                    peers,
+                    clock_drift_millis,
+                    chainspec_info,
+                    consensus_status,
+                    health,
                 );
-                let status_feed =
-                    StatusFeed::new(last_added_block, peers, chainspec_info, consensus_status);
                 responder.respond(status_feed).await;
             }
             .ignore(),
@@ -312,6 +355,11 @@ where
                 main_responder,
                 ..
             } => main_responder.respond(*result).ignore(),
+            Event::GetSwitchBlockResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(*result).ignore(),
             Event::QueryProtocolDataResult {
                 result,
                 main_responder,
@@ -337,6 +385,10 @@ where
                 result,
                 main_responder,
             } => main_responder.respond(*result).ignore(),
+            Event::GetAccountDeploysResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::GetPeersResult {
                 peers,
                 main_responder,
@@ -12,9 +12,12 @@ use casper_execution_engine::{
 use casper_types::{system::auction::EraValidators, Transfer};
 
 use crate::{
+    components::consensus::EraId,
     effect::{requests::RpcRequest, Responder},
     rpcs::chain::BlockIdentifier,
-    types::{Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId},
+    types::{
+        Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId, Timestamp,
+    },
 };
 
 #[derive(Debug, From)]
@@ -31,6 +34,11 @@ pub enum Event {
         result: Box<Option<Vec<Transfer>>>,
         main_responder: Responder<Option<Vec<Transfer>>>,
     },
+    GetSwitchBlockResult {
+        maybe_era_id: Option<EraId>,
+        result: Box<Option<Block>>,
+        main_responder: Responder<Option<Block>>,
+    },
     QueryProtocolDataResult {
         result: Result<Option<Box<ProtocolData>>, engine_state::Error>,
         main_responder: Responder<Result<Option<Box<ProtocolData>>, engine_state::Error>>,
@@ -52,6 +60,10 @@ pub enum Event {
         result: Box<Option<(Deploy, DeployMetadata)>>,
         main_responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    GetAccountDeploysResult {
+        result: Vec<(DeployHash, Timestamp)>,
+        main_responder: Responder<Vec<(DeployHash, Timestamp)>>,
+    },
     GetPeersResult {
         peers: BTreeMap<NodeId, String>,
         main_responder: Responder<BTreeMap<NodeId, String>>,
@@ -92,6 +104,20 @@ impl Display for Event {
                 "get block transfers result for block_hash {}: {:?}",
                 block_hash, result
             ),
+            Event::GetSwitchBlockResult {
+                maybe_era_id: Some(era_id),
+                result,
+                ..
+            } => write!(
+                formatter,
+                "get switch block result for {}: {:?}",
+                era_id, result
+            ),
+            Event::GetSwitchBlockResult {
+                maybe_era_id: None,
+                result,
+                ..
+            } => write!(formatter, "get latest switch block result: {:?}", result),
             Event::QueryProtocolDataResult { result, .. } => {
                 write!(formatter, "query protocol data result: {:?}", result)
             }
@@ -110,6 +136,13 @@ impl Display for Event {
             Event::GetDeployResult { hash, result, .. } => {
                 write!(formatter, "get deploy result for {}: {:?}", hash, result)
             }
+            Event::GetAccountDeploysResult { result, .. } => {
+                write!(
+                    formatter,
+                    "get account deploys result: {} deploys",
+                    result.len()
+                )
+            }
             Event::GetPeersResult { peers, .. } => write!(formatter, "get peers: {}", peers.len()),
             Event::GetMetricsResult { text, .. } => match text {
                 Some(txt) => write!(formatter, "get metrics ({} bytes)", txt.len()),
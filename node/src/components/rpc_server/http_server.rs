@@ -62,10 +62,20 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_balance =
         rpcs::state::GetBalance::create_filter(effect_builder, api_version.clone());
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version.clone());
+    let rpc_get_account_deploys =
+        rpcs::info::GetAccountDeploys::create_filter(effect_builder, api_version.clone());
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version.clone());
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_deploy_limits =
+        rpcs::info::GetDeployLimits::create_filter(effect_builder, api_version.clone());
+    let rpc_get_consensus_status =
+        rpcs::info::GetConsensusStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_validator_changes =
+        rpcs::info::GetValidatorChanges::create_filter(effect_builder, api_version.clone());
     let rpc_get_era_info =
         rpcs::chain::GetEraInfoBySwitchBlock::create_filter(effect_builder, api_version.clone());
+    let rpc_get_era_end =
+        rpcs::chain::GetEraEnd::create_filter(effect_builder, api_version.clone());
     let rpc_get_auction_info =
         rpcs::state::GetAuctionInfo::create_filter(effect_builder, api_version.clone());
     let rpc_get_rpcs = rpcs::docs::ListRpcs::create_filter(effect_builder, api_version);
@@ -96,9 +106,14 @@ pub(super) async fn run<REv: ReactorEventT>(
             .or(rpc_get_item)
             .or(rpc_get_balance)
             .or(rpc_get_deploy)
+            .or(rpc_get_account_deploys)
             .or(rpc_get_peers)
             .or(rpc_get_status)
+            .or(rpc_get_deploy_limits)
+            .or(rpc_get_consensus_status)
+            .or(rpc_get_validator_changes)
             .or(rpc_get_era_info)
+            .or(rpc_get_era_end)
             .or(rpc_get_auction_info)
             .or(rpc_get_rpcs)
             .or(unknown_method)
@@ -34,17 +34,33 @@ pub const RPC_API_PATH: &str = "rpc";
 /// Error code returned if the JSON-RPC response indicates failure.
 ///
 /// See <https://www.jsonrpc.org/specification#error_object> for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i64)]
-enum ErrorCode {
+pub enum ErrorCode {
+    /// The requested deploy was not found.
     NoSuchDeploy = -32000,
+    /// The requested block was not found.
     NoSuchBlock = -32001,
+    /// The given key could not be parsed.
     ParseQueryKey = -32002,
+    /// The global state query failed.
     QueryFailed = -32003,
+    /// The global state query could not be executed.
     QueryFailedToExecute = -32004,
+    /// The given purse URef could not be parsed.
     ParseGetBalanceURef = -32005,
+    /// The balance query failed.
     GetBalanceFailed = -32006,
+    /// The balance query could not be executed.
     GetBalanceFailedToExecute = -32007,
+    /// The submitted deploy was invalid.
     InvalidDeploy = -32008,
+    /// The submitted deploy's time-to-live exceeds the chainspec's maximum.
+    ExcessiveTimeToLive = -32009,
+    /// The era validators query failed.
+    QueryEraValidatorsFailed = -32010,
+    /// The requested page size is zero or exceeds the maximum allowed.
+    InvalidPageSize = -32011,
 }
 
 #[derive(Debug)]
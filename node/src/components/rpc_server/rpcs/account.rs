@@ -23,7 +23,7 @@ use crate::{
     components::rpc_server::rpcs::ErrorCode,
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Deploy, DeployHash},
+    types::{Deploy, DeployHash, DeployValidationFailure, TimeDiff},
 };
 
 static PUT_DEPLOY_PARAMS: Lazy<PutDeployParams> = Lazy::new(|| PutDeployParams {
@@ -65,6 +65,24 @@ impl DocExample for PutDeployResult {
     }
 }
 
+/// Checks that the time-to-live of `deploy` doesn't exceed `max_ttl`, the chainspec's maximum.
+///
+/// Returns the error with which "account_put_deploy" rejects the deploy otherwise.
+pub fn validate_time_to_live(
+    deploy: &Deploy,
+    max_ttl: TimeDiff,
+) -> Result<(), warp_json_rpc::Error> {
+    let ttl = deploy.header().ttl();
+    if ttl <= max_ttl {
+        return Ok(());
+    }
+    let failure = DeployValidationFailure::ExcessiveTimeToLive { max_ttl, got: ttl };
+    Err(warp_json_rpc::Error::custom(
+        ErrorCode::ExcessiveTimeToLive as i64,
+        format!("invalid deploy: {}", failure),
+    ))
+}
+
 /// "account_put_deploy" RPC
 pub struct PutDeploy {}
 
@@ -84,6 +102,19 @@ impl RpcWithParamsExt for PutDeploy {
         async move {
             let deploy_hash = *params.deploy.id();
 
+            // Reject deploys which would otherwise linger in buffers before they reach the
+            // deploy acceptor, and hence gossip or the block proposer.
+            let max_ttl = effect_builder.get_deploy_limits().await.max_ttl;
+            if let Err(error) = validate_time_to_live(&params.deploy, max_ttl) {
+                info!(
+                    %deploy_hash,
+                    ttl = %params.deploy.header().ttl(),
+                    %max_ttl,
+                    "the deploy submitted by the client has an excessive ttl",
+                );
+                return Ok(response_builder.error(error)?);
+            }
+
             // Submit the new deploy to be announced.
             let put_deploy_result = effect_builder
                 .make_request(
@@ -24,11 +24,12 @@ use super::{
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
 };
 use crate::{
+    components::consensus::EraId,
     crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::common::{self},
-    types::{Block, BlockHash, BlockSignatures, Item, JsonBlock},
+    types::{Block, BlockHash, BlockSignatures, Item, JsonBlock, JsonEraEnd},
 };
 pub use era_summary::EraSummary;
 use era_summary::ERA_SUMMARY;
@@ -59,6 +60,15 @@ static GET_STATE_ROOT_HASH_RESULT: Lazy<GetStateRootHashResult> =
         api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
         state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
     });
+static GET_ERA_END_PARAMS: Lazy<GetEraEndParams> = Lazy::new(|| GetEraEndParams {
+    era_id: Block::doc_example().header().era_id().0,
+});
+static GET_ERA_END_RESULT: Lazy<GetEraEndResult> = Lazy::new(|| GetEraEndResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    era_id: Some(Block::doc_example().header().era_id().0),
+    block_hash: Some(Block::doc_example().id()),
+    era_end: JsonEraEnd::from_block_header(Block::doc_example().header()),
+});
 static GET_ERA_INFO_PARAMS: Lazy<GetEraInfoParams> = Lazy::new(|| GetEraInfoParams {
     block_identifier: BlockIdentifier::Hash(Block::doc_example().id()),
 });
@@ -316,6 +326,101 @@ impl RpcWithOptionalParamsExt for GetStateRootHash {
     }
 }
 
+/// Params for "chain_get_era_end" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraEndParams {
+    /// The era whose switch block should be retrieved.
+    pub era_id: u64,
+}
+
+impl DocExample for GetEraEndParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_END_PARAMS
+    }
+}
+
+/// Result for "chain_get_era_end" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraEndResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The era ID, if the switch block was found.
+    pub era_id: Option<u64>,
+    /// The hash of the era's switch block, if found.
+    pub block_hash: Option<BlockHash>,
+    /// The era end recorded in the switch block, if found.
+    pub era_end: Option<JsonEraEnd>,
+}
+
+impl GetEraEndResult {
+    fn new(api_version: Version, maybe_switch_block: Option<Block>) -> Self {
+        match maybe_switch_block {
+            Some(block) => GetEraEndResult {
+                api_version,
+                era_id: Some(block.header().era_id().0),
+                block_hash: Some(*block.hash()),
+                era_end: JsonEraEnd::from_block_header(block.header()),
+            },
+            None => GetEraEndResult {
+                api_version,
+                era_id: None,
+                block_hash: None,
+                era_end: None,
+            },
+        }
+    }
+}
+
+impl DocExample for GetEraEndResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_END_RESULT
+    }
+}
+
+/// "chain_get_era_end" RPC.
+pub struct GetEraEnd {}
+
+impl RpcWithOptionalParams for GetEraEnd {
+    const METHOD: &'static str = "chain_get_era_end";
+    type OptionalRequestParams = GetEraEndParams;
+    type ResponseResult = GetEraEndResult;
+}
+
+impl RpcWithOptionalParamsExt for GetEraEnd {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        maybe_params: Option<Self::OptionalRequestParams>,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Get the switch block from storage, or the latest one if no era was specified.
+            let maybe_era_id = maybe_params.map(|params| EraId(params.era_id));
+            let maybe_switch_block = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetSwitchBlock {
+                        maybe_era_id,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            if let (None, Some(era_id)) = (&maybe_switch_block, maybe_era_id) {
+                info!("failed to get switch block of {} from storage", era_id);
+            }
+
+            // Return the result.
+            let result = Self::ResponseResult::new(api_version, maybe_switch_block);
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Params for "chain_get_era_info" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -19,8 +19,11 @@ use warp_json_rpc::Builder;
 
 use super::{
     account::PutDeploy,
-    chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
+    chain::{GetBlock, GetBlockTransfers, GetEraEnd, GetStateRootHash},
+    info::{
+        GetAccountDeploys, GetConsensusStatus, GetDeploy, GetDeployLimits, GetPeers, GetStatus,
+        GetValidatorChanges,
+    },
     state::{GetAuctionInfo, GetBalance, GetItem},
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
@@ -68,8 +71,20 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
 
     schema.push_with_params::<PutDeploy>("receives a Deploy to be executed by the network");
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
+    schema.push_with_params::<GetAccountDeploys>(
+        "returns the hashes of the deploys sent by an account within a time range",
+    );
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
+    schema.push_without_params::<GetDeployLimits>(
+        "returns the chainspec limits a deploy must comply with",
+    );
+    schema.push_without_params::<GetConsensusStatus>(
+        "returns the current era's validator liveness statistics and the node's round exponent",
+    );
+    schema.push_without_params::<GetValidatorChanges>(
+        "returns the validators joining or leaving the validator set over the upcoming eras",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
@@ -82,6 +97,9 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
+    schema.push_with_optional_params::<GetEraEnd>(
+        "returns the EraEnd recorded in an era's switch block",
+    );
     schema.push_without_params::<GetAuctionInfo>(
         "returns the bids and validators as of the most recently added Block",
     );
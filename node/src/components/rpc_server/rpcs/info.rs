@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, ProtocolVersion, PublicKey};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
@@ -23,9 +23,17 @@ use super::{
     RpcWithoutParamsExt,
 };
 use crate::{
+    components::{
+        consensus::{EraId, ValidatorLiveness},
+        storage::AccountDeploysQuery,
+    },
+    crypto::AsymmetricKeyExt,
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
+    types::{
+        json_compatibility::ValidatorChanges, Block, BlockHash, Deploy, DeployHash, DeployLimits,
+        GetStatusResult, Item, PeersMap, TimeDiff, Timestamp,
+    },
 };
 
 static GET_DEPLOY_PARAMS: Lazy<GetDeployParams> = Lazy::new(|| GetDeployParams {
@@ -39,10 +47,58 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         result: ExecutionResult::example().clone(),
     }],
 });
+static GET_ACCOUNT_DEPLOYS_PARAMS: Lazy<GetAccountDeploysParams> =
+    Lazy::new(|| GetAccountDeploysParams {
+        public_key: *PublicKey::doc_example(),
+        from: *Timestamp::doc_example(),
+        to: *Timestamp::doc_example() + TimeDiff::from(3_600_000),
+        block_hash: None,
+        offset: 0,
+        limit: 10,
+    });
+static GET_ACCOUNT_DEPLOYS_RESULT: Lazy<GetAccountDeploysResult> =
+    Lazy::new(|| GetAccountDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploys: vec![AccountDeploy {
+            deploy_hash: *Deploy::doc_example().id(),
+            timestamp: Deploy::doc_example().header().timestamp(),
+        }],
+        next_offset: None,
+    });
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     peers: GetStatusResult::doc_example().peers.clone(),
 });
+static GET_DEPLOY_LIMITS_RESULT: Lazy<GetDeployLimitsResult> =
+    Lazy::new(|| GetDeployLimitsResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploy_limits: DeployLimits {
+            chain_name: String::from("casper-example"),
+            max_deploy_size: 1_048_576,
+            max_ttl: TimeDiff::from(86_400_000),
+            max_dependencies: 10,
+            payment_args_max_length: 1024,
+            session_args_max_length: 1024,
+        },
+    });
+static GET_CONSENSUS_STATUS_RESULT: Lazy<GetConsensusStatusResult> =
+    Lazy::new(|| GetConsensusStatusResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        era_id: EraId(42),
+        round_exponent: Some(16),
+        validators: vec![ValidatorLiveness {
+            validator_id: *PublicKey::doc_example(),
+            units_produced: 1_024,
+            rounds_missed: 3,
+            round_exponent: Some(16),
+            average_finalization_latency: Some(TimeDiff::from(98_304)),
+        }],
+    });
+static GET_VALIDATOR_CHANGES_RESULT: Lazy<GetValidatorChangesResult> =
+    Lazy::new(|| GetValidatorChangesResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        validator_changes: ValidatorChanges::doc_example().clone(),
+    });
 
 /// Params for "info_get_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -147,6 +203,134 @@ impl RpcWithParamsExt for GetDeploy {
     }
 }
 
+/// The maximum number of deploys returned in a single "info_get_account_deploys" response.
+const MAX_ACCOUNT_DEPLOYS_PAGE_SIZE: u32 = 100;
+
+/// Params for "info_get_account_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysParams {
+    /// The public key of the account which sent the deploys.
+    pub public_key: PublicKey,
+    /// The start of the time range (inclusive).
+    pub from: Timestamp,
+    /// The end of the time range (inclusive).
+    pub to: Timestamp,
+    /// If provided, only deploys executed in the given block are returned.
+    #[serde(default)]
+    pub block_hash: Option<BlockHash>,
+    /// The number of matching deploys to skip.
+    #[serde(default)]
+    pub offset: u32,
+    /// The maximum number of deploys to return.
+    pub limit: u32,
+}
+
+impl DocExample for GetAccountDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_PARAMS
+    }
+}
+
+/// A deploy sent by an account.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountDeploy {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The deploy's timestamp.
+    pub timestamp: Timestamp,
+}
+
+/// Result for "info_get_account_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The matching deploys, ordered by timestamp.
+    pub deploys: Vec<AccountDeploy>,
+    /// The offset of the next page, if there are more matching deploys.
+    pub next_offset: Option<u32>,
+}
+
+impl DocExample for GetAccountDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_account_deploys" RPC.
+pub struct GetAccountDeploys {}
+
+impl RpcWithParams for GetAccountDeploys {
+    const METHOD: &'static str = "info_get_account_deploys";
+    type RequestParams = GetAccountDeploysParams;
+    type ResponseResult = GetAccountDeploysResult;
+}
+
+impl RpcWithParamsExt for GetAccountDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            if params.limit == 0 || params.limit > MAX_ACCOUNT_DEPLOYS_PAGE_SIZE {
+                let error_msg = format!(
+                    "page size must be between 1 and {}, got {}",
+                    MAX_ACCOUNT_DEPLOYS_PAGE_SIZE, params.limit
+                );
+                info!("{}", error_msg);
+                return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                    ErrorCode::InvalidPageSize as i64,
+                    error_msg,
+                ))?);
+            }
+
+            // Request one extra entry to find out whether there is a further page.
+            let limit = params.limit as usize;
+            let query = Box::new(AccountDeploysQuery {
+                account_hash: params.public_key.to_account_hash(),
+                from: params.from,
+                to: params.to,
+                maybe_block_hash: params.block_hash,
+                offset: params.offset as usize,
+                limit: limit + 1,
+            });
+            let mut deploys = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAccountDeploys { query, responder },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let next_offset = if deploys.len() > limit {
+                deploys.truncate(limit);
+                Some(params.offset.saturating_add(params.limit))
+            } else {
+                None
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploys: deploys
+                    .into_iter()
+                    .map(|(deploy_hash, timestamp)| AccountDeploy {
+                        deploy_hash,
+                        timestamp,
+                    })
+                    .collect(),
+                next_offset,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Result for "info_get_peers" RPC response.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -196,6 +380,98 @@ impl RpcWithoutParamsExt for GetPeers {
     }
 }
 
+/// Result for "info_get_deploy_limits" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployLimitsResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The chainspec limits a deploy must comply with.
+    pub deploy_limits: DeployLimits,
+}
+
+impl DocExample for GetDeployLimitsResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DEPLOY_LIMITS_RESULT
+    }
+}
+
+/// "info_get_deploy_limits" RPC.
+pub struct GetDeployLimits {}
+
+impl RpcWithoutParams for GetDeployLimits {
+    const METHOD: &'static str = "info_get_deploy_limits";
+    type ResponseResult = GetDeployLimitsResult;
+}
+
+impl RpcWithoutParamsExt for GetDeployLimits {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let deploy_limits = effect_builder.get_deploy_limits().await;
+            let result = Self::ResponseResult {
+                api_version,
+                deploy_limits,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Result for "info_get_consensus_status" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetConsensusStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The current era.
+    pub era_id: EraId,
+    /// The node's round exponent for the next round, if it is an active validator.
+    pub round_exponent: Option<u8>,
+    /// The liveness statistics of the current era's validators.
+    pub validators: Vec<ValidatorLiveness<PublicKey>>,
+}
+
+impl DocExample for GetConsensusStatusResult {
+    fn doc_example() -> &'static Self {
+        &*GET_CONSENSUS_STATUS_RESULT
+    }
+}
+
+/// "info_get_consensus_status" RPC.
+pub struct GetConsensusStatus {}
+
+impl RpcWithoutParams for GetConsensusStatus {
+    const METHOD: &'static str = "info_get_consensus_status";
+    type ResponseResult = GetConsensusStatusResult;
+}
+
+impl RpcWithoutParamsExt for GetConsensusStatus {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let (era_id, round_exponent, validators) = effect_builder.validator_liveness().await;
+            let result = Self::ResponseResult {
+                api_version,
+                era_id,
+                round_exponent,
+                validators,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// "info_get_status" RPC.
 pub struct GetStatus {}
 
@@ -226,3 +502,102 @@ impl RpcWithoutParamsExt for GetStatus {
         .boxed()
     }
 }
+
+/// Result for "info_get_validator_changes" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetValidatorChangesResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The validator changes over the upcoming eras.
+    pub validator_changes: ValidatorChanges,
+}
+
+impl DocExample for GetValidatorChangesResult {
+    fn doc_example() -> &'static Self {
+        &*GET_VALIDATOR_CHANGES_RESULT
+    }
+}
+
+/// "info_get_validator_changes" RPC.
+pub struct GetValidatorChanges {}
+
+impl RpcWithoutParams for GetValidatorChanges {
+    const METHOD: &'static str = "info_get_validator_changes";
+    type ResponseResult = GetValidatorChangesResult;
+}
+
+impl RpcWithoutParamsExt for GetValidatorChanges {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let maybe_block = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetBlock {
+                        maybe_id: None,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let block = match maybe_block {
+                Some((block, _)) => block,
+                None => {
+                    let error_msg =
+                        "get-validator-changes failed to get last added block".to_string();
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchBlock as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let protocol_version = ProtocolVersion::from_parts(
+                api_version.major as u32,
+                api_version.minor as u32,
+                api_version.patch as u32,
+            );
+            let state_root_hash = *block.header().state_root_hash();
+
+            // The auction holds validator snapshots for the current era up to `auction_delay`
+            // eras ahead.
+            let era_validators_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryEraValidators {
+                        state_root_hash,
+                        protocol_version,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let era_validators = match era_validators_result {
+                Ok(era_validators) => era_validators,
+                Err(error) => {
+                    let error_msg = format!("get-validator-changes failed: {:?}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryEraValidatorsFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let validator_changes =
+                ValidatorChanges::new(state_root_hash, block.header().height(), &era_validators);
+            let result = Self::ResponseResult {
+                api_version,
+                validator_changes,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
@@ -47,12 +47,13 @@ static GET_ITEM_RESULT: Lazy<GetItemResult> = Lazy::new(|| GetItemResult {
     merkle_proof: MERKLE_PROOF.clone(),
 });
 static GET_BALANCE_PARAMS: Lazy<GetBalanceParams> = Lazy::new(|| GetBalanceParams {
-    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
     purse_uref: "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007"
         .to_string(),
 });
 static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
     balance_value: U512::from(123_456),
     merkle_proof: MERKLE_PROOF.clone(),
 });
@@ -70,6 +71,9 @@ pub struct GetItemParams {
     /// `casper_types::Key` as formatted string.
     pub key: String,
     /// The path components starting from the key as base.
+    ///
+    /// Components name keys of accounts and contracts, or elements of stored values, e.g.
+    /// `entry[3]` for the fourth element of the list stored under the map key `entry`.
     #[serde(default)]
     pub path: Vec<String>,
 }
@@ -168,8 +172,10 @@ impl RpcWithParamsExt for GetItem {
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GetBalanceParams {
-    /// The hash of state root.
-    pub state_root_hash: Digest,
+    /// The hash of state root.  If not given, the state root of the last block added to the chain
+    /// as known at the given node will be used.
+    #[serde(default)]
+    pub state_root_hash: Option<Digest>,
     /// Formatted URef.
     pub purse_uref: String,
 }
@@ -187,6 +193,8 @@ pub struct GetBalanceResult {
     /// The RPC API version.
     #[schemars(with = "String")]
     pub api_version: Version,
+    /// The hash of the state root against which the balance was queried.
+    pub state_root_hash: Digest,
     /// The balance value.
     pub balance_value: U512,
     /// The merkle proof.
@@ -230,11 +238,40 @@ impl RpcWithParamsExt for GetBalance {
                 }
             };
 
+            // Use the given state root, or that of the last added block if none was given.
+            let state_root_hash = match params.state_root_hash {
+                Some(state_root_hash) => state_root_hash,
+                None => {
+                    let maybe_block = effect_builder
+                        .make_request(
+                            |responder| RpcRequest::GetBlock {
+                                maybe_id: None,
+                                responder,
+                            },
+                            QueueKind::Api,
+                        )
+                        .await;
+
+                    match maybe_block {
+                        Some((block, _)) => *block.header().state_root_hash(),
+                        None => {
+                            let error_msg =
+                                "get-balance failed to get last added block".to_string();
+                            info!("{}", error_msg);
+                            return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                                ErrorCode::NoSuchBlock as i64,
+                                error_msg,
+                            ))?);
+                        }
+                    }
+                }
+            };
+
             // Get the balance.
             let balance_result = effect_builder
                 .make_request(
                     |responder| RpcRequest::GetBalance {
-                        state_root_hash: params.state_root_hash,
+                        state_root_hash,
                         purse_uref,
                         responder,
                     },
@@ -275,6 +312,7 @@ impl RpcWithParamsExt for GetBalance {
             // Return the result.
             let result = Self::ResponseResult {
                 api_version,
+                state_root_hash,
                 balance_value,
                 merkle_proof,
             };
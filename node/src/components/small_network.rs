@@ -19,23 +19,36 @@
 //! # Connection
 //!
 //! Every node has an ID and a public listening address. The objective of each node is to constantly
-//! maintain an outgoing connection to each other node (and thus have an incoming connection from
-//! these nodes as well).
-//!
-//! Any incoming connection is strictly read from, while any outgoing connection is strictly used
-//! for sending messages.
+//! maintain a connection to each other node.
 //!
 //! Nodes gossip their public listening addresses periodically, and on learning of a new address,
 //! a node will try to establish an outgoing connection.
 //!
-//! On losing an incoming or outgoing connection for a given peer, the other connection is closed.
+//! If both nodes advertise support for it in their handshakes, a single connection is used per
+//! pair of peers, carrying messages in both directions. Should both nodes have connected to each
+//! other, the connection initiated by the node with the lower node ID is kept and the other one is
+//! closed, a decision both sides reach independently. Losing the connection removes the peer.
+//!
+//! Peers not supporting a single connection (e.g. nodes running an older version during a rollout)
+//! are connected to the legacy way: we maintain an outgoing connection to them and they maintain
+//! one to us. Any incoming connection is strictly read from, while any outgoing connection is
+//! strictly used for sending messages. On losing either connection for such a peer, the other
+//! connection is closed.
+//!
 //! No explicit reconnect is attempted. Instead, if the peer is still online, the normal gossiping
 //! process will cause both peers to connect again.
+//!
+//! Gossiped addresses are not trusted blindly; see the `address_validation` module for the checks
+//! applied before and after dialing them.
 
+mod address_validation;
 mod chain_info;
+mod clock_drift;
+mod compression;
 mod config;
 mod error;
 mod event;
+mod features;
 mod gossiped_address;
 mod message;
 #[cfg(test)]
@@ -69,6 +82,7 @@ use pkey::{PKey, Private};
 use prometheus::{IntGauge, Registry};
 use rand::seq::IteratorRandom;
 use serde::{de::DeserializeOwned, Serialize};
+use socket2::{Domain, Protocol, Socket, Type};
 use thiserror::Error;
 use tokio::{
     net::TcpStream,
@@ -80,14 +94,24 @@ use tokio::{
 };
 use tokio_openssl::SslStream;
 use tokio_serde::{formats::SymmetricalMessagePack, SymmetricallyFramed};
-use tokio_util::codec::{Framed, LengthDelimitedCodec};
+use tokio_util::codec::Framed;
 use tracing::{debug, error, info, trace, warn};
 
-use self::error::Result;
-pub(crate) use self::{event::Event, gossiped_address::GossipedAddress, message::Message};
+use self::{
+    address_validation::{AddressValidator, IdentityCheck},
+    clock_drift::ClockDrift,
+    error::Result,
+    features::Features,
+};
+pub(crate) use self::{
+    event::Event,
+    gossiped_address::GossipedAddress,
+    message::{GoodbyeReason, Message},
+};
 use crate::{
     components::{
         network::ENABLE_LIBP2P_NET_ENV_VAR, networking_metrics::NetworkingMetrics, Component,
+        HealthReport,
     },
     effect::{
         announcements::NetworkAnnouncement,
@@ -96,14 +120,17 @@ use crate::{
     },
     reactor::{EventQueueHandle, Finalize, QueueKind, ReactorEvent},
     tls::{self, TlsCert, ValidationError},
-    types::{NodeId, TimeDiff, Timestamp},
+    types::{ComponentHealth, NodeId, TimeDiff, Timestamp},
     utils, NodeRng,
 };
 use chain_info::ChainInfo;
-pub use config::Config;
+use compression::{CompressingCodec, CompressionMetrics, OversizedFrame};
+pub use config::{AddressFamily, Config};
 pub use error::Error;
 
 const MAX_ASYMMETRIC_CONNECTION_SEEN: u16 = 4;
+/// The backlog of pending connections of the listener, as used by `std::net::TcpListener::bind`.
+const LISTEN_BACKLOG: i32 = 128;
 static BLOCKLIST_RETAIN_DURATION: Lazy<TimeDiff> =
     Lazy::new(|| Duration::from_secs(60 * 10).into());
 
@@ -112,6 +139,8 @@ pub(crate) struct OutgoingConnection<P> {
     #[data_size(skip)] // Unfortunately, there is no way to inspect an `UnboundedSender`.
     sender: UnboundedSender<Message<P>>,
     peer_address: SocketAddr,
+    /// Features negotiated with the peer, empty until its handshake has been received.
+    features: Features,
 
     // for keeping track of connection asymmetry, tracking the number of times we've seen this
     // connection be asymmetric.
@@ -119,8 +148,15 @@ pub(crate) struct OutgoingConnection<P> {
 }
 
 #[derive(DataSize, Debug)]
-pub(crate) struct IncomingConnection {
+pub(crate) struct IncomingConnection<P> {
+    /// Messages sent over the incoming connection, only used if the peer uses a single connection.
+    #[data_size(skip)]
+    sender: UnboundedSender<Message<P>>,
     peer_address: SocketAddr,
+    /// Whether messages sent over the incoming connection may be compressed.
+    peer_supports_compression: Arc<AtomicBool>,
+    /// Features negotiated with the peer, empty until its handshake has been received.
+    features: Features,
 
     // for keeping track of connection asymmetry, tracking the number of times we've seen this
     // connection be asymmetric.
@@ -147,14 +183,26 @@ where
     /// Handle to event queue.
     event_queue: EventQueueHandle<REv>,
 
-    /// Incoming network connection addresses.
-    incoming: HashMap<NodeId, IncomingConnection>,
+    /// Incoming network connections.
+    incoming: HashMap<NodeId, IncomingConnection<P>>,
     /// Outgoing network connections' messages.
     outgoing: HashMap<NodeId, OutgoingConnection<P>>,
+    /// Public listening addresses of the peers using a single connection in both directions.
+    single_connection_peers: HashMap<NodeId, SocketAddr>,
 
     /// List of addresses which this node will avoid connecting to and the time they were added.
     blocklist: HashMap<SocketAddr, Timestamp>,
 
+    /// Addresses of peers which said goodbye, and the time until which we won't reconnect to them.
+    goodbye_cooldown: HashMap<SocketAddr, Timestamp>,
+
+    /// The reason sent to peers in a goodbye message when finalizing, if any.
+    #[data_size(skip)]
+    goodbye_reason: Option<GoodbyeReason>,
+
+    /// Number of oversized frames sent by each peer which has not yet been blocklisted for them.
+    oversized_frames: HashMap<NodeId, u32>,
+
     /// Pending outgoing connections: ones for which we are currently trying to make a connection.
     pending: HashMap<SocketAddr, Instant>,
 
@@ -181,6 +229,12 @@ where
 
     /// Known addresses for this node.
     known_addresses: HashSet<SocketAddr>,
+
+    /// Validation of the addresses gossiped to us by peers.
+    address_validator: AddressValidator,
+
+    /// Clock offsets of our peers, from which the drift of our own clock is estimated.
+    clock_drift: ClockDrift,
 }
 
 impl<REv, P> SmallNetwork<REv, P>
@@ -201,19 +255,7 @@ where
         chain_info_source: C,
         notify: bool,
     ) -> Result<(SmallNetwork<REv, P>, Effects<Event<P>>)> {
-        let mut known_addresses = HashSet::new();
-        for address in &cfg.known_addresses {
-            match utils::resolve_address(address) {
-                Ok(known_address) => {
-                    if !known_addresses.insert(known_address) {
-                        warn!(%address, resolved=%known_address, "ignoring duplicated known address");
-                    };
-                }
-                Err(err) => {
-                    warn!(%address, %err, "failed to resolve known address");
-                }
-            }
-        }
+        let known_addresses = resolve_known_addresses(&cfg.known_addresses);
 
         // Assert we have at least one known address in the config.
         if known_addresses.is_empty() {
@@ -221,8 +263,13 @@ where
             return Err(Error::InvalidConfig);
         }
 
+        let preferred_address_family = cfg.preferred_address_family;
         let mut public_address =
-            utils::resolve_address(&cfg.public_address).map_err(Error::ResolveAddr)?;
+            utils::resolve_address_preferring(&cfg.public_address, |address| {
+                preferred_address_family.is_preferred(address)
+            })
+            .map(utils::canonical_address)
+            .map_err(Error::ResolveAddr)?;
 
         let our_id = NodeId::from(&small_network_identity);
         let secret_key = small_network_identity.secret_key;
@@ -230,6 +277,12 @@ where
 
         let chain_info = Arc::new(chain_info_source.into());
 
+        let address_validator = AddressValidator::new(
+            cfg.gossiped_address_dial_interval,
+            cfg.max_bad_gossiped_addresses,
+        );
+        let clock_drift = ClockDrift::new(cfg.clock_drift_warning_threshold);
+
         // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without starting the
         // server.
         if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
@@ -244,8 +297,14 @@ where
                 event_queue,
                 incoming: HashMap::new(),
                 outgoing: HashMap::new(),
+                single_connection_peers: HashMap::new(),
                 pending: HashMap::new(),
                 blocklist: HashMap::new(),
+                goodbye_cooldown: HashMap::new(),
+                goodbye_reason: Some(GoodbyeReason::Shutdown),
+                oversized_frames: HashMap::new(),
+                address_validator,
+                clock_drift,
                 chain_info,
                 shutdown_sender: None,
                 shutdown_receiver: watch::channel(()).1,
@@ -259,8 +318,11 @@ where
         let net_metrics = NetworkingMetrics::new(&registry)?;
 
         // We can now create a listener.
-        let bind_address = utils::resolve_address(&cfg.bind_address).map_err(Error::ResolveAddr)?;
-        let listener = TcpListener::bind(bind_address)
+        let bind_address = utils::resolve_address_preferring(&cfg.bind_address, |address| {
+            preferred_address_family.is_preferred(address)
+        })
+        .map_err(Error::ResolveAddr)?;
+        let listener = bind_listener(bind_address, cfg.dual_stack)
             .map_err(|error| Error::ListenerCreation(error, bind_address))?;
 
         // Once the port has been bound, we can notify systemd if instructed to do so.
@@ -308,8 +370,14 @@ where
             event_queue,
             incoming: HashMap::new(),
             outgoing: HashMap::new(),
+            single_connection_peers: HashMap::new(),
             pending: HashMap::new(),
             blocklist: HashMap::new(),
+            goodbye_cooldown: HashMap::new(),
+            goodbye_reason: Some(GoodbyeReason::Shutdown),
+            oversized_frames: HashMap::new(),
+            address_validator,
+            clock_drift,
             chain_info,
             shutdown_sender: Some(server_shutdown_sender),
             shutdown_receiver,
@@ -333,12 +401,15 @@ where
                 .event(|_| Event::GossipOurAddress),
         );
 
+        // Periodically pick up changes to the DNS records of the known addresses.
+        effects.extend(model.schedule_known_addresses_resolution(effect_builder));
+
         Ok((model, effects))
     }
 
     /// Queues a message to be sent to all nodes.
     fn broadcast_message(&self, msg: Message<P>) {
-        for peer_id in self.outgoing.keys() {
+        for peer_id in self.connected_peers() {
             self.send_message(peer_id.clone(), msg.clone());
         }
     }
@@ -378,6 +449,69 @@ where
         effects
     }
 
+    /// Schedules the next re-resolution of the known addresses, unless it is disabled.
+    fn schedule_known_addresses_resolution(
+        &self,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Effects<Event<P>> {
+        let interval = self.cfg.known_addresses_resolution_interval;
+        if interval.millis() == 0 {
+            return Effects::new();
+        }
+
+        effect_builder
+            .set_timeout(interval.into())
+            .event(|_| Event::ResolveKnownAddresses)
+    }
+
+    /// Resolves the known addresses from the config again in a blocking task.
+    fn resolve_known_addresses(&self) -> Effects<Event<P>> {
+        let addresses = self.cfg.known_addresses.clone();
+        async move {
+            tokio::task::spawn_blocking(move || resolve_known_addresses(&addresses))
+                .await
+                .unwrap_or_else(|error| {
+                    warn!(%error, "failed to join known addresses resolution task");
+                    HashSet::new()
+                })
+        }
+        .event(|addresses| Event::KnownAddressesResolved(Box::new(addresses)))
+    }
+
+    /// Replaces the known addresses with a freshly resolved set, connecting to any newly appearing
+    /// addresses.
+    ///
+    /// If none of the known addresses could be resolved, the previous set is kept.
+    fn handle_known_addresses_resolved(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        resolved_addresses: HashSet<SocketAddr>,
+    ) -> Effects<Event<P>> {
+        let mut effects = self.schedule_known_addresses_resolution(effect_builder);
+
+        if resolved_addresses.is_empty() {
+            warn!("all known addresses failed DNS resolution, keeping previously resolved ones");
+            return effects;
+        }
+
+        for stale_address in self.known_addresses.difference(&resolved_addresses) {
+            info!(%stale_address, "known address no longer resolved, removing it");
+        }
+
+        let new_addresses: Vec<SocketAddr> = resolved_addresses
+            .difference(&self.known_addresses)
+            .copied()
+            .collect();
+        self.known_addresses = resolved_addresses;
+
+        for new_address in new_addresses {
+            info!(%new_address, "newly resolved known address, connecting to it");
+            effects.extend(self.connect_to_peer_if_required(new_address));
+        }
+
+        effects
+    }
+
     /// Queues a message to `count` random nodes on the network.
     fn gossip_message(
         &self,
@@ -387,8 +521,7 @@ where
         exclude: HashSet<NodeId>,
     ) -> HashSet<NodeId> {
         let peer_ids = self
-            .outgoing
-            .keys()
+            .connected_peers()
             .filter(|&peer_id| !exclude.contains(peer_id))
             .choose_multiple(rng, count);
 
@@ -400,7 +533,7 @@ where
                 wanted = count,
                 selected = peer_ids.len(),
                 "could not select enough random nodes for gossiping, not enough non-excluded \
-                outgoing connections"
+                connected peers"
             );
         }
 
@@ -414,8 +547,8 @@ where
     /// Queues a message to be sent to a specific node.
     fn send_message(&self, dest: NodeId, msg: Message<P>) {
         // Try to send the message.
-        if let Some(connection) = self.outgoing.get(&dest) {
-            if let Err(msg) = connection.sender.send(msg) {
+        if let Some(sender) = self.sender(&dest) {
+            if let Err(msg) = sender.send(msg) {
                 // We lost the connection, but that fact has not reached us yet.
                 warn!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, lost connection");
             } else {
@@ -427,6 +560,29 @@ where
         }
     }
 
+    /// Returns the sender for messages to the given peer, if we are connected to it.
+    ///
+    /// Incoming connections are only sent over if the peer uses a single connection.
+    fn sender(&self, peer_id: &NodeId) -> Option<&UnboundedSender<Message<P>>> {
+        match self.outgoing.get(peer_id) {
+            Some(outgoing) => Some(&outgoing.sender),
+            None if self.single_connection_peers.contains_key(peer_id) => {
+                self.incoming.get(peer_id).map(|incoming| &incoming.sender)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the IDs of all peers we can send messages to.
+    fn connected_peers(&self) -> impl Iterator<Item = &NodeId> + '_ {
+        self.outgoing
+            .keys()
+            .chain(self.incoming.keys().filter(move |peer_id| {
+                !self.outgoing.contains_key(peer_id)
+                    && self.single_connection_peers.contains_key(peer_id)
+            }))
+    }
+
     /// Sweep and timeout pending connections.
     ///
     /// This is a reliability measure that sweeps pending connections, since leftover entries will
@@ -500,25 +656,35 @@ where
                 }
 
                 debug!(our_id=%self.our_id, %peer_id, %peer_address, "established incoming connection");
-                // The sink is only used to send a single handshake message, then dropped.
-                let (mut sink, stream) =
-                    framed::<P>(transport, self.chain_info.maximum_net_message_size).split();
-                let handshake = self.chain_info.create_handshake(self.public_address);
-                let mut effects = async move {
-                    let _ = sink.send(handshake).await;
-                }
+                // Unless the peer turns out to use a single connection, the sink is only used to
+                // send the handshake message.
+                let peer_supports_compression = Arc::new(AtomicBool::new(false));
+                let (sink, stream) = self
+                    .framed(transport, Arc::clone(&peer_supports_compression))
+                    .split();
+                let (sender, receiver) = mpsc::unbounded_channel();
+                let mut effects = message_sender(
+                    receiver,
+                    sink,
+                    self.net_metrics.queued_messages.clone(),
+                    self.create_handshake(),
+                )
                 .ignore::<Event<P>>();
 
                 let _ = self.incoming.insert(
                     peer_id.clone(),
                     IncomingConnection {
+                        sender,
                         peer_address,
+                        peer_supports_compression,
+                        features: Features::empty(),
                         times_seen_asymmetric: 0,
                     },
                 );
                 self.net_metrics
                     .open_connections
                     .set(self.incoming.len() as i64);
+                self.drop_redundant_connection(&peer_id);
 
                 // If the connection is now complete, announce the new peer before starting reader.
                 effects.extend(self.check_connection_complete(effect_builder, peer_id.clone()));
@@ -554,7 +720,7 @@ where
         peer_id: NodeId,
         transport: Transport,
     ) -> Effects<Event<P>> {
-        // This connection is send-only, we only use the sink.
+        // Unless the peer uses a single connection, this connection is send-only.
         let peer_address = match transport.get_ref().peer_addr() {
             Ok(peer_addr) => peer_addr,
             Err(err) => {
@@ -582,15 +748,33 @@ where
             return Effects::new();
         }
 
-        // The stream is only used to receive a single handshake message and then dropped.
-        let (sink, stream) =
-            framed::<P>(transport, self.chain_info.maximum_net_message_size).split();
+        if let IdentityCheck::Mismatch { expected, penalize } = self
+            .address_validator
+            .check_identity(peer_address, &peer_id, Timestamp::now())
+        {
+            warn!(
+                our_id=%self.our_id,
+                %peer_id,
+                %expected,
+                %peer_address,
+                "gossiped address is held by a different node than before - closing connection"
+            );
+            return self.penalize_gossiper(effect_builder, penalize);
+        }
+
+        // Unless the peer uses a single connection, the stream is only used to receive a single
+        // handshake message and then dropped.
+        let peer_supports_compression = Arc::new(AtomicBool::new(false));
+        let (sink, stream) = self
+            .framed(transport, Arc::clone(&peer_supports_compression))
+            .split();
         debug!(our_id=%self.our_id, %peer_id, %peer_address, "established outgoing connection");
 
         let (sender, receiver) = mpsc::unbounded_channel();
         let connection = OutgoingConnection {
             peer_address,
             sender,
+            features: Features::empty(),
             times_seen_asymmetric: 0,
         };
         if self.outgoing.insert(peer_id.clone(), connection).is_some() {
@@ -599,17 +783,17 @@ where
             // closing event. If this is not the case, an assumed invariant has been violated.
             error!(our_id=%self.our_id, %peer_id, "did not expect leftover channel in outgoing map");
         }
+        self.drop_redundant_connection(&peer_id);
 
         let mut effects = self.check_connection_complete(effect_builder, peer_id.clone());
 
-        let handshake = self.chain_info.create_handshake(self.public_address);
         let peer_id_cloned = peer_id.clone();
         effects.extend(
             message_sender(
                 receiver,
                 sink,
                 self.net_metrics.queued_messages.clone(),
-                handshake,
+                self.create_handshake(),
             )
             .event(move |result| Event::OutgoingFailed {
                 peer_id: Box::new(Some(peer_id)),
@@ -621,9 +805,12 @@ where
             handshake_reader(
                 self.event_queue,
                 stream,
+                self.shutdown_receiver.clone(),
                 self.our_id.clone(),
                 peer_id_cloned,
                 peer_address,
+                self.our_features(),
+                peer_supports_compression,
             )
             .ignore::<Event<P>>(),
         );
@@ -648,8 +835,30 @@ where
             } else {
                 warn!(our_id=%self.our_id, %peer_id, %peer_address, "outgoing connection closed");
             }
-            effects.extend(self.remove(effect_builder, &peer_id, false));
+            // A peer using a single connection also sends messages over our outgoing connection.
+            let blocklist = matches!(
+                error,
+                Some(Error::MessageNotReceived(ref err)) if OversizedFrame::is_cause_of(err)
+            ) && self.record_oversized_frame(&peer_id);
+            if blocklist {
+                effects.extend(self.remove(effect_builder, &peer_id, true));
+            } else if self.single_connection_peers.contains_key(&peer_id) {
+                // The lost connection may have been a redundant one, already replaced by the
+                // connection the peer initiated.
+                if self
+                    .outgoing
+                    .get(&peer_id)
+                    .map_or(false, |outgoing| outgoing.peer_address == peer_address)
+                {
+                    let _ = self.outgoing.remove(&peer_id);
+                }
+                effects.extend(self.remove_if_disconnected(effect_builder, &peer_id));
+            } else {
+                effects.extend(self.remove(effect_builder, &peer_id, false));
+            }
         } else {
+            let penalize = self.address_validator.record_unreachable(peer_address);
+            effects.extend(self.penalize_gossiper(effect_builder, penalize));
             // If we are not calling remove, call the reconnection check explicitly.
             effects.extend(self.reconnect_if_not_connected_to_any_known_addresses(effect_builder));
         }
@@ -671,7 +880,11 @@ where
         peer_id: &NodeId,
         add_to_blocklist: bool,
     ) -> Effects<Event<P>> {
-        if let Some(incoming) = self.incoming.remove(&peer_id) {
+        self.clock_drift.remove(peer_id);
+        self.update_clock_drift_metric();
+        let single_connection_address = self.single_connection_peers.remove(peer_id);
+        let incoming = self.incoming.remove(&peer_id);
+        if let Some(ref incoming) = incoming {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the incoming connections");
             let _ = self.pending.remove(&incoming.peer_address);
 
@@ -681,16 +894,185 @@ where
         }
         if let Some(outgoing) = self.outgoing.remove(&peer_id) {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the outgoing connections");
-            if add_to_blocklist && !self.known_addresses.contains(&outgoing.peer_address) {
-                info!(our_id=%self.our_id, %peer_id, "blocklisting peer");
-                self.blocklist
-                    .insert(outgoing.peer_address, Timestamp::now());
+            if add_to_blocklist {
+                self.blocklist_peer(peer_id, &outgoing.sender, outgoing.peer_address);
+            }
+        } else if let (Some(incoming), Some(public_address)) = (incoming, single_connection_address)
+        {
+            // A peer using a single connection may only be connected through the connection it
+            // initiated.
+            if add_to_blocklist {
+                self.blocklist_peer(peer_id, &incoming.sender, public_address);
             }
         }
 
         self.reconnect_if_not_connected_to_any_known_addresses(effect_builder)
     }
 
+    /// Removes a peer using a single connection if neither an incoming nor an outgoing connection
+    /// to it remains.
+    fn remove_if_disconnected(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        peer_id: &NodeId,
+    ) -> Effects<Event<P>> {
+        if self.incoming.contains_key(peer_id) || self.outgoing.contains_key(peer_id) {
+            debug!(our_id=%self.our_id, %peer_id, "peer still connected through remaining connection");
+            Effects::new()
+        } else {
+            self.remove(effect_builder, peer_id, false)
+        }
+    }
+
+    /// Says goodbye to a peer and adds its public listening address to the blocklist, unless it is
+    /// a known address.
+    fn blocklist_peer(
+        &mut self,
+        peer_id: &NodeId,
+        sender: &UnboundedSender<Message<P>>,
+        public_address: SocketAddr,
+    ) {
+        if self.known_addresses.contains(&public_address) {
+            return;
+        }
+        info!(our_id=%self.our_id, %peer_id, "blocklisting peer");
+        let goodbye = Message::Goodbye {
+            reason: GoodbyeReason::Blocklisted,
+        };
+        if sender.send(goodbye).is_ok() {
+            self.net_metrics.queued_messages.inc();
+        }
+        self.blocklist.insert(public_address, Timestamp::now());
+    }
+
+    /// Blocklists the given peer, if any, for gossiping too many bad addresses.
+    fn penalize_gossiper(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        gossiper: Option<NodeId>,
+    ) -> Effects<Event<P>> {
+        match gossiper {
+            Some(gossiper) => {
+                warn!(our_id=%self.our_id, peer_id=%gossiper, "peer gossiped too many bad addresses");
+                self.remove(effect_builder, &gossiper, true)
+            }
+            None => Effects::new(),
+        }
+    }
+
+    /// Connects to an address gossiped to us by `sender`, unless it was dialed recently because
+    /// of gossip.
+    fn connect_to_gossiped_address(
+        &mut self,
+        peer_address: SocketAddr,
+        sender: NodeId,
+    ) -> Effects<Event<P>> {
+        let peer_address = utils::canonical_address(peer_address);
+        let now = Timestamp::now();
+        if !self.address_validator.may_dial(peer_address, now) {
+            debug!(
+                our_id=%self.our_id,
+                %peer_address,
+                %sender,
+                "gossiped address dialed recently, not dialing"
+            );
+            return Effects::new();
+        }
+        let effects = self.connect_to_peer_if_required(peer_address);
+        if !effects.is_empty() {
+            self.address_validator
+                .record_dial(peer_address, sender, now);
+        }
+        effects
+    }
+
+    /// Records that a peer sent an oversized frame, returning whether it has now sent too many and
+    /// should be blocklisted.
+    fn record_oversized_frame(&mut self, peer_id: &NodeId) -> bool {
+        self.net_metrics.oversized_frames.inc();
+        let count = self.oversized_frames.entry(peer_id.clone()).or_default();
+        *count += 1;
+        warn!(our_id=%self.our_id, %peer_id, count=*count, "peer sent an oversized frame");
+        if *count < self.cfg.max_oversized_frames {
+            return false;
+        }
+        let _ = self.oversized_frames.remove(peer_id);
+        true
+    }
+
+    /// Closes one of two connections to a peer using a single connection, if both exist.
+    ///
+    /// The connection initiated by the node with the lower node ID is kept, which is the same one
+    /// the peer keeps.
+    fn drop_redundant_connection(&mut self, peer_id: &NodeId) {
+        if !self.single_connection_peers.contains_key(peer_id)
+            || !self.incoming.contains_key(peer_id)
+            || !self.outgoing.contains_key(peer_id)
+        {
+            return;
+        }
+
+        // Dropping the sender closes the connection's sink, and in turn the peer's reader.
+        if keep_outgoing_connection(&self.our_id, peer_id) {
+            debug!(our_id=%self.our_id, %peer_id, "closing redundant incoming connection");
+            let _ = self.incoming.remove(peer_id);
+            self.net_metrics
+                .open_connections
+                .set(self.incoming.len() as i64);
+        } else {
+            debug!(our_id=%self.our_id, %peer_id, "closing redundant outgoing connection");
+            let _ = self.outgoing.remove(peer_id);
+        }
+    }
+
+    /// Returns the optional protocol features we support, as configured.
+    fn our_features(&self) -> Features {
+        Features::empty()
+            .with(Features::COMPRESSION, self.cfg.compression_enabled)
+            .with(
+                Features::SINGLE_CONNECTION,
+                self.cfg.single_connection_enabled,
+            )
+    }
+
+    /// Creates the handshake sent as the first message on every connection.
+    fn create_handshake(&self) -> Message<P> {
+        self.chain_info
+            .create_handshake(self.public_address, self.our_features())
+    }
+
+    /// Records the wall-clock time a peer reported in its handshake, warning if either the peer's
+    /// clock or our own appears to be off.
+    fn record_peer_time(&mut self, peer_id: &NodeId, their_time: Timestamp) {
+        self.clock_drift
+            .record(peer_id.clone(), their_time, Timestamp::now());
+        if let Some(offset_millis) = self.clock_drift.peer_offset_millis(peer_id) {
+            if self.clock_drift.is_excessive(offset_millis) {
+                info!(
+                    our_id=%self.our_id,
+                    %peer_id,
+                    %offset_millis,
+                    "peer's clock differs from ours"
+                );
+            }
+        }
+        if let Some(drift_millis) = self.clock_drift.excessive_drift_millis() {
+            warn!(
+                our_id=%self.our_id,
+                %drift_millis,
+                "our clock appears to drift relative to our peers; check NTP synchronization"
+            );
+        }
+        self.update_clock_drift_metric();
+    }
+
+    /// Updates the clock drift metric with the current estimate.
+    fn update_clock_drift_metric(&self) {
+        self.net_metrics
+            .clock_drift_millis
+            .set(self.clock_drift.estimate_millis().unwrap_or_default());
+    }
+
     /// Gossips our public listening address, and schedules the next such gossip round.
     fn gossip_our_address(&mut self, effect_builder: EffectBuilder<REv>) -> Effects<Event<P>> {
         let our_address = GossipedAddress::new(self.public_address);
@@ -707,12 +1089,17 @@ where
 
     /// Marks connections as asymmetric (only incoming or only outgoing) and removes them if they
     /// pass the upper limit for this. Connections that are symmetrical are reset to 0.
+    ///
+    /// Peers using a single connection are exempt.
     fn enforce_symmetric_connections(
         &mut self,
         effect_builder: EffectBuilder<REv>,
     ) -> Effects<Event<P>> {
         let mut remove = Vec::new();
         for (node_id, conn) in self.incoming.iter_mut() {
+            if self.single_connection_peers.contains_key(node_id) {
+                continue;
+            }
             if !self.outgoing.contains_key(node_id) {
                 if conn.times_seen_asymmetric >= MAX_ASYMMETRIC_CONNECTION_SEEN {
                     remove.push(node_id.clone());
@@ -724,6 +1111,9 @@ where
             }
         }
         for (node_id, conn) in self.outgoing.iter_mut() {
+            if self.single_connection_peers.contains_key(node_id) {
+                continue;
+            }
             if !self.incoming.contains_key(node_id) {
                 if conn.times_seen_asymmetric >= MAX_ASYMMETRIC_CONNECTION_SEEN {
                     remove.push(node_id.clone());
@@ -751,12 +1141,16 @@ where
     where
         REv: From<NetworkAnnouncement<NodeId, P>>,
     {
+        let advertised_features = msg.advertised_features().unwrap_or_default();
         match msg {
             Message::Handshake {
                 network_name,
                 public_address,
                 protocol_version,
+                timestamp,
+                ..
             } => {
+                let public_address = utils::canonical_address(public_address);
                 if network_name != self.chain_info.network_name {
                     info!(
                         our_id=%self.our_id,
@@ -772,8 +1166,53 @@ where
                     return remove;
                 }
 
+                if let Some(timestamp) = timestamp {
+                    self.record_peer_time(&peer_id, timestamp);
+                }
+
+                let features = self.our_features().negotiate(advertised_features);
+                debug!(
+                    our_id=%self.our_id,
+                    %peer_id,
+                    %advertised_features,
+                    negotiated_features=%features,
+                    "negotiated features"
+                );
+                if let Some(incoming) = self.incoming.get_mut(&peer_id) {
+                    incoming.features = features;
+                    if features.contains(Features::COMPRESSION) {
+                        incoming
+                            .peer_supports_compression
+                            .store(true, Ordering::SeqCst);
+                    }
+                }
+                if let Some(outgoing) = self.outgoing.get_mut(&peer_id) {
+                    outgoing.features = features;
+                }
+
+                let mut effects = Effects::new();
+                if features.contains(Features::SINGLE_CONNECTION)
+                    && (self.incoming.contains_key(&peer_id)
+                        || self.outgoing.contains_key(&peer_id))
+                    && self
+                        .single_connection_peers
+                        .insert(peer_id.clone(), public_address)
+                        .is_none()
+                {
+                    debug!(our_id=%self.our_id, %peer_id, "peer uses a single connection");
+                    // Unless already announced as having connected both ways, the peer is now
+                    // complete.
+                    let announced = self.incoming.contains_key(&peer_id)
+                        && self.outgoing.contains_key(&peer_id);
+                    self.drop_redundant_connection(&peer_id);
+                    if !announced {
+                        debug!(%peer_id, "connection to peer is now complete");
+                        effects.extend(effect_builder.announce_new_peer(peer_id).ignore());
+                    }
+                }
+
                 // This speeds up the connection process, but masks potential bugs in the gossiper.
-                let effects = self.connect_to_peer_if_required(public_address);
+                effects.extend(self.connect_to_peer_if_required(public_address));
                 self.update_peers_metric();
 
                 effects
@@ -781,25 +1220,81 @@ where
             Message::Payload(payload) => effect_builder
                 .announce_message_received(peer_id, payload)
                 .ignore(),
+            Message::Goodbye { reason } => {
+                let peer_address = self
+                    .outgoing
+                    .get(&peer_id)
+                    .map(|outgoing| outgoing.peer_address)
+                    .or_else(|| self.single_connection_peers.get(&peer_id).copied());
+                if let Some(peer_address) = peer_address {
+                    let until = Timestamp::now() + self.cfg.goodbye_cooldown;
+                    info!(
+                        our_id=%self.our_id,
+                        %peer_id,
+                        %reason,
+                        %until,
+                        "peer said goodbye, suppressing reconnection attempts"
+                    );
+                    self.goodbye_cooldown.insert(peer_address, until);
+                }
+                let remove = self.remove(effect_builder, &peer_id, false);
+                self.update_peers_metric();
+                remove
+            }
         }
     }
 
+    /// Constructs a new framed transport on a stream.
+    ///
+    /// Outgoing messages are only compressed once `peer_supports_compression` is set.
+    fn framed(
+        &self,
+        stream: Transport,
+        peer_supports_compression: Arc<AtomicBool>,
+    ) -> FramedTransport<P> {
+        let metrics = CompressionMetrics {
+            bytes_before_compression: self.net_metrics.bytes_before_compression.clone(),
+            bytes_after_compression: self.net_metrics.bytes_after_compression.clone(),
+            bytes_decompressed: self.net_metrics.bytes_decompressed.clone(),
+        };
+        let codec = CompressingCodec::new(
+            self.chain_info.maximum_net_message_size as usize,
+            self.cfg.max_incoming_frame_length as usize,
+            self.cfg.compression_threshold as usize,
+            self.cfg.compression_level,
+            peer_supports_compression,
+            metrics,
+        );
+        SymmetricallyFramed::new(
+            Framed::new(stream, codec),
+            SymmetricalMessagePack::<Message<P>>::default(),
+        )
+    }
+
     fn update_peers_metric(&mut self) {
         self.net_metrics.peers.set(self.peers().len() as i64);
     }
 
     fn connect_to_peer_if_required(&mut self, peer_address: SocketAddr) -> Effects<Event<P>> {
+        let current_time = Timestamp::now();
         self.blocklist
-            .retain(|_, ts| *ts > Timestamp::now() - *BLOCKLIST_RETAIN_DURATION);
+            .retain(|_, ts| *ts > current_time - *BLOCKLIST_RETAIN_DURATION);
+        self.goodbye_cooldown
+            .retain(|_, until| *until > current_time);
         if self.pending.contains_key(&peer_address)
             || self.blocklist.contains_key(&peer_address)
+            || self.goodbye_cooldown.contains_key(&peer_address)
             || self
                 .outgoing
                 .iter()
                 .any(|(_peer_id, connection)| connection.peer_address == peer_address)
+            || self
+                .single_connection_peers
+                .values()
+                .any(|&public_address| public_address == peer_address)
         {
             // We're already trying to connect, are connected, or the connection is on the blocklist
-            // - do nothing.
+            // or cooling down after a goodbye - do nothing.
             Effects::new()
         } else {
             // We need to connect.
@@ -828,13 +1323,18 @@ where
     /// Checks whether a connection has been established fully, i.e. with an incoming and outgoing
     /// connection.
     ///
+    /// Peers using a single connection are instead announced once their handshake is received.
+    ///
     /// Returns either no effect or an announcement that a new peer has connected.
     fn check_connection_complete(
         &self,
         effect_builder: EffectBuilder<REv>,
         peer_id: NodeId,
     ) -> Effects<Event<P>> {
-        if self.outgoing.contains_key(&peer_id) && self.incoming.contains_key(&peer_id) {
+        if !self.single_connection_peers.contains_key(&peer_id)
+            && self.outgoing.contains_key(&peer_id)
+            && self.incoming.contains_key(&peer_id)
+        {
             debug!(%peer_id, "connection to peer is now complete");
             effect_builder.announce_new_peer(peer_id).ignore()
         } else {
@@ -871,6 +1371,14 @@ where
         ret
     }
 
+    /// Sets the reason given to peers in a goodbye message when this component is finalized.
+    ///
+    /// `None` means no goodbye message is sent, e.g. when the node keeps running with a new
+    /// instance of this component.
+    pub(crate) fn set_goodbye_reason(&mut self, goodbye_reason: Option<GoodbyeReason>) {
+        self.goodbye_reason = goodbye_reason;
+    }
+
     /// Returns whether or not this node has been disconnected from all known nodes.
     fn is_not_connected_to_any_known_address(&self) -> bool {
         for &known_address in &self.known_addresses {
@@ -882,6 +1390,10 @@ where
                 .outgoing
                 .values()
                 .any(|outgoing_connection| outgoing_connection.peer_address == known_address)
+                || self
+                    .single_connection_peers
+                    .values()
+                    .any(|&public_address| public_address == known_address)
             {
                 return false;
             }
@@ -904,6 +1416,26 @@ where
     P: Send + 'static,
 {
     fn finalize(mut self) -> BoxFuture<'static, ()> {
+        // Let our peers know we are going away, so they don't keep trying to reconnect.
+        if let Some(reason) = self.goodbye_reason {
+            let single_connection_senders = self
+                .incoming
+                .iter()
+                .filter(|(peer_id, _)| {
+                    !self.outgoing.contains_key(peer_id)
+                        && self.single_connection_peers.contains_key(peer_id)
+                })
+                .map(|(_, connection)| &connection.sender);
+            for sender in self
+                .outgoing
+                .values()
+                .map(|connection| &connection.sender)
+                .chain(single_connection_senders)
+            {
+                let _ = sender.send(Message::Goodbye { reason });
+            }
+        }
+
         async move {
             // Close the shutdown socket, causing the server to exit.
             drop(self.shutdown_sender.take());
@@ -977,15 +1509,35 @@ where
                 peer_id,
                 peer_address,
             } => {
-                match result {
+                let blocklist = match result {
                     Ok(()) => {
-                        info!(our_id=%self.our_id, %peer_id, %peer_address, "connection closed",)
+                        info!(our_id=%self.our_id, %peer_id, %peer_address, "connection closed",);
+                        false
                     }
                     Err(err) => {
-                        warn!(our_id=%self.our_id, %peer_id, %peer_address, %err, "connection dropped")
+                        warn!(our_id=%self.our_id, %peer_id, %peer_address, %err, "connection dropped");
+                        OversizedFrame::is_cause_of(&err) && self.record_oversized_frame(&peer_id)
+                    }
+                };
+                if blocklist {
+                    self.remove(effect_builder, &peer_id, true)
+                } else if self.single_connection_peers.contains_key(&peer_id) {
+                    // The closed connection may have been a redundant one, already replaced by the
+                    // connection we initiated.
+                    if self
+                        .incoming
+                        .get(&peer_id)
+                        .map_or(false, |incoming| incoming.peer_address == *peer_address)
+                    {
+                        let _ = self.incoming.remove(&peer_id);
+                        self.net_metrics
+                            .open_connections
+                            .set(self.incoming.len() as i64);
                     }
+                    self.remove_if_disconnected(effect_builder, &peer_id)
+                } else {
+                    self.remove(effect_builder, &peer_id, false)
                 }
-                self.remove(effect_builder, &peer_id, false)
             }
             Event::OutgoingEstablished { peer_id, transport } => {
                 self.setup_outgoing(effect_builder, *peer_id, transport)
@@ -1031,17 +1583,88 @@ where
                 NetworkInfoRequest::GetPeers { responder } => {
                     responder.respond(self.peers()).ignore()
                 }
+                NetworkInfoRequest::GetClockDrift { responder } => responder
+                    .respond(self.clock_drift.estimate_millis())
+                    .ignore(),
             },
             Event::GossipOurAddress => {
                 let mut effects = self.gossip_our_address(effect_builder);
                 effects.extend(self.enforce_symmetric_connections(effect_builder));
                 effects
             }
-            Event::PeerAddressReceived(gossiped_address) => {
-                self.connect_to_peer_if_required(gossiped_address.into())
+            Event::PeerAddressReceived {
+                gossiped_address,
+                sender,
+            } => self.connect_to_gossiped_address(gossiped_address.into(), *sender),
+            Event::ResolveKnownAddresses => self.resolve_known_addresses(),
+            Event::KnownAddressesResolved(addresses) => {
+                self.handle_known_addresses_resolved(effect_builder, *addresses)
+            }
+        }
+    }
+}
+
+impl<REv, P> HealthReport for SmallNetwork<REv, P>
+where
+    REv: 'static,
+{
+    fn health(&self) -> ComponentHealth {
+        if self.outgoing.is_empty() && self.incoming.is_empty() {
+            ComponentHealth::Degraded("not connected to any peers".to_string())
+        } else if let Some(drift_millis) = self.clock_drift.excessive_drift_millis() {
+            ComponentHealth::Degraded(format!(
+                "clock drifts by {} ms relative to peers",
+                drift_millis
+            ))
+        } else {
+            ComponentHealth::Healthy
+        }
+    }
+}
+
+/// Resolves the given known addresses, including every address a hostname resolves to.
+///
+/// Addresses failing resolution are skipped with a warning.
+fn resolve_known_addresses(addresses: &[String]) -> HashSet<SocketAddr> {
+    let mut known_addresses = HashSet::new();
+    for address in addresses {
+        match utils::resolve_addresses(address) {
+            Ok(resolved_addresses) => {
+                for known_address in resolved_addresses {
+                    let known_address = utils::canonical_address(known_address);
+                    if !known_addresses.insert(known_address) {
+                        debug!(%address, resolved=%known_address, "ignoring duplicated known address");
+                    }
+                }
+            }
+            Err(err) => {
+                warn!(%address, %err, "failed to resolve known address");
             }
         }
     }
+    known_addresses
+}
+
+/// Creates a listener bound to `address`.
+///
+/// If `address` is an IPv6 address, the listener also accepts IPv4 connections if `dual_stack` is
+/// set, regardless of the operating system's default.
+fn bind_listener(address: SocketAddr, dual_stack: bool) -> io::Result<TcpListener> {
+    let domain = if address.is_ipv4() {
+        Domain::ipv4()
+    } else {
+        Domain::ipv6()
+    };
+    let socket = Socket::new(domain, Type::stream(), Some(Protocol::tcp()))?;
+    if address.is_ipv6() {
+        socket.set_only_v6(!dual_stack)?;
+    }
+    // Matches `std::net::TcpListener::bind`, allowing a restarted node to rebind its port right
+    // away.
+    socket.set_reuse_address(true)?;
+    socket.bind(&address.into())?;
+    socket.listen(LISTEN_BACKLOG)?;
+    Ok(socket.into_tcp_listener())
 }
 
 /// Core accept loop for the networking server.
@@ -1071,7 +1694,7 @@ async fn server_task<P, REv>(
                     // Move the incoming connection to the event queue for handling.
                     let event = Event::IncomingNew {
                         stream,
-                        peer_address: Box::new(peer_address),
+                        peer_address: Box::new(utils::canonical_address(peer_address)),
                     };
                     event_queue
                         .schedule(event, QueueKind::NetworkIncoming)
@@ -1177,28 +1800,65 @@ async fn setup_tls(
     ))
 }
 
-/// Network handshake reader for single handshake message received by outgoing connection.
+/// Network handshake reader for the handshake message received by outgoing connection.
+///
+/// If compression is negotiated with the peer, `peer_supports_compression` is set, enabling
+/// compression of messages sent over the outgoing connection.
+///
+/// If both we and the peer use a single connection, all subsequent messages are read as well until
+/// the connection is closed, otherwise the stream is dropped after the handshake.
+#[allow(clippy::too_many_arguments)]
 async fn handshake_reader<REv, P>(
     event_queue: EventQueueHandle<REv>,
     mut stream: SplitStream<FramedTransport<P>>,
+    shutdown_receiver: watch::Receiver<()>,
     our_id: NodeId,
     peer_id: NodeId,
     peer_address: SocketAddr,
+    our_features: Features,
+    peer_supports_compression: Arc<AtomicBool>,
 ) where
     P: DeserializeOwned + Send + Display,
     REv: From<Event<P>>,
 {
     if let Some(Ok(msg @ Message::Handshake { .. })) = stream.next().await {
         debug!(%our_id, %msg, %peer_id, "handshake received");
-        return event_queue
+        let features = our_features.negotiate(msg.advertised_features().unwrap_or_default());
+        if features.contains(Features::COMPRESSION) {
+            peer_supports_compression.store(true, Ordering::SeqCst);
+        }
+        let single_connection = features.contains(Features::SINGLE_CONNECTION);
+        event_queue
             .schedule(
                 Event::IncomingMessage {
-                    peer_id: Box::new(peer_id),
+                    peer_id: Box::new(peer_id.clone()),
                     msg: Box::new(msg),
                 },
                 QueueKind::NetworkIncoming,
             )
             .await;
+        if !single_connection {
+            return;
+        }
+
+        let result = message_reader(
+            event_queue,
+            stream,
+            shutdown_receiver,
+            our_id,
+            peer_id.clone(),
+        )
+        .await;
+        return event_queue
+            .schedule(
+                Event::OutgoingFailed {
+                    peer_id: Box::new(Some(peer_id)),
+                    peer_address: Box::new(peer_address),
+                    error: Box::new(result.err().map(Error::MessageNotReceived)),
+                },
+                QueueKind::Network,
+            )
+            .await;
     }
     warn!(%our_id, %peer_id, "receiving handshake failed, closing connection");
     event_queue
@@ -1292,7 +1952,16 @@ where
         sink.send(payload).await.map_err(Error::MessageNotSent)?;
     }
 
-    Ok(())
+    // Shut the connection down, letting the peer's reader know we are done.
+    sink.close().await.map_err(Error::MessageNotSent)
+}
+
+/// Returns whether, of two connections between us and a peer using a single connection, the one we
+/// initiated is kept.
+///
+/// The connection initiated by the node with the lower node ID is kept, so both sides agree.
+fn keep_outgoing_connection(our_id: &NodeId, peer_id: &NodeId) -> bool {
+    our_id < peer_id
 }
 
 /// Transport type alias for base encrypted connections.
@@ -1300,25 +1969,11 @@ type Transport = SslStream<TcpStream>;
 
 /// A framed transport for `Message`s.
 type FramedTransport<P> = SymmetricallyFramed<
-    Framed<Transport, LengthDelimitedCodec>,
+    Framed<Transport, CompressingCodec>,
     Message<P>,
     SymmetricalMessagePack<Message<P>>,
 >;
 
-/// Constructs a new framed transport on a stream.
-fn framed<P>(stream: Transport, maximum_net_message_size: u32) -> FramedTransport<P> {
-    let length_delimited = Framed::new(
-        stream,
-        LengthDelimitedCodec::builder()
-            .max_frame_length(maximum_net_message_size as usize)
-            .new_codec(),
-    );
-    SymmetricallyFramed::new(
-        length_delimited,
-        SymmetricalMessagePack::<Message<P>>::default(),
-    )
-}
-
 /// Initiates a TLS connection to a remote address.
 async fn connect_outgoing(
     peer_address: SocketAddr,
@@ -1372,6 +2027,7 @@ where
             .field("event_queue", &"<event_queue>")
             .field("incoming", &self.incoming)
             .field("outgoing", &self.outgoing)
+            .field("single_connection_peers", &self.single_connection_peers)
             .field("pending", &self.pending)
             .finish()
     }
@@ -0,0 +1,251 @@
+//! Validation of peer addresses learned via gossip.
+//!
+//! Gossiped addresses are untrusted: a malicious peer could gossip the address of an arbitrary
+//! third party to have every node on the network dial it. To limit this, an address learned via
+//! gossip is dialed at most once per dial interval, the node ID found at a gossiped address is
+//! remembered and required to match on subsequent dials, and peers repeatedly gossiping addresses
+//! which turn out to be unreachable or held by a different node are reported for blocklisting.
+
+use std::{collections::HashMap, net::SocketAddr, time::Duration};
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+
+use crate::types::{NodeId, TimeDiff, Timestamp};
+
+/// How long the node ID found at a gossiped address is remembered after it was last confirmed.
+static IDENTITY_RETAIN_DURATION: Lazy<TimeDiff> = Lazy::new(|| Duration::from_secs(60 * 10).into());
+
+/// A dial of an address learned via gossip.
+#[derive(DataSize, Debug)]
+struct GossipedDial {
+    /// When the address was dialed.
+    dialed_at: Timestamp,
+    /// The peer which gossiped the address.
+    gossiped_by: NodeId,
+}
+
+/// The outcome of connecting to a gossiped address.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum IdentityCheck {
+    /// The connected node is the one previously found at the address, or the address was not
+    /// dialed because of gossip.
+    Accepted,
+    /// A different node was previously found at the address.
+    Mismatch {
+        /// The node previously found at the address.
+        expected: NodeId,
+        /// The peer which should be blocklisted for gossiping the address, if any.
+        penalize: Option<NodeId>,
+    },
+}
+
+/// Bookkeeping for dialing addresses learned via gossip.
+#[derive(DataSize, Debug)]
+pub(super) struct AddressValidator {
+    /// Minimum time between two dials of the same gossiped address.
+    dial_interval: TimeDiff,
+    /// Number of bad addresses a peer may gossip in a row before it is blocklisted.
+    max_bad_addresses: u32,
+    /// Gossiped addresses dialed within the last dial interval.
+    dials: HashMap<SocketAddr, GossipedDial>,
+    /// The node found at each gossiped address, and when that was last confirmed.
+    identities: HashMap<SocketAddr, (NodeId, Timestamp)>,
+    /// Number of bad addresses each peer has gossiped since its last good one.
+    bad_address_counts: HashMap<NodeId, u32>,
+}
+
+impl AddressValidator {
+    /// Creates a new validator.
+    pub(super) fn new(dial_interval: TimeDiff, max_bad_addresses: u32) -> Self {
+        AddressValidator {
+            dial_interval,
+            max_bad_addresses,
+            dials: HashMap::new(),
+            identities: HashMap::new(),
+            bad_address_counts: HashMap::new(),
+        }
+    }
+
+    /// Returns whether `address`, gossiped by a peer, may be dialed at `now`.
+    ///
+    /// Addresses dialed because of gossip within the last dial interval may not.
+    pub(super) fn may_dial(&mut self, address: SocketAddr, now: Timestamp) -> bool {
+        let dial_interval = self.dial_interval;
+        self.dials
+            .retain(|_, dial| dial.dialed_at + dial_interval > now);
+        !self.dials.contains_key(&address)
+    }
+
+    /// Records that `address`, gossiped by `gossiped_by`, was dialed at `now`.
+    pub(super) fn record_dial(&mut self, address: SocketAddr, gossiped_by: NodeId, now: Timestamp) {
+        let _ = self.dials.insert(
+            address,
+            GossipedDial {
+                dialed_at: now,
+                gossiped_by,
+            },
+        );
+    }
+
+    /// Checks the node found at `address` after successfully connecting to it at `now`.
+    ///
+    /// If the address was dialed because of gossip, the node must match the one previously found
+    /// there, if any.  Otherwise the node found is remembered.
+    pub(super) fn check_identity(
+        &mut self,
+        address: SocketAddr,
+        peer_id: &NodeId,
+        now: Timestamp,
+    ) -> IdentityCheck {
+        self.identities
+            .retain(|_, (_, confirmed_at)| *confirmed_at + *IDENTITY_RETAIN_DURATION > now);
+
+        if let Some(dial) = self.dials.get(&address) {
+            if let Some((expected, _)) = self.identities.get(&address) {
+                if expected != peer_id {
+                    let expected = expected.clone();
+                    let gossiped_by = dial.gossiped_by.clone();
+                    return IdentityCheck::Mismatch {
+                        expected,
+                        penalize: self.record_bad_address(gossiped_by),
+                    };
+                }
+            }
+            let _ = self.bad_address_counts.remove(&dial.gossiped_by);
+        }
+
+        let _ = self.identities.insert(address, (peer_id.clone(), now));
+        IdentityCheck::Accepted
+    }
+
+    /// Records that `address` could not be connected to.
+    ///
+    /// Returns the peer which should be blocklisted for gossiping it, if any.
+    pub(super) fn record_unreachable(&mut self, address: SocketAddr) -> Option<NodeId> {
+        let gossiped_by = self.dials.get(&address)?.gossiped_by.clone();
+        self.record_bad_address(gossiped_by)
+    }
+
+    /// Records that `gossiped_by` gossiped a bad address, returning it if it has now gossiped too
+    /// many in a row.
+    fn record_bad_address(&mut self, gossiped_by: NodeId) -> Option<NodeId> {
+        let count = self
+            .bad_address_counts
+            .entry(gossiped_by.clone())
+            .or_default();
+        *count += 1;
+        if *count < self.max_bad_addresses {
+            return None;
+        }
+        let _ = self.bad_address_counts.remove(&gossiped_by);
+        Some(gossiped_by)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRng;
+
+    const DIAL_INTERVAL_SECS: u32 = 60;
+    const MAX_BAD_ADDRESSES: u32 = 2;
+
+    fn validator() -> AddressValidator {
+        AddressValidator::new(
+            TimeDiff::from_seconds(DIAL_INTERVAL_SECS),
+            MAX_BAD_ADDRESSES,
+        )
+    }
+
+    fn address(port: u16) -> SocketAddr {
+        ([192, 0, 2, 1], port).into()
+    }
+
+    #[test]
+    fn should_rate_limit_dials_per_address() {
+        let mut validator = validator();
+        let mut rng = TestRng::new();
+        let gossiper = NodeId::random_tls(&mut rng);
+        let now = Timestamp::zero();
+
+        assert!(validator.may_dial(address(1), now));
+        validator.record_dial(address(1), gossiper, now);
+
+        let later = now + TimeDiff::from_seconds(DIAL_INTERVAL_SECS - 1);
+        assert!(!validator.may_dial(address(1), later));
+        assert!(validator.may_dial(address(2), later));
+
+        let after_interval = now + TimeDiff::from_seconds(DIAL_INTERVAL_SECS);
+        assert!(validator.may_dial(address(1), after_interval));
+    }
+
+    #[test]
+    fn should_penalize_peer_gossiping_unreachable_addresses() {
+        let mut validator = validator();
+        let mut rng = TestRng::new();
+        let gossiper = NodeId::random_tls(&mut rng);
+        let now = Timestamp::zero();
+
+        // Addresses not dialed because of gossip are nobody's fault.
+        assert_eq!(validator.record_unreachable(address(1)), None);
+
+        validator.record_dial(address(1), gossiper.clone(), now);
+        validator.record_dial(address(2), gossiper.clone(), now);
+        validator.record_dial(address(3), gossiper.clone(), now);
+        assert_eq!(validator.record_unreachable(address(1)), None);
+
+        // A reachable address resets the count.
+        let peer_id = NodeId::random_tls(&mut rng);
+        assert_eq!(
+            validator.check_identity(address(3), &peer_id, now),
+            IdentityCheck::Accepted
+        );
+        assert_eq!(validator.record_unreachable(address(2)), None);
+        assert_eq!(validator.record_unreachable(address(1)), Some(gossiper));
+    }
+
+    #[test]
+    fn should_require_identity_to_match_on_subsequent_gossip() {
+        let mut validator = validator();
+        let mut rng = TestRng::new();
+        let gossiper = NodeId::random_tls(&mut rng);
+        let peer_id = NodeId::random_tls(&mut rng);
+        let impostor_id = NodeId::random_tls(&mut rng);
+        let now = Timestamp::zero();
+
+        validator.record_dial(address(1), gossiper.clone(), now);
+        assert_eq!(
+            validator.check_identity(address(1), &peer_id, now),
+            IdentityCheck::Accepted
+        );
+        assert_eq!(
+            validator.check_identity(address(1), &peer_id, now),
+            IdentityCheck::Accepted
+        );
+
+        let mismatch = validator.check_identity(address(1), &impostor_id, now);
+        assert_eq!(
+            mismatch,
+            IdentityCheck::Mismatch {
+                expected: peer_id.clone(),
+                penalize: None,
+            }
+        );
+        assert_eq!(
+            validator.check_identity(address(1), &impostor_id, now),
+            IdentityCheck::Mismatch {
+                expected: peer_id,
+                penalize: Some(gossiper),
+            }
+        );
+
+        // The identity is forgotten once it has not been confirmed for long enough.
+        let much_later = now + *IDENTITY_RETAIN_DURATION;
+        validator.record_dial(address(1), NodeId::random_tls(&mut rng), much_later);
+        assert_eq!(
+            validator.check_identity(address(1), &impostor_id, much_later),
+            IdentityCheck::Accepted
+        );
+    }
+}
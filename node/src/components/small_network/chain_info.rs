@@ -8,8 +8,8 @@ use std::net::SocketAddr;
 use casper_types::ProtocolVersion;
 use datasize::DataSize;
 
-use super::Message;
-use crate::types::Chainspec;
+use super::{Features, Message};
+use crate::types::{Chainspec, Timestamp};
 
 /// Data retained from the chainspec by the small networking component.
 ///
@@ -37,11 +37,20 @@ impl ChainInfo {
     }
 
     /// Create a handshake based on chain identification data.
-    pub(super) fn create_handshake<P>(&self, public_address: SocketAddr) -> Message<P> {
+    pub(super) fn create_handshake<P>(
+        &self,
+        public_address: SocketAddr,
+        features: Features,
+    ) -> Message<P> {
+        // The dedicated flags are still set for peers predating the feature bitfield.
         Message::Handshake {
             network_name: self.network_name.clone(),
             public_address,
             protocol_version: self.protocol_version,
+            supports_compression: features.contains(Features::COMPRESSION),
+            supports_single_connection: features.contains(Features::SINGLE_CONNECTION),
+            timestamp: Some(Timestamp::now()),
+            features,
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Estimation of the drift of our wall clock relative to our peers.
+//!
+//! Consensus relies on the wall clocks of all validators roughly agreeing. Every handshake carries
+//! the sender's wall-clock time, from which we derive the offset of each connected peer's clock
+//! relative to ours. Taking the median offset over all peers gives an estimate of our own clock's
+//! drift which is robust against a minority of peers with broken clocks.
+
+use std::collections::HashMap;
+
+use datasize::DataSize;
+
+use crate::types::{NodeId, TimeDiff, Timestamp};
+
+/// The clock offsets of connected peers.
+#[derive(DataSize, Debug)]
+pub(super) struct ClockDrift {
+    /// Drift estimates with an absolute value above this are considered excessive.
+    warning_threshold: TimeDiff,
+    /// The offset in milliseconds of each peer's clock relative to ours, as of its handshake.
+    ///
+    /// Positive values mean the peer's clock is ahead of ours.
+    offsets: HashMap<NodeId, i64>,
+}
+
+impl ClockDrift {
+    /// Creates a new instance without any recorded offsets.
+    pub(super) fn new(warning_threshold: TimeDiff) -> Self {
+        ClockDrift {
+            warning_threshold,
+            offsets: HashMap::new(),
+        }
+    }
+
+    /// Records the wall-clock time a peer reported in its handshake, which we received at `now`.
+    pub(super) fn record(&mut self, peer_id: NodeId, their_time: Timestamp, now: Timestamp) {
+        let offset = (i128::from(their_time.millis()) - i128::from(now.millis()))
+            .max(i128::from(i64::min_value()))
+            .min(i128::from(i64::max_value())) as i64;
+        let _ = self.offsets.insert(peer_id, offset);
+    }
+
+    /// Forgets the offset of a disconnected peer.
+    pub(super) fn remove(&mut self, peer_id: &NodeId) {
+        let _ = self.offsets.remove(peer_id);
+    }
+
+    /// Returns the offset in milliseconds of the given peer's clock relative to ours.
+    pub(super) fn peer_offset_millis(&self, peer_id: &NodeId) -> Option<i64> {
+        self.offsets.get(peer_id).copied()
+    }
+
+    /// Returns the estimated drift in milliseconds of our clock relative to our peers, or `None`
+    /// if no peer has reported its time.
+    ///
+    /// Positive values mean our clock is ahead of our peers'.
+    pub(super) fn estimate_millis(&self) -> Option<i64> {
+        if self.offsets.is_empty() {
+            return None;
+        }
+        let mut offsets: Vec<i64> = self.offsets.values().copied().collect();
+        offsets.sort_unstable();
+        let middle = offsets.len() / 2;
+        let median = if offsets.len() % 2 == 0 {
+            ((i128::from(offsets[middle - 1]) + i128::from(offsets[middle])) / 2) as i64
+        } else {
+            offsets[middle]
+        };
+        Some(median.saturating_neg())
+    }
+
+    /// Returns whether an offset or drift in milliseconds exceeds the warning threshold.
+    pub(super) fn is_excessive(&self, millis: i64) -> bool {
+        millis.saturating_abs() as u64 > self.warning_threshold.millis()
+    }
+
+    /// Returns the estimated drift if it exceeds the warning threshold.
+    pub(super) fn excessive_drift_millis(&self) -> Option<i64> {
+        self.estimate_millis()
+            .filter(|&drift_millis| self.is_excessive(drift_millis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRng;
+
+    const THRESHOLD: TimeDiff = TimeDiff::from_seconds(5);
+
+    #[test]
+    fn should_have_no_estimate_without_peers() {
+        let clock_drift = ClockDrift::new(THRESHOLD);
+        assert_eq!(clock_drift.estimate_millis(), None);
+        assert_eq!(clock_drift.excessive_drift_millis(), None);
+    }
+
+    #[test]
+    fn should_estimate_drift_as_negated_median_offset() {
+        let mut rng = TestRng::new();
+        let mut clock_drift = ClockDrift::new(THRESHOLD);
+        let now = Timestamp::from(1_000_000);
+
+        // Our clock is ten seconds behind two of three peers, the third peer is way off.
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(1_010_000), now);
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(1_010_200), now);
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(10_000), now);
+        assert_eq!(clock_drift.estimate_millis(), Some(-10_000));
+        assert_eq!(clock_drift.excessive_drift_millis(), Some(-10_000));
+
+        // With an even number of peers the two middle offsets are averaged.
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(1_000_000), now);
+        assert_eq!(clock_drift.estimate_millis(), Some(-5_000));
+        assert_eq!(clock_drift.excessive_drift_millis(), None);
+    }
+
+    #[test]
+    fn should_not_overflow_on_extreme_peer_times() {
+        let mut rng = TestRng::new();
+        let mut clock_drift = ClockDrift::new(THRESHOLD);
+        let now = Timestamp::from(1_000_000);
+
+        clock_drift.record(
+            NodeId::random(&mut rng),
+            Timestamp::from(u64::max_value()),
+            now,
+        );
+        clock_drift.record(
+            NodeId::random(&mut rng),
+            Timestamp::from(u64::max_value()),
+            now,
+        );
+        assert_eq!(clock_drift.estimate_millis(), Some(-i64::max_value()));
+
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(0), now);
+        clock_drift.record(NodeId::random(&mut rng), Timestamp::from(0), now);
+        assert_eq!(
+            clock_drift.estimate_millis(),
+            Some(-((i64::max_value() - 1_000_000) / 2))
+        );
+    }
+
+    #[test]
+    fn should_forget_removed_peers() {
+        let mut rng = TestRng::new();
+        let mut clock_drift = ClockDrift::new(THRESHOLD);
+        let now = Timestamp::from(1_000_000);
+
+        let peer_id = NodeId::random(&mut rng);
+        clock_drift.record(peer_id.clone(), Timestamp::from(990_000), now);
+        assert_eq!(clock_drift.peer_offset_millis(&peer_id), Some(-10_000));
+        assert_eq!(clock_drift.excessive_drift_millis(), Some(10_000));
+
+        clock_drift.remove(&peer_id);
+        assert_eq!(clock_drift.peer_offset_millis(&peer_id), None);
+        assert_eq!(clock_drift.estimate_millis(), None);
+    }
+}
@@ -0,0 +1,276 @@
+//! Frame-level compression for the small network.
+//!
+//! Compression is applied on top of the length delimited framing: each frame is either a plain
+//! MessagePack-encoded message or a zstd frame containing one. The two are distinguished by the
+//! zstd magic number, which can never start a valid MessagePack encoding of a `Message`, so frames
+//! sent by peers not supporting compression are always decoded correctly.
+//!
+//! Outgoing frames are only compressed once the peer has advertised support for compression in its
+//! handshake and only if they exceed the configured threshold.
+//!
+//! Incoming frames are rejected with an [`OversizedFrame`] error as soon as their length header
+//! exceeds the maximum incoming frame length, before any space is allocated for them.
+
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use bytes::{Bytes, BytesMut};
+use prometheus::IntCounter;
+use thiserror::Error;
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec};
+
+/// The magic number at the start of every zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Size of the header preceding every frame, holding its length as a big-endian `u32`.
+const LENGTH_HEADER_SIZE: usize = 4;
+
+/// A peer sent a frame larger than the maximum incoming frame length.
+#[derive(Debug, Error)]
+#[error("received frame of {length} bytes, exceeding the maximum of {max_length} bytes")]
+pub(super) struct OversizedFrame {
+    /// The length declared in the frame's header.
+    length: usize,
+    /// The maximum incoming frame length.
+    max_length: usize,
+}
+
+impl OversizedFrame {
+    /// Returns whether `error` was caused by an oversized frame.
+    pub(super) fn is_cause_of(error: &io::Error) -> bool {
+        error
+            .get_ref()
+            .map_or(false, |inner| inner.is::<OversizedFrame>())
+    }
+}
+
+/// Counters tracking the effectiveness of compression.
+#[derive(Clone, Debug)]
+pub(super) struct CompressionMetrics {
+    /// Total size of outgoing frames before compression.
+    pub(super) bytes_before_compression: IntCounter,
+    /// Total size of outgoing frames after compression.
+    pub(super) bytes_after_compression: IntCounter,
+    /// Total size of incoming frames after decompression.
+    pub(super) bytes_decompressed: IntCounter,
+}
+
+/// A length delimited codec which compresses large outgoing frames and transparently decompresses
+/// compressed incoming frames.
+#[derive(Debug)]
+pub(super) struct CompressingCodec {
+    /// The underlying length delimited codec.
+    inner: LengthDelimitedCodec,
+    /// Maximum size of an incoming frame, both before and after decompression.
+    max_incoming_frame_length: usize,
+    /// Whether the length header of the next incoming frame has already been consumed.
+    reading_frame: bool,
+    /// Frames smaller than this are never compressed.
+    threshold: usize,
+    /// The zstd compression level.
+    level: i32,
+    /// Whether the peer supports compression. Set once its handshake has been received.
+    peer_supports_compression: Arc<AtomicBool>,
+    /// Compression metrics.
+    metrics: CompressionMetrics,
+}
+
+impl CompressingCodec {
+    /// Creates a new codec.
+    ///
+    /// Outgoing frames are limited to `max_frame_length`, incoming ones to the smaller of it and
+    /// `max_incoming_frame_length`.
+    pub(super) fn new(
+        max_frame_length: usize,
+        max_incoming_frame_length: usize,
+        threshold: usize,
+        level: i32,
+        peer_supports_compression: Arc<AtomicBool>,
+        metrics: CompressionMetrics,
+    ) -> Self {
+        CompressingCodec {
+            inner: LengthDelimitedCodec::builder()
+                .max_frame_length(max_frame_length)
+                .new_codec(),
+            max_incoming_frame_length: max_incoming_frame_length.min(max_frame_length),
+            reading_frame: false,
+            threshold,
+            level,
+            peer_supports_compression,
+            metrics,
+        }
+    }
+}
+
+impl Decoder for CompressingCodec {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<BytesMut>> {
+        if !self.reading_frame && src.len() >= LENGTH_HEADER_SIZE {
+            let mut header = [0; LENGTH_HEADER_SIZE];
+            header.copy_from_slice(&src[..LENGTH_HEADER_SIZE]);
+            let length = u32::from_be_bytes(header) as usize;
+            if length > self.max_incoming_frame_length {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    OversizedFrame {
+                        length,
+                        max_length: self.max_incoming_frame_length,
+                    },
+                ));
+            }
+        }
+
+        let remaining = src.len();
+        let frame = match self.inner.decode(src)? {
+            Some(frame) => frame,
+            None => {
+                // The inner codec consumes the header as soon as it is complete, even if the rest
+                // of the frame has yet to arrive.
+                self.reading_frame |= src.len() < remaining;
+                return Ok(None);
+            }
+        };
+        self.reading_frame = false;
+        if !frame.starts_with(&ZSTD_MAGIC) {
+            return Ok(Some(frame));
+        }
+        let decompressed = zstd::block::decompress(&frame, self.max_incoming_frame_length)?;
+        self.metrics
+            .bytes_decompressed
+            .inc_by(decompressed.len() as i64);
+        Ok(Some(BytesMut::from(decompressed.as_slice())))
+    }
+}
+
+impl Encoder<Bytes> for CompressingCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        if item.len() < self.threshold || !self.peer_supports_compression.load(Ordering::SeqCst) {
+            return self.inner.encode(item, dst);
+        }
+        let compressed = zstd::block::compress(&item, self.level)?;
+        self.metrics
+            .bytes_before_compression
+            .inc_by(item.len() as i64);
+        self.metrics
+            .bytes_after_compression
+            .inc_by(compressed.len() as i64);
+        self.inner.encode(Bytes::from(compressed), dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::IntCounter;
+
+    use super::*;
+
+    fn metrics() -> CompressionMetrics {
+        CompressionMetrics {
+            bytes_before_compression: IntCounter::new("before", "before").unwrap(),
+            bytes_after_compression: IntCounter::new("after", "after").unwrap(),
+            bytes_decompressed: IntCounter::new("decompressed", "decompressed").unwrap(),
+        }
+    }
+
+    fn codec(peer_supports_compression: bool) -> CompressingCodec {
+        CompressingCodec::new(
+            1024 * 1024,
+            1024 * 1024,
+            64,
+            3,
+            Arc::new(AtomicBool::new(peer_supports_compression)),
+            metrics(),
+        )
+    }
+
+    fn roundtrip(encoder: &mut CompressingCodec, payload: &[u8]) -> (usize, BytesMut) {
+        let mut buffer = BytesMut::new();
+        encoder
+            .encode(Bytes::copy_from_slice(payload), &mut buffer)
+            .unwrap();
+        let encoded_len = buffer.len();
+        let decoded = codec(false).decode(&mut buffer).unwrap().unwrap();
+        (encoded_len, decoded)
+    }
+
+    #[test]
+    fn should_compress_large_frames_if_supported() {
+        let payload = vec![7u8; 4096];
+        let mut encoder = codec(true);
+        let (encoded_len, decoded) = roundtrip(&mut encoder, &payload);
+        assert!(encoded_len < payload.len());
+        assert_eq!(&decoded[..], &payload[..]);
+        assert_eq!(encoder.metrics.bytes_before_compression.get(), 4096);
+    }
+
+    #[test]
+    fn should_not_compress_if_unsupported_or_small() {
+        let payload = vec![7u8; 4096];
+        let (encoded_len, decoded) = roundtrip(&mut codec(false), &payload);
+        assert!(encoded_len > payload.len());
+        assert_eq!(&decoded[..], &payload[..]);
+
+        let payload = vec![7u8; 16];
+        let (encoded_len, decoded) = roundtrip(&mut codec(true), &payload);
+        assert!(encoded_len > payload.len());
+        assert_eq!(&decoded[..], &payload[..]);
+    }
+
+    #[test]
+    fn should_reject_oversized_incoming_frames() {
+        let mut decoder = CompressingCodec::new(
+            1024 * 1024,
+            1024,
+            64,
+            3,
+            Arc::new(AtomicBool::new(false)),
+            metrics(),
+        );
+
+        let mut buffer = BytesMut::new();
+        codec(false)
+            .encode(Bytes::from(vec![7u8; 1025]), &mut buffer)
+            .unwrap();
+        let error = decoder.decode(&mut buffer.split_to(4)).unwrap_err();
+        assert!(OversizedFrame::is_cause_of(&error));
+
+        let error = io::Error::new(io::ErrorKind::InvalidData, "some other error");
+        assert!(!OversizedFrame::is_cause_of(&error));
+    }
+
+    #[test]
+    fn should_accept_frames_arriving_in_pieces() {
+        let mut decoder = CompressingCodec::new(
+            1024 * 1024,
+            1024,
+            64,
+            3,
+            Arc::new(AtomicBool::new(false)),
+            metrics(),
+        );
+
+        // A payload whose bytes would be read as an oversized length if mistaken for a header.
+        let payload = vec![0xffu8; 1024];
+        let mut encoded = BytesMut::new();
+        codec(false)
+            .encode(Bytes::copy_from_slice(&payload), &mut encoded)
+            .unwrap();
+
+        let mut buffer = encoded.split_to(6);
+        assert!(decoder.decode(&mut buffer).unwrap().is_none());
+        buffer.extend_from_slice(&encoded.split_to(8));
+        assert!(decoder.decode(&mut buffer).unwrap().is_none());
+        buffer.extend_from_slice(&encoded);
+        let decoded = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(&decoded[..], &payload[..]);
+    }
+}
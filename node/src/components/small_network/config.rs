@@ -1,6 +1,6 @@
 #[cfg(test)]
-use std::net::{Ipv4Addr, SocketAddr};
-use std::time::Duration;
+use std::net::Ipv4Addr;
+use std::{net::SocketAddr, time::Duration};
 
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
@@ -20,6 +20,90 @@ const DEFAULT_PUBLIC_ADDRESS: &str = "127.0.0.1:0";
 /// Default interval for gossiping network addresses.
 const DEFAULT_GOSSIP_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Default size in bytes above which outgoing messages are compressed.
+fn default_compression_threshold() -> u32 {
+    64 * 1024
+}
+
+/// Default zstd compression level.
+fn default_compression_level() -> i32 {
+    3
+}
+
+/// Default time during which we don't reconnect to a peer that said goodbye.
+fn default_goodbye_cooldown() -> TimeDiff {
+    TimeDiff::from_seconds(60)
+}
+
+/// Default interval between DNS re-resolutions of the known addresses.
+fn default_known_addresses_resolution_interval() -> TimeDiff {
+    TimeDiff::from_seconds(300)
+}
+
+/// Default for whether a single bidirectional connection is used per peer, if the peer supports it.
+fn default_single_connection_enabled() -> bool {
+    true
+}
+
+/// Default maximum size in bytes of a frame received from a peer.
+fn default_max_incoming_frame_length() -> u32 {
+    23_068_672
+}
+
+/// Default number of oversized frames a peer may send before it is blocklisted.
+fn default_max_oversized_frames() -> u32 {
+    3
+}
+
+/// Default minimum time between two dials of the same address learned via gossip.
+fn default_gossiped_address_dial_interval() -> TimeDiff {
+    TimeDiff::from_seconds(300)
+}
+
+/// Default number of bad addresses a peer may gossip in a row before it is blocklisted.
+fn default_max_bad_gossiped_addresses() -> u32 {
+    10
+}
+
+/// Default for whether a listener bound to an IPv6 address also accepts IPv4 connections.
+fn default_dual_stack() -> bool {
+    true
+}
+
+/// Default drift of our clock relative to our peers above which a warning is logged.
+fn default_clock_drift_warning_threshold() -> TimeDiff {
+    TimeDiff::from_seconds(5)
+}
+
+/// The IP address family preferred when a hostname resolves to addresses of both families.
+#[derive(DataSize, Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressFamily {
+    /// Use the first address the hostname resolves to.
+    Any,
+    /// Prefer IPv4 addresses.
+    Ipv4,
+    /// Prefer IPv6 addresses.
+    Ipv6,
+}
+
+impl AddressFamily {
+    /// Returns whether `address` is of the preferred family.
+    pub(super) fn is_preferred(self, address: &SocketAddr) -> bool {
+        match self {
+            AddressFamily::Any => true,
+            AddressFamily::Ipv4 => address.is_ipv4(),
+            AddressFamily::Ipv6 => address.is_ipv6(),
+        }
+    }
+}
+
+impl Default for AddressFamily {
+    fn default() -> Self {
+        AddressFamily::Any
+    }
+}
+
 // Default values for networking configuration:
 impl Default for Config {
     fn default() -> Self {
@@ -32,6 +116,19 @@ impl Default for Config {
             isolation_reconnect_delay: TimeDiff::from_seconds(2),
             initial_gossip_delay: TimeDiff::from_seconds(5),
             max_addr_pending_time: TimeDiff::from_seconds(60),
+            compression_enabled: false,
+            compression_threshold: default_compression_threshold(),
+            compression_level: default_compression_level(),
+            goodbye_cooldown: default_goodbye_cooldown(),
+            known_addresses_resolution_interval: default_known_addresses_resolution_interval(),
+            single_connection_enabled: default_single_connection_enabled(),
+            max_incoming_frame_length: default_max_incoming_frame_length(),
+            max_oversized_frames: default_max_oversized_frames(),
+            gossiped_address_dial_interval: default_gossiped_address_dial_interval(),
+            max_bad_gossiped_addresses: default_max_bad_gossiped_addresses(),
+            preferred_address_family: AddressFamily::default(),
+            dual_stack: default_dual_stack(),
+            clock_drift_warning_threshold: default_clock_drift_warning_threshold(),
         }
     }
 }
@@ -60,6 +157,60 @@ pub struct Config {
     pub initial_gossip_delay: TimeDiff,
     /// Maximum allowed time for an address to be kept in the pending set.
     pub max_addr_pending_time: TimeDiff,
+    /// Whether to advertise support for and use compression of large messages.
+    #[serde(default)]
+    pub compression_enabled: bool,
+    /// Size in bytes above which outgoing messages are compressed.
+    #[serde(default = "default_compression_threshold")]
+    pub compression_threshold: u32,
+    /// The zstd compression level.
+    #[serde(default = "default_compression_level")]
+    pub compression_level: i32,
+    /// How long to refrain from reconnecting to a peer after it announced it is disconnecting.
+    #[serde(default = "default_goodbye_cooldown")]
+    pub goodbye_cooldown: TimeDiff,
+    /// How often the known addresses are resolved again, picking up changes to their DNS records.
+    ///
+    /// A value of zero disables re-resolution.
+    #[serde(default = "default_known_addresses_resolution_interval")]
+    pub known_addresses_resolution_interval: TimeDiff,
+    /// Whether to advertise support for and use a single connection carrying messages in both
+    /// directions to peers which support it.
+    ///
+    /// Peers not supporting it are still connected to using one connection per direction.
+    #[serde(default = "default_single_connection_enabled")]
+    pub single_connection_enabled: bool,
+    /// Maximum size in bytes of a frame received from a peer.
+    ///
+    /// Frames are also limited to the chainspec's `maximum_net_message_size`.  The connection to a
+    /// peer sending a larger frame is closed without reading the frame.
+    #[serde(default = "default_max_incoming_frame_length")]
+    pub max_incoming_frame_length: u32,
+    /// Number of oversized frames a peer may send before it is blocklisted.
+    #[serde(default = "default_max_oversized_frames")]
+    pub max_oversized_frames: u32,
+    /// Minimum time between two dials of the same address learned via gossip.
+    #[serde(default = "default_gossiped_address_dial_interval")]
+    pub gossiped_address_dial_interval: TimeDiff,
+    /// Number of bad addresses a peer may gossip in a row before it is blocklisted.
+    ///
+    /// An address is bad if it cannot be connected to, or if it is held by a different node than
+    /// the one previously found there.
+    #[serde(default = "default_max_bad_gossiped_addresses")]
+    pub max_bad_gossiped_addresses: u32,
+    /// The IP address family preferred when the hostname of the bind or public address resolves
+    /// to addresses of both families.
+    #[serde(default)]
+    pub preferred_address_family: AddressFamily,
+    /// Whether a listener bound to an IPv6 address also accepts IPv4 connections.
+    ///
+    /// Only has an effect if the bind address is the unspecified IPv6 address `[::]`.
+    #[serde(default = "default_dual_stack")]
+    pub dual_stack: bool,
+    /// The drift of our clock relative to our peers, as estimated from the timestamps in their
+    /// handshakes, above which a warning is logged and the component reports itself degraded.
+    #[serde(default = "default_clock_drift_warning_threshold")]
+    pub clock_drift_warning_threshold: TimeDiff,
 }
 
 #[cfg(test)]
@@ -69,6 +69,13 @@ pub enum Error {
         #[source]
         io::Error,
     ),
+    /// Failed to receive message.
+    #[error("failed to receive message: {0}")]
+    MessageNotReceived(
+        #[serde(skip_serializing)]
+        #[source]
+        io::Error,
+    ),
     /// Failed to create TLS acceptor.
     #[error("failed to create acceptor")]
     AcceptorCreation(
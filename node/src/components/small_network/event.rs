@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fmt::{self, Debug, Display, Formatter},
     io, mem,
     net::SocketAddr,
@@ -79,7 +80,16 @@ pub enum Event<P> {
     /// The node should gossip its own public listening address.
     GossipOurAddress,
     /// We received a peer's public listening address via gossip.
-    PeerAddressReceived(GossipedAddress),
+    PeerAddressReceived {
+        /// The gossiped address.
+        gossiped_address: GossipedAddress,
+        /// The peer which gossiped the address to us.
+        sender: Box<NodeId>,
+    },
+    /// The known addresses from the config should be resolved again.
+    ResolveKnownAddresses,
+    /// The known addresses from the config have been resolved again.
+    KnownAddressesResolved(Box<HashSet<SocketAddr>>),
 }
 
 impl From<NetworkRequest<NodeId, ProtocolMessage>> for Event<ProtocolMessage> {
@@ -143,8 +153,17 @@ impl<P: Display> Display for Event<P> {
             Event::NetworkRequest { req } => write!(f, "request: {}", req),
             Event::NetworkInfoRequest { req } => write!(f, "request: {}", req),
             Event::GossipOurAddress => write!(f, "gossip our address"),
-            Event::PeerAddressReceived(gossiped_address) => {
-                write!(f, "received gossiped peer address {}", gossiped_address)
+            Event::PeerAddressReceived {
+                gossiped_address,
+                sender,
+            } => write!(
+                f,
+                "received gossiped peer address {} from {}",
+                gossiped_address, sender
+            ),
+            Event::ResolveKnownAddresses => write!(f, "resolve known addresses"),
+            Event::KnownAddressesResolved(addresses) => {
+                write!(f, "resolved {} known addresses", addresses.len())
             }
         }
     }
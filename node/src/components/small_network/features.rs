@@ -0,0 +1,141 @@
+//! Optional protocol features negotiated during the handshake.
+//!
+//! Each node advertises the features it supports as a bitfield in its handshake. A feature is only
+//! used on a connection if both sides advertise it. Bits not known to this version of the node are
+//! ignored, which allows newer nodes to advertise features older nodes have never heard of.
+
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// A set of optional protocol features, encoded as a bitfield.
+#[derive(Clone, Copy, DataSize, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Features(u64);
+
+impl Features {
+    /// Messages may be compressed.
+    pub(crate) const COMPRESSION: Features = Features(1 << 0);
+    /// Messages are multiplexed in both directions over a single connection.
+    pub(crate) const SINGLE_CONNECTION: Features = Features(1 << 1);
+
+    /// All features known to this version of the node, along with their names.
+    const KNOWN: &'static [(Features, &'static str)] = &[
+        (Features::COMPRESSION, "compression"),
+        (Features::SINGLE_CONNECTION, "single_connection"),
+    ];
+
+    /// Returns the empty set of features.
+    pub(crate) const fn empty() -> Self {
+        Features(0)
+    }
+
+    /// Creates a set of features from a raw bitfield.
+    #[cfg(test)]
+    pub(crate) const fn from_bits(bits: u64) -> Self {
+        Features(bits)
+    }
+
+    /// Returns the raw bitfield.
+    pub(crate) fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if all features in `other` are also in `self`.
+    pub(crate) fn contains(self, other: Features) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `self` with `feature` added if `enabled` is `true`.
+    pub(crate) fn with(self, feature: Features, enabled: bool) -> Self {
+        if enabled {
+            Features(self.0 | feature.0)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the features which are unknown to this version of the node.
+    pub(crate) fn unknown(self) -> Features {
+        let known = Features::KNOWN
+            .iter()
+            .fold(0, |bits, (feature, _)| bits | feature.0);
+        Features(self.0 & !known)
+    }
+
+    /// Returns the features usable on a connection where we support `self` and the peer advertised
+    /// `theirs`.
+    ///
+    /// Only features known to us and supported by both sides are negotiated.
+    pub(crate) fn negotiate(self, theirs: Features) -> Features {
+        let common = Features(self.0 & theirs.0);
+        Features(common.0 & !common.unknown().0)
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut names = Features::KNOWN
+            .iter()
+            .filter(|(feature, _)| self.contains(*feature))
+            .map(|(_, name)| *name)
+            .peekable();
+        write!(f, "[")?;
+        while let Some(name) = names.next() {
+            write!(f, "{}", name)?;
+            if names.peek().is_some() {
+                write!(f, ", ")?;
+            }
+        }
+        let unknown = self.unknown();
+        if unknown != Features::empty() {
+            write!(f, "; unknown: {:#x}", unknown.bits())?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Features;
+
+    #[test]
+    fn should_negotiate_common_features() {
+        let both = Features::empty()
+            .with(Features::COMPRESSION, true)
+            .with(Features::SINGLE_CONNECTION, true);
+        let compression_only = Features::empty().with(Features::COMPRESSION, true);
+        let single_connection_only = Features::empty().with(Features::SINGLE_CONNECTION, true);
+
+        assert_eq!(both.negotiate(both), both);
+        assert_eq!(both.negotiate(compression_only), compression_only);
+        assert_eq!(compression_only.negotiate(both), compression_only);
+        assert_eq!(
+            compression_only.negotiate(single_connection_only),
+            Features::empty()
+        );
+        assert_eq!(both.negotiate(Features::empty()), Features::empty());
+    }
+
+    #[test]
+    fn should_ignore_unknown_features() {
+        let future_feature = Features(1 << 40);
+        let ours = Features::empty().with(Features::COMPRESSION, true);
+        let theirs = Features(Features::COMPRESSION.bits() | future_feature.bits());
+
+        assert_eq!(theirs.unknown(), future_feature);
+        assert_eq!(ours.negotiate(theirs), Features::COMPRESSION);
+        // Even if we somehow advertised it ourselves, an unknown feature is never negotiated.
+        assert_eq!(theirs.negotiate(theirs), Features::COMPRESSION);
+    }
+
+    #[test]
+    fn should_display_known_and_unknown_features() {
+        assert_eq!(Features::empty().to_string(), "[]");
+        let features = Features(Features::COMPRESSION.bits() | Features::SINGLE_CONNECTION.bits());
+        assert_eq!(features.to_string(), "[compression, single_connection]");
+        let features = Features(Features::SINGLE_CONNECTION.bits() | 1 << 8);
+        assert_eq!(features.to_string(), "[single_connection; unknown: 0x100]");
+    }
+}
@@ -6,6 +6,9 @@ use std::{
 use casper_types::ProtocolVersion;
 use serde::{Deserialize, Serialize};
 
+use super::Features;
+use crate::types::Timestamp;
+
 /// The default protocol version to use in absence of one in the protocol version field.
 #[inline]
 fn default_protocol_version() -> ProtocolVersion {
@@ -22,8 +25,69 @@ pub enum Message<P> {
         /// Protocol version the node is speaking.
         #[serde(default = "default_protocol_version")]
         protocol_version: ProtocolVersion,
+        /// Whether the node accepts compressed messages.
+        #[serde(default)]
+        supports_compression: bool,
+        /// Whether the node multiplexes messages in both directions over a single connection.
+        #[serde(default)]
+        supports_single_connection: bool,
+        /// The wall-clock time at which the node sent the handshake.
+        #[serde(default)]
+        timestamp: Option<Timestamp>,
+        /// Optional protocol features supported by the node, possibly including ones unknown to
+        /// us.
+        #[serde(default)]
+        features: Features,
     },
     Payload(P),
+    /// Notification that the sender is about to close the connection.
+    Goodbye {
+        /// Why the connection is being closed.
+        reason: GoodbyeReason,
+    },
+}
+
+impl<P> Message<P> {
+    /// Returns the features advertised in a handshake, or `None` if this is not a handshake.
+    ///
+    /// Peers predating the feature bitfield only advertise compression and single connection
+    /// support via the dedicated flags, so these are folded into the returned set.
+    pub(super) fn advertised_features(&self) -> Option<Features> {
+        match self {
+            Message::Handshake {
+                supports_compression,
+                supports_single_connection,
+                features,
+                ..
+            } => Some(
+                features
+                    .with(Features::COMPRESSION, *supports_compression)
+                    .with(Features::SINGLE_CONNECTION, *supports_single_connection),
+            ),
+            Message::Payload(_) | Message::Goodbye { .. } => None,
+        }
+    }
+}
+
+/// The reason a node gives for disconnecting from a peer.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GoodbyeReason {
+    /// The node is shutting down.
+    Shutdown,
+    /// The node is shutting down to be upgraded.
+    Upgrade,
+    /// The node has blocklisted the peer.
+    Blocklisted,
+}
+
+impl Display for GoodbyeReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GoodbyeReason::Shutdown => write!(f, "shutdown"),
+            GoodbyeReason::Upgrade => write!(f, "upgrade"),
+            GoodbyeReason::Blocklisted => write!(f, "blocklisted"),
+        }
+    }
 }
 
 impl<P: Display> Display for Message<P> {
@@ -33,12 +97,29 @@ impl<P: Display> Display for Message<P> {
                 network_name,
                 public_address,
                 protocol_version,
-            } => write!(
-                f,
-                "handshake: {}, public addr: {}, protocol_version: {}",
-                network_name, public_address, protocol_version,
-            ),
+                supports_compression,
+                supports_single_connection,
+                timestamp,
+                features,
+            } => {
+                write!(
+                    f,
+                    "handshake: {}, public addr: {}, protocol_version: {}, \
+                    supports_compression: {}, supports_single_connection: {}, features: {}",
+                    network_name,
+                    public_address,
+                    protocol_version,
+                    supports_compression,
+                    supports_single_connection,
+                    features,
+                )?;
+                if let Some(timestamp) = timestamp {
+                    write!(f, ", timestamp: {}", timestamp)?;
+                }
+                Ok(())
+            }
             Message::Payload(payload) => write!(f, "payload: {}", payload),
+            Message::Goodbye { reason } => write!(f, "goodbye: {}", reason),
         }
     }
 }
@@ -52,9 +133,9 @@ mod tests {
     use casper_types::ProtocolVersion;
     use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-    use crate::protocol;
+    use crate::{protocol, types::Timestamp};
 
-    use super::Message;
+    use super::{Features, GoodbyeReason, Message};
 
     /// Version 1.0.0 network level message.
     ///
@@ -158,6 +239,10 @@ mod tests {
             network_name: "example-handshake".to_string(),
             public_address: ([12, 34, 56, 78], 12346).into(),
             protocol_version: ProtocolVersion::from_parts(5, 6, 7),
+            supports_compression: true,
+            supports_single_connection: true,
+            timestamp: Some(Timestamp::from(1_234_567)),
+            features: Features::COMPRESSION.with(Features::SINGLE_CONNECTION, true),
         };
 
         let legacy_handshake: V1_0_0_Message = roundtrip_message(&modern_handshake);
@@ -190,13 +275,24 @@ mod tests {
                 network_name,
                 public_address,
                 protocol_version,
+                supports_compression,
+                supports_single_connection,
+                timestamp,
+                features,
             } => {
                 assert_eq!(network_name, "example-handshake");
                 assert_eq!(public_address, ([12, 34, 56, 78], 12346).into());
                 assert_eq!(protocol_version, ProtocolVersion::V1_0_0);
+                assert!(!supports_compression);
+                assert!(!supports_single_connection);
+                assert!(timestamp.is_none());
+                assert_eq!(features, Features::empty());
             }
-            Message::Payload(_) => {
-                panic!("did not expect modern handshake to deserialize to payload")
+            other => {
+                panic!(
+                    "did not expect modern handshake to deserialize to {:?}",
+                    other
+                )
             }
         }
     }
@@ -210,14 +306,141 @@ mod tests {
                 network_name,
                 public_address,
                 protocol_version,
+                supports_compression,
+                supports_single_connection,
+                timestamp,
+                features,
             } => {
                 assert_eq!(network_name, "serialization-test");
                 assert_eq!(public_address, ([12, 34, 56, 78], 12346).into());
                 assert_eq!(protocol_version, ProtocolVersion::V1_0_0);
+                assert!(!supports_compression);
+                assert!(!supports_single_connection);
+                assert!(timestamp.is_none());
+                assert_eq!(features, Features::empty());
             }
-            Message::Payload(_) => {
-                panic!("did not expect modern handshake to deserialize to payload")
+            other => {
+                panic!(
+                    "did not expect modern handshake to deserialize to {:?}",
+                    other
+                )
             }
         }
     }
+
+    #[test]
+    fn current_handshake_roundtrips_capabilities() {
+        let handshake = Message::<protocol::Message>::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+            protocol_version: ProtocolVersion::from_parts(5, 6, 7),
+            supports_compression: false,
+            supports_single_connection: true,
+            timestamp: Some(Timestamp::from(1_234_567)),
+            features: Features::SINGLE_CONNECTION,
+        };
+
+        match roundtrip_message(&handshake) {
+            Message::<protocol::Message>::Handshake {
+                supports_compression,
+                supports_single_connection,
+                timestamp,
+                ..
+            } => {
+                assert!(!supports_compression);
+                assert!(supports_single_connection);
+                assert_eq!(timestamp, Some(Timestamp::from(1_234_567)));
+            }
+            other => panic!("did not expect handshake to deserialize to {:?}", other),
+        }
+    }
+
+    #[test]
+    fn handshake_preserves_unknown_features() {
+        let unknown_feature = 1 << 40;
+        let advertised = Features::from_bits(Features::COMPRESSION.bits() | unknown_feature);
+        let handshake = Message::<protocol::Message>::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+            protocol_version: ProtocolVersion::from_parts(5, 6, 7),
+            supports_compression: true,
+            supports_single_connection: false,
+            timestamp: None,
+            features: advertised,
+        };
+
+        let decoded: Message<protocol::Message> = roundtrip_message(&handshake);
+        let features = decoded
+            .advertised_features()
+            .expect("should be a handshake");
+        assert_eq!(features, advertised);
+        assert_eq!(features.unknown().bits(), unknown_feature);
+        assert_eq!(
+            Features::COMPRESSION
+                .with(Features::SINGLE_CONNECTION, true)
+                .negotiate(features),
+            Features::COMPRESSION
+        );
+    }
+
+    #[test]
+    fn advertised_features_include_legacy_flags() {
+        let legacy_handshake = V1_0_0_Message::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+        };
+        let decoded: Message<protocol::Message> = roundtrip_message(&legacy_handshake);
+        assert_eq!(decoded.advertised_features(), Some(Features::empty()));
+
+        let handshake = Message::<protocol::Message>::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+            protocol_version: ProtocolVersion::from_parts(1, 2, 0),
+            supports_compression: true,
+            supports_single_connection: true,
+            timestamp: None,
+            features: Features::empty(),
+        };
+        assert_eq!(
+            handshake.advertised_features(),
+            Some(Features::COMPRESSION.with(Features::SINGLE_CONNECTION, true))
+        );
+        assert_eq!(
+            Message::<protocol::Message>::Goodbye {
+                reason: GoodbyeReason::Shutdown
+            }
+            .advertised_features(),
+            None
+        );
+    }
+
+    #[test]
+    fn goodbye_roundtrips() {
+        for &reason in &[
+            GoodbyeReason::Shutdown,
+            GoodbyeReason::Upgrade,
+            GoodbyeReason::Blocklisted,
+        ] {
+            let goodbye = Message::<protocol::Message>::Goodbye { reason };
+
+            match roundtrip_message(&goodbye) {
+                Message::<protocol::Message>::Goodbye {
+                    reason: decoded_reason,
+                } => assert_eq!(decoded_reason, reason),
+                other => panic!("did not expect goodbye to deserialize to {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn goodbye_does_not_decode_as_v1_0_0_message() {
+        let goodbye = Message::<protocol::Message>::Goodbye {
+            reason: GoodbyeReason::Shutdown,
+        };
+        let serialized = serialize_message(&goodbye);
+
+        assert!(
+            rmp_serde::from_read::<_, V1_0_0_Message>(std::io::Cursor::new(&serialized)).is_err()
+        );
+    }
 }
@@ -7,6 +7,8 @@ use std::{
     collections::{HashMap, HashSet},
     env,
     fmt::{self, Debug, Display, Formatter},
+    net::{SocketAddr, TcpListener},
+    sync::atomic::Ordering,
     time::{Duration, Instant},
 };
 
@@ -18,7 +20,8 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use super::{
-    chain_info::ChainInfo, Config, Event as SmallNetworkEvent, GossipedAddress, SmallNetwork,
+    chain_info::ChainInfo, keep_outgoing_connection, resolve_known_addresses, AddressFamily,
+    Config, Event as SmallNetworkEvent, Features, GossipedAddress, SmallNetwork,
 };
 use crate::{
     components::{
@@ -29,7 +32,7 @@ use crate::{
     },
     effect::{
         announcements::{ControlAnnouncement, GossiperAnnouncement, NetworkAnnouncement},
-        requests::{NetworkRequest, StorageRequest},
+        requests::{NetworkInfoRequest, NetworkRequest, StorageRequest},
         EffectBuilder, Effects,
     },
     protocol,
@@ -40,7 +43,7 @@ use crate::{
         ConditionCheckReactor,
     },
     types::NodeId,
-    utils::Source,
+    utils::{self, Source},
     NodeRng,
 };
 
@@ -54,6 +57,8 @@ enum Event {
     #[from]
     NetworkRequest(#[serde(skip_serializing)] NetworkRequest<NodeId, Message>),
     #[from]
+    NetworkInfoRequest(#[serde(skip_serializing)] NetworkInfoRequest<NodeId>),
+    #[from]
     ControlAnnouncement(ControlAnnouncement),
     #[from]
     NetworkAnnouncement(#[serde(skip_serializing)] NetworkAnnouncement<NodeId, Message>),
@@ -178,6 +183,11 @@ impl Reactor for TestReactor {
                 rng,
                 Event::SmallNet(SmallNetworkEvent::from(req)),
             ),
+            Event::NetworkInfoRequest(req) => self.dispatch_event(
+                effect_builder,
+                rng,
+                Event::SmallNet(SmallNetworkEvent::NetworkInfoRequest { req: Box::new(req) }),
+            ),
             Event::ControlAnnouncement(ctrl_ann) => {
                 unreachable!("unhandled control announcement: {}", ctrl_ann)
             }
@@ -204,9 +214,14 @@ impl Reactor for TestReactor {
                 Effects::new()
             }
             Event::AddressGossiperAnnouncement(ann) => {
-                let GossiperAnnouncement::NewCompleteItem(gossiped_address) = ann;
-                let reactor_event =
-                    Event::SmallNet(SmallNetworkEvent::PeerAddressReceived(gossiped_address));
+                let GossiperAnnouncement::NewCompleteItem {
+                    item_id: gossiped_address,
+                    sender,
+                } = ann;
+                let reactor_event = Event::SmallNet(SmallNetworkEvent::PeerAddressReceived {
+                    gossiped_address,
+                    sender: Box::new(sender),
+                });
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
         }
@@ -257,8 +272,10 @@ fn network_is_complete(
         }
         let outgoing = net.outgoing.keys().collect::<HashSet<_>>();
         let incoming = net.incoming.keys().collect::<HashSet<_>>();
+        // Peers using a single connection are only connected to in one direction.
         let difference = incoming
             .symmetric_difference(&outgoing)
+            .filter(|peer_id| !net.single_connection_peers.contains_key(peer_id))
             .collect::<HashSet<_>>();
 
         // All nodes should be connected to every other node, except itself, so we add it to the
@@ -274,6 +291,15 @@ fn network_is_complete(
     true
 }
 
+/// Checks whether every node is connected to every other node.
+fn all_nodes_connected(
+    nodes: &HashMap<NodeId, Runner<ConditionCheckReactor<TestReactor>>>,
+) -> bool {
+    nodes
+        .values()
+        .all(|runner| runner.reactor().inner().net.peers().len() == nodes.len() - 1)
+}
+
 /// Checks whether or not a given network has at least one other node in it
 fn network_started(net: &Network<TestReactor>) -> bool {
     net.nodes()
@@ -450,3 +476,310 @@ async fn check_varying_size_network_connects() {
         net.finalize().await;
     }
 }
+
+/// Check that nodes using a single connection per peer and nodes using one connection per direction
+/// connect to each other, as is the case during a rollout.
+#[tokio::test]
+async fn check_mixed_single_connection_network_connects() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let mut net = Network::new();
+
+    let first_node_port = testing::unused_port_on_localhost();
+
+    let first_node_config = Config {
+        single_connection_enabled: false,
+        ..Config::default_local_net_first_node(first_node_port)
+    };
+    let _ = net
+        .add_node_with_config(first_node_config, &mut rng)
+        .await
+        .unwrap();
+
+    for single_connection_enabled in &[true, true, false, true] {
+        let config = Config {
+            single_connection_enabled: *single_connection_enabled,
+            ..Config::default_local_net(first_node_port)
+        };
+        net.add_node_with_config(config, &mut rng).await.unwrap();
+    }
+
+    let timeout = Duration::from_secs(15);
+    let blocklist = HashSet::new();
+    net.settle_on(
+        &mut rng,
+        |nodes| network_is_complete(&blocklist, nodes),
+        timeout,
+    )
+    .await;
+
+    for (node_id, runner) in net.nodes() {
+        let small_net = &runner.reactor().inner().net;
+        // Every peer must be reachable, with at most one connection per single connection peer.
+        assert_eq!(
+            small_net.connected_peers().count(),
+            small_net.peers().len(),
+            "{} cannot send to all its peers",
+            node_id
+        );
+        for peer_id in small_net.single_connection_peers.keys() {
+            assert!(
+                !(small_net.incoming.contains_key(peer_id)
+                    && small_net.outgoing.contains_key(peer_id)),
+                "{} kept two connections to {}",
+                node_id,
+                peer_id
+            );
+        }
+    }
+
+    net.finalize().await;
+}
+
+/// Checks whether every connection between the given nodes carries the features supported by both
+/// of its ends.
+fn features_are_negotiated(
+    nodes: &HashMap<NodeId, Runner<ConditionCheckReactor<TestReactor>>>,
+) -> bool {
+    let our_features = nodes
+        .iter()
+        .map(|(node_id, node)| (node_id, node.reactor().inner().net.our_features()))
+        .collect::<HashMap<_, _>>();
+
+    nodes.iter().all(|(node_id, node)| {
+        let net = &node.reactor().inner().net;
+        let expected = |peer_id: &NodeId| {
+            our_features
+                .get(peer_id)
+                .map(|theirs| our_features[node_id].negotiate(*theirs))
+        };
+        net.incoming
+            .iter()
+            .all(|(peer_id, incoming)| expected(peer_id) == Some(incoming.features))
+            && net
+                .outgoing
+                .iter()
+                .all(|(peer_id, outgoing)| expected(peer_id) == Some(outgoing.features))
+    })
+}
+
+/// Check that nodes with differing optional features connect to each other and only use the
+/// features supported by both ends of each connection.
+#[tokio::test]
+async fn check_mismatched_features_are_negotiated() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let mut net = Network::new();
+
+    let first_node_port = testing::unused_port_on_localhost();
+
+    let first_node_config = Config {
+        compression_enabled: true,
+        single_connection_enabled: false,
+        ..Config::default_local_net_first_node(first_node_port)
+    };
+    let _ = net
+        .add_node_with_config(first_node_config, &mut rng)
+        .await
+        .unwrap();
+
+    for &(compression_enabled, single_connection_enabled) in
+        &[(false, true), (true, true), (false, false)]
+    {
+        let config = Config {
+            compression_enabled,
+            single_connection_enabled,
+            ..Config::default_local_net(first_node_port)
+        };
+        net.add_node_with_config(config, &mut rng).await.unwrap();
+    }
+
+    let timeout = Duration::from_secs(15);
+    let blocklist = HashSet::new();
+    net.settle_on(
+        &mut rng,
+        |nodes| network_is_complete(&blocklist, nodes) && features_are_negotiated(nodes),
+        timeout,
+    )
+    .await;
+
+    for (node_id, runner) in net.nodes() {
+        let small_net = &runner.reactor().inner().net;
+        for (peer_id, incoming) in small_net.incoming.iter() {
+            assert_eq!(
+                incoming.features.contains(Features::COMPRESSION),
+                incoming.peer_supports_compression.load(Ordering::SeqCst),
+                "{} disagrees with itself on compression towards {}",
+                node_id,
+                peer_id
+            );
+        }
+    }
+
+    net.finalize().await;
+}
+
+/// Check that nodes listening on IPv4, IPv6 and both connect to each other.
+#[tokio::test]
+async fn check_mixed_address_family_network_connects() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    // Not every environment has IPv6 enabled.
+    if TcpListener::bind("[::1]:0").is_err() {
+        info!("IPv6 loopback unavailable, skipping mixed address family test");
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let mut net = Network::new();
+
+    let first_node_port = testing::unused_port_on_localhost();
+
+    // The first node listens on both families, but advertises its IPv6 address.
+    let first_node_config = Config {
+        bind_address: format!("[::]:{}", first_node_port),
+        public_address: format!("[::1]:{}", first_node_port),
+        known_addresses: vec![format!("[::1]:{}", first_node_port)],
+        ..Config::default_local_net_first_node(first_node_port)
+    };
+    let _ = net
+        .add_node_with_config(first_node_config, &mut rng)
+        .await
+        .unwrap();
+
+    // An IPv4-only node, joining via the IPv4 address of the first node.
+    net.add_node_with_config(Config::default_local_net(first_node_port), &mut rng)
+        .await
+        .unwrap();
+
+    // An IPv6-only node, joining via the IPv6 address of the first node.
+    let ipv6_node_config = Config {
+        bind_address: "[::1]:0".to_string(),
+        public_address: "[::1]:0".to_string(),
+        known_addresses: vec![format!("[::1]:{}", first_node_port)],
+        dual_stack: false,
+        ..Config::default_local_net(first_node_port)
+    };
+    net.add_node_with_config(ipv6_node_config, &mut rng)
+        .await
+        .unwrap();
+
+    // Every node must learn of the other two via gossip, so wait for a full mesh rather than for
+    // the connections to merely be symmetric.
+    let timeout = Duration::from_secs(15);
+    net.settle_on(&mut rng, |nodes| all_nodes_connected(nodes), timeout)
+        .await;
+
+    for (node_id, runner) in net.nodes() {
+        let small_net = &runner.reactor().inner().net;
+        assert_eq!(
+            small_net.peers().len(),
+            2,
+            "{} is not connected to both other nodes",
+            node_id
+        );
+    }
+
+    net.finalize().await;
+}
+
+#[test]
+fn should_canonicalize_addresses() {
+    let canonical = |address: &str| utils::canonical_address(address.parse().unwrap());
+
+    // IPv4 peers of a dual-stack listener are reported as IPv4-mapped IPv6 addresses.
+    assert_eq!(
+        canonical("[::ffff:192.0.2.1]:34553"),
+        "192.0.2.1:34553".parse::<SocketAddr>().unwrap()
+    );
+    assert_eq!(
+        canonical("192.0.2.1:34553"),
+        "192.0.2.1:34553".parse::<SocketAddr>().unwrap()
+    );
+    assert_eq!(
+        canonical("[2001:db8::1]:34553"),
+        "[2001:db8::1]:34553".parse::<SocketAddr>().unwrap()
+    );
+
+    // IPv6 addresses are gossiped in bracketed form, as expected when parsing them.
+    let gossiped_address = GossipedAddress::new(canonical("[2001:db8::1]:34553"));
+    assert_eq!(
+        gossiped_address.to_string(),
+        "gossiped-address [2001:db8::1]:34553"
+    );
+}
+
+#[test]
+fn should_prefer_configured_address_family() {
+    let ipv4: SocketAddr = "192.0.2.1:34553".parse().unwrap();
+    let ipv6: SocketAddr = "[2001:db8::1]:34553".parse().unwrap();
+
+    assert!(AddressFamily::Any.is_preferred(&ipv4));
+    assert!(AddressFamily::Any.is_preferred(&ipv6));
+    assert!(AddressFamily::Ipv4.is_preferred(&ipv4));
+    assert!(!AddressFamily::Ipv4.is_preferred(&ipv6));
+    assert!(AddressFamily::Ipv6.is_preferred(&ipv6));
+    assert!(!AddressFamily::Ipv6.is_preferred(&ipv4));
+
+    // Without an address of the preferred family, the first address resolved is used.
+    let resolved = utils::resolve_address_preferring("[2001:db8::1]:34553", |address| {
+        AddressFamily::Ipv4.is_preferred(address)
+    })
+    .unwrap();
+    assert_eq!(resolved, ipv6);
+}
+
+#[test]
+fn should_agree_on_connection_to_keep() {
+    let mut rng = crate::new_rng();
+
+    let first = NodeId::random_tls(&mut rng);
+    let second = NodeId::random_tls(&mut rng);
+    assert_ne!(first, second);
+
+    // Exactly one side keeps the connection it initiated.
+    assert_ne!(
+        keep_outgoing_connection(&first, &second),
+        keep_outgoing_connection(&second, &first)
+    );
+}
+
+#[test]
+fn should_skip_unresolvable_and_duplicated_known_addresses() {
+    let addresses = vec![
+        "127.0.0.1:34553".to_string(),
+        "127.0.0.2:34553".to_string(),
+        "127.0.0.1:34553".to_string(),
+        "not a valid address".to_string(),
+    ];
+
+    let resolved = resolve_known_addresses(&addresses);
+
+    let expected: HashSet<SocketAddr> = vec![
+        "127.0.0.1:34553".parse().unwrap(),
+        "127.0.0.2:34553".parse().unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(resolved, expected);
+}
@@ -7,7 +7,9 @@
 //! * storing and loading deploys,
 //! * [temporary until refactored] holding `DeployMetadata` for each deploy,
 //! * holding a read-only copy of the chainspec,
-//! * keeping an index of blocks by height and
+//! * keeping an index of blocks by height,
+//! * keeping indices of deploys by account and timestamp, and by the block they were executed in,
+//!   and
 //! * [unimplemented] managing disk usage by pruning blocks and deploys from storage.
 //!
 //! Any I/O performed by the component is done on the event handling thread, this is on purpose as
@@ -28,8 +30,12 @@
 //!
 //! ## Indices
 //!
-//! The current implementation keeps only in-memory indices, which are not persisted, based upon the
-//! estimate that they are reasonably quick to rebuild on start-up and do not take up much memory.
+//! Block indices are kept in memory only and are not persisted, based upon the estimate that they
+//! are reasonably quick to rebuild on start-up and do not take up much memory.
+//!
+//! Deploy indices grow with the number of deploys and are therefore persisted in their own LMDB
+//! databases, maintained in the same transaction as the data they index.  If they are found empty
+//! on start-up, e.g. when opening a database created by an older version, they are rebuilt.
 //!
 //! ## Errors
 //!
@@ -41,9 +47,10 @@ mod lmdb_ext;
 mod tests;
 
 #[cfg(test)]
-use std::{collections::BTreeSet, convert::TryFrom};
+use std::collections::BTreeSet;
 use std::{
     collections::{btree_map::Entry, BTreeMap},
+    convert::{TryFrom, TryInto},
     fmt::{self, Display, Formatter},
     fs, io, mem,
     path::PathBuf,
@@ -62,11 +69,10 @@ use tempfile::TempDir;
 use thiserror::Error;
 use tracing::{debug, error, info};
 
-use super::Component;
-#[cfg(test)]
-use crate::crypto::hash::Digest;
+use super::{Component, HealthReport};
 use crate::{
     components::consensus::EraId,
+    crypto::hash::Digest,
     effect::{
         requests::{StateStoreRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
@@ -74,14 +80,17 @@ use crate::{
     fatal,
     reactor::ReactorEvent,
     types::{
-        Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash,
-        DeployHeader, DeployMetadata, TimeDiff,
+        Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, ComponentHealth, Deploy,
+        DeployHash, DeployHeader, DeployMetadata, TimeDiff, Timestamp,
     },
     utils::WithDir,
     NodeRng,
 };
 use casper_execution_engine::shared::newtypes::Blake2bHash;
-use casper_types::{ExecutionResult, ProtocolVersion, Transfer, Transform};
+use casper_types::{
+    account::{AccountHash, ACCOUNT_HASH_LENGTH},
+    ExecutionResult, ProtocolVersion, Transfer, Transform,
+};
 use lmdb_ext::{LmdbExtError, TransactionExt, WriteTransactionExt};
 
 /// Filename for the LMDB database created by the Storage component.
@@ -103,7 +112,13 @@ const DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE: usize = 300 * GIB;
 /// Default max state store size.
 const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
 /// Maximum number of allowed dbs.
-const MAX_DB_COUNT: u32 = 7;
+const MAX_DB_COUNT: u32 = 9;
+/// Length of a serialized timestamp in the deploy account index.
+const TIMESTAMP_KEY_LENGTH: usize = 8;
+/// Length of a key in the deploy account index: the account hash, followed by the big-endian
+/// deploy timestamp and the deploy hash, so that keys sort by account, then timestamp.
+const DEPLOY_ACCOUNT_INDEX_KEY_LENGTH: usize =
+    ACCOUNT_HASH_LENGTH + TIMESTAMP_KEY_LENGTH + Digest::LENGTH;
 
 /// OS-specific lmdb flags.
 #[cfg(not(target_os = "macos"))]
@@ -166,6 +181,23 @@ impl From<lmdb::Error> for Error {
     }
 }
 
+/// A query for the deploys sent by an account, served by the deploy account index.
+#[derive(Debug, Serialize)]
+pub struct AccountDeploysQuery {
+    /// The account which sent the deploys.
+    pub account_hash: AccountHash,
+    /// The earliest deploy timestamp to include.
+    pub from: Timestamp,
+    /// The latest deploy timestamp to include.
+    pub to: Timestamp,
+    /// If set, only deploys executed in this block are included.
+    pub maybe_block_hash: Option<BlockHash>,
+    /// The number of matching deploys to skip.
+    pub offset: usize,
+    /// The maximum number of deploys to return.
+    pub limit: usize,
+}
+
 #[derive(DataSize, Debug)]
 pub struct Storage {
     /// Storage location.
@@ -194,6 +226,12 @@ pub struct Storage {
     /// The state storage database.
     #[data_size(skip)]
     state_store_db: Database,
+    /// The index of deploys by account and timestamp.
+    #[data_size(skip)]
+    deploy_account_index_db: Database,
+    /// The index of deploys by the hash of the block they were executed in.
+    #[data_size(skip)]
+    deploy_block_index_db: Database,
     /// A map of block height to block ID.
     block_height_index: BTreeMap<u64, BlockHash>,
     /// A map of era ID to switch block ID.
@@ -230,6 +268,15 @@ where
     }
 }
 
+impl HealthReport for Storage {
+    fn health(&self) -> ComponentHealth {
+        match self.env.begin_ro_txn() {
+            Ok(_) => ComponentHealth::Healthy,
+            Err(error) => ComponentHealth::Unhealthy(format!("cannot read database: {}", error)),
+        }
+    }
+}
+
 impl Storage {
     /// Creates a new storage component.
     pub(crate) fn new(
@@ -273,6 +320,10 @@ impl Storage {
         let transfer_db = env.create_db(Some("transfer"), DatabaseFlags::empty())?;
         let state_store_db = env.create_db(Some("state_store"), DatabaseFlags::empty())?;
         let block_body_db = env.create_db(Some("block_body"), DatabaseFlags::empty())?;
+        let deploy_account_index_db =
+            env.create_db(Some("deploy_account_index"), DatabaseFlags::empty())?;
+        let deploy_block_index_db =
+            env.create_db(Some("deploy_block_index"), DatabaseFlags::empty())?;
 
         // We now need to restore the block-height index. Log messages allow timing here.
         info!("reindexing block store");
@@ -321,6 +372,10 @@ impl Storage {
         // Check the integrity of the block metadata database.
         check_block_metadata_db(&env, &block_metadata_db)?;
 
+        // Rebuild the deploy indices if they have not been populated yet.
+        initialize_deploy_account_index(&env, &deploy_db, &deploy_account_index_db)?;
+        initialize_deploy_block_index(&env, &deploy_metadata_db, &deploy_block_index_db)?;
+
         Ok(Storage {
             root,
             env,
@@ -331,6 +386,8 @@ impl Storage {
             deploy_metadata_db,
             transfer_db,
             state_store_db,
+            deploy_account_index_db,
+            deploy_block_index_db,
             block_height_index,
             switch_block_era_id_index,
         })
@@ -490,6 +547,12 @@ impl Storage {
             StorageRequest::PutDeploy { deploy, responder } => {
                 let mut txn = self.env.begin_rw_txn()?;
                 let outcome = txn.put_value(self.deploy_db, deploy.id(), &deploy, false)?;
+                txn.put(
+                    self.deploy_account_index_db,
+                    &deploy_account_index_key(&deploy),
+                    &[],
+                    WriteFlags::default(),
+                )?;
                 txn.commit()?;
                 responder.respond(outcome).ignore()
             }
@@ -559,6 +622,12 @@ impl Storage {
                         "failed to write deploy metadata for block_hash {} deploy_hash {}",
                         block_hash, deploy_hash
                     );
+                    txn.put(
+                        self.deploy_block_index_db,
+                        &deploy_block_index_key(&block_hash, &deploy_hash),
+                        &[],
+                        WriteFlags::default(),
+                    )?;
                 }
 
                 let was_written =
@@ -690,6 +759,9 @@ impl Storage {
             StorageRequest::GetFinalizedDeploys { ttl, responder } => {
                 responder.respond(self.get_finalized_deploys(ttl)?).ignore()
             }
+            StorageRequest::GetAccountDeploys { query, responder } => responder
+                .respond(self.get_account_deploys(&mut self.env.begin_ro_txn()?, &query)?)
+                .ignore(),
         })
     }
 
@@ -879,6 +951,59 @@ impl Storage {
         Ok(tx.get_value(self.deploy_metadata_db, deploy_hash)?)
     }
 
+    /// Returns the hashes and timestamps of the deploys matching the query, ordered by timestamp.
+    fn get_account_deploys<Tx: Transaction>(
+        &self,
+        txn: &mut Tx,
+        query: &AccountDeploysQuery,
+    ) -> Result<Vec<(DeployHash, Timestamp)>, LmdbExtError> {
+        let AccountDeploysQuery {
+            account_hash,
+            from,
+            to,
+            maybe_block_hash,
+            offset,
+            limit,
+        } = query;
+
+        let mut start_key = Vec::with_capacity(ACCOUNT_HASH_LENGTH + TIMESTAMP_KEY_LENGTH);
+        start_key.extend_from_slice(account_hash.as_bytes());
+        start_key.extend_from_slice(&from.millis().to_be_bytes());
+
+        let mut deploys = Vec::new();
+        let mut skipped = 0;
+        let mut cursor = txn.open_ro_cursor(self.deploy_account_index_db)?;
+        // Note: `iter_from` panics if no key at or after `start_key` exists, so we check first.
+        match cursor.get(Some(&start_key), None, lmdb_sys::MDB_SET_RANGE) {
+            Ok(_) => (),
+            Err(lmdb::Error::NotFound) => return Ok(deploys),
+            Err(error) => return Err(error.into()),
+        }
+        for (raw_key, _) in cursor.iter_from(&start_key) {
+            if deploys.len() >= *limit || !raw_key.starts_with(account_hash.as_bytes()) {
+                break;
+            }
+            let (deploy_hash, timestamp) = parse_deploy_account_index_key(raw_key)?;
+            if timestamp > *to {
+                break;
+            }
+            if let Some(block_hash) = maybe_block_hash {
+                let block_index_key = deploy_block_index_key(block_hash, &deploy_hash);
+                match txn.get(self.deploy_block_index_db, &block_index_key) {
+                    Ok(_) => (),
+                    Err(lmdb::Error::NotFound) => continue,
+                    Err(error) => return Err(error.into()),
+                }
+            }
+            if skipped < *offset {
+                skipped += 1;
+                continue;
+            }
+            deploys.push((deploy_hash, timestamp));
+        }
+        Ok(deploys)
+    }
+
     /// Retrieves transfers associated with block.
     ///
     /// If no transfers are stored for the block, an empty transfers instance will be
@@ -947,6 +1072,51 @@ fn insert_to_block_header_indices(
     Ok(())
 }
 
+/// Returns the key under which the deploy is stored in the deploy account index.
+fn deploy_account_index_key(deploy: &Deploy) -> Vec<u8> {
+    let header = deploy.header();
+    let mut key = Vec::with_capacity(DEPLOY_ACCOUNT_INDEX_KEY_LENGTH);
+    key.extend_from_slice(header.account().to_account_hash().as_bytes());
+    key.extend_from_slice(&header.timestamp().millis().to_be_bytes());
+    key.extend_from_slice(deploy.id().as_ref());
+    key
+}
+
+/// Parses the deploy hash and timestamp from a key of the deploy account index.
+fn parse_deploy_account_index_key(raw_key: &[u8]) -> Result<(DeployHash, Timestamp), LmdbExtError> {
+    if raw_key.len() != DEPLOY_ACCOUNT_INDEX_KEY_LENGTH {
+        return Err(LmdbExtError::DataCorrupted(
+            format!(
+                "deploy account index key has length {}, expected {}",
+                raw_key.len(),
+                DEPLOY_ACCOUNT_INDEX_KEY_LENGTH
+            )
+            .into(),
+        ));
+    }
+    let (timestamp_bytes, deploy_hash_bytes) =
+        raw_key[ACCOUNT_HASH_LENGTH..].split_at(TIMESTAMP_KEY_LENGTH);
+    let timestamp_bytes: [u8; TIMESTAMP_KEY_LENGTH] = timestamp_bytes
+        .try_into()
+        .map_err(|error| LmdbExtError::DataCorrupted(Box::new(error)))?;
+    let deploy_hash = Digest::try_from(deploy_hash_bytes)
+        .map(DeployHash::new)
+        .map_err(|error| LmdbExtError::DataCorrupted(Box::new(error)))?;
+    Ok((
+        deploy_hash,
+        Timestamp::from(u64::from_be_bytes(timestamp_bytes)),
+    ))
+}
+
+/// Returns the key under which the execution of a deploy in a block is stored in the deploy block
+/// index: the block hash followed by the deploy hash.
+fn deploy_block_index_key(block_hash: &BlockHash, deploy_hash: &DeployHash) -> Vec<u8> {
+    let mut key = Vec::with_capacity(Digest::LENGTH * 2);
+    key.extend_from_slice(block_hash.as_ref());
+    key.extend_from_slice(deploy_hash.as_ref());
+    key
+}
+
 /// On-disk storage configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -1074,6 +1244,37 @@ impl Storage {
             .collect()
     }
 
+    /// Directly returns the block with the greatest height from the internal store.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any IO or db corruption error.
+    pub(crate) fn transactional_get_highest_block(&self) -> Option<Block> {
+        let mut txn = self
+            .env
+            .begin_ro_txn()
+            .expect("could not create RO transaction");
+        self.get_highest_block(&mut txn)
+            .expect("could not retrieve highest block from storage")
+    }
+
+    /// Directly returns the metadata of a deploy from the internal store.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any IO or db corruption error.
+    pub(crate) fn get_deploy_metadata_by_hash(
+        &self,
+        deploy_hash: &DeployHash,
+    ) -> Option<DeployMetadata> {
+        let mut txn = self
+            .env
+            .begin_ro_txn()
+            .expect("could not create RO transaction");
+        self.get_deploy_metadata(&mut txn, deploy_hash)
+            .expect("could not retrieve deploy metadata from storage")
+    }
+
     /// Get the switch block for a specified era number in a read-only LMDB database transaction.
     ///
     /// # Panics
@@ -1146,3 +1347,74 @@ fn check_block_metadata_db(
     info!("Check for block_metadata_db complete");
     Ok(())
 }
+
+/// Utility function to populate the deploy account index from the deploy database at bringup, if
+/// the index is empty.
+fn initialize_deploy_account_index(
+    env: &Environment,
+    deploy_db: &Database,
+    deploy_account_index_db: &Database,
+) -> Result<(), LmdbExtError> {
+    let mut txn = env.begin_rw_txn()?;
+    let keys = {
+        if txn
+            .open_ro_cursor(*deploy_account_index_db)?
+            .iter()
+            .next()
+            .is_some()
+        {
+            return Ok(());
+        }
+        info!("building deploy account index");
+        let mut cursor = txn.open_ro_cursor(*deploy_db)?;
+        cursor
+            .iter()
+            .map(|(_, raw_val)| {
+                let deploy: Deploy = lmdb_ext::deserialize(raw_val)?;
+                Ok(deploy_account_index_key(&deploy))
+            })
+            .collect::<Result<Vec<_>, LmdbExtError>>()?
+    };
+    for key in keys {
+        txn.put(*deploy_account_index_db, &key, &[], WriteFlags::default())?;
+    }
+    txn.commit()?;
+    Ok(())
+}
+
+/// Utility function to populate the deploy block index from the deploy metadata database at
+/// bringup, if the index is empty.
+fn initialize_deploy_block_index(
+    env: &Environment,
+    deploy_metadata_db: &Database,
+    deploy_block_index_db: &Database,
+) -> Result<(), LmdbExtError> {
+    let mut txn = env.begin_rw_txn()?;
+    let mut keys = Vec::new();
+    {
+        if txn
+            .open_ro_cursor(*deploy_block_index_db)?
+            .iter()
+            .next()
+            .is_some()
+        {
+            return Ok(());
+        }
+        info!("building deploy block index");
+        let mut cursor = txn.open_ro_cursor(*deploy_metadata_db)?;
+        for (raw_key, raw_val) in cursor.iter() {
+            let deploy_hash = Digest::try_from(raw_key)
+                .map(DeployHash::new)
+                .map_err(|error| LmdbExtError::DataCorrupted(Box::new(error)))?;
+            let metadata: DeployMetadata = lmdb_ext::deserialize(raw_val)?;
+            for block_hash in metadata.execution_results.keys() {
+                keys.push(deploy_block_index_key(block_hash, &deploy_hash));
+            }
+        }
+    }
+    for key in keys {
+        txn.put(*deploy_block_index_db, &key, &[], WriteFlags::default())?;
+    }
+    txn.commit()?;
+    Ok(())
+}
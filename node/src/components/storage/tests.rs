@@ -9,7 +9,7 @@ use smallvec::smallvec;
 
 use casper_types::ExecutionResult;
 
-use super::{Config, Storage};
+use super::{AccountDeploysQuery, Config, Storage};
 use crate::{
     components::consensus::EraId,
     effect::{
@@ -17,7 +17,7 @@ use crate::{
         Multiple,
     },
     testing::{ComponentHarness, TestRng, UnitTestEvent},
-    types::{Block, BlockHash, Deploy, DeployHash, DeployMetadata},
+    types::{Block, BlockHash, Deploy, DeployHash, DeployMetadata, TimeDiff, Timestamp},
     utils::WithDir,
 };
 
@@ -142,6 +142,23 @@ fn get_deploy_and_metadata(
     response
 }
 
+/// Lists the deploys sent by an account from a storage component.
+fn get_account_deploys(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    query: AccountDeploysQuery,
+) -> Vec<(DeployHash, Timestamp)> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetAccountDeploys {
+            query: Box::new(query),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Requests the highest block from a storage component.
 fn get_highest_block(
     harness: &mut ComponentHarness<UnitTestEvent>,
@@ -668,6 +685,144 @@ struct StateData {
     b: i32,
 }
 
+#[test]
+fn list_account_deploys_by_time_range() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    // Store deploys from one account out of timestamp order, plus one from another account.
+    let base_deploy = Deploy::random(&mut harness.rng);
+    let ttl = TimeDiff::from(60_000);
+    let deploys: Vec<Deploy> = [30, 10, 50, 20, 40]
+        .iter()
+        .map(|millis| base_deploy.with_timestamp_and_ttl(Timestamp::from(*millis), ttl))
+        .collect();
+    for deploy in &deploys {
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+    }
+    let other_deploy = Deploy::random(&mut harness.rng).with_timestamp_and_ttl(20.into(), ttl);
+    assert_ne!(
+        other_deploy.header().account(),
+        base_deploy.header().account()
+    );
+    put_deploy(&mut harness, &mut storage, Box::new(other_deploy));
+
+    let expected = |millis: &[u64]| -> Vec<(DeployHash, Timestamp)> {
+        millis
+            .iter()
+            .map(|millis| {
+                let deploy = deploys
+                    .iter()
+                    .find(|deploy| deploy.header().timestamp() == Timestamp::from(*millis))
+                    .unwrap();
+                (*deploy.id(), deploy.header().timestamp())
+            })
+            .collect()
+    };
+    let query = |from: u64, to: u64, offset: usize, limit: usize| AccountDeploysQuery {
+        account_hash: base_deploy.header().account().to_account_hash(),
+        from: from.into(),
+        to: to.into(),
+        maybe_block_hash: None,
+        offset,
+        limit,
+    };
+
+    // The range is inclusive at both ends and results are ordered by timestamp.
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(0, 100, 0, 10)),
+        expected(&[10, 20, 30, 40, 50])
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(20, 40, 0, 10)),
+        expected(&[20, 30, 40])
+    );
+    assert!(get_account_deploys(&mut harness, &mut storage, query(51, 100, 0, 10)).is_empty());
+
+    // Pagination.
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(0, 100, 0, 2)),
+        expected(&[10, 20])
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(0, 100, 2, 2)),
+        expected(&[30, 40])
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(0, 100, 4, 2)),
+        expected(&[50])
+    );
+}
+
+#[test]
+fn list_account_deploys_by_block() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let base_deploy = Deploy::random(&mut harness.rng);
+    let ttl = TimeDiff::from(60_000);
+    let deploys: Vec<Deploy> = (1..=4)
+        .map(|millis| base_deploy.with_timestamp_and_ttl(Timestamp::from(millis), ttl))
+        .collect();
+    for deploy in &deploys {
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+    }
+
+    // Execute the first and third deploy in one block, the others in another.
+    let block_hash_a = BlockHash::random(&mut harness.rng);
+    let block_hash_b = BlockHash::random(&mut harness.rng);
+    for (index, deploy) in deploys.iter().enumerate() {
+        let block_hash = if index % 2 == 0 {
+            block_hash_a
+        } else {
+            block_hash_b
+        };
+        let mut execution_results = HashMap::new();
+        execution_results.insert(*deploy.id(), harness.rng.gen());
+        put_execution_results(&mut harness, &mut storage, block_hash, execution_results);
+    }
+
+    let query = |maybe_block_hash: Option<BlockHash>| AccountDeploysQuery {
+        account_hash: base_deploy.header().account().to_account_hash(),
+        from: 0.into(),
+        to: 10.into(),
+        maybe_block_hash,
+        offset: 0,
+        limit: 10,
+    };
+    let hashes = |entries: Vec<(DeployHash, Timestamp)>| -> Vec<DeployHash> {
+        entries
+            .into_iter()
+            .map(|(deploy_hash, _)| deploy_hash)
+            .collect()
+    };
+
+    assert_eq!(
+        hashes(get_account_deploys(
+            &mut harness,
+            &mut storage,
+            query(Some(block_hash_a))
+        )),
+        vec![*deploys[0].id(), *deploys[2].id()]
+    );
+    assert_eq!(
+        hashes(get_account_deploys(
+            &mut harness,
+            &mut storage,
+            query(Some(block_hash_b))
+        )),
+        vec![*deploys[1].id(), *deploys[3].id()]
+    );
+    let unknown_block_hash = BlockHash::random(&mut harness.rng);
+    assert!(
+        get_account_deploys(&mut harness, &mut storage, query(Some(unknown_block_hash))).is_empty()
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, query(None)).len(),
+        4
+    );
+}
+
 #[test]
 fn store_and_load_state_data() {
     let key1 = b"sample-key-1".to_vec();
@@ -104,31 +104,33 @@ use casper_types::{
 use crate::{
     components::{
         chainspec_loader::NextUpgrade,
-        consensus::{BlockContext, EraId},
+        consensus::{BlockContext, EraId, ValidatorLiveness},
         contract_runtime::EraValidatorsRequest,
         deploy_acceptor,
         fetcher::FetchResult,
         small_network::GossipedAddress,
+        storage::AccountDeploysQuery,
     },
     crypto::hash::Digest,
     effect::requests::LinearChainRequest,
     reactor::{EventQueueHandle, QueueKind},
     types::{
         Block, BlockByHeight, BlockHash, BlockHeader, BlockLike, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, ProtoBlock, TimeDiff, Timestamp,
+        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployLimits, DeployMetadata,
+        FinalitySignature, FinalizedBlock, HealthSnapshot, Item, NodeId, ProtoBlock, TimeDiff,
+        Timestamp,
     },
     utils::Source,
 };
 use announcements::{
-    BlockExecutorAnnouncement, ChainspecLoaderAnnouncement, ConsensusAnnouncement,
-    ControlAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement, LinearChainAnnouncement,
-    NetworkAnnouncement, RpcServerAnnouncement,
+    BlockExecutorAnnouncement, BlockProposerAnnouncement, ChainspecLoaderAnnouncement,
+    ConsensusAnnouncement, ControlAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
+    LinearChainAnnouncement, NetworkAnnouncement, RpcServerAnnouncement,
 };
 use requests::{
     BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest, ChainspecLoaderRequest,
-    ConsensusRequest, ContractRuntimeRequest, FetcherRequest, MetricsRequest, NetworkInfoRequest,
-    NetworkRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest,
+    ConsensusRequest, ContractRuntimeRequest, FetcherRequest, HealthRequest, MetricsRequest,
+    NetworkInfoRequest, NetworkRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest,
 };
 
 /// A pinned, boxed future that produces one or more events.
@@ -464,6 +466,18 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Retrieves the health reported by each of the reactor's components.
+    pub(crate) async fn get_health_snapshot(self) -> HealthSnapshot
+    where
+        REv: From<HealthRequest>,
+    {
+        self.make_request(
+            |responder| HealthRequest::GetSnapshot { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Retrieves block at `height` from the Linear Chain component.
     pub(crate) async fn get_block_at_height_local<I>(self, height: u64) -> Option<Block>
     where
@@ -554,6 +568,19 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the estimated drift in milliseconds of our clock relative to our peers.
+    pub(crate) async fn network_clock_drift<I>(self) -> Option<i64>
+    where
+        REv: From<NetworkInfoRequest<I>>,
+        I: Send + 'static,
+    {
+        self.make_request(
+            |responder| NetworkInfoRequest::GetClockDrift { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Announces that a network message has been received.
     pub(crate) async fn announce_message_received<I, P>(self, sender: I, payload: P)
     where
@@ -593,9 +620,13 @@ impl<REv> EffectBuilder<REv> {
             .await;
     }
 
-    /// Announces that a gossiper has received a new item, where the item's ID is the complete item.
-    pub(crate) async fn announce_complete_item_received_via_gossip<T: Item>(self, item: T::Id)
-    where
+    /// Announces that a gossiper has received a new item from `sender`, where the item's ID is the
+    /// complete item.
+    pub(crate) async fn announce_complete_item_received_via_gossip<T: Item>(
+        self,
+        item: T::Id,
+        sender: NodeId,
+    ) where
         REv: From<GossiperAnnouncement<T>>,
     {
         assert!(
@@ -605,7 +636,23 @@ impl<REv> EffectBuilder<REv> {
         );
         self.0
             .schedule(
-                GossiperAnnouncement::NewCompleteItem(item),
+                GossiperAnnouncement::NewCompleteItem {
+                    item_id: item,
+                    sender,
+                },
+                QueueKind::Regular,
+            )
+            .await;
+    }
+
+    /// Announces that the given pending deploys are nearing expiry and should be gossiped again.
+    pub(crate) async fn announce_deploys_for_rebroadcast(self, deploy_hashes: Vec<DeployHash>)
+    where
+        REv: From<BlockProposerAnnouncement>,
+    {
+        self.0
+            .schedule(
+                BlockProposerAnnouncement::RebroadcastDeploys(deploy_hashes),
                 QueueKind::Regular,
             )
             .await;
@@ -676,6 +723,27 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Announce that execution of a block lags behind its finalization.
+    pub(crate) async fn announce_execution_lagging(
+        self,
+        height: u64,
+        lag: TimeDiff,
+        estimated_duration: TimeDiff,
+    ) where
+        REv: From<BlockExecutorAnnouncement>,
+    {
+        self.0
+            .schedule(
+                BlockExecutorAnnouncement::ExecutionLagging {
+                    height,
+                    lag,
+                    estimated_duration,
+                },
+                QueueKind::Regular,
+            )
+            .await
+    }
+
     /// Announce upgrade activation point read.
     pub(crate) async fn announce_upgrade_activation_point_read(self, next_upgrade: NextUpgrade)
     where
@@ -836,8 +904,6 @@ impl<REv> EffectBuilder<REv> {
     }
 
     /// Requests the highest switch block.
-    // TODO - remove once used.
-    #[allow(unused)]
     pub(crate) async fn get_highest_switch_block_from_storage(self) -> Option<Block>
     where
         REv: From<StorageRequest>,
@@ -948,6 +1014,21 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the hashes and timestamps of the deploys matching the query from the deploy store.
+    pub(crate) async fn get_account_deploys_from_storage(
+        self,
+        query: Box<AccountDeploysQuery>,
+    ) -> Vec<(DeployHash, Timestamp)>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetAccountDeploys { query, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Gets the requested block and its associated metadata.
     pub(crate) async fn get_block_at_height_with_metadata_from_storage(
         self,
@@ -1257,12 +1338,20 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Gets the limits a deploy must comply with from the chainspec loader.
+    pub(crate) async fn get_deploy_limits(self) -> DeployLimits
+    where
+        REv: From<ChainspecLoaderRequest> + Send,
+    {
+        self.make_request(ChainspecLoaderRequest::GetDeployLimits, QueueKind::Regular)
+            .await
+    }
+
     /// Loads potentially previously stored state from storage.
     ///
     /// Key must be a unique key across the the application, as all keys share a common namespace.
     ///
     /// If an error occurs during state loading or no data is found, returns `None`.
-    #[allow(unused)]
     pub(crate) async fn load_state<T>(self, key: Cow<'static, [u8]>) -> Option<T>
     where
         REv: From<StateStoreRequest>,
@@ -1359,6 +1448,7 @@ impl<REv> EffectBuilder<REv> {
     /// Requests a commit of effects on the Contract Runtime component.
     pub(crate) async fn request_commit(
         self,
+        protocol_version: ProtocolVersion,
         state_root_hash: Digest,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, engine_state::Error>
@@ -1367,6 +1457,7 @@ impl<REv> EffectBuilder<REv> {
     {
         self.make_request(
             |responder| ContractRuntimeRequest::Commit {
+                protocol_version,
                 state_root_hash,
                 effects,
                 responder,
@@ -1543,6 +1634,18 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Get the current era, our next round exponent if we're a validator, and the liveness
+    /// statistics of the current era's validators from consensus.
+    pub(crate) async fn validator_liveness(
+        self,
+    ) -> (EraId, Option<u8>, Vec<ValidatorLiveness<PublicKey>>)
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(ConsensusRequest::ValidatorLiveness, QueueKind::Regular)
+            .await
+    }
+
     /// Check if validator is bonded in the future era (`era_id`).
     /// This information is known only by the Contract Runtime since consensus component
     /// knows only about currently active eras.
@@ -19,7 +19,8 @@ use crate::{
     },
     effect::Responder,
     types::{
-        Block, Deploy, DeployHash, DeployHeader, FinalitySignature, FinalizedBlock, Item, Timestamp,
+        Block, Deploy, DeployHash, DeployHeader, FinalitySignature, FinalizedBlock, Item, NodeId,
+        TimeDiff, Timestamp,
     },
     utils::Source,
 };
@@ -217,6 +218,16 @@ pub enum BlockExecutorAnnouncement {
         /// The results of executing the deploys in this block.
         execution_results: HashMap<DeployHash, (DeployHeader, ExecutionResult)>,
     },
+    /// Execution of a finalized block is about to start, but lags behind its finalization by more
+    /// than the configured threshold.
+    ExecutionLagging {
+        /// The height of the block.
+        height: u64,
+        /// The time elapsed since the block was finalized.
+        lag: TimeDiff,
+        /// The estimated time to execute the block, based on previously executed deploys.
+        estimated_duration: TimeDiff,
+    },
 }
 
 impl Display for BlockExecutorAnnouncement {
@@ -225,6 +236,15 @@ impl Display for BlockExecutorAnnouncement {
             BlockExecutorAnnouncement::LinearChainBlock { block, .. } => {
                 write!(f, "created linear chain block {}", block.hash())
             }
+            BlockExecutorAnnouncement::ExecutionLagging {
+                height,
+                lag,
+                estimated_duration,
+            } => write!(
+                f,
+                "execution of block at height {} lags its finalization by {}, estimated to take {}",
+                height, lag, estimated_duration
+            ),
         }
     }
 }
@@ -233,13 +253,38 @@ impl Display for BlockExecutorAnnouncement {
 #[derive(Debug)]
 pub enum GossiperAnnouncement<T: Item> {
     /// A new item has been received, where the item's ID is the complete item.
-    NewCompleteItem(T::Id),
+    NewCompleteItem {
+        /// The ID of the received item.
+        item_id: T::Id,
+        /// The peer which gossiped the item to us.
+        sender: NodeId,
+    },
 }
 
 impl<T: Item> Display for GossiperAnnouncement<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            GossiperAnnouncement::NewCompleteItem(item) => write!(f, "new complete item {}", item),
+            GossiperAnnouncement::NewCompleteItem { item_id, sender } => {
+                write!(f, "new complete item {} from {}", item_id, sender)
+            }
+        }
+    }
+}
+
+/// A block proposer announcement.
+#[derive(Debug)]
+pub enum BlockProposerAnnouncement {
+    /// Pending deploys are nearing expiry without having been finalized and should be gossiped
+    /// again.
+    RebroadcastDeploys(Vec<DeployHash>),
+}
+
+impl Display for BlockProposerAnnouncement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockProposerAnnouncement::RebroadcastDeploys(deploy_hashes) => {
+                write!(f, "rebroadcast {} deploys", deploy_hashes.len())
+            }
         }
     }
 }
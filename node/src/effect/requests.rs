@@ -42,17 +42,19 @@ use casper_types::{
 use super::Responder;
 use crate::{
     components::{
-        consensus::EraId,
+        consensus::{EraId, ValidatorLiveness},
         contract_runtime::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest},
         deploy_acceptor::Error,
         fetcher::FetchResult,
+        storage::AccountDeploysQuery,
     },
     crypto::hash::Digest,
     rpcs::chain::BlockIdentifier,
     types::{
         Block as LinearBlock, Block, BlockHash, BlockHeader, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, NodeId, ProtoBlock, StatusFeed, TimeDiff, Timestamp,
+        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployLimits, DeployMetadata,
+        FinalitySignature, FinalizedBlock, HealthSnapshot, Item, NodeId, ProtoBlock, StatusFeed,
+        TimeDiff, Timestamp,
     },
     utils::DisplayIter,
 };
@@ -80,6 +82,24 @@ impl Display for MetricsRequest {
     }
 }
 
+/// A request for the health of the reactor's components.
+#[derive(Debug)]
+pub enum HealthRequest {
+    /// Collect the health reported by each component.
+    GetSnapshot {
+        /// Responder returning the health snapshot.
+        responder: Responder<HealthSnapshot>,
+    },
+}
+
+impl Display for HealthRequest {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthRequest::GetSnapshot { .. } => write!(formatter, "get health snapshot"),
+        }
+    }
+}
+
 const _NETWORK_EVENT_SIZE: usize = mem::size_of::<NetworkRequest<NodeId, String>>();
 const_assert!(_NETWORK_EVENT_SIZE < 89);
 
@@ -187,6 +207,11 @@ pub enum NetworkInfoRequest<I> {
         // TODO - change the `String` field to a `libp2p::Multiaddr` once small_network is removed.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Get the estimated drift of our clock relative to our peers.
+    GetClockDrift {
+        /// Responder to be called with the drift in milliseconds, if it can be estimated.
+        responder: Responder<Option<i64>>,
+    },
 }
 
 impl<I> Display for NetworkInfoRequest<I>
@@ -196,6 +221,9 @@ where
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             NetworkInfoRequest::GetPeers { responder: _ } => write!(formatter, "get peers"),
+            NetworkInfoRequest::GetClockDrift { responder: _ } => {
+                write!(formatter, "get clock drift")
+            }
         }
     }
 }
@@ -291,6 +319,13 @@ pub enum StorageRequest {
         /// Responder to call with the results.
         responder: Responder<Vec<(DeployHash, DeployHeader)>>,
     },
+    /// Retrieve the hashes and timestamps of deploys sent by an account, ordered by timestamp.
+    GetAccountDeploys {
+        /// The query to run against the deploy account index.
+        query: Box<AccountDeploysQuery>,
+        /// Responder to call with the results.
+        responder: Responder<Vec<(DeployHash, Timestamp)>>,
+    },
     /// Store execution results for a set of deploys of a single block.
     ///
     /// Will return a fatal error if there are already execution results known for a specific
@@ -415,6 +450,9 @@ impl Display for StorageRequest {
             StorageRequest::GetFinalizedDeploys { ttl, .. } => {
                 write!(formatter, "get finalized deploys, ttl: {:?}", ttl)
             }
+            StorageRequest::GetAccountDeploys { query, .. } => {
+                write!(formatter, "get deploys of account {}", query.account_hash)
+            }
         }
     }
 }
@@ -530,6 +568,14 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<Vec<Transfer>>>,
     },
+    /// If `maybe_era_id` is `Some`, return the switch block of the specified era if it exists,
+    /// else `None`.  If `maybe_era_id` is `None`, return the latest switch block.
+    GetSwitchBlock {
+        /// The era of the switch block to be retrieved.
+        maybe_era_id: Option<EraId>,
+        /// Responder to call with the result.
+        responder: Responder<Option<LinearBlock>>,
+    },
     /// Query the global state at the given root hash.
     QueryGlobalState {
         /// The state root hash.
@@ -580,6 +626,13 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    /// Return the hashes and timestamps of deploys sent by an account.
+    GetAccountDeploys {
+        /// The query to run against the deploy account index.
+        query: Box<AccountDeploysQuery>,
+        /// Responder to call with the result.
+        responder: Responder<Vec<(DeployHash, Timestamp)>>,
+    },
     /// Return the connected peers.
     GetPeers {
         /// Responder to call with the result.
@@ -613,6 +666,13 @@ impl<I> Display for RpcRequest<I> {
             RpcRequest::GetBlockTransfers { block_hash, .. } => {
                 write!(formatter, "get transfers {}", block_hash)
             }
+            RpcRequest::GetSwitchBlock {
+                maybe_era_id: Some(era_id),
+                ..
+            } => write!(formatter, "get switch block of {}", era_id),
+            RpcRequest::GetSwitchBlock {
+                maybe_era_id: None, ..
+            } => write!(formatter, "get latest switch block"),
             RpcRequest::QueryProtocolData {
                 protocol_version, ..
             } => write!(formatter, "protocol_version {}", protocol_version),
@@ -644,6 +704,9 @@ impl<I> Display for RpcRequest<I> {
                 state_root_hash, purse_uref
             ),
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
+            RpcRequest::GetAccountDeploys { query, .. } => {
+                write!(formatter, "get deploys of account {}", query.account_hash)
+            }
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
@@ -707,6 +770,8 @@ pub enum ContractRuntimeRequest {
     },
     /// A request to commit existing execution transforms.
     Commit {
+        /// The protocol version under which the effects are committed.
+        protocol_version: ProtocolVersion,
         /// A valid state root hash.
         state_root_hash: Digest,
         /// Effects obtained through `ExecutionResult`
@@ -997,6 +1062,9 @@ pub enum ConsensusRequest {
     IsBondedValidator(EraId, PublicKey, Responder<bool>),
     /// Request for our public key, and if we're a validator, the next round length.
     Status(Responder<(PublicKey, Option<TimeDiff>)>),
+    /// Request for the current era, our next round exponent if we're a validator, and the
+    /// liveness statistics of the current era's validators.
+    ValidatorLiveness(Responder<(EraId, Option<u8>, Vec<ValidatorLiveness<PublicKey>>)>),
 }
 
 /// ChainspecLoader component requests.
@@ -1004,12 +1072,15 @@ pub enum ConsensusRequest {
 pub enum ChainspecLoaderRequest {
     /// Chainspec info request.
     GetChainspecInfo(Responder<ChainspecInfo>),
+    /// Deploy limits request.
+    GetDeployLimits(Responder<DeployLimits>),
 }
 
 impl Display for ChainspecLoaderRequest {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ChainspecLoaderRequest::GetChainspecInfo(_) => write!(f, "get chainspec info"),
+            ChainspecLoaderRequest::GetDeployLimits(_) => write!(f, "get deploy limits"),
         }
     }
 }
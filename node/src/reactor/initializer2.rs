@@ -16,7 +16,7 @@ reactor!(Initializer {
         chainspec_loader.chainspec().protocol_config.version.clone(),
     );
     contract_runtime = ContractRuntime(cfg.map_ref(|cfg| cfg.storage.clone()),
-&cfg.value().contract_runtime, registry);   }
+&cfg.value().contract_runtime, chainspec_loader.chainspec(), registry);   }
 
   events: {}
 
@@ -44,7 +44,7 @@ use crate::{
         rest_server::{self, RestServer},
         small_network::{self, GossipedAddress, SmallNetwork, SmallNetworkIdentity},
         storage::{self, Storage},
-        Component,
+        Component, HealthReport,
     },
     effect::{
         announcements::{
@@ -55,10 +55,10 @@ use crate::{
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
             ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, FetcherRequest,
-            LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest, RestRequest,
-            StateStoreRequest, StorageRequest,
+            HealthRequest, LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest,
+            RestRequest, StateStoreRequest, StorageRequest,
         },
-        EffectBuilder, Effects,
+        EffectBuilder, EffectExt, Effects,
     },
     protocol::Message,
     reactor::{
@@ -68,7 +68,9 @@ use crate::{
         validator::{self, Error, ValidatorInitConfig},
         EventQueueHandle, Finalize, ReactorExit,
     },
-    types::{Block, BlockByHeight, Deploy, ExitCode, NodeId, ProtoBlock, Tag, Timestamp},
+    types::{
+        Block, BlockByHeight, Deploy, ExitCode, HealthSnapshot, NodeId, ProtoBlock, Tag, Timestamp,
+    },
     utils::{Source, WithDir},
     NodeRng,
 };
@@ -102,6 +104,9 @@ pub enum Event {
     /// Metrics request.
     #[from]
     MetricsRequest(#[serde(skip_serializing)] MetricsRequest),
+    /// Health request.
+    #[from]
+    HealthRequest(#[serde(skip_serializing)] HealthRequest),
 
     #[from]
     /// Chainspec Loader event.
@@ -296,6 +301,7 @@ impl Display for Event {
             Event::RestServer(event) => write!(f, "rest server: {}", event),
             Event::EventStreamServer(event) => write!(f, "event stream server: {}", event),
             Event::MetricsRequest(req) => write!(f, "metrics request: {}", req),
+            Event::HealthRequest(req) => write!(f, "health request: {}", req),
             Event::ChainspecLoader(event) => write!(f, "chainspec loader: {}", event),
             Event::ChainspecLoaderRequest(req) => write!(f, "chainspec loader request: {}", req),
             Event::NetworkInfoRequest(req) => write!(f, "network info request: {}", req),
@@ -498,6 +504,8 @@ impl reactor::Reactor for Reactor {
             chainspec_loader.initial_state_root_hash(),
             chainspec_loader.initial_block_header(),
             protocol_version.clone(),
+            // While joining, the executed blocks are expected to lag behind the network.
+            None,
             registry.clone(),
         );
 
@@ -799,6 +807,13 @@ impl reactor::Reactor for Reactor {
 
                 effects
             }
+            Event::BlockExecutorAnnouncement(BlockExecutorAnnouncement::ExecutionLagging {
+                ..
+            }) => {
+                // The block executor has already logged this, and the execution times are exposed
+                // as metrics.
+                Effects::new()
+            }
             Event::LinearChain(event) => reactor::wrap_effects(
                 Event::LinearChain,
                 self.linear_chain.handle_event(effect_builder, rng, event),
@@ -862,10 +877,15 @@ impl reactor::Reactor for Reactor {
                     .handle_event(effect_builder, rng, event),
             ),
             Event::AddressGossiperAnnouncement(ann) => {
-                let GossiperAnnouncement::NewCompleteItem(gossiped_address) = ann;
-                let reactor_event = Event::SmallNetwork(small_network::Event::PeerAddressReceived(
-                    gossiped_address,
-                ));
+                let GossiperAnnouncement::NewCompleteItem {
+                    item_id: gossiped_address,
+                    sender,
+                } = ann;
+                let reactor_event =
+                    Event::SmallNetwork(small_network::Event::PeerAddressReceived {
+                        gossiped_address,
+                        sender: Box::new(sender),
+                    });
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
 
@@ -898,6 +918,9 @@ impl reactor::Reactor for Reactor {
                 Event::MetricsRequest,
                 self.metrics.handle_event(effect_builder, rng, req),
             ),
+            Event::HealthRequest(HealthRequest::GetSnapshot { responder }) => {
+                responder.respond(self.health_snapshot()).ignore()
+            }
             Event::ChainspecLoader(event) => reactor::wrap_effects(
                 Event::ChainspecLoader,
                 self.chainspec_loader
@@ -949,14 +972,27 @@ impl reactor::Reactor for Reactor {
         self.memory_metrics.estimate(&self);
         self.event_queue_metrics
             .record_event_queue_counts(&event_queue_handle);
+        // Component health is logged alongside the periodic metrics update.
+        self.health_snapshot().log();
     }
 }
 
 impl Reactor {
+    /// Collects the health reported by each of the reactor's components.
+    fn health_snapshot(&self) -> HealthSnapshot {
+        let mut snapshot = HealthSnapshot::default();
+        snapshot.insert("small_network", self.small_network.health());
+        snapshot.insert("consensus", self.consensus.health());
+        snapshot.insert("storage", self.storage.health());
+        snapshot.insert("contract_runtime", self.contract_runtime.health());
+        snapshot.insert_gossip_fanout("address_gossiper", self.address_gossiper.infection_target());
+        snapshot
+    }
+
     /// Deconstructs the reactor into config useful for creating a Validator reactor. Shuts down
     /// the network, closing all incoming and outgoing connections, and frees up the listening
     /// socket.
-    pub async fn into_validator_config(self) -> Result<ValidatorInitConfig, Error> {
+    pub async fn into_validator_config(mut self) -> Result<ValidatorInitConfig, Error> {
         let latest_block = self.linear_chain_sync.latest_block().cloned();
         // Clean the state of the linear_chain_sync before shutting it down.
         #[cfg(not(feature = "fast-sync"))]
@@ -975,6 +1011,8 @@ impl Reactor {
             small_network_identity: SmallNetworkIdentity::from(&self.small_network),
             network_identity: NetworkIdentity::from(&self.network),
         };
+        // The node keeps running with the validator's networking, so don't say goodbye to peers.
+        self.small_network.set_goodbye_reason(None);
         self.network.finalize().await;
         self.small_network.finalize().await;
         self.rest_server.finalize().await;
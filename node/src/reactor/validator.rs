@@ -17,6 +17,7 @@ use std::{
 
 use datasize::DataSize;
 use derive_more::From;
+use futures::{future::BoxFuture, FutureExt};
 use prometheus::Registry;
 use reactor::ReactorEvent;
 use serde::Serialize;
@@ -41,27 +42,32 @@ use crate::{
         network::{self, Network, NetworkIdentity, ENABLE_LIBP2P_NET_ENV_VAR},
         rest_server::{self, RestServer},
         rpc_server::{self, RpcServer},
-        small_network::{self, GossipedAddress, SmallNetwork, SmallNetworkIdentity},
+        small_network::{self, GoodbyeReason, GossipedAddress, SmallNetwork, SmallNetworkIdentity},
         storage::{self, Storage},
-        Component,
+        Component, HealthReport,
     },
     effect::{
         announcements::{
-            BlockExecutorAnnouncement, ChainspecLoaderAnnouncement, ConsensusAnnouncement,
-            ControlAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
-            LinearChainAnnouncement, NetworkAnnouncement, RpcServerAnnouncement,
+            BlockExecutorAnnouncement, BlockProposerAnnouncement, ChainspecLoaderAnnouncement,
+            ConsensusAnnouncement, ControlAnnouncement, DeployAcceptorAnnouncement,
+            GossiperAnnouncement, LinearChainAnnouncement, NetworkAnnouncement,
+            RpcServerAnnouncement,
         },
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
             ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, FetcherRequest,
-            LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest, RestRequest,
-            RpcRequest, StateStoreRequest, StorageRequest,
+            HealthRequest, LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest,
+            RestRequest, RpcRequest, StateStoreRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects,
     },
     protocol::Message,
-    reactor::{self, event_queue_metrics::EventQueueMetrics, EventQueueHandle, ReactorExit},
-    types::{Block, Deploy, ExitCode, NodeId, ProtoBlock, Tag, TimeDiff, Timestamp},
+    reactor::{
+        self, event_queue_metrics::EventQueueMetrics, EventQueueHandle, Finalize, ReactorExit,
+    },
+    types::{
+        Block, Deploy, ExitCode, HealthSnapshot, NodeId, ProtoBlock, Tag, TimeDiff, Timestamp,
+    },
     utils::Source,
     NodeRng,
 };
@@ -150,6 +156,9 @@ pub enum Event {
     /// Metrics request.
     #[from]
     MetricsRequest(#[serde(skip_serializing)] MetricsRequest),
+    /// Health request.
+    #[from]
+    HealthRequest(#[serde(skip_serializing)] HealthRequest),
     /// Chainspec info request
     #[from]
     ChainspecLoaderRequest(#[serde(skip_serializing)] ChainspecLoaderRequest),
@@ -179,6 +188,9 @@ pub enum Event {
     /// BlockExecutor announcement.
     #[from]
     BlockExecutorAnnouncement(#[serde(skip_serializing)] BlockExecutorAnnouncement),
+    /// Block proposer announcement.
+    #[from]
+    BlockProposerAnnouncement(#[serde(skip_serializing)] BlockProposerAnnouncement),
     /// Deploy Gossiper announcement.
     #[from]
     DeployGossiperAnnouncement(#[serde(skip_serializing)] GossiperAnnouncement<Deploy>),
@@ -281,6 +293,7 @@ impl Display for Event {
             Event::BlockExecutorRequest(req) => write!(f, "block executor request: {}", req),
             Event::ProtoBlockValidatorRequest(req) => write!(f, "block validator request: {}", req),
             Event::MetricsRequest(req) => write!(f, "metrics request: {}", req),
+            Event::HealthRequest(req) => write!(f, "health request: {}", req),
             Event::ControlAnnouncement(ctrl_ann) => write!(f, "control: {}", ctrl_ann),
             Event::NetworkAnnouncement(ann) => write!(f, "network announcement: {}", ann),
             Event::RpcServerAnnouncement(ann) => write!(f, "api server announcement: {}", ann),
@@ -291,6 +304,9 @@ impl Display for Event {
             Event::BlockExecutorAnnouncement(ann) => {
                 write!(f, "block-executor announcement: {}", ann)
             }
+            Event::BlockProposerAnnouncement(ann) => {
+                write!(f, "block proposer announcement: {}", ann)
+            }
             Event::DeployGossiperAnnouncement(ann) => {
                 write!(f, "deploy gossiper announcement: {}", ann)
             }
@@ -377,6 +393,25 @@ impl Reactor {
     pub(crate) fn storage(&self) -> &Storage {
         &self.storage
     }
+    /// Inspect the contract runtime.
+    pub(crate) fn contract_runtime(&self) -> &ContractRuntime {
+        &self.contract_runtime
+    }
+}
+
+impl Reactor {
+    /// Collects the health reported by each of the reactor's components.
+    fn health_snapshot(&self) -> HealthSnapshot {
+        let mut snapshot = HealthSnapshot::default();
+        snapshot.insert("small_network", self.small_network.health());
+        snapshot.insert("consensus", self.consensus.health());
+        snapshot.insert("block_proposer", self.block_proposer.health());
+        snapshot.insert("storage", self.storage.health());
+        snapshot.insert("contract_runtime", self.contract_runtime.health());
+        snapshot.insert_gossip_fanout("address_gossiper", self.address_gossiper.infection_target());
+        snapshot.insert_gossip_fanout("deploy_gossiper", self.deploy_gossiper.infection_target());
+        snapshot
+    }
 }
 
 impl reactor::Reactor for Reactor {
@@ -462,12 +497,14 @@ impl reactor::Reactor for Reactor {
                 .map(|block| block.height() + 1)
                 .unwrap_or(0),
             chainspec_loader.chainspec().as_ref(),
+            config.block_proposer,
         )?;
         let mut effects = reactor::wrap_effects(Event::BlockProposer, block_proposer_effects);
         let block_executor = BlockExecutor::new(
             chainspec_loader.initial_state_root_hash(),
             chainspec_loader.initial_block_header(),
             protocol_version.clone(),
+            Some(contract_runtime.execution_lag_threshold()),
             registry.clone(),
         )
         .with_parent_map(latest_block);
@@ -661,6 +698,9 @@ impl reactor::Reactor for Reactor {
                 Event::MetricsRequest,
                 self.metrics.handle_event(effect_builder, rng, req),
             ),
+            Event::HealthRequest(HealthRequest::GetSnapshot { responder }) => {
+                responder.respond(self.health_snapshot()).ignore()
+            }
             Event::ChainspecLoaderRequest(req) => {
                 self.dispatch_event(effect_builder, rng, Event::ChainspecLoader(req.into()))
             }
@@ -932,14 +972,35 @@ impl reactor::Reactor for Reactor {
 
                 effects
             }
+            Event::BlockExecutorAnnouncement(BlockExecutorAnnouncement::ExecutionLagging {
+                ..
+            }) => {
+                // The block executor has already logged this, and the execution times are exposed
+                // as metrics.
+                Effects::new()
+            }
+            Event::BlockProposerAnnouncement(BlockProposerAnnouncement::RebroadcastDeploys(
+                deploy_hashes,
+            )) => deploy_hashes
+                .into_iter()
+                .flat_map(|item_id| {
+                    let event = gossiper::Event::RebroadcastItem { item_id };
+                    self.dispatch_event(effect_builder, rng, Event::DeployGossiper(event))
+                })
+                .collect(),
             Event::DeployGossiperAnnouncement(_ann) => {
                 unreachable!("the deploy gossiper should never make an announcement")
             }
             Event::AddressGossiperAnnouncement(ann) => {
-                let GossiperAnnouncement::NewCompleteItem(gossiped_address) = ann;
-                let reactor_event = Event::SmallNetwork(small_network::Event::PeerAddressReceived(
-                    gossiped_address,
-                ));
+                let GossiperAnnouncement::NewCompleteItem {
+                    item_id: gossiped_address,
+                    sender,
+                } = ann;
+                let reactor_event =
+                    Event::SmallNetwork(small_network::Event::PeerAddressReceived {
+                        gossiped_address,
+                        sender: Box::new(sender),
+                    });
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::BlockAdded(block)) => {
@@ -976,7 +1037,9 @@ impl reactor::Reactor for Reactor {
     fn update_metrics(&mut self, event_queue_handle: EventQueueHandle<Self::Event>) {
         self.memory_metrics.estimate(&self);
         self.event_queue_metrics
-            .record_event_queue_counts(&event_queue_handle)
+            .record_event_queue_counts(&event_queue_handle);
+        // Component health is logged alongside the periodic metrics update.
+        self.health_snapshot().log();
     }
 
     fn maybe_exit(&self) -> Option<ReactorExit> {
@@ -986,6 +1049,27 @@ impl reactor::Reactor for Reactor {
     }
 }
 
+impl Finalize for Reactor {
+    fn finalize(mut self) -> BoxFuture<'static, ()> {
+        let goodbye_reason = if self.consensus.stop_for_upgrade() {
+            GoodbyeReason::Upgrade
+        } else {
+            GoodbyeReason::Shutdown
+        };
+        self.small_network.set_goodbye_reason(Some(goodbye_reason));
+
+        let network = self.network.finalize();
+        let small_network = self.small_network.finalize();
+        let rest_server = self.rest_server.finalize();
+        async move {
+            network.await;
+            small_network.await;
+            rest_server.await;
+        }
+        .boxed()
+    }
+}
+
 #[cfg(test)]
 impl NetworkedReactor for Reactor {
     type NodeId = NodeId;
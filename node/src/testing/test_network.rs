@@ -0,0 +1,471 @@
+//! A harness for running a network of validator reactors in-process.
+//!
+//! A [`TestChain`] holds a chainspec with a given number of bonded validators and any number of
+//! additionally funded accounts, and runs every node through the initializer and joiner reactors
+//! before adding it as a validator to a [`Network`].  The [`TestNetworkBuilder`] sets one up.  The
+//! resulting [`TestNetwork`] can be used to submit deploys, advance eras, schedule upgrades and
+//! inspect finalized blocks and global state on all nodes.
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use anyhow::bail;
+use num::Zero;
+use num_rational::Ratio;
+use rand::Rng;
+use semver::Version;
+use tempfile::TempDir;
+
+use casper_execution_engine::{
+    core::engine_state::{ExecutableDeployItem, QueryRequest, QueryResult},
+    shared::{motes::Motes, stored_value::StoredValue},
+};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, system::auction::DelegationRate, ExecutionResult, Key,
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use crate::{
+    components::{
+        chainspec_loader::NextUpgrade, consensus::EraId, gossiper, small_network, storage,
+    },
+    crypto::AsymmetricKeyExt,
+    effect::EffectExt,
+    reactor::{initializer, joiner, validator, ReactorExit, Runner},
+    testing::{
+        self,
+        network::{Network, Nodes},
+        TestRng,
+    },
+    types::{
+        chainspec::{AccountConfig, AccountsConfig, ValidatorConfig},
+        ActivationPoint, Block, Chainspec, Deploy, DeployHash, NodeId, Timestamp,
+    },
+    utils::{External, Loadable, WithDir, RESOURCES_PATH},
+    NodeRng,
+};
+
+/// Payment amount attached to deploys created by [`TestNetwork::transfer_deploy`].
+const TRANSFER_PAYMENT_AMOUNT: u64 = 10_000_000_000;
+
+/// The chainspec and node configurations of a test network of validators.
+pub(crate) struct TestChain {
+    // Keys that validator instances will use, can include duplicates
+    keys: Vec<SecretKey>,
+    storages: Vec<TempDir>,
+    chainspec: Arc<Chainspec>,
+}
+
+impl TestChain {
+    /// Instantiates a new test chain configuration.
+    ///
+    /// Generates secret keys for `size` validators and creates a matching chainspec.
+    pub(crate) fn new(rng: &mut TestRng, size: usize) -> Self {
+        Self::new_with_funded_accounts(rng, size, Vec::new())
+    }
+
+    /// Instantiates a new test chain configuration.
+    ///
+    /// Generates secret keys for `size` validators and creates a matching chainspec, which also
+    /// funds the given unbonded accounts.
+    pub(crate) fn new_with_funded_accounts(
+        rng: &mut TestRng,
+        size: usize,
+        funded_accounts: Vec<(PublicKey, U512)>,
+    ) -> Self {
+        let keys: Vec<SecretKey> = (0..size).map(|_| SecretKey::random(rng)).collect();
+        let stakes = keys
+            .iter()
+            .map(|secret_key| (PublicKey::from(secret_key), rng.gen_range(100..999)))
+            .collect();
+        Self::create(rng, keys, stakes, funded_accounts)
+    }
+
+    /// Instantiates a new test chain configuration.
+    ///
+    /// Takes a vector of bonded keys with specified bond amounts.
+    pub(crate) fn new_with_keys(
+        rng: &mut TestRng,
+        keys: Vec<SecretKey>,
+        stakes: BTreeMap<PublicKey, u64>,
+    ) -> Self {
+        Self::create(rng, keys, stakes, Vec::new())
+    }
+
+    fn create(
+        rng: &mut TestRng,
+        keys: Vec<SecretKey>,
+        stakes: BTreeMap<PublicKey, u64>,
+        funded_accounts: Vec<(PublicKey, U512)>,
+    ) -> Self {
+        // Load the `local` chainspec.
+        let mut chainspec = Chainspec::from_resources("local");
+
+        // Override accounts with those generated from the keys.
+        let mut accounts: Vec<AccountConfig> = stakes
+            .iter()
+            .map(|(public_key, bounded_amounts_u64)| {
+                let validator_config = ValidatorConfig::new(
+                    Motes::new(U512::from(*bounded_amounts_u64)),
+                    DelegationRate::zero(),
+                );
+                AccountConfig::new(
+                    *public_key,
+                    Motes::new(U512::from(rng.gen_range(10000..99999999))),
+                    Some(validator_config),
+                )
+            })
+            .collect();
+        accounts.extend(funded_accounts.into_iter().map(|(public_key, balance)| {
+            AccountConfig::new(public_key, Motes::new(balance), None)
+        }));
+        let delegators = vec![];
+        chainspec.network_config.accounts_config = AccountsConfig::new(accounts, delegators);
+
+        // Make the genesis timestamp 45 seconds from now, to allow for all validators to start up.
+        chainspec.protocol_config.activation_point =
+            ActivationPoint::Genesis(Timestamp::now() + 45000.into());
+
+        chainspec.core_config.minimum_era_height = 1;
+        chainspec.highway_config.finality_threshold_fraction = Ratio::new(34, 100);
+        chainspec.core_config.era_duration = 10.into();
+        chainspec.core_config.auction_delay = 1;
+        chainspec.core_config.unbonding_delay = 3;
+
+        TestChain {
+            keys,
+            chainspec: Arc::new(chainspec),
+            storages: Vec::new(),
+        }
+    }
+
+    /// Returns the chainspec shared by all nodes.
+    pub(crate) fn chainspec(&self) -> &Chainspec {
+        &self.chainspec
+    }
+
+    /// Creates an initializer/validator configuration for the `idx`th validator.
+    fn create_node_config(&mut self, idx: usize, first_node_port: u16) -> validator::Config {
+        // Set the network configuration.
+        let mut cfg = validator::Config {
+            network: if idx == 0 {
+                small_network::Config::default_local_net_first_node(first_node_port)
+            } else {
+                small_network::Config::default_local_net(first_node_port)
+            },
+            gossip: gossiper::Config::new_with_small_timeouts(),
+            ..Default::default()
+        };
+
+        // ...and the secret key for our validator.
+        cfg.consensus.secret_key_path = External::from_value(self.keys[idx].duplicate());
+
+        // Additionally set up storage in a temporary directory.
+        let (storage_cfg, temp_dir) = storage::Config::default_for_tests();
+        cfg.consensus.unit_hashes_folder = temp_dir.path().to_path_buf();
+        self.storages.push(temp_dir);
+        cfg.storage = storage_cfg;
+
+        cfg
+    }
+
+    /// Runs every validator through the initializer and joiner reactors and adds it to a network.
+    pub(crate) async fn create_initialized_network(
+        &mut self,
+        rng: &mut NodeRng,
+    ) -> anyhow::Result<Network<validator::Reactor>> {
+        let root = RESOURCES_PATH.join("local");
+
+        let mut network: Network<validator::Reactor> = Network::new();
+        let first_node_port = testing::unused_port_on_localhost();
+
+        for idx in 0..self.keys.len() {
+            let cfg = self.create_node_config(idx, first_node_port);
+
+            // We create an initializer reactor here and run it to completion.
+            let mut initializer_runner = Runner::<initializer::Reactor>::new_with_chainspec(
+                WithDir::new(root.clone(), cfg),
+                Arc::clone(&self.chainspec),
+            )
+            .await?;
+            let reactor_exit = initializer_runner.run(rng).await;
+            if reactor_exit != ReactorExit::ProcessShouldContinue {
+                bail!("failed to initialize successfully");
+            }
+
+            // Now we can construct the actual node.
+            let initializer = initializer_runner.into_inner();
+            let mut joiner_runner =
+                Runner::<joiner::Reactor>::new(WithDir::new(root.clone(), initializer), rng)
+                    .await?;
+            let _ = joiner_runner.run(rng).await;
+
+            let config = joiner_runner.into_inner().into_validator_config().await?;
+
+            network
+                .add_node_with_config(config, rng)
+                .await
+                .expect("could not add node to reactor");
+        }
+
+        Ok(network)
+    }
+}
+
+/// Builder for a [`TestNetwork`].
+pub(crate) struct TestNetworkBuilder {
+    /// Number of validator nodes to run, each bonded with a random stake.
+    validator_count: usize,
+    /// Accounts funded at genesis which are not bonded.
+    funded_accounts: Vec<(PublicKey, U512)>,
+}
+
+impl TestNetworkBuilder {
+    /// Creates a new builder for a network of `validator_count` validators.
+    pub(crate) fn new(validator_count: usize) -> Self {
+        assert!(
+            validator_count >= 1,
+            "network must have at least one validator (count: {})",
+            validator_count
+        );
+        TestNetworkBuilder {
+            validator_count,
+            funded_accounts: Vec::new(),
+        }
+    }
+
+    /// Funds the account of `public_key` with `balance` motes at genesis.
+    pub(crate) fn with_funded_account(mut self, public_key: PublicKey, balance: U512) -> Self {
+        self.funded_accounts.push((public_key, balance));
+        self
+    }
+
+    /// Creates the chainspec, initializes every node and connects them into a network.
+    pub(crate) async fn build(self, rng: &mut TestRng) -> anyhow::Result<TestNetwork> {
+        let mut chain =
+            TestChain::new_with_funded_accounts(rng, self.validator_count, self.funded_accounts);
+        let network = chain.create_initialized_network(rng).await?;
+        Ok(TestNetwork { network, chain })
+    }
+}
+
+/// A network of validator reactors running in the current process.
+pub(crate) struct TestNetwork {
+    network: Network<validator::Reactor>,
+    /// The chain configuration, which also keeps the nodes' storage directories alive.
+    chain: TestChain,
+}
+
+impl TestNetwork {
+    /// Creates a signed deploy transferring `amount` motes from the account of `secret_key` to the
+    /// account of `target`.
+    pub(crate) fn transfer_deploy(
+        &self,
+        rng: &mut TestRng,
+        secret_key: &SecretKey,
+        target: PublicKey,
+        amount: U512,
+    ) -> Deploy {
+        let payment = ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: runtime_args! { "amount" => U512::from(TRANSFER_PAYMENT_AMOUNT) },
+        };
+        let session = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                "amount" => amount,
+                "target" => target.to_account_hash(),
+                "id" => Some(rng.gen::<u64>()),
+            },
+        };
+        Deploy::new(
+            Timestamp::now(),
+            self.chain.chainspec.deploy_config.max_ttl,
+            1,
+            None,
+            vec![],
+            self.chain.chainspec.network_config.name.clone(),
+            payment,
+            session,
+            secret_key,
+        )
+    }
+
+    /// Submits `deploy` to every node, as if it had been received via the JSON-RPC API.
+    pub(crate) async fn submit_deploy(&mut self, deploy: Deploy) {
+        let node_ids: Vec<NodeId> = self.network.nodes().keys().cloned().collect();
+        for node_id in node_ids {
+            let deploy = Box::new(deploy.clone());
+            self.network
+                .process_injected_effect_on(&node_id, |effect_builder| {
+                    effect_builder
+                        .announce_deploy_received(deploy, None)
+                        .ignore()
+                })
+                .await;
+        }
+    }
+
+    /// Runs the network until all nodes are in `era_id`.
+    pub(crate) async fn advance_to_era(
+        &mut self,
+        rng: &mut TestRng,
+        era_id: EraId,
+        within: Duration,
+    ) {
+        self.network.settle_on(rng, is_in_era(era_id), within).await;
+    }
+
+    /// Runs the network until every node has executed `deploy_hash` in a finalized block.
+    pub(crate) async fn settle_on_deploy_executed(
+        &mut self,
+        rng: &mut TestRng,
+        deploy_hash: DeployHash,
+        within: Duration,
+    ) {
+        self.network
+            .settle_on(rng, has_executed(deploy_hash), within)
+            .await;
+    }
+
+    /// Announces an upgrade to `protocol_version`, activated at `activation_era`, to all nodes.
+    pub(crate) async fn schedule_upgrade(
+        &mut self,
+        activation_era: EraId,
+        protocol_version: Version,
+    ) {
+        let next_upgrade =
+            NextUpgrade::new(ActivationPoint::EraId(activation_era), protocol_version);
+        let node_ids: Vec<NodeId> = self.network.nodes().keys().cloned().collect();
+        for node_id in node_ids {
+            let next_upgrade = next_upgrade.clone();
+            self.network
+                .process_injected_effect_on(&node_id, |effect_builder| {
+                    effect_builder
+                        .announce_upgrade_activation_point_read(next_upgrade)
+                        .ignore()
+                })
+                .await;
+        }
+    }
+
+    /// Runs the network until every node has stopped to allow a scheduled upgrade to proceed.
+    pub(crate) async fn settle_on_upgrade(&mut self, rng: &mut TestRng, within: Duration) {
+        self.network
+            .settle_on(
+                rng,
+                |nodes: &Nodes<validator::Reactor>| {
+                    nodes
+                        .values()
+                        .all(|runner| runner.reactor().inner().consensus().stop_for_upgrade())
+                },
+                within,
+            )
+            .await;
+    }
+
+    /// Returns the highest block stored by each node.
+    pub(crate) fn highest_blocks(&self) -> Vec<Block> {
+        self.network
+            .reactors()
+            .map(|reactor| {
+                reactor
+                    .storage()
+                    .transactional_get_highest_block()
+                    .expect("node should have stored at least one block")
+            })
+            .collect()
+    }
+
+    /// Asserts that all nodes agree on the block at the lowest of their highest heights, and
+    /// returns it.
+    pub(crate) fn assert_finalized_blocks_agree(&self) -> Block {
+        let highest_blocks = self.highest_blocks();
+        let lowest = highest_blocks
+            .iter()
+            .min_by_key(|block| block.height())
+            .expect("network should have at least one node")
+            .clone();
+        for block in &highest_blocks {
+            if block.height() == lowest.height() {
+                assert_eq!(
+                    block.hash(),
+                    lowest.hash(),
+                    "nodes disagree on block at height {}",
+                    lowest.height()
+                );
+            }
+        }
+        lowest
+    }
+
+    /// Asserts that every node executed `deploy_hash` successfully.
+    pub(crate) fn assert_deploy_succeeded(&self, deploy_hash: &DeployHash) {
+        for reactor in self.network.reactors() {
+            let metadata = reactor
+                .storage()
+                .get_deploy_metadata_by_hash(deploy_hash)
+                .unwrap_or_else(|| panic!("deploy {} should have been executed", deploy_hash));
+            assert!(
+                !metadata.execution_results.is_empty(),
+                "deploy {} should have an execution result",
+                deploy_hash
+            );
+            for execution_result in metadata.execution_results.values() {
+                if let ExecutionResult::Failure { error_message, .. } = execution_result {
+                    panic!("deploy {} failed: {}", deploy_hash, error_message);
+                }
+            }
+        }
+    }
+
+    /// Queries global state under `key` and `path` at `block`'s state root on every node, asserting
+    /// that all nodes return the same value.
+    pub(crate) fn query_global_state(
+        &self,
+        block: &Block,
+        key: Key,
+        path: &[String],
+    ) -> StoredValue {
+        let mut values = self.network.reactors().map(|reactor| {
+            let query_request =
+                QueryRequest::new((*block.state_root_hash()).into(), key, path.to_vec());
+            match reactor
+                .contract_runtime()
+                .query_global_state(query_request)
+                .expect("global state query should not fail")
+            {
+                QueryResult::Success { value, .. } => *value,
+                other => panic!("global state query for {} returned {:?}", key, other),
+            }
+        });
+        let value = values
+            .next()
+            .expect("network should have at least one node");
+        for other_value in values {
+            assert_eq!(value, other_value, "nodes disagree on value under {}", key);
+        }
+        value
+    }
+}
+
+/// Returns a predicate checking whether all nodes are in the given era.
+pub(crate) fn is_in_era(era_id: EraId) -> impl Fn(&Nodes<validator::Reactor>) -> bool {
+    move |nodes: &Nodes<validator::Reactor>| {
+        nodes
+            .values()
+            .all(|runner| runner.reactor().inner().consensus().current_era() == era_id)
+    }
+}
+
+/// Returns a predicate checking whether all nodes have an execution result for the given deploy.
+fn has_executed(deploy_hash: DeployHash) -> impl Fn(&Nodes<validator::Reactor>) -> bool {
+    move |nodes: &Nodes<validator::Reactor>| {
+        nodes.values().all(|runner| {
+            runner
+                .reactor()
+                .inner()
+                .storage()
+                .get_deploy_metadata_by_hash(&deploy_hash)
+                .map_or(false, |metadata| !metadata.execution_results.is_empty())
+        })
+    }
+}
@@ -18,12 +18,13 @@ use rand::{CryptoRng, RngCore};
 use rand_chacha::ChaCha20Rng;
 
 pub use block::{
-    json_compatibility::JsonBlock, Block, BlockBody, BlockHash, BlockHeader, BlockSignatures,
-    BlockValidationError, FinalitySignature,
+    json_compatibility::{JsonBlock, JsonEraEnd},
+    Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, BlockValidationError,
+    FinalitySignature,
 };
 pub(crate) use block::{BlockByHeight, BlockLike, FinalizedBlock, ProtoBlock};
 pub(crate) use chainspec::ActivationPoint;
-pub use chainspec::Chainspec;
+pub use chainspec::{Chainspec, DeployLimits};
 pub use deploy::{
     Approval, Deploy, DeployHash, DeployHeader, DeployMetadata, DeployValidationFailure,
     Error as DeployError, ExcessiveSizeError as ExcessiveSizeDeployError,
@@ -33,7 +34,10 @@ pub use item::{Item, Tag};
 pub use node_config::NodeConfig;
 pub(crate) use node_id::NodeId;
 pub use peers_map::PeersMap;
-pub use status_feed::{ChainspecInfo, GetStatusResult, StatusFeed};
+pub use status_feed::{
+    ChainspecInfo, ComponentHealth, ComponentStatus, GetReadinessResult, GetStatusResult,
+    HealthSnapshot, StatusFeed,
+};
 pub use timestamp::{TimeDiff, Timestamp};
 
 /// An object-safe RNG trait that requires a cryptographically strong random number generator.
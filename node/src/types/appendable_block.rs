@@ -29,9 +29,16 @@ pub(crate) enum AddError {
 }
 
 /// A block that is still being added to. It keeps track of and enforces block limits.
+///
+/// If the chainspec's `transfers_count_toward_block_gas_limit` is set, each transfer costs a fixed
+/// amount of gas, the cost of a wasmless transfer. The block gas limit then applies to the total
+/// gas of deploys and transfers, but only once the block contains at least one wasm deploy: a block
+/// containing only transfers is limited by the transfer count alone.
 #[derive(Clone, DataSize, Debug)]
 pub struct AppendableBlock {
     deploy_config: DeployConfig,
+    #[data_size(skip)]
+    transfer_cost: Gas,
     deploy_hashes: Vec<DeployHash>,
     transfer_hashes: Vec<DeployHash>,
     deploy_and_transfer_set: HashSet<DeployHash>,
@@ -43,9 +50,14 @@ pub struct AppendableBlock {
 
 impl AppendableBlock {
     /// Creates an empty `AppendableBlock`.
-    pub(crate) fn new(deploy_config: DeployConfig, timestamp: Timestamp) -> Self {
+    pub(crate) fn new(
+        deploy_config: DeployConfig,
+        transfer_cost: Gas,
+        timestamp: Timestamp,
+    ) -> Self {
         AppendableBlock {
             deploy_config,
+            transfer_cost,
             deploy_hashes: Vec::new(),
             transfer_hashes: Vec::new(),
             timestamp,
@@ -60,6 +72,11 @@ impl AppendableBlock {
         self.total_size
     }
 
+    /// Returns the total gas of all deploys and transfers so far.
+    pub(crate) fn total_gas(&self) -> Gas {
+        self.total_gas
+    }
+
     /// Attempts to add a deploy to the block; returns an error if that would violate a validity
     /// condition.
     pub(crate) fn add(
@@ -80,12 +97,22 @@ impl AppendableBlock {
             if self.has_max_transfer_count() {
                 return Err(AddError::TransferCount);
             }
+            if self.deploy_config.transfers_count_toward_block_gas_limit {
+                let new_total_gas = self
+                    .total_gas
+                    .checked_add(self.transfer_cost)
+                    .ok_or(AddError::GasLimit)?;
+                if !self.deploy_hashes.is_empty() && self.exceeds_gas_limit(new_total_gas) {
+                    return Err(AddError::GasLimit);
+                }
+                self.total_gas = new_total_gas;
+            }
             self.transfer_hashes.push(hash);
         } else {
             if self.has_max_deploy_count() {
                 return Err(AddError::DeployCount);
             }
-            // Only deploys count towards the size and gas limits.
+            // Only deploys count towards the size limit.
             let new_total_size = self
                 .total_size
                 .checked_add(deploy_type.size())
@@ -96,7 +123,7 @@ impl AppendableBlock {
             let gas =
                 Gas::from_motes(payment_amount, gas_price).ok_or(AddError::InvalidGasAmount)?;
             let new_total_gas = self.total_gas.checked_add(gas).ok_or(AddError::GasLimit)?;
-            if new_total_gas > Gas::from(self.deploy_config.block_gas_limit) {
+            if self.exceeds_gas_limit(new_total_gas) {
                 return Err(AddError::GasLimit);
             }
             self.deploy_hashes.push(hash);
@@ -130,4 +157,9 @@ impl AppendableBlock {
     fn has_max_deploy_count(&self) -> bool {
         self.deploy_hashes.len() == self.deploy_config.block_max_deploy_count as usize
     }
+
+    /// Returns `true` if the given amount of gas exceeds the block gas limit.
+    fn exceeds_gas_limit(&self, gas: Gas) -> bool {
+        gas > Gas::from(self.deploy_config.block_gas_limit)
+    }
 }
@@ -1389,13 +1389,21 @@ pub(crate) mod json_compatibility {
         }
     }
 
+    /// A JSON-friendly representation of `EraEnd`.
     #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
     #[serde(deny_unknown_fields)]
-    struct JsonEraEnd {
+    pub struct JsonEraEnd {
         era_report: JsonEraReport,
         next_era_validator_weights: Vec<ValidatorWeight>,
     }
 
+    impl JsonEraEnd {
+        /// Returns the era end of the given block header, or `None` if it is not a switch block.
+        pub fn from_block_header(block_header: &BlockHeader) -> Option<Self> {
+            block_header.era_end.clone().map(JsonEraEnd::from)
+        }
+    }
+
     impl From<EraEnd> for JsonEraEnd {
         fn from(data: EraEnd) -> Self {
             let json_era_end = JsonEraReport::from(data.era_report);
@@ -26,17 +26,23 @@ use casper_execution_engine::{
 };
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::DELEGATION_RATE_DENOMINATOR,
     ProtocolVersion,
 };
 
 #[cfg(test)]
 pub(crate) use self::accounts_config::{AccountConfig, ValidatorConfig};
-pub use self::error::Error;
 pub(crate) use self::{
-    accounts_config::AccountsConfig, activation_point::ActivationPoint, core_config::CoreConfig,
-    deploy_config::DeployConfig, global_state_update::GlobalStateUpdate,
-    highway_config::HighwayConfig, network_config::NetworkConfig, protocol_config::ProtocolConfig,
+    accounts_config::AccountsConfig,
+    activation_point::ActivationPoint,
+    core_config::{CoreConfig, EraEndPolicy},
+    deploy_config::DeployConfig,
+    global_state_update::GlobalStateUpdate,
+    highway_config::HighwayConfig,
+    network_config::NetworkConfig,
+    protocol_config::ProtocolConfig,
 };
+pub use self::{deploy_config::DeployLimits, error::Error};
 #[cfg(test)]
 use crate::testing::TestRng;
 use crate::{
@@ -81,6 +87,19 @@ impl Chainspec {
             warn!("era duration is less than minimum era height * round length!");
         }
 
+        if self.core_config.era_end_policy == EraEndPolicy::HeightOrDuration
+            && self.core_config.era_duration.millis() == 0
+        {
+            warn!("era duration is zero, so every era will end after its first block!");
+        }
+
+        if self.core_config.max_delegation_rate > DELEGATION_RATE_DENOMINATOR {
+            warn!(
+                "max delegation rate exceeds {}, so it will not be effective!",
+                DELEGATION_RATE_DENOMINATOR
+            );
+        }
+
         self.highway_config.validate_config();
     }
 
@@ -194,9 +213,11 @@ impl From<&Chainspec> for ExecConfig {
             chainspec.system_costs_config,
             chainspec.core_config.validator_slots,
             chainspec.core_config.auction_delay,
-            chainspec.core_config.locked_funds_period.millis(),
             chainspec.core_config.round_seigniorage_rate,
             chainspec.core_config.unbonding_delay,
+            chainspec.core_config.max_delegation_rate,
+            chainspec.core_config.max_delegation_rate_change,
+            chainspec.core_config.max_delegators_per_validator,
             chainspec
                 .protocol_config
                 .activation_point
@@ -274,11 +295,29 @@ mod tests {
             remove_contract_user_group_urefs: HostFunction::new(131, [0, 1, 2, 3, 4, 5]),
             print: HostFunction::new(123, [0, 1]),
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
+            lock_contract_package: HostFunction::new(142, [0, 1]),
+            emit_event: HostFunction::new(143, [0, 1]),
+            call_contract_with_gas_limit: HostFunction::new(144, [0, 1, 2, 3, 4, 5, 6, 7, 8]),
+            call_versioned_contract_with_gas_limit: HostFunction::new(
+                145,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            ),
+            transfer_from_purse_to_account_with_receipt: HostFunction::new(
+                146,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            ),
+            provision_contract_user_group_urefs: HostFunction::new(147, [0, 1, 2, 3, 4, 5]),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
             17, // initial_memory
             19, // max_stack_height
+            21, // max_named_key_length
+            23, // max_cl_value_size
+            25, // max_named_keys_count
+            27, // max_associated_keys
+            29, // max_action_threshold
+            31, // max_contract_wasm_size
             EXPECTED_GENESIS_COSTS,
             EXPECTED_GENESIS_STORAGE_COSTS,
             *EXPECTED_GENESIS_HOST_FUNCTION_COSTS,
@@ -345,6 +384,10 @@ mod tests {
 
         assert_eq!(spec.core_config.era_duration, TimeDiff::from(180000));
         assert_eq!(spec.core_config.minimum_era_height, 9);
+        assert_eq!(
+            spec.core_config.era_end_policy,
+            EraEndPolicy::HeightAndDuration
+        );
         assert_eq!(
             spec.highway_config.finality_threshold_fraction,
             Ratio::new(2, 25)
@@ -365,6 +408,8 @@ mod tests {
         assert_eq!(spec.deploy_config.max_block_size, 12);
         assert_eq!(spec.deploy_config.block_max_deploy_count, 125);
         assert_eq!(spec.deploy_config.block_gas_limit, 13);
+        assert!(!spec.deploy_config.no_fee_mode);
+        assert!(!spec.deploy_config.transfers_count_toward_block_gas_limit);
 
         assert_eq!(spec.wasm_config, *EXPECTED_GENESIS_WASM_COSTS);
     }
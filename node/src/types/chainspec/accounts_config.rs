@@ -3,6 +3,7 @@
 mod account_config;
 mod delegator_config;
 mod validator_config;
+mod vesting_config;
 
 use std::path::Path;
 
@@ -20,6 +21,7 @@ use super::error::ChainspecAccountsLoadError;
 pub use account_config::AccountConfig;
 pub use delegator_config::DelegatorConfig;
 pub use validator_config::ValidatorConfig;
+pub use vesting_config::VestingConfig;
 
 const CHAINSPEC_ACCOUNTS_FILENAME: &str = "accounts.toml";
 
@@ -134,6 +136,9 @@ impl From<AccountsConfig> for Vec<GenesisAccount> {
 
 #[cfg(test)]
 mod tests {
+    use casper_execution_engine::shared::motes::Motes;
+    use casper_types::{system::auction::DelegationRate, U512};
+
     use super::*;
 
     #[test]
@@ -142,4 +147,24 @@ mod tests {
         let accounts_config = AccountsConfig::random(&mut rng);
         bytesrepr::test_serialization_roundtrip(&accounts_config);
     }
+
+    #[test]
+    fn validator_config_serialization_roundtrip() {
+        let validator_config = ValidatorConfig::new(Motes::new(U512::from(1000)), 10);
+        bytesrepr::test_serialization_roundtrip(&validator_config);
+        bytesrepr::test_serialization_roundtrip(
+            &validator_config.with_vesting(VestingConfig::new(2, 4)),
+        );
+    }
+
+    #[test]
+    fn should_serialize_validator_config_without_vesting_as_before() {
+        let bonded_amount = Motes::new(U512::from(1000));
+        let delegation_rate: DelegationRate = 10;
+        let mut expected_bytes = bonded_amount.to_bytes().unwrap();
+        expected_bytes.append(&mut delegation_rate.to_bytes().unwrap());
+
+        let validator_config = ValidatorConfig::new(bonded_amount, delegation_rate);
+        assert_eq!(validator_config.to_bytes().unwrap(), expected_bytes);
+    }
 }
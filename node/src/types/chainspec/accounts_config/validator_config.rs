@@ -14,14 +14,24 @@ use casper_types::{
     system::auction::DelegationRate,
 };
 
+use super::VestingConfig;
 #[cfg(test)]
 use crate::testing::TestRng;
 
+/// The leading byte of a serialized validator config with a vesting schedule.
+///
+/// Configs without one are serialized as they were before vesting was configurable, starting with
+/// the length of the bonded amount, which is never this value.
+const VESTING_TAG: u8 = u8::max_value();
+
 #[derive(PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize, DataSize, Debug, Copy, Clone)]
 pub struct ValidatorConfig {
     bonded_amount: Motes,
     #[serde(default = "DelegationRate::zero")]
     delegation_rate: DelegationRate,
+    /// Era-based vesting of the bonded amount, replacing the locked funds period if set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vesting: Option<VestingConfig>,
 }
 
 impl ValidatorConfig {
@@ -29,9 +39,16 @@ impl ValidatorConfig {
         Self {
             bonded_amount,
             delegation_rate,
+            vesting: None,
         }
     }
 
+    /// Sets the era-based vesting of the bonded amount.
+    pub fn with_vesting(mut self, vesting: VestingConfig) -> Self {
+        self.vesting = Some(vesting);
+        self
+    }
+
     pub fn delegation_rate(&self) -> DelegationRate {
         self.delegation_rate
     }
@@ -40,15 +57,21 @@ impl ValidatorConfig {
         self.bonded_amount
     }
 
+    pub fn vesting(&self) -> Option<VestingConfig> {
+        self.vesting
+    }
+
     #[cfg(test)]
     /// Generates a random instance using a `TestRng`.
     pub fn random(rng: &mut TestRng) -> Self {
         let bonded_amount = Motes::new(U512::from(rng.gen::<u64>()));
         let delegation_rate = rng.gen();
+        let vesting = rng.gen();
 
         ValidatorConfig {
             bonded_amount,
             delegation_rate,
+            vesting,
         }
     }
 }
@@ -61,31 +84,59 @@ impl Distribution<ValidatorConfig> for Standard {
         let bonded_amount = Motes::new(U512::from(u512_array));
 
         let delegation_rate = rng.gen();
+        let vesting = rng.gen();
 
-        ValidatorConfig::new(bonded_amount, delegation_rate)
+        ValidatorConfig {
+            bonded_amount,
+            delegation_rate,
+            vesting,
+        }
     }
 }
 
 impl ToBytes for ValidatorConfig {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        if self.vesting.is_some() {
+            buffer.push(VESTING_TAG);
+        }
         buffer.extend(self.bonded_amount.to_bytes()?);
         buffer.extend(self.delegation_rate.to_bytes()?);
+        if let Some(vesting) = self.vesting {
+            buffer.extend(vesting.to_bytes()?);
+        }
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.bonded_amount.serialized_length() + self.delegation_rate.serialized_length()
+        let vesting_length = match self.vesting {
+            Some(vesting) => VESTING_TAG.serialized_length() + vesting.serialized_length(),
+            None => 0,
+        };
+        self.bonded_amount.serialized_length()
+            + self.delegation_rate.serialized_length()
+            + vesting_length
     }
 }
 
 impl FromBytes for ValidatorConfig {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (bonded_amount, remainder) = FromBytes::from_bytes(bytes)?;
+        let (has_vesting, remainder) = match bytes.split_first() {
+            Some((&VESTING_TAG, remainder)) => (true, remainder),
+            _ => (false, bytes),
+        };
+        let (bonded_amount, remainder) = FromBytes::from_bytes(remainder)?;
         let (delegation_rate, remainder) = FromBytes::from_bytes(remainder)?;
+        let (vesting, remainder) = if has_vesting {
+            let (vesting, remainder) = FromBytes::from_bytes(remainder)?;
+            (Some(vesting), remainder)
+        } else {
+            (None, remainder)
+        };
         let account_config = ValidatorConfig {
             bonded_amount,
             delegation_rate,
+            vesting,
         };
         Ok((account_config, remainder))
     }
@@ -93,9 +144,13 @@ impl FromBytes for ValidatorConfig {
 
 impl From<ValidatorConfig> for GenesisValidator {
     fn from(account_config: ValidatorConfig) -> Self {
-        GenesisValidator::new(
+        let genesis_validator = GenesisValidator::new(
             account_config.bonded_amount(),
             account_config.delegation_rate,
-        )
+        );
+        match account_config.vesting {
+            Some(vesting) => genesis_validator.with_vesting(vesting.into()),
+            None => genesis_validator,
+        }
     }
 }
@@ -0,0 +1,68 @@
+use datasize::DataSize;
+#[cfg(test)]
+use rand::{distributions::Standard, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::core::engine_state::genesis::GenesisVesting;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+/// Era-based vesting of a genesis validator's stake and of the stakes delegated to it at genesis.
+#[derive(PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize, DataSize, Debug, Copy, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct VestingConfig {
+    /// Number of eras after genesis during which the whole stake is locked.
+    cliff_eras: u64,
+    /// Number of eras after the cliff over which the stake is released in equal installments.
+    release_eras: u64,
+}
+
+impl VestingConfig {
+    pub fn new(cliff_eras: u64, release_eras: u64) -> Self {
+        Self {
+            cliff_eras,
+            release_eras,
+        }
+    }
+
+    pub fn cliff_eras(&self) -> u64 {
+        self.cliff_eras
+    }
+
+    pub fn release_eras(&self) -> u64 {
+        self.release_eras
+    }
+}
+
+#[cfg(test)]
+impl Distribution<VestingConfig> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> VestingConfig {
+        VestingConfig::new(rng.gen::<u32>() as u64, rng.gen::<u32>() as u64)
+    }
+}
+
+impl ToBytes for VestingConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.cliff_eras.to_bytes()?);
+        buffer.extend(self.release_eras.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.cliff_eras.serialized_length() + self.release_eras.serialized_length()
+    }
+}
+
+impl FromBytes for VestingConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (cliff_eras, remainder) = FromBytes::from_bytes(bytes)?;
+        let (release_eras, remainder) = FromBytes::from_bytes(remainder)?;
+        Ok((VestingConfig::new(cliff_eras, release_eras), remainder))
+    }
+}
+
+impl From<VestingConfig> for GenesisVesting {
+    fn from(vesting_config: VestingConfig) -> Self {
+        GenesisVesting::new(vesting_config.cliff_eras, vesting_config.release_eras)
+    }
+}
@@ -4,7 +4,12 @@ use num::rational::Ratio;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+#[cfg(test)]
+use casper_types::system::auction::DELEGATION_RATE_DENOMINATOR;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::DelegationRate,
+};
 
 #[cfg(test)]
 use crate::testing::TestRng;
@@ -16,15 +21,22 @@ use crate::types::TimeDiff;
 pub struct CoreConfig {
     pub(crate) era_duration: TimeDiff,
     pub(crate) minimum_era_height: u64,
+    /// Whether an era ends once both `era_duration` and `minimum_era_height` are reached, or as
+    /// soon as either of them is.
+    pub(crate) era_end_policy: EraEndPolicy,
     pub(crate) validator_slots: u32,
     /// Number of eras before an auction actually defines the set of validators.
     /// If you bond with a sufficient bid in era N, you will be a validator in era N +
     /// auction_delay + 1
     pub(crate) auction_delay: u64,
-    /// The period after genesis during which a genesis validator's bid is locked.
-    pub(crate) locked_funds_period: TimeDiff,
     /// The delay in number of eras for paying out the the unbonding amount.
     pub(crate) unbonding_delay: u64,
+    /// The maximum delegation rate a validator may set.
+    pub(crate) max_delegation_rate: DelegationRate,
+    /// The maximum amount by which a validator may change its delegation rate in a single era.
+    pub(crate) max_delegation_rate_change: DelegationRate,
+    /// The maximum number of delegators, including contract delegators, a validator may have.
+    pub(crate) max_delegators_per_validator: u32,
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
@@ -36,10 +48,17 @@ impl CoreConfig {
     pub fn random(rng: &mut TestRng) -> Self {
         let era_duration = TimeDiff::from(rng.gen_range(600_000..604_800_000));
         let minimum_era_height = rng.gen_range(5..100);
+        let era_end_policy = if rng.gen() {
+            EraEndPolicy::HeightAndDuration
+        } else {
+            EraEndPolicy::HeightOrDuration
+        };
         let validator_slots = rng.gen();
         let auction_delay = rng.gen::<u32>() as u64;
-        let locked_funds_period = TimeDiff::from(rng.gen_range(600_000..604_800_000));
         let unbonding_delay = rng.gen_range(1..1_000_000_000);
+        let max_delegation_rate = rng.gen_range(0..=DELEGATION_RATE_DENOMINATOR);
+        let max_delegation_rate_change = rng.gen_range(0..=max_delegation_rate);
+        let max_delegators_per_validator = rng.gen();
         let round_seigniorage_rate = Ratio::new(
             rng.gen_range(1..1_000_000_000),
             rng.gen_range(1..1_000_000_000),
@@ -48,10 +67,13 @@ impl CoreConfig {
         CoreConfig {
             era_duration,
             minimum_era_height,
+            era_end_policy,
             validator_slots,
             auction_delay,
-            locked_funds_period,
             unbonding_delay,
+            max_delegation_rate,
+            max_delegation_rate_change,
+            max_delegators_per_validator,
             round_seigniorage_rate,
         }
     }
@@ -62,10 +84,13 @@ impl ToBytes for CoreConfig {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
         buffer.extend(self.era_duration.to_bytes()?);
         buffer.extend(self.minimum_era_height.to_bytes()?);
+        buffer.extend(self.era_end_policy.to_bytes()?);
         buffer.extend(self.validator_slots.to_bytes()?);
         buffer.extend(self.auction_delay.to_bytes()?);
-        buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
+        buffer.extend(self.max_delegation_rate.to_bytes()?);
+        buffer.extend(self.max_delegation_rate_change.to_bytes()?);
+        buffer.extend(self.max_delegators_per_validator.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
         Ok(buffer)
     }
@@ -73,10 +98,13 @@ impl ToBytes for CoreConfig {
     fn serialized_length(&self) -> usize {
         self.era_duration.serialized_length()
             + self.minimum_era_height.serialized_length()
+            + self.era_end_policy.serialized_length()
             + self.validator_slots.serialized_length()
             + self.auction_delay.serialized_length()
-            + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
+            + self.max_delegation_rate.serialized_length()
+            + self.max_delegation_rate_change.serialized_length()
+            + self.max_delegators_per_validator.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
     }
 }
@@ -85,24 +113,92 @@ impl FromBytes for CoreConfig {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (era_duration, remainder) = TimeDiff::from_bytes(bytes)?;
         let (minimum_era_height, remainder) = u64::from_bytes(remainder)?;
+        let (era_end_policy, remainder) = EraEndPolicy::from_bytes(remainder)?;
         let (validator_slots, remainder) = u32::from_bytes(remainder)?;
         let (auction_delay, remainder) = u64::from_bytes(remainder)?;
-        let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
+        let (max_delegation_rate, remainder) = DelegationRate::from_bytes(remainder)?;
+        let (max_delegation_rate_change, remainder) = DelegationRate::from_bytes(remainder)?;
+        let (max_delegators_per_validator, remainder) = u32::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
+            era_end_policy,
             validator_slots,
             auction_delay,
-            locked_funds_period,
             unbonding_delay,
+            max_delegation_rate,
+            max_delegation_rate_change,
+            max_delegators_per_validator,
             round_seigniorage_rate,
         };
         Ok((config, remainder))
     }
 }
 
+/// The condition for the last block of an era, in terms of the chainspec's `minimum_era_height` and
+/// `era_duration`.
+#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum EraEndPolicy {
+    /// The last block is the first one that has at least the minimum height _and_ is at least the
+    /// era duration after the start of the era.
+    HeightAndDuration,
+    /// The last block is the first one that has at least the minimum height _or_ is at least the
+    /// era duration after the start of the era, whichever comes first.
+    HeightOrDuration,
+}
+
+impl EraEndPolicy {
+    const HEIGHT_AND_DURATION_TAG: u8 = 0;
+    const HEIGHT_OR_DURATION_TAG: u8 = 1;
+
+    /// Returns whether a block ends the era, given whether it has reached the minimum height and
+    /// whether it has reached the era duration.
+    pub(crate) fn is_era_end(self, height_reached: bool, duration_reached: bool) -> bool {
+        match self {
+            EraEndPolicy::HeightAndDuration => height_reached && duration_reached,
+            EraEndPolicy::HeightOrDuration => height_reached || duration_reached,
+        }
+    }
+
+    /// Returns the minimum length of an era, given the time it takes to reach the minimum height
+    /// at the minimum round length, and the era duration.
+    pub(crate) fn min_era_length(self, by_height: TimeDiff, by_duration: TimeDiff) -> TimeDiff {
+        match self {
+            EraEndPolicy::HeightAndDuration => by_height.max(by_duration),
+            EraEndPolicy::HeightOrDuration => by_height.min(by_duration),
+        }
+    }
+}
+
+impl ToBytes for EraEndPolicy {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            EraEndPolicy::HeightAndDuration => Self::HEIGHT_AND_DURATION_TAG,
+            EraEndPolicy::HeightOrDuration => Self::HEIGHT_OR_DURATION_TAG,
+        };
+        Ok(vec![tag])
+    }
+
+    fn serialized_length(&self) -> usize {
+        bytesrepr::U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for EraEndPolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let policy = match tag {
+            Self::HEIGHT_AND_DURATION_TAG => EraEndPolicy::HeightAndDuration,
+            Self::HEIGHT_OR_DURATION_TAG => EraEndPolicy::HeightOrDuration,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((policy, remainder))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +218,22 @@ mod tests {
         let decoded = toml::from_str(&encoded).unwrap();
         assert_eq!(config, decoded);
     }
+
+    #[test]
+    fn era_end_policy_should_require_height_and_or_duration() {
+        let and = EraEndPolicy::HeightAndDuration;
+        assert!(and.is_era_end(true, true));
+        assert!(!and.is_era_end(true, false));
+        assert!(!and.is_era_end(false, true));
+
+        let or = EraEndPolicy::HeightOrDuration;
+        assert!(or.is_era_end(true, true));
+        assert!(or.is_era_end(true, false));
+        assert!(or.is_era_end(false, true));
+        assert!(!or.is_era_end(false, false));
+
+        let (short, long) = (TimeDiff::from(1_000), TimeDiff::from(2_000));
+        assert_eq!(and.min_era_length(short, long), long);
+        assert_eq!(or.min_era_length(short, long), short);
+    }
 }
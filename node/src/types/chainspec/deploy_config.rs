@@ -6,6 +6,7 @@ use datasize::DataSize;
 use num_traits::Zero;
 #[cfg(test)]
 use rand::Rng;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -35,6 +36,8 @@ pub struct DeployConfig {
     pub(crate) payment_args_max_length: u32,
     pub(crate) session_args_max_length: u32,
     pub(crate) native_transfer_minimum_motes: u64,
+    pub(crate) no_fee_mode: bool,
+    pub(crate) transfers_count_toward_block_gas_limit: bool,
 }
 
 #[cfg(test)]
@@ -53,6 +56,8 @@ impl DeployConfig {
         let session_args_max_length = rng.gen();
         let native_transfer_minimum_motes =
             rng.gen_range(MAX_PAYMENT_AMOUNT..1_000_000_000_000_000);
+        let no_fee_mode = rng.gen();
+        let transfers_count_toward_block_gas_limit = rng.gen();
 
         DeployConfig {
             max_payment_cost,
@@ -66,6 +71,40 @@ impl DeployConfig {
             payment_args_max_length,
             session_args_max_length,
             native_transfer_minimum_motes,
+            no_fee_mode,
+            transfers_count_toward_block_gas_limit,
+        }
+    }
+}
+
+/// The chainspec limits a deploy must comply with in order to be accepted by the network.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeployLimits {
+    /// The name of the network.
+    pub chain_name: String,
+    /// The maximum serialized size of a deploy in bytes.
+    pub max_deploy_size: u32,
+    /// The maximum time-to-live of a deploy.
+    pub max_ttl: TimeDiff,
+    /// The maximum number of dependencies of a deploy.
+    pub max_dependencies: u8,
+    /// The maximum serialized length of the runtime args of a deploy's payment code.
+    pub payment_args_max_length: u32,
+    /// The maximum serialized length of the runtime args of a deploy's session code.
+    pub session_args_max_length: u32,
+}
+
+impl DeployLimits {
+    /// Constructs a new `DeployLimits` from the given network name and deploy config.
+    pub(crate) fn new(chain_name: String, deploy_config: &DeployConfig) -> Self {
+        DeployLimits {
+            chain_name,
+            max_deploy_size: deploy_config.max_deploy_size,
+            max_ttl: deploy_config.max_ttl,
+            max_dependencies: deploy_config.max_dependencies,
+            payment_args_max_length: deploy_config.payment_args_max_length,
+            session_args_max_length: deploy_config.session_args_max_length,
         }
     }
 }
@@ -85,6 +124,8 @@ impl Default for DeployConfig {
             payment_args_max_length: 1024,
             session_args_max_length: 1024,
             native_transfer_minimum_motes: MAX_PAYMENT_AMOUNT,
+            no_fee_mode: false,
+            transfers_count_toward_block_gas_limit: false,
         }
     }
 }
@@ -103,6 +144,8 @@ impl ToBytes for DeployConfig {
         buffer.extend(self.payment_args_max_length.to_bytes()?);
         buffer.extend(self.session_args_max_length.to_bytes()?);
         buffer.extend(self.native_transfer_minimum_motes.to_bytes()?);
+        buffer.extend(self.no_fee_mode.to_bytes()?);
+        buffer.extend(self.transfers_count_toward_block_gas_limit.to_bytes()?);
         Ok(buffer)
     }
 
@@ -118,6 +161,10 @@ impl ToBytes for DeployConfig {
             + self.payment_args_max_length.serialized_length()
             + self.session_args_max_length.serialized_length()
             + self.native_transfer_minimum_motes.serialized_length()
+            + self.no_fee_mode.serialized_length()
+            + self
+                .transfers_count_toward_block_gas_limit
+                .serialized_length()
     }
 }
 
@@ -135,6 +182,8 @@ impl FromBytes for DeployConfig {
         let (payment_args_max_length, remainder) = u32::from_bytes(remainder)?;
         let (session_args_max_length, remainder) = u32::from_bytes(remainder)?;
         let (native_transfer_minimum_motes, remainder) = u64::from_bytes(remainder)?;
+        let (no_fee_mode, remainder) = bool::from_bytes(remainder)?;
+        let (transfers_count_toward_block_gas_limit, remainder) = bool::from_bytes(remainder)?;
         let config = DeployConfig {
             max_payment_cost,
             max_ttl,
@@ -147,6 +196,8 @@ impl FromBytes for DeployConfig {
             payment_args_max_length,
             session_args_max_length,
             native_transfer_minimum_motes,
+            no_fee_mode,
+            transfers_count_toward_block_gas_limit,
         };
         Ok((config, remainder))
     }
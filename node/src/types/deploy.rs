@@ -22,7 +22,7 @@ use tracing::{info, warn};
 
 use casper_execution_engine::{
     core::engine_state::{executable_deploy_item::ExecutableDeployItem, DeployItem},
-    shared::motes::Motes,
+    shared::{gas::Gas, motes::Motes},
 };
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
@@ -71,6 +71,7 @@ static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
         body_hash,
         dependencies: vec![DeployHash::new(Digest::from([1u8; Digest::LENGTH]))],
         chain_name: String::from("casper-example"),
+        max_gas: None,
     };
     let serialized_header = serialize_header(&header);
     let hash = DeployHash::new(hash::hash(&serialized_header));
@@ -272,6 +273,18 @@ impl From<Digest> for DeployHash {
     }
 }
 
+impl From<DeployHash> for casper_types::DeployHash {
+    fn from(deploy_hash: DeployHash) -> Self {
+        casper_types::DeployHash::new(deploy_hash.0.to_array())
+    }
+}
+
+impl From<casper_types::DeployHash> for DeployHash {
+    fn from(deploy_hash: casper_types::DeployHash) -> Self {
+        DeployHash(Digest::from(deploy_hash.value()))
+    }
+}
+
 impl AsRef<[u8]> for DeployHash {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -294,6 +307,16 @@ impl FromBytes for DeployHash {
     }
 }
 
+/// The byte serialized in place of the tag of the account's public key in a `DeployHeader` with a
+/// versioned layout.
+///
+/// Headers serialized before the layout was versioned start with the tag of a `PublicKey`, which
+/// is never this value.  Headers without a gas cap are still serialized with the unversioned layout
+/// so that their hashes are unchanged.
+const DEPLOY_HEADER_VERSIONED_LAYOUT_TAG: u8 = u8::max_value();
+/// The current version of the serialized layout of a `DeployHeader`.
+const DEPLOY_HEADER_LAYOUT_VERSION: u8 = 1;
+
 /// The header portion of a [`Deploy`](struct.Deploy.html).
 #[derive(
     Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
@@ -307,6 +330,8 @@ pub struct DeployHeader {
     body_hash: Digest,
     dependencies: Vec<DeployHash>,
     chain_name: String,
+    #[serde(default)]
+    max_gas: Option<u64>,
 }
 
 impl DeployHeader {
@@ -351,6 +376,12 @@ impl DeployHeader {
         &self.chain_name
     }
 
+    /// Upper bound on the gas spent executing the deploy's payment and session code, regardless of
+    /// how much the payment code paid for.
+    pub fn max_gas(&self) -> Option<u64> {
+        self.max_gas
+    }
+
     /// Determine if this deploy header has valid values based on a `DeployConfig` and timestamp.
     pub fn is_valid(&self, deploy_config: &DeployConfig, current_timestamp: Timestamp) -> bool {
         let ttl_valid = self.ttl() <= deploy_config.max_ttl;
@@ -371,6 +402,10 @@ impl DeployHeader {
 impl ToBytes for DeployHeader {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        if self.max_gas.is_some() {
+            buffer.push(DEPLOY_HEADER_VERSIONED_LAYOUT_TAG);
+            buffer.push(DEPLOY_HEADER_LAYOUT_VERSION);
+        }
         buffer.extend(self.account.to_bytes()?);
         buffer.extend(self.timestamp.to_bytes()?);
         buffer.extend(self.ttl.to_bytes()?);
@@ -378,11 +413,19 @@ impl ToBytes for DeployHeader {
         buffer.extend(self.body_hash.to_bytes()?);
         buffer.extend(self.dependencies.to_bytes()?);
         buffer.extend(self.chain_name.to_bytes()?);
+        if let Some(max_gas) = self.max_gas {
+            buffer.extend(max_gas.to_bytes()?);
+        }
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.account.serialized_length()
+        let versioned_layout_length = match self.max_gas {
+            Some(max_gas) => 2 * bytesrepr::U8_SERIALIZED_LENGTH + max_gas.serialized_length(),
+            None => 0,
+        };
+        versioned_layout_length
+            + self.account.serialized_length()
             + self.timestamp.serialized_length()
             + self.ttl.serialized_length()
             + self.gas_price.serialized_length()
@@ -394,13 +437,30 @@ impl ToBytes for DeployHeader {
 
 impl FromBytes for DeployHeader {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (account, remainder) = PublicKey::from_bytes(bytes)?;
+        let (is_versioned, remainder) = match u8::from_bytes(bytes)? {
+            (DEPLOY_HEADER_VERSIONED_LAYOUT_TAG, remainder) => {
+                let (version, remainder) = u8::from_bytes(remainder)?;
+                if version != DEPLOY_HEADER_LAYOUT_VERSION {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                (true, remainder)
+            }
+            _ => (false, bytes),
+        };
+        let (account, remainder) = PublicKey::from_bytes(remainder)?;
         let (timestamp, remainder) = Timestamp::from_bytes(remainder)?;
         let (ttl, remainder) = TimeDiff::from_bytes(remainder)?;
         let (gas_price, remainder) = u64::from_bytes(remainder)?;
         let (body_hash, remainder) = Digest::from_bytes(remainder)?;
         let (dependencies, remainder) = Vec::<DeployHash>::from_bytes(remainder)?;
         let (chain_name, remainder) = String::from_bytes(remainder)?;
+        // Headers without a gas cap are always serialized with the unversioned layout.
+        let (max_gas, remainder) = if is_versioned {
+            let (max_gas, remainder) = u64::from_bytes(remainder)?;
+            (Some(max_gas), remainder)
+        } else {
+            (None, remainder)
+        };
         let deploy_header = DeployHeader {
             account,
             timestamp,
@@ -409,6 +469,7 @@ impl FromBytes for DeployHeader {
             body_hash,
             dependencies,
             chain_name,
+            max_gas,
         };
         Ok((deploy_header, remainder))
     }
@@ -502,6 +563,7 @@ impl Deploy {
         timestamp: Timestamp,
         ttl: TimeDiff,
         gas_price: u64,
+        max_gas: Option<u64>,
         dependencies: Vec<DeployHash>,
         chain_name: String,
         payment: ExecutableDeployItem,
@@ -522,6 +584,7 @@ impl Deploy {
             body_hash,
             dependencies,
             chain_name,
+            max_gas,
         };
         let serialized_header = serialize_header(&header);
         let hash = DeployHash::new(hash::hash(&serialized_header));
@@ -547,6 +610,33 @@ impl Deploy {
         self.approvals.push(approval);
     }
 
+    /// Returns a copy of this `Deploy` with the given timestamp and time-to-live.
+    ///
+    /// The body and deploy hashes are recomputed, and the approvals are dropped since they were
+    /// signatures of the old deploy hash.  The returned deploy needs to be signed again.
+    pub fn with_timestamp_and_ttl(&self, timestamp: Timestamp, ttl: TimeDiff) -> Deploy {
+        let serialized_body = serialize_body(&self.payment, &self.session);
+        let body_hash = hash::hash(&serialized_body);
+
+        let header = DeployHeader {
+            timestamp,
+            ttl,
+            body_hash,
+            ..self.header.clone()
+        };
+        let serialized_header = serialize_header(&header);
+        let hash = DeployHash::new(hash::hash(&serialized_header));
+
+        Deploy {
+            hash,
+            header,
+            payment: self.payment.clone(),
+            session: self.session.clone(),
+            approvals: vec![],
+            is_valid: None,
+        }
+    }
+
     /// Returns the `DeployHash` identifying this `Deploy`.
     pub fn id(&self) -> &DeployHash {
         &self.hash
@@ -614,6 +704,17 @@ impl Deploy {
         }
     }
 
+    /// Returns the hash of the deploy's payment and session code, which the body hash in its
+    /// header should match.
+    pub fn compute_body_hash(&self) -> Digest {
+        hash::hash(&serialize_body(&self.payment, &self.session))
+    }
+
+    /// Returns the hash of the deploy's header, which the deploy hash should match.
+    pub fn compute_hash(&self) -> DeployHash {
+        DeployHash::new(hash::hash(&serialize_header(&self.header)))
+    }
+
     /// Returns true if the serialized size of the deploy is not greater than `max_deploy_size`.
     pub fn is_valid_size(&self, max_deploy_size: u32) -> Result<(), ExcessiveSizeError> {
         let deploy_size = self.serialized_length();
@@ -747,6 +848,7 @@ impl Deploy {
         let timestamp = Timestamp::random(rng);
         let ttl = TimeDiff::from(rng.gen_range(60_000..3_600_000));
         let gas_price = rng.gen_range(1..100);
+        let max_gas = if rng.gen() { Some(rng.gen()) } else { None };
 
         let dependencies = vec![
             DeployHash::new(hash::hash(rng.next_u64().to_le_bytes())),
@@ -764,6 +866,7 @@ impl Deploy {
             timestamp,
             ttl,
             gas_price,
+            max_gas,
             dependencies,
             chain_name,
             payment,
@@ -800,15 +903,13 @@ fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem
 // Computationally expensive validity check for a given deploy instance, including
 // asymmetric_key signing verification.
 fn validate_deploy(deploy: &Deploy) -> Result<(), DeployValidationFailure> {
-    let serialized_body = serialize_body(&deploy.payment, &deploy.session);
-    let body_hash = hash::hash(&serialized_body);
+    let body_hash = deploy.compute_body_hash();
     if body_hash != deploy.header.body_hash {
         warn!(?deploy, ?body_hash, "invalid deploy body hash");
         return Err(DeployValidationFailure::InvalidBodyHash);
     }
 
-    let serialized_header = serialize_header(&deploy.header);
-    let hash = DeployHash::new(hash::hash(&serialized_header));
+    let hash = deploy.compute_hash();
     if hash != deploy.hash {
         warn!(?deploy, ?hash, "invalid deploy hash");
         return Err(DeployValidationFailure::InvalidDeployHash);
@@ -870,7 +971,11 @@ impl From<Deploy> for DeployItem {
             deploy.payment().clone(),
             deploy.header().gas_price(),
             authorization_keys,
-            casper_types::DeployHash::new(deploy.id().inner().to_array()),
+            (*deploy.id()).into(),
+            deploy
+                .header()
+                .max_gas()
+                .map(|max_gas| Gas::new(U512::from(max_gas))),
         )
     }
 }
@@ -964,6 +1069,73 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&deploy);
     }
 
+    #[test]
+    fn should_serialize_header_without_max_gas_as_before() {
+        let mut rng = crate::new_rng();
+        let header = DeployHeader {
+            max_gas: None,
+            ..Deploy::random(&mut rng).take_header()
+        };
+
+        let mut legacy_bytes = header.account.to_bytes().unwrap();
+        legacy_bytes.extend(header.timestamp.to_bytes().unwrap());
+        legacy_bytes.extend(header.ttl.to_bytes().unwrap());
+        legacy_bytes.extend(header.gas_price.to_bytes().unwrap());
+        legacy_bytes.extend(header.body_hash.to_bytes().unwrap());
+        legacy_bytes.extend(header.dependencies.to_bytes().unwrap());
+        legacy_bytes.extend(header.chain_name.to_bytes().unwrap());
+
+        assert_eq!(header.to_bytes().unwrap(), legacy_bytes);
+        let decoded: DeployHeader = bytesrepr::deserialize(legacy_bytes).unwrap();
+        assert_eq!(decoded, header);
+    }
+
+    #[test]
+    fn should_roundtrip_header_with_max_gas() {
+        let mut rng = crate::new_rng();
+        let header = DeployHeader {
+            max_gas: Some(rng.gen()),
+            ..Deploy::random(&mut rng).take_header()
+        };
+
+        let bytes = header.to_bytes().unwrap();
+        assert_eq!(bytes[0], DEPLOY_HEADER_VERSIONED_LAYOUT_TAG);
+        bytesrepr::test_serialization_roundtrip(&header);
+
+        let mut bytes = bytes;
+        bytes[1] = DEPLOY_HEADER_LAYOUT_VERSION + 1;
+        let result: Result<DeployHeader, _> = bytesrepr::deserialize(bytes);
+        assert_eq!(result, Err(bytesrepr::Error::Formatting));
+    }
+
+    #[test]
+    fn should_pass_max_gas_to_deploy_item() {
+        let mut rng = crate::new_rng();
+        let secret_key = SecretKey::random(&mut rng);
+        let module_bytes = || ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: RuntimeArgs::new(),
+        };
+        let deploy = |max_gas| {
+            Deploy::new(
+                Timestamp::now(),
+                TimeDiff::from(60_000),
+                1,
+                max_gas,
+                vec![],
+                "net-1".to_string(),
+                module_bytes(),
+                module_bytes(),
+                &secret_key,
+            )
+        };
+
+        let deploy_item = DeployItem::from(deploy(Some(1_000)));
+        assert_eq!(deploy_item.max_gas, Some(Gas::new(U512::from(1_000))));
+        let deploy_item = DeployItem::from(deploy(None));
+        assert_eq!(deploy_item.max_gas, None);
+    }
+
     fn create_deploy(
         rng: &mut TestRng,
         ttl: TimeDiff,
@@ -985,6 +1157,7 @@ mod tests {
             Timestamp::now(),
             ttl,
             1,
+            None,
             dependencies,
             chain_name.to_string(),
             ExecutableDeployItem::ModuleBytes {
@@ -4,12 +4,17 @@ mod account;
 mod auction_state;
 mod contracts;
 mod stored_value;
+mod validator_changes;
 
 pub use account::Account;
-pub use auction_state::AuctionState;
+pub use auction_state::{
+    AuctionState, JsonBid, JsonBids, JsonContractDelegator, JsonDelegator, JsonEraValidators,
+    JsonValidatorWeights,
+};
 use casper_types::{contracts::NamedKeys, NamedKey};
 pub use contracts::{Contract, ContractPackage};
 pub use stored_value::StoredValue;
+pub use validator_changes::{JsonValidatorChange, ValidatorChangeKind, ValidatorChanges};
 
 /// A helper function to change NamedKeys into a Vec<NamedKey>
 pub fn vectorize(keys: &NamedKeys) -> Vec<NamedKey> {
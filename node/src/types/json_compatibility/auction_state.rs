@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 
 use casper_types::{
     system::auction::{Bid, Bids, DelegationRate, Delegator, EraId, EraValidators},
-    AccessRights, PublicKey, SecretKey, URef, U512,
+    AccessRights, ContractPackageHash, PublicKey, SecretKey, URef, U512,
 };
 
 use crate::{crypto::hash::Digest, rpcs::docs::DocExample};
@@ -71,6 +71,18 @@ pub struct JsonValidatorWeights {
     weight: U512,
 }
 
+impl JsonValidatorWeights {
+    /// Returns the validator's public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the validator's weight.
+    pub fn weight(&self) -> &U512 {
+        &self.weight
+    }
+}
+
 /// The validators for the given era.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -79,6 +91,18 @@ pub struct JsonEraValidators {
     validator_weights: Vec<JsonValidatorWeights>,
 }
 
+impl JsonEraValidators {
+    /// Returns the era ID.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the weights of the validators in the era.
+    pub fn validator_weights(&self) -> &[JsonValidatorWeights] {
+        &self.validator_weights
+    }
+}
+
 /// A delegator associated with the given validator.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -89,6 +113,41 @@ pub struct JsonDelegator {
     delegatee: PublicKey,
 }
 
+impl JsonDelegator {
+    /// Returns the delegator's public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the delegated amount.
+    pub fn staked_amount(&self) -> &U512 {
+        &self.staked_amount
+    }
+}
+
+/// A contract delegator associated with the given validator.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonContractDelegator {
+    contract_package_hash: ContractPackageHash,
+    staked_amount: U512,
+    bonding_purse: URef,
+    unbond_purse: URef,
+    delegatee: PublicKey,
+}
+
+impl JsonContractDelegator {
+    /// Returns the contract package of the delegating contract.
+    pub fn contract_package_hash(&self) -> &ContractPackageHash {
+        &self.contract_package_hash
+    }
+
+    /// Returns the delegated amount.
+    pub fn staked_amount(&self) -> &U512 {
+        &self.staked_amount
+    }
+}
+
 /// An entry in a founding validator map representing a bid.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -103,6 +162,35 @@ pub struct JsonBid {
     delegators: Vec<JsonDelegator>,
     /// Is this an inactive validator.
     inactive: bool,
+    /// The contract delegators.
+    contract_delegators: Vec<JsonContractDelegator>,
+}
+
+impl JsonBid {
+    /// Returns the amount of tokens staked by the validator (not including delegators).
+    pub fn staked_amount(&self) -> &U512 {
+        &self.staked_amount
+    }
+
+    /// Returns the delegation rate.
+    pub fn delegation_rate(&self) -> DelegationRate {
+        self.delegation_rate
+    }
+
+    /// Returns the delegators.
+    pub fn delegators(&self) -> &[JsonDelegator] {
+        &self.delegators
+    }
+
+    /// Returns whether the validator is inactive.
+    pub fn inactive(&self) -> bool {
+        self.inactive
+    }
+
+    /// Returns the contract delegators.
+    pub fn contract_delegators(&self) -> &[JsonContractDelegator] {
+        &self.contract_delegators
+    }
 }
 
 impl From<Bid> for JsonBid {
@@ -116,12 +204,24 @@ impl From<Bid> for JsonBid {
                 delegatee: *delegator.validator_public_key(),
             });
         }
+        let json_contract_delegators = bid
+            .contract_delegators()
+            .values()
+            .map(|contract_delegator| JsonContractDelegator {
+                contract_package_hash: *contract_delegator.contract_package_hash(),
+                staked_amount: *contract_delegator.staked_amount(),
+                bonding_purse: *contract_delegator.bonding_purse(),
+                unbond_purse: *contract_delegator.unbond_purse(),
+                delegatee: *contract_delegator.validator_public_key(),
+            })
+            .collect();
         JsonBid {
             bonding_purse: *bid.bonding_purse(),
             staked_amount: *bid.staked_amount(),
             delegation_rate: *bid.delegation_rate(),
             delegators: json_delegators,
             inactive: bid.inactive(),
+            contract_delegators: json_contract_delegators,
         }
     }
 }
@@ -134,6 +234,18 @@ pub struct JsonBids {
     bid: JsonBid,
 }
 
+impl JsonBids {
+    /// Returns the validator's public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the validator's bid.
+    pub fn bid(&self) -> &JsonBid {
+        &self.bid
+    }
+}
+
 /// Data structure summarizing auction contract data.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -187,6 +299,11 @@ impl AuctionState {
             bids: json_bids,
         }
     }
+
+    /// Returns all bids.
+    pub fn bids(&self) -> &[JsonBids] {
+        &self.bids
+    }
 }
 
 impl DocExample for AuctionState {
@@ -8,17 +8,23 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::json_compatibility::vectorize;
 use casper_types::{
-    Contract as DomainContract, ContractHash, ContractPackage as DomainContractPackage,
-    ContractPackageHash, ContractWasmHash, EntryPoint, NamedKey, URef,
+    account::AccountHash, Contract as DomainContract, ContractHash,
+    ContractPackage as DomainContractPackage, ContractPackageHash, ContractWasmHash, EntryPoint,
+    NamedKey, URef,
 };
 
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, DataSize, JsonSchema,
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, DataSize, JsonSchema,
 )]
 pub struct ContractVersion {
     protocol_version_major: u32,
     contract_version: u32,
     contract_hash: ContractHash,
+    /// Block time in milliseconds at which the version was added.
+    created_at: u64,
+    creator: AccountHash,
+    /// Hex-encoded hash of the version's metadata, if any.
+    metadata_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema)]
@@ -83,10 +89,13 @@ impl From<&DomainContractPackage> for ContractPackage {
         let versions = contract_package
             .versions()
             .iter()
-            .map(|(version_key, hash)| ContractVersion {
+            .map(|(version_key, version_info)| ContractVersion {
                 protocol_version_major: version_key.protocol_version_major(),
                 contract_version: version_key.contract_version(),
-                contract_hash: *hash,
+                contract_hash: version_info.contract_hash(),
+                created_at: version_info.created_at().into(),
+                creator: version_info.creator(),
+                metadata_hash: version_info.metadata_hash().map(hex::encode),
             })
             .collect();
 
@@ -0,0 +1,197 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    system::auction::{EraId, EraValidators},
+    PublicKey, SecretKey, U512,
+};
+
+use crate::{crypto::hash::Digest, rpcs::docs::DocExample};
+
+static VALIDATOR_CHANGES: Lazy<ValidatorChanges> = Lazy::new(|| {
+    let public_key_1 = SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+    let public_key_2 = SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into();
+
+    let mut validator_weights_1 = BTreeMap::new();
+    validator_weights_1.insert(public_key_1, U512::from(10));
+
+    let mut validator_weights_2 = BTreeMap::new();
+    validator_weights_2.insert(public_key_2, U512::from(20));
+
+    let mut era_validators = BTreeMap::new();
+    era_validators.insert(10u64, validator_weights_1);
+    era_validators.insert(11u64, validator_weights_2);
+
+    let state_root_hash = Digest::from([11; Digest::LENGTH]);
+    ValidatorChanges::new(state_root_hash, 10, &era_validators)
+});
+
+/// The kind of change to a validator's membership of the validator set.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, JsonSchema)]
+pub enum ValidatorChangeKind {
+    /// The validator joins the validator set.
+    Added,
+    /// The validator leaves the validator set.
+    Removed,
+    /// The validator remains in the validator set with a different weight.
+    WeightChanged,
+}
+
+/// A change to a single validator between two consecutive eras.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonValidatorChange {
+    /// The era in which the change takes effect.
+    pub era_id: EraId,
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The kind of change.
+    pub kind: ValidatorChangeKind,
+    /// The validator's weight in the previous era, if it was a validator then.
+    pub previous_weight: Option<U512>,
+    /// The validator's weight in `era_id`, if it is a validator then.
+    pub weight: Option<U512>,
+}
+
+/// Changes to the validator set over the eras for which the auction holds validator snapshots.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorChanges {
+    /// Global state hash.
+    pub state_root_hash: Digest,
+    /// Block height.
+    pub block_height: u64,
+    /// The validator changes, ordered by era and then by public key.
+    pub changes: Vec<JsonValidatorChange>,
+}
+
+impl ValidatorChanges {
+    /// Creates a new instance of `ValidatorChanges` by comparing each era's validator snapshot
+    /// with that of the preceding era.
+    pub fn new(state_root_hash: Digest, block_height: u64, era_validators: &EraValidators) -> Self {
+        let mut changes = Vec::new();
+        let mut snapshots = era_validators.iter();
+        if let Some((_, mut previous_weights)) = snapshots.next() {
+            for (era_id, weights) in snapshots {
+                for (public_key, weight) in weights {
+                    let kind = match previous_weights.get(public_key) {
+                        None => ValidatorChangeKind::Added,
+                        Some(previous_weight) if previous_weight != weight => {
+                            ValidatorChangeKind::WeightChanged
+                        }
+                        Some(_) => continue,
+                    };
+                    changes.push(JsonValidatorChange {
+                        era_id: *era_id,
+                        public_key: *public_key,
+                        kind,
+                        previous_weight: previous_weights.get(public_key).copied(),
+                        weight: Some(*weight),
+                    });
+                }
+                for (public_key, previous_weight) in previous_weights {
+                    if !weights.contains_key(public_key) {
+                        changes.push(JsonValidatorChange {
+                            era_id: *era_id,
+                            public_key: *public_key,
+                            kind: ValidatorChangeKind::Removed,
+                            previous_weight: Some(*previous_weight),
+                            weight: None,
+                        });
+                    }
+                }
+                previous_weights = weights;
+            }
+        }
+        changes.sort_by(|lhs, rhs| (lhs.era_id, lhs.public_key).cmp(&(rhs.era_id, rhs.public_key)));
+
+        ValidatorChanges {
+            state_root_hash,
+            block_height,
+            changes,
+        }
+    }
+}
+
+impl DocExample for ValidatorChanges {
+    fn doc_example() -> &'static Self {
+        &*VALIDATOR_CHANGES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public_key(seed: u8) -> PublicKey {
+        SecretKey::ed25519([seed; SecretKey::ED25519_LENGTH]).into()
+    }
+
+    #[test]
+    fn should_report_added_removed_and_reweighted_validators() {
+        let (kept, reweighted, removed, added) =
+            (public_key(1), public_key(2), public_key(3), public_key(4));
+
+        let mut era_1 = BTreeMap::new();
+        era_1.insert(kept, U512::from(10));
+        era_1.insert(reweighted, U512::from(20));
+        era_1.insert(removed, U512::from(30));
+
+        let mut era_2 = BTreeMap::new();
+        era_2.insert(kept, U512::from(10));
+        era_2.insert(reweighted, U512::from(25));
+        era_2.insert(added, U512::from(40));
+
+        let mut era_validators = BTreeMap::new();
+        era_validators.insert(1, era_1);
+        era_validators.insert(2, era_2);
+
+        let validator_changes =
+            ValidatorChanges::new(Digest::from([1; Digest::LENGTH]), 5, &era_validators);
+
+        let mut expected = vec![
+            JsonValidatorChange {
+                era_id: 2,
+                public_key: reweighted,
+                kind: ValidatorChangeKind::WeightChanged,
+                previous_weight: Some(U512::from(20)),
+                weight: Some(U512::from(25)),
+            },
+            JsonValidatorChange {
+                era_id: 2,
+                public_key: removed,
+                kind: ValidatorChangeKind::Removed,
+                previous_weight: Some(U512::from(30)),
+                weight: None,
+            },
+            JsonValidatorChange {
+                era_id: 2,
+                public_key: added,
+                kind: ValidatorChangeKind::Added,
+                previous_weight: None,
+                weight: Some(U512::from(40)),
+            },
+        ];
+        expected.sort_by_key(|change| change.public_key);
+        assert_eq!(validator_changes.changes, expected);
+    }
+
+    #[test]
+    fn should_report_no_changes_for_single_era() {
+        let mut era_1 = BTreeMap::new();
+        era_1.insert(public_key(1), U512::from(10));
+
+        let mut era_validators = BTreeMap::new();
+        era_validators.insert(1, era_1);
+
+        let validator_changes =
+            ValidatorChanges::new(Digest::from([1; Digest::LENGTH]), 5, &era_validators);
+        assert!(validator_changes.changes.is_empty());
+    }
+}
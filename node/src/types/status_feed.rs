@@ -3,6 +3,7 @@
 
 use std::{
     collections::BTreeMap,
+    fmt::{self, Display, Formatter},
     hash::Hash,
     net::{IpAddr, Ipv4Addr, SocketAddr},
 };
@@ -11,6 +12,7 @@ use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use semver::Version;
 use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
 
 use casper_types::PublicKey;
 
@@ -38,13 +40,22 @@ static GET_STATUS_RESULT: Lazy<GetStatusResult> = Lazy::new(|| {
     let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 54321);
     let mut peers = BTreeMap::new();
     peers.insert(node_id.clone(), socket_addr.to_string());
+    let mut health = HealthSnapshot::default();
+    health.insert("small_network", ComponentHealth::Healthy);
+    health.insert(
+        "block_proposer",
+        ComponentHealth::Degraded("initializing".to_string()),
+    );
+    health.insert_gossip_fanout("deploy_gossiper", 5);
     let status_feed = StatusFeed::<NodeId> {
         last_added_block: Some(Block::doc_example().clone()),
         peers,
+        clock_drift_millis: Some(-250),
         chainspec_info: ChainspecInfo::doc_example().clone(),
         our_public_signing_key: *PublicKey::doc_example(),
         round_length: Some(TimeDiff::from(1 << 16)),
         version: crate::VERSION_STRING.as_str(),
+        health,
     };
     GetStatusResult::new(status_feed, DOCS_EXAMPLE_PROTOCOL_VERSION.clone())
 });
@@ -89,6 +100,8 @@ pub struct StatusFeed<I> {
     pub last_added_block: Option<Block>,
     /// The peer nodes which are connected to this node.
     pub peers: BTreeMap<I, String>,
+    /// The estimated drift in milliseconds of our clock relative to our peers.
+    pub clock_drift_millis: Option<i64>,
     /// The chainspec info for this node.
     pub chainspec_info: ChainspecInfo,
     /// Our public signing key.
@@ -97,22 +110,28 @@ pub struct StatusFeed<I> {
     pub round_length: Option<TimeDiff>,
     /// The compiled node version.
     pub version: &'static str,
+    /// The health reported by the node's components.
+    pub health: HealthSnapshot,
 }
 
 impl<I> StatusFeed<I> {
     pub(crate) fn new(
         last_added_block: Option<Block>,
         peers: BTreeMap<I, String>,
+        clock_drift_millis: Option<i64>,
         chainspec_info: ChainspecInfo,
         (our_public_signing_key, round_length): (PublicKey, Option<TimeDiff>),
+        health: HealthSnapshot,
     ) -> Self {
         StatusFeed {
             last_added_block,
             peers,
+            clock_drift_millis,
             chainspec_info,
             our_public_signing_key,
             round_length,
             version: crate::VERSION_STRING.as_str(),
+            health,
         }
     }
 }
@@ -155,6 +174,11 @@ pub struct GetStatusResult {
     pub starting_state_root_hash: String,
     /// The node ID and network address of each connected peer.
     pub peers: PeersMap,
+    /// The estimated drift in milliseconds of this node's clock relative to its peers, if any
+    /// peer has reported its time.
+    ///
+    /// Positive values mean this node's clock is ahead of its peers'.
+    pub clock_drift_millis: Option<i64>,
     /// The minimal info of the last block from the linear chain.
     pub last_added_block_info: Option<MinimalBlockInfo>,
     /// Our public signing key.
@@ -165,6 +189,8 @@ pub struct GetStatusResult {
     pub next_upgrade: Option<NextUpgrade>,
     /// The compiled node version.
     pub build_version: String,
+    /// The health reported by the node's components.
+    pub component_health: HealthSnapshot,
 }
 
 impl GetStatusResult {
@@ -177,11 +203,13 @@ impl GetStatusResult {
                 .starting_state_root_hash
                 .to_string(),
             peers: PeersMap::from(status_feed.peers),
+            clock_drift_millis: status_feed.clock_drift_millis,
             last_added_block_info: status_feed.last_added_block.map(Into::into),
             our_public_signing_key: status_feed.our_public_signing_key,
             round_length: status_feed.round_length,
             next_upgrade: status_feed.chainspec_info.next_upgrade,
             build_version: crate::VERSION_STRING.clone(),
+            component_health: status_feed.health,
         }
     }
 }
@@ -191,3 +219,278 @@ impl DocExample for GetStatusResult {
         &*GET_STATUS_RESULT
     }
 }
+
+/// Readiness of an individual component as reported by the "ready" REST endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    /// The component is ready.
+    Ready,
+    /// The component is not yet ready.
+    NotReady,
+}
+
+impl From<bool> for ComponentStatus {
+    fn from(ready: bool) -> Self {
+        if ready {
+            ComponentStatus::Ready
+        } else {
+            ComponentStatus::NotReady
+        }
+    }
+}
+
+/// Result for the "ready" REST endpoint.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct GetReadinessResult {
+    /// Whether all components are ready.
+    pub ready: bool,
+    /// The readiness of each component.
+    pub components: BTreeMap<String, ComponentStatus>,
+}
+
+impl GetReadinessResult {
+    /// The node is considered ready once it is connected to at least `min_peers` peers, its era
+    /// supervisor is active, and the last block it added is no older than `max_block_age`.
+    pub(crate) fn new<I>(
+        status_feed: &StatusFeed<I>,
+        min_peers: usize,
+        max_block_age: TimeDiff,
+        now: Timestamp,
+    ) -> Self {
+        let mut components = BTreeMap::new();
+        let _ = components.insert(
+            "network".to_string(),
+            ComponentStatus::from(status_feed.peers.len() >= min_peers),
+        );
+        let _ = components.insert(
+            "consensus".to_string(),
+            ComponentStatus::from(
+                status_feed
+                    .health
+                    .components
+                    .get("consensus")
+                    .map_or(false, ComponentHealth::is_healthy),
+            ),
+        );
+        let _ = components.insert(
+            "linear_chain".to_string(),
+            ComponentStatus::from(
+                status_feed
+                    .last_added_block
+                    .as_ref()
+                    .map_or(false, |block| {
+                        now.saturating_diff(block.header().timestamp()) <= max_block_age
+                    }),
+            ),
+        );
+        let ready = components
+            .values()
+            .all(|status| *status == ComponentStatus::Ready);
+        GetReadinessResult { ready, components }
+    }
+}
+
+/// Health of an individual component, as reported via `HealthReport`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "status", content = "reason", rename_all = "snake_case")]
+pub enum ComponentHealth {
+    /// The component is operating normally.
+    Healthy,
+    /// The component is operating, but not at full capacity.
+    Degraded(String),
+    /// The component is not able to operate.
+    Unhealthy(String),
+}
+
+impl ComponentHealth {
+    /// Returns `true` if the component is fully healthy.
+    pub fn is_healthy(&self) -> bool {
+        *self == ComponentHealth::Healthy
+    }
+}
+
+impl Display for ComponentHealth {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentHealth::Healthy => write!(formatter, "healthy"),
+            ComponentHealth::Degraded(reason) => write!(formatter, "degraded: {}", reason),
+            ComponentHealth::Unhealthy(reason) => write!(formatter, "unhealthy: {}", reason),
+        }
+    }
+}
+
+/// A snapshot of the health of all components of a reactor.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HealthSnapshot {
+    /// The health of each component, keyed by component name.
+    pub components: BTreeMap<String, ComponentHealth>,
+    /// The number of peers each gossiper currently gossips a new item to, keyed by gossiper name.
+    #[serde(default)]
+    pub gossip_fanout: BTreeMap<String, usize>,
+}
+
+impl HealthSnapshot {
+    /// Records the health of the named component.
+    pub(crate) fn insert(&mut self, name: &str, health: ComponentHealth) {
+        let _ = self.components.insert(name.to_string(), health);
+    }
+
+    /// Records the effective fanout of the named gossiper.
+    pub(crate) fn insert_gossip_fanout(&mut self, name: &str, fanout: usize) {
+        let _ = self.gossip_fanout.insert(name.to_string(), fanout);
+    }
+
+    /// Returns `true` if all components are healthy.
+    pub fn is_healthy(&self) -> bool {
+        self.components.values().all(ComponentHealth::is_healthy)
+    }
+
+    /// Logs the health of every component which isn't healthy.
+    pub(crate) fn log(&self) {
+        if self.is_healthy() {
+            debug!("all components healthy");
+            return;
+        }
+        for (component, health) in &self.components {
+            match health {
+                ComponentHealth::Healthy => (),
+                ComponentHealth::Degraded(reason) => {
+                    info!(%component, %reason, "component degraded")
+                }
+                ComponentHealth::Unhealthy(reason) => {
+                    warn!(%component, %reason, "component unhealthy")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRng;
+
+    const MIN_PEERS: usize = 2;
+    const MAX_BLOCK_AGE: TimeDiff = TimeDiff::from_seconds(60);
+
+    fn ready_status_feed(rng: &mut TestRng) -> StatusFeed<u8> {
+        let mut health = HealthSnapshot::default();
+        health.insert("consensus", ComponentHealth::Healthy);
+        StatusFeed {
+            last_added_block: Some(Block::random(rng)),
+            peers: (0..MIN_PEERS as u8)
+                .map(|peer| (peer, format!("127.0.0.1:{}", 34553 + peer as u16)))
+                .collect(),
+            clock_drift_millis: None,
+            chainspec_info: ChainspecInfo::doc_example().clone(),
+            our_public_signing_key: *PublicKey::doc_example(),
+            round_length: None,
+            version: crate::VERSION_STRING.as_str(),
+            health,
+        }
+    }
+
+    fn readiness(status_feed: &StatusFeed<u8>, block_age: TimeDiff) -> GetReadinessResult {
+        let now = status_feed
+            .last_added_block
+            .as_ref()
+            .map_or_else(Timestamp::now, |block| block.header().timestamp())
+            + block_age;
+        GetReadinessResult::new(status_feed, MIN_PEERS, MAX_BLOCK_AGE, now)
+    }
+
+    #[test]
+    fn should_be_ready_if_all_checks_pass() {
+        let mut rng = TestRng::new();
+        let status_feed = ready_status_feed(&mut rng);
+
+        let result = readiness(&status_feed, MAX_BLOCK_AGE);
+        assert!(result.ready);
+        assert!(result
+            .components
+            .values()
+            .all(|status| *status == ComponentStatus::Ready));
+    }
+
+    #[test]
+    fn should_not_be_ready_with_too_few_peers() {
+        let mut rng = TestRng::new();
+        let mut status_feed = ready_status_feed(&mut rng);
+        let _ = status_feed.peers.remove(&0);
+
+        let result = readiness(&status_feed, TimeDiff::default());
+        assert!(!result.ready);
+        assert_eq!(result.components["network"], ComponentStatus::NotReady);
+        assert_eq!(result.components["consensus"], ComponentStatus::Ready);
+        assert_eq!(result.components["linear_chain"], ComponentStatus::Ready);
+    }
+
+    #[test]
+    fn should_not_be_ready_if_era_supervisor_is_not_active() {
+        let mut rng = TestRng::new();
+        let mut status_feed = ready_status_feed(&mut rng);
+        status_feed.health.insert(
+            "consensus",
+            ComponentHealth::Degraded("eras not initialized yet".to_string()),
+        );
+
+        let result = readiness(&status_feed, TimeDiff::default());
+        assert!(!result.ready);
+        assert_eq!(result.components["consensus"], ComponentStatus::NotReady);
+
+        status_feed.health = HealthSnapshot::default();
+        let result = readiness(&status_feed, TimeDiff::default());
+        assert!(!result.ready);
+        assert_eq!(result.components["consensus"], ComponentStatus::NotReady);
+    }
+
+    #[test]
+    fn should_not_be_ready_if_last_block_is_too_old_or_missing() {
+        let mut rng = TestRng::new();
+        let mut status_feed = ready_status_feed(&mut rng);
+
+        let result = readiness(&status_feed, MAX_BLOCK_AGE + TimeDiff::from(1));
+        assert!(!result.ready);
+        assert_eq!(result.components["network"], ComponentStatus::Ready);
+        assert_eq!(result.components["linear_chain"], ComponentStatus::NotReady);
+
+        status_feed.last_added_block = None;
+        let result = readiness(&status_feed, TimeDiff::default());
+        assert!(!result.ready);
+        assert_eq!(result.components["linear_chain"], ComponentStatus::NotReady);
+    }
+
+    #[test]
+    fn health_snapshot_should_be_healthy_only_if_all_components_are() {
+        let mut snapshot = HealthSnapshot::default();
+        assert!(snapshot.is_healthy());
+
+        snapshot.insert("storage", ComponentHealth::Healthy);
+        assert!(snapshot.is_healthy());
+
+        snapshot.insert(
+            "small_network",
+            ComponentHealth::Degraded("not connected to any peers".to_string()),
+        );
+        assert!(!snapshot.is_healthy());
+
+        snapshot.insert("small_network", ComponentHealth::Healthy);
+        assert!(snapshot.is_healthy());
+    }
+
+    #[test]
+    fn component_health_should_serialize_with_reason() {
+        let healthy = serde_json::to_value(&ComponentHealth::Healthy).unwrap();
+        assert_eq!(healthy, serde_json::json!({ "status": "healthy" }));
+
+        let unhealthy =
+            serde_json::to_value(&ComponentHealth::Unhealthy("disk full".to_string())).unwrap();
+        assert_eq!(
+            unhealthy,
+            serde_json::json!({ "status": "unhealthy", "reason": "disk full" })
+        );
+    }
+}
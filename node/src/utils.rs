@@ -15,7 +15,7 @@ use std::{
     fmt::{self, Debug, Display, Formatter},
     fs,
     io::{self, Write},
-    net::{SocketAddr, ToSocketAddrs},
+    net::{Ipv4Addr, SocketAddr, SocketAddrV6, ToSocketAddrs},
     ops::{Add, Div},
     os::unix::fs::OpenOptionsExt,
     path::{Path, PathBuf},
@@ -99,6 +99,66 @@ pub(crate) fn resolve_address(address: &str) -> Result<SocketAddr, ResolveAddres
         })
 }
 
+/// Parses a network address from a string, with DNS resolution, returning every address the
+/// hostname resolves to.
+pub(crate) fn resolve_addresses(address: &str) -> Result<Vec<SocketAddr>, ResolveAddressError> {
+    let addresses: Vec<SocketAddr> = address
+        .to_socket_addrs()
+        .map_err(|err| ResolveAddressError {
+            address: address.to_string(),
+            kind: ResolveAddressErrorKind::ErrorResolving(err),
+        })?
+        .collect();
+    if addresses.is_empty() {
+        return Err(ResolveAddressError {
+            address: address.to_string(),
+            kind: ResolveAddressErrorKind::NoAddressFound,
+        });
+    }
+    Ok(addresses)
+}
+
+/// Parses a network address from a string, with DNS resolution, returning the first address
+/// matching `prefer` or the first address the hostname resolves to if none does.
+pub(crate) fn resolve_address_preferring<F>(
+    address: &str,
+    prefer: F,
+) -> Result<SocketAddr, ResolveAddressError>
+where
+    F: Fn(&SocketAddr) -> bool,
+{
+    let addresses = resolve_addresses(address)?;
+    // `resolve_addresses` never returns an empty list.
+    Ok(addresses
+        .iter()
+        .copied()
+        .find(|address| prefer(address))
+        .unwrap_or(addresses[0]))
+}
+
+/// Returns `address` in canonical form, so that the same endpoint is always represented by the
+/// same `SocketAddr`.
+///
+/// IPv4-mapped IPv6 addresses, as reported for IPv4 peers of a dual-stack listener, are converted
+/// to IPv4 addresses, and the flow label of IPv6 addresses is cleared.
+pub(crate) fn canonical_address(address: SocketAddr) -> SocketAddr {
+    match address {
+        SocketAddr::V4(_) => address,
+        SocketAddr::V6(address) => match address.ip().segments() {
+            [0, 0, 0, 0, 0, 0xffff, high, low] => {
+                let ip = Ipv4Addr::from(u32::from(high) << 16 | u32::from(low));
+                SocketAddr::from((ip, address.port()))
+            }
+            _ => SocketAddr::V6(SocketAddrV6::new(
+                *address.ip(),
+                address.port(),
+                0,
+                address.scope_id(),
+            )),
+        },
+    }
+}
+
 /// An error starting one of the HTTP servers.
 #[derive(Debug, Error)]
 pub enum ListeningError {
@@ -9,9 +9,9 @@ use core::mem::MaybeUninit;
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, ToBytes},
     contracts::{ContractVersion, NamedKeys},
-    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
+    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Gas, Key, Phase,
     RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
 };
 
@@ -115,6 +115,88 @@ pub fn call_versioned_contract<T: CLTyped + FromBytes>(
     deserialize_contract_result(bytes_written)
 }
 
+/// Calls the given stored contract like [`call_contract`], but allows it to use at most `gas_limit`
+/// gas.
+///
+/// If the stored contract exhausts `gas_limit`, all of its effects are discarded and
+/// `Err(ApiError::OutOfSubCallGas)` is returned, leaving the caller free to continue.
+pub fn call_contract_with_gas_limit<T: CLTyped + FromBytes>(
+    contract_hash: ContractHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+    gas_limit: Gas,
+) -> Result<T, ApiError> {
+    let (contract_hash_ptr, contract_hash_size, _bytes) = contract_api::to_ptr(contract_hash);
+    let (entry_point_name_ptr, entry_point_name_size, _bytes) =
+        contract_api::to_ptr(entry_point_name);
+    let (runtime_args_ptr, runtime_args_size, _bytes) = contract_api::to_ptr(runtime_args);
+    let (gas_limit_ptr, gas_limit_size, _bytes) = contract_api::to_ptr(gas_limit);
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_call_contract_with_gas_limit(
+                contract_hash_ptr,
+                contract_hash_size,
+                entry_point_name_ptr,
+                entry_point_name_size,
+                runtime_args_ptr,
+                runtime_args_size,
+                gas_limit_ptr,
+                gas_limit_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret)?;
+        unsafe { bytes_written.assume_init() }
+    };
+    Ok(deserialize_contract_result(bytes_written))
+}
+
+/// Invokes a stored contract package like [`call_versioned_contract`], but allows the called
+/// contract to use at most `gas_limit` gas.
+///
+/// If the called contract exhausts `gas_limit`, all of its effects are discarded and
+/// `Err(ApiError::OutOfSubCallGas)` is returned, leaving the caller free to continue.
+pub fn call_versioned_contract_with_gas_limit<T: CLTyped + FromBytes>(
+    contract_package_hash: ContractPackageHash,
+    contract_version: Option<ContractVersion>,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+    gas_limit: Gas,
+) -> Result<T, ApiError> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes) =
+        contract_api::to_ptr(contract_package_hash);
+    let (contract_version_ptr, contract_version_size, _bytes) =
+        contract_api::to_ptr(contract_version);
+    let (entry_point_name_ptr, entry_point_name_size, _bytes) =
+        contract_api::to_ptr(entry_point_name);
+    let (runtime_args_ptr, runtime_args_size, _bytes) = contract_api::to_ptr(runtime_args);
+    let (gas_limit_ptr, gas_limit_size, _bytes) = contract_api::to_ptr(gas_limit);
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_call_versioned_contract_with_gas_limit(
+                contract_package_hash_ptr,
+                contract_package_hash_size,
+                contract_version_ptr,
+                contract_version_size,
+                entry_point_name_ptr,
+                entry_point_name_size,
+                runtime_args_ptr,
+                runtime_args_size,
+                gas_limit_ptr,
+                gas_limit_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret)?;
+        unsafe { bytes_written.assume_init() }
+    };
+    Ok(deserialize_contract_result(bytes_written))
+}
+
 fn deserialize_contract_result<T: CLTyped + FromBytes>(bytes_written: usize) -> T {
     let serialized_result = if bytes_written == 0 {
         // If no bytes were written, the host buffer hasn't been set and hence shouldn't be read.
@@ -320,6 +402,16 @@ pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
     ret
 }
 
+/// Emits an event with the given payload.
+///
+/// Events are not written to global state; they are reported in order of emission in the execution
+/// result of a successful deploy, along with the key of the account or contract which emitted them.
+pub fn emit_event<T: CLTyped + ToBytes>(payload: T) {
+    let cl_value = CLValue::from_t(payload).unwrap_or_revert();
+    let (payload_ptr, payload_size, _bytes) = contract_api::to_ptr(cl_value);
+    unsafe { ext_ffi::casper_emit_event(payload_ptr, payload_size) }
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
@@ -229,6 +229,57 @@ pub fn provision_contract_user_group_uref(
     Ok(bytesrepr::deserialize(value_bytes).unwrap_or_revert())
 }
 
+/// Creates a new "user group" for a (versioned) contract, populated with `num_new_urefs` newly
+/// created URefs which are returned.
+///
+/// This is equivalent to calling [`create_contract_user_group`] without any existing URefs.  The
+/// call fails if the package would exceed [`MAX_GROUPS`](casper_types::contracts::MAX_GROUPS)
+/// groups or [`MAX_TOTAL_UREFS`](casper_types::contracts::MAX_TOTAL_UREFS) URefs across all of its
+/// groups.
+pub fn create_contract_user_group_with_urefs(
+    contract_package_hash: ContractPackageHash,
+    group_label: &str,
+    num_new_urefs: u8,
+) -> Result<Vec<URef>, ApiError> {
+    create_contract_user_group(
+        contract_package_hash,
+        group_label,
+        num_new_urefs,
+        BTreeSet::new(),
+    )
+}
+
+/// Extends specified group with `num_new_urefs` new `URef`s, returning them.
+///
+/// The call fails without changing the group if the package would exceed
+/// [`MAX_TOTAL_UREFS`](casper_types::contracts::MAX_TOTAL_UREFS) URefs across all of its groups.
+pub fn provision_contract_user_group_urefs(
+    package_hash: ContractPackageHash,
+    label: &str,
+    num_new_urefs: u8,
+) -> Result<Vec<URef>, ApiError> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
+        contract_api::to_ptr(package_hash);
+    let (label_ptr, label_size, _bytes2) = contract_api::to_ptr(label);
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_provision_contract_user_group_urefs(
+                contract_package_hash_ptr,
+                contract_package_hash_size,
+                label_ptr,
+                label_size,
+                num_new_urefs,
+                value_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret)?;
+        unsafe { value_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    Ok(bytesrepr::deserialize(value_bytes).unwrap_or_revert())
+}
+
 /// Removes specified urefs from a named group.
 pub fn remove_contract_user_group_urefs(
     package_hash: ContractPackageHash,
@@ -335,3 +386,17 @@ pub fn disable_contract_version(
 
     api_error::result_from(result)
 }
+
+/// Permanently lock the contract package stored at the given `ContractPackageHash`. No further
+/// versions can be added to a locked package, and its user groups can no longer be changed. Note
+/// that this contract package must have been created by `create_contract_package_at_hash` first.
+pub fn lock_contract_package(contract_package_hash: ContractPackageHash) -> Result<(), ApiError> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes) =
+        contract_api::to_ptr(contract_package_hash);
+
+    let result = unsafe {
+        ext_ffi::casper_lock_contract_package(contract_package_hash_ptr, contract_package_hash_size)
+    };
+
+    api_error::result_from(result)
+}
@@ -10,7 +10,7 @@ use casper_types::{
         auction::{self, EraId, EraInfo},
         SystemContractType,
     },
-    ApiError, ContractHash, HashAddr, TransferResult, TransferredTo, URef, U512,
+    ApiError, ContractHash, HashAddr, TransferReceipt, TransferResult, TransferredTo, URef, U512,
     UREF_SERIALIZED_LENGTH,
 };
 
@@ -179,6 +179,58 @@ pub fn transfer_from_purse_to_account(
     TransferredTo::result_from(transferred_to_value)
 }
 
+/// Transfers `amount` of motes from the default purse of the account to `target` account and
+/// returns a [`TransferReceipt`] identifying the recorded transfer.  If `target` does not exist it
+/// will be created.
+pub fn transfer_to_account_with_receipt(
+    target: AccountHash,
+    amount: U512,
+    id: Option<u64>,
+) -> Result<TransferReceipt, ApiError> {
+    transfer_from_purse_to_account_with_receipt(account::get_main_purse(), target, amount, id)
+}
+
+/// Transfers `amount` of motes from `source` purse to `target` account and returns a
+/// [`TransferReceipt`] identifying the recorded transfer.  If `target` does not exist it will be
+/// created.
+///
+/// May only be called during the session phase, otherwise execution reverts with
+/// [`ApiError::InvalidPhase`].  If the transfer succeeds without being recorded,
+/// [`ApiError::TransferNotRecorded`] is returned.
+pub fn transfer_from_purse_to_account_with_receipt(
+    source: URef,
+    target: AccountHash,
+    amount: U512,
+    id: Option<u64>,
+) -> Result<TransferReceipt, ApiError> {
+    let (source_ptr, source_size, _bytes1) = contract_api::to_ptr(source);
+    let (target_ptr, target_size, _bytes2) = contract_api::to_ptr(target);
+    let (amount_ptr, amount_size, _bytes3) = contract_api::to_ptr(amount);
+    let (id_ptr, id_size, _bytes4) = contract_api::to_ptr(id);
+
+    let receipt_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let return_code = unsafe {
+            ext_ffi::casper_transfer_from_purse_to_account_with_receipt(
+                source_ptr,
+                source_size,
+                target_ptr,
+                target_size,
+                amount_ptr,
+                amount_size,
+                id_ptr,
+                id_size,
+                output_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(return_code)?;
+        unsafe { output_size.assume_init() }
+    };
+    let receipt_bytes = runtime::read_host_buffer(receipt_size).unwrap_or_revert();
+    let receipt: TransferReceipt = bytesrepr::deserialize(receipt_bytes).unwrap_or_revert();
+    Ok(receipt)
+}
+
 /// Transfers `amount` of motes from `source` purse to `target` purse.  If `target` does not exist
 /// the transfer fails.
 #[doc(hidden)]
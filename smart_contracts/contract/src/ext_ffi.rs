@@ -325,6 +325,41 @@ extern "C" {
         id_size: usize,
         result_ptr: *const i32,
     ) -> i32;
+    /// This function uses the mint contract's transfer function to transfer tokens from the
+    /// specified purse to the main purse of the target account, like
+    /// [`casper_transfer_from_purse_to_account`].  On success, a serialized `TransferReceipt`
+    /// describing the recorded transfer is written to the host buffer and its size is written to
+    /// `output_size_ptr`.  If the transfer succeeded but was not recorded,
+    /// [`ApiError::TransferNotRecorded`] is returned.
+    ///
+    /// [`ApiError::TransferNotRecorded`]: casper_types::ApiError::TransferNotRecorded
+    ///
+    /// # Arguments
+    ///
+    /// * `source_ptr` - pointer in wasm memory to bytes representing the source `URef` to transfer
+    ///   from
+    /// * `source_size` - size of the source `URef` (in bytes)
+    /// * `target_ptr` - pointer in wasm memory to bytes representing the target account to transfer
+    ///   to
+    /// * `target_size` - size of the target (in bytes)
+    /// * `amount_ptr` - pointer in wasm memory to bytes representing the amount to transfer to the
+    ///   target account
+    /// * `amount_size` - size of the amount (in bytes)
+    /// * `id_ptr` - pointer in wasm memory to bytes representing the user-defined transaction id
+    /// * `id_size` - size of the id (in bytes)
+    /// * `output_size_ptr` - pointer to a value where the size of the serialized receipt will be
+    ///   set on successful transfer
+    pub fn casper_transfer_from_purse_to_account_with_receipt(
+        source_ptr: *const u8,
+        source_size: usize,
+        target_ptr: *const u8,
+        target_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        id_ptr: *const u8,
+        id_size: usize,
+        output_size_ptr: *mut usize,
+    ) -> i32;
     /// This function uses the mint contract’s transfer function to transfer
     /// tokens from the specified source purse to the specified target purse. If
     /// the target account does not exist then it is automatically created, and
@@ -543,6 +578,17 @@ extern "C" {
         contract_hash_ptr: *const u8,
         contract_hash_size: usize,
     ) -> i32;
+    /// Permanently locks a contract package, preventing new versions and changes to its user
+    /// groups. Returns non-zero standard error for a failure, otherwise a zero indicates success.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    pub fn casper_lock_contract_package(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+    ) -> i32;
     /// Calls a contract by its hash. Requires entry point name that has to be present on a
     /// specified contract, and serialized named arguments. Returns a standard error code in
     /// case of failure, otherwise a successful execution returns zero. Bytes returned from contract
@@ -595,6 +641,69 @@ extern "C" {
         runtime_args_size: usize,
         result_size: *mut usize,
     ) -> i32;
+    /// Calls a contract by its hash in the same way as [`casper_call_contract`], but allows it to
+    /// use at most the given amount of gas.  If the called contract exhausts this gas sub-limit,
+    /// all of its effects are discarded and [`ApiError::OutOfSubCallGas`] is returned rather than
+    /// the whole execution being aborted.
+    ///
+    /// [`ApiError::OutOfSubCallGas`]: casper_types::ApiError::OutOfSubCallGas
+    ///
+    /// # Arguments
+    /// * `contract_hash_ptr` - pointer to serialized contract hash.
+    /// * `contract_hash_size` - size of contract hash in serialized form.
+    /// * `entry_point_name_ptr` - pointer to serialized contract entry point name
+    /// * `entry_point_name_size` - size of serialized contract entry point name
+    /// * `runtime_args_ptr` - pointer to serialized runtime arguments
+    /// * `runtime_args_size` - size of serialized runtime arguments
+    /// * `gas_limit_ptr` - pointer to serialized gas sub-limit
+    /// * `gas_limit_size` - size of serialized gas sub-limit
+    /// * `result_size` - a pointer to a value which will be set to a size of bytes of called
+    ///   contract return value
+    pub fn casper_call_contract_with_gas_limit(
+        contract_hash_ptr: *const u8,
+        contract_hash_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        runtime_args_ptr: *const u8,
+        runtime_args_size: usize,
+        gas_limit_ptr: *const u8,
+        gas_limit_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    /// Calls a contract by its package hash in the same way as [`casper_call_versioned_contract`],
+    /// but allows it to use at most the given amount of gas.  If the called contract exhausts this
+    /// gas sub-limit, all of its effects are discarded and [`ApiError::OutOfSubCallGas`] is
+    /// returned rather than the whole execution being aborted.
+    ///
+    /// [`ApiError::OutOfSubCallGas`]: casper_types::ApiError::OutOfSubCallGas
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `contract_version_ptr` - pointer to serialized optional contract version
+    /// * `contract_version_size` - size of serialized optional contract version
+    /// * `entry_point_name_ptr` - pointer to serialized contract entry point name
+    /// * `entry_point_name_size` - size of serialized contract entry point name
+    /// * `runtime_args_ptr` - pointer to serialized runtime arguments
+    /// * `runtime_args_size` - size of serialized runtime arguments
+    /// * `gas_limit_ptr` - pointer to serialized gas sub-limit
+    /// * `gas_limit_size` - size of serialized gas sub-limit
+    /// * `result_size` - a pointer to a value which will be set to a size of bytes of called
+    ///   contract return value
+    pub fn casper_call_versioned_contract_with_gas_limit(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        contract_version_ptr: *const u8,
+        contract_version_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        runtime_args_ptr: *const u8,
+        runtime_args_size: usize,
+        gas_limit_ptr: *const u8,
+        gas_limit_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
     /// This function queries the host side to check for given named argument existence and returns
     /// a size in bytes of given argument. Returns zero for success or non-zero value for
     /// failure as described in standard error codes.
@@ -668,6 +777,27 @@ extern "C" {
         label_size: usize,
         value_size_ptr: *const usize,
     ) -> i32;
+    /// Requests host to provision `num_new_urefs` additional [`casper_types::URef`]s to a specified
+    /// group identified by its label.  Either all of the new URefs are added to the group, or none
+    /// are if a limit would be exceeded.  Returns standard error code for non-zero value,
+    /// otherwise zero indicated success.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `label_ptr` - serialized group label
+    /// * `label_size` - size of serialized group label
+    /// * `num_new_urefs` - number of new urefs to add to the group
+    /// * `value_size_ptr` - size of data written to a host buffer will be saved here
+    pub fn casper_provision_contract_user_group_urefs(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        label_ptr: *const u8,
+        label_size: usize,
+        num_new_urefs: u8,
+        value_size_ptr: *const usize,
+    ) -> i32;
     /// Removes user group urefs. Accepts a contract package hash, label name of a group, and a list
     /// of urefs that will be removed from the group.
     ///
@@ -700,6 +830,16 @@ extern "C" {
         out_ptr: *mut u8,
         out_size: usize,
     ) -> i32;
+    /// Emits an event with the given payload, which is recorded in the execution result of the
+    /// deploy rather than in global state. This function will cause a `Trap` if the bytes in wasm
+    /// memory from offset `payload_ptr` to `payload_ptr + payload_size` cannot be de-serialized
+    /// into a `CLValue`, or if the payload exceeds the maximum size of a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload_ptr` - pointer to serialized `CLValue` payload of the event
+    /// * `payload_size` - size of the serialized payload (in bytes)
+    pub fn casper_emit_event(payload_ptr: *const u8, payload_size: usize);
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
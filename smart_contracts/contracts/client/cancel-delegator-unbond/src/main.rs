@@ -0,0 +1,33 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use casper_contract::contract_api::{runtime, system};
+use casper_types::{runtime_args, system::auction, PublicKey, RuntimeArgs, U512};
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_DELEGATOR: &str = "delegator";
+const ARG_VALIDATOR: &str = "validator";
+
+fn cancel_delegator_unbond(delegator: PublicKey, validator: PublicKey, amount: U512) -> U512 {
+    let contract_hash = system::get_auction();
+    let args = runtime_args! {
+        auction::ARG_DELEGATOR => delegator,
+        auction::ARG_VALIDATOR => validator,
+        auction::ARG_AMOUNT => amount,
+    };
+    runtime::call_contract(contract_hash, auction::METHOD_CANCEL_DELEGATOR_UNBOND, args)
+}
+
+// Cancel delegator unbond contract.
+//
+// Accepts a delegator's public key, the public key of the validator it undelegated from, and an
+// amount (of type `U512`) of its pending undelegations to be restored to the delegation.
+#[no_mangle]
+pub extern "C" fn call() {
+    let delegator = runtime::get_named_arg(ARG_DELEGATOR);
+    let validator = runtime::get_named_arg(ARG_VALIDATOR);
+    let amount = runtime::get_named_arg(ARG_AMOUNT);
+    cancel_delegator_unbond(delegator, validator, amount);
+}
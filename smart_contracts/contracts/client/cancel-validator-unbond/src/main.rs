@@ -0,0 +1,30 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use casper_contract::contract_api::{runtime, system};
+use casper_types::{runtime_args, system::auction, PublicKey, RuntimeArgs, U512};
+
+const ARG_PUBLIC_KEY: &str = "public_key";
+const ARG_AMOUNT: &str = "amount";
+
+fn cancel_validator_unbond(public_key: PublicKey, amount: U512) -> U512 {
+    let contract_hash = system::get_auction();
+    let args = runtime_args! {
+        auction::ARG_PUBLIC_KEY => public_key,
+        auction::ARG_AMOUNT => amount,
+    };
+    runtime::call_contract(contract_hash, auction::METHOD_CANCEL_VALIDATOR_UNBOND, args)
+}
+
+// Cancel validator unbond contract.
+//
+// Accepts a validator's public key and an amount (of type `U512`) of its pending withdrawals to be
+// restored to its bid.
+#[no_mangle]
+pub extern "C" fn call() {
+    let public_key = runtime::get_named_arg(ARG_PUBLIC_KEY);
+    let amount = runtime::get_named_arg(ARG_AMOUNT);
+    cancel_validator_unbond(public_key, amount);
+}
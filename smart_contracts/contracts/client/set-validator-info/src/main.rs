@@ -0,0 +1,43 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::contract_api::{runtime, system};
+use casper_types::{runtime_args, system::auction, PublicKey, RuntimeArgs};
+
+const ARG_PUBLIC_KEY: &str = "public_key";
+const ARG_NAME: &str = "name";
+const ARG_URL: &str = "url";
+const ARG_ICON_HASH: &str = "icon_hash";
+
+fn set_validator_info(
+    public_key: PublicKey,
+    name: String,
+    url: String,
+    icon_hash: Option<[u8; 32]>,
+) {
+    let contract_hash = system::get_auction();
+    let args = runtime_args! {
+        auction::ARG_PUBLIC_KEY => public_key,
+        auction::ARG_NAME => name,
+        auction::ARG_URL => url,
+        auction::ARG_ICON_HASH => icon_hash,
+    };
+    runtime::call_contract::<()>(contract_hash, auction::METHOD_SET_VALIDATOR_INFO, args);
+}
+
+// Set validator info contract.
+//
+// Accepts a validator's public key, its name and URL (of type `String`) and optionally the hash of
+// its icon (of type `[u8; 32]`), and publishes them as the validator's metadata.
+#[no_mangle]
+pub extern "C" fn call() {
+    let public_key = runtime::get_named_arg(ARG_PUBLIC_KEY);
+    let name = runtime::get_named_arg(ARG_NAME);
+    let url = runtime::get_named_arg(ARG_URL);
+    let icon_hash = runtime::get_named_arg(ARG_ICON_HASH);
+    set_validator_info(public_key, name, url, icon_hash);
+}
@@ -5,13 +5,14 @@ extern crate alloc;
 
 use alloc::{collections::BTreeMap, string::String};
 
-use casper_contract::contract_api::{runtime, system};
+use casper_contract::contract_api::{runtime, storage, system};
 
 use casper_types::{
     runtime_args,
     system::auction::{
-        ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_REWARD_FACTORS, ARG_VALIDATOR,
-        METHOD_DELEGATE, METHOD_DISTRIBUTE, METHOD_RUN_AUCTION, METHOD_UNDELEGATE,
+        Bid, Delegator, ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_PUBLIC_KEY,
+        ARG_REWARD_FACTORS, ARG_VALIDATOR, METHOD_DELEGATE, METHOD_DISTRIBUTE, METHOD_GET_BID,
+        METHOD_GET_DELEGATOR, METHOD_RUN_AUCTION, METHOD_UNDELEGATE,
     },
     ApiError, PublicKey, RuntimeArgs, U512,
 };
@@ -21,6 +22,7 @@ const ARG_AMOUNT: &str = "amount";
 const ARG_DELEGATE: &str = "delegate";
 const ARG_UNDELEGATE: &str = "undelegate";
 const ARG_RUN_AUCTION: &str = "run_auction";
+const RESULT_KEY: &str = "result";
 
 #[repr(u16)]
 enum Error {
@@ -40,6 +42,8 @@ pub extern "C" fn call() {
         }
         ARG_RUN_AUCTION => run_auction(),
         METHOD_DISTRIBUTE => distribute(),
+        METHOD_GET_BID => get_bid(),
+        METHOD_GET_DELEGATOR => get_delegator(),
         _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
     };
 }
@@ -88,3 +92,25 @@ fn distribute() {
     };
     runtime::call_contract::<()>(auction, METHOD_DISTRIBUTE, args);
 }
+
+fn get_bid() {
+    let auction = system::get_auction();
+    let public_key: PublicKey = runtime::get_named_arg(ARG_PUBLIC_KEY);
+    let args = runtime_args! {
+        ARG_PUBLIC_KEY => public_key,
+    };
+    let bid: Option<Bid> = runtime::call_contract(auction, METHOD_GET_BID, args);
+    runtime::put_key(RESULT_KEY, storage::new_uref(bid).into());
+}
+
+fn get_delegator() {
+    let auction = system::get_auction();
+    let validator: PublicKey = runtime::get_named_arg(ARG_VALIDATOR);
+    let delegator: PublicKey = runtime::get_named_arg(ARG_DELEGATOR);
+    let args = runtime_args! {
+        ARG_VALIDATOR => validator,
+        ARG_DELEGATOR => delegator,
+    };
+    let delegator: Option<Delegator> = runtime::call_contract(auction, METHOD_GET_DELEGATOR, args);
+    runtime::put_key(RESULT_KEY, storage::new_uref(delegator).into());
+}
@@ -0,0 +1,117 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::convert::TryInto;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    AccessRights, ApiError, CLType, ContractHash, ContractPackageHash, EntryPointAccess,
+    EntryPointType, URef,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ARG_ACTION: &str = "action";
+const ARG_CONTRACT_PACKAGE: &str = "contract_package";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ACCESS_KEY_NAME: &str = "access_key_name";
+const ARG_GROUP_LABEL: &str = "group_label";
+const ACTION_ADD_VERSION: &str = "add_version";
+const ACTION_DISABLE_VERSION: &str = "disable_version";
+const ACTION_ATTENUATE_ACCESS_KEY: &str = "attenuate_access_key";
+const ACTION_LOCK_PACKAGE: &str = "lock_package";
+const ACTION_CREATE_GROUP: &str = "create_group";
+
+#[repr(u16)]
+enum Error {
+    UnknownAction = 0,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+fn add_version() {
+    let contract_package: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    storage::add_contract_version(contract_package, entry_points, NamedKeys::new());
+}
+
+fn disable_version() {
+    let contract_package: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+
+    storage::disable_contract_version(contract_package, contract_hash).unwrap_or_revert();
+}
+
+fn lock_package() {
+    let contract_package: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+
+    storage::lock_contract_package(contract_package).unwrap_or_revert();
+}
+
+fn create_group() {
+    let contract_package: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+    let group_label: String = runtime::get_named_arg(ARG_GROUP_LABEL);
+
+    storage::create_contract_user_group(contract_package, &group_label, 1, BTreeSet::new())
+        .unwrap_or_revert();
+}
+
+/// Replaces the named access key with a read-only copy of itself.
+fn attenuate_access_key() {
+    let access_key_name: String = runtime::get_named_arg(ARG_ACCESS_KEY_NAME);
+
+    let access_key: URef = runtime::get_key(&access_key_name)
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert();
+
+    runtime::remove_key(&access_key_name);
+    runtime::put_key(
+        &access_key_name,
+        URef::new(access_key.addr(), AccessRights::READ).into(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let action: String = runtime::get_named_arg(ARG_ACTION);
+
+    match action.as_str() {
+        ACTION_ADD_VERSION => add_version(),
+        ACTION_DISABLE_VERSION => disable_version(),
+        ACTION_ATTENUATE_ACCESS_KEY => attenuate_access_key(),
+        ACTION_LOCK_PACKAGE => lock_package(),
+        ACTION_CREATE_GROUP => create_group(),
+        _ => runtime::revert(Error::UnknownAction),
+    }
+}
@@ -0,0 +1,46 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    CLType, EntryPointAccess, EntryPointType, Parameter,
+};
+
+const ARG_MESSAGE: &str = "message";
+const ENTRY_POINT_EMIT: &str = "emit";
+const HASH_KEY_NAME: &str = "emit_event_hash";
+
+/// Emits the given message as an event from within a stored contract.
+#[no_mangle]
+pub extern "C" fn emit() {
+    let message: String = runtime::get_named_arg(ARG_MESSAGE);
+    runtime::emit_event(message);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: String = runtime::get_named_arg(ARG_MESSAGE);
+    runtime::emit_event(message);
+    runtime::emit_event(42u64);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_EMIT,
+            vec![Parameter::new(ARG_MESSAGE, CLType::String)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
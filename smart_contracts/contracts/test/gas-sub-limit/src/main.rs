@@ -0,0 +1,86 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    ApiError, CLType, ContractHash, EntryPointAccess, EntryPointType, Gas, Parameter, RuntimeArgs,
+    U512,
+};
+
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_GAS_LIMIT: &str = "gas_limit";
+const ENTRY_POINT_BURN_GAS: &str = "burn_gas";
+const ENTRY_POINT_CALL_BURN_GAS: &str = "call_burn_gas";
+const COUNTER_KEY_NAME: &str = "counter";
+const HASH_KEY_NAME: &str = "gas_sub_limit_hash";
+const RESULT_KEY_NAME: &str = "sub_call_result";
+
+/// Writes to the counter, then loops until it runs out of gas.
+#[no_mangle]
+pub extern "C" fn burn_gas() {
+    let counter = runtime::get_key(COUNTER_KEY_NAME)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert();
+    storage::write(counter, 1u64);
+    loop {
+        storage::write(counter, 2u64);
+    }
+}
+
+/// Calls `burn_gas` with the given gas sub-limit and records the resulting error code.
+#[no_mangle]
+pub extern "C" fn call_burn_gas() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let gas_limit: U512 = runtime::get_named_arg(ARG_GAS_LIMIT);
+
+    let result: Result<(), ApiError> = runtime::call_contract_with_gas_limit(
+        contract_hash,
+        ENTRY_POINT_BURN_GAS,
+        RuntimeArgs::new(),
+        Gas::new(gas_limit),
+    );
+    let error = result.err().unwrap_or_revert_with(ApiError::User(0));
+    runtime::put_key(RESULT_KEY_NAME, storage::new_uref(u32::from(error)).into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_BURN_GAS,
+            vec![],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_CALL_BURN_GAS,
+            vec![
+                Parameter::new(ARG_CONTRACT_HASH, CLType::ByteArray(32)),
+                Parameter::new(ARG_GAS_LIMIT, CLType::U512),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        ));
+        entry_points
+    };
+
+    let counter = storage::new_uref(0u64);
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(COUNTER_KEY_NAME.to_string(), counter.into());
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+    runtime::put_key(COUNTER_KEY_NAME, counter.into());
+}
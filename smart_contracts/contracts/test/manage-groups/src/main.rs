@@ -19,7 +19,7 @@ use casper_contract::{
 };
 use casper_types::{
     contracts::{EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, NamedKeys},
-    CLType, ContractPackageHash, Key, Parameter, URef,
+    ApiError, CLType, ContractPackageHash, Key, Parameter, URef,
 };
 
 const PACKAGE_HASH_KEY: &str = "package_hash_key";
@@ -27,6 +27,7 @@ const PACKAGE_ACCESS_KEY: &str = "package_access_key";
 const CREATE_GROUP: &str = "create_group";
 const REMOVE_GROUP: &str = "remove_group";
 const EXTEND_GROUP_UREFS: &str = "extend_group_urefs";
+const TRY_EXTEND_GROUP_UREFS: &str = "try_extend_group_urefs";
 const REMOVE_GROUP_UREFS: &str = "remove_group_urefs";
 const GROUP_NAME_ARG: &str = "group_name";
 const UREFS_ARG: &str = "urefs";
@@ -71,12 +72,34 @@ pub extern "C" fn extend_group_urefs() {
         .into();
     let group_name: String = runtime::get_named_arg(GROUP_NAME_ARG);
     let new_urefs_count: u64 = runtime::get_named_arg(TOTAL_NEW_UREFS_ARG);
+    let new_urefs_count: u8 = new_urefs_count
+        .try_into()
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
 
     // Provisions additional urefs inside group
-    for _ in 1..=new_urefs_count {
-        let _new_uref = storage::provision_contract_user_group_uref(package_hash_key, &group_name)
-            .unwrap_or_revert();
-    }
+    let _new_urefs = storage::provision_contract_user_group_urefs(
+        package_hash_key,
+        &group_name,
+        new_urefs_count,
+    )
+    .unwrap_or_revert();
+}
+
+/// Like `extend_group_urefs`, but ignores any error so that the deploy succeeds.
+#[no_mangle]
+pub extern "C" fn try_extend_group_urefs() {
+    let package_hash_key: ContractPackageHash = runtime::get_key(PACKAGE_HASH_KEY)
+        .and_then(Key::into_hash)
+        .unwrap_or_revert()
+        .into();
+    let group_name: String = runtime::get_named_arg(GROUP_NAME_ARG);
+    let new_urefs_count: u8 = runtime::get_named_arg(TOTAL_NEW_UREFS_ARG);
+
+    let _ = storage::provision_contract_user_group_urefs(
+        package_hash_key,
+        &group_name,
+        new_urefs_count,
+    );
 }
 
 #[no_mangle]
@@ -137,6 +160,18 @@ fn create_entry_points_1() -> EntryPoints {
     );
     entry_points.add_entry_point(extend_group_urefs);
 
+    let try_extend_group_urefs = EntryPoint::new(
+        TRY_EXTEND_GROUP_UREFS.to_string(),
+        vec![
+            Parameter::new(GROUP_NAME_ARG, CLType::String),
+            Parameter::new(TOTAL_NEW_UREFS_ARG, CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    );
+    entry_points.add_entry_point(try_extend_group_urefs);
+
     let entry_point_name = REMOVE_GROUP_UREFS.to_string();
     let remove_group_urefs = EntryPoint::new(
         entry_point_name,
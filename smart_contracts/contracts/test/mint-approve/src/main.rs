@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{account::AccountHash, runtime_args, system::mint, RuntimeArgs, U512};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let spender: AccountHash = runtime::get_named_arg(mint::ARG_SPENDER);
+    let amount: U512 = runtime::get_named_arg(mint::ARG_AMOUNT);
+
+    let result: Result<(), mint::Error> = runtime::call_contract(
+        system::get_mint(),
+        mint::METHOD_APPROVE,
+        runtime_args! {
+            mint::ARG_OWNER_PURSE => account::get_main_purse(),
+            mint::ARG_SPENDER => spender,
+            mint::ARG_AMOUNT => amount,
+        },
+    );
+    result.unwrap_or_revert();
+}
@@ -0,0 +1,56 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    runtime_args,
+    system::mint,
+    CLType, CLTyped, EntryPointAccess, EntryPointType, Parameter, RuntimeArgs, URefAddr, U512,
+};
+
+const ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
+const HASH_KEY_NAME: &str = "mint_transfer_from_stored";
+
+/// Tries to spend the caller's allowance on the given owner purse from within a stored contract.
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner_purse: URefAddr = runtime::get_named_arg(mint::ARG_OWNER_PURSE);
+    let amount: U512 = runtime::get_named_arg(mint::ARG_AMOUNT);
+
+    let result: Result<(), mint::Error> = runtime::call_contract(
+        system::get_mint(),
+        mint::METHOD_TRANSFER_FROM,
+        runtime_args! {
+            mint::ARG_OWNER_PURSE => owner_purse,
+            mint::ARG_TARGET => system::create_purse(),
+            mint::ARG_AMOUNT => amount,
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    );
+    result.unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_TRANSFER_FROM,
+        vec![
+            Parameter::new(mint::ARG_OWNER_PURSE, URefAddr::cl_type()),
+            Parameter::new(mint::ARG_AMOUNT, U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, system::mint, RuntimeArgs, URefAddr, U512};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let owner_purse: URefAddr = runtime::get_named_arg(mint::ARG_OWNER_PURSE);
+    let amount: U512 = runtime::get_named_arg(mint::ARG_AMOUNT);
+
+    let result: Result<(), mint::Error> = runtime::call_contract(
+        system::get_mint(),
+        mint::METHOD_TRANSFER_FROM,
+        runtime_args! {
+            mint::ARG_OWNER_PURSE => owner_purse,
+            mint::ARG_TARGET => account::get_main_purse(),
+            mint::ARG_AMOUNT => amount,
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    );
+    result.unwrap_or_revert();
+}
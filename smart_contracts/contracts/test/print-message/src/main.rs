@@ -0,0 +1,17 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::contract_api::runtime;
+
+const ARG_MESSAGE: &str = "message";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: String = runtime::get_named_arg(ARG_MESSAGE);
+    runtime::print("before message");
+    runtime::print(&message);
+}
@@ -0,0 +1,80 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    runtime_args, CLType, CLTyped, ContractHash, EntryPointAccess, EntryPointType, Parameter,
+    RuntimeArgs,
+};
+
+const ARG_CALLS: &str = "calls";
+const ENTRY_POINT_GUARDED: &str = "guarded";
+const ENTRY_POINT_UNGUARDED: &str = "unguarded";
+const HASH_KEY_NAME_A: &str = "reentrancy_guard_a";
+const HASH_KEY_NAME_B: &str = "reentrancy_guard_b";
+
+/// Makes the first of the given calls, passing the remaining ones on to it.
+fn call_next() {
+    let mut calls: Vec<(ContractHash, String)> = runtime::get_named_arg(ARG_CALLS);
+    if calls.is_empty() {
+        return;
+    }
+    let (contract_hash, entry_point_name) = calls.remove(0);
+    runtime::call_contract::<()>(
+        contract_hash,
+        &entry_point_name,
+        runtime_args! { ARG_CALLS => calls },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn guarded() {
+    call_next()
+}
+
+#[no_mangle]
+pub extern "C" fn unguarded() {
+    call_next()
+}
+
+fn entry_points() -> EntryPoints {
+    let calls_parameter = || {
+        vec![Parameter::new(
+            ARG_CALLS,
+            Vec::<(ContractHash, String)>::cl_type(),
+        )]
+    };
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(
+        EntryPoint::new(
+            ENTRY_POINT_GUARDED,
+            calls_parameter(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        )
+        .with_non_reentrant(true),
+    );
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UNGUARDED,
+        calls_parameter(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    for hash_key_name in [HASH_KEY_NAME_A, HASH_KEY_NAME_B].iter() {
+        let (contract_hash, _contract_version) =
+            storage::new_contract(entry_points(), None, None, None);
+        runtime::put_key(hash_key_name, contract_hash.into());
+    }
+}
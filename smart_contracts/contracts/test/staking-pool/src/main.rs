@@ -0,0 +1,130 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    runtime_args,
+    system::auction,
+    CLType, CLTyped, EntryPointAccess, EntryPointType, Parameter, PublicKey, RuntimeArgs, URef,
+    U512,
+};
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_VALIDATOR: &str = "validator";
+const ENTRY_POINT_DELEGATE: &str = "delegate";
+const ENTRY_POINT_UNDELEGATE: &str = "undelegate";
+const ENTRY_POINT_DELEGATE_FROM_SESSION: &str = "delegate_from_session";
+const POOL_PURSE_KEY_NAME: &str = "pool_purse";
+const HASH_KEY_NAME: &str = "staking_pool_hash";
+const PURSE_KEY_NAME: &str = "staking_pool_purse";
+
+fn pool_purse() -> URef {
+    runtime::get_key(POOL_PURSE_KEY_NAME)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert()
+}
+
+fn delegate_from_contract(validator: PublicKey, amount: U512, source_purse: URef) -> U512 {
+    let args = runtime_args! {
+        auction::ARG_VALIDATOR => validator,
+        auction::ARG_AMOUNT => amount,
+        auction::ARG_SOURCE_PURSE => source_purse,
+    };
+    runtime::call_contract(
+        system::get_auction(),
+        auction::METHOD_DELEGATE_FROM_CONTRACT,
+        args,
+    )
+}
+
+/// Delegates `amount` motes from the pool's purse to `validator`.
+#[no_mangle]
+pub extern "C" fn delegate() {
+    let validator = runtime::get_named_arg(ARG_VALIDATOR);
+    let amount = runtime::get_named_arg(ARG_AMOUNT);
+    delegate_from_contract(validator, amount, pool_purse());
+}
+
+/// Undelegates `amount` motes of the pool's delegation to `validator`.
+#[no_mangle]
+pub extern "C" fn undelegate() {
+    let validator: PublicKey = runtime::get_named_arg(ARG_VALIDATOR);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let args = runtime_args! {
+        auction::ARG_VALIDATOR => validator,
+        auction::ARG_AMOUNT => amount,
+    };
+    let _: U512 = runtime::call_contract(
+        system::get_auction(),
+        auction::METHOD_UNDELEGATE_FROM_CONTRACT,
+        args,
+    );
+}
+
+/// Attempts to delegate from the caller's main purse in the caller's session context, which the
+/// auction rejects.
+#[no_mangle]
+pub extern "C" fn delegate_from_session() {
+    let validator = runtime::get_named_arg(ARG_VALIDATOR);
+    let amount = runtime::get_named_arg(ARG_AMOUNT);
+    delegate_from_contract(validator, amount, account::get_main_purse());
+}
+
+// Staking pool contract.
+//
+// Funds the pool's purse with `amount` motes from the caller's main purse and stores a contract
+// which delegates from and undelegates to that purse.
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let parameters = vec![
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ];
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_DELEGATE,
+            parameters.clone(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_UNDELEGATE,
+            parameters.clone(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_DELEGATE_FROM_SESSION,
+            parameters,
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        ));
+        entry_points
+    };
+
+    let pool_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), pool_purse, amount, None)
+        .unwrap_or_revert();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(POOL_PURSE_KEY_NAME.to_string(), pool_purse.into());
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+    runtime::put_key(PURSE_KEY_NAME, pool_purse.into_read().into());
+}
@@ -0,0 +1,24 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{account::AccountHash, U512};
+
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ID: &str = "id";
+const RECEIPT_KEY_NAME: &str = "transfer_receipt";
+
+/// Transfers from the caller's main purse and stores the returned receipt under a named key.
+#[no_mangle]
+pub extern "C" fn call() {
+    let target: AccountHash = runtime::get_named_arg(ARG_TARGET);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let id: Option<u64> = runtime::get_named_arg(ARG_ID);
+
+    let receipt = system::transfer_to_account_with_receipt(target, amount, id).unwrap_or_revert();
+    runtime::put_key(RECEIPT_KEY_NAME, storage::new_uref(receipt).into());
+}
@@ -1,4 +1,11 @@
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FromIterator,
+};
 
 use bitflags::bitflags;
 use datasize::DataSize;
@@ -8,7 +15,7 @@ use rand::{
 };
 use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::bytesrepr;
+use crate::{bytesrepr, Key, URef, URefAddr};
 
 /// The number of bytes in a serialized [`AccessRights`].
 pub const ACCESS_RIGHTS_SERIALIZED_LENGTH: usize = 1;
@@ -131,6 +138,104 @@ impl Distribution<AccessRights> for Standard {
     }
 }
 
+/// Error returned when a [`URef`] is used without its access rights having been granted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ForgedReference(pub URef);
+
+impl Display for ForgedReference {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Forged reference: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ForgedReference {}
+
+/// The [`URef`]s known to an execution context, used to detect forged references.
+///
+/// A `URef` is only valid in a context which has previously been granted the same address with at
+/// least the access rights being claimed, either by holding it as a named key, by receiving it as
+/// an argument or return value, or by creating it.  Access rights granted by different `URef`s to
+/// the same address are not combined: each claimed `URef` must be covered by a single grant.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct AccessRightsValidator {
+    known_rights: BTreeMap<URefAddr, BTreeSet<AccessRights>>,
+}
+
+impl AccessRightsValidator {
+    /// Constructs a validator which knows no `URef`s.
+    pub fn new() -> Self {
+        AccessRightsValidator::default()
+    }
+
+    /// Constructs a validator which knows the `URef`s among the given keys.
+    pub fn from_keys<I: IntoIterator<Item = Key>>(keys: I) -> Self {
+        keys.into_iter().filter_map(|key| key.into_uref()).collect()
+    }
+
+    /// Grants the address and access rights of `uref`.
+    pub fn insert(&mut self, uref: URef) {
+        self.known_rights
+            .entry(uref.addr())
+            .or_default()
+            .insert(uref.access_rights());
+    }
+
+    /// Grants all the `URef`s known to `other`, in addition to those already granted.
+    pub fn extend(&mut self, other: AccessRightsValidator) {
+        for (addr, rights) in other.known_rights {
+            self.known_rights.entry(addr).or_default().extend(rights);
+        }
+    }
+
+    /// Returns `true` if any `URef` with the address `addr` has been granted, regardless of its
+    /// access rights.
+    pub fn contains_addr(&self, addr: &URefAddr) -> bool {
+        self.known_rights.contains_key(addr)
+    }
+
+    /// Returns `true` if a `URef` with the same address as `uref` and at least its access rights
+    /// has been granted.
+    pub fn has_access_rights(&self, uref: &URef) -> bool {
+        let claimed_rights = uref.access_rights();
+        self.known_rights
+            .get(&uref.addr())
+            .map(|known_rights| {
+                known_rights
+                    .iter()
+                    .any(|rights| *rights & claimed_rights == claimed_rights)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Checks that `uref` is not forged.
+    pub fn validate_uref(&self, uref: &URef) -> Result<(), ForgedReference> {
+        if self.has_access_rights(uref) {
+            Ok(())
+        } else {
+            Err(ForgedReference(*uref))
+        }
+    }
+
+    /// Checks that `key` is not a forged `URef`.  Keys of other variants are always valid.
+    pub fn validate_key(&self, key: &Key) -> Result<(), ForgedReference> {
+        match key {
+            Key::URef(uref) => self.validate_uref(uref),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromIterator<URef> for AccessRightsValidator {
+    fn from_iter<I: IntoIterator<Item = URef>>(urefs: I) -> Self {
+        let mut validator = AccessRightsValidator::new();
+        for uref in urefs {
+            validator.insert(uref);
+        }
+        validator
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,4 +284,93 @@ mod tests {
         test_addable(AccessRights::WRITE, false);
         test_addable(AccessRights::READ_ADD_WRITE, true);
     }
+
+    const ADDR: URefAddr = [1; 32];
+    const OTHER_ADDR: URefAddr = [2; 32];
+
+    #[test]
+    fn should_reject_unknown_uref() {
+        let validator = AccessRightsValidator::from_keys(vec![Key::URef(URef::new(
+            ADDR,
+            AccessRights::READ_ADD_WRITE,
+        ))]);
+        let forged = URef::new(OTHER_ADDR, AccessRights::READ);
+        assert!(!validator.contains_addr(&OTHER_ADDR));
+        assert_eq!(
+            validator.validate_uref(&forged),
+            Err(ForgedReference(forged))
+        );
+    }
+
+    #[test]
+    fn should_accept_uref_with_fewer_rights() {
+        let validator: AccessRightsValidator = vec![URef::new(ADDR, AccessRights::READ_WRITE)]
+            .into_iter()
+            .collect();
+        for rights in &[
+            AccessRights::NONE,
+            AccessRights::READ,
+            AccessRights::WRITE,
+            AccessRights::READ_WRITE,
+        ] {
+            assert!(validator.validate_uref(&URef::new(ADDR, *rights)).is_ok());
+        }
+        let escalated = URef::new(ADDR, AccessRights::READ_ADD);
+        assert!(validator.contains_addr(&ADDR));
+        assert_eq!(
+            validator.validate_uref(&escalated),
+            Err(ForgedReference(escalated))
+        );
+    }
+
+    #[test]
+    fn should_not_combine_rights_of_separate_grants() {
+        let mut validator = AccessRightsValidator::new();
+        validator.insert(URef::new(ADDR, AccessRights::READ));
+        validator.insert(URef::new(ADDR, AccessRights::ADD));
+
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::READ)));
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::ADD)));
+        assert!(!validator.has_access_rights(&URef::new(ADDR, AccessRights::READ_ADD)));
+    }
+
+    #[test]
+    fn should_keep_grants_when_extended() {
+        let mut validator = AccessRightsValidator::new();
+        validator.insert(URef::new(ADDR, AccessRights::READ_WRITE));
+        let mut other = AccessRightsValidator::new();
+        other.insert(URef::new(ADDR, AccessRights::ADD));
+        validator.extend(other);
+
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::ADD)));
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::READ_WRITE)));
+        assert!(!validator.has_access_rights(&URef::new(ADDR, AccessRights::READ_ADD_WRITE)));
+    }
+
+    #[test]
+    fn should_keep_all_collected_grants() {
+        let validator: AccessRightsValidator = vec![
+            URef::new(ADDR, AccessRights::READ),
+            URef::new(ADDR, AccessRights::WRITE),
+            URef::new(OTHER_ADDR, AccessRights::READ),
+            URef::new(ADDR, AccessRights::ADD),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::ADD)));
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::READ)));
+        assert!(validator.has_access_rights(&URef::new(ADDR, AccessRights::WRITE)));
+        assert!(!validator.has_access_rights(&URef::new(ADDR, AccessRights::READ_WRITE)));
+        assert!(validator.has_access_rights(&URef::new(OTHER_ADDR, AccessRights::READ)));
+    }
+
+    #[test]
+    fn should_only_validate_uref_keys() {
+        let validator = AccessRightsValidator::new();
+        assert!(validator.validate_key(&Key::Hash([3; 32])).is_ok());
+        assert!(validator
+            .validate_key(&Key::URef(URef::new(ADDR, AccessRights::NONE)))
+            .is_err());
+    }
 }
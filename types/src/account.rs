@@ -10,10 +10,6 @@ use core::{
     fmt::{self, Debug, Display, Formatter},
 };
 
-use blake2::{
-    digest::{Update, VariableOutput},
-    VarBlake2b,
-};
 use datasize::DataSize;
 use rand::{
     distributions::{Distribution, Standard},
@@ -27,7 +23,7 @@ use thiserror::Error;
 
 use crate::{
     bytesrepr::{Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    CLType, CLTyped, PublicKey, BLAKE2B_DIGEST_LENGTH,
+    checksummed_hex, hashing, CLType, CLTyped, PublicKey, BLAKE2B_DIGEST_LENGTH,
 };
 
 const FORMATTED_STRING_PREFIX: &str = "account-hash-";
@@ -48,6 +44,8 @@ pub enum FromStrError {
     InvalidPrefix,
     /// The hash is not valid hex.
     Hex(base16::DecodeError),
+    /// The hash is mixed-case, but doesn't match its checksum.
+    InvalidChecksum,
     /// The hash is the wrong length.
     Hash(TryFromSliceError),
 }
@@ -58,6 +56,15 @@ impl From<base16::DecodeError> for FromStrError {
     }
 }
 
+impl From<checksummed_hex::DecodeError> for FromStrError {
+    fn from(error: checksummed_hex::DecodeError) -> Self {
+        match error {
+            checksummed_hex::DecodeError::Hex(error) => FromStrError::Hex(error),
+            checksummed_hex::DecodeError::InvalidChecksum => FromStrError::InvalidChecksum,
+        }
+    }
+}
+
 impl From<TryFromSliceError> for FromStrError {
     fn from(error: TryFromSliceError) -> Self {
         FromStrError::Hash(error)
@@ -71,6 +78,10 @@ impl Display for FromStrError {
             FromStrError::Hex(error) => {
                 write!(f, "failed to decode address portion from hex: {}", error)
             }
+            FromStrError::InvalidChecksum => write!(
+                f,
+                "address portion doesn't match its checksum; check for typos"
+            ),
             FromStrError::Hash(error) => write!(f, "address portion is wrong length: {}", error),
         }
     }
@@ -136,6 +147,13 @@ pub enum SetThresholdFailure {
         error("New threshold should be lower or equal than total weight of associated keys")
     )]
     InsufficientTotalWeight = 4,
+    /// Setting a threshold to a value greater than the maximum action threshold configured for the
+    /// network is disallowed.
+    #[cfg_attr(
+        feature = "std",
+        error("New threshold should be lower than or equal to the maximum action threshold")
+    )]
+    MaxThresholdExceeded = 5,
 }
 
 // This conversion is not intended to be used by third party crates.
@@ -157,13 +175,18 @@ impl TryFrom<i32> for SetThresholdFailure {
             d if d == SetThresholdFailure::InsufficientTotalWeight as i32 => {
                 Ok(SetThresholdFailure::InsufficientTotalWeight)
             }
+            d if d == SetThresholdFailure::MaxThresholdExceeded as i32 => {
+                Ok(SetThresholdFailure::MaxThresholdExceeded)
+            }
             _ => Err(TryFromIntError(())),
         }
     }
 }
 
-/// Maximum number of associated keys (i.e. map of [`AccountHash`]s to [`Weight`]s) for a single
-/// account.
+/// Default maximum number of associated keys (i.e. map of [`AccountHash`]s to [`Weight`]s) for a
+/// single account.
+///
+/// The maximum in effect is configured for the network and may change at protocol upgrades.
 pub const MAX_ASSOCIATED_KEYS: usize = 10;
 
 /// The number of bytes in a serialized [`Weight`].
@@ -244,12 +267,25 @@ impl AccountHash {
         )
     }
 
-    /// Parses a string formatted as per `Self::to_formatted_string()` into an `AccountHash`.
+    /// Formats the `AccountHash` as per `Self::to_formatted_string()`, but with the hex portion
+    /// checksummed; see [`checksummed_hex`].
+    pub fn to_checksummed_formatted_string(&self) -> String {
+        format!(
+            "{}{}",
+            FORMATTED_STRING_PREFIX,
+            checksummed_hex::encode(&self.0),
+        )
+    }
+
+    /// Parses a string formatted as per `Self::to_formatted_string()` or
+    /// `Self::to_checksummed_formatted_string()` into an `AccountHash`.
+    ///
+    /// A mixed-case hex portion is rejected if it doesn't match its checksum.
     pub fn from_formatted_str(input: &str) -> Result<Self, FromStrError> {
         let remainder = input
             .strip_prefix(FORMATTED_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
-        let bytes = AccountHashBytes::try_from(base16::decode(remainder)?.as_ref())?;
+        let bytes = AccountHashBytes::try_from(checksummed_hex::decode(remainder)?.as_ref())?;
         Ok(AccountHash(bytes))
     }
 
@@ -321,15 +357,7 @@ impl<'de> Deserialize<'de> for AccountHash {
 
 #[doc(hidden)]
 pub fn blake2b<T: AsRef<[u8]>>(data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
-    let mut result = [0; BLAKE2B_DIGEST_LENGTH];
-    // NOTE: Assumed safe as `BLAKE2B_DIGEST_LENGTH` is a valid value for a hasher
-    let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher");
-
-    hasher.update(data);
-    hasher.finalize_variable(|slice| {
-        result.copy_from_slice(slice);
-    });
-    result
+    hashing::blake2b256(data)
 }
 
 impl TryFrom<&[u8]> for AccountHash {
@@ -412,8 +440,8 @@ impl Distribution<AccountHash> for Standard {
 #[cfg_attr(feature = "std", derive(Error))]
 #[repr(i32)]
 pub enum AddKeyFailure {
-    /// There are already [`MAX_ASSOCIATED_KEYS`] [`AccountHash`]s associated with the given
-    /// account.
+    /// There are already as many [`AccountHash`]s associated with the given account as the maximum
+    /// number of associated keys configured for the network.
     #[cfg_attr(
         feature = "std",
         error("Unable to add new associated key because maximum amount of keys is reached")
@@ -567,7 +595,7 @@ mod tests {
 
     #[test]
     fn try_from_i32_for_set_threshold_failure() {
-        let max_valid_value_for_variant = SetThresholdFailure::InsufficientTotalWeight as i32;
+        let max_valid_value_for_variant = SetThresholdFailure::MaxThresholdExceeded as i32;
         assert_eq!(
             Err(TryFromIntError(())),
             SetThresholdFailure::try_from(max_valid_value_for_variant + 1),
@@ -637,6 +665,39 @@ mod tests {
         assert!(AccountHash::from_formatted_str(invalid_hex).is_err());
     }
 
+    #[test]
+    fn account_hash_from_str_should_check_checksum() {
+        let account_hash = AccountHash([0xab; 32]);
+        let encoded = account_hash.to_checksummed_formatted_string();
+        assert_ne!(encoded, encoded.to_lowercase());
+        assert_eq!(
+            AccountHash::from_formatted_str(&encoded).unwrap(),
+            account_hash
+        );
+
+        // Single-case input carries no checksum and is accepted.
+        let lowercase = account_hash.to_formatted_string();
+        assert_eq!(lowercase, encoded.to_lowercase());
+        assert_eq!(
+            AccountHash::from_formatted_str(&lowercase).unwrap(),
+            account_hash
+        );
+
+        // Mixed-case input with a mangled checksum is rejected.
+        let position = encoded
+            .find(|character: char| character.is_ascii_uppercase())
+            .unwrap();
+        let mut mangled = encoded.clone();
+        mangled.replace_range(
+            position..position + 1,
+            &encoded[position..position + 1].to_ascii_lowercase(),
+        );
+        assert!(matches!(
+            AccountHash::from_formatted_str(&mangled),
+            Err(FromStrError::InvalidChecksum)
+        ));
+    }
+
     #[test]
     fn account_hash_serde_roundtrip() {
         let account_hash = AccountHash([255; 32]);
@@ -31,6 +31,10 @@ const HEADER_ERROR_OFFSET: u32 = (MINT_ERROR_OFFSET - 1) - u8::MAX as u32; // 64
 /// Contract header errors will have this value added to them when being converted to a `u32`.
 const AUCTION_ERROR_OFFSET: u32 = (HEADER_ERROR_OFFSET - 1) - u8::MAX as u32; // 64512..=64767
 
+/// Invalid phase errors will have the offending phase added to this value when being converted to
+/// a `u32`, giving the range 64256..=64511.
+const INVALID_PHASE_ERROR_OFFSET: u32 = (AUCTION_ERROR_OFFSET - 1) - u8::MAX as u32;
+
 /// Minimum value of user error's inclusive range.
 const USER_ERROR_MIN: u32 = RESERVED_ERROR_MAX + 1;
 
@@ -61,6 +65,12 @@ const AUCTION_ERROR_MIN: u32 = AUCTION_ERROR_OFFSET;
 /// Maximum value of an auction contract error's inclusive range.
 const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 
+/// Minimum value of an invalid phase error's inclusive range.
+const INVALID_PHASE_ERROR_MIN: u32 = INVALID_PHASE_ERROR_OFFSET;
+
+/// Maximum value of an invalid phase error's inclusive range.
+const INVALID_PHASE_ERROR_MAX: u32 = INVALID_PHASE_ERROR_OFFSET + u8::MAX as u32;
+
 /// Errors which can be encountered while running a smart contract.
 ///
 /// An `ApiError` can be converted to a `u32` in order to be passed via the execution engine's
@@ -71,7 +81,8 @@ const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 ///
 /// | Inclusive range | Variant(s)                                                      |
 /// | ----------------| ----------------------------------------------------------------|
-/// | [1, 64511]      | all except reserved system contract error ranges defined below. |
+/// | [1, 64255]      | all except reserved system contract error ranges defined below. |
+/// | [64256, 64511]  | `InvalidPhase`                                                  |
 /// | [64512, 64767]  | `Auction`                                                       |
 /// | [64768, 65023]  | `ContractHeader`                                                |
 /// | [65024, 65279]  | `Mint`                                                          |
@@ -191,6 +202,34 @@ const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 34 => HostBufferFull
 /// # );
+/// # show_and_check!(
+/// 35 => AllocLayout
+/// # );
+/// # show_and_check!(
+/// 36 => ValueTooLarge
+/// # );
+/// # show_and_check!(
+/// 37 => MaxNamedKeysExceeded
+/// # );
+/// # show_and_check!(
+/// 38 => OutOfSubCallGas
+/// # );
+/// # show_and_check!(
+/// 39 => MaxThresholdExceeded
+/// # );
+/// # show_and_check!(
+/// 40 => TransferNotRecorded
+/// # );
+///
+/// // Invalid phase errors:
+/// use casper_types::Phase;
+/// # show_and_check!(
+/// 64_257 => InvalidPhase(Phase::Payment as u8)
+/// # );
+/// # show_and_check!(
+/// 64_258 => InvalidPhase(Phase::Session as u8)
+/// # );
+///
 /// // Auction errors:
 /// use casper_types::system::auction::Error as AuctionError;
 /// # show_and_check!(
@@ -309,6 +348,12 @@ const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 64_773 => ContractHeaderError::MaxTotalURefsExceeded
 /// # );
+/// # show_and_check!(
+/// 64_778 => ContractHeaderError::MissingAccessKey
+/// # );
+/// # show_and_check!(
+/// 64_779 => ContractHeaderError::InvalidAccessKeyRights
+/// # );
 /// // Mint errors:
 /// use casper_types::system::mint::Error as MintError;
 /// # show_and_check!(
@@ -436,7 +481,8 @@ const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 /// ```
 ///
 /// Users can specify a C-style enum and implement `From` to ease usage of
-/// `casper_contract::runtime::revert()`, e.g.
+/// `casper_contract::runtime::revert()`, or, with the `derive` feature enabled, derive
+/// [`ContractError`](crate::ContractError) to generate the conversions, e.g.
 /// ```
 /// use casper_types::ApiError;
 ///
@@ -499,8 +545,8 @@ pub enum ApiError {
     LeftOverBytes,
     /// Out of memory error.
     OutOfMemory,
-    /// There are already [`MAX_ASSOCIATED_KEYS`](crate::account::MAX_ASSOCIATED_KEYS)
-    /// [`AccountHash`](crate::account::AccountHash)s associated with the given account.
+    /// There are already as many [`AccountHash`](crate::account::AccountHash)s associated with the
+    /// given account as the maximum number of associated keys configured for the network.
     MaxKeysLimit,
     /// The given [`AccountHash`](crate::account::AccountHash) is already associated with the given
     /// account.
@@ -536,6 +582,20 @@ pub enum ApiError {
     HostBufferFull,
     /// Could not lay out an array in memory
     AllocLayout,
+    /// The serialized value is larger than the maximum size allowed to be written to global state.
+    ValueTooLarge,
+    /// Adding another named key would exceed the maximum number of named keys.
+    MaxNamedKeysExceeded,
+    /// A called contract exhausted the gas sub-limit it was given by its caller.
+    OutOfSubCallGas,
+    /// The given action threshold is greater than the maximum action threshold configured for the
+    /// network.
+    MaxThresholdExceeded,
+    /// The transfer succeeded, but no record of it was created from which to issue a receipt.
+    TransferNotRecorded,
+    /// A host function was called in a phase in which it is not allowed.  The internal `u8` value
+    /// is the offending [`Phase`](crate::Phase).
+    InvalidPhase(u8),
     /// Error specific to Auction contract.
     AuctionError(u8),
     /// Contract header errors.
@@ -556,6 +616,7 @@ impl From<bytesrepr::Error> for ApiError {
             bytesrepr::Error::Formatting => ApiError::Formatting,
             bytesrepr::Error::LeftOverBytes => ApiError::LeftOverBytes,
             bytesrepr::Error::OutOfMemory => ApiError::OutOfMemory,
+            bytesrepr::Error::ExceededLimit => ApiError::OutOfMemory,
         }
     }
 }
@@ -597,6 +658,7 @@ impl From<SetThresholdFailure> for ApiError {
             SetThresholdFailure::DeploymentThreshold => ApiError::DeploymentThreshold,
             SetThresholdFailure::PermissionDeniedError => ApiError::PermissionDenied,
             SetThresholdFailure::InsufficientTotalWeight => ApiError::InsufficientTotalWeight,
+            SetThresholdFailure::MaxThresholdExceeded => ApiError::MaxThresholdExceeded,
         }
     }
 }
@@ -686,6 +748,12 @@ impl From<ApiError> for u32 {
             ApiError::HostBufferEmpty => 33,
             ApiError::HostBufferFull => 34,
             ApiError::AllocLayout => 35,
+            ApiError::ValueTooLarge => 36,
+            ApiError::MaxNamedKeysExceeded => 37,
+            ApiError::OutOfSubCallGas => 38,
+            ApiError::MaxThresholdExceeded => 39,
+            ApiError::TransferNotRecorded => 40,
+            ApiError::InvalidPhase(value) => INVALID_PHASE_ERROR_OFFSET + u32::from(value),
             ApiError::AuctionError(value) => AUCTION_ERROR_OFFSET + u32::from(value),
             ApiError::ContractHeader(value) => HEADER_ERROR_OFFSET + u32::from(value),
             ApiError::Mint(value) => MINT_ERROR_OFFSET + u32::from(value),
@@ -733,11 +801,19 @@ impl From<u32> for ApiError {
             33 => ApiError::HostBufferEmpty,
             34 => ApiError::HostBufferFull,
             35 => ApiError::AllocLayout,
+            36 => ApiError::ValueTooLarge,
+            37 => ApiError::MaxNamedKeysExceeded,
+            38 => ApiError::OutOfSubCallGas,
+            39 => ApiError::MaxThresholdExceeded,
+            40 => ApiError::TransferNotRecorded,
             USER_ERROR_MIN..=USER_ERROR_MAX => ApiError::User(value as u16),
             HP_ERROR_MIN..=HP_ERROR_MAX => ApiError::HandlePayment(value as u8),
             MINT_ERROR_MIN..=MINT_ERROR_MAX => ApiError::Mint(value as u8),
             HEADER_ERROR_MIN..=HEADER_ERROR_MAX => ApiError::ContractHeader(value as u8),
             AUCTION_ERROR_MIN..=AUCTION_ERROR_MAX => ApiError::AuctionError(value as u8),
+            INVALID_PHASE_ERROR_MIN..=INVALID_PHASE_ERROR_MAX => {
+                ApiError::InvalidPhase(value as u8)
+            }
             _ => ApiError::Unhandled,
         }
     }
@@ -783,6 +859,12 @@ impl Debug for ApiError {
             ApiError::HostBufferEmpty => write!(f, "ApiError::HostBufferEmpty")?,
             ApiError::HostBufferFull => write!(f, "ApiError::HostBufferFull")?,
             ApiError::AllocLayout => write!(f, "ApiError::AllocLayout")?,
+            ApiError::ValueTooLarge => write!(f, "ApiError::ValueTooLarge")?,
+            ApiError::MaxNamedKeysExceeded => write!(f, "ApiError::MaxNamedKeysExceeded")?,
+            ApiError::OutOfSubCallGas => write!(f, "ApiError::OutOfSubCallGas")?,
+            ApiError::MaxThresholdExceeded => write!(f, "ApiError::MaxThresholdExceeded")?,
+            ApiError::TransferNotRecorded => write!(f, "ApiError::TransferNotRecorded")?,
+            ApiError::InvalidPhase(value) => write!(f, "ApiError::InvalidPhase({})", value)?,
             ApiError::AuctionError(value) => write!(f, "ApiError::AuctionError({})", value)?,
             ApiError::ContractHeader(value) => write!(f, "ApiError::ContractHeader({})", value)?,
             ApiError::Mint(value) => write!(f, "ApiError::Mint({})", value)?,
@@ -797,6 +879,7 @@ impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ApiError::User(value) => write!(f, "User error: {}", value),
+            ApiError::InvalidPhase(value) => write!(f, "Invalid phase: {}", value),
             ApiError::ContractHeader(value) => write!(f, "Contract header error: {}", value),
             ApiError::Mint(value) => write!(f, "Mint error: {}", value),
             ApiError::HandlePayment(value) => write!(f, "Handle Payment error: {}", value),
@@ -951,6 +1034,13 @@ mod tests {
         round_trip(Err(ApiError::HostBufferEmpty));
         round_trip(Err(ApiError::HostBufferFull));
         round_trip(Err(ApiError::AllocLayout));
+        round_trip(Err(ApiError::ValueTooLarge));
+        round_trip(Err(ApiError::MaxNamedKeysExceeded));
+        round_trip(Err(ApiError::OutOfSubCallGas));
+        round_trip(Err(ApiError::MaxThresholdExceeded));
+        round_trip(Err(ApiError::TransferNotRecorded));
+        round_trip(Err(ApiError::InvalidPhase(0)));
+        round_trip(Err(ApiError::InvalidPhase(u8::MAX)));
         round_trip(Err(ApiError::ContractHeader(0)));
         round_trip(Err(ApiError::ContractHeader(u8::MAX)));
         round_trip(Err(ApiError::Mint(0)));
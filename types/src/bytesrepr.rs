@@ -1,5 +1,7 @@
 //! Contains serialization and deserialization code for types used throughout the system.
 mod bytes;
+mod length_encoding;
+mod limits;
 
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
@@ -22,6 +24,18 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub use bytes::Bytes;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use length_encoding::test_serialization_roundtrip_with_encoding;
+pub(crate) use length_encoding::write_length_prefix;
+#[cfg(feature = "std")]
+pub use length_encoding::{
+    deserialize_with_encoding, from_bytes_with_encoding, serialize_with_encoding,
+};
+pub use length_encoding::{length_prefix_serialized_length, read_length_prefix, LengthEncoding};
+#[cfg(feature = "std")]
+pub use limits::{deserialize_with_limit, from_bytes_with_limit};
+pub use limits::{DeserializationLimits, DEFAULT_MAX_ALLOCATION, DEFAULT_MAX_DEPTH};
 
 /// The number of bytes in a serialized `()`.
 pub const UNIT_SERIALIZED_LENGTH: usize = 0;
@@ -115,6 +129,9 @@ pub enum Error {
     /// Out of memory error.
     #[cfg_attr(feature = "std", error("Serialization error: out of memory"))]
     OutOfMemory,
+    /// Allocation or nesting depth limit exceeded during bounded deserialization.
+    #[cfg_attr(feature = "std", error("Deserialization error: exceeded limit"))]
+    ExceededLimit,
 }
 
 /// Deserializes `bytes` into an instance of `T`.
@@ -309,8 +326,9 @@ impl ToBytes for String {
 
 impl FromBytes for String {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (size, remainder) = u32::from_bytes(bytes)?;
+        let (size, remainder) = length_encoding::read_length_prefix(bytes)?;
         let (str_bytes, remainder) = safe_split_at(remainder, size as usize)?;
+        limits::charge_allocation(str_bytes.len())?;
         let result = String::from_utf8(str_bytes.to_vec()).map_err(|_| Error::Formatting)?;
         Ok((result, remainder))
     }
@@ -326,7 +344,10 @@ fn ensure_efficient_serialization<T>() {
 }
 
 fn iterator_serialized_length<'a, T: 'a + ToBytes>(ts: impl Iterator<Item = &'a T>) -> usize {
-    U32_SERIALIZED_LENGTH + ts.map(ToBytes::serialized_length).sum::<usize>()
+    let (count, items_length) = ts.fold((0, 0), |(count, items_length), t| {
+        (count + 1, items_length + t.serialized_length())
+    });
+    length_encoding::length_prefix_serialized_length(count) + items_length
 }
 
 impl<T: ToBytes> ToBytes for Vec<T> {
@@ -334,7 +355,7 @@ impl<T: ToBytes> ToBytes for Vec<T> {
         ensure_efficient_serialization::<T>();
 
         let mut result = try_vec_with_capacity(self.serialized_length())?;
-        result.append(&mut (self.len() as u32).to_bytes()?);
+        length_encoding::write_length_prefix(self.len(), &mut result);
 
         for item in self.iter() {
             result.append(&mut item.to_bytes()?);
@@ -347,7 +368,7 @@ impl<T: ToBytes> ToBytes for Vec<T> {
         ensure_efficient_serialization::<T>();
 
         let mut result = allocate_buffer(&self)?;
-        result.append(&mut (self.len() as u32).to_bytes()?);
+        length_encoding::write_length_prefix(self.len(), &mut result);
 
         for item in self {
             result.append(&mut item.into_bytes()?);
@@ -389,8 +410,10 @@ impl<T: FromBytes> FromBytes for Vec<T> {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
         ensure_efficient_serialization::<T>();
 
-        let (count, mut stream) = u32::from_bytes(bytes)?;
+        let (count, mut stream) = length_encoding::read_length_prefix(bytes)?;
 
+        let _guard = limits::enter_nested()?;
+        limits::charge_allocation((count as usize).saturating_mul(mem::size_of::<T>()))?;
         let mut result = try_vec_with_capacity(count as usize)?;
         for _ in 0..count {
             let (value, remainder) = T::from_bytes(stream)?;
@@ -410,7 +433,7 @@ impl<T: ToBytes> ToBytes for VecDeque<T> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let (slice1, slice2) = self.as_slices();
         let mut result = allocate_buffer(self)?;
-        result.append(&mut (self.len() as u32).to_bytes()?);
+        length_encoding::write_length_prefix(self.len(), &mut result);
         for item in slice1.iter().chain(slice2.iter()) {
             result.append(&mut item.to_bytes()?);
         }
@@ -479,8 +502,7 @@ impl<V: ToBytes> ToBytes for BTreeSet<V> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
 
-        let num_keys = self.len() as u32;
-        result.append(&mut num_keys.to_bytes()?);
+        length_encoding::write_length_prefix(self.len(), &mut result);
 
         for value in self.iter() {
             result.append(&mut value.to_bytes()?);
@@ -490,15 +512,18 @@ impl<V: ToBytes> ToBytes for BTreeSet<V> {
     }
 
     fn serialized_length(&self) -> usize {
-        U32_SERIALIZED_LENGTH + self.iter().map(|v| v.serialized_length()).sum::<usize>()
+        length_encoding::length_prefix_serialized_length(self.len())
+            + self.iter().map(|v| v.serialized_length()).sum::<usize>()
     }
 }
 
 impl<V: FromBytes + Ord> FromBytes for BTreeSet<V> {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (num_keys, mut stream) = u32::from_bytes(bytes)?;
+        let (num_keys, mut stream) = length_encoding::read_length_prefix(bytes)?;
+        let _guard = limits::enter_nested()?;
         let mut result = BTreeSet::new();
         for _ in 0..num_keys {
+            limits::charge_allocation(mem::size_of::<V>())?;
             let (v, rem) = V::from_bytes(stream)?;
             result.insert(v);
             stream = rem;
@@ -515,8 +540,7 @@ where
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
 
-        let num_keys = self.len() as u32;
-        result.append(&mut num_keys.to_bytes()?);
+        length_encoding::write_length_prefix(self.len(), &mut result);
 
         for (key, value) in self.iter() {
             result.append(&mut key.to_bytes()?);
@@ -527,7 +551,7 @@ where
     }
 
     fn serialized_length(&self) -> usize {
-        U32_SERIALIZED_LENGTH
+        length_encoding::length_prefix_serialized_length(self.len())
             + self
                 .iter()
                 .map(|(key, value)| key.serialized_length() + value.serialized_length())
@@ -541,9 +565,11 @@ where
     V: FromBytes,
 {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (num_keys, mut stream) = u32::from_bytes(bytes)?;
+        let (num_keys, mut stream) = length_encoding::read_length_prefix(bytes)?;
+        let _guard = limits::enter_nested()?;
         let mut result = BTreeMap::new();
         for _ in 0..num_keys {
+            limits::charge_allocation(mem::size_of::<K>() + mem::size_of::<V>())?;
             let (k, rem) = K::from_bytes(stream)?;
             let (v, rem) = V::from_bytes(rem)?;
             result.insert(k, v);
@@ -584,6 +610,7 @@ impl<T: FromBytes> FromBytes for Option<T> {
         match tag {
             OPTION_NONE_TAG => Ok((None, rem)),
             OPTION_SOME_TAG => {
+                let _guard = limits::enter_nested()?;
                 let (t, rem) = T::from_bytes(rem)?;
                 Ok((Some(t), rem))
             }
@@ -616,6 +643,7 @@ impl<T: ToBytes, E: ToBytes> ToBytes for Result<T, E> {
 impl<T: FromBytes, E: FromBytes> FromBytes for Result<T, E> {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
         let (variant, rem) = u8::from_bytes(bytes)?;
+        let _guard = limits::enter_nested()?;
         match variant {
             RESULT_ERR_TAG => {
                 let (value, rem) = E::from_bytes(rem)?;
@@ -1090,16 +1118,15 @@ where
 
 /// Serializes a slice of bytes with a length prefix.
 ///
-/// This function is serializing a slice of bytes with an addition of a 4 byte length prefix.
+/// This function is serializing a slice of bytes with an addition of a length prefix, which is 4
+/// bytes long unless a compact [`LengthEncoding`] is in use.
 ///
 /// For safety you should prefer to use [`vec_u8_to_bytes`]. For efficiency reasons you should also
 /// avoid using serializing Vec<u8>.
 fn u8_slice_to_bytes(bytes: &[u8]) -> Result<Vec<u8>, Error> {
     let serialized_length = u8_slice_serialized_length(bytes);
     let mut vec = try_vec_with_capacity(serialized_length)?;
-    let length_prefix = bytes.len() as u32;
-    let length_prefix_bytes = length_prefix.to_le_bytes();
-    vec.extend_from_slice(&length_prefix_bytes);
+    length_encoding::write_length_prefix(bytes.len(), &mut vec);
     vec.extend_from_slice(bytes);
     Ok(vec)
 }
@@ -1118,7 +1145,7 @@ pub(crate) fn vec_u8_to_bytes(vec: &Vec<u8>) -> Result<Vec<u8>, Error> {
 /// This function adds a length prefix in the beggining.
 #[inline(always)]
 fn u8_slice_serialized_length(bytes: &[u8]) -> usize {
-    U32_SERIALIZED_LENGTH + bytes.len()
+    length_encoding::length_prefix_serialized_length(bytes.len()) + bytes.len()
 }
 
 #[allow(clippy::ptr_arg)]
@@ -1172,6 +1199,42 @@ mod tests {
         let bytes = b"0123456789".to_vec();
         bytes.to_bytes().unwrap();
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_not_exceed_allocation_limit() {
+        let mut malicious_bytes = u32::max_value().to_bytes().unwrap();
+        malicious_bytes.extend_from_slice(&[0; 8]);
+        let limits = DeserializationLimits::new(1024, DEFAULT_MAX_DEPTH);
+        let result: Result<Vec<u64>, Error> = deserialize_with_limit(malicious_bytes, limits);
+        assert_eq!(result.unwrap_err(), Error::ExceededLimit);
+
+        let bytes = vec![String::from("casper"); 16].to_bytes().unwrap();
+        let limits = DeserializationLimits::new(64, DEFAULT_MAX_DEPTH);
+        let result: Result<Vec<String>, Error> = deserialize_with_limit(bytes.clone(), limits);
+        assert_eq!(result.unwrap_err(), Error::ExceededLimit);
+
+        let result: Result<Vec<String>, Error> =
+            deserialize_with_limit(bytes, DeserializationLimits::default());
+        assert_eq!(result.unwrap(), vec![String::from("casper"); 16]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_not_exceed_depth_limit() {
+        let nested: Option<Option<Option<u8>>> = Some(Some(Some(1)));
+        let bytes = nested.to_bytes().unwrap();
+
+        let limits = DeserializationLimits::new(DEFAULT_MAX_ALLOCATION, 2);
+        let result: Result<Option<Option<Option<u8>>>, Error> =
+            deserialize_with_limit(bytes.clone(), limits);
+        assert_eq!(result.unwrap_err(), Error::ExceededLimit);
+
+        let limits = DeserializationLimits::new(DEFAULT_MAX_ALLOCATION, 3);
+        let result: Result<Option<Option<Option<u8>>>, Error> =
+            deserialize_with_limit(bytes, limits);
+        assert_eq!(result.unwrap(), nested);
+    }
 }
 
 #[cfg(test)]
@@ -1181,7 +1244,7 @@ mod proptests {
     use proptest::{collection::vec, prelude::*};
 
     use crate::{
-        bytesrepr::{self, bytes::gens::bytes_arb, ToBytes},
+        bytesrepr::{self, bytes::gens::bytes_arb, LengthEncoding, ToBytes},
         gens::*,
     };
 
@@ -1392,4 +1455,79 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&t);
         }
     }
+
+    proptest! {
+        #[test]
+        fn test_vec_u8_leb128(u in bytes_arb(0..300)) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&u, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_vec_i32_leb128(u in vec(any::<i32>(), 0..300)) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&u, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_vecdeque_i32_leb128((front, back) in (vec(any::<i32>(), 0..100), vec(any::<i32>(), 0..100))) {
+            let mut vec_deque = VecDeque::new();
+            for f in front {
+                vec_deque.push_front(f);
+            }
+            for f in back {
+                vec_deque.push_back(f);
+            }
+            bytesrepr::test_serialization_roundtrip_with_encoding(&vec_deque, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_vec_vec_u8_leb128(u in vec(bytes_arb(0..200), 0..10)) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&u, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_uref_map_leb128(m in proptest::collection::btree_map("[a-z_]{1,32}", key_arb(), 128..200)) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&m, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_btree_set_leb128(s in proptest::collection::btree_set(any::<u64>(), 0..200)) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&s, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_string_leb128(s in "\\PC*") {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&s, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_option_leb128(o in proptest::option::of(vec(key_arb(), 0..10))) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&o, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_cl_value_leb128(cl_value in cl_value_arb()) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&cl_value, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_contract_leb128(contract in contract_arb()) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&contract, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_contract_package_leb128(contract_package in contract_package_arb()) {
+            bytesrepr::test_serialization_roundtrip_with_encoding(&contract_package, LengthEncoding::Leb128);
+        }
+
+        #[test]
+        fn test_encodings_agree(u in vec(bytes_arb(0..200), 0..10)) {
+            let compact = bytesrepr::serialize_with_encoding(&u, LengthEncoding::Leb128).unwrap();
+            let fixed = bytesrepr::serialize_with_encoding(&u, LengthEncoding::U32).unwrap();
+            prop_assert!(compact.len() <= fixed.len());
+            prop_assert_eq!(fixed, u.to_bytes().unwrap());
+            let decoded: Vec<bytesrepr::Bytes> =
+                bytesrepr::deserialize_with_encoding(compact, LengthEncoding::Leb128).unwrap();
+            prop_assert_eq!(decoded, u);
+        }
+    }
 }
@@ -1,9 +1,13 @@
 use alloc::{
+    borrow::Cow,
     string::String,
+    sync::Arc,
     vec::{IntoIter, Vec},
 };
 use core::{
-    cmp, fmt,
+    cmp::{self, Ordering},
+    fmt,
+    hash::{Hash, Hasher},
     iter::FromIterator,
     mem,
     ops::{Deref, Index, Range, RangeFrom, RangeFull, RangeTo},
@@ -24,8 +28,11 @@ use super::{Error, FromBytes, ToBytes};
 use crate::{CLType, CLTyped};
 
 /// A newtype wrapper for bytes that has efficient serialization routines.
-#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Default, Hash)]
-pub struct Bytes(Vec<u8>);
+///
+/// The bytes are held in a reference-counted buffer, so cloning a `Bytes` never copies the
+/// underlying data, and deserializing one from an owned `Vec<u8>` reuses its allocation.
+#[derive(Clone)]
+pub struct Bytes(Arc<Vec<u8>>);
 
 impl Bytes {
     /// Constructs a new, empty vector of bytes.
@@ -43,31 +50,80 @@ impl Bytes {
     pub fn as_slice(&self) -> &[u8] {
         self
     }
+
+    /// Returns the bytes as a [`Cow`], borrowing rather than copying them.
+    pub fn as_cow(&self) -> Cow<[u8]> {
+        Cow::Borrowed(self.as_slice())
+    }
+}
+
+impl Default for Bytes {
+    fn default() -> Self {
+        Bytes::from(Vec::new())
+    }
 }
 
 impl Deref for Bytes {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
-        self.0.deref()
+        self.inner_bytes()
+    }
+}
+
+impl PartialEq for Bytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for Bytes {}
+
+impl PartialOrd for Bytes {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bytes {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl Hash for Bytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Bytes").field(&self.as_slice()).finish()
     }
 }
 
 impl From<Vec<u8>> for Bytes {
     fn from(vec: Vec<u8>) -> Self {
-        Self(vec)
+        Bytes(Arc::new(vec))
     }
 }
 
 impl From<Bytes> for Vec<u8> {
     fn from(bytes: Bytes) -> Self {
-        bytes.0
+        Arc::try_unwrap(bytes.0).unwrap_or_else(|buffer| buffer.as_ref().clone())
     }
 }
 
 impl From<&[u8]> for Bytes {
     fn from(bytes: &[u8]) -> Self {
-        Self(bytes.to_vec())
+        Bytes::from(bytes.to_vec())
+    }
+}
+
+impl<'a> From<Cow<'a, [u8]>> for Bytes {
+    fn from(bytes: Cow<'a, [u8]>) -> Self {
+        Bytes::from(bytes.into_owned())
     }
 }
 
@@ -79,43 +135,53 @@ impl CLTyped for Bytes {
 
 impl AsRef<[u8]> for Bytes {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.inner_bytes()
     }
 }
 
 impl ToBytes for Bytes {
     #[inline(always)]
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        super::vec_u8_to_bytes(&self.0)
+        super::vec_u8_to_bytes(self.inner_bytes())
     }
 
     #[inline(always)]
     fn into_bytes(self) -> Result<Vec<u8>, Error> {
-        super::vec_u8_to_bytes(&self.0)
+        super::vec_u8_to_bytes(self.inner_bytes())
     }
 
     #[inline(always)]
     fn serialized_length(&self) -> usize {
-        super::vec_u8_serialized_length(&self.0)
+        super::vec_u8_serialized_length(self.inner_bytes())
     }
 }
 
 impl FromBytes for Bytes {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), super::Error> {
-        let (size, remainder) = u32::from_bytes(bytes)?;
+        let (size, remainder) = super::length_encoding::read_length_prefix(bytes)?;
         let (result, remainder) = super::safe_split_at(remainder, size as usize)?;
-        Ok((Bytes(result.to_vec()), remainder))
-    }
-
-    fn from_vec(stream: Vec<u8>) -> Result<(Self, Vec<u8>), Error> {
-        let (size, mut stream) = u32::from_vec(stream)?;
-
-        if size as usize > stream.len() {
-            Err(Error::EarlyEndOfStream)
-        } else {
-            let remainder = stream.split_off(size as usize);
-            Ok((Bytes(stream), remainder))
+        super::limits::charge_allocation(result.len())?;
+        Ok((Bytes::from(result), remainder))
+    }
+
+    fn from_vec(mut stream: Vec<u8>) -> Result<(Self, Vec<u8>), Error> {
+        let (size, remainder) = super::length_encoding::read_length_prefix(&stream)?;
+        let start = stream.len() - remainder.len();
+        let end = start
+            .checked_add(size as usize)
+            .filter(|end| *end <= stream.len())
+            .ok_or(Error::EarlyEndOfStream)?;
+        super::limits::charge_allocation(size as usize)?;
+
+        // Reuse the stream's allocation for whichever of the bytes and the remainder is larger.
+        if stream.len() - end > end - start {
+            let bytes = Bytes::from(&stream[start..end]);
+            stream.drain(..end);
+            return Ok((bytes, stream));
         }
+        let remainder = stream.split_off(end);
+        stream.drain(..start);
+        Ok((Bytes::from(stream), remainder))
     }
 }
 
@@ -123,8 +189,7 @@ impl Index<usize> for Bytes {
     type Output = u8;
 
     fn index(&self, index: usize) -> &u8 {
-        let Bytes(ref dat) = self;
-        &dat[index]
+        &self.inner_bytes()[index]
     }
 }
 
@@ -132,8 +197,7 @@ impl Index<Range<usize>> for Bytes {
     type Output = [u8];
 
     fn index(&self, index: Range<usize>) -> &[u8] {
-        let &Bytes(ref dat) = self;
-        &dat[index]
+        &self.inner_bytes()[index]
     }
 }
 
@@ -141,8 +205,7 @@ impl Index<RangeTo<usize>> for Bytes {
     type Output = [u8];
 
     fn index(&self, index: RangeTo<usize>) -> &[u8] {
-        let &Bytes(ref dat) = self;
-        &dat[index]
+        &self.inner_bytes()[index]
     }
 }
 
@@ -150,8 +213,7 @@ impl Index<RangeFrom<usize>> for Bytes {
     type Output = [u8];
 
     fn index(&self, index: RangeFrom<usize>) -> &[u8] {
-        let &Bytes(ref dat) = self;
-        &dat[index]
+        &self.inner_bytes()[index]
     }
 }
 
@@ -159,8 +221,7 @@ impl Index<RangeFull> for Bytes {
     type Output = [u8];
 
     fn index(&self, _: RangeFull) -> &[u8] {
-        let &Bytes(ref dat) = self;
-        &dat[..]
+        self.inner_bytes()
     }
 }
 
@@ -168,7 +229,7 @@ impl FromIterator<u8> for Bytes {
     #[inline]
     fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Bytes {
         let vec = Vec::from_iter(iter);
-        Bytes(vec)
+        Bytes::from(vec)
     }
 }
 
@@ -178,7 +239,7 @@ impl<'a> IntoIterator for &'a Bytes {
     type IntoIter = slice::Iter<'a, u8>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.inner_bytes().iter()
     }
 }
 
@@ -188,7 +249,7 @@ impl IntoIterator for Bytes {
     type IntoIter = IntoIter<u8>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        Vec::from(self).into_iter()
     }
 }
 
@@ -275,7 +336,7 @@ impl<'de> Deserialize<'de> for Bytes {
         if deserializer.is_human_readable() {
             let hex_string = String::deserialize(deserializer)?;
             base16::decode(&hex_string)
-                .map(Bytes)
+                .map(Bytes::from)
                 .map_err(SerdeError::custom)
         } else {
             let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
@@ -290,9 +351,9 @@ impl Serialize for Bytes {
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            base16::encode_lower(&self.0).serialize(serializer)
+            base16::encode_lower(self.inner_bytes()).serialize(serializer)
         } else {
-            serializer.serialize_bytes(&self.0)
+            serializer.serialize_bytes(self.inner_bytes())
         }
     }
 }
@@ -369,6 +430,50 @@ mod tests {
         let truth: Bytes = TRUTH.into();
         assert_tokens(&truth.compact(), &[Token::Bytes(TRUTH)]);
     }
+
+    #[test]
+    fn should_deserialize_from_vec_without_reallocating() {
+        let data: Bytes = vec![1, 2, 3, 4, 5].into();
+        let serialized = data.to_bytes().expect("should serialize data");
+        let buffer_ptr = serialized.as_ptr();
+
+        let (deserialized, rem) = Bytes::from_vec(serialized).expect("should deserialize data");
+        assert_eq!(deserialized, data);
+        assert!(rem.is_empty());
+        assert_eq!(deserialized.as_ptr(), buffer_ptr);
+    }
+
+    #[test]
+    fn should_deserialize_from_vec_and_keep_rem() {
+        let data: Bytes = vec![1, 2, 3, 4, 5].into();
+        let expected_rem: Vec<u8> = vec![6, 7];
+        let mut serialized = data.to_bytes().expect("should serialize data");
+        serialized.extend(&expected_rem);
+
+        let (deserialized, rem) = Bytes::from_vec(serialized).expect("should deserialize data");
+        assert_eq!(deserialized, data);
+        assert_eq!(rem, expected_rem);
+
+        let truncated = data.to_bytes().expect("should serialize data")[..6].to_vec();
+        assert_eq!(
+            Bytes::from_vec(truncated).unwrap_err(),
+            Error::EarlyEndOfStream
+        );
+    }
+
+    #[test]
+    fn should_share_buffer_when_cloning() {
+        let data: Bytes = vec![1, 2, 3, 4, 5].into();
+
+        let cloned = data.clone();
+        assert_eq!(cloned.as_ptr(), data.as_ptr());
+
+        assert_eq!(Vec::from(cloned), vec![1, 2, 3, 4, 5]);
+        let data_ptr = data.as_ptr();
+        let vec = Vec::from(data);
+        assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+        assert_eq!(vec.as_ptr(), data_ptr);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,315 @@
+//! Support for alternative encodings of collection length prefixes.
+//!
+//! By default, every `String`, `Vec`, `VecDeque`, `BTreeSet`, `BTreeMap` and
+//! [`Bytes`](super::Bytes) is prefixed with its length as a little-endian `u32`.  When serializing
+//! via [`serialize_with_encoding`] or deserializing via [`deserialize_with_encoding`] with
+//! [`LengthEncoding::Leb128`], those length prefixes are instead written as unsigned LEB128
+//! varints, which take a single byte for collections of fewer than 128 elements.
+//!
+//! Only minimal LEB128 encodings are accepted, so each value still has exactly one serialized
+//! form under either encoding.
+//!
+//! The encoding is tracked per thread, so it is only selectable with the `std` feature enabled.
+//! Without it, lengths are always encoded as `u32`s.
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+use alloc::vec::Vec;
+
+use super::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH};
+
+const LENGTH_ENCODING_U32_TAG: u8 = 0;
+const LENGTH_ENCODING_LEB128_TAG: u8 = 1;
+
+/// The maximum number of bytes in a LEB128-encoded `u32`.
+const MAX_LEB128_LENGTH: usize = 5;
+const LEB128_PAYLOAD_MASK: u8 = 0x7f;
+const LEB128_CONTINUATION_BIT: u8 = 0x80;
+
+/// The encoding used for the length prefixes of serialized collections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LengthEncoding {
+    /// Lengths are encoded as little-endian `u32`s.
+    U32,
+    /// Lengths are encoded as unsigned LEB128 varints.
+    Leb128,
+}
+
+impl Default for LengthEncoding {
+    fn default() -> Self {
+        LengthEncoding::U32
+    }
+}
+
+impl ToBytes for LengthEncoding {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let tag = match self {
+            LengthEncoding::U32 => LENGTH_ENCODING_U32_TAG,
+            LengthEncoding::Leb128 => LENGTH_ENCODING_LEB128_TAG,
+        };
+        tag.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for LengthEncoding {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            LENGTH_ENCODING_U32_TAG => Ok((LengthEncoding::U32, remainder)),
+            LENGTH_ENCODING_LEB128_TAG => Ok((LengthEncoding::Leb128, remainder)),
+            _ => Err(Error::Formatting),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static CURRENT: Cell<LengthEncoding> = Cell::new(LengthEncoding::U32);
+}
+
+/// Restores the previously current encoding when dropped, including while unwinding.
+#[cfg(feature = "std")]
+struct RestoreEncoding(LengthEncoding);
+
+#[cfg(feature = "std")]
+impl Drop for RestoreEncoding {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.0));
+    }
+}
+
+/// Calls `f` with length prefixes encoded using `encoding`, restoring the previous encoding
+/// afterwards.
+#[cfg(feature = "std")]
+fn with_encoding<R>(encoding: LengthEncoding, f: impl FnOnce() -> R) -> R {
+    let _restore = RestoreEncoding(CURRENT.with(|current| current.replace(encoding)));
+    f()
+}
+
+/// Serializes `t` into a `Vec<u8>`, encoding all length prefixes using `encoding`.
+#[cfg(feature = "std")]
+pub fn serialize_with_encoding<T: ToBytes + ?Sized>(
+    t: &T,
+    encoding: LengthEncoding,
+) -> Result<Vec<u8>, Error> {
+    with_encoding(encoding, || t.to_bytes())
+}
+
+/// Deserializes the slice into `T`, decoding all length prefixes using `encoding`.
+#[cfg(feature = "std")]
+pub fn from_bytes_with_encoding<T: FromBytes>(
+    bytes: &[u8],
+    encoding: LengthEncoding,
+) -> Result<(T, &[u8]), Error> {
+    with_encoding(encoding, || T::from_bytes(bytes))
+}
+
+/// Deserializes `bytes` into an instance of `T`, decoding all length prefixes using `encoding`.
+///
+/// Returns an error if the bytes cannot be deserialized into `T` or if not all of the input bytes
+/// are consumed in the operation.
+#[cfg(feature = "std")]
+pub fn deserialize_with_encoding<T: FromBytes>(
+    bytes: Vec<u8>,
+    encoding: LengthEncoding,
+) -> Result<T, Error> {
+    let (t, remainder) = from_bytes_with_encoding(&bytes, encoding)?;
+    if remainder.is_empty() {
+        Ok(t)
+    } else {
+        Err(Error::LeftOverBytes)
+    }
+}
+
+#[cfg(feature = "std")]
+fn current() -> LengthEncoding {
+    CURRENT.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+fn current() -> LengthEncoding {
+    LengthEncoding::U32
+}
+
+/// Returns the number of bytes taken by the prefix for a collection of `length` elements under
+/// the current encoding.
+pub fn length_prefix_serialized_length(length: usize) -> usize {
+    match current() {
+        LengthEncoding::U32 => U32_SERIALIZED_LENGTH,
+        LengthEncoding::Leb128 => {
+            let significant_bits = 32 - (length as u32 | 1).leading_zeros() as usize;
+            (significant_bits + 6) / 7
+        }
+    }
+}
+
+/// Appends the prefix for a collection of `length` elements to `buffer` under the current
+/// encoding.
+pub(crate) fn write_length_prefix(length: usize, buffer: &mut Vec<u8>) {
+    let length = length as u32;
+    match current() {
+        LengthEncoding::U32 => buffer.extend_from_slice(&length.to_le_bytes()),
+        LengthEncoding::Leb128 => {
+            let mut remaining = length;
+            loop {
+                let byte = remaining as u8 & LEB128_PAYLOAD_MASK;
+                remaining >>= 7;
+                if remaining == 0 {
+                    buffer.push(byte);
+                    return;
+                }
+                buffer.push(byte | LEB128_CONTINUATION_BIT);
+            }
+        }
+    }
+}
+
+/// Reads a collection length prefix from the start of `bytes` under the current encoding.
+pub fn read_length_prefix(bytes: &[u8]) -> Result<(u32, &[u8]), Error> {
+    match current() {
+        LengthEncoding::U32 => u32::from_bytes(bytes),
+        LengthEncoding::Leb128 => read_leb128(bytes),
+    }
+}
+
+fn read_leb128(bytes: &[u8]) -> Result<(u32, &[u8]), Error> {
+    let mut length = 0u32;
+    for (index, byte) in bytes.iter().take(MAX_LEB128_LENGTH).enumerate() {
+        let payload = byte & LEB128_PAYLOAD_MASK;
+        // The final byte may only carry the four most significant bits of a `u32`.
+        if index == MAX_LEB128_LENGTH - 1 && payload > 0x0f {
+            return Err(Error::Formatting);
+        }
+        length |= u32::from(payload) << (7 * index);
+        if byte & LEB128_CONTINUATION_BIT == 0 {
+            // A trailing zero byte would make the encoding non-minimal.
+            if index > 0 && *byte == 0 {
+                return Err(Error::Formatting);
+            }
+            return Ok((length, &bytes[index + 1..]));
+        }
+    }
+    if bytes.len() < MAX_LEB128_LENGTH {
+        Err(Error::EarlyEndOfStream)
+    } else {
+        Err(Error::Formatting)
+    }
+}
+
+// This test helper is not intended to be used by third party crates.
+#[doc(hidden)]
+/// Asserts that we can serialize and then deserialize a value with its length prefixes encoded
+/// using `encoding`.
+#[cfg(feature = "std")]
+pub fn test_serialization_roundtrip_with_encoding<T>(t: &T, encoding: LengthEncoding)
+where
+    T: alloc::fmt::Debug + ToBytes + FromBytes + PartialEq,
+{
+    with_encoding(encoding, || super::test_serialization_roundtrip(t))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{string::String, vec};
+
+    use super::*;
+
+    #[test]
+    fn should_encode_boundary_lengths_as_minimal_leb128() {
+        let cases: [(usize, &[u8]); 6] = [
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (127, &[0x7f]),
+            (128, &[0x80, 0x01]),
+            (16_384, &[0x80, 0x80, 0x01]),
+            (u32::max_value() as usize, &[0xff, 0xff, 0xff, 0xff, 0x0f]),
+        ];
+        with_encoding(LengthEncoding::Leb128, || {
+            for (length, expected) in cases.iter() {
+                let mut buffer = Vec::new();
+                write_length_prefix(*length, &mut buffer);
+                assert_eq!(buffer.as_slice(), *expected);
+                assert_eq!(length_prefix_serialized_length(*length), expected.len());
+
+                buffer.push(42);
+                let (decoded, remainder) = read_length_prefix(&buffer).unwrap();
+                assert_eq!(decoded as usize, *length);
+                assert_eq!(remainder, &[42]);
+            }
+        })
+    }
+
+    #[test]
+    fn should_reject_malformed_leb128() {
+        with_encoding(LengthEncoding::Leb128, || {
+            // Non-minimal encodings of zero and of one.
+            assert_eq!(read_length_prefix(&[0x80, 0x00]), Err(Error::Formatting));
+            assert_eq!(
+                read_length_prefix(&[0x81, 0x80, 0x00]),
+                Err(Error::Formatting)
+            );
+            // Overflows a `u32`.
+            assert_eq!(
+                read_length_prefix(&[0xff, 0xff, 0xff, 0xff, 0x10]),
+                Err(Error::Formatting)
+            );
+            assert_eq!(
+                read_length_prefix(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+                Err(Error::Formatting)
+            );
+            // Truncated.
+            assert_eq!(read_length_prefix(&[]), Err(Error::EarlyEndOfStream));
+            assert_eq!(read_length_prefix(&[0x80]), Err(Error::EarlyEndOfStream));
+        })
+    }
+
+    #[test]
+    fn should_serialize_small_collections_more_compactly() {
+        let value = vec![String::from("casper"); 3];
+
+        let fixed = serialize_with_encoding(&value, LengthEncoding::U32).unwrap();
+        let compact = serialize_with_encoding(&value, LengthEncoding::Leb128).unwrap();
+        assert_eq!(fixed.len(), compact.len() + 4 * (U32_SERIALIZED_LENGTH - 1));
+
+        let decoded: Vec<String> =
+            deserialize_with_encoding(compact, LengthEncoding::Leb128).unwrap();
+        assert_eq!(decoded, value);
+        let decoded: Vec<String> = deserialize_with_encoding(fixed, LengthEncoding::U32).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn should_restore_previous_encoding() {
+        with_encoding(LengthEncoding::Leb128, || {
+            let fixed = serialize_with_encoding(&vec![1u64], LengthEncoding::U32).unwrap();
+            assert_eq!(fixed.len(), U32_SERIALIZED_LENGTH + 8);
+            assert_eq!(current(), LengthEncoding::Leb128);
+        });
+        assert_eq!(current(), LengthEncoding::U32);
+    }
+
+    #[test]
+    fn should_restore_previous_encoding_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_encoding(LengthEncoding::Leb128, || {
+                panic!("deserialization panicked")
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(current(), LengthEncoding::U32);
+    }
+
+    #[test]
+    fn should_serialize_length_encoding() {
+        for encoding in [LengthEncoding::U32, LengthEncoding::Leb128].iter() {
+            super::super::test_serialization_roundtrip(encoding);
+        }
+        assert_eq!(LengthEncoding::from_bytes(&[2]), Err(Error::Formatting));
+    }
+}
@@ -0,0 +1,147 @@
+//! Support for bounded deserialization.
+//!
+//! When deserializing via [`from_bytes_with_limit`] or [`deserialize_with_limit`], every
+//! allocation made by the [`FromBytes`] implementations in this module is charged against a budget
+//! and every nested collection, `Option` or `Result` consumes one level of the allowed nesting
+//! depth.  Exceeding either limit aborts deserialization with an error rather than attempting the
+//! allocation.
+//!
+//! The budget is tracked per thread, so it is only available with the `std` feature enabled.
+//! Without it, the charging functions are no-ops.
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+use super::Error;
+#[cfg(feature = "std")]
+use super::FromBytes;
+
+/// The default maximum number of bytes which may be allocated during a single bounded
+/// deserialization.
+pub const DEFAULT_MAX_ALLOCATION: usize = 64 * 1024 * 1024;
+/// The default maximum nesting depth of a single bounded deserialization.
+pub const DEFAULT_MAX_DEPTH: u32 = 64;
+
+/// Limits applied during a bounded deserialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeserializationLimits {
+    /// The maximum total number of bytes which may be allocated.
+    pub max_allocation: usize,
+    /// The maximum nesting depth of collections, `Option`s and `Result`s.
+    pub max_depth: u32,
+}
+
+impl DeserializationLimits {
+    /// Constructs new `DeserializationLimits`.
+    pub const fn new(max_allocation: usize, max_depth: u32) -> Self {
+        DeserializationLimits {
+            max_allocation,
+            max_depth,
+        }
+    }
+}
+
+impl Default for DeserializationLimits {
+    fn default() -> Self {
+        DeserializationLimits::new(DEFAULT_MAX_ALLOCATION, DEFAULT_MAX_DEPTH)
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static REMAINING: Cell<Option<DeserializationLimits>> = Cell::new(None);
+}
+
+/// Deserializes the slice into `T`, failing if doing so would exceed the given `limits`.
+#[cfg(feature = "std")]
+pub fn from_bytes_with_limit<T: FromBytes>(
+    bytes: &[u8],
+    limits: DeserializationLimits,
+) -> Result<(T, &[u8]), Error> {
+    let previous = REMAINING.with(|remaining| remaining.replace(Some(limits)));
+    let result = T::from_bytes(bytes);
+    REMAINING.with(|remaining| remaining.set(previous));
+    result
+}
+
+/// Deserializes `bytes` into an instance of `T`, failing if doing so would exceed the given
+/// `limits`.
+///
+/// Returns an error if the bytes cannot be deserialized into `T` or if not all of the input bytes
+/// are consumed in the operation.
+#[cfg(feature = "std")]
+pub fn deserialize_with_limit<T: FromBytes>(
+    bytes: Vec<u8>,
+    limits: DeserializationLimits,
+) -> Result<T, Error> {
+    let (t, remainder) = from_bytes_with_limit(&bytes, limits)?;
+    if remainder.is_empty() {
+        Ok(t)
+    } else {
+        Err(Error::LeftOverBytes)
+    }
+}
+
+/// Charges `size` bytes against the allocation budget of the current bounded deserialization, if
+/// any.
+#[cfg(feature = "std")]
+pub(super) fn charge_allocation(size: usize) -> Result<(), Error> {
+    REMAINING.with(|remaining| match remaining.get() {
+        None => Ok(()),
+        Some(mut limits) => {
+            limits.max_allocation = limits
+                .max_allocation
+                .checked_sub(size)
+                .ok_or(Error::ExceededLimit)?;
+            remaining.set(Some(limits));
+            Ok(())
+        }
+    })
+}
+
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+pub(super) fn charge_allocation(_size: usize) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Guard representing one level of nesting; restores the level when dropped.
+pub(super) struct DepthGuard(());
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        REMAINING.with(|remaining| {
+            if let Some(mut limits) = remaining.get() {
+                limits.max_depth += 1;
+                remaining.set(Some(limits));
+            }
+        })
+    }
+}
+
+/// Consumes one level of the allowed nesting depth of the current bounded deserialization, if
+/// any.
+#[cfg(feature = "std")]
+pub(super) fn enter_nested() -> Result<DepthGuard, Error> {
+    REMAINING.with(|remaining| match remaining.get() {
+        None => Ok(DepthGuard(())),
+        Some(mut limits) => {
+            limits.max_depth = limits
+                .max_depth
+                .checked_sub(1)
+                .ok_or(Error::ExceededLimit)?;
+            remaining.set(Some(limits));
+            Ok(DepthGuard(()))
+        }
+    })
+}
+
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+pub(super) fn enter_nested() -> Result<DepthGuard, Error> {
+    Ok(DepthGuard(()))
+}
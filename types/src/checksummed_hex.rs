@@ -0,0 +1,148 @@
+//! Checksummed hex encoding, protecting hex strings against copy-paste and typing errors.
+//!
+//! The encoding is similar to [EIP-55](https://eips.ethereum.org/EIPS/eip-55): the letters of the
+//! lowercase hex encoding are uppercased or left lowercase depending on the corresponding bit of
+//! the blake2b-256 hash of the encoded bytes.  A single altered character will almost certainly
+//! cause the case pattern to no longer match the checksum.
+//!
+//! Hex strings which are entirely lowercase or entirely uppercase carry no checksum, and are
+//! accepted by [`decode`] for compatibility with input produced before checksums were introduced.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+use crate::hashing;
+
+/// Error returned when decoding a checksummed hex string.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The input is not valid hex.
+    Hex(base16::DecodeError),
+    /// The input is mixed-case, but its case pattern doesn't match the checksum.
+    InvalidChecksum,
+}
+
+impl From<base16::DecodeError> for DecodeError {
+    fn from(error: base16::DecodeError) -> Self {
+        DecodeError::Hex(error)
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DecodeError::Hex(error) => write!(f, "{}", error),
+            DecodeError::InvalidChecksum => write!(f, "checksum mismatch"),
+        }
+    }
+}
+
+/// Returns an iterator over the bits of the checksum of `input`, most significant bit first,
+/// cycling if `input` encodes to more hex characters than there are checksum bits.
+fn checksum_bits(input: &[u8]) -> impl Iterator<Item = bool> {
+    let hash = hashing::blake2b256(input);
+    (0..hash.len() * 8)
+        .cycle()
+        .map(move |index| (hash[index / 8] >> (7 - index % 8)) & 1 == 1)
+}
+
+/// Encodes `input` as hex, with the case of each letter determined by the checksum of `input`.
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    let input = input.as_ref();
+    base16::encode_lower(input)
+        .chars()
+        .zip(checksum_bits(input))
+        .map(|(character, bit)| {
+            if bit {
+                character.to_ascii_uppercase()
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+/// Returns `true` if `input` is exactly the checksummed hex encoding of the bytes it represents.
+///
+/// Unlike [`decode`], this rejects single-case input which would be encoded as mixed-case.
+pub fn validate(input: &str) -> bool {
+    match base16::decode(input) {
+        Ok(bytes) => encode(&bytes) == input,
+        Err(_) => false,
+    }
+}
+
+/// Decodes a hex string, verifying its checksum if it is mixed-case.
+///
+/// Entirely lowercase or entirely uppercase input is accepted without a checksum.
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    let input = input.as_ref();
+    let bytes = base16::decode(input)?;
+    let has_lowercase = input.iter().any(u8::is_ascii_lowercase);
+    let has_uppercase = input.iter().any(u8::is_ascii_uppercase);
+    if has_lowercase && has_uppercase && encode(&bytes).as_bytes() != input {
+        return Err(DecodeError::InvalidChecksum);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_roundtrip() {
+        for length in 0..100 {
+            let input: Vec<u8> = (0..length).map(|index| (index * 37) as u8).collect();
+            let encoded = encode(&input);
+            assert!(validate(&encoded));
+            assert_eq!(decode(&encoded).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn should_be_mixed_case() {
+        let encoded = encode([0xab; 32]);
+        assert!(encoded
+            .chars()
+            .any(|character| character.is_ascii_lowercase()));
+        assert!(encoded
+            .chars()
+            .any(|character| character.is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn should_accept_single_case_input() {
+        let input = [0xab; 32];
+        let lowercase = base16::encode_lower(&input);
+        let uppercase = base16::encode_upper(&input);
+        assert_eq!(decode(&lowercase).unwrap(), input);
+        assert_eq!(decode(&uppercase).unwrap(), input);
+        assert!(!validate(&lowercase));
+        assert!(!validate(&uppercase));
+    }
+
+    #[test]
+    fn should_reject_altered_case() {
+        let encoded = encode([0xab; 32]);
+        let position = encoded
+            .find(|character: char| character.is_ascii_uppercase())
+            .unwrap();
+        let mut altered = encoded.clone();
+        altered.replace_range(
+            position..position + 1,
+            &encoded[position..position + 1].to_ascii_lowercase(),
+        );
+        assert!(!validate(&altered));
+        assert!(matches!(
+            decode(&altered),
+            Err(DecodeError::InvalidChecksum)
+        ));
+    }
+
+    #[test]
+    fn should_reject_invalid_hex() {
+        assert!(!validate("0g"));
+        assert!(matches!(decode("0g"), Err(DecodeError::Hex(_))));
+    }
+}
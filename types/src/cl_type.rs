@@ -100,6 +100,9 @@ pub enum CLType {
     /// 3-ary tuple of `CLType`s.
     Tuple3([Box<CLType>; 3]),
     /// Unspecified type.
+    ///
+    /// Deprecated in new entry point declarations: values whose type is only known at runtime
+    /// should be passed as a [`DynamicValue`](crate::DynamicValue) instead.
     Any,
 }
 
@@ -134,6 +137,36 @@ impl CLType {
     }
 }
 
+impl CLType {
+    /// Returns `true` if this type is or contains [`CLType::Any`].
+    pub fn contains_any(&self) -> bool {
+        match self {
+            CLType::Any => true,
+            CLType::Option(cl_type) | CLType::List(cl_type) => cl_type.contains_any(),
+            CLType::Result { ok, err } => ok.contains_any() || err.contains_any(),
+            CLType::Map { key, value } => key.contains_any() || value.contains_any(),
+            CLType::Tuple1(cl_types) => cl_types.iter().any(|cl_type| cl_type.contains_any()),
+            CLType::Tuple2(cl_types) => cl_types.iter().any(|cl_type| cl_type.contains_any()),
+            CLType::Tuple3(cl_types) => cl_types.iter().any(|cl_type| cl_type.contains_any()),
+            CLType::Bool
+            | CLType::I32
+            | CLType::I64
+            | CLType::U8
+            | CLType::U32
+            | CLType::U64
+            | CLType::U128
+            | CLType::U256
+            | CLType::U512
+            | CLType::Unit
+            | CLType::String
+            | CLType::Key
+            | CLType::URef
+            | CLType::PublicKey
+            | CLType::ByteArray(_) => false,
+        }
+    }
+}
+
 /// Returns the `CLType` describing a "named key" on the system, i.e. a `(String, Key)`.
 pub fn named_key_type() -> CLType {
     CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)])
@@ -708,4 +741,16 @@ mod tests {
         let any = Any("Any test".to_string());
         round_trip(&any);
     }
+
+    #[test]
+    fn should_find_nested_any() {
+        assert!(CLType::Any.contains_any());
+        assert!(!<BTreeMap<String, Vec<u8>>>::cl_type().contains_any());
+        assert!(CLType::Map {
+            key: Box::new(CLType::String),
+            value: Box::new(CLType::Option(Box::new(CLType::Any))),
+        }
+        .contains_any());
+        assert!(CLType::Tuple2([Box::new(CLType::U8), Box::new(CLType::Any)]).contains_any());
+    }
 }
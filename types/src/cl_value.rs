@@ -1,7 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::{string::String, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec, vec::Vec};
 use core::fmt;
 
 use datasize::DataSize;
@@ -13,11 +13,16 @@ use serde_json::Value;
 use thiserror::Error;
 
 use crate::{
-    bytesrepr::{self, Bytes, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     CLType, CLTyped,
 };
 
+mod dynamic_value;
 mod jsonrepr;
+mod path;
+
+pub use dynamic_value::{DynamicValue, DynamicValueError};
+pub use path::{CLValuePathElement, CLValuePathError};
 
 /// Error while converting a [`CLValue`] into a given type.
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -98,6 +103,40 @@ impl CLValue {
         CLValue::from_components(CLType::Unit, Vec::new())
     }
 
+    /// A convenience method to create a `CLValue` holding `None`, of type
+    /// `CLType::Option(inner_type)`.
+    ///
+    /// This allows creating a typed `None` where no Rust type implementing [`CLTyped`] is at hand.
+    pub fn none(inner_type: CLType) -> Self {
+        CLValue::from_components(
+            CLType::Option(Box::new(inner_type)),
+            vec![bytesrepr::OPTION_NONE_TAG],
+        )
+    }
+
+    /// A convenience method to create a `CLValue` holding an empty list, of type
+    /// `CLType::List(item_type)`.
+    pub fn empty_list(item_type: CLType) -> Result<Self, CLValueError> {
+        let bytes = Vec::<()>::new().into_bytes()?;
+        Ok(CLValue::from_components(
+            CLType::List(Box::new(item_type)),
+            bytes,
+        ))
+    }
+
+    /// A convenience method to create a `CLValue` holding an empty map, of type
+    /// `CLType::Map { key: key_type, value: value_type }`.
+    pub fn empty_map(key_type: CLType, value_type: CLType) -> Result<Self, CLValueError> {
+        let bytes = BTreeMap::<(), ()>::new().into_bytes()?;
+        Ok(CLValue::from_components(
+            CLType::Map {
+                key: Box::new(key_type),
+                value: Box::new(value_type),
+            },
+            bytes,
+        ))
+    }
+
     // This is only required in order to implement `TryFrom<state::CLValue> for CLValue` (i.e. the
     // conversion from the Protobuf `CLValue`) in a separate module to this one.
     #[doc(hidden)]
@@ -129,7 +168,7 @@ impl CLValue {
     ///
     /// Note, this method doesn't actually serialize `self`, and hence is relatively cheap.
     pub fn serialized_length(&self) -> usize {
-        self.cl_type.serialized_length() + U32_SERIALIZED_LENGTH + self.bytes.len()
+        self.cl_type.serialized_length() + self.bytes.serialized_length()
     }
 }
 
@@ -156,6 +195,13 @@ impl FromBytes for CLValue {
         let cl_value = CLValue { cl_type, bytes };
         Ok((cl_value, remainder))
     }
+
+    fn from_vec(stream: Vec<u8>) -> Result<(Self, Vec<u8>), bytesrepr::Error> {
+        let (bytes, remainder) = Bytes::from_vec(stream)?;
+        let (cl_type, remainder) = CLType::from_vec(remainder)?;
+        let cl_value = CLValue { cl_type, bytes };
+        Ok((cl_value, remainder))
+    }
 }
 
 /// We need to implement `JsonSchema` for `CLValue` as though it is a `CLValueJson`.
@@ -251,6 +297,15 @@ mod tests {
         assert_eq!(cl_value, decoded);
     }
 
+    #[test]
+    fn bytesrepr_roundtrip_from_vec() {
+        let cl_value = CLValue::from_t(String::from("hello")).unwrap();
+        let serialized = cl_value.to_bytes().unwrap();
+        let decoded: CLValue = bytesrepr::deserialize(serialized).unwrap();
+        assert_eq!(decoded, cl_value);
+        assert_eq!(decoded.into_t::<String>().unwrap(), "hello");
+    }
+
     #[test]
     fn json_roundtrip() {
         let cl_value = CLValue::from_t(true).unwrap();
@@ -0,0 +1,219 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::convert::TryFrom;
+
+use datasize::DataSize;
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+use super::jsonrepr;
+use crate::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTypeMismatch, CLTyped, CLValue, CLValueError,
+};
+
+/// Error while constructing a [`DynamicValue`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub enum DynamicValueError {
+    /// The type of the value is or contains [`CLType::Any`].
+    #[cfg_attr(feature = "std", error("value of unspecified type cannot be tagged"))]
+    UntypedValue,
+    /// An error while serializing the value or checking it against its type.
+    #[cfg_attr(feature = "std", error("{}", _0))]
+    CLValue(CLValueError),
+}
+
+impl From<CLValueError> for DynamicValueError {
+    fn from(error: CLValueError) -> Self {
+        DynamicValueError::CLValue(error)
+    }
+}
+
+impl From<bytesrepr::Error> for DynamicValueError {
+    fn from(error: bytesrepr::Error) -> Self {
+        DynamicValueError::CLValue(CLValueError::Serialization(error))
+    }
+}
+
+/// A value carrying its [`CLType`] inline, for values whose type is only known at runtime.
+///
+/// This supersedes declaring such values as [`CLType::Any`]. A `DynamicValue` is itself of a fixed
+/// `CLType`: a pair of byte lists holding the serialized type tag and the serialized value. It can
+/// hence be type checked and indexed like any other value, while the tag is checked on every
+/// downcast to a concrete type.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, DataSize, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct DynamicValue(CLValue);
+
+impl DynamicValue {
+    /// Constructs a `DynamicValue` from `t`.
+    pub fn from_t<T: CLTyped + ToBytes>(t: T) -> Result<Self, DynamicValueError> {
+        DynamicValue::try_from(CLValue::from_t(t)?)
+    }
+
+    /// Converts a stored value declared as [`CLType::Any`] into a `DynamicValue` of the given
+    /// concrete type.
+    ///
+    /// The underlying bytes must represent exactly one value of `cl_type`. Values which already
+    /// have a concrete type are accepted if it matches `cl_type`.
+    pub fn from_any(cl_value: CLValue, cl_type: CLType) -> Result<Self, DynamicValueError> {
+        if cl_type.contains_any() {
+            return Err(DynamicValueError::UntypedValue);
+        }
+        if *cl_value.cl_type() == cl_type {
+            return Ok(DynamicValue(cl_value));
+        }
+        if *cl_value.cl_type() != CLType::Any {
+            return Err(CLValueError::Type(CLTypeMismatch {
+                expected: cl_type,
+                found: cl_value.cl_type().clone(),
+            })
+            .into());
+        }
+
+        let (_, bytes) = cl_value.destructure();
+        let cl_value = CLValue::from_components(cl_type, bytes.into());
+        // The JSON representation is only available if the bytes parse as the given type.
+        if jsonrepr::cl_value_to_json(&cl_value).is_none() {
+            return Err(bytesrepr::Error::Formatting.into());
+        }
+        Ok(DynamicValue(cl_value))
+    }
+
+    /// The [`CLType`] of the wrapped value.
+    pub fn cl_type(&self) -> &CLType {
+        self.0.cl_type()
+    }
+
+    /// Returns `true` if the wrapped value is of type `T`.
+    pub fn is<T: CLTyped>(&self) -> bool {
+        *self.cl_type() == T::cl_type()
+    }
+
+    /// Converts the wrapped value into `T`, failing if it is not of type `T`.
+    pub fn downcast<T: CLTyped + FromBytes>(self) -> Result<T, CLValueError> {
+        self.0.into_t()
+    }
+
+    /// Returns the wrapped value as a [`CLValue`].
+    pub fn into_cl_value(self) -> CLValue {
+        self.0
+    }
+}
+
+impl TryFrom<CLValue> for DynamicValue {
+    type Error = DynamicValueError;
+
+    fn try_from(cl_value: CLValue) -> Result<Self, Self::Error> {
+        if cl_value.cl_type().contains_any() {
+            return Err(DynamicValueError::UntypedValue);
+        }
+        Ok(DynamicValue(cl_value))
+    }
+}
+
+impl From<DynamicValue> for CLValue {
+    fn from(dynamic_value: DynamicValue) -> Self {
+        dynamic_value.0
+    }
+}
+
+impl CLTyped for DynamicValue {
+    fn cl_type() -> CLType {
+        CLType::Tuple2([
+            Box::new(CLType::List(Box::new(CLType::U8))),
+            Box::new(CLType::List(Box::new(CLType::U8))),
+        ])
+    }
+}
+
+impl ToBytes for DynamicValue {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut cl_type_bytes = Vec::with_capacity(self.cl_type().serialized_length());
+        self.cl_type().append_bytes(&mut cl_type_bytes)?;
+        let mut result = Bytes::from(cl_type_bytes).into_bytes()?;
+        result.append(&mut self.0.bytes.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        let cl_type_length = self.cl_type().serialized_length();
+        bytesrepr::length_prefix_serialized_length(cl_type_length)
+            + cl_type_length
+            + self.0.bytes.serialized_length()
+    }
+}
+
+impl FromBytes for DynamicValue {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (cl_type_bytes, remainder) = Bytes::from_bytes(bytes)?;
+        let (value_bytes, remainder) = Bytes::from_bytes(remainder)?;
+        let cl_type: CLType = bytesrepr::deserialize(cl_type_bytes.into())?;
+        if cl_type.contains_any() {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let dynamic_value = DynamicValue(CLValue::from_components(cl_type, value_bytes.into()));
+        Ok((dynamic_value, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+    use crate::U512;
+
+    #[test]
+    fn should_downcast_to_tagged_type_only() {
+        let dynamic_value = DynamicValue::from_t(U512::from(7)).unwrap();
+        assert!(dynamic_value.is::<U512>());
+        assert!(!dynamic_value.is::<u64>());
+
+        assert!(dynamic_value.clone().downcast::<u64>().is_err());
+        assert_eq!(dynamic_value.downcast::<U512>().unwrap(), U512::from(7));
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let dynamic_value = DynamicValue::from_t(Some("hello".to_string())).unwrap();
+        bytesrepr::test_serialization_roundtrip(&dynamic_value);
+
+        // the representation is a well-typed pair of byte lists
+        let cl_value = CLValue::from_t(dynamic_value.clone()).unwrap();
+        assert_eq!(cl_value.into_t::<DynamicValue>().unwrap(), dynamic_value);
+    }
+
+    #[test]
+    fn should_reject_untyped_values() {
+        let cl_value = CLValue::from_components(CLType::Any, vec![1, 2, 3]);
+        assert_eq!(
+            DynamicValue::try_from(cl_value),
+            Err(DynamicValueError::UntypedValue)
+        );
+    }
+
+    #[test]
+    fn should_convert_stored_any_value() {
+        let bytes = ToBytes::to_bytes(&"stored".to_string()).unwrap();
+        let stored = CLValue::from_components(CLType::Any, bytes.clone());
+
+        let dynamic_value = DynamicValue::from_any(stored, CLType::String).unwrap();
+        assert_eq!(dynamic_value.downcast::<String>().unwrap(), "stored");
+
+        // the bytes must represent a single value of the given type
+        let stored = CLValue::from_components(CLType::Any, bytes);
+        assert!(DynamicValue::from_any(stored.clone(), CLType::U8).is_err());
+        assert_eq!(
+            DynamicValue::from_any(stored, CLType::Any),
+            Err(DynamicValueError::UntypedValue)
+        );
+
+        // values of another concrete type are not reinterpreted
+        let typed = CLValue::from_t(1u32).unwrap();
+        assert!(DynamicValue::from_any(typed, CLType::I32).is_err());
+    }
+}
@@ -19,7 +19,7 @@ pub fn cl_value_to_json(cl_value: &CLValue) -> Option<Value> {
     })
 }
 
-fn to_json<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(Value, &'a [u8])> {
+pub(super) fn to_json<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(Value, &'a [u8])> {
     match cl_type {
         CLType::Bool => simple_type_to_json::<bool>(bytes),
         CLType::I32 => simple_type_to_json::<i32>(bytes),
@@ -44,7 +44,7 @@ fn to_json<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(Value, &'a [u8])> {
             }
         }
         CLType::List(inner_cl_type) => {
-            let (count, mut stream) = u32::from_bytes(bytes).ok()?;
+            let (count, mut stream) = bytesrepr::read_length_prefix(bytes).ok()?;
             let mut result: Vec<Value> = Vec::new();
             for _ in 0..count {
                 let (value, remainder) = to_json(inner_cl_type, &stream)?;
@@ -72,7 +72,7 @@ fn to_json<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(Value, &'a [u8])> {
             }
         }
         CLType::Map { key, value } => {
-            let (num_keys, mut stream) = u32::from_bytes(bytes).unwrap();
+            let (num_keys, mut stream) = bytesrepr::read_length_prefix(bytes).ok()?;
             let mut result: Vec<Value> = Vec::new();
             for _ in 0..num_keys {
                 let (k, remainder) = to_json(key, stream)?;
@@ -0,0 +1,351 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Formatter};
+
+use serde_json::Value;
+
+use super::jsonrepr;
+use crate::{
+    bytesrepr::{self, FromBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
+    CLType, CLValue,
+};
+
+const OK_ELEMENT_NAME: &str = "Ok";
+const ERR_ELEMENT_NAME: &str = "Err";
+
+/// A single step of a path into the elements of a [`CLValue`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CLValuePathElement {
+    /// A map entry whose key is rendered as the given name, or the `Ok` or `Err` variant of a
+    /// result.
+    Name(String),
+    /// The element at the given position of a list or tuple, or a map entry whose key is rendered
+    /// as the given number.
+    Index(u32),
+}
+
+impl CLValuePathElement {
+    /// Parses a path segment such as `entry`, `entry[3]` or `[0][1]` into the elements it names.
+    pub fn parse_segment(segment: &str) -> Result<Vec<CLValuePathElement>, CLValuePathError> {
+        let invalid_segment = || CLValuePathError::InvalidSegment(segment.to_string());
+
+        let (name, mut indices) = match segment.find('[') {
+            Some(position) => segment.split_at(position),
+            None => (segment, ""),
+        };
+        if name.contains(']') {
+            return Err(invalid_segment());
+        }
+
+        let mut elements = Vec::new();
+        if !name.is_empty() {
+            elements.push(CLValuePathElement::Name(name.to_string()));
+        }
+        while !indices.is_empty() {
+            let end = indices.find(']').ok_or_else(invalid_segment)?;
+            if !indices.starts_with('[') {
+                return Err(invalid_segment());
+            }
+            let index = indices[1..end].parse().map_err(|_| invalid_segment())?;
+            elements.push(CLValuePathElement::Index(index));
+            indices = &indices[end + 1..];
+        }
+
+        if elements.is_empty() {
+            return Err(invalid_segment());
+        }
+        Ok(elements)
+    }
+
+    /// Returns `true` if `key`, in its JSON representation, matches this element.
+    fn matches_map_key(&self, key: &Value) -> bool {
+        let key = match key {
+            Value::String(key) => key.clone(),
+            other => other.to_string(),
+        };
+        match self {
+            CLValuePathElement::Name(name) => *name == key,
+            CLValuePathElement::Index(index) => index.to_string() == key,
+        }
+    }
+}
+
+impl Display for CLValuePathElement {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CLValuePathElement::Name(name) => write!(f, "{}", name),
+            CLValuePathElement::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// Error while navigating into the elements of a [`CLValue`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CLValuePathError {
+    /// The path segment could not be parsed.
+    InvalidSegment(String),
+    /// The value does not contain the requested element.
+    ElementNotFound {
+        /// The requested element.
+        element: CLValuePathElement,
+        /// The type of the value searched.
+        cl_type: CLType,
+    },
+    /// The value is not of a type which holds elements.
+    NotAContainer(CLType),
+    /// The serialized value does not match its type.
+    Malformed(CLType),
+}
+
+impl Display for CLValuePathError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CLValuePathError::InvalidSegment(segment) => {
+                write!(f, "invalid path segment '{}'", segment)
+            }
+            CLValuePathError::ElementNotFound { element, cl_type } => {
+                write!(f, "no element {} in value of type {:?}", element, cl_type)
+            }
+            CLValuePathError::NotAContainer(cl_type) => {
+                write!(f, "value of type {:?} has no elements", cl_type)
+            }
+            CLValuePathError::Malformed(cl_type) => {
+                write!(f, "value does not parse as type {:?}", cl_type)
+            }
+        }
+    }
+}
+
+impl CLValue {
+    /// Returns the element of this value reached by following the given path segment, e.g.
+    /// `entry[3]`.
+    ///
+    /// Map entries are looked up by the JSON representation of their keys, lists and tuples are
+    /// indexed by position, results are entered via `Ok` or `Err` and options which hold a value
+    /// are entered implicitly.
+    pub fn get_path_segment(&self, segment: &str) -> Result<CLValue, CLValuePathError> {
+        let mut cl_value = self.clone();
+        for element in CLValuePathElement::parse_segment(segment)? {
+            cl_value = cl_value.get_element(&element)?;
+        }
+        Ok(cl_value)
+    }
+
+    /// Returns the element of this value identified by `element`.
+    pub fn get_element(&self, element: &CLValuePathElement) -> Result<CLValue, CLValuePathError> {
+        let malformed = || CLValuePathError::Malformed(self.cl_type.clone());
+        let not_found = || CLValuePathError::ElementNotFound {
+            element: element.clone(),
+            cl_type: self.cl_type.clone(),
+        };
+        let bytes = self.inner_bytes();
+
+        match (&self.cl_type, element) {
+            (CLType::Option(inner_cl_type), _) => {
+                let (tag, remainder) = u8::from_bytes(bytes).map_err(|_| malformed())?;
+                match tag {
+                    OPTION_NONE_TAG => Err(not_found()),
+                    OPTION_SOME_TAG => {
+                        let inner =
+                            CLValue::from_components((**inner_cl_type).clone(), remainder.to_vec());
+                        inner.get_element(element)
+                    }
+                    _ => Err(malformed()),
+                }
+            }
+            (CLType::Result { ok, err }, CLValuePathElement::Name(name)) => {
+                let (tag, remainder) = u8::from_bytes(bytes).map_err(|_| malformed())?;
+                let inner_cl_type = match (tag, name.as_str()) {
+                    (RESULT_OK_TAG, OK_ELEMENT_NAME) => ok,
+                    (RESULT_ERR_TAG, ERR_ELEMENT_NAME) => err,
+                    (RESULT_OK_TAG, _) | (RESULT_ERR_TAG, _) => return Err(not_found()),
+                    _ => return Err(malformed()),
+                };
+                Ok(CLValue::from_components(
+                    (**inner_cl_type).clone(),
+                    remainder.to_vec(),
+                ))
+            }
+            (CLType::List(inner_cl_type), CLValuePathElement::Index(index)) => {
+                let (count, mut stream) =
+                    bytesrepr::read_length_prefix(bytes).map_err(|_| malformed())?;
+                if *index >= count {
+                    return Err(not_found());
+                }
+                for _ in 0..*index {
+                    let (_, remainder) =
+                        split_element(inner_cl_type, stream).ok_or_else(malformed)?;
+                    stream = remainder;
+                }
+                let (element_bytes, _) =
+                    split_element(inner_cl_type, stream).ok_or_else(malformed)?;
+                Ok(CLValue::from_components(
+                    (**inner_cl_type).clone(),
+                    element_bytes.to_vec(),
+                ))
+            }
+            (CLType::Tuple1(cl_types), CLValuePathElement::Index(index)) => {
+                self.get_tuple_element(cl_types, *index)
+            }
+            (CLType::Tuple2(cl_types), CLValuePathElement::Index(index)) => {
+                self.get_tuple_element(cl_types, *index)
+            }
+            (CLType::Tuple3(cl_types), CLValuePathElement::Index(index)) => {
+                self.get_tuple_element(cl_types, *index)
+            }
+            (CLType::Map { key, value }, _) => {
+                let (num_keys, mut stream) =
+                    bytesrepr::read_length_prefix(bytes).map_err(|_| malformed())?;
+                for _ in 0..num_keys {
+                    let (json_key, remainder) =
+                        jsonrepr::to_json(key, stream).ok_or_else(malformed)?;
+                    let (value_bytes, remainder) =
+                        split_element(value, remainder).ok_or_else(malformed)?;
+                    if element.matches_map_key(&json_key) {
+                        return Ok(CLValue::from_components(
+                            (**value).clone(),
+                            value_bytes.to_vec(),
+                        ));
+                    }
+                    stream = remainder;
+                }
+                Err(not_found())
+            }
+            (CLType::Result { .. }, CLValuePathElement::Index(_))
+            | (CLType::List(_), CLValuePathElement::Name(_))
+            | (CLType::Tuple1(_), CLValuePathElement::Name(_))
+            | (CLType::Tuple2(_), CLValuePathElement::Name(_))
+            | (CLType::Tuple3(_), CLValuePathElement::Name(_)) => Err(not_found()),
+            (cl_type, _) => Err(CLValuePathError::NotAContainer(cl_type.clone())),
+        }
+    }
+
+    /// Returns the element at `index` of this tuple value with element types `cl_types`.
+    fn get_tuple_element(
+        &self,
+        cl_types: &[Box<CLType>],
+        index: u32,
+    ) -> Result<CLValue, CLValuePathError> {
+        let mut stream: &[u8] = self.inner_bytes();
+        for (position, cl_type) in cl_types.iter().enumerate() {
+            let (element_bytes, remainder) = split_element(cl_type, stream)
+                .ok_or_else(|| CLValuePathError::Malformed(self.cl_type.clone()))?;
+            if position == index as usize {
+                return Ok(CLValue::from_components(
+                    (**cl_type).clone(),
+                    element_bytes.to_vec(),
+                ));
+            }
+            stream = remainder;
+        }
+        Err(CLValuePathError::ElementNotFound {
+            element: CLValuePathElement::Index(index),
+            cl_type: self.cl_type.clone(),
+        })
+    }
+}
+
+/// Splits `bytes` into the serialized value of type `cl_type` at their start and the remainder.
+fn split_element<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let (_, remainder) = jsonrepr::to_json(cl_type, bytes)?;
+    Some(bytes.split_at(bytes.len() - remainder.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::ToString, vec};
+
+    use super::*;
+    use crate::{Key, U512};
+
+    fn nested_value() -> CLValue {
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            "entry".to_string(),
+            vec![(1u64, "one".to_string()), (2u64, "two".to_string())],
+        );
+        entries.insert("empty".to_string(), Vec::new());
+        CLValue::from_t(entries).unwrap()
+    }
+
+    #[test]
+    fn should_parse_segments() {
+        assert_eq!(
+            CLValuePathElement::parse_segment("entry[3][0]").unwrap(),
+            vec![
+                CLValuePathElement::Name("entry".to_string()),
+                CLValuePathElement::Index(3),
+                CLValuePathElement::Index(0)
+            ]
+        );
+        assert_eq!(
+            CLValuePathElement::parse_segment("[1]").unwrap(),
+            vec![CLValuePathElement::Index(1)]
+        );
+        for invalid in &["", "entry[", "entry[x]", "entry[1]x", "entry]"] {
+            assert_eq!(
+                CLValuePathElement::parse_segment(invalid),
+                Err(CLValuePathError::InvalidSegment(invalid.to_string())),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn should_navigate_into_maps_lists_and_tuples() {
+        let cl_value = nested_value();
+
+        let field = cl_value.get_path_segment("entry[1]").unwrap();
+        let field = field.get_path_segment("[1]").unwrap();
+        assert_eq!(field.into_t::<String>().unwrap(), "two");
+
+        let number = cl_value.get_path_segment("entry[0][0]").unwrap();
+        assert_eq!(number.into_t::<u64>().unwrap(), 1);
+    }
+
+    #[test]
+    fn should_navigate_into_options_and_results() {
+        let mut entries = BTreeMap::new();
+        entries.insert(7u32, Key::Hash([1; 32]));
+        let ok: Result<_, String> = Ok(Some(entries));
+        let cl_value = CLValue::from_t(ok).unwrap();
+
+        let key = cl_value.get_path_segment("Ok").unwrap();
+        let key = key.get_path_segment("[7]").unwrap();
+        assert_eq!(key.into_t::<Key>().unwrap(), Key::Hash([1; 32]));
+
+        let none: Option<Vec<u8>> = None;
+        let cl_value = CLValue::from_t(none).unwrap();
+        assert!(matches!(
+            cl_value.get_path_segment("[0]"),
+            Err(CLValuePathError::ElementNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn should_report_missing_elements() {
+        let cl_value = nested_value();
+
+        assert_eq!(
+            cl_value.get_path_segment("missing"),
+            Err(CLValuePathError::ElementNotFound {
+                element: CLValuePathElement::Name("missing".to_string()),
+                cl_type: cl_value.cl_type().clone(),
+            })
+        );
+        assert!(matches!(
+            cl_value.get_path_segment("empty[0]"),
+            Err(CLValuePathError::ElementNotFound { .. })
+        ));
+
+        let leaf = CLValue::from_t(U512::one()).unwrap();
+        assert_eq!(
+            leaf.get_path_segment("field"),
+            Err(CLValuePathError::NotAContainer(CLType::U512))
+        );
+    }
+}
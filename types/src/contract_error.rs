@@ -0,0 +1,155 @@
+//! Support for contract-defined error types mapped onto [`ApiError::User`].
+
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt::{self, Display, Formatter};
+
+use crate::ApiError;
+
+/// A contract-defined error type whose values map onto the user error space of [`ApiError`], i.e.
+/// [`ApiError::User`].
+///
+/// With the `derive` feature enabled, this is usually derived for a C-style enum via
+/// `#[derive(ContractError)]`, in which case each variant's discriminant is used as its user error
+/// code.  The derived implementation is equivalent to:
+///
+/// ```
+/// use casper_types::{ApiError, ContractError};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum FailureCode {
+///     Zero = 0,  // 65,536 as an ApiError::User
+///     One,       // 65,537 as an ApiError::User
+///     Two,       // 65,538 as an ApiError::User
+/// }
+///
+/// impl ContractError for FailureCode {
+///     const SCHEMA: &'static [(u16, &'static str)] = &[(0, "Zero"), (1, "One"), (2, "Two")];
+///
+///     fn user_error_code(&self) -> u16 {
+///         match self {
+///             FailureCode::Zero => 0,
+///             FailureCode::One => 1,
+///             FailureCode::Two => 2,
+///         }
+///     }
+///
+///     fn from_user_error_code(code: u16) -> Option<Self> {
+///         match code {
+///             0 => Some(FailureCode::Zero),
+///             1 => Some(FailureCode::One),
+///             2 => Some(FailureCode::Two),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// impl From<FailureCode> for ApiError {
+///     fn from(error: FailureCode) -> Self {
+///         ApiError::User(error.user_error_code())
+///     }
+/// }
+///
+/// assert_eq!(ApiError::User(1), FailureCode::One.into());
+/// assert_eq!(
+///     Some(FailureCode::Two),
+///     FailureCode::from_api_error(ApiError::User(2))
+/// );
+/// assert_eq!("One", FailureCode::One.name());
+/// ```
+pub trait ContractError: Sized {
+    /// The user error code and name of every value of the error type.
+    const SCHEMA: &'static [(u16, &'static str)];
+
+    /// Returns the user error code of `self`.
+    fn user_error_code(&self) -> u16;
+
+    /// Returns the error with the given user error code, if any.
+    fn from_user_error_code(code: u16) -> Option<Self>;
+
+    /// Returns the error represented by `error`, if any.
+    fn from_api_error(error: ApiError) -> Option<Self> {
+        match error {
+            ApiError::User(code) => Self::from_user_error_code(code),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of `self` as recorded in the schema.
+    fn name(&self) -> &'static str {
+        let code = self.user_error_code();
+        Self::SCHEMA
+            .iter()
+            .find(|(schema_code, _)| *schema_code == code)
+            .map_or("unknown", |(_, name)| name)
+    }
+}
+
+/// A registry of names for user error codes, used to display [`ApiError::User`] errors
+/// meaningfully.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct UserErrorSchema {
+    names: BTreeMap<u16, String>,
+}
+
+impl UserErrorSchema {
+    /// Constructs a new, empty `UserErrorSchema`.
+    pub fn new() -> Self {
+        UserErrorSchema::default()
+    }
+
+    /// Constructs a new `UserErrorSchema` holding the schema of `E`.
+    pub fn of<E: ContractError>() -> Self {
+        let mut schema = UserErrorSchema::new();
+        schema.register::<E>();
+        schema
+    }
+
+    /// Registers the schema of `E`, replacing any existing names for the same codes.
+    pub fn register<E: ContractError>(&mut self) -> &mut Self {
+        for (code, name) in E::SCHEMA {
+            self.insert(*code, String::from(*name));
+        }
+        self
+    }
+
+    /// Registers `name` for the user error `code`, replacing any existing name.
+    pub fn insert(&mut self, code: u16, name: String) -> &mut Self {
+        let _ = self.names.insert(code, name);
+        self
+    }
+
+    /// Returns the name registered for the user error `code`, if any.
+    pub fn name(&self, code: u16) -> Option<&str> {
+        self.names.get(&code).map(String::as_str)
+    }
+
+    /// Returns a value which displays `error`, including the registered name if it is a user
+    /// error.
+    pub fn display<'a>(&'a self, error: &'a ApiError) -> DisplayWithSchema<'a> {
+        DisplayWithSchema {
+            schema: self,
+            error,
+        }
+    }
+}
+
+/// Displays an [`ApiError`] using the names registered in a [`UserErrorSchema`].
+///
+/// Created via [`UserErrorSchema::display`].
+#[derive(Debug)]
+pub struct DisplayWithSchema<'a> {
+    schema: &'a UserErrorSchema,
+    error: &'a ApiError,
+}
+
+impl<'a> Display for DisplayWithSchema<'a> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self.error {
+            ApiError::User(code) => match self.schema.name(*code) {
+                Some(name) => write!(formatter, "User error: {} ({})", code, name),
+                None => Display::fmt(self.error, formatter),
+            },
+            _ => Display::fmt(self.error, formatter),
+        }
+    }
+}
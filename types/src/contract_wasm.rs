@@ -14,7 +14,7 @@ use crate::{
     account,
     account::TryFromSliceForAccountHashError,
     bytesrepr::{Bytes, Error, FromBytes, ToBytes},
-    uref, CLType, CLTyped, HashAddr,
+    hashing, uref, CLType, CLTyped, HashAddr,
 };
 
 const CONTRACT_WASM_MAX_DISPLAY_LEN: usize = 16;
@@ -80,6 +80,42 @@ impl Display for FromStrError {
     }
 }
 
+/// An error returned when constructing or verifying a [`ContractWasm`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractWasmError {
+    /// The WASM bytes exceed the maximum allowed size.
+    TooLarge {
+        /// The size of the WASM bytes.
+        actual: usize,
+        /// The maximum allowed size.
+        max: usize,
+    },
+    /// The hash of the WASM bytes doesn't match the expected hash.
+    HashMismatch {
+        /// The expected hash.
+        expected: ContractWasmHash,
+        /// The hash of the WASM bytes.
+        actual: ContractWasmHash,
+    },
+}
+
+impl Display for ContractWasmError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ContractWasmError::TooLarge { actual, max } => write!(
+                f,
+                "contract wasm of {} bytes exceeds the maximum of {} bytes",
+                actual, max
+            ),
+            ContractWasmError::HashMismatch { expected, actual } => write!(
+                f,
+                "contract wasm hash {} doesn't match expected hash {}",
+                actual, expected
+            ),
+        }
+    }
+}
+
 /// A newtype wrapping a `HashAddr` which is the raw bytes of
 /// the ContractWasmHash
 #[derive(DataSize, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
@@ -250,6 +286,49 @@ impl ContractWasm {
         }
     }
 
+    /// Creates new WASM object from bytes, returning an error if there are more than `max_size`
+    /// of them.
+    pub fn try_new(bytes: Vec<u8>, max_size: usize) -> Result<Self, ContractWasmError> {
+        if bytes.len() > max_size {
+            return Err(ContractWasmError::TooLarge {
+                actual: bytes.len(),
+                max: max_size,
+            });
+        }
+        Ok(ContractWasm::new(bytes))
+    }
+
+    /// Returns the number of contained WASM bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if there are no contained WASM bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the Blake2b hash of the contained WASM bytes.
+    pub fn hash(&self) -> ContractWasmHash {
+        ContractWasmHash::new(hashing::blake2b256(self.bytes()))
+    }
+
+    /// Checks that the hash of the contained WASM bytes is `expected`.
+    ///
+    /// The execution engine stores contract WASM under an address-generated [`ContractWasmHash`]
+    /// rather than the hash of its bytes, so this only applies to hashes obtained from
+    /// [`ContractWasm::hash`].
+    pub fn verify_against(&self, expected: &ContractWasmHash) -> Result<(), ContractWasmError> {
+        let actual = self.hash();
+        if actual != *expected {
+            return Err(ContractWasmError::HashMismatch {
+                expected: *expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
     /// Consumes instance of [`ContractWasm`] and returns its bytes.
     pub fn take_bytes(self) -> Vec<u8> {
         self.bytes.into()
@@ -276,6 +355,11 @@ impl FromBytes for ContractWasm {
         let (bytes, rem1) = FromBytes::from_bytes(bytes)?;
         Ok((ContractWasm { bytes }, rem1))
     }
+
+    fn from_vec(stream: Vec<u8>) -> Result<(Self, Vec<u8>), Error> {
+        let (bytes, remainder) = Bytes::from_vec(stream)?;
+        Ok((ContractWasm { bytes }, remainder))
+    }
 }
 
 #[cfg(test)]
@@ -305,6 +389,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_limit_size_of_contract_wasm() {
+        const SIZE: usize = 8;
+        assert_eq!(
+            ContractWasm::try_new(vec![0; SIZE], SIZE).unwrap().len(),
+            SIZE
+        );
+        assert_eq!(
+            ContractWasm::try_new(vec![0; SIZE + 1], SIZE),
+            Err(ContractWasmError::TooLarge {
+                actual: SIZE + 1,
+                max: SIZE
+            })
+        );
+    }
+
+    #[test]
+    fn should_verify_contract_wasm_hash() {
+        let contract_wasm = ContractWasm::new(vec![1, 2, 3]);
+        let hash = contract_wasm.hash();
+        assert_eq!(hash.value(), hashing::blake2b256([1u8, 2, 3]));
+        assert!(contract_wasm.verify_against(&hash).is_ok());
+
+        let other_hash = ContractWasm::new(vec![1, 2]).hash();
+        assert_eq!(
+            contract_wasm.verify_against(&other_hash),
+            Err(ContractWasmError::HashMismatch {
+                expected: other_hash,
+                actual: hash
+            })
+        );
+    }
+
     #[test]
     fn contract_wasm_hash_from_slice() {
         let bytes: Vec<u8> = (0..32).collect();
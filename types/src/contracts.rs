@@ -21,12 +21,12 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 
 use crate::{
     account,
-    account::TryFromSliceForAccountHashError,
-    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    account::{AccountHash, TryFromSliceForAccountHashError},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
     contract_wasm::ContractWasmHash,
     uref,
     uref::URef,
-    CLType, CLTyped, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
+    BlockTime, CLType, CLTyped, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
 };
 
 /// Maximum number of distinct user groups.
@@ -65,6 +65,12 @@ pub enum Error {
     GroupInUse = 8,
     /// URef already exists in given group.
     URefAlreadyExists = 9,
+    /// Attempted to manage the versions of a contract package without its access key being in
+    /// the current context.
+    MissingAccessKey = 10,
+    /// Attempted to manage the versions of a contract package with an access key lacking the
+    /// required access rights.
+    InvalidAccessKeyRights = 11,
 }
 
 /// Associated error type of `TryFrom<&[u8]>` for `ContractHash`.
@@ -249,8 +255,104 @@ impl fmt::Display for ContractVersionKey {
     }
 }
 
+/// A single version of a contract package: the contract hash together with its provenance.
+///
+/// Versions added before provenance was recorded have a creation time of zero, the default account
+/// hash as creator and no metadata hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ContractVersionInfo {
+    contract_hash: ContractHash,
+    created_at: BlockTime,
+    creator: AccountHash,
+    metadata_hash: Option<HashAddr>,
+}
+
+impl ContractVersionInfo {
+    /// Returns a new `ContractVersionInfo`.
+    pub fn new(
+        contract_hash: ContractHash,
+        created_at: BlockTime,
+        creator: AccountHash,
+        metadata_hash: Option<HashAddr>,
+    ) -> Self {
+        ContractVersionInfo {
+            contract_hash,
+            created_at,
+            creator,
+            metadata_hash,
+        }
+    }
+
+    /// Returns the hash of the contract.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the block time at which the version was added.
+    pub fn created_at(&self) -> BlockTime {
+        self.created_at
+    }
+
+    /// Returns the account which added the version.
+    pub fn creator(&self) -> AccountHash {
+        self.creator
+    }
+
+    /// Returns the hash of the version's metadata, e.g. its release notes, if any.
+    pub fn metadata_hash(&self) -> Option<HashAddr> {
+        self.metadata_hash
+    }
+
+    /// Sets the hash of the version's metadata.
+    pub fn set_metadata_hash(&mut self, metadata_hash: Option<HashAddr>) {
+        self.metadata_hash = metadata_hash;
+    }
+
+    /// Returns the provenance of the version, i.e. everything but the contract hash.
+    fn provenance(&self) -> VersionProvenance {
+        (self.created_at, self.creator, self.metadata_hash)
+    }
+}
+
+impl From<ContractHash> for ContractVersionInfo {
+    /// Returns the info of a version whose provenance is unknown.
+    fn from(contract_hash: ContractHash) -> Self {
+        ContractVersionInfo::new(
+            contract_hash,
+            BlockTime::default(),
+            AccountHash::default(),
+            None,
+        )
+    }
+}
+
+impl ToBytes for ContractVersionInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.contract_hash.to_bytes()?);
+        result.append(&mut self.provenance().to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length() + self.provenance().serialized_length()
+    }
+}
+
+impl FromBytes for ContractVersionInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_hash, bytes) = ContractHash::from_bytes(bytes)?;
+        let ((created_at, creator, metadata_hash), bytes) = VersionProvenance::from_bytes(bytes)?;
+        let result = ContractVersionInfo::new(contract_hash, created_at, creator, metadata_hash);
+        Ok((result, bytes))
+    }
+}
+
+/// The creation time, creator and metadata hash of a contract version.
+type VersionProvenance = (BlockTime, AccountHash, Option<HashAddr>);
+
 /// Collection of contract versions.
-pub type ContractVersions = BTreeMap<ContractVersionKey, ContractHash>;
+pub type ContractVersions = BTreeMap<ContractVersionKey, ContractVersionInfo>;
 
 /// Collection of disabled contract versions. The runtime will not permit disabled
 /// contract versions to be executed.
@@ -555,8 +657,15 @@ pub enum ContractPackageStatus {
     Locked,
     /// The package is unlocked and can be versioned.
     Unlocked,
+    /// The package has been locked via [`ContractPackage::lock`], and can neither be versioned nor
+    /// have its user groups changed.
+    Frozen,
 }
 
+const CONTRACT_PACKAGE_STATUS_UNLOCKED_TAG: u8 = 0;
+const CONTRACT_PACKAGE_STATUS_LOCKED_TAG: u8 = 1;
+const CONTRACT_PACKAGE_STATUS_FROZEN_TAG: u8 = 2;
+
 impl ContractPackageStatus {
     /// Create a new status flag based on a boolean value
     pub fn new(is_locked: bool) -> Self {
@@ -577,29 +686,43 @@ impl Default for ContractPackageStatus {
 impl ToBytes for ContractPackageStatus {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
-        match self {
-            ContractPackageStatus::Unlocked => result.append(&mut false.to_bytes()?),
-            ContractPackageStatus::Locked => result.append(&mut true.to_bytes()?),
-        }
+        // The tags of `Unlocked` and `Locked` match the serialized `false` and `true` which were
+        // written before `Frozen` was introduced.
+        let tag = match self {
+            ContractPackageStatus::Unlocked => CONTRACT_PACKAGE_STATUS_UNLOCKED_TAG,
+            ContractPackageStatus::Locked => CONTRACT_PACKAGE_STATUS_LOCKED_TAG,
+            ContractPackageStatus::Frozen => CONTRACT_PACKAGE_STATUS_FROZEN_TAG,
+        };
+        result.push(tag);
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        match self {
-            ContractPackageStatus::Unlocked => false.serialized_length(),
-            ContractPackageStatus::Locked => true.serialized_length(),
-        }
+        U8_SERIALIZED_LENGTH
     }
 }
 
 impl FromBytes for ContractPackageStatus {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (val, bytes) = bool::from_bytes(bytes)?;
-        let status = ContractPackageStatus::new(val);
+        let (tag, bytes) = u8::from_bytes(bytes)?;
+        let status = match tag {
+            CONTRACT_PACKAGE_STATUS_UNLOCKED_TAG => ContractPackageStatus::Unlocked,
+            CONTRACT_PACKAGE_STATUS_LOCKED_TAG => ContractPackageStatus::Locked,
+            CONTRACT_PACKAGE_STATUS_FROZEN_TAG => ContractPackageStatus::Frozen,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
         Ok((status, bytes))
     }
 }
 
+/// The byte serialized in place of the lock status of a `ContractPackage` with a versioned layout.
+///
+/// Packages serialized before the layout was versioned have a lock status of either `0` or `1`
+/// there, and lack the provenance of their versions.
+const CONTRACT_PACKAGE_VERSIONED_LAYOUT_TAG: u8 = u8::max_value();
+/// The current version of the serialized layout of a `ContractPackage`.
+const CONTRACT_PACKAGE_LAYOUT_VERSION: u8 = 1;
+
 /// Contract definition, metadata, and security container.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
 pub struct ContractPackage {
@@ -665,7 +788,9 @@ impl ContractPackage {
         if !self.is_version_enabled(contract_version_key) {
             return None;
         }
-        self.versions.get(&contract_version_key)
+        self.versions
+            .get(&contract_version_key)
+            .map(|version_info| &version_info.contract_hash)
     }
 
     /// Checks if the given contract version exists and is available for use.
@@ -678,11 +803,11 @@ impl ContractPackage {
     pub fn insert_contract_version(
         &mut self,
         protocol_version_major: ProtocolVersionMajor,
-        contract_hash: ContractHash,
+        version_info: ContractVersionInfo,
     ) -> ContractVersionKey {
         let contract_version = self.next_contract_version_for(protocol_version_major);
         let key = ContractVersionKey::new(protocol_version_major, contract_version);
-        self.versions.insert(key, contract_hash);
+        self.versions.insert(key, version_info);
         key
     }
 
@@ -691,7 +816,13 @@ impl ContractPackage {
         let contract_version_key = self
             .versions
             .iter()
-            .filter_map(|(k, v)| if *v == contract_hash { Some(*k) } else { None })
+            .filter_map(|(k, v)| {
+                if v.contract_hash == contract_hash {
+                    Some(*k)
+                } else {
+                    None
+                }
+            })
             .next()
             .ok_or(Error::ContractNotFound)?;
 
@@ -773,19 +904,53 @@ impl ContractPackage {
     /// Return the contract hash for the newest enabled contract version.
     pub fn current_contract_hash(&self) -> Option<ContractHash> {
         match self.enabled_versions().values().next_back() {
-            Some(contract_hash) => Some(*contract_hash),
+            Some(version_info) => Some(version_info.contract_hash),
             None => None,
         }
     }
 
+    /// Writes the contract hash of each version, as serialized in the original package format.
+    fn write_contract_hashes(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        bytesrepr::write_length_prefix(self.versions.len(), buffer);
+        for (version_key, version_info) in &self.versions {
+            buffer.append(&mut version_key.to_bytes()?);
+            buffer.append(&mut version_info.contract_hash.to_bytes()?);
+        }
+        Ok(())
+    }
+
+    /// Writes the provenance of each version, serialized after the lock status in the versioned
+    /// package layout.
+    fn write_provenances(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        bytesrepr::write_length_prefix(self.versions.len(), buffer);
+        for (version_key, version_info) in &self.versions {
+            buffer.append(&mut version_key.to_bytes()?);
+            buffer.append(&mut version_info.provenance().to_bytes()?);
+        }
+        Ok(())
+    }
+
     /// Return the lock status of the contract package.
     pub fn is_locked(&self) -> bool {
         match self.lock_status {
             ContractPackageStatus::Unlocked => false,
-            ContractPackageStatus::Locked => true,
+            ContractPackageStatus::Locked | ContractPackageStatus::Frozen => true,
         }
     }
 
+    /// Returns `true` if the contract package has been locked via [`ContractPackage::lock`].
+    ///
+    /// Unlike packages created locked, the user groups of a frozen package cannot be changed.
+    pub fn is_frozen(&self) -> bool {
+        self.lock_status == ContractPackageStatus::Frozen
+    }
+
+    /// Permanently locks the contract package, preventing new versions and changes to its user
+    /// groups.
+    pub fn lock(&mut self) {
+        self.lock_status = ContractPackageStatus::Frozen;
+    }
+
     /// Return the package status itself
     pub fn get_lock_status(&self) -> ContractPackageStatus {
         self.lock_status.clone()
@@ -797,19 +962,31 @@ impl ToBytes for ContractPackage {
         let mut result = bytesrepr::allocate_buffer(self)?;
 
         result.append(&mut self.access_key.to_bytes()?);
-        result.append(&mut self.versions.to_bytes()?);
+        self.write_contract_hashes(&mut result)?;
         result.append(&mut self.disabled_versions.to_bytes()?);
         result.append(&mut self.groups.to_bytes()?);
+        result.push(CONTRACT_PACKAGE_VERSIONED_LAYOUT_TAG);
+        result.push(CONTRACT_PACKAGE_LAYOUT_VERSION);
         result.append(&mut self.lock_status.to_bytes()?);
+        self.write_provenances(&mut result)?;
 
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
+        let versions_length: usize = self
+            .versions
+            .iter()
+            .map(|(version_key, version_info)| {
+                2 * version_key.serialized_length() + version_info.serialized_length()
+            })
+            .sum();
         self.access_key.serialized_length()
-            + self.versions.serialized_length()
+            + 2 * bytesrepr::length_prefix_serialized_length(self.versions.len())
+            + versions_length
             + self.disabled_versions.serialized_length()
             + self.groups.serialized_length()
+            + 2 * U8_SERIALIZED_LENGTH
             + self.lock_status.serialized_length()
     }
 }
@@ -817,10 +994,39 @@ impl ToBytes for ContractPackage {
 impl FromBytes for ContractPackage {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (access_key, bytes) = URef::from_bytes(bytes)?;
-        let (versions, bytes) = ContractVersions::from_bytes(bytes)?;
+        let (contract_hashes, bytes) =
+            BTreeMap::<ContractVersionKey, ContractHash>::from_bytes(bytes)?;
         let (disabled_versions, bytes) = DisabledVersions::from_bytes(bytes)?;
         let (groups, bytes) = Groups::from_bytes(bytes)?;
-        let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+        let (lock_status, mut provenances, bytes) = match u8::from_bytes(bytes)? {
+            (CONTRACT_PACKAGE_VERSIONED_LAYOUT_TAG, remainder) => {
+                let (version, remainder) = u8::from_bytes(remainder)?;
+                if version != CONTRACT_PACKAGE_LAYOUT_VERSION {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let (lock_status, remainder) = ContractPackageStatus::from_bytes(remainder)?;
+                let (provenances, remainder) =
+                    BTreeMap::<ContractVersionKey, VersionProvenance>::from_bytes(remainder)?;
+                (lock_status, provenances, remainder)
+            }
+            _ => {
+                // Packages stored before the layout was versioned have no version provenance.
+                let (lock_status, remainder) = ContractPackageStatus::from_bytes(bytes)?;
+                (lock_status, BTreeMap::new(), remainder)
+            }
+        };
+        let versions = contract_hashes
+            .into_iter()
+            .map(|(version_key, contract_hash)| {
+                let version_info = match provenances.remove(&version_key) {
+                    Some((created_at, creator, metadata_hash)) => {
+                        ContractVersionInfo::new(contract_hash, created_at, creator, metadata_hash)
+                    }
+                    None => ContractVersionInfo::from(contract_hash),
+                };
+                (version_key, version_info)
+            })
+            .collect();
         let result = ContractPackage {
             access_key,
             versions,
@@ -1113,6 +1319,12 @@ impl FromBytes for EntryPointType {
     }
 }
 
+/// Bit set in the serialized [`EntryPointType`] of a non-reentrant [`EntryPoint`].
+///
+/// Entry points serialized before the flag was introduced never have this bit set, so they are
+/// still read back unchanged.
+const ENTRY_POINT_NON_REENTRANT_FLAG: u8 = 0x80;
+
 /// Default name for an entry point
 pub const DEFAULT_ENTRY_POINT_NAME: &str = "call";
 
@@ -1135,6 +1347,8 @@ pub struct EntryPoint {
     ret: CLType,
     access: EntryPointAccess,
     entry_point_type: EntryPointType,
+    #[serde(default)]
+    non_reentrant: bool,
 }
 
 impl From<EntryPoint> for (String, Parameters, CLType, EntryPointAccess, EntryPointType) {
@@ -1164,9 +1378,19 @@ impl EntryPoint {
             ret,
             access,
             entry_point_type,
+            non_reentrant: false,
         }
     }
 
+    /// Returns `self` marked as non-reentrant if `non_reentrant` is `true`.
+    ///
+    /// A non-reentrant entry point cannot be called while a call to it is already in progress
+    /// further up the call stack, e.g. by a contract it called in turn.
+    pub fn with_non_reentrant(mut self, non_reentrant: bool) -> Self {
+        self.non_reentrant = non_reentrant;
+        self
+    }
+
     /// Create a default [`EntryPoint`] with specified name.
     pub fn default_with_name<T: Into<String>>(name: T) -> Self {
         EntryPoint {
@@ -1199,6 +1423,21 @@ impl EntryPoint {
     pub fn entry_point_type(&self) -> EntryPointType {
         self.entry_point_type
     }
+
+    /// Returns `true` if this entry point must not be re-entered.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.non_reentrant
+    }
+
+    /// Returns `true` if any argument or the return type is declared using the deprecated
+    /// [`CLType::Any`].
+    pub fn uses_any_type(&self) -> bool {
+        self.ret.contains_any()
+            || self
+                .args
+                .iter()
+                .any(|parameter| parameter.cl_type().contains_any())
+    }
 }
 
 impl Default for EntryPoint {
@@ -1210,6 +1449,7 @@ impl Default for EntryPoint {
             ret: CLType::Unit,
             access: EntryPointAccess::Public,
             entry_point_type: EntryPointType::Session,
+            non_reentrant: false,
         }
     }
 }
@@ -1221,7 +1461,13 @@ impl ToBytes for EntryPoint {
         result.append(&mut self.args.to_bytes()?);
         self.ret.append_bytes(&mut result)?;
         result.append(&mut self.access.to_bytes()?);
-        result.append(&mut self.entry_point_type.to_bytes()?);
+        // The non-reentrant flag is packed into the entry point type's tag, as entry points are
+        // nested within other values and so cannot be extended by appending a field.
+        let mut entry_point_type_tag = self.entry_point_type as u8;
+        if self.non_reentrant {
+            entry_point_type_tag |= ENTRY_POINT_NON_REENTRANT_FLAG;
+        }
+        result.append(&mut entry_point_type_tag.to_bytes()?);
 
         Ok(result)
     }
@@ -1241,7 +1487,10 @@ impl FromBytes for EntryPoint {
         let (args, bytes) = Vec::<Parameter>::from_bytes(bytes)?;
         let (ret, bytes) = CLType::from_bytes(bytes)?;
         let (access, bytes) = EntryPointAccess::from_bytes(bytes)?;
-        let (entry_point_type, bytes) = EntryPointType::from_bytes(bytes)?;
+        let (entry_point_type_tag, bytes) = u8::from_bytes(bytes)?;
+        let non_reentrant = entry_point_type_tag & ENTRY_POINT_NON_REENTRANT_FLAG != 0;
+        let (entry_point_type, _) =
+            EntryPointType::from_bytes(&[entry_point_type_tag & !ENTRY_POINT_NON_REENTRANT_FLAG])?;
 
         Ok((
             EntryPoint {
@@ -1250,6 +1499,7 @@ impl FromBytes for EntryPoint {
                 ret,
                 access,
                 entry_point_type,
+                non_reentrant,
             },
             bytes,
         ))
@@ -1431,8 +1681,13 @@ mod tests {
         let _named_keys = NamedKeys::new();
         let protocol_version = ProtocolVersion::V1_0_0;
 
-        contract_package
-            .insert_contract_version(protocol_version.value().major, contract_hash.into());
+        let version_info = ContractVersionInfo::new(
+            contract_hash.into(),
+            BlockTime::new(1_000),
+            AccountHash::new([44; 32]),
+            Some([45; 32]),
+        );
+        contract_package.insert_contract_version(protocol_version.value().major, version_info);
 
         contract_package
     }
@@ -1449,15 +1704,18 @@ mod tests {
         );
         assert_eq!(contract_package.next_contract_version_for(major), 1);
 
-        let next_version = contract_package.insert_contract_version(major, [123; 32].into());
+        let next_version =
+            contract_package.insert_contract_version(major, ContractHash::new([123; 32]).into());
         assert_eq!(next_version, ContractVersionKey::new(major, 1));
         assert_eq!(contract_package.next_contract_version_for(major), 2);
-        let next_version_2 = contract_package.insert_contract_version(major, [124; 32].into());
+        let next_version_2 =
+            contract_package.insert_contract_version(major, ContractHash::new([124; 32]).into());
         assert_eq!(next_version_2, ContractVersionKey::new(major, 2));
 
         let major = 2;
         assert_eq!(contract_package.next_contract_version_for(major), 1);
-        let next_version_3 = contract_package.insert_contract_version(major, [42; 32].into());
+        let next_version_3 =
+            contract_package.insert_contract_version(major, ContractHash::new([42; 32]).into());
         assert_eq!(next_version_3, ContractVersionKey::new(major, 1));
     }
 
@@ -1471,6 +1729,89 @@ mod tests {
         assert_eq!(rem.len(), 0);
     }
 
+    #[test]
+    fn should_deserialize_package_without_version_provenance() {
+        let contract_package = make_contract_package();
+        let mut legacy_bytes = Vec::new();
+        legacy_bytes.append(&mut contract_package.access_key.to_bytes().unwrap());
+        contract_package
+            .write_contract_hashes(&mut legacy_bytes)
+            .unwrap();
+        legacy_bytes.append(&mut contract_package.disabled_versions.to_bytes().unwrap());
+        legacy_bytes.append(&mut contract_package.groups.to_bytes().unwrap());
+        legacy_bytes.append(&mut contract_package.lock_status.to_bytes().unwrap());
+
+        let (decoded_package, rem) =
+            ContractPackage::from_bytes(&legacy_bytes).expect("should deserialize");
+        assert!(rem.is_empty());
+        assert_eq!(decoded_package.groups(), contract_package.groups());
+        assert_eq!(decoded_package.versions().len(), 1);
+
+        let (version_key, version_info) = contract_package.versions().iter().next().unwrap();
+        let decoded_version_info = decoded_package.versions()[version_key];
+        assert_eq!(
+            decoded_version_info,
+            ContractVersionInfo::from(version_info.contract_hash())
+        );
+        assert_eq!(decoded_version_info.created_at(), BlockTime::default());
+        assert_eq!(decoded_version_info.creator(), AccountHash::default());
+        assert_eq!(decoded_version_info.metadata_hash(), None);
+    }
+
+    #[test]
+    fn should_not_deserialize_package_with_unknown_layout_version() {
+        let contract_package = make_contract_package();
+        let mut bytes = contract_package.to_bytes().expect("should serialize");
+        assert_eq!(bytes.len(), contract_package.serialized_length());
+
+        let tag_index = contract_package.access_key.serialized_length()
+            + bytesrepr::length_prefix_serialized_length(contract_package.versions().len())
+            + contract_package
+                .versions()
+                .iter()
+                .map(|(version_key, version_info)| {
+                    version_key.serialized_length()
+                        + version_info.contract_hash().serialized_length()
+                })
+                .sum::<usize>()
+            + contract_package.disabled_versions.serialized_length()
+            + contract_package.groups.serialized_length();
+        assert_eq!(bytes[tag_index], CONTRACT_PACKAGE_VERSIONED_LAYOUT_TAG);
+        bytes[tag_index + 1] = CONTRACT_PACKAGE_LAYOUT_VERSION + 1;
+
+        assert_eq!(
+            ContractPackage::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
+    #[test]
+    fn should_keep_version_provenance() {
+        let contract_package = make_contract_package();
+        let version_info = contract_package.versions().values().next().unwrap();
+        assert_eq!(version_info.contract_hash(), ContractHash::new([42; 32]));
+        assert_eq!(version_info.created_at(), BlockTime::new(1_000));
+        assert_eq!(version_info.creator(), AccountHash::new([44; 32]));
+        assert_eq!(version_info.metadata_hash(), Some([45; 32]));
+        assert_eq!(
+            contract_package.current_contract_hash(),
+            Some(ContractHash::new([42; 32]))
+        );
+    }
+
+    #[test]
+    fn should_set_metadata_hash() {
+        let mut contract_package = make_contract_package();
+        let version_info = contract_package.versions_mut().values_mut().next().unwrap();
+        version_info.set_metadata_hash(Some([46; 32]));
+        assert_eq!(version_info.metadata_hash(), Some([46; 32]));
+
+        let bytes = contract_package.to_bytes().expect("should serialize");
+        let (decoded_package, _) = ContractPackage::from_bytes(&bytes).expect("should deserialize");
+        let decoded_version_info = decoded_package.versions().values().next().unwrap();
+        assert_eq!(decoded_version_info.metadata_hash(), Some([46; 32]));
+    }
+
     #[test]
     fn should_remove_group() {
         let mut contract_package = make_contract_package();
@@ -1491,7 +1832,7 @@ mod tests {
             "should return contract not found error"
         );
 
-        let next_version = contract_package.insert_contract_version(1, CONTRACT_HASH);
+        let next_version = contract_package.insert_contract_version(1, CONTRACT_HASH.into());
         assert!(
             contract_package.is_version_enabled(next_version),
             "version should exist and be enabled"
@@ -1515,6 +1856,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_lock_contract_package() {
+        let mut contract_package = make_contract_package();
+        assert!(!contract_package.is_locked());
+
+        contract_package.lock();
+        assert!(contract_package.is_locked());
+        assert!(contract_package.is_frozen());
+
+        let bytes = contract_package.to_bytes().expect("should serialize");
+        let (decoded, rem) = ContractPackage::from_bytes(&bytes).expect("should deserialize");
+        assert!(rem.is_empty());
+        assert!(decoded.is_locked());
+        assert!(decoded.is_frozen());
+    }
+
+    #[test]
+    fn should_not_freeze_package_created_locked() {
+        let contract_package = ContractPackage::new(
+            URef::new([0; 32], AccessRights::NONE),
+            ContractVersions::default(),
+            DisabledVersions::default(),
+            Groups::default(),
+            ContractPackageStatus::new(true),
+        );
+        assert!(contract_package.is_locked());
+        assert!(!contract_package.is_frozen());
+    }
+
+    #[test]
+    fn should_deserialize_lock_status_written_as_bool() {
+        for (is_locked, expected_status) in &[
+            (false, ContractPackageStatus::Unlocked),
+            (true, ContractPackageStatus::Locked),
+        ] {
+            let bytes = is_locked.to_bytes().unwrap();
+            let (status, rem) = ContractPackageStatus::from_bytes(&bytes).unwrap();
+            assert!(rem.is_empty());
+            assert_eq!(status, *expected_status);
+        }
+
+        bytesrepr::test_serialization_roundtrip(&ContractPackageStatus::Frozen);
+        assert_eq!(
+            ContractPackageStatus::from_bytes(&[3]),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
     #[test]
     fn contract_hash_from_slice() {
         let bytes: Vec<u8> = (0..32).collect();
@@ -1578,6 +1967,48 @@ mod tests {
         assert!(ContractPackageHash::from_formatted_str(invalid_hex).is_err());
     }
 
+    #[test]
+    fn should_serialize_reentrant_entry_point_as_before() {
+        let entry_point = EntryPoint::new(
+            "method",
+            vec![Parameter::new("amount", CLType::U512)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        let mut legacy_bytes = Vec::new();
+        legacy_bytes.append(&mut entry_point.name.to_bytes().unwrap());
+        legacy_bytes.append(&mut entry_point.args.to_bytes().unwrap());
+        entry_point.ret.append_bytes(&mut legacy_bytes).unwrap();
+        legacy_bytes.append(&mut entry_point.access.to_bytes().unwrap());
+        legacy_bytes.append(&mut entry_point.entry_point_type.to_bytes().unwrap());
+
+        assert_eq!(entry_point.to_bytes().unwrap(), legacy_bytes);
+        let decoded: EntryPoint = bytesrepr::deserialize(legacy_bytes).unwrap();
+        assert!(!decoded.is_non_reentrant());
+        assert_eq!(decoded, entry_point);
+    }
+
+    #[test]
+    fn should_roundtrip_non_reentrant_entry_point() {
+        for entry_point_type in [EntryPointType::Session, EntryPointType::Contract].iter() {
+            let entry_point = EntryPoint::new(
+                "method",
+                Vec::new(),
+                CLType::Unit,
+                EntryPointAccess::Public,
+                *entry_point_type,
+            )
+            .with_non_reentrant(true);
+            assert!(entry_point.is_non_reentrant());
+            bytesrepr::test_serialization_roundtrip(&entry_point);
+
+            let json_string = serde_json::to_string(&entry_point).unwrap();
+            let decoded: EntryPoint = serde_json::from_str(&json_string).unwrap();
+            assert_eq!(decoded, entry_point);
+        }
+    }
+
     #[test]
     fn contract_hash_serde_roundtrip() {
         let contract_hash = ContractHash([255; 32]);
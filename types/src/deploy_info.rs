@@ -1,7 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 #[cfg(feature = "std")]
 use schemars::JsonSchema;
@@ -10,9 +10,110 @@ use serde::{Deserialize, Serialize};
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes},
-    DeployHash, TransferAddr, URef, U512,
+    CLValue, ContractHash, DeployHash, HashAddr, TransferAddr, URef, U512,
 };
 
+const MODULE_BYTES_TAG: u8 = 0;
+const STORED_CONTRACT_TAG: u8 = 1;
+const STANDARD_PAYMENT_TAG: u8 = 2;
+const TRANSFER_TAG: u8 = 3;
+
+/// The byte serialized in place of the length of `gas` in a [`DeployInfo`] with a versioned
+/// layout.
+///
+/// `DeployInfo`s serialized before the layout was versioned have the length of a `U512` there,
+/// which is never more than 64, and lack the fields which follow `gas`.
+const DEPLOY_INFO_VERSIONED_LAYOUT_TAG: u8 = u8::max_value();
+/// The current version of the serialized layout of a [`DeployInfo`].
+const DEPLOY_INFO_LAYOUT_VERSION: u8 = 1;
+
+/// The code invoked by a Deploy's session or payment, as resolved by the execution engine.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub enum DeployTarget {
+    /// Wasm module bytes supplied with the Deploy.
+    ModuleBytes {
+        /// Blake2b hash of the module bytes.
+        module_hash: HashAddr,
+    },
+    /// An entry point of a stored contract.
+    ///
+    /// Contracts invoked by name or by contract package are recorded under the hash of the
+    /// contract version which was actually executed.
+    StoredContract {
+        /// Hash of the executed contract.
+        contract_hash: ContractHash,
+        /// Name of the executed entry point.
+        entry_point: String,
+    },
+    /// Standard payment, i.e. empty module bytes executed natively as payment.
+    StandardPayment,
+    /// A native transfer.
+    Transfer,
+}
+
+impl ToBytes for DeployTarget {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            DeployTarget::ModuleBytes { module_hash } => {
+                result.push(MODULE_BYTES_TAG);
+                result.append(&mut module_hash.to_bytes()?);
+            }
+            DeployTarget::StoredContract {
+                contract_hash,
+                entry_point,
+            } => {
+                result.push(STORED_CONTRACT_TAG);
+                result.append(&mut contract_hash.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+            }
+            DeployTarget::StandardPayment => result.push(STANDARD_PAYMENT_TAG),
+            DeployTarget::Transfer => result.push(TRANSFER_TAG),
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        bytesrepr::U8_SERIALIZED_LENGTH
+            + match self {
+                DeployTarget::ModuleBytes { module_hash } => module_hash.serialized_length(),
+                DeployTarget::StoredContract {
+                    contract_hash,
+                    entry_point,
+                } => contract_hash.serialized_length() + entry_point.serialized_length(),
+                DeployTarget::StandardPayment | DeployTarget::Transfer => 0,
+            }
+    }
+}
+
+impl FromBytes for DeployTarget {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            MODULE_BYTES_TAG => {
+                let (module_hash, rem) = HashAddr::from_bytes(rem)?;
+                Ok((DeployTarget::ModuleBytes { module_hash }, rem))
+            }
+            STORED_CONTRACT_TAG => {
+                let (contract_hash, rem) = ContractHash::from_bytes(rem)?;
+                let (entry_point, rem) = String::from_bytes(rem)?;
+                Ok((
+                    DeployTarget::StoredContract {
+                        contract_hash,
+                        entry_point,
+                    },
+                    rem,
+                ))
+            }
+            STANDARD_PAYMENT_TAG => Ok((DeployTarget::StandardPayment, rem)),
+            TRANSFER_TAG => Ok((DeployTarget::Transfer, rem)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
 /// Information relating to the given Deploy.
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -28,16 +129,31 @@ pub struct DeployInfo {
     pub source: URef,
     /// Gas cost of executing the Deploy.
     pub gas: U512,
+    /// User-defined id supplied with a native transfer Deploy.
+    pub id: Option<u64>,
+    /// Value returned via `runtime::ret()` by the Deploy's session code, if any.
+    pub ret: Option<CLValue>,
+    /// The code invoked as the Deploy's session, if recorded.
+    pub session_target: Option<DeployTarget>,
+    /// The code invoked as the Deploy's payment, if recorded.
+    ///
+    /// Native transfers and Deploys executed without fees run no payment code.
+    pub payment_target: Option<DeployTarget>,
 }
 
 impl DeployInfo {
     /// Creates a [`DeployInfo`].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         deploy_hash: DeployHash,
         transfers: &[TransferAddr],
         from: AccountHash,
         source: URef,
         gas: U512,
+        id: Option<u64>,
+        ret: Option<CLValue>,
+        session_target: Option<DeployTarget>,
+        payment_target: Option<DeployTarget>,
     ) -> Self {
         let transfers = transfers.to_vec();
         DeployInfo {
@@ -46,6 +162,10 @@ impl DeployInfo {
             from,
             source,
             gas,
+            id,
+            ret,
+            session_target,
+            payment_target,
         }
     }
 }
@@ -56,7 +176,25 @@ impl FromBytes for DeployInfo {
         let (transfers, rem) = Vec::<TransferAddr>::from_bytes(rem)?;
         let (from, rem) = AccountHash::from_bytes(rem)?;
         let (source, rem) = URef::from_bytes(rem)?;
-        let (gas, rem) = U512::from_bytes(rem)?;
+        let (gas, id, ret, session_target, payment_target, rem) = match u8::from_bytes(rem)? {
+            (DEPLOY_INFO_VERSIONED_LAYOUT_TAG, remainder) => {
+                let (version, remainder) = u8::from_bytes(remainder)?;
+                if version != DEPLOY_INFO_LAYOUT_VERSION {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let (gas, remainder) = U512::from_bytes(remainder)?;
+                let (id, remainder) = <Option<u64>>::from_bytes(remainder)?;
+                let (ret, remainder) = <Option<CLValue>>::from_bytes(remainder)?;
+                let (session_target, remainder) = <Option<DeployTarget>>::from_bytes(remainder)?;
+                let (payment_target, remainder) = <Option<DeployTarget>>::from_bytes(remainder)?;
+                (gas, id, ret, session_target, payment_target, remainder)
+            }
+            _ => {
+                // `DeployInfo`s stored before the layout was versioned end with `gas`.
+                let (gas, remainder) = U512::from_bytes(rem)?;
+                (gas, None, None, None, None, remainder)
+            }
+        };
         Ok((
             DeployInfo {
                 deploy_hash,
@@ -64,6 +202,10 @@ impl FromBytes for DeployInfo {
                 from,
                 source,
                 gas,
+                id,
+                ret,
+                session_target,
+                payment_target,
             },
             rem,
         ))
@@ -77,7 +219,13 @@ impl ToBytes for DeployInfo {
         result.append(&mut self.transfers.to_bytes()?);
         result.append(&mut self.from.to_bytes()?);
         result.append(&mut self.source.to_bytes()?);
+        result.push(DEPLOY_INFO_VERSIONED_LAYOUT_TAG);
+        result.push(DEPLOY_INFO_LAYOUT_VERSION);
         result.append(&mut self.gas.to_bytes()?);
+        result.append(&mut self.id.to_bytes()?);
+        result.append(&mut self.ret.to_bytes()?);
+        result.append(&mut self.session_target.to_bytes()?);
+        result.append(&mut self.payment_target.to_bytes()?);
         Ok(result)
     }
 
@@ -86,25 +234,31 @@ impl ToBytes for DeployInfo {
             + self.transfers.serialized_length()
             + self.from.serialized_length()
             + self.source.serialized_length()
+            + 2 * bytesrepr::U8_SERIALIZED_LENGTH
             + self.gas.serialized_length()
+            + self.id.serialized_length()
+            + self.ret.serialized_length()
+            + self.session_target.serialized_length()
+            + self.payment_target.serialized_length()
     }
 }
 
 /// Generators for a [`Deploy`]
 #[cfg(any(feature = "gens", test))]
 pub(crate) mod gens {
-    use alloc::vec::Vec;
+    use alloc::{string::String, vec::Vec};
 
     use proptest::{
         array,
         collection::{self, SizeRange},
-        prelude::{Arbitrary, Strategy},
+        option,
+        prelude::{any, prop_oneof, Arbitrary, Just, Strategy},
     };
 
     use crate::{
         account::AccountHash,
-        gens::{u512_arb, uref_arb},
-        DeployHash, DeployInfo, TransferAddr,
+        gens::{cl_value_arb, u512_arb, uref_arb},
+        ContractHash, DeployHash, DeployInfo, DeployTarget, TransferAddr,
     };
 
     pub fn deploy_hash_arb() -> impl Strategy<Value = DeployHash> {
@@ -123,6 +277,21 @@ pub(crate) mod gens {
         array::uniform32(<u8>::arbitrary()).prop_map(AccountHash::new)
     }
 
+    pub fn deploy_target_arb() -> impl Strategy<Value = DeployTarget> {
+        prop_oneof![
+            array::uniform32(<u8>::arbitrary())
+                .prop_map(|module_hash| DeployTarget::ModuleBytes { module_hash }),
+            (array::uniform32(<u8>::arbitrary()), any::<String>()).prop_map(
+                |(contract_hash, entry_point)| DeployTarget::StoredContract {
+                    contract_hash: ContractHash::new(contract_hash),
+                    entry_point,
+                }
+            ),
+            Just(DeployTarget::StandardPayment),
+            Just(DeployTarget::Transfer),
+        ]
+    }
+
     /// Creates an arbitrary [`Deploy`]
     pub fn deploy_info_arb() -> impl Strategy<Value = DeployInfo> {
         let transfers_length_range = 0..5;
@@ -132,29 +301,135 @@ pub(crate) mod gens {
             account_hash_arb(),
             uref_arb(),
             u512_arb(),
+            option::of(<u64>::arbitrary()),
+            option::of(cl_value_arb()),
+            option::of(deploy_target_arb()),
+            option::of(deploy_target_arb()),
         )
-            .prop_map(|(deploy_hash, transfers, from, source, gas)| DeployInfo {
-                deploy_hash,
-                transfers,
-                from,
-                source,
-                gas,
-            })
+            .prop_map(
+                |(
+                    deploy_hash,
+                    transfers,
+                    from,
+                    source,
+                    gas,
+                    id,
+                    ret,
+                    session_target,
+                    payment_target,
+                )| {
+                    DeployInfo {
+                        deploy_hash,
+                        transfers,
+                        from,
+                        source,
+                        gas,
+                        id,
+                        ret,
+                        session_target,
+                        payment_target,
+                    }
+                },
+            )
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use proptest::prelude::*;
 
-    use crate::bytesrepr;
+    use crate::bytesrepr::{self, FromBytes, ToBytes};
+
+    use super::{gens, DeployInfo};
+
+    /// Returns the length of the fields serialized before the layout tag.
+    fn unversioned_prefix_length(deploy_info: &DeployInfo) -> usize {
+        deploy_info.deploy_hash.serialized_length()
+            + deploy_info.transfers.serialized_length()
+            + deploy_info.from.serialized_length()
+            + deploy_info.source.serialized_length()
+    }
+
+    /// Serializes `deploy_info` as it was before the layout was versioned.
+    fn legacy_bytes(deploy_info: &DeployInfo) -> Vec<u8> {
+        let mut result = deploy_info.deploy_hash.to_bytes().unwrap();
+        result.append(&mut deploy_info.transfers.to_bytes().unwrap());
+        result.append(&mut deploy_info.from.to_bytes().unwrap());
+        result.append(&mut deploy_info.source.to_bytes().unwrap());
+        result.append(&mut deploy_info.gas.to_bytes().unwrap());
+        result
+    }
 
-    use super::gens;
+    fn assert_legacy_fields(deserialized: &DeployInfo, deploy_info: &DeployInfo) {
+        assert_eq!(deserialized.deploy_hash, deploy_info.deploy_hash);
+        assert_eq!(deserialized.transfers, deploy_info.transfers);
+        assert_eq!(deserialized.from, deploy_info.from);
+        assert_eq!(deserialized.source, deploy_info.source);
+        assert_eq!(deserialized.gas, deploy_info.gas);
+        assert_eq!(deserialized.id, None);
+        assert_eq!(deserialized.ret, None);
+        assert_eq!(deserialized.session_target, None);
+        assert_eq!(deserialized.payment_target, None);
+    }
 
     proptest! {
         #[test]
         fn test_serialization_roundtrip(deploy_info in gens::deploy_info_arb()) {
             bytesrepr::test_serialization_roundtrip(&deploy_info)
         }
+
+        #[test]
+        fn should_deserialize_legacy_deploy_info(deploy_info in gens::deploy_info_arb()) {
+            let deserialized: DeployInfo =
+                bytesrepr::deserialize(legacy_bytes(&deploy_info)).unwrap();
+            assert_legacy_fields(&deserialized, &deploy_info);
+        }
+
+        #[test]
+        fn should_deserialize_legacy_deploy_info_followed_by_other_bytes(
+            deploy_info in gens::deploy_info_arb(),
+            trailing_bytes in any::<Vec<u8>>(),
+        ) {
+            let mut bytes = legacy_bytes(&deploy_info);
+            bytes.extend_from_slice(&trailing_bytes);
+
+            let (deserialized, remainder) = DeployInfo::from_bytes(&bytes).unwrap();
+            assert_legacy_fields(&deserialized, &deploy_info);
+            assert_eq!(remainder, trailing_bytes.as_slice());
+        }
+
+        #[test]
+        fn should_deserialize_list_of_legacy_deploy_infos(
+            deploy_infos in proptest::collection::vec(gens::deploy_info_arb(), 1..4),
+        ) {
+            let mut bytes = (deploy_infos.len() as u32).to_bytes().unwrap();
+            for deploy_info in &deploy_infos {
+                bytes.append(&mut legacy_bytes(deploy_info));
+            }
+
+            let deserialized: Vec<DeployInfo> = bytesrepr::deserialize(bytes).unwrap();
+            assert_eq!(deserialized.len(), deploy_infos.len());
+            for (deserialized, deploy_info) in deserialized.iter().zip(&deploy_infos) {
+                assert_legacy_fields(deserialized, deploy_info);
+            }
+        }
+
+        #[test]
+        fn should_not_deserialize_unknown_layout_version(deploy_info in gens::deploy_info_arb()) {
+            let mut bytes = deploy_info.to_bytes().unwrap();
+            let tag_index = unversioned_prefix_length(&deploy_info);
+            assert_eq!(bytes[tag_index], super::DEPLOY_INFO_VERSIONED_LAYOUT_TAG);
+            bytes[tag_index + 1] = super::DEPLOY_INFO_LAYOUT_VERSION + 1;
+
+            let result: Result<DeployInfo, _> = bytesrepr::deserialize(bytes);
+            assert_eq!(result, Err(bytesrepr::Error::Formatting));
+        }
+
+        #[test]
+        fn test_deploy_target_serialization_roundtrip(target in gens::deploy_target_arb()) {
+            bytesrepr::test_serialization_roundtrip(&target)
+        }
     }
 }
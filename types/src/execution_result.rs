@@ -14,6 +14,7 @@ use alloc::{
     vec,
     vec::Vec,
 };
+use core::convert::TryFrom;
 
 #[cfg(feature = "std")]
 use once_cell::sync::Lazy;
@@ -31,14 +32,34 @@ use crate::KEY_HASH_LENGTH;
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    system::auction::{Bid, EraInfo, UnbondingPurse},
-    CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
+    system::{
+        self,
+        auction::{Bid, EraInfo, UnbondingPurse},
+        SystemContractType,
+    },
+    ApiError, CLValue, DeployInfo, Key, NamedKey, Transfer, TransferAddr, U128, U256, U512,
 };
 
 /// Constants to track ExecutionResult serialization.
 const EXECUTION_RESULT_FAILURE_TAG: u8 = 0;
 const EXECUTION_RESULT_SUCCESS_TAG: u8 = 1;
 
+/// The leading byte of an `ExecutionResult` serialized with a versioned layout.
+///
+/// Results serialized before the layout was versioned start with one of the variant tags above
+/// and lack the structured error, the events and the fee breakdown.
+const EXECUTION_RESULT_VERSIONED_LAYOUT_TAG: u8 = u8::max_value();
+/// The current version of the serialized layout of an `ExecutionResult`.
+const EXECUTION_RESULT_LAYOUT_VERSION: u8 = 1;
+
+/// Constants to track ExecutionError serialization.
+const EXECUTION_ERROR_API_ERROR_TAG: u8 = 0;
+const EXECUTION_ERROR_SYSTEM_CONTRACT_TAG: u8 = 1;
+const EXECUTION_ERROR_OUT_OF_GAS_TAG: u8 = 2;
+const EXECUTION_ERROR_MISSING_ENTRY_POINT_TAG: u8 = 3;
+const EXECUTION_ERROR_TYPE_MISMATCH_TAG: u8 = 4;
+const EXECUTION_ERROR_OTHER_TAG: u8 = 5;
+
 /// Constants to track operation serialization.
 const OP_READ_TAG: u8 = 0;
 const OP_WRITE_TAG: u8 = 1;
@@ -99,10 +120,24 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         TransferAddr::new([130; KEY_HASH_LENGTH]),
     ];
 
+    let events = vec![ContractEvent {
+        emitter: Key::Hash([44; KEY_HASH_LENGTH]),
+        payload: CLValue::from_t("transferred".to_string()).unwrap(),
+    }];
+
+    let fee_breakdown = FeeBreakdown {
+        payment_amount: U512::from(200_000),
+        gas_used: U512::from(123_456),
+        refund: U512::zero(),
+        proposer_reward: U512::from(200_000),
+    };
+
     ExecutionResult::Success {
         effect,
         transfers,
         cost: U512::from(123_456),
+        events,
+        fee_breakdown: Some(fee_breakdown),
     }
 });
 
@@ -121,6 +156,10 @@ pub enum ExecutionResult {
         cost: U512,
         /// The error message associated with executing the deploy.
         error_message: String,
+        /// The structured cause of the failure.
+        error: ExecutionError,
+        /// How the payment for the deploy was settled, if it was settled.
+        fee_breakdown: Option<FeeBreakdown>,
     },
     /// The result of a successful execution.
     Success {
@@ -130,6 +169,10 @@ pub enum ExecutionResult {
         transfers: Vec<TransferAddr>,
         /// The cost of executing the deploy.
         cost: U512,
+        /// The events emitted by contracts while executing the deploy, in order of emission.
+        events: Vec<ContractEvent>,
+        /// How the payment for the deploy was settled, if it was settled.
+        fee_breakdown: Option<FeeBreakdown>,
     },
 }
 
@@ -182,12 +225,16 @@ impl Distribution<ExecutionResult> for Standard {
                 transfers,
                 cost: rng.gen::<u64>().into(),
                 error_message: format!("Error message {}", rng.gen::<u64>()),
+                error: rng.gen(),
+                fee_breakdown: if rng.gen() { Some(rng.gen()) } else { None },
             }
         } else {
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
+                events: (0..rng.gen_range(0..4)).map(|_| rng.gen()).collect(),
+                fee_breakdown: if rng.gen() { Some(rng.gen()) } else { None },
             }
         }
     }
@@ -196,55 +243,73 @@ impl Distribution<ExecutionResult> for Standard {
 impl ToBytes for ExecutionResult {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.push(EXECUTION_RESULT_VERSIONED_LAYOUT_TAG);
+        buffer.push(EXECUTION_RESULT_LAYOUT_VERSION);
         match self {
             ExecutionResult::Failure {
                 effect,
                 transfers,
                 cost,
                 error_message,
+                error,
+                fee_breakdown,
             } => {
                 buffer.push(EXECUTION_RESULT_FAILURE_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
+                buffer.extend(error.to_bytes()?);
+                buffer.extend(fee_breakdown.to_bytes()?);
             }
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                events,
+                fee_breakdown,
             } => {
                 buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
+                buffer.extend(events.to_bytes()?);
+                buffer.extend(fee_breakdown.to_bytes()?);
             }
         }
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        U8_SERIALIZED_LENGTH
+        3 * U8_SERIALIZED_LENGTH
             + match self {
                 ExecutionResult::Failure {
                     effect,
                     transfers,
                     cost,
                     error_message,
+                    error,
+                    fee_breakdown,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
                         + error_message.serialized_length()
+                        + error.serialized_length()
+                        + fee_breakdown.serialized_length()
                 }
                 ExecutionResult::Success {
                     effect,
                     transfers,
                     cost,
+                    events,
+                    fee_breakdown,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
+                        + events.serialized_length()
+                        + fee_breakdown.serialized_length()
                 }
             }
     }
@@ -253,17 +318,29 @@ impl ToBytes for ExecutionResult {
 impl FromBytes for ExecutionResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (tag, remainder) = u8::from_bytes(bytes)?;
+        if tag != EXECUTION_RESULT_VERSIONED_LAYOUT_TAG {
+            return legacy_execution_result_from_bytes(bytes);
+        }
+        let (version, remainder) = u8::from_bytes(remainder)?;
+        if version != EXECUTION_RESULT_LAYOUT_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (tag, remainder) = u8::from_bytes(remainder)?;
         match tag {
             EXECUTION_RESULT_FAILURE_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let (error_message, remainder) = String::from_bytes(remainder)?;
+                let (error, remainder) = ExecutionError::from_bytes(remainder)?;
+                let (fee_breakdown, remainder) = Option::<FeeBreakdown>::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Failure {
                     effect,
                     transfers,
                     cost,
                     error_message,
+                    error,
+                    fee_breakdown,
                 };
                 Ok((execution_result, remainder))
             }
@@ -271,10 +348,14 @@ impl FromBytes for ExecutionResult {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
+                let (events, remainder) = Vec::<ContractEvent>::from_bytes(remainder)?;
+                let (fee_breakdown, remainder) = Option::<FeeBreakdown>::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Success {
                     effect,
                     transfers,
                     cost,
+                    events,
+                    fee_breakdown,
                 };
                 Ok((execution_result, remainder))
             }
@@ -283,6 +364,397 @@ impl FromBytes for ExecutionResult {
     }
 }
 
+/// Deserializes an `ExecutionResult` serialized before its layout was versioned.
+fn legacy_execution_result_from_bytes(
+    bytes: &[u8],
+) -> Result<(ExecutionResult, &[u8]), bytesrepr::Error> {
+    let (tag, remainder) = u8::from_bytes(bytes)?;
+    match tag {
+        EXECUTION_RESULT_FAILURE_TAG => {
+            let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
+            let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
+            let (cost, remainder) = U512::from_bytes(remainder)?;
+            let (error_message, remainder) = String::from_bytes(remainder)?;
+            let execution_result = ExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+                error: ExecutionError::Other,
+                fee_breakdown: None,
+            };
+            Ok((execution_result, remainder))
+        }
+        EXECUTION_RESULT_SUCCESS_TAG => {
+            let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
+            let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
+            let (cost, remainder) = U512::from_bytes(remainder)?;
+            let execution_result = ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                events: Vec::new(),
+                fee_breakdown: None,
+            };
+            Ok((execution_result, remainder))
+        }
+        _ => Err(bytesrepr::Error::Formatting),
+    }
+}
+
+/// The structured cause of a failed deploy execution.
+///
+/// Unlike the free-form `error_message` of [`ExecutionResult::Failure`], this is stable and
+/// intended to be matched on by clients.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub enum ExecutionError {
+    /// Execution was reverted with the given [`ApiError`] code, e.g. by a call to
+    /// `runtime::revert`.
+    ApiError(u32),
+    /// A system contract returned an error.
+    SystemContract {
+        /// The system contract which failed.
+        contract: SystemContractType,
+        /// The contract-specific error code.
+        code: u8,
+    },
+    /// The deploy ran out of gas.
+    OutOfGas {
+        /// The gas used at each of the most recent host function calls, oldest first.  The last
+        /// checkpoint is the call during which gas ran out, unless it ran out in Wasm code.
+        checkpoints: Vec<GasCheckpoint>,
+        /// The name of the last host function call which completed successfully, if any.
+        last_host_operation: Option<String>,
+    },
+    /// The called entry point does not exist.
+    MissingEntryPoint(String),
+    /// A value had a different type than expected.
+    TypeMismatch {
+        /// The expected type.
+        expected: String,
+        /// The actual type.
+        found: String,
+    },
+    /// Any other failure; `error_message` holds the details.
+    Other,
+}
+
+impl From<ApiError> for ExecutionError {
+    fn from(api_error: ApiError) -> Self {
+        match api_error {
+            ApiError::Mint(code) => ExecutionError::SystemContract {
+                contract: SystemContractType::Mint,
+                code,
+            },
+            ApiError::HandlePayment(code) => ExecutionError::SystemContract {
+                contract: SystemContractType::HandlePayment,
+                code,
+            },
+            ApiError::AuctionError(code) => ExecutionError::SystemContract {
+                contract: SystemContractType::Auction,
+                code,
+            },
+            _ => ExecutionError::ApiError(api_error.into()),
+        }
+    }
+}
+
+impl From<system::Error> for ExecutionError {
+    fn from(error: system::Error) -> Self {
+        let api_error = match error {
+            system::Error::Mint(error) => ApiError::from(error),
+            system::Error::HandlePayment(error) => ApiError::from(error),
+            system::Error::Auction(error) => ApiError::from(error),
+        };
+        api_error.into()
+    }
+}
+
+impl Distribution<ExecutionError> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ExecutionError {
+        match rng.gen_range(0..6) {
+            0 => ExecutionError::ApiError(rng.gen()),
+            1 => ExecutionError::SystemContract {
+                contract: *[
+                    SystemContractType::Mint,
+                    SystemContractType::HandlePayment,
+                    SystemContractType::StandardPayment,
+                    SystemContractType::Auction,
+                ]
+                .choose(rng)
+                .unwrap(),
+                code: rng.gen(),
+            },
+            2 => ExecutionError::OutOfGas {
+                checkpoints: (0..rng.gen_range(0..4)).map(|_| rng.gen()).collect(),
+                last_host_operation: if rng.gen() {
+                    Some(format!("host_function_{}", rng.gen::<u64>()))
+                } else {
+                    None
+                },
+            },
+            3 => ExecutionError::MissingEntryPoint(format!("entry_point_{}", rng.gen::<u64>())),
+            4 => ExecutionError::TypeMismatch {
+                expected: format!("Type {}", rng.gen::<u64>()),
+                found: format!("Type {}", rng.gen::<u64>()),
+            },
+            _ => ExecutionError::Other,
+        }
+    }
+}
+
+impl ToBytes for ExecutionError {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            ExecutionError::ApiError(code) => {
+                buffer.push(EXECUTION_ERROR_API_ERROR_TAG);
+                buffer.extend(code.to_bytes()?);
+            }
+            ExecutionError::SystemContract { contract, code } => {
+                buffer.push(EXECUTION_ERROR_SYSTEM_CONTRACT_TAG);
+                buffer.extend(u32::from(*contract).to_bytes()?);
+                buffer.extend(code.to_bytes()?);
+            }
+            ExecutionError::OutOfGas {
+                checkpoints,
+                last_host_operation,
+            } => {
+                buffer.push(EXECUTION_ERROR_OUT_OF_GAS_TAG);
+                buffer.extend(checkpoints.to_bytes()?);
+                buffer.extend(last_host_operation.to_bytes()?);
+            }
+            ExecutionError::MissingEntryPoint(name) => {
+                buffer.push(EXECUTION_ERROR_MISSING_ENTRY_POINT_TAG);
+                buffer.extend(name.to_bytes()?);
+            }
+            ExecutionError::TypeMismatch { expected, found } => {
+                buffer.push(EXECUTION_ERROR_TYPE_MISMATCH_TAG);
+                buffer.extend(expected.to_bytes()?);
+                buffer.extend(found.to_bytes()?);
+            }
+            ExecutionError::Other => buffer.push(EXECUTION_ERROR_OTHER_TAG),
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                ExecutionError::ApiError(code) => code.serialized_length(),
+                ExecutionError::SystemContract { contract, code } => {
+                    u32::from(*contract).serialized_length() + code.serialized_length()
+                }
+                ExecutionError::OutOfGas {
+                    checkpoints,
+                    last_host_operation,
+                } => checkpoints.serialized_length() + last_host_operation.serialized_length(),
+                ExecutionError::Other => 0,
+                ExecutionError::MissingEntryPoint(name) => name.serialized_length(),
+                ExecutionError::TypeMismatch { expected, found } => {
+                    expected.serialized_length() + found.serialized_length()
+                }
+            }
+    }
+}
+
+impl FromBytes for ExecutionError {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            EXECUTION_ERROR_API_ERROR_TAG => {
+                let (code, remainder) = u32::from_bytes(remainder)?;
+                Ok((ExecutionError::ApiError(code), remainder))
+            }
+            EXECUTION_ERROR_SYSTEM_CONTRACT_TAG => {
+                let (contract, remainder) = u32::from_bytes(remainder)?;
+                let contract = SystemContractType::try_from(contract)
+                    .map_err(|_| bytesrepr::Error::Formatting)?;
+                let (code, remainder) = u8::from_bytes(remainder)?;
+                Ok((ExecutionError::SystemContract { contract, code }, remainder))
+            }
+            EXECUTION_ERROR_OUT_OF_GAS_TAG => {
+                let (checkpoints, remainder) = Vec::<GasCheckpoint>::from_bytes(remainder)?;
+                let (last_host_operation, remainder) = Option::<String>::from_bytes(remainder)?;
+                let out_of_gas = ExecutionError::OutOfGas {
+                    checkpoints,
+                    last_host_operation,
+                };
+                Ok((out_of_gas, remainder))
+            }
+            EXECUTION_ERROR_MISSING_ENTRY_POINT_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                Ok((ExecutionError::MissingEntryPoint(name), remainder))
+            }
+            EXECUTION_ERROR_TYPE_MISMATCH_TAG => {
+                let (expected, remainder) = String::from_bytes(remainder)?;
+                let (found, remainder) = String::from_bytes(remainder)?;
+                Ok((ExecutionError::TypeMismatch { expected, found }, remainder))
+            }
+            EXECUTION_ERROR_OTHER_TAG => Ok((ExecutionError::Other, remainder)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// The gas used by a deploy at the point of calling a host function.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct GasCheckpoint {
+    /// The name of the host function called.
+    pub host_function: String,
+    /// The gas used before the call was charged for.
+    pub gas_used: U512,
+}
+
+impl Distribution<GasCheckpoint> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GasCheckpoint {
+        GasCheckpoint {
+            host_function: format!("host_function_{}", rng.gen::<u64>()),
+            gas_used: U512::from(rng.gen::<u64>()),
+        }
+    }
+}
+
+impl ToBytes for GasCheckpoint {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.host_function.to_bytes()?);
+        buffer.extend(self.gas_used.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.host_function.serialized_length() + self.gas_used.serialized_length()
+    }
+}
+
+impl FromBytes for GasCheckpoint {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (host_function, remainder) = String::from_bytes(bytes)?;
+        let (gas_used, remainder) = U512::from_bytes(remainder)?;
+        let gas_checkpoint = GasCheckpoint {
+            host_function,
+            gas_used,
+        };
+        Ok((gas_checkpoint, remainder))
+    }
+}
+
+/// A structured event emitted by a contract while executing a deploy.
+///
+/// Events are not written to global state; they are only recorded in the [`ExecutionResult`] of
+/// a successful deploy.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractEvent {
+    /// The key of the account or contract which emitted the event.
+    #[cfg_attr(feature = "std", schemars(with = "String"))]
+    pub emitter: Key,
+    /// The payload of the event.
+    pub payload: CLValue,
+}
+
+impl Distribution<ContractEvent> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ContractEvent {
+        ContractEvent {
+            emitter: rng.gen(),
+            payload: CLValue::from_t(rng.gen::<u64>()).unwrap(),
+        }
+    }
+}
+
+impl ToBytes for ContractEvent {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.emitter.to_bytes()?);
+        buffer.extend(self.payload.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.emitter.serialized_length() + self.payload.serialized_length()
+    }
+}
+
+impl FromBytes for ContractEvent {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (emitter, remainder) = Key::from_bytes(bytes)?;
+        let (payload, remainder) = CLValue::from_bytes(remainder)?;
+        let contract_event = ContractEvent { emitter, payload };
+        Ok((contract_event, remainder))
+    }
+}
+
+/// How the payment for a deploy was settled once it had been executed.
+///
+/// The motes in the deploy's payment purse are split between a refund to the payer and a reward
+/// to the proposer of the block containing the deploy.
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct FeeBreakdown {
+    /// The motes in the payment purse when the payment was settled.
+    pub payment_amount: U512,
+    /// The gas used by the payment and session code.
+    pub gas_used: U512,
+    /// The motes refunded to the payer.
+    pub refund: U512,
+    /// The motes paid to the block proposer.
+    pub proposer_reward: U512,
+}
+
+impl Distribution<FeeBreakdown> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FeeBreakdown {
+        let refund = U512::from(rng.gen::<u64>());
+        let proposer_reward = U512::from(rng.gen::<u64>());
+        FeeBreakdown {
+            payment_amount: refund + proposer_reward,
+            gas_used: U512::from(rng.gen::<u64>()),
+            refund,
+            proposer_reward,
+        }
+    }
+}
+
+impl ToBytes for FeeBreakdown {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.payment_amount.to_bytes()?);
+        buffer.extend(self.gas_used.to_bytes()?);
+        buffer.extend(self.refund.to_bytes()?);
+        buffer.extend(self.proposer_reward.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.payment_amount.serialized_length()
+            + self.gas_used.serialized_length()
+            + self.refund.serialized_length()
+            + self.proposer_reward.serialized_length()
+    }
+}
+
+impl FromBytes for FeeBreakdown {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (payment_amount, remainder) = U512::from_bytes(bytes)?;
+        let (gas_used, remainder) = U512::from_bytes(remainder)?;
+        let (refund, remainder) = U512::from_bytes(remainder)?;
+        let (proposer_reward, remainder) = U512::from_bytes(remainder)?;
+        let fee_breakdown = FeeBreakdown {
+            payment_amount,
+            gas_used,
+            refund,
+            proposer_reward,
+        };
+        Ok((fee_breakdown, remainder))
+    }
+}
+
 /// The effect of executing a single deploy.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Default, Debug)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -679,4 +1151,96 @@ mod tests {
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    #[test]
+    fn should_deserialize_execution_result_written_before_layout_was_versioned() {
+        let effect = ExecutionEffect::default();
+        let transfers = vec![TransferAddr::new([1; 32])];
+        let cost = U512::from(100);
+
+        let mut legacy_success = vec![EXECUTION_RESULT_SUCCESS_TAG];
+        legacy_success.extend(effect.to_bytes().unwrap());
+        legacy_success.extend(transfers.to_bytes().unwrap());
+        legacy_success.extend(cost.to_bytes().unwrap());
+        let expected_success = ExecutionResult::Success {
+            effect: effect.clone(),
+            transfers: transfers.clone(),
+            cost,
+            events: Vec::new(),
+            fee_breakdown: None,
+        };
+        assert_eq!(
+            bytesrepr::deserialize::<ExecutionResult>(legacy_success),
+            Ok(expected_success)
+        );
+
+        let error_message = "error".to_string();
+        let mut legacy_failure = vec![EXECUTION_RESULT_FAILURE_TAG];
+        legacy_failure.extend(effect.to_bytes().unwrap());
+        legacy_failure.extend(transfers.to_bytes().unwrap());
+        legacy_failure.extend(cost.to_bytes().unwrap());
+        legacy_failure.extend(error_message.to_bytes().unwrap());
+        let expected_failure = ExecutionResult::Failure {
+            effect,
+            transfers,
+            cost,
+            error_message,
+            error: ExecutionError::Other,
+            fee_breakdown: None,
+        };
+        assert_eq!(
+            bytesrepr::deserialize::<ExecutionResult>(legacy_failure),
+            Ok(expected_failure)
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_execution_result_with_unknown_layout_version() {
+        let mut rng = get_rng();
+        let execution_result: ExecutionResult = rng.gen();
+        let mut bytes = execution_result.to_bytes().unwrap();
+        bytes[1] = EXECUTION_RESULT_LAYOUT_VERSION + 1;
+        assert_eq!(
+            bytesrepr::deserialize::<ExecutionResult>(bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn bytesrepr_test_execution_error() {
+        let mut rng = get_rng();
+        for _ in 0..10 {
+            let execution_error: ExecutionError = rng.gen();
+            bytesrepr::test_serialization_roundtrip(&execution_error);
+        }
+    }
+
+    #[test]
+    fn bytesrepr_test_contract_event() {
+        let mut rng = get_rng();
+        let contract_event: ContractEvent = rng.gen();
+        bytesrepr::test_serialization_roundtrip(&contract_event);
+    }
+
+    #[test]
+    fn bytesrepr_test_fee_breakdown() {
+        let mut rng = get_rng();
+        let fee_breakdown: FeeBreakdown = rng.gen();
+        bytesrepr::test_serialization_roundtrip(&fee_breakdown);
+    }
+
+    #[test]
+    fn should_classify_system_contract_api_errors() {
+        assert_eq!(
+            ExecutionError::from(ApiError::AuctionError(3)),
+            ExecutionError::SystemContract {
+                contract: SystemContractType::Auction,
+                code: 3,
+            }
+        );
+        assert_eq!(
+            ExecutionError::from(ApiError::User(1)),
+            ExecutionError::ApiError(u32::from(ApiError::User(1)))
+        );
+    }
 }
@@ -15,15 +15,19 @@ use proptest::{
 use crate::{
     account::{AccountHash, Weight},
     contracts::{
-        ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters,
+        ContractPackageStatus, ContractVersionInfo, ContractVersions, DisabledVersions, Groups,
+        NamedKeys, Parameters,
     },
-    AccessRights, CLType, CLValue, Contract, ContractHash, ContractPackage, ContractVersionKey,
-    ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, NamedArg,
-    Parameter, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    AccessRights, BlockTime, CLType, CLValue, Contract, ContractHash, ContractPackage,
+    ContractVersionKey, ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Group, Key, NamedArg, Parameter, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
 };
 
 use crate::deploy_info::gens::{deploy_hash_arb, transfer_addr_arb};
-pub use crate::{deploy_info::gens::deploy_info_arb, transfer::gens::transfer_arb};
+pub use crate::{
+    deploy_info::gens::deploy_info_arb,
+    transfer::gens::{transfer_arb, transfer_receipt_arb},
+};
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
     vec(any::<u8>(), 32).prop_map(|b| {
@@ -74,6 +78,7 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
         uref_arb().prop_map(|uref| Key::Balance(uref.addr())),
         account_hash_arb().prop_map(Key::Bid),
         account_hash_arb().prop_map(Key::Withdraw),
+        u8_slice_32().prop_map(Key::Allowance),
     ]
 }
 
@@ -266,10 +271,12 @@ pub fn entry_point_arb() -> impl Strategy<Value = EntryPoint> {
         entry_point_type_arb(),
         entry_point_access_arb(),
         cl_type_arb(),
+        any::<bool>(),
     )
         .prop_map(
-            |(name, parameters, entry_point_type, entry_point_access, ret)| {
+            |(name, parameters, entry_point_type, entry_point_access, ret, non_reentrant)| {
                 EntryPoint::new(name, parameters, ret, entry_point_access, entry_point_type)
+                    .with_non_reentrant(non_reentrant)
             },
         )
 }
@@ -314,10 +321,27 @@ pub fn contract_version_key_arb() -> impl Strategy<Value = ContractVersionKey> {
         .prop_map(|(major, contract_ver)| ContractVersionKey::new(major, contract_ver))
 }
 
+pub fn contract_version_info_arb() -> impl Strategy<Value = ContractVersionInfo> {
+    (
+        u8_slice_32(),
+        any::<u64>(),
+        account_hash_arb(),
+        option::of(u8_slice_32()),
+    )
+        .prop_map(|(contract_hash, created_at, creator, metadata_hash)| {
+            ContractVersionInfo::new(
+                ContractHash::new(contract_hash),
+                BlockTime::new(created_at),
+                creator,
+                metadata_hash,
+            )
+        })
+}
+
 pub fn contract_versions_arb() -> impl Strategy<Value = ContractVersions> {
     btree_map(
         contract_version_key_arb(),
-        u8_slice_32().prop_map(ContractHash::new),
+        contract_version_info_arb(),
         1..5,
     )
 }
@@ -0,0 +1,127 @@
+//! Blake2b hashing helpers, usable both within contracts and off-chain.
+//!
+//! These are implemented in pure Rust, so a contract can derive keys itself rather than calling
+//! into the host, and will get exactly the same result as off-chain code deriving the same keys.
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+
+use crate::{HashAddr, BLAKE2B_DIGEST_LENGTH};
+
+/// Returns the 256-bit Blake2b digest of `data`.
+///
+/// This is the same hash function as used by the host, e.g. to derive an
+/// [`AccountHash`](crate::account::AccountHash) from a [`PublicKey`](crate::PublicKey).
+pub fn blake2b256<T: AsRef<[u8]>>(data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let mut hasher = Blake2bHasher::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// An incremental 256-bit Blake2b hasher.
+///
+/// Hashing data in several calls to [`Blake2bHasher::update`] gives the same digest as hashing the
+/// concatenation of that data with [`blake2b256`].
+#[derive(Clone)]
+pub struct Blake2bHasher(VarBlake2b);
+
+impl Blake2bHasher {
+    /// Constructs a new hasher.
+    pub fn new() -> Self {
+        // NOTE: Assumed safe as `BLAKE2B_DIGEST_LENGTH` is a valid value for a hasher
+        Blake2bHasher(VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher"))
+    }
+
+    /// Feeds `data` into the hasher.
+    pub fn update<T: AsRef<[u8]>>(&mut self, data: T) {
+        self.0.update(data);
+    }
+
+    /// Consumes the hasher, returning the digest of all the data fed into it.
+    pub fn finalize(self) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+        let mut result = [0; BLAKE2B_DIGEST_LENGTH];
+        self.0.finalize_variable(|slice| {
+            result.copy_from_slice(slice);
+        });
+        result
+    }
+}
+
+impl Default for Blake2bHasher {
+    fn default() -> Self {
+        Blake2bHasher::new()
+    }
+}
+
+/// Returns the [`HashAddr`] derived from `data`.
+pub fn hash_addr<T: AsRef<[u8]>>(data: T) -> HashAddr {
+    blake2b256(data)
+}
+
+/// Returns the [`HashAddr`] derived from a sequence of `parts`.
+///
+/// Each part is prefixed with its length as a little-endian `u32` before hashing, as a
+/// [`Bytes`](crate::bytesrepr::Bytes) value is serialized, so that different sequences can't
+/// produce the same preimage.  For example, the parts `["ab", "c"]` and `["a", "bc"]` give
+/// different addresses.
+pub fn hash_addr_from_parts<I, T>(parts: I) -> HashAddr
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut hasher = Blake2bHasher::new();
+    for part in parts {
+        let part = part.as_ref();
+        hasher.update((part.len() as u32).to_le_bytes());
+        hasher.update(part);
+    }
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::AccountHash, PublicKey, SecretKey};
+
+    #[test]
+    fn should_match_known_digest() {
+        // Blake2b-256 of the empty input.
+        let expected = [
+            0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2, 0xe8, 0xab, 0x2e, 0xb0, 0x60, 0x99,
+            0xda, 0xa1, 0xd1, 0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87, 0xfa, 0xab, 0x45, 0xcd,
+            0xf1, 0x2f, 0xe3, 0xa8,
+        ];
+        assert_eq!(blake2b256(b""), expected);
+    }
+
+    #[test]
+    fn incremental_hashing_should_match_one_shot() {
+        let mut hasher = Blake2bHasher::new();
+        hasher.update(b"casper");
+        hasher.update(b"");
+        hasher.update(b"labs");
+        assert_eq!(hasher.finalize(), blake2b256(b"casperlabs"));
+    }
+
+    #[test]
+    fn should_derive_account_hash_as_host_does() {
+        let public_key: PublicKey = SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let account_hash = AccountHash::from_public_key(&public_key, blake2b256);
+        assert_eq!(account_hash, AccountHash::from(&public_key));
+    }
+
+    #[test]
+    fn hash_addr_from_parts_should_be_unambiguous() {
+        assert_ne!(
+            hash_addr_from_parts(&["ab", "c"]),
+            hash_addr_from_parts(&["a", "bc"])
+        );
+        assert_ne!(hash_addr_from_parts(&["abc"]), hash_addr(b"abc"));
+        assert_eq!(
+            hash_addr_from_parts(vec![b"ab".to_vec(), b"c".to_vec()]),
+            hash_addr_from_parts(&["ab", "c"])
+        );
+    }
+}
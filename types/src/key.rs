@@ -24,17 +24,19 @@ use crate::{
     bytesrepr::{self, Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
     contract_wasm::ContractWasmHash,
     contracts::{ContractHash, ContractPackageHash},
+    hashing,
     system::auction::EraId,
+    transfer,
     uref::{self, URef, URefAddr, UREF_SERIALIZED_LENGTH},
     DeployHash, Tagged, TransferAddr, DEPLOY_HASH_LENGTH, TRANSFER_ADDR_LENGTH, UREF_ADDR_LENGTH,
 };
 
 const HASH_PREFIX: &str = "hash-";
-const DEPLOY_INFO_PREFIX: &str = "deploy-";
 const ERA_INFO_PREFIX: &str = "era-";
 const BALANCE_PREFIX: &str = "balance-";
 const BID_PREFIX: &str = "bid-";
 const WITHDRAW_PREFIX: &str = "withdraw-";
+const ALLOWANCE_PREFIX: &str = "allowance-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
@@ -55,10 +57,14 @@ const KEY_ERA_INFO_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + U64_SER
 const KEY_BALANCE_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_ADDR_LENGTH;
 const KEY_BID_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
 const KEY_WITHDRAW_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
+const KEY_ALLOWANCE_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
 
+/// An alias for [`Key`]s allowance variant.
+pub type AllowanceAddr = [u8; KEY_HASH_LENGTH];
+
 impl From<HashAddr> for Key {
     fn from(addr: HashAddr) -> Self {
         Key::Hash(addr)
@@ -78,6 +84,7 @@ pub enum KeyTag {
     Balance = 6,
     Bid = 7,
     Withdraw = 8,
+    Allowance = 9,
 }
 
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
@@ -104,6 +111,8 @@ pub enum Key {
     Bid(AccountHash),
     /// A `Key` under which we store unbond information.
     Withdraw(AccountHash),
+    /// A `Key` under which we store the amount a spender may transfer out of an owner's purse.
+    Allowance(AllowanceAddr),
 }
 
 #[derive(Debug)]
@@ -115,6 +124,8 @@ pub enum FromStrError {
     AccountHash(account::FromStrError),
     URef(uref::FromStrError),
     EraId(ParseIntError),
+    Transfer(transfer::FromStrError),
+    DeployInfo(transfer::FromStrError),
 }
 
 impl From<base16::DecodeError> for FromStrError {
@@ -165,6 +176,10 @@ impl Display for FromStrError {
             }
             FromStrError::URef(error) => write!(f, "uref from string error: {:?}", error),
             FromStrError::EraId(error) => write!(f, "era id from string error: {}", error),
+            FromStrError::Transfer(error) => write!(f, "transfer from string error: {}", error),
+            FromStrError::DeployInfo(error) => {
+                write!(f, "deploy info from string error: {}", error)
+            }
         }
     }
 }
@@ -182,7 +197,8 @@ impl Key {
             Key::EraInfo(_) => String::from("Key::EraInfo"),
             Key::Balance(_) => String::from("Key::Balance"),
             Key::Bid(_) => String::from("Key::Bid"),
-            Key::Withdraw(_) => String::from("Key::Unbond"),
+            Key::Withdraw(_) => String::from("Key::Withdraw"),
+            Key::Allowance(_) => String::from("Key::Allowance"),
         }
     }
 
@@ -208,13 +224,7 @@ impl Key {
             Key::Hash(addr) => format!("{}{}", HASH_PREFIX, base16::encode_lower(addr)),
             Key::URef(uref) => uref.to_formatted_string(),
             Key::Transfer(transfer_addr) => transfer_addr.to_formatted_string(),
-            Key::DeployInfo(addr) => {
-                format!(
-                    "{}{}",
-                    DEPLOY_INFO_PREFIX,
-                    base16::encode_lower(addr.as_bytes())
-                )
-            }
+            Key::DeployInfo(deploy_hash) => deploy_hash.to_formatted_string(),
             Key::EraInfo(era_id) => {
                 format!("{}{}", ERA_INFO_PREFIX, era_id.to_string())
             }
@@ -227,23 +237,40 @@ impl Key {
             Key::Withdraw(account_hash) => {
                 format!("{}{}", WITHDRAW_PREFIX, base16::encode_lower(&account_hash))
             }
+            Key::Allowance(allowance_addr) => {
+                format!(
+                    "{}{}",
+                    ALLOWANCE_PREFIX,
+                    base16::encode_lower(&allowance_addr)
+                )
+            }
         }
     }
 
     /// Parses a string formatted as per `Self::to_formatted_string()` into a `Key`.
     pub fn from_formatted_str(input: &str) -> Result<Key, FromStrError> {
-        if let Ok(account_hash) = AccountHash::from_formatted_str(input) {
-            Ok(Key::Account(account_hash))
-        } else if let Some(hex) = input.strip_prefix(HASH_PREFIX) {
+        match AccountHash::from_formatted_str(input) {
+            Ok(account_hash) => return Ok(Key::Account(account_hash)),
+            Err(account::FromStrError::InvalidPrefix) => {}
+            Err(error) => return Err(error.into()),
+        }
+
+        match DeployHash::from_formatted_str(input) {
+            Ok(deploy_hash) => return Ok(Key::DeployInfo(deploy_hash)),
+            Err(transfer::FromStrError::InvalidPrefix) => {}
+            Err(error) => return Err(FromStrError::DeployInfo(error)),
+        }
+
+        match TransferAddr::from_formatted_str(input) {
+            Ok(transfer_addr) => return Ok(Key::Transfer(transfer_addr)),
+            Err(transfer::FromStrError::InvalidPrefix) => {}
+            Err(error) => return Err(FromStrError::Transfer(error)),
+        }
+
+        if let Some(hex) = input.strip_prefix(HASH_PREFIX) {
             Ok(Key::Hash(HashAddr::try_from(
                 base16::decode(hex)?.as_ref(),
             )?))
-        } else if let Some(hex) = input.strip_prefix(DEPLOY_INFO_PREFIX) {
-            Ok(Key::DeployInfo(DeployHash::new(
-                <[u8; DEPLOY_HASH_LENGTH]>::try_from(base16::decode(hex)?.as_ref())?,
-            )))
-        } else if let Ok(transfer_addr) = TransferAddr::from_formatted_str(input) {
-            Ok(Key::Transfer(transfer_addr))
         } else if let Ok(uref) = URef::from_formatted_str(input) {
             Ok(Key::URef(uref))
         } else if let Some(era_id_str) = input.strip_prefix(ERA_INFO_PREFIX) {
@@ -260,6 +287,10 @@ impl Key {
             Ok(Key::Withdraw(AccountHash::new(AccountHashBytes::try_from(
                 base16::decode(hex)?.as_ref(),
             )?)))
+        } else if let Some(hex) = input.strip_prefix(ALLOWANCE_PREFIX) {
+            Ok(Key::Allowance(AllowanceAddr::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
         } else {
             Err(FromStrError::InvalidPrefix)
         }
@@ -300,6 +331,15 @@ impl Key {
         }
     }
 
+    /// Returns the [`Key::Allowance`] under which the amount `spender` may transfer out of the
+    /// purse with address `purse_addr` is stored.
+    pub fn allowance(purse_addr: URefAddr, spender: AccountHash) -> Key {
+        let mut preimage = Vec::with_capacity(UREF_ADDR_LENGTH + KEY_HASH_LENGTH);
+        preimage.extend_from_slice(&purse_addr);
+        preimage.extend_from_slice(spender.as_bytes());
+        Key::Allowance(hashing::blake2b256(preimage))
+    }
+
     /// Casts a [`Key::URef`] to a [`Key::Hash`]
     pub fn uref_to_hash(&self) -> Option<Key> {
         let uref = self.as_uref()?;
@@ -320,6 +360,9 @@ impl Display for Key {
             Key::Balance(uref_addr) => write!(f, "Key::Balance({})", HexFmt(uref_addr)),
             Key::Bid(account_hash) => write!(f, "Key::Bid({})", account_hash),
             Key::Withdraw(account_hash) => write!(f, "Key::Withdraw({})", account_hash),
+            Key::Allowance(allowance_addr) => {
+                write!(f, "Key::Allowance({})", HexFmt(allowance_addr))
+            }
         }
     }
 }
@@ -342,6 +385,7 @@ impl Tagged<KeyTag> for Key {
             Key::Balance(_) => KeyTag::Balance,
             Key::Bid(_) => KeyTag::Bid,
             Key::Withdraw(_) => KeyTag::Withdraw,
+            Key::Allowance(_) => KeyTag::Allowance,
         }
     }
 }
@@ -371,6 +415,12 @@ impl From<TransferAddr> for Key {
     }
 }
 
+impl From<DeployHash> for Key {
+    fn from(deploy_hash: DeployHash) -> Key {
+        Key::DeployInfo(deploy_hash)
+    }
+}
+
 impl From<ContractHash> for Key {
     fn from(contract_hash: ContractHash) -> Key {
         Key::Hash(contract_hash.value())
@@ -421,6 +471,9 @@ impl ToBytes for Key {
             Key::Withdraw(account_hash) => {
                 result.append(&mut account_hash.to_bytes()?);
             }
+            Key::Allowance(allowance_addr) => {
+                result.append(&mut allowance_addr.to_bytes()?);
+            }
         }
         Ok(result)
     }
@@ -438,6 +491,7 @@ impl ToBytes for Key {
             Key::Balance(_) => KEY_BALANCE_SERIALIZED_LENGTH,
             Key::Bid(_) => KEY_BID_SERIALIZED_LENGTH,
             Key::Withdraw(_) => KEY_WITHDRAW_SERIALIZED_LENGTH,
+            Key::Allowance(_) => KEY_ALLOWANCE_SERIALIZED_LENGTH,
         }
     }
 }
@@ -482,6 +536,10 @@ impl FromBytes for Key {
                 let (account_hash, rem) = AccountHash::from_bytes(remainder)?;
                 Ok((Key::Withdraw(account_hash), rem))
             }
+            tag if tag == KeyTag::Allowance as u8 => {
+                let (allowance_addr, rem) = FromBytes::from_bytes(remainder)?;
+                Ok((Key::Allowance(allowance_addr), rem))
+            }
             _ => Err(Error::Formatting),
         }
     }
@@ -489,7 +547,7 @@ impl FromBytes for Key {
 
 impl Distribution<Key> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Key {
-        match rng.gen_range(0..8) {
+        match rng.gen_range(0..10) {
             0 => Key::Account(rng.gen()),
             1 => Key::Hash(rng.gen()),
             2 => Key::URef(rng.gen()),
@@ -499,6 +557,7 @@ impl Distribution<Key> for Standard {
             6 => Key::Balance(rng.gen()),
             7 => Key::Bid(rng.gen()),
             8 => Key::Withdraw(rng.gen()),
+            9 => Key::Allowance(rng.gen()),
             _ => unreachable!(),
         }
     }
@@ -518,6 +577,7 @@ mod serde_helpers {
         Balance(String),
         Bid(String),
         Withdraw(String),
+        Allowance(String),
     }
 
     impl From<&Key> for HumanReadable {
@@ -533,6 +593,7 @@ mod serde_helpers {
                 Key::Balance(_) => HumanReadable::Balance(formatted_string),
                 Key::Bid(_) => HumanReadable::Bid(formatted_string),
                 Key::Withdraw(_) => HumanReadable::Withdraw(formatted_string),
+                Key::Allowance(_) => HumanReadable::Allowance(formatted_string),
             }
         }
     }
@@ -550,7 +611,8 @@ mod serde_helpers {
                 | HumanReadable::EraInfo(formatted_string)
                 | HumanReadable::Balance(formatted_string)
                 | HumanReadable::Bid(formatted_string)
-                | HumanReadable::Withdraw(formatted_string) => {
+                | HumanReadable::Withdraw(formatted_string)
+                | HumanReadable::Allowance(formatted_string) => {
                     Key::from_formatted_str(&formatted_string)
                 }
             }
@@ -568,6 +630,7 @@ mod serde_helpers {
         Balance(&'a URefAddr),
         Bid(&'a AccountHash),
         Withdraw(&'a AccountHash),
+        Allowance(&'a AllowanceAddr),
     }
 
     impl<'a> From<&'a Key> for BinarySerHelper<'a> {
@@ -582,6 +645,7 @@ mod serde_helpers {
                 Key::Balance(uref_addr) => BinarySerHelper::Balance(uref_addr),
                 Key::Bid(account_hash) => BinarySerHelper::Bid(account_hash),
                 Key::Withdraw(account_hash) => BinarySerHelper::Withdraw(account_hash),
+                Key::Allowance(allowance_addr) => BinarySerHelper::Allowance(allowance_addr),
             }
         }
     }
@@ -597,6 +661,7 @@ mod serde_helpers {
         Balance(URefAddr),
         Bid(AccountHash),
         Withdraw(AccountHash),
+        Allowance(AllowanceAddr),
     }
 
     impl From<BinaryDeserHelper> for Key {
@@ -611,6 +676,7 @@ mod serde_helpers {
                 BinaryDeserHelper::Balance(uref_addr) => Key::Balance(uref_addr),
                 BinaryDeserHelper::Bid(account_hash) => Key::Bid(account_hash),
                 BinaryDeserHelper::Withdraw(account_hash) => Key::Withdraw(account_hash),
+                BinaryDeserHelper::Allowance(allowance_addr) => Key::Allowance(allowance_addr),
             }
         }
     }
@@ -817,6 +883,15 @@ mod tests {
 
         let invalid_hex = "000000000000000000000000000000000000000000000000000000000000000g";
         assert!(Key::from_formatted_str(&format!("{}{}", HASH_PREFIX, invalid_hex)).is_err());
+
+        assert!(matches!(
+            Key::from_formatted_str(&format!("transfer-{}", short_addr)),
+            Err(FromStrError::Transfer(_))
+        ));
+        assert!(matches!(
+            Key::from_formatted_str(&format!("deploy-{}", invalid_hex)),
+            Err(FromStrError::DeployInfo(_))
+        ));
     }
 
     #[test]
@@ -880,6 +955,7 @@ mod tests {
         round_trip(&Key::Balance(URef::new(array, AccessRights::READ).addr()));
         round_trip(&Key::Bid(AccountHash::new(array)));
         round_trip(&Key::Withdraw(AccountHash::new(array)));
+        round_trip(&Key::Allowance(array));
     }
 
     #[test]
@@ -900,6 +976,7 @@ mod tests {
         round_trip(&Key::EraInfo(42));
         round_trip(&Key::Balance(URef::new(array, AccessRights::READ).addr()));
         round_trip(&Key::Withdraw(AccountHash::new(array)));
+        round_trip(&Key::Allowance(array));
 
         let zeros = [0; BLAKE2B_DIGEST_LENGTH];
 
@@ -912,5 +989,6 @@ mod tests {
         round_trip(&Key::Balance(URef::new(zeros, AccessRights::READ).addr()));
         round_trip(&Key::Bid(AccountHash::new(zeros)));
         round_trip(&Key::Withdraw(AccountHash::new(zeros)));
+        round_trip(&Key::Allowance(zeros));
     }
 }
@@ -28,17 +28,22 @@ pub mod account;
 pub mod api_error;
 mod block_time;
 pub mod bytesrepr;
+pub mod checksummed_hex;
 mod cl_type;
 mod cl_value;
+mod contract_error;
 mod contract_wasm;
 pub mod contracts;
 pub mod crypto;
 mod deploy_info;
 mod execution_result;
+mod gas;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
+pub mod hashing;
 mod json_pretty_printer;
 mod key;
+mod motes;
 mod named_key;
 mod phase;
 mod protocol_version;
@@ -51,34 +56,57 @@ mod transfer_result;
 mod uint;
 mod uref;
 
-pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
+pub use crate::semver::{
+    ParseSemVerError, SemVer, SemVerConstraint, SemVerOp, SEM_VER_SERIALIZED_LENGTH,
+};
+pub use access_rights::{
+    AccessRights, AccessRightsValidator, ForgedReference, ACCESS_RIGHTS_SERIALIZED_LENGTH,
+};
 #[doc(inline)]
 pub use api_error::ApiError;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
+#[cfg(feature = "derive")]
+pub use casper_types_macros::ContractError;
 pub use cl_type::{named_key_type, CLType, CLTyped};
-pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
-pub use contract_wasm::{ContractWasm, ContractWasmHash};
+pub use cl_value::{
+    CLTypeMismatch, CLValue, CLValueError, CLValuePathElement, CLValuePathError, DynamicValue,
+    DynamicValueError,
+};
+pub use contract_error::{ContractError, DisplayWithSchema, UserErrorSchema};
+pub use contract_wasm::{ContractWasm, ContractWasmError, ContractWasmHash};
 pub use contracts::{
     Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersion,
-    ContractVersionKey, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group,
-    Parameter,
+    ContractVersionInfo, ContractVersionKey, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Group, Parameter,
 };
 pub use crypto::*;
-pub use deploy_info::DeployInfo;
+pub use deploy_info::{DeployInfo, DeployTarget};
 pub use execution_result::{
-    ExecutionEffect, ExecutionResult, OpKind, Operation, Transform, TransformEntry,
+    ContractEvent, ExecutionEffect, ExecutionError, ExecutionResult, FeeBreakdown, GasCheckpoint,
+    OpKind, Operation, Transform, TransformEntry,
 };
+pub use gas::Gas;
 pub use json_pretty_printer::json_pretty_print;
 #[doc(inline)]
-pub use key::{HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH};
-pub use named_key::NamedKey;
-pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
+pub use key::{AllowanceAddr, HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH};
+pub use motes::Motes;
+pub use named_key::{
+    validate_named_key_name, NamedKey, NamedKeyError, NamedKeysExt, NamedKeysWithPrefix,
+};
+pub use phase::{
+    host_function_allowed_phases, Phase, PhaseSet, HOST_FUNCTION_ALLOWED_PHASES,
+    PHASE_SERIALIZED_LENGTH,
+};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
 pub use runtime_args::{NamedArg, RuntimeArgs};
-pub use semver::{SemVer, SEM_VER_SERIALIZED_LENGTH};
 pub use tagged::Tagged;
-pub use transfer::{DeployHash, Transfer, TransferAddr, DEPLOY_HASH_LENGTH, TRANSFER_ADDR_LENGTH};
+pub use transfer::{
+    DeployHash, FromStrError as TransferFromStrError, Transfer, TransferAddr, TransferReceipt,
+    DEPLOY_HASH_LENGTH, TRANSFER_ADDR_LENGTH,
+};
 pub use transfer_result::{TransferResult, TransferredTo};
-pub use uref::{FromStrError as URefFromStrError, URef, UREF_ADDR_LENGTH, UREF_SERIALIZED_LENGTH};
+pub use uref::{
+    FromStrError as URefFromStrError, URef, URefAddr, UREF_ADDR_LENGTH, UREF_SERIALIZED_LENGTH,
+};
 
 pub use crate::uint::{UIntParseError, U128, U256, U512};
@@ -1,13 +1,21 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::btree_map, string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Bound,
+};
 
 #[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::bytesrepr::{self, FromBytes, ToBytes};
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::{ContractHash, NamedKeys},
+    HashAddr, Key, URef,
+};
 
 /// A named key.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Default, Debug)]
@@ -41,3 +49,216 @@ impl FromBytes for NamedKey {
         Ok((named_key, remainder))
     }
 }
+
+/// Errors which may occur when accessing or validating named keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamedKeyError {
+    /// There is no key under the given name.
+    Missing(String),
+    /// The key under the given name is not of the expected variant.
+    UnexpectedKeyVariant(String),
+    /// The name is empty.
+    EmptyName,
+    /// The name is longer than the maximum allowed length.
+    NameTooLong {
+        /// The maximum allowed length in bytes.
+        max: u32,
+        /// The actual length in bytes.
+        actual: usize,
+    },
+    /// The name contains a control character.
+    InvalidCharacter(char),
+}
+
+impl Display for NamedKeyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            NamedKeyError::Missing(name) => write!(f, "named key {} not found", name),
+            NamedKeyError::UnexpectedKeyVariant(name) => {
+                write!(f, "named key {} has an unexpected key variant", name)
+            }
+            NamedKeyError::EmptyName => write!(f, "named key name is empty"),
+            NamedKeyError::NameTooLong { max, actual } => write!(
+                f,
+                "named key name is {} bytes long, but at most {} bytes are allowed",
+                actual, max
+            ),
+            NamedKeyError::InvalidCharacter(c) => {
+                write!(f, "named key name contains invalid character {:?}", c)
+            }
+        }
+    }
+}
+
+/// Validates the name of a named key.
+///
+/// A valid name is non-empty, at most `max_length` bytes long and contains no control characters.
+pub fn validate_named_key_name(name: &str, max_length: u32) -> Result<(), NamedKeyError> {
+    if name.is_empty() {
+        return Err(NamedKeyError::EmptyName);
+    }
+    if name.len() > max_length as usize {
+        return Err(NamedKeyError::NameTooLong {
+            max: max_length,
+            actual: name.len(),
+        });
+    }
+    if let Some(c) = name.chars().find(|c| c.is_control()) {
+        return Err(NamedKeyError::InvalidCharacter(c));
+    }
+    Ok(())
+}
+
+/// Typed accessors and prefix queries for [`NamedKeys`].
+pub trait NamedKeysExt {
+    /// Returns the [`URef`] stored under `name`.
+    fn get_uref(&self, name: &str) -> Result<URef, NamedKeyError>;
+
+    /// Returns the hash stored under `name`.
+    fn get_hash(&self, name: &str) -> Result<HashAddr, NamedKeyError>;
+
+    /// Returns the [`ContractHash`] stored under `name`.
+    fn get_contract_hash(&self, name: &str) -> Result<ContractHash, NamedKeyError>;
+
+    /// Returns an iterator over all entries whose name starts with `prefix`, in name order.
+    fn with_prefix<'a>(&'a self, prefix: &'a str) -> NamedKeysWithPrefix<'a>;
+}
+
+impl NamedKeysExt for NamedKeys {
+    fn get_uref(&self, name: &str) -> Result<URef, NamedKeyError> {
+        get_key(self, name)?
+            .into_uref()
+            .ok_or_else(|| NamedKeyError::UnexpectedKeyVariant(name.into()))
+    }
+
+    fn get_hash(&self, name: &str) -> Result<HashAddr, NamedKeyError> {
+        get_key(self, name)?
+            .into_hash()
+            .ok_or_else(|| NamedKeyError::UnexpectedKeyVariant(name.into()))
+    }
+
+    fn get_contract_hash(&self, name: &str) -> Result<ContractHash, NamedKeyError> {
+        self.get_hash(name).map(ContractHash::new)
+    }
+
+    fn with_prefix<'a>(&'a self, prefix: &'a str) -> NamedKeysWithPrefix<'a> {
+        NamedKeysWithPrefix {
+            range: self.range::<str, _>((Bound::Included(prefix), Bound::Unbounded)),
+            prefix,
+        }
+    }
+}
+
+fn get_key(named_keys: &NamedKeys, name: &str) -> Result<Key, NamedKeyError> {
+    named_keys
+        .get(name)
+        .copied()
+        .ok_or_else(|| NamedKeyError::Missing(name.into()))
+}
+
+/// An iterator over the entries of [`NamedKeys`] whose name starts with a given prefix.
+///
+/// Returned by [`NamedKeysExt::with_prefix`].
+pub struct NamedKeysWithPrefix<'a> {
+    range: btree_map::Range<'a, String, Key>,
+    prefix: &'a str,
+}
+
+impl<'a> Iterator for NamedKeysWithPrefix<'a> {
+    type Item = (&'a String, &'a Key);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Names sharing the prefix are contiguous and start at the prefix itself, so iteration can
+        // stop at the first name which doesn't match.
+        let prefix = self.prefix;
+        self.range
+            .next()
+            .filter(|(name, _)| name.starts_with(prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AccessRights;
+
+    fn named_keys() -> NamedKeys {
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            "purse".into(),
+            Key::URef(URef::new([1; 32], AccessRights::READ)),
+        );
+        named_keys.insert("contract".into(), Key::Hash([2; 32]));
+        named_keys.insert("era_1".into(), Key::Hash([3; 32]));
+        named_keys.insert("era_2".into(), Key::Hash([4; 32]));
+        named_keys.insert("erase".into(), Key::Hash([5; 32]));
+        named_keys.insert("f".into(), Key::Hash([6; 32]));
+        named_keys
+    }
+
+    #[test]
+    fn should_get_typed_keys() {
+        let named_keys = named_keys();
+        assert_eq!(
+            named_keys.get_uref("purse"),
+            Ok(URef::new([1; 32], AccessRights::READ))
+        );
+        assert_eq!(named_keys.get_hash("contract"), Ok([2; 32]));
+        assert_eq!(
+            named_keys.get_contract_hash("contract"),
+            Ok(ContractHash::new([2; 32]))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_get_missing_or_mismatched_keys() {
+        let named_keys = named_keys();
+        assert_eq!(
+            named_keys.get_uref("missing"),
+            Err(NamedKeyError::Missing("missing".into()))
+        );
+        assert_eq!(
+            named_keys.get_uref("contract"),
+            Err(NamedKeyError::UnexpectedKeyVariant("contract".into()))
+        );
+        assert_eq!(
+            named_keys.get_contract_hash("purse"),
+            Err(NamedKeyError::UnexpectedKeyVariant("purse".into()))
+        );
+    }
+
+    #[test]
+    fn should_iterate_with_prefix() {
+        let named_keys = named_keys();
+        let names: Vec<&String> = named_keys
+            .with_prefix("era")
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["era_1", "era_2", "erase"]);
+        let names: Vec<&String> = named_keys
+            .with_prefix("era_")
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["era_1", "era_2"]);
+        assert_eq!(named_keys.with_prefix("").count(), named_keys.len());
+        assert_eq!(named_keys.with_prefix("x").count(), 0);
+    }
+
+    #[test]
+    fn should_validate_names() {
+        assert!(validate_named_key_name("a", 1).is_ok());
+        assert!(validate_named_key_name("üñí", 6).is_ok());
+        assert_eq!(
+            validate_named_key_name("", 10),
+            Err(NamedKeyError::EmptyName)
+        );
+        assert_eq!(
+            validate_named_key_name("ab", 1),
+            Err(NamedKeyError::NameTooLong { max: 1, actual: 2 })
+        );
+        assert_eq!(
+            validate_named_key_name("a\nb", 10),
+            Err(NamedKeyError::InvalidCharacter('\n'))
+        );
+    }
+}
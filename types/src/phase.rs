@@ -8,7 +8,7 @@ use num_traits::{FromPrimitive, ToPrimitive};
 
 use crate::{
     bytesrepr::{Error, FromBytes, ToBytes},
-    CLType, CLTyped,
+    ApiError, CLType, CLTyped,
 };
 
 /// The number of bytes in a serialized [`Phase`].
@@ -28,6 +28,18 @@ pub enum Phase {
     FinalizePayment = 3,
 }
 
+impl Phase {
+    /// Returns `Ok(())` if `self` is one of `allowed_phases`, or else
+    /// [`ApiError::InvalidPhase`] holding `self`.
+    pub fn check_allowed(self, allowed_phases: PhaseSet) -> Result<(), ApiError> {
+        if allowed_phases.contains(self) {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidPhase(self as u8))
+        }
+    }
+}
+
 impl ToBytes for Phase {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         // NOTE: Assumed safe as [`Phase`] is represented as u8.
@@ -54,3 +66,106 @@ impl CLTyped for Phase {
         CLType::U8
     }
 }
+
+/// A set of [`Phase`]s.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PhaseSet(u8);
+
+impl PhaseSet {
+    /// The set of all phases.
+    pub const ALL: PhaseSet = PhaseSet::EMPTY
+        .with(Phase::System)
+        .with(Phase::Payment)
+        .with(Phase::Session)
+        .with(Phase::FinalizePayment);
+
+    /// The empty set.
+    pub const EMPTY: PhaseSet = PhaseSet(0);
+
+    /// Returns a copy of `self` which additionally contains `phase`.
+    pub const fn with(self, phase: Phase) -> Self {
+        PhaseSet(self.0 | (1 << phase as u8))
+    }
+
+    /// Returns `true` if `self` contains `phase`.
+    pub fn contains(self, phase: Phase) -> bool {
+        self.0 & (1 << phase as u8) != 0
+    }
+}
+
+const SESSION_ONLY: PhaseSet = PhaseSet::EMPTY.with(Phase::Session);
+
+/// The phases in which host functions with restricted availability may be called, keyed by the
+/// name under which they are imported by Wasm.
+///
+/// Host functions which are not listed may be called in any phase.  The execution engine reverts
+/// with [`ApiError::InvalidPhase`] if a listed host function is called in any other phase.
+pub const HOST_FUNCTION_ALLOWED_PHASES: &[(&str, PhaseSet)] = &[
+    ("casper_add_associated_key", SESSION_ONLY),
+    ("casper_remove_associated_key", SESSION_ONLY),
+    ("casper_update_associated_key", SESSION_ONLY),
+    ("casper_set_action_threshold", SESSION_ONLY),
+    (
+        "casper_transfer_from_purse_to_account_with_receipt",
+        SESSION_ONLY,
+    ),
+];
+
+/// Returns the phases in which the host function imported under `host_function_name` may be
+/// called.
+pub fn host_function_allowed_phases(host_function_name: &str) -> PhaseSet {
+    HOST_FUNCTION_ALLOWED_PHASES
+        .iter()
+        .find(|(name, _)| *name == host_function_name)
+        .map(|(_, allowed_phases)| *allowed_phases)
+        .unwrap_or(PhaseSet::ALL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_contain_only_added_phases() {
+        let phase_set = PhaseSet::EMPTY.with(Phase::Payment).with(Phase::Session);
+        assert!(!phase_set.contains(Phase::System));
+        assert!(phase_set.contains(Phase::Payment));
+        assert!(phase_set.contains(Phase::Session));
+        assert!(!phase_set.contains(Phase::FinalizePayment));
+    }
+
+    #[test]
+    fn should_allow_unlisted_host_functions_in_all_phases() {
+        assert_eq!(
+            host_function_allowed_phases("casper_read_value"),
+            PhaseSet::ALL
+        );
+        assert_eq!(
+            host_function_allowed_phases("casper_add_associated_key"),
+            PhaseSet::EMPTY.with(Phase::Session)
+        );
+    }
+
+    #[test]
+    fn should_report_offending_phase() {
+        let allowed_phases = host_function_allowed_phases("casper_set_action_threshold");
+        assert_eq!(Phase::Session.check_allowed(allowed_phases), Ok(()));
+        assert_eq!(
+            Phase::Payment.check_allowed(allowed_phases),
+            Err(ApiError::InvalidPhase(Phase::Payment as u8))
+        );
+    }
+
+    #[test]
+    fn should_not_list_host_function_twice() {
+        for (index, (name, _)) in HOST_FUNCTION_ALLOWED_PHASES.iter().enumerate() {
+            assert!(
+                HOST_FUNCTION_ALLOWED_PHASES[index + 1..]
+                    .iter()
+                    .all(|(other_name, _)| other_name != name),
+                "{} is listed twice",
+                name
+            );
+        }
+    }
+}
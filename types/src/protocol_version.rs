@@ -9,7 +9,7 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 
 use crate::{
     bytesrepr::{Error, FromBytes, ToBytes},
-    SemVer,
+    SemVer, SemVerConstraint,
 };
 
 /// A newtype wrapping a [`SemVer`] which represents a Casper Platform protocol version.
@@ -123,7 +123,7 @@ impl ProtocolVersion {
     ///
     /// Two protocol versions with different major version are considered to be incompatible.
     pub fn is_compatible_with(&self, version: &ProtocolVersion) -> bool {
-        self.0.major == version.0.major
+        SemVerConstraint::major(self.0.major).matches(&version.0)
     }
 }
 
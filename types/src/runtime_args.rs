@@ -189,16 +189,91 @@ impl FromBytes for RuntimeArgs {
 ///   "bar" => "Hello, world!"
 /// };
 /// ```
+///
+/// # Typed `None` values and empty collections
+///
+/// A value of `None`, `[]` or `{}` carries no type information of its own, so the
+/// [`crate::CLType`] of such an argument can be given explicitly after a colon:
+///
+/// * `None: <inner CLType>` creates a `CLType::Option(<inner CLType>)` holding `None`
+/// * `[]: <item CLType>` creates an empty `CLType::List(<item CLType>)`
+/// * `{}: (<key CLType>, <value CLType>)` creates an empty `CLType::Map`
+///
+/// ```
+/// use casper_types::{runtime_args, CLType, RuntimeArgs};
+/// let named_args = runtime_args! {
+///   "purse" => None: CLType::URef,
+///   "amounts" => []: CLType::U512,
+///   "named_keys" => {}: (CLType::String, CLType::Key),
+///   "foo" => 42,
+/// };
+/// assert_eq!(
+///     named_args.get("purse").unwrap().cl_type(),
+///     &CLType::Option(Box::new(CLType::URef))
+/// );
+/// ```
+///
+/// A bare `None` or empty collection is rejected, as its type cannot be inferred:
+///
+/// ```compile_fail
+/// use casper_types::{runtime_args, RuntimeArgs};
+/// let _named_args = runtime_args! { "purse" => None };
+/// ```
+///
+/// ```compile_fail
+/// use casper_types::{runtime_args, RuntimeArgs};
+/// let _named_args = runtime_args! { "amounts" => vec![] };
+/// ```
+///
+/// The explicit type must be a [`crate::CLType`], not a Rust type:
+///
+/// ```compile_fail
+/// use casper_types::{runtime_args, RuntimeArgs, URef};
+/// let _named_args = runtime_args! { "purse" => None: URef };
+/// ```
+///
+/// An empty map needs both its key and value types:
+///
+/// ```compile_fail
+/// use casper_types::{runtime_args, CLType, RuntimeArgs};
+/// let _named_args = runtime_args! { "named_keys" => {}: CLType::Key };
+/// ```
 #[macro_export]
 macro_rules! runtime_args {
     () => (RuntimeArgs::new());
-    ( $($key:expr => $value:expr,)+ ) => (runtime_args!($($key => $value),+));
-    ( $($key:expr => $value:expr),* ) => {
+    (@insert $named_args:ident; ) => {};
+    (@insert $named_args:ident; $key:expr => None: $inner_type:expr $(, $($rest:tt)*)?) => {
+        $named_args.insert_cl_value($key, $crate::CLValue::none($inner_type));
+        $crate::runtime_args!(@insert $named_args; $($($rest)*)?);
+    };
+    (@insert $named_args:ident; $key:expr => []: $item_type:expr $(, $($rest:tt)*)?) => {
+        $named_args.insert_cl_value($key, $crate::CLValue::empty_list($item_type).unwrap());
+        $crate::runtime_args!(@insert $named_args; $($($rest)*)?);
+    };
+    (
+        @insert $named_args:ident;
+        $key:expr => {}: ($key_type:expr, $value_type:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $named_args.insert_cl_value(
+            $key,
+            $crate::CLValue::empty_map($key_type, $value_type).unwrap(),
+        );
+        $crate::runtime_args!(@insert $named_args; $($($rest)*)?);
+    };
+    (@insert $named_args:ident; $key:expr => $value:expr $(, $($rest:tt)*)?) => {
+        $named_args.insert($key, $value).unwrap();
+        $crate::runtime_args!(@insert $named_args; $($($rest)*)?);
+    };
+    (@insert $named_args:ident; $($invalid:tt)+) => {
+        compile_error!(concat!(
+            "invalid runtime argument: ",
+            stringify!($($invalid)+)
+        ));
+    };
+    ( $($tokens:tt)+ ) => {
         {
             let mut named_args = RuntimeArgs::new();
-            $(
-                named_args.insert($key, $value).unwrap();
-            )*
+            $crate::runtime_args!(@insert named_args; $($tokens)+);
             named_args
         }
     };
@@ -207,6 +282,7 @@ macro_rules! runtime_args {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{CLType, Key, URef, U512};
 
     #[test]
     fn test_runtime_args() {
@@ -241,6 +317,25 @@ mod tests {
         assert_eq!(runtime_args! {}, RuntimeArgs::new());
     }
 
+    #[test]
+    fn macro_typed_none_and_empty_collections() {
+        let runtime_args = runtime_args! {
+            "purse" => None: CLType::URef,
+            "amounts" => []: CLType::U512,
+            "named_keys" => {}: (CLType::String, CLType::Key),
+            "foo" => 1i32,
+        };
+
+        let mut expected = RuntimeArgs::new();
+        expected.insert("purse", Option::<URef>::None).unwrap();
+        expected.insert("amounts", Vec::<U512>::new()).unwrap();
+        expected
+            .insert("named_keys", BTreeMap::<String, Key>::new())
+            .unwrap();
+        expected.insert("foo", 1i32).unwrap();
+        assert_eq!(runtime_args, expected);
+    }
+
     #[test]
     fn btreemap_compat() {
         // This test assumes same serialization format as BTreeMap
@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use core::{convert::TryFrom, fmt, num::ParseIntError};
+use core::{convert::TryFrom, fmt, num::ParseIntError, str::FromStr};
 
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
@@ -85,6 +85,7 @@ impl fmt::Display for SemVer {
     }
 }
 
+/// Error returned when parsing a [`SemVer`] or a [`SemVerConstraint`] from a string.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Error))]
 #[cfg_attr(not(feature = "std"), derive(Display))]
@@ -119,11 +120,166 @@ impl TryFrom<&str> for SemVer {
     }
 }
 
+impl FromStr for SemVer {
+    type Err = ParseSemVerError;
+
+    fn from_str(value: &str) -> Result<SemVer, Self::Err> {
+        SemVer::try_from(value)
+    }
+}
+
+/// The operator of a [`SemVerConstraint`].
+#[derive(Copy, Clone, DataSize, Debug, Hash, PartialEq, Eq)]
+pub enum SemVerOp {
+    /// `=`: matches only the given version, or any version starting with the given parts if the
+    /// constraint is partial.
+    Exact,
+    /// `^`: matches any version which doesn't change the left-most non-zero part.
+    Caret,
+    /// `~`: matches any version with the same major and minor parts, and a patch part no lower
+    /// than the given one.
+    Tilde,
+}
+
+impl SemVerOp {
+    fn symbol(&self) -> char {
+        match self {
+            SemVerOp::Exact => '=',
+            SemVerOp::Caret => '^',
+            SemVerOp::Tilde => '~',
+        }
+    }
+}
+
+/// A constraint which versions can be matched against, e.g. `^1.2.3`, `~1.2` or `=1.2.3`.
+///
+/// The minor and patch parts may be omitted, in which case they act as wildcards. The matching
+/// rules are the same as Cargo's, and a constraint without an operator is a caret constraint.
+#[derive(Copy, Clone, DataSize, Debug, Hash, PartialEq, Eq)]
+pub struct SemVerConstraint {
+    op: SemVerOp,
+    major: u32,
+    minor: Option<u32>,
+    patch: Option<u32>,
+}
+
+impl SemVerConstraint {
+    /// Constructs a constraint matching exactly the given version.
+    pub const fn exact(version: SemVer) -> Self {
+        Self::full(SemVerOp::Exact, version)
+    }
+
+    /// Constructs a caret constraint, matching any version compatible with the given one.
+    pub const fn caret(version: SemVer) -> Self {
+        Self::full(SemVerOp::Caret, version)
+    }
+
+    /// Constructs a tilde constraint, matching any patch-level change of the given version.
+    pub const fn tilde(version: SemVer) -> Self {
+        Self::full(SemVerOp::Tilde, version)
+    }
+
+    /// Constructs a constraint matching any version with the given major part, i.e. `^major`.
+    pub const fn major(major: u32) -> Self {
+        SemVerConstraint {
+            op: SemVerOp::Caret,
+            major,
+            minor: None,
+            patch: None,
+        }
+    }
+
+    const fn full(op: SemVerOp, version: SemVer) -> Self {
+        SemVerConstraint {
+            op,
+            major: version.major,
+            minor: Some(version.minor),
+            patch: Some(version.patch),
+        }
+    }
+
+    /// Returns the operator of this constraint.
+    pub fn op(&self) -> SemVerOp {
+        self.op
+    }
+
+    /// Returns `true` if the given version satisfies this constraint.
+    pub fn matches(&self, version: &SemVer) -> bool {
+        if version.major != self.major {
+            return false;
+        }
+        let minor = match self.minor {
+            Some(minor) => minor,
+            None => return true,
+        };
+        match self.op {
+            SemVerOp::Exact => {
+                version.minor == minor && self.patch.map_or(true, |patch| version.patch == patch)
+            }
+            SemVerOp::Tilde => {
+                version.minor == minor && self.patch.map_or(true, |patch| version.patch >= patch)
+            }
+            SemVerOp::Caret => {
+                let patch = self.patch.unwrap_or(0);
+                if self.major > 0 {
+                    (version.minor, version.patch) >= (minor, patch)
+                } else if minor > 0 || self.patch.is_none() {
+                    version.minor == minor && version.patch >= patch
+                } else {
+                    version.minor == 0 && version.patch == patch
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for SemVerConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.op.symbol(), self.major)?;
+        if let Some(minor) = self.minor {
+            write!(f, ".{}", minor)?;
+        }
+        if let Some(patch) = self.patch {
+            write!(f, ".{}", patch)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SemVerConstraint {
+    type Err = ParseSemVerError;
+
+    fn from_str(value: &str) -> Result<SemVerConstraint, Self::Err> {
+        let value = value.trim();
+        let (op, version) = match value.chars().next() {
+            Some('=') => (SemVerOp::Exact, &value[1..]),
+            Some('^') => (SemVerOp::Caret, &value[1..]),
+            Some('~') => (SemVerOp::Tilde, &value[1..]),
+            _ => (SemVerOp::Caret, value),
+        };
+        let tokens: Vec<&str> = version.trim_start().split('.').collect();
+        if tokens.len() > 3 {
+            return Err(ParseSemVerError::InvalidVersionFormat);
+        }
+        let major = tokens[0].parse()?;
+        let minor = tokens.get(1).map(|token| token.parse()).transpose()?;
+        let patch = tokens.get(2).map(|token| token.parse()).transpose()?;
+        Ok(SemVerConstraint {
+            op,
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use core::convert::TryInto;
 
+    use proptest::prelude::*;
+
     #[test]
     fn should_compare_semver_versions() {
         assert!(SemVer::new(0, 0, 0) < SemVer::new(1, 2, 3));
@@ -153,4 +309,134 @@ mod tests {
         assert!(SemVer::try_from("1").is_err());
         assert!(SemVer::try_from("0").is_err());
     }
+
+    #[test]
+    fn parse_with_from_str() {
+        assert_eq!("1.2.3".parse(), Ok(SemVer::new(1, 2, 3)));
+        assert_eq!(
+            "1.2".parse::<SemVer>(),
+            Err(ParseSemVerError::InvalidVersionFormat)
+        );
+        assert_eq!(
+            SemVer::new(10, 0, 7).to_string().parse(),
+            Ok(SemVer::new(10, 0, 7))
+        );
+    }
+
+    #[test]
+    fn should_parse_constraints() {
+        let constraint: SemVerConstraint = "^1.2.3".parse().unwrap();
+        assert_eq!(constraint, SemVerConstraint::caret(SemVer::new(1, 2, 3)));
+        let constraint: SemVerConstraint = "1.2.3".parse().unwrap();
+        assert_eq!(constraint, SemVerConstraint::caret(SemVer::new(1, 2, 3)));
+        let constraint: SemVerConstraint = "~1.2.3".parse().unwrap();
+        assert_eq!(constraint, SemVerConstraint::tilde(SemVer::new(1, 2, 3)));
+        let constraint: SemVerConstraint = "=1.2.3".parse().unwrap();
+        assert_eq!(constraint, SemVerConstraint::exact(SemVer::new(1, 2, 3)));
+        let constraint: SemVerConstraint = "^2".parse().unwrap();
+        assert_eq!(constraint, SemVerConstraint::major(2));
+        assert_eq!(constraint.to_string(), "^2");
+
+        assert!("".parse::<SemVerConstraint>().is_err());
+        assert!("^".parse::<SemVerConstraint>().is_err());
+        assert!(">1.2.3".parse::<SemVerConstraint>().is_err());
+        assert!("^1.2.3.4".parse::<SemVerConstraint>().is_err());
+        assert!("~1..3".parse::<SemVerConstraint>().is_err());
+    }
+
+    #[test]
+    fn should_match_constraints() {
+        let exact = SemVerConstraint::exact(SemVer::new(1, 2, 3));
+        assert!(exact.matches(&SemVer::new(1, 2, 3)));
+        assert!(!exact.matches(&SemVer::new(1, 2, 4)));
+
+        let tilde = SemVerConstraint::tilde(SemVer::new(1, 2, 3));
+        assert!(tilde.matches(&SemVer::new(1, 2, 3)));
+        assert!(tilde.matches(&SemVer::new(1, 2, 99)));
+        assert!(!tilde.matches(&SemVer::new(1, 2, 2)));
+        assert!(!tilde.matches(&SemVer::new(1, 3, 0)));
+
+        let caret = SemVerConstraint::caret(SemVer::new(1, 2, 3));
+        assert!(caret.matches(&SemVer::new(1, 2, 3)));
+        assert!(caret.matches(&SemVer::new(1, 99, 0)));
+        assert!(!caret.matches(&SemVer::new(1, 2, 2)));
+        assert!(!caret.matches(&SemVer::new(2, 0, 0)));
+
+        let caret = SemVerConstraint::caret(SemVer::new(0, 2, 3));
+        assert!(caret.matches(&SemVer::new(0, 2, 9)));
+        assert!(!caret.matches(&SemVer::new(0, 3, 0)));
+
+        let caret = SemVerConstraint::caret(SemVer::new(0, 0, 3));
+        assert!(caret.matches(&SemVer::new(0, 0, 3)));
+        assert!(!caret.matches(&SemVer::new(0, 0, 4)));
+
+        let major = SemVerConstraint::major(0);
+        assert!(major.matches(&SemVer::new(0, 0, 0)));
+        assert!(major.matches(&SemVer::new(0, 99, 99)));
+        assert!(!major.matches(&SemVer::new(1, 0, 0)));
+    }
+
+    fn semver_arb() -> impl Strategy<Value = SemVer> {
+        (0u32..4, 0u32..4, 0u32..4)
+            .prop_map(|(major, minor, patch)| SemVer::new(major, minor, patch))
+    }
+
+    fn constraint_arb() -> impl Strategy<Value = SemVerConstraint> {
+        (
+            prop_oneof![
+                Just(SemVerOp::Exact),
+                Just(SemVerOp::Caret),
+                Just(SemVerOp::Tilde)
+            ],
+            0u32..4,
+            proptest::option::of((0u32..4, proptest::option::of(0u32..4))),
+        )
+            .prop_map(|(op, major, rest)| SemVerConstraint {
+                op,
+                major,
+                minor: rest.map(|(minor, _)| minor),
+                patch: rest.and_then(|(_, patch)| patch),
+            })
+    }
+
+    fn to_semver_crate(version: &SemVer) -> semver::Version {
+        semver::Version::new(
+            version.major.into(),
+            version.minor.into(),
+            version.patch.into(),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn display_and_parse_should_roundtrip(version in semver_arb()) {
+            prop_assert_eq!(version.to_string().parse::<SemVer>(), Ok(version));
+            prop_assert_eq!(
+                semver::Version::parse(&version.to_string()).unwrap(),
+                to_semver_crate(&version)
+            );
+        }
+
+        #[test]
+        fn ordering_should_agree_with_semver_crate(lhs in semver_arb(), rhs in semver_arb()) {
+            prop_assert_eq!(lhs.cmp(&rhs), to_semver_crate(&lhs).cmp(&to_semver_crate(&rhs)));
+        }
+
+        #[test]
+        fn constraint_display_and_parse_should_roundtrip(constraint in constraint_arb()) {
+            prop_assert_eq!(constraint.to_string().parse::<SemVerConstraint>(), Ok(constraint));
+        }
+
+        #[test]
+        fn matching_should_agree_with_semver_crate(
+            constraint in constraint_arb(),
+            version in semver_arb(),
+        ) {
+            let requirement = semver::VersionReq::parse(&constraint.to_string()).unwrap();
+            prop_assert_eq!(
+                constraint.matches(&version),
+                requirement.matches(&to_semver_crate(&version))
+            );
+        }
+    }
 }
@@ -0,0 +1,217 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::EraId,
+    U512,
+};
+
+/// A vesting schedule for the stake of a genesis validator or delegator, measured in eras.
+///
+/// The whole initial stake is locked for `cliff_eras` eras after `start_era`.  After the cliff
+/// it is released in `release_eras` equal installments, one at the start of each subsequent era.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct EraVestingSchedule {
+    /// The era from which the schedule is measured.
+    start_era: EraId,
+    /// The number of eras during which the whole initial stake is locked.
+    cliff_eras: u64,
+    /// The number of eras over which the stake is released after the cliff.
+    release_eras: u64,
+    /// The amount locked by the schedule before anything is released.
+    initial_locked_amount: U512,
+}
+
+impl EraVestingSchedule {
+    /// Creates a new vesting schedule.
+    pub fn new(
+        start_era: EraId,
+        cliff_eras: u64,
+        release_eras: u64,
+        initial_locked_amount: U512,
+    ) -> Self {
+        EraVestingSchedule {
+            start_era,
+            cliff_eras,
+            release_eras,
+            initial_locked_amount,
+        }
+    }
+
+    /// Returns the era from which the schedule is measured.
+    pub fn start_era(&self) -> EraId {
+        self.start_era
+    }
+
+    /// Returns the number of eras during which the whole initial stake is locked.
+    pub fn cliff_eras(&self) -> u64 {
+        self.cliff_eras
+    }
+
+    /// Returns the number of eras over which the stake is released after the cliff.
+    pub fn release_eras(&self) -> u64 {
+        self.release_eras
+    }
+
+    /// Returns the amount locked by the schedule before anything is released.
+    pub fn initial_locked_amount(&self) -> U512 {
+        self.initial_locked_amount
+    }
+
+    /// Returns the amount which is still locked in the given era.
+    pub fn locked_amount(&self, era_id: EraId) -> U512 {
+        let elapsed_eras = era_id.saturating_sub(self.start_era);
+        if elapsed_eras < self.cliff_eras {
+            return self.initial_locked_amount;
+        }
+
+        let released_installments = (elapsed_eras - self.cliff_eras).saturating_add(1);
+        if released_installments >= self.release_eras {
+            return U512::zero();
+        }
+
+        let installment = self.initial_locked_amount / U512::from(self.release_eras);
+        self.initial_locked_amount - installment * U512::from(released_installments)
+    }
+}
+
+impl ToBytes for EraVestingSchedule {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.start_era.to_bytes()?);
+        result.append(&mut self.cliff_eras.to_bytes()?);
+        result.append(&mut self.release_eras.to_bytes()?);
+        result.append(&mut self.initial_locked_amount.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.start_era.serialized_length()
+            + self.cliff_eras.serialized_length()
+            + self.release_eras.serialized_length()
+            + self.initial_locked_amount.serialized_length()
+    }
+}
+
+impl FromBytes for EraVestingSchedule {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (start_era, bytes) = FromBytes::from_bytes(bytes)?;
+        let (cliff_eras, bytes) = FromBytes::from_bytes(bytes)?;
+        let (release_eras, bytes) = FromBytes::from_bytes(bytes)?;
+        let (initial_locked_amount, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            EraVestingSchedule {
+                start_era,
+                cliff_eras,
+                release_eras,
+                initial_locked_amount,
+            },
+            bytes,
+        ))
+    }
+}
+
+/// Generators for [`EraVestingSchedule`]
+#[cfg(test)]
+mod gens {
+    use proptest::prelude::{Arbitrary, Strategy};
+
+    use super::EraVestingSchedule;
+    use crate::gens::u512_arb;
+
+    pub fn era_vesting_schedule_arb() -> impl Strategy<Value = EraVestingSchedule> {
+        (
+            <u64>::arbitrary(),
+            <u64>::arbitrary(),
+            <u64>::arbitrary(),
+            u512_arb(),
+        )
+            .prop_map(
+                |(start_era, cliff_eras, release_eras, initial_locked_amount)| {
+                    EraVestingSchedule::new(
+                        start_era,
+                        cliff_eras,
+                        release_eras,
+                        initial_locked_amount,
+                    )
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    use crate::{
+        bytesrepr,
+        gens::u512_arb,
+        system::auction::bid::{era_vesting::gens::era_vesting_schedule_arb, EraVestingSchedule},
+        U512,
+    };
+
+    #[test]
+    fn should_lock_everything_until_cliff() {
+        let schedule = EraVestingSchedule::new(10, 3, 4, U512::from(100));
+        assert_eq!(schedule.locked_amount(0), U512::from(100));
+        assert_eq!(schedule.locked_amount(10), U512::from(100));
+        assert_eq!(schedule.locked_amount(12), U512::from(100));
+    }
+
+    #[test]
+    fn should_release_linearly_after_cliff() {
+        let schedule = EraVestingSchedule::new(10, 3, 4, U512::from(100));
+        assert_eq!(schedule.locked_amount(13), U512::from(75));
+        assert_eq!(schedule.locked_amount(14), U512::from(50));
+        assert_eq!(schedule.locked_amount(15), U512::from(25));
+        assert_eq!(schedule.locked_amount(16), U512::zero());
+        assert_eq!(schedule.locked_amount(u64::max_value()), U512::zero());
+    }
+
+    #[test]
+    fn should_keep_remainder_locked_until_last_installment() {
+        let schedule = EraVestingSchedule::new(0, 0, 3, U512::from(10));
+        assert_eq!(schedule.locked_amount(0), U512::from(7));
+        assert_eq!(schedule.locked_amount(1), U512::from(4));
+        assert_eq!(schedule.locked_amount(2), U512::zero());
+    }
+
+    #[test]
+    fn should_release_everything_at_cliff_without_release_period() {
+        let schedule = EraVestingSchedule::new(0, 5, 0, U512::from(100));
+        assert_eq!(schedule.locked_amount(4), U512::from(100));
+        assert_eq!(schedule.locked_amount(5), U512::zero());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_locked_amount_never_increases(
+            amount in u512_arb(),
+            cliff_eras in 0u64..100,
+            release_eras in 0u64..100,
+        ) {
+            let schedule = EraVestingSchedule::new(0, cliff_eras, release_eras, amount);
+            let mut previous = schedule.locked_amount(0);
+            for era_id in 1..=cliff_eras + release_eras {
+                let locked_amount = schedule.locked_amount(era_id);
+                prop_assert!(locked_amount <= previous);
+                previous = locked_amount;
+            }
+            prop_assert_eq!(previous, U512::zero());
+        }
+
+        #[test]
+        fn prop_serialization_roundtrip(schedule in era_vesting_schedule_arb()) {
+            bytesrepr::test_serialization_roundtrip(&schedule)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
+mod era_vesting;
 mod vesting;
 
 use alloc::{collections::BTreeMap, vec::Vec};
@@ -11,12 +12,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{DelegationRate, Delegator, Error},
-    CLType, CLTyped, PublicKey, URef, U512,
+    system::auction::{layout, ContractDelegator, DelegationRate, Delegator, EraId, Error},
+    CLType, CLTyped, ContractPackageHash, PublicKey, URef, U512,
 };
 
+pub use era_vesting::EraVestingSchedule;
 pub use vesting::VestingSchedule;
 
+/// The current version of the serialized layout of a [`Bid`].
+///
+/// Version 1 appends the delegation rate change era, the contract delegators and the era-based
+/// vesting schedule to the fields of a bid written before its layout was versioned.
+const BID_LAYOUT_VERSION: u8 = 1;
+
 /// An entry in the validator map.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -36,6 +44,13 @@ pub struct Bid {
     delegators: BTreeMap<PublicKey, Delegator>,
     /// `true` if validator has been "evicted"
     inactive: bool,
+    /// Era in which the delegation rate was last changed. `None` if never changed.
+    delegation_rate_changed_era: Option<EraId>,
+    /// This validator's contract delegators, indexed by their contract package hashes
+    contract_delegators: BTreeMap<ContractPackageHash, ContractDelegator>,
+    /// Era-based vesting schedule for a genesis validator. `None` if non-genesis validator or if
+    /// the validator's funds are locked by `vesting_schedule` instead.
+    era_vesting_schedule: Option<EraVestingSchedule>,
 }
 
 impl Bid {
@@ -58,9 +73,30 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            delegation_rate_changed_era: None,
+            contract_delegators: BTreeMap::new(),
+            era_vesting_schedule: None,
         }
     }
 
+    /// Creates new instance of a bid with funds locked according to an era-based vesting schedule.
+    pub fn vesting(
+        validator_public_key: PublicKey,
+        bonding_purse: URef,
+        staked_amount: U512,
+        delegation_rate: DelegationRate,
+        era_vesting_schedule: EraVestingSchedule,
+    ) -> Self {
+        let mut bid = Self::unlocked(
+            validator_public_key,
+            bonding_purse,
+            staked_amount,
+            delegation_rate,
+        );
+        bid.era_vesting_schedule = Some(era_vesting_schedule);
+        bid
+    }
+
     /// Creates new instance of a bid with unlocked funds.
     pub fn unlocked(
         validator_public_key: PublicKey,
@@ -79,6 +115,9 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            delegation_rate_changed_era: None,
+            contract_delegators: BTreeMap::new(),
+            era_vesting_schedule: None,
         }
     }
 
@@ -107,6 +146,12 @@ impl Bid {
         &self.delegation_rate
     }
 
+    /// Gets the era in which the delegation rate of the provided bid was last changed.  `None` if
+    /// it was never changed.
+    pub fn delegation_rate_changed_era(&self) -> Option<EraId> {
+        self.delegation_rate_changed_era
+    }
+
     /// Returns a reference to the vesting schedule of the provided bid.  `None` if a non-genesis
     /// validator.
     pub fn vesting_schedule(&self) -> Option<&VestingSchedule> {
@@ -119,6 +164,17 @@ impl Bid {
         self.vesting_schedule.as_mut()
     }
 
+    /// Returns a reference to the era-based vesting schedule of the provided bid.  `None` if a
+    /// non-genesis validator or if the funds are locked by the timestamp-based vesting schedule.
+    pub fn era_vesting_schedule(&self) -> Option<&EraVestingSchedule> {
+        self.era_vesting_schedule.as_ref()
+    }
+
+    /// Returns `true` if the provided bid is owned by a genesis validator with locked funds.
+    pub fn is_founder(&self) -> bool {
+        self.vesting_schedule.is_some() || self.era_vesting_schedule.is_some()
+    }
+
     /// Returns a reference to the delegators of the provided bid
     pub fn delegators(&self) -> &BTreeMap<PublicKey, Delegator> {
         &self.delegators
@@ -129,6 +185,23 @@ impl Bid {
         &mut self.delegators
     }
 
+    /// Returns a reference to the contract delegators of the provided bid
+    pub fn contract_delegators(&self) -> &BTreeMap<ContractPackageHash, ContractDelegator> {
+        &self.contract_delegators
+    }
+
+    /// Returns a mutable reference to the contract delegators of the provided bid
+    pub fn contract_delegators_mut(
+        &mut self,
+    ) -> &mut BTreeMap<ContractPackageHash, ContractDelegator> {
+        &mut self.contract_delegators
+    }
+
+    /// Returns the number of delegators of the provided bid, including contract delegators
+    pub fn delegator_count(&self) -> usize {
+        self.delegators.len() + self.contract_delegators.len()
+    }
+
     /// Returns `true` if validator is inactive
     pub fn inactive(&self) -> bool {
         self.inactive
@@ -138,6 +211,7 @@ impl Bid {
     pub fn decrease_stake(
         &mut self,
         amount: U512,
+        era_id: EraId,
         era_end_timestamp_millis: u64,
     ) -> Result<U512, Error> {
         let updated_staked_amount = self
@@ -145,6 +219,12 @@ impl Bid {
             .checked_sub(amount)
             .ok_or(Error::UnbondTooLarge)?;
 
+        if let Some(era_vesting_schedule) = self.era_vesting_schedule.as_ref() {
+            if updated_staked_amount < era_vesting_schedule.locked_amount(era_id) {
+                return Err(Error::ValidatorFundsLocked);
+            }
+        }
+
         let vesting_schedule = match self.vesting_schedule.as_ref() {
             Some(vesting_sechdule) => vesting_sechdule,
             None => {
@@ -187,6 +267,39 @@ impl Bid {
         self
     }
 
+    /// Changes the delegation rate of the provided bid in the given era.
+    ///
+    /// The rate may be changed at most once per era and by no more than `max_change` in either
+    /// direction.  Setting the current rate again is always allowed and is not recorded as a
+    /// change.
+    pub fn change_delegation_rate(
+        &mut self,
+        delegation_rate: DelegationRate,
+        max_change: DelegationRate,
+        era_id: EraId,
+    ) -> Result<&mut Self, Error> {
+        if delegation_rate == self.delegation_rate {
+            return Ok(self);
+        }
+
+        if self.delegation_rate_changed_era == Some(era_id) {
+            return Err(Error::DelegationRateChangeCooldown);
+        }
+
+        let change = if delegation_rate > self.delegation_rate {
+            delegation_rate - self.delegation_rate
+        } else {
+            self.delegation_rate - delegation_rate
+        };
+        if change > max_change {
+            return Err(Error::DelegationRateChangeTooLarge);
+        }
+
+        self.delegation_rate = delegation_rate;
+        self.delegation_rate_changed_era = Some(era_id);
+        Ok(self)
+    }
+
     /// Initializes the vesting schedule of provided bid if the provided timestamp is greater than
     /// or equal to the bid's initial release timestamp and the bid is owned by a genesis
     /// validator.
@@ -235,12 +348,19 @@ impl Bid {
         true
     }
 
-    /// Returns the total staked amount of validator + all delegators
+    /// Returns the total staked amount of validator + all delegators, including contract
+    /// delegators
     pub fn total_staked_amount(&self) -> Result<U512, Error> {
         self.delegators
-            .iter()
-            .fold(Some(U512::zero()), |maybe_a, (_, b)| {
-                maybe_a.and_then(|a| a.checked_add(*b.staked_amount()))
+            .values()
+            .map(Delegator::staked_amount)
+            .chain(
+                self.contract_delegators
+                    .values()
+                    .map(ContractDelegator::staked_amount),
+            )
+            .fold(Some(U512::zero()), |maybe_a, b| {
+                maybe_a.and_then(|a| a.checked_add(*b))
             })
             .and_then(|delegators_sum| delegators_sum.checked_add(*self.staked_amount()))
             .ok_or(Error::InvalidAmount)
@@ -256,6 +376,7 @@ impl CLTyped for Bid {
 impl ToBytes for Bid {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
+        layout::write_layout_version(BID_LAYOUT_VERSION, &mut result);
         result.extend(self.validator_public_key.to_bytes()?);
         result.extend(self.bonding_purse.to_bytes()?);
         result.extend(self.staked_amount.to_bytes()?);
@@ -263,22 +384,30 @@ impl ToBytes for Bid {
         result.extend(self.vesting_schedule.to_bytes()?);
         result.extend(self.delegators.to_bytes()?);
         result.extend(self.inactive.to_bytes()?);
+        result.extend(self.delegation_rate_changed_era.to_bytes()?);
+        result.extend(self.contract_delegators.to_bytes()?);
+        result.extend(self.era_vesting_schedule.to_bytes()?);
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        self.validator_public_key.serialized_length()
+        layout::LAYOUT_VERSION_SERIALIZED_LENGTH
+            + self.validator_public_key.serialized_length()
             + self.bonding_purse.serialized_length()
             + self.staked_amount.serialized_length()
             + self.delegation_rate.serialized_length()
             + self.vesting_schedule.serialized_length()
             + self.delegators.serialized_length()
             + self.inactive.serialized_length()
+            + self.delegation_rate_changed_era.serialized_length()
+            + self.contract_delegators.serialized_length()
+            + self.era_vesting_schedule.serialized_length()
     }
 }
 
 impl FromBytes for Bid {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (version, bytes) = layout::read_layout_version(bytes)?;
         let (validator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
         let (bonding_purse, bytes) = FromBytes::from_bytes(bytes)?;
         let (staked_amount, bytes) = FromBytes::from_bytes(bytes)?;
@@ -286,47 +415,123 @@ impl FromBytes for Bid {
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegators, bytes) = FromBytes::from_bytes(bytes)?;
         let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
-        Ok((
-            Bid {
-                validator_public_key,
-                bonding_purse,
-                staked_amount,
-                delegation_rate,
-                vesting_schedule,
-                delegators,
-                inactive,
-            },
-            bytes,
-        ))
+        let mut bid = Bid {
+            validator_public_key,
+            bonding_purse,
+            staked_amount,
+            delegation_rate,
+            vesting_schedule,
+            delegators,
+            inactive,
+            delegation_rate_changed_era: None,
+            contract_delegators: BTreeMap::new(),
+            era_vesting_schedule: None,
+        };
+        match version {
+            None => Ok((bid, bytes)),
+            Some(BID_LAYOUT_VERSION) => {
+                let (delegation_rate_changed_era, bytes) = FromBytes::from_bytes(bytes)?;
+                let (contract_delegators, bytes) = FromBytes::from_bytes(bytes)?;
+                let (era_vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
+                bid.delegation_rate_changed_era = delegation_rate_changed_era;
+                bid.contract_delegators = contract_delegators;
+                bid.era_vesting_schedule = era_vesting_schedule;
+                Ok((bid, bytes))
+            }
+            Some(_) => Err(bytesrepr::Error::Formatting),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloc::collections::BTreeMap;
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use core::iter::FromIterator;
 
     use crate::{
-        bytesrepr,
-        system::auction::{bid::VestingSchedule, Bid, DelegationRate, Delegator},
-        AccessRights, PublicKey, SecretKey, URef, U512,
+        bytesrepr::{self, ToBytes},
+        system::auction::{
+            bid::{EraVestingSchedule, VestingSchedule, BID_LAYOUT_VERSION},
+            Bid, ContractDelegator, DelegationRate, Delegator, Error,
+        },
+        AccessRights, ContractPackageHash, PublicKey, SecretKey, URef, U512,
     };
 
     #[test]
     fn serialization_roundtrip() {
+        let validator_public_key =
+            PublicKey::from(SecretKey::ed25519([0u8; SecretKey::ED25519_LENGTH]));
+        let contract_package_hash = ContractPackageHash::new([43; 32]);
+        let contract_delegator = ContractDelegator::new(
+            contract_package_hash,
+            U512::one(),
+            URef::new([44; 32], AccessRights::READ_ADD_WRITE),
+            URef::new([45; 32], AccessRights::ADD),
+            validator_public_key,
+        );
         let founding_validator = Bid {
-            validator_public_key: PublicKey::from(SecretKey::ed25519(
-                [0u8; SecretKey::ED25519_LENGTH],
-            )),
+            validator_public_key,
             bonding_purse: URef::new([42; 32], AccessRights::READ_ADD_WRITE),
             staked_amount: U512::one(),
             delegation_rate: DelegationRate::max_value(),
             vesting_schedule: Some(VestingSchedule::default()),
             delegators: BTreeMap::default(),
             inactive: true,
+            delegation_rate_changed_era: Some(u64::max_value()),
+            contract_delegators: BTreeMap::from_iter(vec![(
+                contract_package_hash,
+                contract_delegator,
+            )]),
+            era_vesting_schedule: Some(EraVestingSchedule::new(1, 2, 3, U512::from(4))),
         };
         bytesrepr::test_serialization_roundtrip(&founding_validator);
     }
 
+    #[test]
+    fn should_deserialize_bid_written_before_layout_was_versioned() {
+        let mut bid = Bid::locked(
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::from(1000),
+            10,
+            u64::max_value(),
+        );
+        bid.deactivate();
+
+        let mut legacy_bytes = Vec::new();
+        legacy_bytes.append(&mut bid.validator_public_key.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.bonding_purse.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.staked_amount.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.delegation_rate.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.vesting_schedule.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.delegators.to_bytes().unwrap());
+        legacy_bytes.append(&mut bid.inactive.to_bytes().unwrap());
+
+        let decoded: Bid = bytesrepr::deserialize(legacy_bytes).expect("should deserialize");
+        assert_eq!(decoded, bid);
+        assert_eq!(decoded.delegation_rate_changed_era(), None);
+
+        // Bids inside a collection must not swallow the bytes of the next element.
+        let bids = vec![bid.clone(), bid];
+        bytesrepr::test_serialization_roundtrip(&bids);
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_bid_layout_version() {
+        let bid = Bid::unlocked(
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::from(1000),
+            10,
+        );
+        let mut bytes = bid.to_bytes().unwrap();
+        bytes[1] = BID_LAYOUT_VERSION + 1;
+        assert_eq!(
+            bytesrepr::deserialize::<Bid>(bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
     #[test]
     fn should_initialize_delegators_different_timestamps() {
         const WEEK_MILLIS: u64 = 7 * 24 * 60 * 60 * 1000;
@@ -424,4 +629,74 @@ mod tests {
         // Validator initialized, and all delegators initialized
         assert!(!bid.process(delegator_2_release_timestamp + 1));
     }
+
+    #[test]
+    fn should_enforce_delegation_rate_change_bounds() {
+        const MAX_CHANGE: DelegationRate = 5;
+
+        let mut bid = Bid::unlocked(
+            SecretKey::ed25519([42; 32]).into(),
+            URef::new([42; 32], AccessRights::ADD),
+            U512::from(1000),
+            10,
+        );
+
+        assert_eq!(
+            bid.change_delegation_rate(16, MAX_CHANGE, 1),
+            Err(Error::DelegationRateChangeTooLarge)
+        );
+        assert!(bid.change_delegation_rate(15, MAX_CHANGE, 1).is_ok());
+        assert_eq!(*bid.delegation_rate(), 15);
+        assert_eq!(bid.delegation_rate_changed_era(), Some(1));
+
+        // Only one change per era, but re-stating the current rate is fine.
+        assert_eq!(
+            bid.change_delegation_rate(12, MAX_CHANGE, 1),
+            Err(Error::DelegationRateChangeCooldown)
+        );
+        assert!(bid.change_delegation_rate(15, MAX_CHANGE, 1).is_ok());
+
+        assert!(bid.change_delegation_rate(10, MAX_CHANGE, 2).is_ok());
+        assert_eq!(*bid.delegation_rate(), 10);
+        assert_eq!(bid.delegation_rate_changed_era(), Some(2));
+    }
+
+    #[test]
+    fn should_enforce_era_vesting_schedule_on_decrease_stake() {
+        const START_ERA: u64 = 0;
+        const CLIFF_ERAS: u64 = 2;
+        const RELEASE_ERAS: u64 = 4;
+
+        let staked_amount = U512::from(1000);
+        let era_vesting_schedule =
+            EraVestingSchedule::new(START_ERA, CLIFF_ERAS, RELEASE_ERAS, staked_amount);
+        let mut bid = Bid::vesting(
+            SecretKey::ed25519([42; 32]).into(),
+            URef::new([42; 32], AccessRights::ADD),
+            staked_amount,
+            0,
+            era_vesting_schedule,
+        );
+        assert!(bid.is_founder());
+
+        assert_eq!(
+            bid.decrease_stake(U512::one(), CLIFF_ERAS - 1, 0),
+            Err(Error::ValidatorFundsLocked)
+        );
+
+        // First installment of 250 motes is released at the end of the cliff.
+        assert_eq!(
+            bid.decrease_stake(U512::from(251), CLIFF_ERAS, 0),
+            Err(Error::ValidatorFundsLocked)
+        );
+        assert_eq!(
+            bid.decrease_stake(U512::from(250), CLIFF_ERAS, 0),
+            Ok(U512::from(750))
+        );
+
+        assert_eq!(
+            bid.decrease_stake(U512::from(750), CLIFF_ERAS + RELEASE_ERAS - 1, 0),
+            Ok(U512::zero())
+        );
+    }
 }
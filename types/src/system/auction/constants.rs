@@ -48,14 +48,20 @@ pub const ARG_MINT_CONTRACT_PACKAGE_HASH: &str = "mint_contract_package_hash";
 pub const ARG_GENESIS_VALIDATORS: &str = "genesis_validators";
 /// Named constant of `auction_delay`
 pub const ARG_AUCTION_DELAY: &str = "auction_delay";
-/// Named constant for `locked_funds_period`
-pub const ARG_LOCKED_FUNDS_PERIOD: &str = "locked_funds_period";
 /// Named constant for `unbonding_delay`
 pub const ARG_UNBONDING_DELAY: &str = "unbonding_delay";
 /// Named constant for `era_end_timestamp_millis`;
 pub const ARG_ERA_END_TIMESTAMP_MILLIS: &str = "era_end_timestamp_millis";
 /// Named constant for `evicted_validators`;
 pub const ARG_EVICTED_VALIDATORS: &str = "evicted_validators";
+/// Named constant for `name`.
+pub const ARG_NAME: &str = "name";
+/// Named constant for `url`.
+pub const ARG_URL: &str = "url";
+/// Named constant for `icon_hash`.
+pub const ARG_ICON_HASH: &str = "icon_hash";
+/// Named constant for `source_purse`.
+pub const ARG_SOURCE_PURSE: &str = "source_purse";
 
 /// Named constant for method `get_era_validators`.
 pub const METHOD_GET_ERA_VALIDATORS: &str = "get_era_validators";
@@ -67,6 +73,10 @@ pub const METHOD_WITHDRAW_BID: &str = "withdraw_bid";
 pub const METHOD_DELEGATE: &str = "delegate";
 /// Named constant for method `undelegate`.
 pub const METHOD_UNDELEGATE: &str = "undelegate";
+/// Named constant for method `delegate_from_contract`.
+pub const METHOD_DELEGATE_FROM_CONTRACT: &str = "delegate_from_contract";
+/// Named constant for method `undelegate_from_contract`.
+pub const METHOD_UNDELEGATE_FROM_CONTRACT: &str = "undelegate_from_contract";
 /// Named constant for method `run_auction`.
 pub const METHOD_RUN_AUCTION: &str = "run_auction";
 /// Named constant for method `slash`.
@@ -77,6 +87,16 @@ pub const METHOD_DISTRIBUTE: &str = "distribute";
 pub const METHOD_READ_ERA_ID: &str = "read_era_id";
 /// Named constant for method `activate_bid`.
 pub const METHOD_ACTIVATE_BID: &str = "activate_bid";
+/// Named constant for method `cancel_validator_unbond`.
+pub const METHOD_CANCEL_VALIDATOR_UNBOND: &str = "cancel_validator_unbond";
+/// Named constant for method `cancel_delegator_unbond`.
+pub const METHOD_CANCEL_DELEGATOR_UNBOND: &str = "cancel_delegator_unbond";
+/// Named constant for method `get_bid`.
+pub const METHOD_GET_BID: &str = "get_bid";
+/// Named constant for method `get_delegator`.
+pub const METHOD_GET_DELEGATOR: &str = "get_delegator";
+/// Named constant for method `set_validator_info`.
+pub const METHOD_SET_VALIDATOR_INFO: &str = "set_validator_info";
 
 /// Storage for `EraId`.
 pub const ERA_ID_KEY: &str = "era_id";
@@ -88,7 +108,20 @@ pub const SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY: &str = "seigniorage_recipients_sn
 pub const VALIDATOR_SLOTS_KEY: &str = "validator_slots";
 /// Amount of auction delay.
 pub const AUCTION_DELAY_KEY: &str = "auction_delay";
-/// Default lock period for new bid entries represented in eras.
-pub const LOCKED_FUNDS_PERIOD_KEY: &str = "locked_funds_period";
 /// Unbonding delay expressed in eras.
 pub const UNBONDING_DELAY_KEY: &str = "unbonding_delay";
+/// Upper bound of a validator's delegation rate.
+pub const MAX_DELEGATION_RATE_KEY: &str = "max_delegation_rate";
+/// Maximum amount by which a validator's delegation rate may change in a single era.
+pub const MAX_DELEGATION_RATE_CHANGE_KEY: &str = "max_delegation_rate_change";
+/// Maximum number of delegators, including contract delegators, of a single validator.
+pub const MAX_DELEGATORS_PER_VALIDATOR_KEY: &str = "max_delegators_per_validator";
+/// Storage for `StakingStats`.
+pub const STAKING_STATS_KEY: &str = "staking_stats";
+/// Prefix of the named keys under which validators' `ValidatorInfo` records are stored.
+pub const VALIDATOR_INFO_KEY_PREFIX: &str = "validator_info_";
+
+/// Maximum length in bytes of a validator's name.
+pub const MAX_VALIDATOR_NAME_LENGTH: usize = 64;
+/// Maximum length in bytes of a validator's URL.
+pub const MAX_VALIDATOR_URL_LENGTH: usize = 256;
@@ -0,0 +1,169 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::Error,
+    CLType, CLTyped, ContractPackageHash, PublicKey, URef, U512,
+};
+
+/// Represents a contract delegating the funds of one of its purses to a validator.
+///
+/// Unlike a [`Delegator`](crate::system::auction::Delegator), a contract has no account to be paid
+/// out to, so it is identified by its contract package and unbonded funds are paid out to its
+/// unbond purse instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractDelegator {
+    contract_package_hash: ContractPackageHash,
+    staked_amount: U512,
+    bonding_purse: URef,
+    unbond_purse: URef,
+    validator_public_key: PublicKey,
+}
+
+impl ContractDelegator {
+    /// Creates a new [`ContractDelegator`].
+    pub fn new(
+        contract_package_hash: ContractPackageHash,
+        staked_amount: U512,
+        bonding_purse: URef,
+        unbond_purse: URef,
+        validator_public_key: PublicKey,
+    ) -> Self {
+        ContractDelegator {
+            contract_package_hash,
+            staked_amount,
+            bonding_purse,
+            unbond_purse,
+            validator_public_key,
+        }
+    }
+
+    /// Returns the contract package of the delegating contract
+    pub fn contract_package_hash(&self) -> &ContractPackageHash {
+        &self.contract_package_hash
+    }
+
+    /// Returns the staked amount
+    pub fn staked_amount(&self) -> &U512 {
+        &self.staked_amount
+    }
+
+    /// Returns the mutable staked amount
+    pub fn staked_amount_mut(&mut self) -> &mut U512 {
+        &mut self.staked_amount
+    }
+
+    /// Returns the bonding purse
+    pub fn bonding_purse(&self) -> &URef {
+        &self.bonding_purse
+    }
+
+    /// Returns the purse unbonded funds are paid out to
+    pub fn unbond_purse(&self) -> &URef {
+        &self.unbond_purse
+    }
+
+    /// Returns delegatee
+    pub fn validator_public_key(&self) -> &PublicKey {
+        &self.validator_public_key
+    }
+
+    /// Decreases the stake of the provided delegator
+    pub fn decrease_stake(&mut self, amount: U512) -> Result<U512, Error> {
+        let updated_staked_amount = self
+            .staked_amount
+            .checked_sub(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        self.staked_amount = updated_staked_amount;
+
+        Ok(updated_staked_amount)
+    }
+
+    /// Increases the stake of the provided delegator
+    pub fn increase_stake(&mut self, amount: U512) -> Result<U512, Error> {
+        let updated_staked_amount = self
+            .staked_amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        self.staked_amount = updated_staked_amount;
+
+        Ok(updated_staked_amount)
+    }
+}
+
+impl CLTyped for ContractDelegator {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ContractDelegator {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.contract_package_hash.to_bytes()?);
+        buffer.extend(self.staked_amount.to_bytes()?);
+        buffer.extend(self.bonding_purse.to_bytes()?);
+        buffer.extend(self.unbond_purse.to_bytes()?);
+        buffer.extend(self.validator_public_key.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_package_hash.serialized_length()
+            + self.staked_amount.serialized_length()
+            + self.bonding_purse.serialized_length()
+            + self.unbond_purse.serialized_length()
+            + self.validator_public_key.serialized_length()
+    }
+}
+
+impl FromBytes for ContractDelegator {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_package_hash, bytes) = ContractPackageHash::from_bytes(bytes)?;
+        let (staked_amount, bytes) = U512::from_bytes(bytes)?;
+        let (bonding_purse, bytes) = URef::from_bytes(bytes)?;
+        let (unbond_purse, bytes) = URef::from_bytes(bytes)?;
+        let (validator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
+        Ok((
+            ContractDelegator {
+                contract_package_hash,
+                staked_amount,
+                bonding_purse,
+                unbond_purse,
+                validator_public_key,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytesrepr, system::auction::ContractDelegator, AccessRights, ContractPackageHash,
+        SecretKey, URef, U512,
+    };
+
+    #[test]
+    fn serialization_roundtrip() {
+        let contract_delegator = ContractDelegator::new(
+            ContractPackageHash::new([41; 32]),
+            U512::one(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            URef::new([43; 32], AccessRights::ADD),
+            SecretKey::ed25519([44; SecretKey::ED25519_LENGTH]).into(),
+        );
+        bytesrepr::test_serialization_roundtrip(&contract_delegator);
+    }
+}
@@ -9,10 +9,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{bid::VestingSchedule, Error},
+    system::auction::{bid::VestingSchedule, layout, EraId, EraVestingSchedule, Error},
     CLType, CLTyped, PublicKey, URef, U512,
 };
 
+/// The current version of the serialized layout of a [`Delegator`].
+///
+/// Version 1 appends the era-based vesting schedule to the fields of a delegator written before
+/// its layout was versioned.
+const DELEGATOR_LAYOUT_VERSION: u8 = 1;
+
 /// Represents a party delegating their stake to a validator (or "delegatee")
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -23,6 +29,7 @@ pub struct Delegator {
     bonding_purse: URef,
     validator_public_key: PublicKey,
     vesting_schedule: Option<VestingSchedule>,
+    era_vesting_schedule: Option<EraVestingSchedule>,
 }
 
 impl Delegator {
@@ -40,6 +47,7 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            era_vesting_schedule: None,
         }
     }
 
@@ -58,9 +66,29 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            era_vesting_schedule: None,
         }
     }
 
+    /// Creates new instance of a [`Delegator`] with funds locked according to an era-based
+    /// vesting schedule.
+    pub fn vesting(
+        delegator_public_key: PublicKey,
+        staked_amount: U512,
+        bonding_purse: URef,
+        validator_public_key: PublicKey,
+        era_vesting_schedule: EraVestingSchedule,
+    ) -> Self {
+        let mut delegator = Self::unlocked(
+            delegator_public_key,
+            staked_amount,
+            bonding_purse,
+            validator_public_key,
+        );
+        delegator.era_vesting_schedule = Some(era_vesting_schedule);
+        delegator
+    }
+
     /// Returns the staked amount
     pub fn staked_amount(&self) -> &U512 {
         &self.staked_amount
@@ -85,6 +113,7 @@ impl Delegator {
     pub fn decrease_stake(
         &mut self,
         amount: U512,
+        era_id: EraId,
         era_end_timestamp_millis: u64,
     ) -> Result<U512, Error> {
         let updated_staked_amount = self
@@ -92,6 +121,12 @@ impl Delegator {
             .checked_sub(amount)
             .ok_or(Error::InvalidAmount)?;
 
+        if let Some(era_vesting_schedule) = self.era_vesting_schedule.as_ref() {
+            if updated_staked_amount < era_vesting_schedule.locked_amount(era_id) {
+                return Err(Error::DelegatorFundsLocked);
+            }
+        }
+
         let vesting_schedule = match self.vesting_schedule.as_ref() {
             Some(vesting_sechdule) => vesting_sechdule,
             None => {
@@ -139,6 +174,13 @@ impl Delegator {
     pub fn vesting_schedule_mut(&mut self) -> Option<&mut VestingSchedule> {
         self.vesting_schedule.as_mut()
     }
+
+    /// Returns a reference to the era-based vesting schedule of the provided delegator bid.
+    /// `None` if a non-genesis delegator or if the funds are locked by the timestamp-based vesting
+    /// schedule.
+    pub fn era_vesting_schedule(&self) -> Option<&EraVestingSchedule> {
+        self.era_vesting_schedule.as_ref()
+    }
 }
 
 impl CLTyped for Delegator {
@@ -150,30 +192,40 @@ impl CLTyped for Delegator {
 impl ToBytes for Delegator {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        layout::write_layout_version(DELEGATOR_LAYOUT_VERSION, &mut buffer);
         buffer.extend(self.delegator_public_key.to_bytes()?);
         buffer.extend(self.staked_amount.to_bytes()?);
         buffer.extend(self.bonding_purse.to_bytes()?);
         buffer.extend(self.validator_public_key.to_bytes()?);
         buffer.extend(self.vesting_schedule.to_bytes()?);
+        buffer.extend(self.era_vesting_schedule.to_bytes()?);
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.delegator_public_key.serialized_length()
+        layout::LAYOUT_VERSION_SERIALIZED_LENGTH
+            + self.delegator_public_key.serialized_length()
             + self.staked_amount.serialized_length()
             + self.bonding_purse.serialized_length()
             + self.validator_public_key.serialized_length()
             + self.vesting_schedule.serialized_length()
+            + self.era_vesting_schedule.serialized_length()
     }
 }
 
 impl FromBytes for Delegator {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (version, bytes) = layout::read_layout_version(bytes)?;
         let (delegator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
         let (staked_amount, bytes) = U512::from_bytes(bytes)?;
         let (bonding_purse, bytes) = URef::from_bytes(bytes)?;
         let (validator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
+        let (era_vesting_schedule, bytes) = match version {
+            None => (None, bytes),
+            Some(DELEGATOR_LAYOUT_VERSION) => FromBytes::from_bytes(bytes)?,
+            Some(_) => return Err(bytesrepr::Error::Formatting),
+        };
         Ok((
             Delegator {
                 delegator_public_key,
@@ -181,6 +233,7 @@ impl FromBytes for Delegator {
                 bonding_purse,
                 validator_public_key,
                 vesting_schedule,
+                era_vesting_schedule,
             },
             bytes,
         ))
@@ -189,7 +242,13 @@ impl FromBytes for Delegator {
 
 #[cfg(test)]
 mod tests {
-    use crate::{bytesrepr, system::auction::Delegator, AccessRights, SecretKey, URef, U512};
+    use alloc::vec::Vec;
+
+    use crate::{
+        bytesrepr::{self, ToBytes},
+        system::auction::{Delegator, EraVestingSchedule},
+        AccessRights, SecretKey, URef, U512,
+    };
 
     #[test]
     fn serialization_roundtrip() {
@@ -214,5 +273,35 @@ mod tests {
             release_timestamp_millis,
         );
         bytesrepr::test_serialization_roundtrip(&locked_delegator);
+
+        let vesting_delegator = Delegator::vesting(
+            delegator_public_key,
+            staked_amount,
+            bonding_purse,
+            validator_public_key,
+            EraVestingSchedule::new(0, 1, 2, staked_amount),
+        );
+        bytesrepr::test_serialization_roundtrip(&vesting_delegator);
+    }
+
+    #[test]
+    fn should_deserialize_delegator_written_before_layout_was_versioned() {
+        let delegator = Delegator::locked(
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into(),
+            U512::one(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into(),
+            42,
+        );
+
+        let mut legacy_bytes = Vec::new();
+        legacy_bytes.append(&mut delegator.delegator_public_key.to_bytes().unwrap());
+        legacy_bytes.append(&mut delegator.staked_amount.to_bytes().unwrap());
+        legacy_bytes.append(&mut delegator.bonding_purse.to_bytes().unwrap());
+        legacy_bytes.append(&mut delegator.validator_public_key.to_bytes().unwrap());
+        legacy_bytes.append(&mut delegator.vesting_schedule.to_bytes().unwrap());
+
+        let decoded: Delegator = bytesrepr::deserialize(legacy_bytes).expect("should deserialize");
+        assert_eq!(decoded, delegator);
     }
 }
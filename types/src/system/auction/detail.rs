@@ -7,10 +7,11 @@ use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        constants::*, Auction, Bids, EraId, Error, RuntimeProvider, SeigniorageAllocation,
-        SeigniorageRecipientsSnapshot, StorageProvider, UnbondingPurse, UnbondingPurses,
+        constants::*, Auction, Bid, Bids, DelegationRate, EraId, Error, RuntimeProvider,
+        SeigniorageAllocation, SeigniorageRecipientsSnapshot, StakingStats, StorageProvider,
+        UnbondingPurse, UnbondingPurses,
     },
-    CLTyped, Key, KeyTag, PublicKey, URef, U512,
+    CLTyped, ContractPackageHash, Key, KeyTag, PublicKey, URef, U512,
 };
 
 fn read_from<P, T>(provider: &mut P, name: &str) -> Result<T, Error>
@@ -182,6 +183,76 @@ where
     read_from(provider, UNBONDING_DELAY_KEY)
 }
 
+/// Reads a delegation rate bound, defaulting to [`DELEGATION_RATE_DENOMINATOR`] (i.e. no
+/// restriction) for auction contracts installed before the bound was introduced.
+fn read_delegation_rate_bound<P>(provider: &mut P, name: &str) -> Result<DelegationRate, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider.named_keys_get(name).is_none() {
+        return Ok(DELEGATION_RATE_DENOMINATOR);
+    }
+    read_from(provider, name)
+}
+
+pub fn get_max_delegation_rate<P>(provider: &mut P) -> Result<DelegationRate, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_delegation_rate_bound(provider, MAX_DELEGATION_RATE_KEY)
+}
+
+pub fn get_max_delegation_rate_change<P>(provider: &mut P) -> Result<DelegationRate, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_delegation_rate_bound(provider, MAX_DELEGATION_RATE_CHANGE_KEY)
+}
+
+/// Reads the maximum number of delegators per validator, defaulting to no restriction for auction
+/// contracts installed before the limit was introduced.
+pub fn get_max_delegators_per_validator<P>(provider: &mut P) -> Result<u32, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider
+        .named_keys_get(MAX_DELEGATORS_PER_VALIDATOR_KEY)
+        .is_none()
+    {
+        return Ok(u32::max_value());
+    }
+    read_from(provider, MAX_DELEGATORS_PER_VALIDATOR_KEY)
+}
+
+/// Returns [`Error::TooManyDelegators`] if the given bid can't take on another delegator.
+pub fn check_delegator_capacity<P>(provider: &mut P, bid: &Bid) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let max_delegators = get_max_delegators_per_validator(provider)?;
+    if bid.delegator_count() >= max_delegators as usize {
+        return Err(Error::TooManyDelegators);
+    }
+    Ok(())
+}
+
+/// Applies `update` to the stored [`StakingStats`].
+///
+/// Auction contracts installed before the statistics were introduced don't maintain them, in which
+/// case this does nothing.
+pub(crate) fn update_staking_stats<P, F>(provider: &mut P, update: F) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+    F: FnOnce(&mut StakingStats) -> Result<(), Error>,
+{
+    if provider.named_keys_get(STAKING_STATS_KEY).is_none() {
+        return Ok(());
+    }
+    let mut staking_stats: StakingStats = read_from(provider, STAKING_STATS_KEY)?;
+    update(&mut staking_stats)?;
+    write_to(provider, STAKING_STATS_KEY, staking_stats)
+}
+
 /// Iterates over unbonding entries and checks if a locked amount can be paid already if
 /// a specific era is reached.
 ///
@@ -192,40 +263,55 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(provider: &mut P) ->
     }
 
     // Update `unbonding_purses` data
-    let mut unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
+    let unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
 
     let current_era_id = provider.read_era_id()?;
 
     let unbonding_delay = get_unbonding_delay(provider)?;
 
-    for unbonding_list in unbonding_purses.values_mut() {
+    // Only the lists of validators with matured requests are written back.
+    let mut processed_unbonding_purses = UnbondingPurses::new();
+
+    for (validator_account_hash, unbonding_list) in unbonding_purses {
         let mut new_unbonding_list = Vec::new();
         for unbonding_purse in unbonding_list.iter() {
             // Since `process_unbond_requests` is run before `run_auction`, we should check if
             // current era id + unbonding delay is equal or greater than the `era_of_creation` that
             // was calculated on `unbond` attempt.
             if current_era_id >= unbonding_purse.era_of_creation() + unbonding_delay {
-                let account_hash =
-                    AccountHash::from_public_key(unbonding_purse.unbonder_public_key(), |x| {
-                        provider.blake2b(x)
-                    });
-
                 // Move funds from bid purse to unbonding purse
-                provider
-                    .transfer_purse_to_account(
-                        *unbonding_purse.bonding_purse(),
-                        account_hash,
-                        *unbonding_purse.amount(),
-                    )
-                    .map_err(|_| Error::TransferToUnbondingPurse)?;
+                match unbonding_purse.unbond_purse() {
+                    Some(unbond_purse) => provider
+                        .transfer_purse_to_purse(
+                            *unbonding_purse.bonding_purse(),
+                            *unbond_purse,
+                            *unbonding_purse.amount(),
+                        )
+                        .map_err(|_| Error::TransferToUnbondingPurse)?,
+                    None => {
+                        let account_hash = AccountHash::from_public_key(
+                            unbonding_purse.unbonder_public_key(),
+                            |x| provider.blake2b(x),
+                        );
+                        provider
+                            .transfer_purse_to_account(
+                                *unbonding_purse.bonding_purse(),
+                                account_hash,
+                                *unbonding_purse.amount(),
+                            )
+                            .map_err(|_| Error::TransferToUnbondingPurse)?;
+                    }
+                }
             } else {
                 new_unbonding_list.push(unbonding_purse.clone());
             }
         }
-        *unbonding_list = new_unbonding_list;
+        if new_unbonding_list.len() != unbonding_list.len() {
+            processed_unbonding_purses.insert(validator_account_hash, new_unbonding_list);
+        }
     }
 
-    set_unbonding_purses(provider, unbonding_purses)?;
+    set_unbonding_purses(provider, processed_unbonding_purses)?;
     Ok(())
 }
 
@@ -258,6 +344,109 @@ pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
     Ok(())
 }
 
+/// Creates a new purse in unbonding_purses for a contract delegator, given a validator's key, the
+/// contract delegator's bonding purse and the purse the unbonded funds are to be paid out to.
+pub(crate) fn create_contract_unbonding_purse<P: Auction + ?Sized>(
+    provider: &mut P,
+    validator_public_key: PublicKey,
+    bonding_purse: URef,
+    unbond_purse: URef,
+    amount: U512,
+) -> Result<(), Error> {
+    if provider.get_balance(bonding_purse)?.unwrap_or_default() < amount {
+        return Err(Error::UnbondTooLarge);
+    }
+
+    let validator_account_hash = AccountHash::from(&validator_public_key);
+    let mut unbonding_purses = provider.read_withdraw(&validator_account_hash)?;
+    let era_of_creation = provider.read_era_id()?;
+    let new_unbonding_purse = UnbondingPurse::with_unbond_purse(
+        bonding_purse,
+        validator_public_key,
+        era_of_creation,
+        amount,
+        unbond_purse,
+    );
+    unbonding_purses.push(new_unbonding_purse);
+    provider.write_withdraw(validator_account_hash, unbonding_purses)?;
+
+    Ok(())
+}
+
+/// Cancels `amount` motes of the pending unbonding requests made by an unbonder from the given
+/// validator's bid, starting with the most recent ones.
+///
+/// Only requests made from `bonding_purse` are considered, or from the bonding purse of the most
+/// recent request if `None`. Requests are shrunk or removed as needed, and the bonding purse is
+/// returned so the caller can restore the stake.
+pub(crate) fn cancel_unbonding_purses<P: Auction + ?Sized>(
+    provider: &mut P,
+    validator_public_key: PublicKey,
+    unbonder_public_key: PublicKey,
+    bonding_purse: Option<URef>,
+    amount: U512,
+) -> Result<URef, Error> {
+    if amount.is_zero() {
+        return Err(Error::InvalidAmount);
+    }
+
+    let validator_account_hash = AccountHash::from(&validator_public_key);
+    let mut unbonding_purses = provider.read_withdraw(&validator_account_hash)?;
+
+    let bonding_purse = bonding_purse
+        .or_else(|| {
+            unbonding_purses
+                .iter()
+                .rev()
+                .find(|unbonding_purse| {
+                    unbonding_purse.unbonder_public_key() == &unbonder_public_key
+                })
+                .map(|unbonding_purse| *unbonding_purse.bonding_purse())
+        })
+        .ok_or(Error::UnbondingRequestNotFound)?;
+    let is_cancellable = |unbonding_purse: &UnbondingPurse| {
+        unbonding_purse.unbonder_public_key() == &unbonder_public_key
+            && unbonding_purse.bonding_purse() == &bonding_purse
+    };
+
+    let pending_amount = unbonding_purses
+        .iter()
+        .filter(|&unbonding_purse| is_cancellable(unbonding_purse))
+        .fold(U512::zero(), |total, unbonding_purse| {
+            total + *unbonding_purse.amount()
+        });
+    if pending_amount.is_zero() {
+        return Err(Error::UnbondingRequestNotFound);
+    }
+    if pending_amount < amount {
+        return Err(Error::UnbondTooLarge);
+    }
+
+    let mut remaining = amount;
+    for unbonding_purse in unbonding_purses.iter_mut().rev() {
+        if remaining.is_zero() {
+            break;
+        }
+        if !is_cancellable(&*unbonding_purse) {
+            continue;
+        }
+        let cancelled = remaining.min(*unbonding_purse.amount());
+        *unbonding_purse = UnbondingPurse::new(
+            bonding_purse,
+            *unbonding_purse.validator_public_key(),
+            unbonder_public_key,
+            unbonding_purse.era_of_creation(),
+            *unbonding_purse.amount() - cancelled,
+        );
+        remaining -= cancelled;
+    }
+    unbonding_purses.retain(|unbonding_purse| !unbonding_purse.amount().is_zero());
+
+    provider.write_withdraw(validator_account_hash, unbonding_purses)?;
+
+    Ok(bonding_purse)
+}
+
 /// Reinvests delegator reward by increasing its stake.
 pub fn reinvest_delegator_rewards<P>(
     provider: &mut P,
@@ -305,6 +494,56 @@ where
     Ok(delegator_payouts)
 }
 
+/// Reinvests contract delegator reward by increasing its stake.
+pub fn reinvest_contract_delegator_rewards<P>(
+    provider: &mut P,
+    seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
+    validator_public_key: PublicKey,
+    rewards: impl Iterator<Item = (ContractPackageHash, Ratio<U512>)>,
+) -> Result<Vec<(U512, URef)>, Error>
+where
+    P: StorageProvider,
+{
+    let mut contract_delegator_payouts = Vec::new();
+
+    let validator_account_hash = AccountHash::from(&validator_public_key);
+
+    let mut bid = match provider.read_bid(&validator_account_hash)? {
+        Some(bid) => bid,
+        None => return Err(Error::ValidatorNotFound),
+    };
+
+    let contract_delegators = bid.contract_delegators_mut();
+
+    for (contract_package_hash, contract_delegator_reward) in rewards {
+        let contract_delegator = match contract_delegators.get_mut(&contract_package_hash) {
+            Some(contract_delegator) => contract_delegator,
+            None => continue,
+        };
+
+        let contract_delegator_reward_trunc = contract_delegator_reward.to_integer();
+
+        contract_delegator.increase_stake(contract_delegator_reward_trunc)?;
+
+        contract_delegator_payouts.push((
+            contract_delegator_reward_trunc,
+            *contract_delegator.bonding_purse(),
+        ));
+
+        let allocation = SeigniorageAllocation::contract_delegator(
+            contract_package_hash,
+            validator_public_key,
+            contract_delegator_reward_trunc,
+        );
+
+        seigniorage_allocations.push(allocation);
+    }
+
+    provider.write_bid(validator_account_hash, bid)?;
+
+    Ok(contract_delegator_payouts)
+}
+
 /// Reinvests validator reward by increasing its stake and returns its bonding purse.
 pub fn reinvest_validator_reward<P>(
     provider: &mut P,
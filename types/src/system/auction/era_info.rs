@@ -9,11 +9,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, PublicKey, U512,
+    CLType, CLTyped, ContractPackageHash, PublicKey, U512,
 };
 
 const SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG: u8 = 0;
 const SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG: u8 = 1;
+const SEIGNIORAGE_ALLOCATION_CONTRACT_DELEGATOR_TAG: u8 = 2;
 
 /// Information about a seigniorage allocation
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
@@ -36,6 +37,15 @@ pub enum SeigniorageAllocation {
         /// Allocated amount
         amount: U512,
     },
+    /// Info about a seigniorage allocation for a contract delegator
+    ContractDelegator {
+        /// Contract package hash of the delegating contract
+        contract_package_hash: ContractPackageHash,
+        /// Validator's public key
+        validator_public_key: PublicKey,
+        /// Allocated amount
+        amount: U512,
+    },
 }
 
 impl SeigniorageAllocation {
@@ -60,11 +70,25 @@ impl SeigniorageAllocation {
         }
     }
 
+    /// Constructs a [`SeigniorageAllocation::ContractDelegator`]
+    pub const fn contract_delegator(
+        contract_package_hash: ContractPackageHash,
+        validator_public_key: PublicKey,
+        amount: U512,
+    ) -> Self {
+        SeigniorageAllocation::ContractDelegator {
+            contract_package_hash,
+            validator_public_key,
+            amount,
+        }
+    }
+
     /// Returns the amount for a given seigniorage allocation
     pub fn amount(&self) -> &U512 {
         match self {
             SeigniorageAllocation::Validator { amount, .. } => amount,
             SeigniorageAllocation::Delegator { amount, .. } => amount,
+            SeigniorageAllocation::ContractDelegator { amount, .. } => amount,
         }
     }
 
@@ -72,6 +96,9 @@ impl SeigniorageAllocation {
         match self {
             SeigniorageAllocation::Validator { .. } => SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG,
             SeigniorageAllocation::Delegator { .. } => SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG,
+            SeigniorageAllocation::ContractDelegator { .. } => {
+                SEIGNIORAGE_ALLOCATION_CONTRACT_DELEGATOR_TAG
+            }
         }
     }
 }
@@ -97,6 +124,15 @@ impl ToBytes for SeigniorageAllocation {
                 buffer.append(&mut validator_public_key.to_bytes()?);
                 buffer.append(&mut amount.to_bytes()?);
             }
+            SeigniorageAllocation::ContractDelegator {
+                contract_package_hash,
+                validator_public_key,
+                amount,
+            } => {
+                buffer.append(&mut contract_package_hash.to_bytes()?);
+                buffer.append(&mut validator_public_key.to_bytes()?);
+                buffer.append(&mut amount.to_bytes()?);
+            }
         }
         Ok(buffer)
     }
@@ -117,6 +153,15 @@ impl ToBytes for SeigniorageAllocation {
                         + validator_public_key.serialized_length()
                         + amount.serialized_length()
                 }
+                SeigniorageAllocation::ContractDelegator {
+                    contract_package_hash,
+                    validator_public_key,
+                    amount,
+                } => {
+                    contract_package_hash.serialized_length()
+                        + validator_public_key.serialized_length()
+                        + amount.serialized_length()
+                }
             }
     }
 }
@@ -146,6 +191,19 @@ impl FromBytes for SeigniorageAllocation {
                     rem,
                 ))
             }
+            SEIGNIORAGE_ALLOCATION_CONTRACT_DELEGATOR_TAG => {
+                let (contract_package_hash, rem) = ContractPackageHash::from_bytes(rem)?;
+                let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
+                let (amount, rem) = U512::from_bytes(rem)?;
+                Ok((
+                    SeigniorageAllocation::contract_delegator(
+                        contract_package_hash,
+                        validator_public_key,
+                        amount,
+                    ),
+                    rem,
+                ))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -190,6 +248,8 @@ impl EraInfo {
     ///   against the validator public key.
     /// * If the match candidate is a delegator allocation, the provided public key is matched
     ///   against the delegator public key.
+    ///
+    /// Contract delegator allocations are never selected.
     pub fn select(&self, public_key: PublicKey) -> impl Iterator<Item = &SeigniorageAllocation> {
         self.seigniorage_allocations
             .iter()
@@ -202,6 +262,7 @@ impl EraInfo {
                     delegator_public_key,
                     ..
                 } => public_key == *delegator_public_key,
+                SeigniorageAllocation::ContractDelegator { .. } => false,
             })
     }
 }
@@ -238,8 +299,9 @@ impl CLTyped for EraInfo {
 #[cfg(any(feature = "gens", test))]
 pub mod gens {
     use proptest::{
+        array,
         collection::{self, SizeRange},
-        prelude::Strategy,
+        prelude::{Arbitrary, Strategy},
         prop_oneof,
     };
 
@@ -247,6 +309,7 @@ pub mod gens {
         crypto::gens::public_key_arb,
         gens::u512_arb,
         system::auction::{EraInfo, SeigniorageAllocation},
+        ContractPackageHash,
     };
 
     fn seigniorage_allocation_validator_arb() -> impl Strategy<Value = SeigniorageAllocation> {
@@ -263,11 +326,28 @@ pub mod gens {
         )
     }
 
+    fn seigniorage_allocation_contract_delegator_arb(
+    ) -> impl Strategy<Value = SeigniorageAllocation> {
+        (
+            array::uniform32(<u8>::arbitrary()).prop_map(ContractPackageHash::new),
+            public_key_arb(),
+            u512_arb(),
+        )
+            .prop_map(|(contract_package_hash, validator_public_key, amount)| {
+                SeigniorageAllocation::contract_delegator(
+                    contract_package_hash,
+                    validator_public_key,
+                    amount,
+                )
+            })
+    }
+
     /// Creates an arbitrary [`SeignorageAllocation`](crate::system::auction::SeigniorageAllocation)
     pub fn seigniorage_allocation_arb() -> impl Strategy<Value = SeigniorageAllocation> {
         prop_oneof![
             seigniorage_allocation_validator_arb(),
-            seigniorage_allocation_delegator_arb()
+            seigniorage_allocation_delegator_arb(),
+            seigniorage_allocation_contract_delegator_arb()
         ]
     }
 
@@ -146,6 +146,32 @@ pub enum Error {
     /// made.
     #[cfg_attr(feature = "std", error("Delegator's funds are locked"))]
     DelegatorFundsLocked = 38,
+    /// Delegation rate differs from the current one by more than the allowed change per era.
+    #[cfg_attr(feature = "std", error("Delegation rate change too large"))]
+    DelegationRateChangeTooLarge = 39,
+    /// Delegation rate has already been changed in the current era.
+    #[cfg_attr(
+        feature = "std",
+        error("Delegation rate already changed in the current era")
+    )]
+    DelegationRateChangeCooldown = 40,
+    /// Raised when an attempt is made to cancel an unbonding request which doesn't exist.
+    #[cfg_attr(feature = "std", error("Unbonding request not found"))]
+    UnbondingRequestNotFound = 41,
+    /// Raised when a validator's name exceeds [`MAX_VALIDATOR_NAME_LENGTH`] bytes.
+    ///
+    /// [`MAX_VALIDATOR_NAME_LENGTH`]: crate::system::auction::MAX_VALIDATOR_NAME_LENGTH
+    #[cfg_attr(feature = "std", error("Validator name too long"))]
+    ValidatorNameTooLong = 42,
+    /// Raised when a validator's URL exceeds [`MAX_VALIDATOR_URL_LENGTH`] bytes.
+    ///
+    /// [`MAX_VALIDATOR_URL_LENGTH`]: crate::system::auction::MAX_VALIDATOR_URL_LENGTH
+    #[cfg_attr(feature = "std", error("Validator URL too long"))]
+    ValidatorUrlTooLong = 43,
+    /// Raised when a new delegator attempts to delegate to a validator which already has the
+    /// maximum number of delegators.
+    #[cfg_attr(feature = "std", error("Validator has too many delegators"))]
+    TooManyDelegators = 44,
 
     // NOTE: These variants below and related plumbing will be removed once support for WASM
     // system contracts will be dropped.
@@ -225,6 +251,16 @@ impl TryFrom<u8> for Error {
             d if d == Error::Transfer as u8 => Ok(Error::Transfer),
             d if d == Error::DelegationRateTooLarge as u8 => Ok(Error::DelegationRateTooLarge),
             d if d == Error::DelegatorFundsLocked as u8 => Ok(Error::DelegatorFundsLocked),
+            d if d == Error::DelegationRateChangeTooLarge as u8 => {
+                Ok(Error::DelegationRateChangeTooLarge)
+            }
+            d if d == Error::DelegationRateChangeCooldown as u8 => {
+                Ok(Error::DelegationRateChangeCooldown)
+            }
+            d if d == Error::UnbondingRequestNotFound as u8 => Ok(Error::UnbondingRequestNotFound),
+            d if d == Error::ValidatorNameTooLong as u8 => Ok(Error::ValidatorNameTooLong),
+            d if d == Error::ValidatorUrlTooLong as u8 => Ok(Error::ValidatorUrlTooLong),
+            d if d == Error::TooManyDelegators as u8 => Ok(Error::TooManyDelegators),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
             _ => Err(TryFromU8ForError(())),
         }
@@ -0,0 +1,35 @@
+//! Versioning of the serialized layouts of auction records.
+//!
+//! Records which gained fields after genesis are serialized with a leading
+//! [`VERSIONED_LAYOUT_TAG`] followed by the version of their layout.  Records written before their
+//! layout was versioned start with the tag of a [`PublicKey`](crate::PublicKey) or the length of a
+//! `U512`, neither of which is ever [`VERSIONED_LAYOUT_TAG`].
+
+use alloc::vec::Vec;
+
+use crate::bytesrepr::{self, FromBytes, U8_SERIALIZED_LENGTH};
+
+/// The leading byte of a record serialized with a versioned layout.
+const VERSIONED_LAYOUT_TAG: u8 = u8::max_value();
+
+/// The number of bytes taken by the tag and version prefixing a versioned layout.
+pub(super) const LAYOUT_VERSION_SERIALIZED_LENGTH: usize = 2 * U8_SERIALIZED_LENGTH;
+
+/// Appends the prefix of a record serialized with the given layout version to `buffer`.
+pub(super) fn write_layout_version(version: u8, buffer: &mut Vec<u8>) {
+    buffer.push(VERSIONED_LAYOUT_TAG);
+    buffer.push(version);
+}
+
+/// Reads the layout version from the start of `bytes`.
+///
+/// Returns `None` without consuming any bytes if the record was written before its layout was
+/// versioned.
+pub(super) fn read_layout_version(bytes: &[u8]) -> Result<(Option<u8>, &[u8]), bytesrepr::Error> {
+    let (tag, remainder) = u8::from_bytes(bytes)?;
+    if tag != VERSIONED_LAYOUT_TAG {
+        return Ok((None, bytes));
+    }
+    let (version, remainder) = u8::from_bytes(remainder)?;
+    Ok((Some(version), remainder))
+}
@@ -1,22 +1,27 @@
 //! Contains implementation of a Auction contract functionality.
 mod bid;
 mod constants;
+mod contract_delegator;
 mod delegator;
 mod detail;
 mod era_info;
 mod error;
+mod layout;
 mod providers;
 mod seigniorage_recipient;
+mod staking_stats;
 mod unbonding_purse;
+mod validator_info;
 
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use num_rational::Ratio;
 
-use crate::{account::AccountHash, PublicKey, U512};
+use crate::{account::AccountHash, AccessRights, ContractPackageHash, Key, PublicKey, URef, U512};
 
-pub use bid::Bid;
+pub use bid::{Bid, EraVestingSchedule};
 pub use constants::*;
+pub use contract_delegator::ContractDelegator;
 pub use delegator::Delegator;
 pub use era_info::*;
 pub use error::Error;
@@ -24,7 +29,9 @@ pub use providers::{
     AccountProvider, MintProvider, RuntimeProvider, StorageProvider, SystemProvider,
 };
 pub use seigniorage_recipient::SeigniorageRecipient;
+pub use staking_stats::StakingStats;
 pub use unbonding_purse::UnbondingPurse;
+pub use validator_info::{validator_info_key_name, ValidatorInfo};
 
 /// Representation of delegation rate of tokens. Range from 0..=100.
 pub type DelegationRate = u8;
@@ -88,9 +95,35 @@ pub trait Auction:
         Ok(seigniorage_recipients)
     }
 
+    /// Returns the bid of a single validator, including its delegators, or `None` if the validator
+    /// has no bid.
+    ///
+    /// Unlike reading the entire `Bids` map, the cost of this call does not grow with the number of
+    /// validators.
+    fn get_bid(&mut self, public_key: PublicKey) -> Result<Option<Bid>, Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        self.read_bid(&account_hash)
+    }
+
+    /// Returns the delegation made by `delegator_public_key` to `validator_public_key`, or `None`
+    /// if there is no such delegation.
+    ///
+    /// Returns [`Error::ValidatorNotFound`] if the validator has no bid.
+    fn get_delegator(
+        &mut self,
+        validator_public_key: PublicKey,
+        delegator_public_key: PublicKey,
+    ) -> Result<Option<Delegator>, Error> {
+        let bid = self
+            .get_bid(validator_public_key)?
+            .ok_or(Error::ValidatorNotFound)?;
+        Ok(bid.delegators().get(&delegator_public_key).cloned())
+    }
+
     /// For a non-founder validator, this adds, or modifies, an entry in the `bids` collection and
     /// calls `bond` in the Mint contract to create (or top off) a bid purse. It also adjusts the
-    /// delegation rate.
+    /// delegation rate, which may change at most once per era and by no more than the configured
+    /// maximum change, and never beyond the configured maximum rate.
     fn add_bid(
         &mut self,
         public_key: PublicKey,
@@ -106,7 +139,9 @@ pub trait Auction:
             return Err(Error::BondTooSmall);
         }
 
-        if delegation_rate > DELEGATION_RATE_DENOMINATOR {
+        if delegation_rate > DELEGATION_RATE_DENOMINATOR
+            || delegation_rate > detail::get_max_delegation_rate(self)?
+        {
             return Err(Error::DelegationRateTooLarge);
         }
 
@@ -120,11 +155,12 @@ pub trait Auction:
                 if bid.inactive() {
                     bid.activate();
                 }
+                let max_delegation_rate_change = detail::get_max_delegation_rate_change(self)?;
+                let era_id = detail::get_era_id(self)?;
+                bid.change_delegation_rate(delegation_rate, max_delegation_rate_change, era_id)?;
                 self.transfer_purse_to_purse(source, *bid.bonding_purse(), amount)
                     .map_err(|_| Error::TransferToBidPurse)?;
-                let updated_amount = bid
-                    .with_delegation_rate(delegation_rate)
-                    .increase_stake(amount)?;
+                let updated_amount = bid.increase_stake(amount)?;
                 self.write_bid(account_hash, bid)?;
                 updated_amount
             }
@@ -138,6 +174,8 @@ pub trait Auction:
             }
         };
 
+        detail::update_staking_stats(self, |staking_stats| staking_stats.increase_bonded(amount))?;
+
         Ok(updated_amount)
     }
 
@@ -159,11 +197,12 @@ pub trait Auction:
             .read_bid(&account_hash)?
             .ok_or(Error::ValidatorNotFound)?;
 
+        let era_id = detail::get_era_id(self)?;
         let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
 
         // Fails if requested amount is greater than either the total stake or the amount of vested
         // stake.
-        let updated_stake = bid.decrease_stake(amount, era_end_timestamp_millis)?;
+        let updated_stake = bid.decrease_stake(amount, era_id, era_end_timestamp_millis)?;
 
         detail::create_unbonding_purse(
             self,
@@ -173,6 +212,8 @@ pub trait Auction:
             amount,
         )?;
 
+        let mut unbonded_delegations = Vec::new();
+
         if updated_stake.is_zero() {
             // Automatically unbond delegators
             for (delegator_public_key, delegator) in bid.delegators() {
@@ -183,15 +224,36 @@ pub trait Auction:
                     *delegator.bonding_purse(),
                     *delegator.staked_amount(),
                 )?;
+                unbonded_delegations.push(*delegator.staked_amount());
+            }
+
+            for contract_delegator in bid.contract_delegators().values() {
+                detail::create_contract_unbonding_purse(
+                    self,
+                    public_key,
+                    *contract_delegator.bonding_purse(),
+                    *contract_delegator.unbond_purse(),
+                    *contract_delegator.staked_amount(),
+                )?;
+                unbonded_delegations.push(*contract_delegator.staked_amount());
             }
 
             *bid.delegators_mut() = BTreeMap::new();
+            *bid.contract_delegators_mut() = BTreeMap::new();
 
             bid.deactivate();
         }
 
         self.write_bid(account_hash, bid)?;
 
+        detail::update_staking_stats(self, |staking_stats| {
+            staking_stats.decrease_bonded(amount);
+            for delegated_amount in unbonded_delegations {
+                staking_stats.remove_delegator(delegated_amount);
+            }
+            Ok(())
+        })?;
+
         Ok(updated_stake)
     }
 
@@ -227,6 +289,11 @@ pub trait Auction:
             }
         };
 
+        let is_new_delegator = !bid.delegators().contains_key(&delegator_public_key);
+        if is_new_delegator {
+            detail::check_delegator_capacity(self, &bid)?;
+        }
+
         let delegators = bid.delegators_mut();
 
         let new_delegation_amount = match delegators.get_mut(&delegator_public_key) {
@@ -253,6 +320,14 @@ pub trait Auction:
 
         self.write_bid(validator_account_hash, bid)?;
 
+        detail::update_staking_stats(self, |staking_stats| {
+            if is_new_delegator {
+                staking_stats.add_delegator(amount)
+            } else {
+                staking_stats.increase_delegated(amount)
+            }
+        })?;
+
         Ok(new_delegation_amount)
     }
 
@@ -291,8 +366,10 @@ pub trait Auction:
                     amount,
                 )?;
 
+                let era_id = detail::get_era_id(self)?;
                 let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
-                let updated_stake = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
+                let updated_stake =
+                    delegator.decrease_stake(amount, era_id, era_end_timestamp_millis)?;
                 if updated_stake == U512::zero() {
                     delegators.remove(&delegator_public_key);
                 };
@@ -303,9 +380,277 @@ pub trait Auction:
 
         self.write_bid(validator_account_hash, bid)?;
 
+        detail::update_staking_stats(self, |staking_stats| {
+            if new_amount.is_zero() {
+                staking_stats.remove_delegator(amount);
+            } else {
+                staking_stats.decrease_delegated(amount);
+            }
+            Ok(())
+        })?;
+
+        Ok(new_amount)
+    }
+
+    /// Adds a new contract delegator to the validator's contract delegators, or tops off a current
+    /// one, by transferring `amount` motes from `source`, a purse of the calling contract.
+    ///
+    /// Contract delegators are identified by `contract_package_hash`, the contract package of the
+    /// caller as determined by the host, which is `None` if the caller is not a stored contract.
+    /// Unbonded funds are paid out to the `source` purse of the contract's first delegation to the
+    /// validator, since the contract has no account.
+    ///
+    /// Returns the new delegated amount.
+    fn delegate_from_contract(
+        &mut self,
+        contract_package_hash: Option<ContractPackageHash>,
+        validator_public_key: PublicKey,
+        amount: U512,
+        source: URef,
+    ) -> Result<U512, Error> {
+        let contract_package_hash = contract_package_hash.ok_or(Error::InvalidContext)?;
+
+        if amount.is_zero() {
+            return Err(Error::BondTooSmall);
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = self
+            .read_bid(&validator_account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let is_new_delegator = !bid
+            .contract_delegators()
+            .contains_key(&contract_package_hash);
+        if is_new_delegator {
+            detail::check_delegator_capacity(self, &bid)?;
+        }
+
+        let contract_delegators = bid.contract_delegators_mut();
+
+        let new_delegation_amount = match contract_delegators.get_mut(&contract_package_hash) {
+            Some(contract_delegator) => {
+                self.transfer_purse_to_purse(source, *contract_delegator.bonding_purse(), amount)
+                    .map_err(|_| Error::TransferToDelegatorPurse)?;
+                contract_delegator.increase_stake(amount)?
+            }
+            None => {
+                let bonding_purse = self.create_purse()?;
+                self.transfer_purse_to_purse(source, bonding_purse, amount)
+                    .map_err(|_| Error::TransferToDelegatorPurse)?;
+                // Only the right to add funds to the unbond purse is recorded, as bids are
+                // readable by anyone.
+                let contract_delegator = ContractDelegator::new(
+                    contract_package_hash,
+                    amount,
+                    bonding_purse,
+                    source.with_access_rights(AccessRights::ADD),
+                    validator_public_key,
+                );
+                contract_delegators.insert(contract_package_hash, contract_delegator);
+                amount
+            }
+        };
+
+        self.write_bid(validator_account_hash, bid)?;
+
+        detail::update_staking_stats(self, |staking_stats| {
+            if is_new_delegator {
+                staking_stats.add_delegator(amount)
+            } else {
+                staking_stats.increase_delegated(amount)
+            }
+        })?;
+
+        Ok(new_delegation_amount)
+    }
+
+    /// Removes an amount of motes (or the entry altogether, if the remaining amount is 0) from
+    /// the calling contract's entry in the validator's contract delegators, and creates an
+    /// unbonding request paying out to the contract's unbond purse.
+    ///
+    /// `contract_package_hash` is determined by the host as for
+    /// [`Auction::delegate_from_contract`]. Returns the remaining delegated amount.
+    fn undelegate_from_contract(
+        &mut self,
+        contract_package_hash: Option<ContractPackageHash>,
+        validator_public_key: PublicKey,
+        amount: U512,
+    ) -> Result<U512, Error> {
+        let contract_package_hash = contract_package_hash.ok_or(Error::InvalidContext)?;
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = self
+            .read_bid(&validator_account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let contract_delegators = bid.contract_delegators_mut();
+
+        let new_amount = match contract_delegators.get_mut(&contract_package_hash) {
+            Some(contract_delegator) => {
+                detail::create_contract_unbonding_purse(
+                    self,
+                    validator_public_key,
+                    *contract_delegator.bonding_purse(),
+                    *contract_delegator.unbond_purse(),
+                    amount,
+                )?;
+
+                let updated_stake = contract_delegator.decrease_stake(amount)?;
+                if updated_stake.is_zero() {
+                    contract_delegators.remove(&contract_package_hash);
+                }
+                updated_stake
+            }
+            None => return Err(Error::DelegatorNotFound),
+        };
+
+        self.write_bid(validator_account_hash, bid)?;
+
+        detail::update_staking_stats(self, |staking_stats| {
+            if new_amount.is_zero() {
+                staking_stats.remove_delegator(amount);
+            } else {
+                staking_stats.decrease_delegated(amount);
+            }
+            Ok(())
+        })?;
+
         Ok(new_amount)
     }
 
+    /// Cancels `amount` motes of the validator's pending unbonding requests created by
+    /// [`Auction::withdraw_bid`], most recent first, and adds them back to the validator's stake.
+    ///
+    /// The bid is reactivated if it was deactivated by withdrawing all of its stake. Delegators
+    /// which were unbonded along with it are not restored. Returns the updated stake.
+    fn cancel_validator_unbond(
+        &mut self,
+        public_key: PublicKey,
+        amount: U512,
+    ) -> Result<U512, Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = self
+            .read_bid(&account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        detail::cancel_unbonding_purses(
+            self,
+            public_key,
+            public_key, // validator is the unbonder
+            Some(*bid.bonding_purse()),
+            amount,
+        )?;
+
+        let updated_stake = bid.increase_stake(amount)?;
+        if bid.inactive() {
+            bid.activate();
+        }
+
+        self.write_bid(account_hash, bid)?;
+
+        detail::update_staking_stats(self, |staking_stats| staking_stats.increase_bonded(amount))?;
+
+        Ok(updated_stake)
+    }
+
+    /// Cancels `amount` motes of the delegator's pending unbonding requests created by
+    /// [`Auction::undelegate`], most recent first, and adds them back to the delegation.
+    ///
+    /// If the delegator was removed from the validator's bid after undelegating everything, it is
+    /// restored with its original bonding purse. Returns the updated delegated amount.
+    fn cancel_delegator_unbond(
+        &mut self,
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+    ) -> Result<U512, Error> {
+        let account_hash = AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = self
+            .read_bid(&validator_account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let current_bonding_purse = bid
+            .delegators()
+            .get(&delegator_public_key)
+            .map(|delegator| *delegator.bonding_purse());
+
+        let bonding_purse = detail::cancel_unbonding_purses(
+            self,
+            validator_public_key,
+            delegator_public_key,
+            current_bonding_purse,
+            amount,
+        )?;
+
+        let delegators = bid.delegators_mut();
+        let is_new_delegator = !delegators.contains_key(&delegator_public_key);
+        let updated_amount = match delegators.get_mut(&delegator_public_key) {
+            Some(delegator) => delegator.increase_stake(amount)?,
+            None => {
+                let delegator = Delegator::unlocked(
+                    delegator_public_key,
+                    amount,
+                    bonding_purse,
+                    validator_public_key,
+                );
+                delegators.insert(delegator_public_key, delegator);
+                amount
+            }
+        };
+
+        self.write_bid(validator_account_hash, bid)?;
+
+        detail::update_staking_stats(self, |staking_stats| {
+            if is_new_delegator {
+                staking_stats.add_delegator(amount)
+            } else {
+                staking_stats.increase_delegated(amount)
+            }
+        })?;
+
+        Ok(updated_amount)
+    }
+
+    /// Sets the public metadata of the validator with the given public key, replacing any set
+    /// previously.
+    ///
+    /// Only validators with a bid may set their metadata. It is stored under the auction contract's
+    /// named key given by [`validator_info_key_name`].
+    fn set_validator_info(
+        &mut self,
+        public_key: PublicKey,
+        validator_info: ValidatorInfo,
+    ) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        if self.read_bid(&account_hash)?.is_none() {
+            return Err(Error::ValidatorNotFound);
+        }
+
+        let name = validator_info_key_name(&account_hash);
+        match self.named_keys_get(&name) {
+            Some(Key::URef(uref)) => self.write(uref, validator_info),
+            Some(_) => Err(Error::InvalidKeyVariant),
+            None => {
+                let uref = self.new_uref(validator_info)?;
+                self.put_key(&name, Key::URef(uref))
+            }
+        }
+    }
+
     /// Slashes each validator.
     ///
     /// This can be only invoked through a system call.
@@ -315,18 +660,26 @@ pub trait Auction:
         }
 
         let mut burned_amount: U512 = U512::zero();
+        let mut slashed_bonded: U512 = U512::zero();
+        let mut slashed_delegated: U512 = U512::zero();
 
         for validator_public_key in validator_public_keys {
             // Burn stake, deactivate
             let validator_account_hash = AccountHash::from(&validator_public_key);
             if let Some(mut bid) = self.read_bid(&validator_account_hash)? {
                 burned_amount += *bid.staked_amount();
+                slashed_bonded += *bid.staked_amount();
                 *bid.staked_amount_mut() = U512::zero();
                 bid.deactivate();
                 // Reset delegator stakes when deactivating validator bid.
                 for delegator in bid.delegators_mut().values_mut() {
+                    slashed_delegated += *delegator.staked_amount();
                     *delegator.staked_amount_mut() = U512::zero();
                 }
+                for contract_delegator in bid.contract_delegators_mut().values_mut() {
+                    slashed_delegated += *contract_delegator.staked_amount();
+                    *contract_delegator.staked_amount_mut() = U512::zero();
+                }
                 self.write_bid(validator_account_hash, bid)?;
             };
 
@@ -344,6 +697,13 @@ pub trait Auction:
 
         self.reduce_total_supply(burned_amount)?;
 
+        // Slashed delegators keep their (now empty) entries in the validator's bid.
+        detail::update_staking_stats(self, |staking_stats| {
+            staking_stats.decrease_bonded(slashed_bonded);
+            staking_stats.decrease_delegated(slashed_delegated);
+            Ok(())
+        })?;
+
         Ok(())
     }
 
@@ -386,7 +746,7 @@ pub trait Auction:
         let winners: ValidatorWeights = {
             let founder_weights: ValidatorWeights = bids
                 .iter()
-                .filter(|(_public_key, bid)| bid.vesting_schedule().is_some() && !bid.inactive())
+                .filter(|(_public_key, bid)| bid.is_founder() && !bid.inactive())
                 .map(|(public_key, bid)| {
                     let total_staked_amount = bid.total_staked_amount()?;
                     Ok((*public_key, total_staked_amount))
@@ -396,7 +756,7 @@ pub trait Auction:
             // We collect these into a vec for sorting
             let mut non_founder_weights: Vec<(PublicKey, U512)> = bids
                 .iter()
-                .filter(|(_public_key, bid)| bid.vesting_schedule().is_none() && !bid.inactive())
+                .filter(|(_public_key, bid)| !bid.is_founder() && !bid.inactive())
                 .map(|(public_key, bid)| {
                     let total_staked_amount = bid.total_staked_amount()?;
                     Ok((*public_key, total_staked_amount))
@@ -515,8 +875,23 @@ pub trait Auction:
                 public_key,
                 delegator_rewards,
             )?;
+            let contract_delegator_rewards = recipient.contract_delegator_stake().iter().map(
+                |(contract_package_hash, contract_delegator_stake)| {
+                    let reward_multiplier =
+                        Ratio::new(*contract_delegator_stake, delegator_total_stake);
+                    let reward = delegators_part * reward_multiplier;
+                    (*contract_package_hash, reward)
+                },
+            );
+            let contract_delegator_payouts = detail::reinvest_contract_delegator_rewards(
+                self,
+                &mut seigniorage_allocations,
+                public_key,
+                contract_delegator_rewards,
+            )?;
             let total_delegator_payout = delegator_payouts
                 .iter()
+                .chain(contract_delegator_payouts.iter())
                 .map(|(amount, _bonding_purse)| *amount)
                 .sum();
 
@@ -528,6 +903,10 @@ pub trait Auction:
                 public_key,
                 validator_reward,
             )?;
+            detail::update_staking_stats(self, |staking_stats| {
+                staking_stats.increase_bonded(validator_reward)?;
+                staking_stats.increase_delegated(total_delegator_payout)
+            })?;
             // TODO: add "mint into existing purse" facility
             let tmp_validator_reward_purse =
                 self.mint(validator_reward).map_err(|_| Error::MintReward)?;
@@ -542,7 +921,10 @@ pub trait Auction:
             let tmp_delegator_reward_purse = self
                 .mint(total_delegator_payout)
                 .map_err(|_| Error::MintReward)?;
-            for (delegator_payout, bonding_purse) in delegator_payouts {
+            for (delegator_payout, bonding_purse) in delegator_payouts
+                .into_iter()
+                .chain(contract_delegator_payouts)
+            {
                 self.transfer_purse_to_purse(
                     tmp_delegator_reward_purse,
                     bonding_purse,
@@ -15,6 +15,9 @@ pub trait RuntimeProvider {
     /// Gets named key under a `name`.
     fn named_keys_get(&self, name: &str) -> Option<Key>;
 
+    /// Puts a named key under a `name`, replacing any existing key of that name.
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error>;
+
     /// Gets keys in a given keyspace
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error>;
 
@@ -30,6 +33,9 @@ pub trait StorageProvider {
     /// Writes data to [`URef].
     fn write<T: ToBytes + CLTyped>(&mut self, uref: URef, value: T) -> Result<(), Error>;
 
+    /// Creates a new [`URef`] holding `value`.
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, value: T) -> Result<URef, Error>;
+
     /// Reads [`Bid`] at account hash derived from given public key
     fn read_bid(&mut self, account_hash: &AccountHash) -> Result<Option<Bid>, Error>;
 
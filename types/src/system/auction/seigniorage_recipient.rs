@@ -2,10 +2,16 @@ use alloc::{collections::BTreeMap, vec::Vec};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{Bid, DelegationRate},
-    CLType, CLTyped, PublicKey, U512,
+    system::auction::{layout, Bid, DelegationRate},
+    CLType, CLTyped, ContractPackageHash, PublicKey, U512,
 };
 
+/// The current version of the serialized layout of a [`SeigniorageRecipient`].
+///
+/// Version 1 appends the contract delegators' stake to the fields of a recipient written before
+/// its layout was versioned.
+const SEIGNIORAGE_RECIPIENT_LAYOUT_VERSION: u8 = 1;
+
 /// The seigniorage recipient details.
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct SeigniorageRecipient {
@@ -15,6 +21,8 @@ pub struct SeigniorageRecipient {
     delegation_rate: DelegationRate,
     /// Delegators and their bids.
     delegator_stake: BTreeMap<PublicKey, U512>,
+    /// Contract delegators and their bids.
+    contract_delegator_stake: BTreeMap<ContractPackageHash, U512>,
 }
 
 impl SeigniorageRecipient {
@@ -33,14 +41,23 @@ impl SeigniorageRecipient {
         &self.delegator_stake
     }
 
+    /// Returns contract delegators of the provided recipient and their stake
+    pub fn contract_delegator_stake(&self) -> &BTreeMap<ContractPackageHash, U512> {
+        &self.contract_delegator_stake
+    }
+
     /// Calculates total stake, including delegators' total stake
     pub fn total_stake(&self) -> U512 {
         self.stake + self.delegator_total_stake()
     }
 
-    /// Caculates total stake for all delegators
+    /// Caculates total stake for all delegators, including contract delegators
     pub fn delegator_total_stake(&self) -> U512 {
-        self.delegator_stake.values().cloned().sum()
+        self.delegator_stake
+            .values()
+            .chain(self.contract_delegator_stake.values())
+            .cloned()
+            .sum()
     }
 }
 
@@ -53,29 +70,40 @@ impl CLTyped for SeigniorageRecipient {
 impl ToBytes for SeigniorageRecipient {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
+        layout::write_layout_version(SEIGNIORAGE_RECIPIENT_LAYOUT_VERSION, &mut result);
         result.extend(self.stake.to_bytes()?);
         result.extend(self.delegation_rate.to_bytes()?);
         result.extend(self.delegator_stake.to_bytes()?);
+        result.extend(self.contract_delegator_stake.to_bytes()?);
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        self.stake.serialized_length()
+        layout::LAYOUT_VERSION_SERIALIZED_LENGTH
+            + self.stake.serialized_length()
             + self.delegation_rate.serialized_length()
             + self.delegator_stake.serialized_length()
+            + self.contract_delegator_stake.serialized_length()
     }
 }
 
 impl FromBytes for SeigniorageRecipient {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (version, bytes) = layout::read_layout_version(bytes)?;
         let (stake, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegator_stake, bytes) = FromBytes::from_bytes(bytes)?;
+        let (contract_delegator_stake, bytes) = match version {
+            None => (BTreeMap::new(), bytes),
+            Some(SEIGNIORAGE_RECIPIENT_LAYOUT_VERSION) => FromBytes::from_bytes(bytes)?,
+            Some(_) => return Err(bytesrepr::Error::Formatting),
+        };
         Ok((
             SeigniorageRecipient {
                 stake,
                 delegation_rate,
                 delegator_stake,
+                contract_delegator_stake,
             },
             bytes,
         ))
@@ -89,23 +117,31 @@ impl From<&Bid> for SeigniorageRecipient {
             .iter()
             .map(|(public_key, delegator)| (*public_key, *delegator.staked_amount()))
             .collect();
+        let contract_delegator_stake = bid
+            .contract_delegators()
+            .iter()
+            .map(|(contract_package_hash, contract_delegator)| {
+                (*contract_package_hash, *contract_delegator.staked_amount())
+            })
+            .collect();
         Self {
             stake: *bid.staked_amount(),
             delegation_rate: *bid.delegation_rate(),
             delegator_stake,
+            contract_delegator_stake,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloc::collections::BTreeMap;
+    use alloc::{collections::BTreeMap, vec::Vec};
     use core::iter::FromIterator;
 
     use crate::{
-        bytesrepr,
+        bytesrepr::{self, ToBytes},
         system::auction::{DelegationRate, SeigniorageRecipient},
-        SecretKey, U512,
+        ContractPackageHash, SecretKey, U512,
     };
 
     #[test]
@@ -121,7 +157,31 @@ mod tests {
                 (delegator_2_key, U512::max_value()),
                 (delegator_3_key, U512::zero()),
             ]),
+            contract_delegator_stake: BTreeMap::from_iter(vec![(
+                ContractPackageHash::new([45; 32]),
+                U512::max_value(),
+            )]),
         };
         bytesrepr::test_serialization_roundtrip(&seigniorage_recipient);
     }
+
+    #[test]
+    fn should_deserialize_recipient_written_before_layout_was_versioned() {
+        let delegator_key = SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let seigniorage_recipient = SeigniorageRecipient {
+            stake: U512::from(1000),
+            delegation_rate: 10,
+            delegator_stake: BTreeMap::from_iter(vec![(delegator_key, U512::from(500))]),
+            contract_delegator_stake: BTreeMap::new(),
+        };
+
+        let mut legacy_bytes = Vec::new();
+        legacy_bytes.append(&mut seigniorage_recipient.stake.to_bytes().unwrap());
+        legacy_bytes.append(&mut seigniorage_recipient.delegation_rate.to_bytes().unwrap());
+        legacy_bytes.append(&mut seigniorage_recipient.delegator_stake.to_bytes().unwrap());
+
+        let decoded: SeigniorageRecipient =
+            bytesrepr::deserialize(legacy_bytes).expect("should deserialize");
+        assert_eq!(decoded, seigniorage_recipient);
+    }
 }
@@ -0,0 +1,185 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::{Bids, Error},
+    CLType, CLTyped, U512,
+};
+
+/// Totals over all bids in the auction, kept up to date as stakes change.
+///
+/// Serialized as a tuple of `(total_bonded, total_delegated, delegator_count)`.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct StakingStats {
+    /// Total stake of all validators (not including delegators).
+    total_bonded: U512,
+    /// Total stake of all delegators.
+    total_delegated: U512,
+    /// Number of delegations across all validators.
+    delegator_count: u64,
+}
+
+impl StakingStats {
+    /// Computes the statistics of the given bids.
+    pub fn from_bids(bids: &Bids) -> Result<Self, Error> {
+        let mut staking_stats = StakingStats::default();
+        for bid in bids.values() {
+            staking_stats.increase_bonded(*bid.staked_amount())?;
+            for delegator in bid.delegators().values() {
+                staking_stats.add_delegator(*delegator.staked_amount())?;
+            }
+            for contract_delegator in bid.contract_delegators().values() {
+                staking_stats.add_delegator(*contract_delegator.staked_amount())?;
+            }
+        }
+        Ok(staking_stats)
+    }
+
+    /// Returns the total stake of all validators, not including delegators.
+    pub fn total_bonded(&self) -> &U512 {
+        &self.total_bonded
+    }
+
+    /// Returns the total stake of all delegators.
+    pub fn total_delegated(&self) -> &U512 {
+        &self.total_delegated
+    }
+
+    /// Returns the number of delegations across all validators.
+    pub fn delegator_count(&self) -> u64 {
+        self.delegator_count
+    }
+
+    /// Adds `amount` to the validators' total stake.
+    pub fn increase_bonded(&mut self, amount: U512) -> Result<(), Error> {
+        self.total_bonded = self
+            .total_bonded
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        Ok(())
+    }
+
+    /// Removes `amount` from the validators' total stake.
+    pub fn decrease_bonded(&mut self, amount: U512) {
+        self.total_bonded = self.total_bonded.saturating_sub(amount);
+    }
+
+    /// Adds `amount` to the delegators' total stake.
+    pub fn increase_delegated(&mut self, amount: U512) -> Result<(), Error> {
+        self.total_delegated = self
+            .total_delegated
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        Ok(())
+    }
+
+    /// Removes `amount` from the delegators' total stake.
+    pub fn decrease_delegated(&mut self, amount: U512) {
+        self.total_delegated = self.total_delegated.saturating_sub(amount);
+    }
+
+    /// Records a new delegation of `amount`.
+    pub fn add_delegator(&mut self, amount: U512) -> Result<(), Error> {
+        self.increase_delegated(amount)?;
+        self.delegator_count = self
+            .delegator_count
+            .checked_add(1)
+            .ok_or(Error::InvalidAmount)?;
+        Ok(())
+    }
+
+    /// Records the removal of a delegation which had `amount` remaining.
+    pub fn remove_delegator(&mut self, amount: U512) {
+        self.decrease_delegated(amount);
+        self.delegator_count = self.delegator_count.saturating_sub(1);
+    }
+}
+
+impl CLTyped for StakingStats {
+    fn cl_type() -> CLType {
+        CLType::Tuple3([
+            Box::new(U512::cl_type()),
+            Box::new(U512::cl_type()),
+            Box::new(u64::cl_type()),
+        ])
+    }
+}
+
+impl ToBytes for StakingStats {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.total_bonded.to_bytes()?);
+        result.extend(self.total_delegated.to_bytes()?);
+        result.extend(self.delegator_count.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.total_bonded.serialized_length()
+            + self.total_delegated.serialized_length()
+            + self.delegator_count.serialized_length()
+    }
+}
+
+impl FromBytes for StakingStats {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (total_bonded, bytes) = FromBytes::from_bytes(bytes)?;
+        let (total_delegated, bytes) = FromBytes::from_bytes(bytes)?;
+        let (delegator_count, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            StakingStats {
+                total_bonded,
+                total_delegated,
+                delegator_count,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytesrepr,
+        system::auction::{Bid, Bids, Delegator, StakingStats},
+        AccessRights, CLValue, PublicKey, SecretKey, URef, U512,
+    };
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut staking_stats = StakingStats::default();
+        staking_stats.increase_bonded(U512::max_value()).unwrap();
+        staking_stats.add_delegator(U512::from(42)).unwrap();
+        bytesrepr::test_serialization_roundtrip(&staking_stats);
+
+        // the representation is a well-typed tuple
+        let cl_value = CLValue::from_t(staking_stats).unwrap();
+        assert_eq!(
+            cl_value.into_t::<(U512, U512, u64)>().unwrap(),
+            (U512::max_value(), U512::from(42), 1)
+        );
+    }
+
+    #[test]
+    fn should_compute_stats_from_bids() {
+        let validator: PublicKey = SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let delegator_1: PublicKey = SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into();
+        let delegator_2: PublicKey = SecretKey::ed25519([44; SecretKey::ED25519_LENGTH]).into();
+        let purse = URef::new([0; 32], AccessRights::READ_ADD_WRITE);
+
+        let mut bid = Bid::unlocked(validator, purse, U512::from(1_000), 0);
+        for (delegator, amount) in &[(delegator_1, 10), (delegator_2, 20)] {
+            bid.delegators_mut().insert(
+                *delegator,
+                Delegator::unlocked(*delegator, U512::from(*amount), purse, validator),
+            );
+        }
+        let mut bids = Bids::new();
+        bids.insert(validator, bid);
+
+        let staking_stats = StakingStats::from_bids(&bids).unwrap();
+        assert_eq!(*staking_stats.total_bonded(), U512::from(1_000));
+        assert_eq!(*staking_stats.total_delegated(), U512::from(30));
+        assert_eq!(staking_stats.delegator_count(), 2);
+    }
+}
@@ -28,6 +28,9 @@ pub struct UnbondingPurse {
     era_of_creation: EraId,
     /// Unbonding Amount.
     amount: U512,
+    /// The purse the unbonded funds are paid out to.  `None` if paid out to the main purse of the
+    /// unbonder's account.
+    unbond_purse: Option<URef>,
 }
 
 impl UnbondingPurse {
@@ -45,6 +48,29 @@ impl UnbondingPurse {
             unbonder_public_key,
             era_of_creation,
             amount,
+            unbond_purse: None,
+        }
+    }
+
+    /// Creates [`UnbondingPurse`] instance for an unbonding request made by a contract, paying the
+    /// unbonded funds out to `unbond_purse`.
+    ///
+    /// Contracts have no public key, so [`UnbondingPurse::unbonder_public_key`] of such requests
+    /// is [`PublicKey::System`].
+    pub const fn with_unbond_purse(
+        bonding_purse: URef,
+        validator_public_key: PublicKey,
+        era_of_creation: EraId,
+        amount: U512,
+        unbond_purse: URef,
+    ) -> Self {
+        Self {
+            bonding_purse,
+            validator_public_key,
+            unbonder_public_key: PublicKey::System,
+            era_of_creation,
+            amount,
+            unbond_purse: Some(unbond_purse),
         }
     }
 
@@ -82,6 +108,12 @@ impl UnbondingPurse {
     pub fn amount(&self) -> &U512 {
         &self.amount
     }
+
+    /// Returns the purse the unbonded funds are paid out to, or `None` if they are paid out to the
+    /// main purse of the unbonder's account.
+    pub fn unbond_purse(&self) -> Option<&URef> {
+        self.unbond_purse.as_ref()
+    }
 }
 
 impl ToBytes for UnbondingPurse {
@@ -92,6 +124,7 @@ impl ToBytes for UnbondingPurse {
         result.extend(&self.unbonder_public_key.to_bytes()?);
         result.extend(&self.era_of_creation.to_bytes()?);
         result.extend(&self.amount.to_bytes()?);
+        result.extend(&self.unbond_purse.to_bytes()?);
         Ok(result)
     }
     fn serialized_length(&self) -> usize {
@@ -100,6 +133,7 @@ impl ToBytes for UnbondingPurse {
             + self.unbonder_public_key.serialized_length()
             + self.era_of_creation.serialized_length()
             + self.amount.serialized_length()
+            + self.unbond_purse.serialized_length()
     }
 }
 
@@ -110,6 +144,7 @@ impl FromBytes for UnbondingPurse {
         let (unbonder_public_key, bytes) = FromBytes::from_bytes(bytes)?;
         let (era_of_creation, bytes) = FromBytes::from_bytes(bytes)?;
         let (amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (unbond_purse, bytes) = FromBytes::from_bytes(bytes)?;
         Ok((
             UnbondingPurse {
                 bonding_purse,
@@ -117,6 +152,7 @@ impl FromBytes for UnbondingPurse {
                 unbonder_public_key,
                 era_of_creation,
                 amount,
+                unbond_purse,
             },
             bytes,
         ))
@@ -140,6 +176,7 @@ mod tests {
     };
 
     const BONDING_PURSE: URef = URef::new([41; 32], AccessRights::READ_ADD_WRITE);
+    const UNBOND_PURSE: URef = URef::new([44; 32], AccessRights::ADD);
     const ERA_OF_WITHDRAWAL: EraId = EraId::max_value();
 
     static VALIDATOR_PUBLIC_KEY: Lazy<PublicKey> =
@@ -156,9 +193,18 @@ mod tests {
             unbonder_public_key: *UNBONDER_PUBLIC_KEY,
             era_of_creation: ERA_OF_WITHDRAWAL,
             amount: *AMOUNT,
+            unbond_purse: None,
         };
-
         bytesrepr::test_serialization_roundtrip(&unbonding_purse);
+
+        let contract_unbonding_purse = UnbondingPurse::with_unbond_purse(
+            BONDING_PURSE,
+            *VALIDATOR_PUBLIC_KEY,
+            ERA_OF_WITHDRAWAL,
+            *AMOUNT,
+            UNBOND_PURSE,
+        );
+        bytesrepr::test_serialization_roundtrip(&contract_unbonding_purse);
     }
     #[test]
     fn should_be_validator_condition() {
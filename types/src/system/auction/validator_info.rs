@@ -0,0 +1,171 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::{
+        Error, MAX_VALIDATOR_NAME_LENGTH, MAX_VALIDATOR_URL_LENGTH, VALIDATOR_INFO_KEY_PREFIX,
+    },
+    CLType, CLTyped, BLAKE2B_DIGEST_LENGTH,
+};
+
+/// Public metadata a validator publishes about itself, e.g. for display by explorers and
+/// delegator UIs.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorInfo {
+    /// Display name of the validator.
+    name: String,
+    /// URL of the validator's website.
+    url: String,
+    /// Hash of the validator's icon.
+    icon_hash: Option<[u8; BLAKE2B_DIGEST_LENGTH]>,
+}
+
+impl ValidatorInfo {
+    /// Creates a new [`ValidatorInfo`], checking that `name` and `url` don't exceed
+    /// [`MAX_VALIDATOR_NAME_LENGTH`] and [`MAX_VALIDATOR_URL_LENGTH`] bytes respectively.
+    pub fn new(
+        name: String,
+        url: String,
+        icon_hash: Option<[u8; BLAKE2B_DIGEST_LENGTH]>,
+    ) -> Result<Self, Error> {
+        if name.len() > MAX_VALIDATOR_NAME_LENGTH {
+            return Err(Error::ValidatorNameTooLong);
+        }
+        if url.len() > MAX_VALIDATOR_URL_LENGTH {
+            return Err(Error::ValidatorUrlTooLong);
+        }
+        Ok(ValidatorInfo {
+            name,
+            url,
+            icon_hash,
+        })
+    }
+
+    /// Returns the validator's display name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the URL of the validator's website.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the hash of the validator's icon.
+    pub fn icon_hash(&self) -> Option<&[u8; BLAKE2B_DIGEST_LENGTH]> {
+        self.icon_hash.as_ref()
+    }
+
+    /// Returns the total size in bytes of the fields set by the validator, used to charge for
+    /// storing them.
+    pub fn size(&self) -> usize {
+        self.name.len() + self.url.len() + self.icon_hash.map_or(0, |icon_hash| icon_hash.len())
+    }
+}
+
+/// Returns the name of the auction contract's named key under which the [`ValidatorInfo`] of the
+/// validator with the given account hash is stored.
+pub fn validator_info_key_name(account_hash: &AccountHash) -> String {
+    let mut name = String::from(VALIDATOR_INFO_KEY_PREFIX);
+    name.push_str(&base16::encode_lower(&account_hash.value()));
+    name
+}
+
+impl ToBytes for ValidatorInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(&self.name.to_bytes()?);
+        result.extend(&self.url.to_bytes()?);
+        result.extend(&self.icon_hash.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.url.serialized_length()
+            + self.icon_hash.serialized_length()
+    }
+}
+
+impl FromBytes for ValidatorInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, bytes) = FromBytes::from_bytes(bytes)?;
+        let (url, bytes) = FromBytes::from_bytes(bytes)?;
+        let (icon_hash, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            ValidatorInfo {
+                name,
+                url,
+                icon_hash,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl CLTyped for ValidatorInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let validator_info = ValidatorInfo::new(
+            "Validator".to_string(),
+            "https://validator.example".to_string(),
+            Some([7; BLAKE2B_DIGEST_LENGTH]),
+        )
+        .unwrap();
+        bytesrepr::test_serialization_roundtrip(&validator_info);
+        bytesrepr::test_serialization_roundtrip(&ValidatorInfo::default());
+    }
+
+    #[test]
+    fn should_enforce_length_limits() {
+        let long_name = "a".repeat(MAX_VALIDATOR_NAME_LENGTH + 1);
+        let long_url = "a".repeat(MAX_VALIDATOR_URL_LENGTH + 1);
+        assert_eq!(
+            ValidatorInfo::new(long_name, String::new(), None),
+            Err(Error::ValidatorNameTooLong)
+        );
+        assert_eq!(
+            ValidatorInfo::new(String::new(), long_url, None),
+            Err(Error::ValidatorUrlTooLong)
+        );
+
+        let validator_info = ValidatorInfo::new(
+            "a".repeat(MAX_VALIDATOR_NAME_LENGTH),
+            "a".repeat(MAX_VALIDATOR_URL_LENGTH),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            validator_info.size(),
+            MAX_VALIDATOR_NAME_LENGTH + MAX_VALIDATOR_URL_LENGTH
+        );
+    }
+
+    #[test]
+    fn key_name_should_contain_account_hash() {
+        let name = validator_info_key_name(&AccountHash::new([0xab; 32]));
+        assert!(name.starts_with(VALIDATOR_INFO_KEY_PREFIX));
+        assert_eq!(name.len(), VALIDATOR_INFO_KEY_PREFIX.len() + 64);
+    }
+}
@@ -39,12 +39,14 @@ pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
     }
 
     /// Finalize payment with `amount_spent` and a given `account`.
+    ///
+    /// Returns the motes refunded to the payer followed by those paid to `target`.
     fn finalize_payment(
         &mut self,
         amount_spent: U512,
         account: AccountHash,
         target: URef,
-    ) -> Result<(), Error> {
+    ) -> Result<(U512, U512), Error> {
         internal::finalize_payment(self, amount_spent, account, target)
     }
 }
@@ -101,7 +103,7 @@ mod internal {
         amount_spent: U512,
         account: AccountHash,
         target: URef,
-    ) -> Result<(), Error> {
+    ) -> Result<(U512, U512), Error> {
         let caller = provider.get_caller();
         if caller != PublicKey::System.to_account_hash() {
             return Err(Error::SystemFunctionCalledByUserAccount);
@@ -136,13 +138,16 @@ mod internal {
             .map_err(|_| Error::FailedTransferToRewardsPurse)?;
 
         if refund_amount.is_zero() {
-            return Ok(());
+            return Ok((refund_amount, validator_reward));
         }
 
         // give refund
         let refund_purse = match refund_purse {
             Some(uref) => uref,
-            None => return refund_to_account::<P>(provider, payment_purse, account, refund_amount),
+            None => {
+                refund_to_account::<P>(provider, payment_purse, account, refund_amount)?;
+                return Ok((refund_amount, validator_reward));
+            }
         };
 
         // in case of failure to transfer to refund purse we fall back on the account's main purse
@@ -150,10 +155,10 @@ mod internal {
             .transfer_purse_to_purse(payment_purse, refund_purse, refund_amount)
             .is_err()
         {
-            return refund_to_account::<P>(provider, payment_purse, account, refund_amount);
+            refund_to_account::<P>(provider, payment_purse, account, refund_amount)?;
         }
 
-        Ok(())
+        Ok((refund_amount, validator_reward))
     }
 
     pub fn refund_to_account<M: MintProvider>(
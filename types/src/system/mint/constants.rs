@@ -10,6 +10,10 @@ pub const ARG_TO: &str = "to";
 pub const ARG_SOURCE: &str = "source";
 /// Named constant for `target`.
 pub const ARG_TARGET: &str = "target";
+/// Named constant for `spender`.
+pub const ARG_SPENDER: &str = "spender";
+/// Named constant for `owner_purse`.
+pub const ARG_OWNER_PURSE: &str = "owner_purse";
 /// Named constant for `round_seigniorage_rate` used in installer.
 pub const ARG_ROUND_SEIGNIORAGE_RATE: &str = "round_seigniorage_rate";
 
@@ -25,6 +29,10 @@ pub const METHOD_BALANCE: &str = "balance";
 pub const METHOD_TRANSFER: &str = "transfer";
 /// Named constant for method `read_base_round_reward`.
 pub const METHOD_READ_BASE_ROUND_REWARD: &str = "read_base_round_reward";
+/// Named constant for method `approve`.
+pub const METHOD_APPROVE: &str = "approve";
+/// Named constant for method `transfer_from`.
+pub const METHOD_TRANSFER_FROM: &str = "transfer_from";
 
 /// Storage for mint contract hash.
 pub const HASH_KEY: &str = "mint_hash";
@@ -77,6 +77,15 @@ pub enum Error {
     #[cfg_attr(feature = "std", error("GasLimit"))]
     GasLimit = 18,
 
+    /// The requested amount exceeds the spender's remaining allowance on the source purse.
+    #[cfg_attr(feature = "std", error("Insufficient allowance"))]
+    InsufficientAllowance = 19,
+
+    /// An allowance can only be spent by an account calling the mint directly, not from within a
+    /// stored contract.
+    #[cfg_attr(feature = "std", error("Invalid caller"))]
+    InvalidCaller = 20,
+
     #[cfg(test)]
     #[doc(hidden)]
     #[cfg_attr(feature = "std", error("Sentinel error"))]
@@ -141,6 +150,8 @@ impl TryFrom<u8> for Error {
             d if d == Error::Serialize as u8 => Ok(Error::Serialize),
             d if d == Error::EqualSourceAndTarget as u8 => Ok(Error::EqualSourceAndTarget),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
+            d if d == Error::InsufficientAllowance as u8 => Ok(Error::InsufficientAllowance),
+            d if d == Error::InvalidCaller as u8 => Ok(Error::InvalidCaller),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -7,7 +7,7 @@ mod system_provider;
 
 use num_rational::Ratio;
 
-use crate::{account::AccountHash, Key, PublicKey, URef, U512};
+use crate::{account::AccountHash, AccessRights, Key, PublicKey, URef, URefAddr, U512};
 
 pub use crate::system::mint::{
     constants::*, error::Error, runtime_provider::RuntimeProvider,
@@ -115,6 +115,65 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         Ok(())
     }
 
+    /// Approves `spender` to transfer up to `amount` of tokens out of `owner_purse`, replacing any
+    /// previously approved allowance.  Approving an `amount` of zero revokes the allowance.
+    fn approve(
+        &mut self,
+        owner_purse: URef,
+        spender: AccountHash,
+        amount: U512,
+    ) -> Result<(), Error> {
+        if !owner_purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        if self.read_balance(owner_purse)?.is_none() {
+            return Err(Error::SourceNotFound);
+        }
+        self.write_allowance(owner_purse.addr(), spender, amount)
+    }
+
+    /// Transfers `amount` of tokens from the purse at `owner_purse` to a `target` purse on behalf
+    /// of the caller, deducting `amount` from the allowance the caller was approved for.
+    ///
+    /// Only an account calling the mint directly can spend its allowance, so that contracts called
+    /// by the spender can't.
+    fn transfer_from(
+        &mut self,
+        owner_purse: URefAddr,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<(), Error> {
+        if !target.is_addable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let spender = self.get_immediate_caller().ok_or(Error::InvalidCaller)?;
+        let allowance = self.read_allowance(owner_purse, spender)?;
+        if amount > allowance {
+            return Err(Error::InsufficientAllowance);
+        }
+        // The allowance, not the URef, authorizes the transfer, so the source grants no access.
+        let source = URef::new(owner_purse, AccessRights::NONE);
+        if source.addr() == target.addr() {
+            return Err(Error::EqualSourceAndTarget);
+        }
+        let source_balance: U512 = match self.read_balance(source)? {
+            Some(source_balance) => source_balance,
+            None => return Err(Error::SourceNotFound),
+        };
+        if amount > source_balance {
+            return Err(Error::InsufficientFunds);
+        }
+        if self.read_balance(target)?.is_none() {
+            return Err(Error::DestNotFound);
+        }
+        self.write_allowance(owner_purse, spender, allowance - amount)?;
+        self.write_balance(source, source_balance - amount)?;
+        self.add_balance(target, amount)?;
+        self.record_transfer(None, source, target, amount, id)?;
+        Ok(())
+    }
+
     /// Retrieves the base round reward.
     fn read_base_round_reward(&mut self) -> Result<U512, Error> {
         let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
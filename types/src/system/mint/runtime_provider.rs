@@ -5,6 +5,10 @@ pub trait RuntimeProvider {
     /// This method should return the caller of the current context.
     fn get_caller(&self) -> AccountHash;
 
+    /// This method should return the account which called the mint directly, or `None` if the
+    /// mint was called from within a stored contract.
+    fn get_immediate_caller(&self) -> Option<AccountHash>;
+
     /// This method should handle storing given [`Key`] under `name`.
     fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error>;
 
@@ -1,7 +1,8 @@
 use crate::{
+    account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::mint::Error,
-    CLTyped, URef, U512,
+    CLTyped, URef, URefAddr, U512,
 };
 
 /// Provides functionality of a contract storage.
@@ -26,4 +27,17 @@ pub trait StorageProvider {
 
     /// Add amount to an existing balance.
     fn add_balance(&mut self, uref: URef, value: U512) -> Result<(), Error>;
+
+    /// Read the amount `spender` may transfer out of the purse at `purse_addr`.  Returns zero if
+    /// no allowance has been approved.
+    fn read_allowance(&mut self, purse_addr: URefAddr, spender: AccountHash)
+        -> Result<U512, Error>;
+
+    /// Write the amount `spender` may transfer out of the purse at `purse_addr`.
+    fn write_allowance(
+        &mut self,
+        purse_addr: URefAddr,
+        spender: AccountHash,
+        amount: U512,
+    ) -> Result<(), Error>;
 }
@@ -57,13 +57,18 @@ mod system_contract_type {
         fmt::{self, Display, Formatter},
     };
 
+    #[cfg(feature = "std")]
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
     use crate::ApiError;
 
     /// System contract types.
     ///
     /// Used by converting to a `u32` and passing as the `system_contract_index` argument of
     /// `ext_ffi::casper_get_system_contract()`.
-    #[derive(Debug, Copy, Clone, PartialEq)]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "std", derive(JsonSchema))]
     pub enum SystemContractType {
         /// Mint contract.
         Mint,
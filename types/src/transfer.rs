@@ -6,6 +6,7 @@ use core::{
     array::TryFromSliceError,
     convert::TryFrom,
     fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
 };
 
 use datasize::DataSize;
@@ -20,7 +21,7 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, URef, U512,
+    ApiError, BlockTime, CLType, CLTyped, Key, URef, U512,
 };
 
 /// The length of a deploy hash.
@@ -28,6 +29,7 @@ pub const DEPLOY_HASH_LENGTH: usize = 32;
 /// The length of a transfer address.
 pub const TRANSFER_ADDR_LENGTH: usize = 32;
 const TRANSFER_ADDR_FORMATTED_STRING_PREFIX: &str = "transfer-";
+const DEPLOY_HASH_FORMATTED_STRING_PREFIX: &str = "deploy-";
 
 /// A newtype wrapping a [`[u8; DEPLOY_HASH_LENGTH]`] which is the raw bytes of the deploy hash.
 #[derive(DataSize, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug)]
@@ -48,6 +50,63 @@ impl DeployHash {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Formats the `DeployHash` as a prefixed, hex-encoded string.
+    pub fn to_formatted_string(&self) -> String {
+        format!(
+            "{}{}",
+            DEPLOY_HASH_FORMATTED_STRING_PREFIX,
+            base16::encode_lower(&self.0),
+        )
+    }
+
+    /// Parses a string formatted as per `Self::to_formatted_string()` into a `DeployHash`.
+    pub fn from_formatted_str(input: &str) -> Result<Self, FromStrError> {
+        let remainder = input
+            .strip_prefix(DEPLOY_HASH_FORMATTED_STRING_PREFIX)
+            .ok_or(FromStrError::InvalidPrefix)?;
+        Ok(DeployHash(decode_hex(remainder)?))
+    }
+}
+
+impl Display for DeployHash {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", base16::encode_lower(&self.0))
+    }
+}
+
+impl TryFrom<&str> for DeployHash {
+    type Error = FromStrError;
+
+    /// Parses either a plain hex-encoded string, or one formatted as per
+    /// `Self::to_formatted_string()`.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.starts_with(DEPLOY_HASH_FORMATTED_STRING_PREFIX) {
+            DeployHash::from_formatted_str(input)
+        } else {
+            Ok(DeployHash(decode_hex(input)?))
+        }
+    }
+}
+
+impl FromStr for DeployHash {
+    type Err = FromStrError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        DeployHash::try_from(input)
+    }
+}
+
+impl TryFrom<Key> for DeployHash {
+    type Error = ApiError;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        if let Key::DeployInfo(deploy_hash) = key {
+            Ok(deploy_hash)
+        } else {
+            Err(ApiError::UnexpectedKeyVariant)
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -211,7 +270,98 @@ impl ToBytes for Transfer {
     }
 }
 
-/// Error returned when decoding a `TransferAddr` from a formatted string.
+/// Proof of a transfer made to an account, returned to the payer by the transfer host functions.
+///
+/// The full [`Transfer`] is recorded in global state under `Key::Transfer(transfer_addr)`, so a
+/// contract can store the receipt and later use it to reference the payment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TransferReceipt {
+    /// Address under which the transfer is recorded
+    pub transfer_addr: TransferAddr,
+    /// Account from which transfer was executed
+    pub from: AccountHash,
+    /// Account to which funds were transferred
+    pub to: AccountHash,
+    /// Transfer amount
+    pub amount: U512,
+    /// Block time at which the transfer was executed
+    pub timestamp: BlockTime,
+    /// User-defined id
+    pub id: Option<u64>,
+}
+
+impl TransferReceipt {
+    /// Creates a [`TransferReceipt`].
+    pub fn new(
+        transfer_addr: TransferAddr,
+        from: AccountHash,
+        to: AccountHash,
+        amount: U512,
+        timestamp: BlockTime,
+        id: Option<u64>,
+    ) -> Self {
+        TransferReceipt {
+            transfer_addr,
+            from,
+            to,
+            amount,
+            timestamp,
+            id,
+        }
+    }
+}
+
+impl CLTyped for TransferReceipt {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for TransferReceipt {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (transfer_addr, rem) = TransferAddr::from_bytes(bytes)?;
+        let (from, rem) = AccountHash::from_bytes(rem)?;
+        let (to, rem) = AccountHash::from_bytes(rem)?;
+        let (amount, rem) = U512::from_bytes(rem)?;
+        let (timestamp, rem) = BlockTime::from_bytes(rem)?;
+        let (id, rem) = <Option<u64>>::from_bytes(rem)?;
+        Ok((
+            TransferReceipt {
+                transfer_addr,
+                from,
+                to,
+                amount,
+                timestamp,
+                id,
+            },
+            rem,
+        ))
+    }
+}
+
+impl ToBytes for TransferReceipt {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.transfer_addr.to_bytes()?);
+        result.append(&mut self.from.to_bytes()?);
+        result.append(&mut self.to.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.timestamp.to_bytes()?);
+        result.append(&mut self.id.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.transfer_addr.serialized_length()
+            + self.from.serialized_length()
+            + self.to.serialized_length()
+            + self.amount.serialized_length()
+            + self.timestamp.serialized_length()
+            + self.id.serialized_length()
+    }
+}
+
+/// Error returned when decoding a `TransferAddr` or a `DeployHash` from a string.
 #[derive(Debug)]
 pub enum FromStrError {
     /// The prefix is invalid.
@@ -237,7 +387,11 @@ impl From<TryFromSliceError> for FromStrError {
 impl Display for FromStrError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            FromStrError::InvalidPrefix => write!(f, "prefix is not 'transfer-'"),
+            FromStrError::InvalidPrefix => write!(
+                f,
+                "prefix is not '{}' or '{}'",
+                TRANSFER_ADDR_FORMATTED_STRING_PREFIX, DEPLOY_HASH_FORMATTED_STRING_PREFIX
+            ),
             FromStrError::Hex(error) => {
                 write!(f, "failed to decode address portion from hex: {}", error)
             }
@@ -246,6 +400,13 @@ impl Display for FromStrError {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrError {}
+
+fn decode_hex(input: &str) -> Result<[u8; 32], FromStrError> {
+    Ok(<[u8; 32]>::try_from(base16::decode(input)?.as_ref())?)
+}
+
 /// A newtype wrapping a [`[u8; TRANSFER_ADDR_LENGTH]`] which is the raw bytes of the transfer
 /// address.
 #[derive(DataSize, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
@@ -281,8 +442,41 @@ impl TransferAddr {
         let remainder = input
             .strip_prefix(TRANSFER_ADDR_FORMATTED_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
-        let bytes = <[u8; TRANSFER_ADDR_LENGTH]>::try_from(base16::decode(remainder)?.as_ref())?;
-        Ok(TransferAddr(bytes))
+        Ok(TransferAddr(decode_hex(remainder)?))
+    }
+}
+
+impl TryFrom<&str> for TransferAddr {
+    type Error = FromStrError;
+
+    /// Parses either a plain hex-encoded string, or one formatted as per
+    /// `Self::to_formatted_string()`.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.starts_with(TRANSFER_ADDR_FORMATTED_STRING_PREFIX) {
+            TransferAddr::from_formatted_str(input)
+        } else {
+            Ok(TransferAddr(decode_hex(input)?))
+        }
+    }
+}
+
+impl FromStr for TransferAddr {
+    type Err = FromStrError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        TransferAddr::try_from(input)
+    }
+}
+
+impl TryFrom<Key> for TransferAddr {
+    type Error = ApiError;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        if let Key::Transfer(transfer_addr) = key {
+            Ok(transfer_addr)
+        } else {
+            Err(ApiError::UnexpectedKeyVariant)
+        }
     }
 }
 
@@ -399,7 +593,7 @@ pub mod gens {
     use crate::{
         deploy_info::gens::{account_hash_arb, deploy_hash_arb},
         gens::{u512_arb, uref_arb},
-        Transfer,
+        BlockTime, Transfer, TransferAddr, TransferReceipt,
     };
 
     /// Creates an arbitrary [`Transfer`]
@@ -427,6 +621,28 @@ pub mod gens {
                 }
             })
     }
+
+    /// Creates an arbitrary [`TransferReceipt`]
+    pub fn transfer_receipt_arb() -> impl Strategy<Value = TransferReceipt> {
+        (
+            <[u8; 32]>::arbitrary(),
+            account_hash_arb(),
+            account_hash_arb(),
+            u512_arb(),
+            <u64>::arbitrary(),
+            option::of(<u64>::arbitrary()),
+        )
+            .prop_map(|(transfer_addr, from, to, amount, timestamp, id)| {
+                TransferReceipt::new(
+                    TransferAddr::new(transfer_addr),
+                    from,
+                    to,
+                    amount,
+                    BlockTime::new(timestamp),
+                    id,
+                )
+            })
+    }
 }
 
 #[cfg(test)]
@@ -442,6 +658,13 @@ mod tests {
         fn test_serialization_roundtrip(transfer in gens::transfer_arb()) {
             bytesrepr::test_serialization_roundtrip(&transfer)
         }
+
+        #[test]
+        fn test_transfer_receipt_serialization_roundtrip(
+            transfer_receipt in gens::transfer_receipt_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&transfer_receipt)
+        }
     }
 
     #[test]
@@ -471,6 +694,87 @@ mod tests {
         assert!(TransferAddr::from_formatted_str(invalid_hex).is_err());
     }
 
+    #[test]
+    fn transfer_addr_parse_plain_and_formatted_hex() {
+        let transfer_address = TransferAddr([7; 32]);
+        assert_eq!(
+            transfer_address
+                .to_string()
+                .parse::<TransferAddr>()
+                .unwrap(),
+            transfer_address
+        );
+        assert_eq!(
+            TransferAddr::try_from(transfer_address.to_formatted_string().as_str()).unwrap(),
+            transfer_address
+        );
+        assert!(matches!(
+            "transfer-0707".parse::<TransferAddr>(),
+            Err(FromStrError::Length(_))
+        ));
+        assert!(matches!(
+            "deploy-0000000000000000000000000000000000000000000000000000000000000000"
+                .parse::<TransferAddr>(),
+            Err(FromStrError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn deploy_hash_from_str() {
+        let deploy_hash = DeployHash([9; 32]);
+        let encoded = deploy_hash.to_formatted_string();
+        assert!(encoded.starts_with(DEPLOY_HASH_FORMATTED_STRING_PREFIX));
+        assert_eq!(
+            DeployHash::from_formatted_str(&encoded).unwrap(),
+            deploy_hash
+        );
+        assert_eq!(encoded.parse::<DeployHash>().unwrap(), deploy_hash);
+        assert_eq!(
+            deploy_hash.to_string().parse::<DeployHash>().unwrap(),
+            deploy_hash
+        );
+
+        let json = serde_json::to_string(&deploy_hash).unwrap();
+        assert_eq!(json, format!("\"{}\"", deploy_hash));
+
+        assert!(matches!(
+            DeployHash::from_formatted_str(&deploy_hash.to_string()),
+            Err(FromStrError::InvalidPrefix)
+        ));
+        assert!(matches!(
+            "deploy-0909".parse::<DeployHash>(),
+            Err(FromStrError::Length(_))
+        ));
+        assert!(matches!(
+            "000000000000000000000000000000000000000000000000000000000000000g"
+                .parse::<DeployHash>(),
+            Err(FromStrError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn key_conversions() {
+        let deploy_hash = DeployHash([1; 32]);
+        let transfer_addr = TransferAddr([2; 32]);
+
+        assert_eq!(
+            DeployHash::try_from(Key::from(deploy_hash)),
+            Ok(deploy_hash)
+        );
+        assert_eq!(
+            TransferAddr::try_from(Key::from(transfer_addr)),
+            Ok(transfer_addr)
+        );
+        assert_eq!(
+            DeployHash::try_from(Key::from(transfer_addr)),
+            Err(ApiError::UnexpectedKeyVariant)
+        );
+        assert_eq!(
+            TransferAddr::try_from(Key::Hash([2; 32])),
+            Err(ApiError::UnexpectedKeyVariant)
+        );
+    }
+
     #[test]
     fn transfer_addr_serde_roundtrip() {
         let transfer_address = TransferAddr([255; 32]);
@@ -0,0 +1,62 @@
+use casper_types::{ApiError, ContractError, UserErrorSchema};
+
+#[derive(ContractError, Copy, Clone, Debug, PartialEq)]
+enum Error {
+    InsufficientFunds = 1,
+    Unauthorized,
+    Overflow = 500,
+    Unknown = 65_535,
+}
+
+#[test]
+fn should_convert_to_and_from_api_error() {
+    assert_eq!(ApiError::from(Error::InsufficientFunds), ApiError::User(1));
+    assert_eq!(ApiError::from(Error::Unauthorized), ApiError::User(2));
+    assert_eq!(ApiError::from(Error::Overflow), ApiError::User(500));
+    assert_eq!(
+        ApiError::from(Error::Unknown),
+        ApiError::User(u16::max_value())
+    );
+
+    for error in &[
+        Error::InsufficientFunds,
+        Error::Unauthorized,
+        Error::Overflow,
+        Error::Unknown,
+    ] {
+        assert_eq!(Error::from_api_error(ApiError::from(*error)), Some(*error));
+    }
+    assert_eq!(Error::from_api_error(ApiError::User(3)), None);
+    assert_eq!(Error::from_api_error(ApiError::InvalidArgument), None);
+}
+
+#[test]
+fn should_record_schema() {
+    assert_eq!(
+        Error::SCHEMA,
+        &[
+            (1, "InsufficientFunds"),
+            (2, "Unauthorized"),
+            (500, "Overflow"),
+            (65_535, "Unknown")
+        ]
+    );
+    assert_eq!(Error::Overflow.name(), "Overflow");
+}
+
+#[test]
+fn should_display_user_errors_with_schema() {
+    let schema = UserErrorSchema::of::<Error>();
+    assert_eq!(
+        schema.display(&ApiError::User(2)).to_string(),
+        "User error: 2 (Unauthorized)"
+    );
+    assert_eq!(
+        schema.display(&ApiError::User(3)).to_string(),
+        ApiError::User(3).to_string()
+    );
+    assert_eq!(
+        schema.display(&ApiError::InvalidArgument).to_string(),
+        ApiError::InvalidArgument.to_string()
+    );
+}
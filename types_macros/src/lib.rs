@@ -0,0 +1,117 @@
+//! Derive macros for the `casper-types` crate.  See `README.md` for details.
+
+#![doc(html_root_url = "https://docs.rs/casper-types-macros/1.1.2")]
+#![doc(
+    html_favicon_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Favicon_RGB_50px.png",
+    html_logo_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Symbol_RGB.png",
+    test(attr(forbid(warnings)))
+)]
+#![warn(missing_docs, trivial_casts, trivial_numeric_casts)]
+
+use std::convert::TryFrom;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, ExprLit, Fields, Lit};
+
+/// Derives `casper_types::ContractError` and `From<_> for casper_types::ApiError` for a C-style
+/// enum.
+///
+/// Each variant maps to `ApiError::User` with its discriminant as the user error code, so
+/// discriminants must be integer literals (or implicit) and must fit in a `u16`.
+#[proc_macro_derive(ContractError)]
+pub fn derive_contract_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_contract_error(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_contract_error(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ContractError can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut codes = Vec::with_capacity(data.variants.len());
+    let mut next_discriminant = 0_u64;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "ContractError can only be derived for enums without fields",
+            ));
+        }
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => literal_discriminant(expr)?,
+            None => next_discriminant,
+        };
+        let code = u16::try_from(discriminant).map_err(|_| {
+            Error::new_spanned(
+                variant,
+                format!(
+                    "discriminant {} of {} does not fit in a u16",
+                    discriminant, variant.ident
+                ),
+            )
+        })?;
+        next_discriminant = discriminant + 1;
+        variants.push(&variant.ident);
+        codes.push(code);
+    }
+
+    let name = &input.ident;
+    let names = variants.iter().map(|variant| variant.to_string());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::casper_types::ContractError for #name #ty_generics #where_clause {
+            const SCHEMA: &'static [(u16, &'static str)] = &[
+                #( (#codes, #names), )*
+            ];
+
+            fn user_error_code(&self) -> u16 {
+                match self {
+                    #( #name::#variants => #codes, )*
+                }
+            }
+
+            fn from_user_error_code(code: u16) -> ::core::option::Option<Self> {
+                match code {
+                    #( #codes => ::core::option::Option::Some(#name::#variants), )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics>
+            for ::casper_types::ApiError #where_clause
+        {
+            fn from(error: #name #ty_generics) -> Self {
+                ::casper_types::ApiError::User(
+                    ::casper_types::ContractError::user_error_code(&error),
+                )
+            }
+        }
+    })
+}
+
+/// Returns the value of an explicit discriminant, which must be a non-negative integer literal.
+fn literal_discriminant(expr: &Expr) -> Result<u64, Error> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        _ => Err(Error::new_spanned(
+            expr,
+            "ContractError requires discriminants to be integer literals",
+        )),
+    }
+}